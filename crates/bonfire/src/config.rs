@@ -1,6 +1,6 @@
 use async_tungstenite::tungstenite::{handshake, Message};
 use futures::channel::oneshot::Sender;
-use revolt_quark::{Error, Result};
+use revolt_quark::{events::server::EventFilter, Error, Result};
 use serde::{Deserialize, Serialize};
 
 /// Enumeration of supported protocol formats
@@ -16,6 +16,8 @@ pub struct ProtocolConfiguration {
     protocol_version: i32,
     format: ProtocolFormat,
     session_token: Option<String>,
+    acknowledge: bool,
+    exclude: Vec<EventFilter>,
 }
 
 impl ProtocolConfiguration {
@@ -24,11 +26,15 @@ impl ProtocolConfiguration {
         protocol_version: i32,
         format: ProtocolFormat,
         session_token: Option<String>,
+        acknowledge: bool,
+        exclude: Vec<EventFilter>,
     ) -> Self {
         Self {
             protocol_version,
             format,
             session_token,
+            acknowledge,
+            exclude,
         }
     }
 
@@ -83,6 +89,25 @@ impl ProtocolConfiguration {
     pub fn get_protocol_format(&self) -> &ProtocolFormat {
         &self.format
     }
+
+    /// Check whether the client asked for event batches to be acknowledged
+    pub fn get_acknowledge(&self) -> bool {
+        self.acknowledge
+    }
+
+    /// Add categories of events the client does not want to receive
+    pub fn exclude_events(&mut self, filters: Vec<EventFilter>) {
+        for filter in filters {
+            if !self.exclude.contains(&filter) {
+                self.exclude.push(filter);
+            }
+        }
+    }
+
+    /// Get the categories of events the client does not want to receive
+    pub fn get_excluded_events(&self) -> &[EventFilter] {
+        &self.exclude
+    }
 }
 
 /// Object holding one side of a channel for receiving the parsed information
@@ -112,6 +137,8 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
         let mut protocol_version = 1;
         let mut format = ProtocolFormat::Json;
         let mut session_token = None;
+        let mut acknowledge = false;
+        let mut exclude = vec![];
 
         // Parse and map parameters from key-value to known variables.
         for (key, value) in params {
@@ -127,6 +154,13 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
                     _ => {}
                 },
                 "token" => session_token = Some(value.into()),
+                "ack" => acknowledge = matches!(value, "true" | "1"),
+                "exclude" => {
+                    exclude = value
+                        .split(',')
+                        .filter_map(EventFilter::from_name)
+                        .collect();
+                }
                 _ => {}
             }
         }
@@ -139,6 +173,8 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
                 protocol_version,
                 format,
                 session_token,
+                acknowledge,
+                exclude,
             })
             .is_ok()
         {
@@ -0,0 +1,67 @@
+//! Graceful draining of connections when the gateway is shutting down
+//!
+//! Each client is asked to reconnect at a random point within the drain
+//! period, so the remaining nodes are not hit by every client at once.
+
+use std::{env, sync::Mutex, thread, time::Duration};
+
+use async_std::{
+    channel::{bounded, Receiver, Sender},
+    task,
+};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use signal_hook::{
+    consts::{SIGINT, SIGTERM},
+    iterator::Signals,
+};
+
+/// Seconds over which connections are closed once draining starts
+pub static DRAIN_PERIOD: Lazy<u64> = Lazy::new(|| {
+    env::var("REVOLT_DRAIN_PERIOD")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30)
+});
+
+/// Channel which is closed once draining starts, waking everyone waiting on it
+static SIGNAL: Lazy<(Mutex<Option<Sender<()>>>, Receiver<()>)> = Lazy::new(|| {
+    let (sender, receiver) = bounded(1);
+    (Mutex::new(Some(sender)), receiver)
+});
+
+/// Start draining connections
+pub fn start() {
+    if SIGNAL.0.lock().unwrap().take().is_some() {
+        info!("Draining connections over {}s", *DRAIN_PERIOD);
+    }
+}
+
+/// Wait until draining starts
+pub async fn started() {
+    SIGNAL.1.recv().await.ok();
+}
+
+/// Wait until a connection should be closed
+pub async fn wait() {
+    started().await;
+
+    let delay = rand::thread_rng().gen_range(0..=*DRAIN_PERIOD * 1000);
+    task::sleep(Duration::from_millis(delay)).await;
+}
+
+/// Start draining on the first interrupt or termination signal,
+/// exiting immediately on the second
+pub fn listen_for_signals() {
+    let mut signals = Signals::new([SIGINT, SIGTERM]).expect("Failed to register signal handlers");
+
+    thread::spawn(move || {
+        for (index, _) in signals.forever().enumerate() {
+            if index == 0 {
+                start();
+            } else {
+                std::process::exit(1);
+            }
+        }
+    });
+}
@@ -1,6 +1,7 @@
-use std::env;
+use std::{env, time::Duration};
 
-use async_std::net::TcpListener;
+use async_std::{net::TcpListener, task};
+use futures::{pin_mut, select, FutureExt};
 use revolt_presence::clear_region;
 
 #[macro_use]
@@ -9,6 +10,7 @@ extern crate log;
 pub mod config;
 
 mod database;
+mod drain;
 mod websocket;
 
 #[async_std::main]
@@ -27,8 +29,26 @@ async fn main() {
     let try_socket = TcpListener::bind(bind).await;
     let listener = try_socket.expect("Failed to bind");
 
+    // Stop accepting connections and drain existing ones when asked to shut down.
+    drain::listen_for_signals();
+    let shutdown = drain::started().fuse();
+    pin_mut!(shutdown);
+
     // Start accepting new connections and spawn a client for each connection.
-    while let Ok((stream, addr)) = listener.accept().await {
-        websocket::spawn_client(database::get_db(), stream, addr);
+    loop {
+        let accept = listener.accept().fuse();
+        pin_mut!(accept);
+
+        select! {
+            connection = accept => match connection {
+                Ok((stream, addr)) => websocket::spawn_client(database::get_db(), stream, addr),
+                Err(_) => break,
+            },
+            () = shutdown => break,
+        }
     }
+
+    // Give every client a chance to be asked to reconnect before exiting.
+    drop(listener);
+    task::sleep(Duration::from_secs(*drain::DRAIN_PERIOD + 5)).await;
 }
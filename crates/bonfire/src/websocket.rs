@@ -1,6 +1,9 @@
 use std::net::SocketAddr;
 
-use futures::{channel::oneshot, pin_mut, select, FutureExt, SinkExt, StreamExt, TryStreamExt};
+use futures::{
+    channel::{mpsc, oneshot},
+    pin_mut, select, FutureExt, SinkExt, StreamExt, TryStreamExt,
+};
 use revolt_presence::{create_session, delete_session};
 use revolt_quark::{
     events::{
@@ -9,7 +12,7 @@ use revolt_quark::{
         state::{State, SubscriptionStateChange},
     },
     models::{user::UserHint, User},
-    redis_kiss, Database,
+    redis_kiss, Database, Result,
 };
 
 use async_std::{net::TcpStream, sync::Mutex, task};
@@ -25,7 +28,7 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
 
         // Upgrade the TCP connection to a WebSocket connection.
         // In this process, we also parse any additional parameters given.
-        // e.g. wss://example.com?format=json&version=1
+        // e.g. wss://example.com?format=json&version=1&ack=true
         let (sender, receiver) = oneshot::channel();
         if let Ok(ws) = async_tungstenite::accept_hdr_async_with_config(
             stream,
@@ -49,10 +52,11 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                 // If the user has not provided authentication, request information.
                 if config.get_session_token().is_none() {
                     'outer: while let Ok(message) = read.try_next().await {
-                        if let Ok(ClientMessage::Authenticate { token }) =
+                        if let Ok(ClientMessage::Authenticate { token, exclude }) =
                             config.decode(message.as_ref().unwrap())
                         {
                             config.set_session_token(token);
+                            config.exclude_events(exclude);
                             break 'outer;
                         }
                     }
@@ -64,6 +68,9 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                         Ok(user) => {
                             info!("User {addr:?} authenticated as @{}", user.username);
 
+                            // Delivery acknowledgements are only offered to bots.
+                            let acknowledge = config.get_acknowledge() && user.bot.is_some();
+
                             // Create local state.
                             let mut state = State::from(user);
                             let user_id = state.cache.user_id.clone();
@@ -93,6 +100,10 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                                     state.broadcast_presence_change(true).await;
                                 }
 
+                                // Member list subscriptions are passed from the reader to the listener.
+                                let (requests_tx, mut requests) =
+                                    mpsc::unbounded::<(String, Vec<String>)>();
+
                                 // Create a PubSub connection to poll on.
                                 let listener = async {
                                     if let Ok(mut conn) = redis_kiss::open_pubsub_connection().await
@@ -136,7 +147,26 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                                             );*/
 
                                             // Handle incoming events.
-                                            match conn.on_message().next().await.map(|item| {
+                                            let item = {
+                                                let next = conn.on_message().next().fuse();
+                                                pin_mut!(next);
+
+                                                select! {
+                                                    item = next => item,
+                                                    request = requests.next() => {
+                                                        // Watch the members the client can see.
+                                                        if let Some((server, users)) = request {
+                                                            state
+                                                                .subscribe_member_list(db, &server, users)
+                                                                .await;
+                                                        }
+
+                                                        continue;
+                                                    }
+                                                }
+                                            };
+
+                                            match item.map(|item| {
                                                 (
                                                     item.get_channel_name().to_string(),
                                                     redis_kiss::decode_payload::<EventV1>(&item),
@@ -144,17 +174,25 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                                             }) {
                                                 Some((channel, item)) => {
                                                     if let Ok(mut event) = item {
+                                                        // State is kept up to date even for events
+                                                        // the client asked not to receive.
                                                         if state
                                                             .handle_incoming_event_v1(
                                                                 db, &mut event,
                                                             )
                                                             .await
-                                                            && write.lock().await
-                                                                .send(config.encode(&event))
-                                                                .await
-                                                                .is_err()
                                                         {
-                                                            break;
+                                                            if let Some(event) = event
+                                                                .filter(config.get_excluded_events())
+                                                            {
+                                                                if write.lock().await
+                                                                    .send(config.encode(&event))
+                                                                    .await
+                                                                    .is_err()
+                                                                {
+                                                                    break;
+                                                                }
+                                                            }
                                                         }
                                                     } else {
                                                         warn!("Failed to deserialise an event for {channel}!");
@@ -170,53 +208,121 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                                 .fuse();
 
                                 // Read from WebSocket stream.
-                                let worker =
-                                    async {
-                                        while let Ok(Some(msg)) = read.try_next().await {
-                                            if let Ok(payload) = config.decode(&msg) {
-                                                match payload {
-                                                    ClientMessage::BeginTyping { channel } => {
-                                                        EventV1::ChannelStartTyping {
-                                                            id: channel.clone(),
-                                                            user: user_id.clone(),
-                                                        }
-                                                        .p(channel.clone())
-                                                        .await;
+                                let worker = async {
+                                    while let Ok(Some(msg)) = read.try_next().await {
+                                        if let Ok(payload) = config.decode(&msg) {
+                                            match payload {
+                                                ClientMessage::BeginTyping { channel } => {
+                                                    EventV1::ChannelStartTyping {
+                                                        id: channel.clone(),
+                                                        user: user_id.clone(),
                                                     }
-                                                    ClientMessage::EndTyping { channel } => {
-                                                        EventV1::ChannelStopTyping {
-                                                            id: channel.clone(),
-                                                            user: user_id.clone(),
+                                                    .p(channel.clone())
+                                                    .await;
+                                                }
+                                                ClientMessage::EndTyping { channel } => {
+                                                    EventV1::ChannelStopTyping {
+                                                        id: channel.clone(),
+                                                        user: user_id.clone(),
+                                                    }
+                                                    .p(channel.clone())
+                                                    .await;
+                                                }
+                                                ClientMessage::SubscribeMembers {
+                                                    server,
+                                                    users,
+                                                } => {
+                                                    requests_tx
+                                                        .unbounded_send((server, users))
+                                                        .ok();
+                                                }
+                                                ClientMessage::UpdatePresence {
+                                                    presence,
+                                                    activity,
+                                                } => {
+                                                    let result = match db.fetch_user(&user_id).await
+                                                    {
+                                                        Ok(mut user) => {
+                                                            user.set_bot_presence(
+                                                                db, presence, activity,
+                                                            )
+                                                            .await
                                                         }
-                                                        .p(channel.clone())
-                                                        .await;
+                                                        Err(err) => Err(err),
+                                                    };
+
+                                                    if let Err(err) = result {
+                                                        write
+                                                            .lock()
+                                                            .await
+                                                            .send(config.encode(&err))
+                                                            .await
+                                                            .ok();
                                                     }
-                                                    ClientMessage::Ping { data, responded } => {
-                                                        if responded.is_none() {
-                                                            write
-                                                                .lock()
-                                                                .await
-                                                                .send(config.encode(
-                                                                    &EventV1::Pong { data },
-                                                                ))
-                                                                .await
-                                                                .ok();
+                                                }
+                                                ClientMessage::Batch { id, events } => {
+                                                    let mut delivered = true;
+                                                    for event in events {
+                                                        if publish(event, &user_id).await.is_err() {
+                                                            delivered = false;
+                                                            break;
                                                         }
                                                     }
-                                                    _ => {}
+
+                                                    if acknowledge {
+                                                        let event = if delivered {
+                                                            EventV1::Acknowledged { id }
+                                                        } else {
+                                                            EventV1::DeliveryFailed { id }
+                                                        };
+
+                                                        write
+                                                            .lock()
+                                                            .await
+                                                            .send(config.encode(&event))
+                                                            .await
+                                                            .ok();
+                                                    }
                                                 }
+                                                ClientMessage::Ping { data, responded } => {
+                                                    if responded.is_none() {
+                                                        write
+                                                            .lock()
+                                                            .await
+                                                            .send(
+                                                                config.encode(&EventV1::Pong {
+                                                                    data,
+                                                                }),
+                                                            )
+                                                            .await
+                                                            .ok();
+                                                    }
+                                                }
+                                                _ => {}
                                             }
                                         }
                                     }
-                                    .fuse();
+                                }
+                                .fuse();
+
+                                // Ask the client to reconnect elsewhere if this node is shutting down.
+                                let drain = crate::drain::wait().fuse();
 
-                                // Pin both tasks.
-                                pin_mut!(listener, worker);
+                                // Pin all tasks.
+                                pin_mut!(listener, worker, drain);
 
-                                // Wait for either disconnect or for listener to die.
+                                // Wait for either disconnect, for listener to die or for the node to drain.
                                 select!(
                                     () = listener => {},
-                                    () = worker => {}
+                                    () = worker => {},
+                                    () = drain => {
+                                        write
+                                            .lock()
+                                            .await
+                                            .send(config.encode(&EventV1::Reconnect))
+                                            .await
+                                            .ok();
+                                    }
                                 );
 
                                 // * Combine the streams back once we are ready to disconnect.
@@ -250,3 +356,28 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
         info!("User disconnected from {addr:?}");
     });
 }
+
+/// Publish an event sent by the client within a batch,
+/// returning an error if the broker did not accept it.
+async fn publish(message: ClientMessage, user_id: &str) -> Result<()> {
+    match message {
+        ClientMessage::BeginTyping { channel } => {
+            EventV1::ChannelStartTyping {
+                id: channel.clone(),
+                user: user_id.to_string(),
+            }
+            .try_p(channel)
+            .await
+        }
+        ClientMessage::EndTyping { channel } => {
+            EventV1::ChannelStopTyping {
+                id: channel.clone(),
+                user: user_id.to_string(),
+            }
+            .try_p(channel)
+            .await
+        }
+        // Other messages do not publish anything.
+        _ => Ok(()),
+    }
+}
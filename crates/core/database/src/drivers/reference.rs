@@ -3,8 +3,12 @@ use std::{collections::HashMap, sync::Arc};
 use futures::lock::Mutex;
 
 use crate::{
-    AccountStrike, Bot, Channel, File, Member, MemberCompositeKey, Server, User, UserSettings,
-    Webhook,
+    AccountStrike, AdminAuditEntry, AuditLogEntry, BanSyncGroup, BanSyncProposal, Bot, Broadcast,
+    Channel, ChannelFeed, ChannelInbox, ChannelSchedule, ChannelTopicChange, FeatureFlag,
+    FederatedMessage, File, KeywordHighlights, MaintenanceStatus, Member, MemberCompositeKey,
+    MessageHighlight, ModerationCase, PolicyAcceptance, PolicyDocument, ReactionRole, RoleTemplate,
+    ScheduledMessage, Server, ServerAnnouncement, SessionScopes, Thread, User, UserSettings,
+    VoiceSession, Webhook,
 };
 
 database_derived!(
@@ -27,5 +31,30 @@ database_derived!(
         pub channels: Arc<Mutex<HashMap<String, Channel>>>,
         pub channel_invites: Arc<Mutex<HashMap<String, ()>>>,
         pub channel_unreads: Arc<Mutex<HashMap<String, ()>>>,
+        pub channel_inboxes: Arc<Mutex<HashMap<String, ChannelInbox>>>,
+        pub channel_feeds: Arc<Mutex<HashMap<String, ChannelFeed>>>,
+        pub channel_schedules: Arc<Mutex<HashMap<String, ChannelSchedule>>>,
+        pub federated_messages: Arc<Mutex<HashMap<String, FederatedMessage>>>,
+        pub role_templates: Arc<Mutex<HashMap<String, RoleTemplate>>>,
+        pub feature_flags: Arc<Mutex<HashMap<String, FeatureFlag>>>,
+        pub admin_audit_log: Arc<Mutex<Vec<AdminAuditEntry>>>,
+        pub reaction_roles: Arc<Mutex<HashMap<String, ReactionRole>>>,
+        pub message_highlights: Arc<Mutex<HashMap<String, MessageHighlight>>>,
+        pub ban_sync_groups: Arc<Mutex<HashMap<String, BanSyncGroup>>>,
+        pub ban_sync_proposals: Arc<Mutex<HashMap<String, BanSyncProposal>>>,
+        pub moderation_cases: Arc<Mutex<HashMap<String, ModerationCase>>>,
+        pub moderation_case_counters: Arc<Mutex<HashMap<String, u32>>>,
+        pub policy_documents: Arc<Mutex<HashMap<String, PolicyDocument>>>,
+        pub policy_acceptances: Arc<Mutex<HashMap<String, PolicyAcceptance>>>,
+        pub voice_sessions: Arc<Mutex<HashMap<String, VoiceSession>>>,
+        pub keyword_highlights: Arc<Mutex<HashMap<String, KeywordHighlights>>>,
+        pub server_announcements: Arc<Mutex<HashMap<String, ServerAnnouncement>>>,
+        pub channel_topic_history: Arc<Mutex<HashMap<String, ChannelTopicChange>>>,
+        pub threads: Arc<Mutex<HashMap<String, Thread>>>,
+        pub broadcasts: Arc<Mutex<HashMap<String, Broadcast>>>,
+        pub maintenance: Arc<Mutex<Option<MaintenanceStatus>>>,
+        pub server_audit_log: Arc<Mutex<HashMap<String, AuditLogEntry>>>,
+        pub session_scopes: Arc<Mutex<HashMap<String, SessionScopes>>>,
+        pub scheduled_messages: Arc<Mutex<HashMap<String, ScheduledMessage>>>,
     }
 );
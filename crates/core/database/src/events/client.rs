@@ -2,7 +2,8 @@ use authifier::AuthifierEvent;
 use serde::{Deserialize, Serialize};
 
 use revolt_models::v0::{
-    Channel, FieldsChannel, FieldsWebhook, PartialChannel, PartialWebhook, Webhook,
+    Channel, FederatedMessage, FieldsChannel, FieldsWebhook, PartialChannel, PartialThread,
+    PartialWebhook, Thread, UserFlagsValue, Webhook,
 };
 use revolt_result::Error;
 
@@ -172,7 +173,10 @@ pub enum EventV1 {
     /// - Server Memberships
     ///
     /// User flags are specified to explain why a wipe is occurring though not all reasons will necessarily ever appear.
-    UserPlatformWipe { user_id: String, flags: i32 },
+    UserPlatformWipe {
+        user_id: String,
+        flags: UserFlagsValue,
+    },
 
     /// New emoji
     EmojiCreate(Emoji),
@@ -227,6 +231,15 @@ pub enum EventV1 {
     /// Delete webhook
     WebhookDelete { id: String },
 
+    /// New thread
+    ThreadCreate(Thread),
+
+    /// Update existing thread
+    ThreadUpdate { id: String, data: PartialThread },
+
+    /// New direct message from or to a user on another instance
+    FederatedMessage(FederatedMessage),
+
     /// Auth events
     Auth(AuthifierEvent),
 }
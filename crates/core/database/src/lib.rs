@@ -41,14 +41,75 @@ macro_rules! auto_derived {
     };
 }
 
+/// Generate builder, apply and diff helpers between a model and its partial
+macro_rules! partial_helpers {
+    ( $name:ident, $( $field:ident: $field_ty:ty ),* ) => {
+        paste::paste! {
+            impl $name {
+                /// Apply a partial onto this object
+                pub fn apply(&mut self, partial: [<Partial $name>]) {
+                    self.apply_options(partial);
+                }
+
+                /// Create a partial which turns this object into other
+                ///
+                /// Fields which were cleared in other cannot be represented and are left out.
+                #[allow(clippy::useless_conversion)]
+                pub fn diff(&self, other: &$name) -> [<Partial $name>] {
+                    let mut partial = [<Partial $name>]::default();
+                    $(
+                        if self.$field != other.$field {
+                            partial.$field = other.$field.clone().into();
+                        }
+                    )*
+                    partial
+                }
+            }
+
+            impl [<Partial $name>] {
+                /// Apply this partial onto an object
+                pub fn apply_to(self, target: &mut $name) {
+                    target.apply_options(self);
+                }
+
+                $(
+                    #[doc = concat!("Set `", stringify!($field), "` on this partial")]
+                    #[allow(clippy::useless_conversion)]
+                    pub fn [<with_ $field>](mut self, value: $field_ty) -> Self {
+                        self.$field = value.into();
+                        self
+                    }
+                )*
+            }
+        }
+    };
+}
+
 macro_rules! auto_derived_partial {
-    ( $item:item, $name:expr ) => {
+    (
+        $( #[$meta:meta] )*
+        $vis:vis struct $name:ident {
+            $(
+                $( #[$field_meta:meta] )*
+                $field_vis:vis $field:ident: $field_ty:ty
+            ),* $(,)?
+        },
+        $partial:expr
+    ) => {
         #[derive(OptionalStruct, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
         #[optional_derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
-        #[optional_name = $name]
+        #[optional_name = $partial]
         #[opt_skip_serializing_none]
         #[opt_some_priority]
-        $item
+        $( #[$meta] )*
+        $vis struct $name {
+            $(
+                $( #[$field_meta] )*
+                $field_vis $field: $field_ty
+            ),*
+        }
+
+        partial_helpers!($name, $( $field: $field_ty ),*);
     };
 }
 
@@ -0,0 +1,185 @@
+use revolt_result::Result;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Record of a mutation made through the admin API
+    pub struct AdminAuditEntry {
+        /// Entry Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Position of this entry in the log, starting from 1
+        pub sequence: i64,
+        /// Id of the privileged user who made the change
+        pub actor_id: String,
+        /// Operation that was performed, such as `strike.create`
+        pub operation: String,
+        /// Id of the object that was changed
+        pub target_id: String,
+        /// JSON representation of the object before the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<String>,
+        /// JSON representation of the object after the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<String>,
+        /// Hash of the previous entry in the log
+        pub previous_hash: String,
+        /// Hash of this entry, chained onto the previous hash
+        pub hash: String,
+    }
+);
+
+/// Number of times to retry appending when another entry claims the same position
+static APPEND_ATTEMPTS: usize = 10;
+
+impl AdminAuditEntry {
+    /// Append a new entry to the admin audit log
+    ///
+    /// Each position in the log can only be taken once, so if another entry
+    /// is appended concurrently this chains onto that entry and tries again.
+    pub async fn record<T: Serialize>(
+        db: &Database,
+        actor_id: &str,
+        operation: &str,
+        target_id: &str,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<AdminAuditEntry> {
+        let before = before
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|_| create_error!(InternalError))?;
+
+        let after = after
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|_| create_error!(InternalError))?;
+
+        for _ in 0..APPEND_ATTEMPTS {
+            let (sequence, previous_hash) = db
+                .fetch_latest_admin_audit_entry()
+                .await?
+                .map(|entry| (entry.sequence + 1, entry.hash))
+                .unwrap_or((1, String::new()));
+
+            let mut entry = AdminAuditEntry {
+                id: Ulid::new().to_string(),
+                sequence,
+                actor_id: actor_id.to_string(),
+                operation: operation.to_string(),
+                target_id: target_id.to_string(),
+                before: before.clone(),
+                after: after.clone(),
+                previous_hash,
+                hash: String::new(),
+            };
+
+            entry.hash = entry.compute_hash();
+            if db.insert_admin_audit_entry(&entry).await? {
+                return Ok(entry);
+            }
+        }
+
+        Err(create_error!(InternalError))
+    }
+
+    /// Calculate the hash of this entry from its contents and the previous hash
+    pub fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        for field in [
+            Some(&self.previous_hash),
+            Some(&self.id),
+            Some(&self.actor_id),
+            Some(&self.operation),
+            Some(&self.target_id),
+            self.before.as_ref(),
+            self.after.as_ref(),
+        ] {
+            // Length prefix each field so that values cannot bleed into one another
+            let field = field.map(String::as_bytes);
+            hasher.update(field.map_or(-1_i64, |v| v.len() as i64).to_le_bytes());
+            hasher.update(field.unwrap_or_default());
+        }
+
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Verify a contiguous run of entries, oldest first
+    ///
+    /// Returns the id of the first entry which has been tampered with or does
+    /// not chain onto the entry before it.
+    pub fn verify_chain(entries: &[AdminAuditEntry], previous_hash: &str) -> Option<String> {
+        let mut previous_hash = previous_hash;
+        for entry in entries {
+            if entry.previous_hash != previous_hash || entry.hash != entry.compute_hash() {
+                return Some(entry.id.clone());
+            }
+
+            previous_hash = &entry.hash;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AdminAuditEntry;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let first =
+                AdminAuditEntry::record(&db, "actor", "test.create", "target", None, Some(&1))
+                    .await
+                    .unwrap();
+
+            let second =
+                AdminAuditEntry::record(&db, "actor", "test.edit", "target", Some(&1), Some(&2))
+                    .await
+                    .unwrap();
+
+            assert_eq!(second.previous_hash, first.hash);
+            assert_eq!((first.sequence, second.sequence), (1, 2));
+
+            // Positions in the log cannot be taken twice
+            let conflicting = AdminAuditEntry {
+                id: "conflicting".to_string(),
+                ..second.clone()
+            };
+
+            assert!(!db.insert_admin_audit_entry(&conflicting).await.unwrap());
+
+            let entries = db.fetch_admin_audit_entries(None, 10).await.unwrap();
+            assert_eq!(entries, vec![first.clone(), second.clone()]);
+            assert_eq!(AdminAuditEntry::verify_chain(&entries, ""), None);
+
+            let tampered = vec![
+                first.clone(),
+                AdminAuditEntry {
+                    after: Some("3".to_string()),
+                    ..second.clone()
+                },
+            ];
+
+            assert_eq!(
+                AdminAuditEntry::verify_chain(&tampered, ""),
+                Some(second.id.clone())
+            );
+
+            let after = db
+                .fetch_admin_audit_entries(Some(first.sequence), 10)
+                .await
+                .unwrap();
+
+            assert_eq!(after, vec![second]);
+        });
+    }
+}
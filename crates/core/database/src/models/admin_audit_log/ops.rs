@@ -0,0 +1,24 @@
+use revolt_result::Result;
+
+use crate::AdminAuditEntry;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractAdminAuditLog: Sync + Send {
+    /// Insert a new entry into the admin audit log
+    ///
+    /// Returns false if another entry already holds the same sequence number.
+    async fn insert_admin_audit_entry(&self, entry: &AdminAuditEntry) -> Result<bool>;
+
+    /// Fetch the most recent entry in the admin audit log
+    async fn fetch_latest_admin_audit_entry(&self) -> Result<Option<AdminAuditEntry>>;
+
+    /// Fetch entries from the admin audit log, oldest first, optionally after a given sequence number
+    async fn fetch_admin_audit_entries(
+        &self,
+        after: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<AdminAuditEntry>>;
+}
@@ -0,0 +1,67 @@
+use ::mongodb::error::{ErrorKind, WriteFailure};
+use ::mongodb::options::{FindOneOptions, FindOptions};
+use revolt_result::Result;
+
+use crate::AdminAuditEntry;
+use crate::MongoDb;
+
+use super::AbstractAdminAuditLog;
+
+static COL: &str = "admin_audit_log";
+
+#[async_trait]
+impl AbstractAdminAuditLog for MongoDb {
+    /// Insert a new entry into the admin audit log
+    ///
+    /// Returns false if another entry already holds the same sequence number.
+    async fn insert_admin_audit_entry(&self, entry: &AdminAuditEntry) -> Result<bool> {
+        match self
+            .col::<AdminAuditEntry>(COL)
+            .insert_one(entry, None)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(error) => match *error.kind {
+                // Duplicate key on the unique sequence index
+                ErrorKind::Write(WriteFailure::WriteError(ref error)) if error.code == 11000 => {
+                    Ok(false)
+                }
+                _ => Err(create_database_error!("insert_one", COL)),
+            },
+        }
+    }
+
+    /// Fetch the most recent entry in the admin audit log
+    async fn fetch_latest_admin_audit_entry(&self) -> Result<Option<AdminAuditEntry>> {
+        query!(
+            self,
+            find_one_with_options,
+            COL,
+            doc! {},
+            FindOneOptions::builder()
+                .sort(doc! { "sequence": -1_i32 })
+                .build()
+        )
+    }
+
+    /// Fetch entries from the admin audit log, oldest first, optionally after a given sequence number
+    async fn fetch_admin_audit_entries(
+        &self,
+        after: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<AdminAuditEntry>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            match after {
+                Some(sequence) => doc! { "sequence": { "$gt": sequence } },
+                None => doc! {},
+            },
+            FindOptions::builder()
+                .sort(doc! { "sequence": 1_i32 })
+                .limit(limit)
+                .build()
+        )
+    }
+}
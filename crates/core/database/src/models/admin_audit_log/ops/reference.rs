@@ -0,0 +1,49 @@
+use revolt_result::Result;
+
+use crate::AdminAuditEntry;
+use crate::ReferenceDb;
+
+use super::AbstractAdminAuditLog;
+
+#[async_trait]
+impl AbstractAdminAuditLog for ReferenceDb {
+    /// Insert a new entry into the admin audit log
+    ///
+    /// Returns false if another entry already holds the same sequence number.
+    async fn insert_admin_audit_entry(&self, entry: &AdminAuditEntry) -> Result<bool> {
+        let mut entries = self.admin_audit_log.lock().await;
+        if entries.iter().any(|existing| existing.id == entry.id) {
+            Err(create_database_error!("insert", "admin_audit_log"))
+        } else if entries
+            .iter()
+            .any(|existing| existing.sequence == entry.sequence)
+        {
+            Ok(false)
+        } else {
+            entries.push(entry.clone());
+            entries.sort_by_key(|entry| entry.sequence);
+            Ok(true)
+        }
+    }
+
+    /// Fetch the most recent entry in the admin audit log
+    async fn fetch_latest_admin_audit_entry(&self) -> Result<Option<AdminAuditEntry>> {
+        let entries = self.admin_audit_log.lock().await;
+        Ok(entries.last().cloned())
+    }
+
+    /// Fetch entries from the admin audit log, oldest first, optionally after a given sequence number
+    async fn fetch_admin_audit_entries(
+        &self,
+        after: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<AdminAuditEntry>> {
+        let entries = self.admin_audit_log.lock().await;
+        Ok(entries
+            .iter()
+            .filter(|entry| after.map_or(true, |sequence| entry.sequence > sequence))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
@@ -72,6 +72,142 @@ pub async fn create_database(db: &MongoDb) {
         .await
         .expect("Failed to create bots collection.");
 
+    db.create_collection("channel_inboxes", None)
+        .await
+        .expect("Failed to create channel_inboxes collection.");
+
+    db.create_collection("channel_feeds", None)
+        .await
+        .expect("Failed to create channel_feeds collection.");
+
+    db.create_collection("federated_messages", None)
+        .await
+        .expect("Failed to create federated_messages collection.");
+
+    db.create_collection("role_templates", None)
+        .await
+        .expect("Failed to create role_templates collection.");
+
+    db.create_collection("server_stats", None)
+        .await
+        .expect("Failed to create server_stats collection.");
+
+    db.create_collection("stats_snapshots", None)
+        .await
+        .expect("Failed to create stats_snapshots collection.");
+
+    db.create_collection("safety_monitors", None)
+        .await
+        .expect("Failed to create safety_monitors collection.");
+
+    db.create_collection("server_layouts", None)
+        .await
+        .expect("Failed to create server_layouts collection.");
+
+    db.create_collection("emoji_usage", None)
+        .await
+        .expect("Failed to create emoji_usage collection.");
+
+    db.create_collection("channel_schedules", None)
+        .await
+        .expect("Failed to create channel_schedules collection.");
+
+    db.create_collection("admin_audit_log", None)
+        .await
+        .expect("Failed to create admin_audit_log collection.");
+
+    db.create_collection("feature_flags", None)
+        .await
+        .expect("Failed to create feature_flags collection.");
+
+    db.create_collection("reaction_roles", None)
+        .await
+        .expect("Failed to create reaction_roles collection.");
+
+    db.create_collection("message_highlights", None)
+        .await
+        .expect("Failed to create message_highlights collection.");
+
+    db.create_collection("ban_sync_groups", None)
+        .await
+        .expect("Failed to create ban_sync_groups collection.");
+
+    db.create_collection("ban_sync_proposals", None)
+        .await
+        .expect("Failed to create ban_sync_proposals collection.");
+
+    db.create_collection("moderation_cases", None)
+        .await
+        .expect("Failed to create moderation_cases collection.");
+
+    db.create_collection("policy_documents", None)
+        .await
+        .expect("Failed to create policy_documents collection.");
+
+    db.create_collection("policy_acceptances", None)
+        .await
+        .expect("Failed to create policy_acceptances collection.");
+
+    db.create_collection("voice_sessions", None)
+        .await
+        .expect("Failed to create voice_sessions collection.");
+
+    db.create_collection("keyword_highlights", None)
+        .await
+        .expect("Failed to create keyword_highlights collection.");
+
+    db.create_collection("server_announcements", None)
+        .await
+        .expect("Failed to create server_announcements collection.");
+
+    db.create_collection("deleted_messages", None)
+        .await
+        .expect("Failed to create deleted_messages collection.");
+
+    db.create_collection("legal_holds", None)
+        .await
+        .expect("Failed to create legal_holds collection.");
+
+    db.create_collection("channel_topic_history", None)
+        .await
+        .expect("Failed to create channel_topic_history collection.");
+
+    db.create_collection("threads", None)
+        .await
+        .expect("Failed to create threads collection.");
+
+    db.create_collection("dm_layouts", None)
+        .await
+        .expect("Failed to create dm_layouts collection.");
+
+    db.create_collection("broadcasts", None)
+        .await
+        .expect("Failed to create broadcasts collection.");
+
+    db.create_collection("maintenance", None)
+        .await
+        .expect("Failed to create maintenance collection.");
+
+    db.create_collection("server_audit_log", None)
+        .await
+        .expect("Failed to create server_audit_log collection.");
+
+    db.create_collection("session_scopes", None)
+        .await
+        .expect("Failed to create session_scopes collection.");
+
+    db.create_collection("scheduled_messages", None)
+        .await
+        .expect("Failed to create scheduled_messages collection.");
+
+    db.create_collection("message_revisions", None)
+        .await
+        .expect("Failed to create message_revisions collection.");
+
+    db.create_collection("moderation_case_counters", None)
+        .await
+        .expect("Failed to create moderation_case_counters collection.");
+
     db.create_collection(
         "pubsub",
         CreateCollectionOptions::builder()
@@ -182,6 +318,356 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create server_members index.");
 
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_inboxes",
+            "indexes": [
+                {
+                    "key": {
+                        "channel_id": 1_i32
+                    },
+                    "name": "channel_id",
+                    "unique": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_inboxes index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_feeds",
+            "indexes": [
+                {
+                    "key": {
+                        "channel_id": 1_i32
+                    },
+                    "name": "channel_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_feeds index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "federated_messages",
+            "indexes": [
+                {
+                    "key": {
+                        "user_id": 1_i32,
+                        "remote_user": 1_i32
+                    },
+                    "name": "conversation"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create federated_messages index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "safety_snapshots",
+            "indexes": [
+                {
+                    "key": {
+                        "content._id": 1_i32
+                    },
+                    "name": "content_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create safety_snapshots index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "reaction_roles",
+            "indexes": [
+                {
+                    "key": {
+                        "message_id": 1_i32,
+                    },
+                    "name": "message_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create reaction_roles index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "ban_sync_groups",
+            "indexes": [
+                {
+                    "key": {
+                        "join_code": 1_i32,
+                    },
+                    "name": "join_code",
+                    "unique": true
+                },
+                {
+                    "key": {
+                        "members.server_id": 1_i32,
+                    },
+                    "name": "members_server_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create ban_sync_groups index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "ban_sync_proposals",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                    },
+                    "name": "server_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create ban_sync_proposals index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "moderation_cases",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                        "case_number": 1_i32,
+                    },
+                    "name": "case_number",
+                    "unique": true
+                },
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                        "user_id": 1_i32,
+                    },
+                    "name": "member"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create moderation_cases index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "voice_sessions",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                        "user_id": 1_i32
+                    },
+                    "name": "member"
+                },
+                {
+                    "key": {
+                        "user_id": 1_i32,
+                        "left_at": 1_i32
+                    },
+                    "name": "open_session"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create voice_sessions index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_topic_history",
+            "indexes": [
+                {
+                    "key": {
+                        "channel_id": 1_i32,
+                        "_id": -1_i32,
+                    },
+                    "name": "channel"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_topic_history index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "threads",
+            "indexes": [
+                {
+                    "key": {
+                        "channel_id": 1_i32,
+                        "_id": -1_i32,
+                    },
+                    "name": "channel"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create threads index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "broadcasts",
+            "indexes": [
+                {
+                    "key": {
+                        "sent": 1_i32,
+                        "send_at": 1_i32
+                    },
+                    "name": "pending"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create broadcasts index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_audit_log",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_audit_log index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "session_scopes",
+            "indexes": [
+                {
+                    "key": {
+                        "user_id": 1_i32
+                    },
+                    "name": "user_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create session_scopes index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "scheduled_messages",
+            "indexes": [
+                {
+                    "key": {
+                        "channel_id": 1_i32
+                    },
+                    "name": "channel_id"
+                },
+                {
+                    "key": {
+                        "send_at": 1_i32
+                    },
+                    "name": "send_at"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create scheduled_messages index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "message_revisions",
+            "indexes": [
+                {
+                    "key": {
+                        "message_id": 1_i32,
+                        "_id": -1_i32,
+                    },
+                    "name": "message"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create message_revisions index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "messages",
+            "indexes": [
+                {
+                    "key": {
+                        "attachments.content_type": 1_i32
+                    },
+                    "name": "attachments_content_type"
+                },
+                {
+                    "key": {
+                        "attachments.size": 1_i32
+                    },
+                    "name": "attachments_size"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create attachment indexes.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "admin_audit_log",
+            "indexes": [
+                {
+                    "key": {
+                        "sequence": 1_i32
+                    },
+                    "name": "sequence",
+                    "unique": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create admin_audit_log index.");
+
     db.collection("migrations")
         .insert_one(
             doc! {
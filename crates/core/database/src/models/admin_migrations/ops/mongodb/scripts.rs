@@ -1,4 +1,4 @@
-use std::{ops::BitXor, time::Duration};
+use std::{collections::HashMap, ops::BitXor, time::Duration};
 
 use crate::{
     mongodb::{
@@ -16,7 +16,7 @@ struct MigrationInfo {
     revision: i32,
 }
 
-pub const LATEST_REVISION: i32 = 23;
+pub const LATEST_REVISION: i32 = 59;
 
 pub async fn migrate_database(db: &MongoDb) {
     let migrations = db.col::<Document>("migrations");
@@ -768,6 +768,925 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
             .expect("Failed to update server members.");
     }
 
+    if revision <= 23 {
+        info!("Running migration [revision 23 / 15-10-2026]: Add collection `channel_inboxes`.");
+
+        db.db()
+            .create_collection("channel_inboxes", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_inboxes",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32
+                            },
+                            "name": "channel_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel inbox index.");
+    }
+
+    if revision <= 24 {
+        info!("Running migration [revision 24 / 15-10-2026]: Add collection `channel_feeds`.");
+
+        db.db()
+            .create_collection("channel_feeds", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_feeds",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32
+                            },
+                            "name": "channel_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel feed index.");
+    }
+
+    if revision <= 25 {
+        info!("Running migration [revision 25 / 15-10-2026]: Add collection `federated_messages`.");
+
+        db.db()
+            .create_collection("federated_messages", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "federated_messages",
+                    "indexes": [
+                        {
+                            "key": {
+                                "user_id": 1_i32,
+                                "remote_user": 1_i32
+                            },
+                            "name": "conversation"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create federated message index.");
+    }
+
+    if revision <= 26 {
+        info!("Running migration [revision 26 / 15-10-2026]: Split coarse permissions into granular thread, pin, emoji and invite permissions.");
+
+        /// Grant the new granular permissions wherever their coarse counterpart is set
+        fn split(value: i64) -> i64 {
+            let mut value = value;
+
+            // ManageMessages => ManageThreads + PinMessages
+            if value & (1 << 23) != 0 {
+                value |= (1 << 36) | (1 << 37);
+            }
+
+            // React => UseExternalEmojis
+            if value & (1 << 29) != 0 {
+                value |= 1 << 38;
+            }
+
+            // InviteOthers => CreateInvites
+            if value & (1 << 25) != 0 {
+                value |= 1 << 39;
+            }
+
+            value
+        }
+
+        /// Apply to a permission value or an override
+        fn split_bson(value: &mut Bson) {
+            match value {
+                Bson::Int64(v) => *v = split(*v),
+                Bson::Int32(v) => {
+                    let v = *v as i64;
+                    *value = Bson::Int64(split(v));
+                }
+                Bson::Document(document) => {
+                    for key in ["a", "d"] {
+                        if let Some(v) = document.get_mut(key) {
+                            split_bson(v);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let servers = db.col::<Document>("servers");
+        let mut cursor = servers.find(doc! {}, None).await.unwrap();
+
+        while let Some(Ok(mut document)) = cursor.next().await {
+            let id = document.get_str("_id").unwrap().to_string();
+            info!("Updating server {id}");
+
+            let mut update = doc! {};
+
+            if let Some(mut value) = document.remove("default_permissions") {
+                split_bson(&mut value);
+                update.insert("default_permissions", value);
+            }
+
+            if let Some(Bson::Document(mut roles)) = document.remove("roles") {
+                for role in roles.keys().cloned().collect::<Vec<String>>() {
+                    if let Some(Bson::Document(role)) = roles.get_mut(role) {
+                        if let Some(value) = role.get_mut("permissions") {
+                            split_bson(value);
+                        }
+                    }
+                }
+
+                update.insert("roles", roles);
+            }
+
+            if !update.is_empty() {
+                servers
+                    .update_one(doc! { "_id": id }, doc! { "$set": update }, None)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let channels = db.col::<Document>("channels");
+        let mut cursor = channels.find(doc! {}, None).await.unwrap();
+
+        while let Some(Ok(mut document)) = cursor.next().await {
+            let id = document.get_str("_id").unwrap().to_string();
+            info!("Updating channel {id}");
+
+            let mut update = doc! {};
+
+            for key in ["default_permissions", "permissions"] {
+                if let Some(mut value) = document.remove(key) {
+                    split_bson(&mut value);
+                    update.insert(key, value);
+                }
+            }
+
+            if let Some(Bson::Document(mut role_permissions)) = document.remove("role_permissions")
+            {
+                for role in role_permissions.keys().cloned().collect::<Vec<String>>() {
+                    if let Some(value) = role_permissions.get_mut(role) {
+                        split_bson(value);
+                    }
+                }
+
+                update.insert("role_permissions", role_permissions);
+            }
+
+            if !update.is_empty() {
+                channels
+                    .update_one(doc! { "_id": id }, doc! { "$set": update }, None)
+                    .await
+                    .unwrap();
+            }
+        }
+    }
+
+    if revision <= 27 {
+        info!("Running migration [revision 27 / 15-10-2026]: Add collection `role_templates`.");
+
+        db.db()
+            .create_collection("role_templates", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 28 {
+        info!("Running migration [revision 28 / 15-10-2026]: Add collection `server_stats`.");
+
+        db.db()
+            .create_collection("server_stats", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 29 {
+        info!("Running migration [revision 29 / 15-10-2026]: Add collection `stats_snapshots`.");
+
+        db.db()
+            .create_collection("stats_snapshots", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 30 {
+        info!("Running migration [revision 30 / 15-10-2026]: Add collection `safety_monitors` and index `snapshot.content._id`.");
+
+        db.db()
+            .create_collection("safety_monitors", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "safety_snapshots",
+                    "indexes": [
+                        {
+                            "key": {
+                                "content._id": 1_i32
+                            },
+                            "name": "content_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create safety snapshot index.");
+    }
+
+    if revision <= 31 {
+        info!("Running migration [revision 31 / 15-10-2026]: Add collection `server_layouts`.");
+
+        db.db()
+            .create_collection("server_layouts", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 32 {
+        info!("Running migration [revision 32 / 15-10-2026]: Add collection `emoji_usage`.");
+
+        db.db()
+            .create_collection("emoji_usage", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 33 {
+        info!("Running migration [revision 33 / 15-10-2026]: Add collection `channel_schedules`.");
+
+        db.db()
+            .create_collection("channel_schedules", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 34 {
+        info!("Running migration [revision 34 / 15-10-2026]: Add collection `admin_audit_log`.");
+
+        db.db()
+            .create_collection("admin_audit_log", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 35 {
+        info!("Running migration [revision 35 / 15-10-2026]: Add collection `feature_flags`.");
+
+        db.db()
+            .create_collection("feature_flags", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 36 {
+        info!("Running migration [revision 36 / 15-10-2026]: Add collection `reaction_roles` and index `message_id`.");
+
+        db.db()
+            .create_collection("reaction_roles", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "reaction_roles",
+                    "indexes": [
+                        {
+                            "key": {
+                                "message_id": 1_i32
+                            },
+                            "name": "message_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create reaction role index.");
+    }
+
+    if revision <= 37 {
+        info!("Running migration [revision 37 / 15-10-2026]: Add collection `message_highlights`.");
+
+        db.db()
+            .create_collection("message_highlights", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 38 {
+        info!("Running migration [revision 38 / 15-10-2026]: Add collections `ban_sync_groups` and `ban_sync_proposals`.");
+
+        db.db()
+            .create_collection("ban_sync_groups", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .create_collection("ban_sync_proposals", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "ban_sync_groups",
+                    "indexes": [
+                        {
+                            "key": {
+                                "join_code": 1_i32
+                            },
+                            "name": "join_code",
+                            "unique": true
+                        },
+                        {
+                            "key": {
+                                "members.server_id": 1_i32
+                            },
+                            "name": "members_server_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create ban sync group indexes.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "ban_sync_proposals",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32
+                            },
+                            "name": "server_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create ban sync proposal index.");
+    }
+
+    if revision <= 39 {
+        info!("Running migration [revision 39 / 15-10-2026]: Add collection `moderation_cases` with case number and member indexes.");
+
+        db.db()
+            .create_collection("moderation_cases", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "moderation_cases",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32,
+                                "case_number": 1_i32
+                            },
+                            "name": "case_number",
+                            "unique": true
+                        },
+                        {
+                            "key": {
+                                "server_id": 1_i32,
+                                "user_id": 1_i32
+                            },
+                            "name": "member"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create moderation case indexes.");
+    }
+
+    if revision <= 40 {
+        info!("Running migration [revision 40 / 15-10-2026]: Add collections `policy_documents` and `policy_acceptances`.");
+
+        db.db()
+            .create_collection("policy_documents", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .create_collection("policy_acceptances", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 41 {
+        info!("Running migration [revision 41 / 15-10-2026]: Add collection `voice_sessions` with member index.");
+
+        db.db()
+            .create_collection("voice_sessions", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "voice_sessions",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32,
+                                "user_id": 1_i32
+                            },
+                            "name": "member"
+                        },
+                        {
+                            "key": {
+                                "user_id": 1_i32,
+                                "left_at": 1_i32
+                            },
+                            "name": "open_session"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create voice session indexes.");
+    }
+
+    if revision <= 42 {
+        info!("Running migration [revision 42 / 15-10-2026]: Add collection `keyword_highlights`.");
+
+        db.db()
+            .create_collection("keyword_highlights", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 43 {
+        info!("Running migration [revision 43 / 15-10-2026]: Add collection `server_announcements`.");
+
+        db.db()
+            .create_collection("server_announcements", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 44 {
+        info!("Running migration [revision 44 / 15-10-2026]: Add collection `deleted_messages`.");
+
+        db.db()
+            .create_collection("deleted_messages", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 45 {
+        info!("Running migration [revision 45 / 15-10-2026]: Add collection `legal_holds`.");
+
+        db.db()
+            .create_collection("legal_holds", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 46 {
+        info!("Running migration [revision 46 / 15-10-2026]: Add collection `channel_topic_history` with channel index.");
+
+        db.db()
+            .create_collection("channel_topic_history", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_topic_history",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "channel"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel topic history index.");
+    }
+
+    if revision <= 47 {
+        info!("Running migration [revision 47 / 15-10-2026]: Add collection `threads` with channel index.");
+
+        db.db()
+            .create_collection("threads", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "threads",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "channel"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create thread indexes.");
+    }
+
+    if revision <= 48 {
+        info!("Running migration [revision 48 / 15-10-2026]: Add collection `dm_layouts`.");
+
+        db.db()
+            .create_collection("dm_layouts", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 49 {
+        info!("Running migration [revision 49 / 15-10-2026]: Add collection `broadcasts` with pending index.");
+
+        db.db()
+            .create_collection("broadcasts", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "broadcasts",
+                    "indexes": [
+                        {
+                            "key": {
+                                "sent": 1_i32,
+                                "send_at": 1_i32
+                            },
+                            "name": "pending"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create broadcast index.");
+    }
+
+    if revision <= 50 {
+        info!("Running migration [revision 50 / 15-10-2026]: Add collection `maintenance`.");
+
+        db.db()
+            .create_collection("maintenance", None)
+            .await
+            .unwrap();
+    }
+
+    if revision <= 51 {
+        info!("Running migration [revision 51 / 15-10-2026]: Add collection `server_audit_log` with server index.");
+
+        db.db()
+            .create_collection("server_audit_log", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_audit_log",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server audit log index.");
+    }
+
+    if revision <= 52 {
+        info!("Running migration [revision 52 / 15-10-2026]: Add collection `session_scopes` with user index.");
+
+        db.db()
+            .create_collection("session_scopes", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "session_scopes",
+                    "indexes": [
+                        {
+                            "key": {
+                                "user_id": 1_i32
+                            },
+                            "name": "user_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create session scopes index.");
+    }
+
+    if revision <= 53 {
+        info!("Running migration [revision 53 / 15-10-2026]: Add collection `scheduled_messages` with channel and send time indexes.");
+
+        db.db()
+            .create_collection("scheduled_messages", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "scheduled_messages",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32
+                            },
+                            "name": "channel_id"
+                        },
+                        {
+                            "key": {
+                                "send_at": 1_i32
+                            },
+                            "name": "send_at"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create scheduled messages indexes.");
+    }
+
+    if revision <= 54 {
+        info!("Running migration [revision 54 / 15-10-2026]: Add collection `message_revisions` with message index.");
+
+        db.db()
+            .create_collection("message_revisions", None)
+            .await
+            .unwrap();
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "message_revisions",
+                    "indexes": [
+                        {
+                            "key": {
+                                "message_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "message"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create message revisions index.");
+    }
+
+    if revision <= 55 {
+        info!("Running migration [revision 55 / 15-10-2026]: Make `channel_inboxes.channel_id` index unique.");
+
+        if db
+            .db()
+            .run_command(
+                doc! {
+                    "dropIndexes": "channel_inboxes",
+                    "index": ["channel_id"]
+                },
+                None,
+            )
+            .await
+            .is_err()
+        {
+            info!("Failed to drop `channel_inboxes.channel_id` index but this is ok since that means it's probably gone.");
+        }
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_inboxes",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel_id": 1_i32
+                            },
+                            "name": "channel_id",
+                            "unique": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create unique channel inboxes index.");
+    }
+
+    if revision <= 56 {
+        info!("Running migration [revision 56 / 15-10-2026]: Add indexes for attachment content type and size.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "messages",
+                    "indexes": [
+                        {
+                            "key": {
+                                "attachments.content_type": 1_i32
+                            },
+                            "name": "attachments_content_type"
+                        },
+                        {
+                            "key": {
+                                "attachments.size": 1_i32
+                            },
+                            "name": "attachments_size"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create attachment indexes.");
+    }
+
+    if revision <= 57 {
+        info!("Running migration [revision 57 / 15-10-2026]: Add collection `moderation_case_counters` and seed it from existing cases.");
+
+        db.db()
+            .create_collection("moderation_case_counters", None)
+            .await
+            .expect("Failed to create moderation_case_counters collection.");
+
+        // Carry on from the highest case number already handed out in each server
+        let mut counters: HashMap<String, i64> = HashMap::new();
+        let mut cursor = db
+            .col::<Document>("moderation_cases")
+            .find(
+                doc! {},
+                FindOptions::builder()
+                    .projection(doc! {
+                        "server_id": 1_i32,
+                        "case_number": 1_i32
+                    })
+                    .build(),
+            )
+            .await
+            .expect("Failed to fetch moderation cases.");
+
+        while let Some(Ok(doc)) = cursor.next().await {
+            let case_number = match doc.get("case_number") {
+                Some(Bson::Int32(value)) => *value as i64,
+                Some(Bson::Int64(value)) => *value,
+                _ => continue,
+            };
+
+            if let Ok(server_id) = doc.get_str("server_id") {
+                let counter = counters.entry(server_id.to_string()).or_default();
+                *counter = (*counter).max(case_number);
+            }
+        }
+
+        if !counters.is_empty() {
+            db.col::<Document>("moderation_case_counters")
+                .insert_many(
+                    counters.into_iter().map(|(server_id, case_number)| {
+                        doc! {
+                            "_id": server_id,
+                            "case_number": case_number
+                        }
+                    }),
+                    None,
+                )
+                .await
+                .expect("Failed to seed moderation case counters.");
+        }
+    }
+
+    if revision <= 58 {
+        info!("Running migration [revision 58 / 15-10-2026]: Number `admin_audit_log` entries and add a unique index on their sequence.");
+
+        // Follow the hash chain rather than ids, which are not ordered within a millisecond
+        let mut by_previous_hash: HashMap<String, Vec<Document>> = HashMap::new();
+        let mut cursor = db
+            .col::<Document>("admin_audit_log")
+            .find(
+                doc! {},
+                FindOptions::builder().sort(doc! { "_id": 1_i32 }).build(),
+            )
+            .await
+            .expect("Failed to fetch admin audit log.");
+
+        while let Some(Ok(doc)) = cursor.next().await {
+            let previous_hash = doc.get_str("previous_hash").unwrap_or_default().to_string();
+            by_previous_hash.entry(previous_hash).or_default().push(doc);
+        }
+
+        let mut ordered = vec![];
+        let mut previous_hash = String::new();
+        while let Some(entries) = by_previous_hash.get_mut(&previous_hash) {
+            let entry = entries.remove(0);
+            if entries.is_empty() {
+                by_previous_hash.remove(&previous_hash);
+            }
+
+            previous_hash = entry.get_str("hash").unwrap_or_default().to_string();
+            ordered.push(entry);
+        }
+
+        // Entries which do not chain on are kept at the end, in the order they were created
+        let mut unchained: Vec<Document> = by_previous_hash.into_values().flatten().collect();
+        unchained.sort_by(|a, b| a.get_str("_id").ok().cmp(&b.get_str("_id").ok()));
+        ordered.append(&mut unchained);
+
+        for (index, entry) in ordered.iter().enumerate() {
+            db.col::<Document>("admin_audit_log")
+                .update_one(
+                    doc! {
+                        "_id": entry.get_str("_id").unwrap_or_default()
+                    },
+                    doc! {
+                        "$set": {
+                            "sequence": index as i64 + 1
+                        }
+                    },
+                    None,
+                )
+                .await
+                .expect("Failed to number admin audit log entry.");
+        }
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "admin_audit_log",
+                    "indexes": [
+                        {
+                            "key": {
+                                "sequence": 1_i32
+                            },
+                            "name": "sequence",
+                            "unique": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create admin_audit_log index.");
+    }
+
     // Need to migrate fields on attachments, change `user_id`, `object_id`, etc to `parent`.
 
     // Reminder to update LATEST_REVISION when adding new migrations.
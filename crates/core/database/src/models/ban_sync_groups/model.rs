@@ -0,0 +1,129 @@
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Group of servers which share their bans with one another
+    pub struct BanSyncGroup {
+        /// Ban sync group Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Display name of the group
+        pub name: String,
+        /// Code other servers use to join this group
+        pub join_code: String,
+        /// Servers taking part in this group
+        pub members: Vec<BanSyncMember>,
+    }
+
+    /// Server taking part in a ban sync group
+    pub struct BanSyncMember {
+        /// Id of the server
+        pub server_id: String,
+        /// How bans from other servers in the group are handled
+        pub policy: BanSyncPolicy,
+    }
+
+    /// How a server handles bans shared by others in its group
+    pub enum BanSyncPolicy {
+        /// Bans are proposed to the server's moderators
+        Propose,
+        /// Bans are applied immediately
+        AutoApply,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl BanSyncGroup {
+    /// Create a new ban sync group
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_ban_sync_group(self).await
+    }
+
+    /// Find the membership of a given server
+    pub fn member(&self, server_id: &str) -> Option<&BanSyncMember> {
+        self.members
+            .iter()
+            .find(|member| member.server_id == server_id)
+    }
+
+    /// Add a server to this group, replacing any existing membership
+    pub async fn join(
+        &mut self,
+        db: &Database,
+        server_id: &str,
+        policy: BanSyncPolicy,
+    ) -> Result<()> {
+        self.members.retain(|member| member.server_id != server_id);
+        self.members.push(BanSyncMember {
+            server_id: server_id.to_string(),
+            policy,
+        });
+
+        db.update_ban_sync_group_members(&self.id, &self.members)
+            .await
+    }
+
+    /// Remove a server from this group, deleting the group once it is empty
+    pub async fn leave(&mut self, db: &Database, server_id: &str) -> Result<()> {
+        self.members.retain(|member| member.server_id != server_id);
+        db.delete_group_ban_sync_proposals(&self.id, server_id)
+            .await?;
+
+        if self.members.is_empty() {
+            db.delete_ban_sync_group(&self.id).await
+        } else {
+            db.update_ban_sync_group_members(&self.id, &self.members)
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BanSyncGroup, BanSyncPolicy};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let mut group = BanSyncGroup {
+                id: "group".to_string(),
+                name: "Allies".to_string(),
+                join_code: "code".to_string(),
+                members: vec![],
+            };
+
+            group.create(&db).await.unwrap();
+            group
+                .join(&db, "server", BanSyncPolicy::AutoApply)
+                .await
+                .unwrap();
+            group
+                .join(&db, "other", BanSyncPolicy::Propose)
+                .await
+                .unwrap();
+
+            let fetched = db.fetch_ban_sync_group("group").await.unwrap();
+            let fetched_code = db.fetch_ban_sync_group_by_code("code").await.unwrap();
+            let fetched_server = db.fetch_server_ban_sync_groups("other").await.unwrap();
+
+            assert_eq!(group, fetched);
+            assert_eq!(group, fetched_code);
+            assert_eq!(vec![group.clone()], fetched_server);
+            assert_eq!(
+                Some(&BanSyncPolicy::Propose),
+                fetched.member("other").map(|member| &member.policy)
+            );
+
+            group.leave(&db, "server").await.unwrap();
+            assert!(db
+                .fetch_server_ban_sync_groups("server")
+                .await
+                .unwrap()
+                .is_empty());
+
+            group.leave(&db, "other").await.unwrap();
+            assert!(db.fetch_ban_sync_group("group").await.is_err());
+        });
+    }
+}
@@ -0,0 +1,31 @@
+use revolt_result::Result;
+
+use crate::{BanSyncGroup, BanSyncMember};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractBanSyncGroups: Sync + Send {
+    /// Insert new ban sync group into the database
+    async fn insert_ban_sync_group(&self, group: &BanSyncGroup) -> Result<()>;
+
+    /// Fetch ban sync group by id
+    async fn fetch_ban_sync_group(&self, id: &str) -> Result<BanSyncGroup>;
+
+    /// Fetch ban sync group by its join code
+    async fn fetch_ban_sync_group_by_code(&self, code: &str) -> Result<BanSyncGroup>;
+
+    /// Fetch ban sync groups a server is taking part in
+    async fn fetch_server_ban_sync_groups(&self, server_id: &str) -> Result<Vec<BanSyncGroup>>;
+
+    /// Replace the members of a ban sync group
+    async fn update_ban_sync_group_members(
+        &self,
+        id: &str,
+        members: &[BanSyncMember],
+    ) -> Result<()>;
+
+    /// Delete ban sync group by id
+    async fn delete_ban_sync_group(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,76 @@
+use bson::{to_bson, Document};
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{BanSyncGroup, BanSyncMember};
+
+use super::AbstractBanSyncGroups;
+
+static COL: &str = "ban_sync_groups";
+
+#[async_trait]
+impl AbstractBanSyncGroups for MongoDb {
+    /// Insert new ban sync group into the database
+    async fn insert_ban_sync_group(&self, group: &BanSyncGroup) -> Result<()> {
+        query!(self, insert_one, COL, &group).map(|_| ())
+    }
+
+    /// Fetch ban sync group by id
+    async fn fetch_ban_sync_group(&self, id: &str) -> Result<BanSyncGroup> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync group by its join code
+    async fn fetch_ban_sync_group_by_code(&self, code: &str) -> Result<BanSyncGroup> {
+        query!(
+            self,
+            find_one,
+            COL,
+            doc! {
+                "join_code": code
+            }
+        )?
+        .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync groups a server is taking part in
+    async fn fetch_server_ban_sync_groups(&self, server_id: &str) -> Result<Vec<BanSyncGroup>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "members.server_id": server_id
+            }
+        )
+    }
+
+    /// Replace the members of a ban sync group
+    async fn update_ban_sync_group_members(
+        &self,
+        id: &str,
+        members: &[BanSyncMember],
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$set": {
+                        "members": to_bson(members)
+                            .map_err(|_| create_database_error!("to_bson", "members"))?
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Delete ban sync group by id
+    async fn delete_ban_sync_group(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,74 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{BanSyncGroup, BanSyncMember};
+
+use super::AbstractBanSyncGroups;
+
+#[async_trait]
+impl AbstractBanSyncGroups for ReferenceDb {
+    /// Insert new ban sync group into the database
+    async fn insert_ban_sync_group(&self, group: &BanSyncGroup) -> Result<()> {
+        let mut ban_sync_groups = self.ban_sync_groups.lock().await;
+        if ban_sync_groups.contains_key(&group.id) {
+            Err(create_database_error!("insert", "ban_sync_groups"))
+        } else {
+            ban_sync_groups.insert(group.id.to_string(), group.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch ban sync group by id
+    async fn fetch_ban_sync_group(&self, id: &str) -> Result<BanSyncGroup> {
+        let ban_sync_groups = self.ban_sync_groups.lock().await;
+        ban_sync_groups
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync group by its join code
+    async fn fetch_ban_sync_group_by_code(&self, code: &str) -> Result<BanSyncGroup> {
+        let ban_sync_groups = self.ban_sync_groups.lock().await;
+        ban_sync_groups
+            .values()
+            .find(|group| group.join_code == code)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync groups a server is taking part in
+    async fn fetch_server_ban_sync_groups(&self, server_id: &str) -> Result<Vec<BanSyncGroup>> {
+        let ban_sync_groups = self.ban_sync_groups.lock().await;
+        Ok(ban_sync_groups
+            .values()
+            .filter(|group| group.member(server_id).is_some())
+            .cloned()
+            .collect())
+    }
+
+    /// Replace the members of a ban sync group
+    async fn update_ban_sync_group_members(
+        &self,
+        id: &str,
+        members: &[BanSyncMember],
+    ) -> Result<()> {
+        let mut ban_sync_groups = self.ban_sync_groups.lock().await;
+        if let Some(group) = ban_sync_groups.get_mut(id) {
+            group.members = members.to_vec();
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete ban sync group by id
+    async fn delete_ban_sync_group(&self, id: &str) -> Result<()> {
+        let mut ban_sync_groups = self.ban_sync_groups.lock().await;
+        if ban_sync_groups.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
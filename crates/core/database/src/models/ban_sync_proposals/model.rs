@@ -0,0 +1,82 @@
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Ban shared by another server in a ban sync group, awaiting review
+    pub struct BanSyncProposal {
+        /// Proposal Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Ban sync group the ban was shared through
+        pub group_id: String,
+        /// Server the ban was originally applied in
+        pub source_server_id: String,
+        /// Server the ban is proposed to
+        pub server_id: String,
+        /// Id of the user to ban
+        pub user_id: String,
+        /// Reason given for the original ban
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl BanSyncProposal {
+    /// Create a new ban sync proposal
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_ban_sync_proposal(self).await
+    }
+
+    /// Delete this proposal
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_ban_sync_proposal(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BanSyncProposal;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let proposal = BanSyncProposal {
+                id: "proposal".to_string(),
+                group_id: "group".to_string(),
+                source_server_id: "source".to_string(),
+                server_id: "server".to_string(),
+                user_id: "user".to_string(),
+                reason: Some("Raid".to_string()),
+            };
+
+            let other = BanSyncProposal {
+                id: "other".to_string(),
+                group_id: "other_group".to_string(),
+                ..proposal.clone()
+            };
+
+            proposal.create(&db).await.unwrap();
+            other.create(&db).await.unwrap();
+
+            let fetched = db.fetch_ban_sync_proposal("proposal").await.unwrap();
+            let fetched_all = db.fetch_ban_sync_proposals("server").await.unwrap();
+
+            assert_eq!(proposal, fetched);
+            assert_eq!(2, fetched_all.len());
+
+            proposal.delete(&db).await.unwrap();
+            assert!(db.fetch_ban_sync_proposal("proposal").await.is_err());
+
+            db.delete_group_ban_sync_proposals("other_group", "server")
+                .await
+                .unwrap();
+            assert!(db
+                .fetch_ban_sync_proposals("server")
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+}
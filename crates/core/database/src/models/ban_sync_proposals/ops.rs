@@ -0,0 +1,24 @@
+use revolt_result::Result;
+
+use crate::BanSyncProposal;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractBanSyncProposals: Sync + Send {
+    /// Insert new ban sync proposal into the database
+    async fn insert_ban_sync_proposal(&self, proposal: &BanSyncProposal) -> Result<()>;
+
+    /// Fetch ban sync proposal by id
+    async fn fetch_ban_sync_proposal(&self, id: &str) -> Result<BanSyncProposal>;
+
+    /// Fetch ban sync proposals awaiting review in a server
+    async fn fetch_ban_sync_proposals(&self, server_id: &str) -> Result<Vec<BanSyncProposal>>;
+
+    /// Delete ban sync proposal by id
+    async fn delete_ban_sync_proposal(&self, id: &str) -> Result<()>;
+
+    /// Delete every proposal made to a server through a given group
+    async fn delete_group_ban_sync_proposals(&self, group_id: &str, server_id: &str) -> Result<()>;
+}
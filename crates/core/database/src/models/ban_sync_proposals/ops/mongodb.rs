@@ -0,0 +1,54 @@
+use bson::Document;
+use revolt_result::Result;
+
+use crate::BanSyncProposal;
+use crate::MongoDb;
+
+use super::AbstractBanSyncProposals;
+
+static COL: &str = "ban_sync_proposals";
+
+#[async_trait]
+impl AbstractBanSyncProposals for MongoDb {
+    /// Insert new ban sync proposal into the database
+    async fn insert_ban_sync_proposal(&self, proposal: &BanSyncProposal) -> Result<()> {
+        query!(self, insert_one, COL, &proposal).map(|_| ())
+    }
+
+    /// Fetch ban sync proposal by id
+    async fn fetch_ban_sync_proposal(&self, id: &str) -> Result<BanSyncProposal> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync proposals awaiting review in a server
+    async fn fetch_ban_sync_proposals(&self, server_id: &str) -> Result<Vec<BanSyncProposal>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server_id": server_id
+            }
+        )
+    }
+
+    /// Delete ban sync proposal by id
+    async fn delete_ban_sync_proposal(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Delete every proposal made to a server through a given group
+    async fn delete_group_ban_sync_proposals(&self, group_id: &str, server_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .delete_many(
+                doc! {
+                    "group_id": group_id,
+                    "server_id": server_id
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
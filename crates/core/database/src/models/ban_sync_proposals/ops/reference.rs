@@ -0,0 +1,57 @@
+use revolt_result::Result;
+
+use crate::BanSyncProposal;
+use crate::ReferenceDb;
+
+use super::AbstractBanSyncProposals;
+
+#[async_trait]
+impl AbstractBanSyncProposals for ReferenceDb {
+    /// Insert new ban sync proposal into the database
+    async fn insert_ban_sync_proposal(&self, proposal: &BanSyncProposal) -> Result<()> {
+        let mut ban_sync_proposals = self.ban_sync_proposals.lock().await;
+        if ban_sync_proposals.contains_key(&proposal.id) {
+            Err(create_database_error!("insert", "ban_sync_proposals"))
+        } else {
+            ban_sync_proposals.insert(proposal.id.to_string(), proposal.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch ban sync proposal by id
+    async fn fetch_ban_sync_proposal(&self, id: &str) -> Result<BanSyncProposal> {
+        let ban_sync_proposals = self.ban_sync_proposals.lock().await;
+        ban_sync_proposals
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch ban sync proposals awaiting review in a server
+    async fn fetch_ban_sync_proposals(&self, server_id: &str) -> Result<Vec<BanSyncProposal>> {
+        let ban_sync_proposals = self.ban_sync_proposals.lock().await;
+        Ok(ban_sync_proposals
+            .values()
+            .filter(|proposal| proposal.server_id == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Delete ban sync proposal by id
+    async fn delete_ban_sync_proposal(&self, id: &str) -> Result<()> {
+        let mut ban_sync_proposals = self.ban_sync_proposals.lock().await;
+        if ban_sync_proposals.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete every proposal made to a server through a given group
+    async fn delete_group_ban_sync_proposals(&self, group_id: &str, server_id: &str) -> Result<()> {
+        let mut ban_sync_proposals = self.ban_sync_proposals.lock().await;
+        ban_sync_proposals
+            .retain(|_, proposal| proposal.group_id != group_id || proposal.server_id != server_id);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use revolt_models::v0::BotFlagsValue;
 use revolt_result::Result;
 
 use crate::Database;
@@ -36,7 +37,7 @@ auto_derived_partial!(
 
         /// Enum of bot flags
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub flags: Option<i32>,
+        pub flags: Option<BotFlagsValue>,
     },
     "PartialBot"
 );
@@ -91,6 +92,8 @@ impl Bot {
 
 #[cfg(test)]
 mod tests {
+    use revolt_models::v0::{BotFlags, UserFlags};
+
     use crate::{Bot, FieldsBot, PartialBot, User};
 
     #[async_std::test]
@@ -148,7 +151,41 @@ mod tests {
             bot.delete(&db).await.unwrap();
             assert!(db.fetch_bot(bot_id).await.is_err());
             assert_eq!(0, db.get_number_of_bots_by_user(user_id).await.unwrap());
-            assert_eq!(db.fetch_user(bot_id).await.unwrap().flags, Some(2))
+            assert_eq!(
+                db.fetch_user(bot_id).await.unwrap().flags,
+                Some(UserFlags::Deleted.into())
+            )
         });
     }
+
+    #[test]
+    fn partial_helpers() {
+        let bot = Bot {
+            id: "bot".to_string(),
+            owner: "user".to_string(),
+            token: "token".to_string(),
+            flags: Some(BotFlags::Verified.into()),
+            ..Default::default()
+        };
+
+        let mut edited = bot.clone();
+        edited.public = true;
+        edited.token = "new_token".to_string();
+
+        let partial = bot.diff(&edited);
+        assert_eq!(
+            partial,
+            PartialBot::default()
+                .with_public(true)
+                .with_token("new_token".to_string())
+        );
+
+        let mut applied = bot.clone();
+        partial.apply_to(&mut applied);
+        assert_eq!(applied, edited);
+
+        // Cleared fields can't be expressed by a partial
+        edited.flags = None;
+        assert_eq!(bot.diff(&edited).flags, None);
+    }
 }
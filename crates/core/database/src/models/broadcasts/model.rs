@@ -0,0 +1,122 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Announcement sent to users by the instance administrators
+    pub struct Broadcast {
+        /// Broadcast Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Administrator who created this broadcast
+        pub author_id: String,
+
+        /// Content of the broadcast
+        pub content: String,
+        /// Users who receive this broadcast
+        pub target: BroadcastTarget,
+        /// Whether to also send the broadcast as a direct message from the system user
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub send_dm: bool,
+        /// Time at which the broadcast should be sent
+        pub send_at: Timestamp,
+        /// Whether the broadcast has been sent
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub sent: bool,
+    }
+
+    /// Users who receive a broadcast
+    #[serde(tag = "type")]
+    pub enum BroadcastTarget {
+        /// Every user on the instance
+        All,
+        /// Members of the given servers
+        Servers { ids: Vec<String> },
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl Broadcast {
+    /// Create a new broadcast
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_broadcast(self).await
+    }
+
+    /// Check whether this broadcast should be sent
+    pub fn is_due(&self) -> bool {
+        !self.sent && *self.send_at <= *Timestamp::now_utc()
+    }
+
+    /// Mark this broadcast as sent
+    ///
+    /// Returns false if another node already marked it, in which case it
+    /// must not be delivered again.
+    pub async fn mark_sent(&mut self, db: &Database) -> Result<bool> {
+        let claimed = db.mark_broadcast_sent(&self.id).await?;
+        self.sent = true;
+        Ok(claimed)
+    }
+
+    /// Delete this broadcast
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_broadcast(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{Broadcast, BroadcastTarget};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let now = Broadcast {
+                id: "now".to_string(),
+                author_id: "admin".to_string(),
+                content: "Maintenance tonight".to_string(),
+                target: BroadcastTarget::All,
+                send_dm: false,
+                send_at: Timestamp::now_utc(),
+                sent: false,
+            };
+
+            let later = Broadcast {
+                id: "later".to_string(),
+                target: BroadcastTarget::Servers {
+                    ids: vec!["server".to_string()],
+                },
+                send_at: Timestamp::now_utc()
+                    .checked_add(Duration::hours(1))
+                    .unwrap(),
+                ..now.clone()
+            };
+
+            now.create(&db).await.unwrap();
+            later.create(&db).await.unwrap();
+            assert!(now.is_due());
+            assert!(!later.is_due());
+
+            let due = db.fetch_due_broadcasts(Timestamp::now_utc()).await.unwrap();
+
+            assert_eq!(vec![now.clone()], due);
+            assert_eq!(2, db.fetch_broadcasts().await.unwrap().len());
+
+            let mut now = due.into_iter().next().unwrap();
+            assert!(now.mark_sent(&db).await.unwrap());
+            assert!(!now.is_due());
+            assert!(!now.clone().mark_sent(&db).await.unwrap());
+            assert_eq!(now, db.fetch_broadcast("now").await.unwrap());
+            assert!(db
+                .fetch_due_broadcasts(Timestamp::now_utc())
+                .await
+                .unwrap()
+                .is_empty());
+
+            later.delete(&db).await.unwrap();
+            assert!(db.fetch_broadcast("later").await.is_err());
+        });
+    }
+}
@@ -0,0 +1,28 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Broadcast;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractBroadcasts: Sync + Send {
+    /// Insert new broadcast into the database
+    async fn insert_broadcast(&self, broadcast: &Broadcast) -> Result<()>;
+
+    /// Fetch broadcast by id
+    async fn fetch_broadcast(&self, id: &str) -> Result<Broadcast>;
+
+    /// Fetch all broadcasts, newest first
+    async fn fetch_broadcasts(&self) -> Result<Vec<Broadcast>>;
+
+    /// Fetch unsent broadcasts due at or before the given time
+    async fn fetch_due_broadcasts(&self, before: Timestamp) -> Result<Vec<Broadcast>>;
+
+    /// Mark a broadcast as sent, returning whether it was unsent before this call
+    async fn mark_broadcast_sent(&self, id: &str) -> Result<bool>;
+
+    /// Delete broadcast by id
+    async fn delete_broadcast(&self, id: &str) -> Result<()>;
+}
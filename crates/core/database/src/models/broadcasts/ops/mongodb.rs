@@ -0,0 +1,79 @@
+use ::mongodb::options::FindOptions;
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Broadcast;
+use crate::MongoDb;
+
+use super::AbstractBroadcasts;
+
+static COL: &str = "broadcasts";
+
+#[async_trait]
+impl AbstractBroadcasts for MongoDb {
+    /// Insert new broadcast into the database
+    async fn insert_broadcast(&self, broadcast: &Broadcast) -> Result<()> {
+        query!(self, insert_one, COL, &broadcast).map(|_| ())
+    }
+
+    /// Fetch broadcast by id
+    async fn fetch_broadcast(&self, id: &str) -> Result<Broadcast> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all broadcasts, newest first
+    async fn fetch_broadcasts(&self) -> Result<Vec<Broadcast>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            doc! {},
+            FindOptions::builder().sort(doc! { "_id": -1_i32 }).build()
+        )
+    }
+
+    /// Fetch unsent broadcasts due at or before the given time
+    async fn fetch_due_broadcasts(&self, before: Timestamp) -> Result<Vec<Broadcast>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "sent": {
+                    "$ne": true
+                },
+                "send_at": {
+                    "$lte": to_bson(&before).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Mark a broadcast as sent, returning whether it was unsent before this call
+    async fn mark_broadcast_sent(&self, id: &str) -> Result<bool> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id,
+                    "sent": {
+                        "$ne": true
+                    }
+                },
+                doc! {
+                    "$set": {
+                        "sent": true
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|result| result.modified_count == 1)
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Delete broadcast by id
+    async fn delete_broadcast(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,68 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Broadcast;
+use crate::ReferenceDb;
+
+use super::AbstractBroadcasts;
+
+#[async_trait]
+impl AbstractBroadcasts for ReferenceDb {
+    /// Insert new broadcast into the database
+    async fn insert_broadcast(&self, broadcast: &Broadcast) -> Result<()> {
+        let mut broadcasts = self.broadcasts.lock().await;
+        if broadcasts.contains_key(&broadcast.id) {
+            Err(create_database_error!("insert", "broadcasts"))
+        } else {
+            broadcasts.insert(broadcast.id.to_string(), broadcast.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch broadcast by id
+    async fn fetch_broadcast(&self, id: &str) -> Result<Broadcast> {
+        let broadcasts = self.broadcasts.lock().await;
+        broadcasts
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all broadcasts, newest first
+    async fn fetch_broadcasts(&self) -> Result<Vec<Broadcast>> {
+        let broadcasts = self.broadcasts.lock().await;
+        let mut broadcasts: Vec<Broadcast> = broadcasts.values().cloned().collect();
+        broadcasts.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(broadcasts)
+    }
+
+    /// Fetch unsent broadcasts due at or before the given time
+    async fn fetch_due_broadcasts(&self, before: Timestamp) -> Result<Vec<Broadcast>> {
+        let broadcasts = self.broadcasts.lock().await;
+        Ok(broadcasts
+            .values()
+            .filter(|broadcast| !broadcast.sent && *broadcast.send_at <= *before)
+            .cloned()
+            .collect())
+    }
+
+    /// Mark a broadcast as sent, returning whether it was unsent before this call
+    async fn mark_broadcast_sent(&self, id: &str) -> Result<bool> {
+        let mut broadcasts = self.broadcasts.lock().await;
+        if let Some(broadcast) = broadcasts.get_mut(id) {
+            Ok(!std::mem::replace(&mut broadcast.sent, true))
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete broadcast by id
+    async fn delete_broadcast(&self, id: &str) -> Result<()> {
+        let mut broadcasts = self.broadcasts.lock().await;
+        if broadcasts.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
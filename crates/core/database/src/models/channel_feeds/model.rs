@@ -0,0 +1,205 @@
+use iso8601_timestamp::{Duration, Timestamp};
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived_partial!(
+    /// RSS / Atom feed subscription for a channel
+    pub struct ChannelFeed {
+        /// Feed Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// The channel new entries are posted to
+        pub channel_id: String,
+        /// The webhook used to post new entries
+        pub webhook_id: String,
+        /// The user who created this subscription
+        pub creator_id: String,
+
+        /// URL of the RSS or Atom feed
+        pub url: String,
+        /// How often the feed should be polled, in minutes
+        pub interval: u32,
+        /// Only post entries whose title or summary contains this text
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub filter: Option<String>,
+
+        /// Id of the most recent entry that was seen
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_entry_id: Option<String>,
+        /// Time at which the feed was last polled
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_polled_at: Option<Timestamp>,
+    },
+    "PartialChannelFeed"
+);
+
+auto_derived!(
+    /// Optional fields on channel feed object
+    pub enum FieldsChannelFeed {
+        Filter,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ChannelFeed {
+    /// Create a new feed subscription
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_channel_feed(self).await
+    }
+
+    /// Check whether this feed should be polled
+    pub fn is_due(&self) -> bool {
+        match &self.last_polled_at {
+            Some(last_polled_at) => last_polled_at
+                .checked_add(Duration::minutes(self.interval as i64))
+                .map_or(true, |due| *Timestamp::now_utc() >= *due),
+            None => true,
+        }
+    }
+
+    /// Claim this feed for polling
+    ///
+    /// Returns false if another worker has polled it since it was fetched.
+    pub async fn claim(&mut self, db: &Database) -> Result<bool> {
+        let now = Timestamp::now_utc();
+        let claimed = db
+            .claim_channel_feed(&self.id, self.last_polled_at.as_ref(), now)
+            .await?;
+
+        if claimed {
+            self.last_polled_at = Some(now);
+        }
+
+        Ok(claimed)
+    }
+
+    /// Record the most recent entry seen while polling
+    pub async fn mark_polled(
+        &mut self,
+        db: &Database,
+        last_entry_id: Option<String>,
+    ) -> Result<()> {
+        self.update(
+            db,
+            PartialChannelFeed {
+                last_entry_id,
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
+    /// Update this feed
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialChannelFeed,
+        remove: Vec<FieldsChannelFeed>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field);
+        }
+
+        self.apply_options(partial.clone());
+        db.update_channel_feed(&self.id, &partial, &remove).await
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsChannelFeed) {
+        match field {
+            FieldsChannelFeed::Filter => self.filter = None,
+        }
+    }
+
+    /// Delete this feed and the webhook it posts through
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_channel_feed(&self.id).await?;
+
+        if let Ok(webhook) = db.fetch_webhook(&self.webhook_id).await {
+            webhook.delete(db).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChannelFeed, FieldsChannelFeed, PartialChannelFeed};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let feed_id = "feed";
+            let channel_id = "channel";
+
+            let feed = ChannelFeed {
+                id: feed_id.to_string(),
+                channel_id: channel_id.to_string(),
+                webhook_id: "webhook".to_string(),
+                creator_id: "user".to_string(),
+                url: "https://example.com/feed.xml".to_string(),
+                interval: 30,
+                filter: Some("release".to_string()),
+                ..Default::default()
+            };
+
+            feed.create(&db).await.unwrap();
+            assert!(feed.is_due());
+
+            let mut updated_feed = feed.clone();
+            updated_feed
+                .update(
+                    &db,
+                    PartialChannelFeed {
+                        interval: Some(60),
+                        last_entry_id: Some("entry".to_string()),
+                        ..Default::default()
+                    },
+                    vec![FieldsChannelFeed::Filter],
+                )
+                .await
+                .unwrap();
+
+            let fetched_feed = db.fetch_channel_feed(feed_id).await.unwrap();
+            let fetched_feeds = db.fetch_channel_feeds(channel_id).await.unwrap();
+            let all_feeds = db.fetch_all_channel_feeds().await.unwrap();
+
+            assert_eq!(updated_feed, fetched_feed);
+            assert_ne!(feed, fetched_feed);
+            assert!(fetched_feed.filter.is_none());
+            assert_eq!(1, fetched_feeds.len());
+            assert_eq!(fetched_feeds, all_feeds);
+
+            feed.delete(&db).await.unwrap();
+            assert!(db.fetch_channel_feed(feed_id).await.is_err());
+            assert_eq!(0, db.fetch_channel_feeds(channel_id).await.unwrap().len());
+        });
+    }
+
+    #[async_std::test]
+    async fn claim() {
+        database_test!(|db| async move {
+            let feed = ChannelFeed {
+                id: "feed".to_string(),
+                channel_id: "channel".to_string(),
+                webhook_id: "webhook".to_string(),
+                creator_id: "user".to_string(),
+                url: "https://example.com/feed.xml".to_string(),
+                interval: 30,
+                ..Default::default()
+            };
+
+            feed.create(&db).await.unwrap();
+
+            let mut first = feed.clone();
+            let mut second = feed.clone();
+            assert!(first.claim(&db).await.unwrap());
+            assert!(!second.claim(&db).await.unwrap());
+            assert!(!first.is_due());
+            assert!(!db.fetch_channel_feed(&feed.id).await.unwrap().is_due());
+        });
+    }
+}
@@ -0,0 +1,43 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{ChannelFeed, FieldsChannelFeed, PartialChannelFeed};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelFeeds: Sync + Send {
+    /// Insert new feed into the database
+    async fn insert_channel_feed(&self, feed: &ChannelFeed) -> Result<()>;
+
+    /// Fetch feed by id
+    async fn fetch_channel_feed(&self, feed_id: &str) -> Result<ChannelFeed>;
+
+    /// Fetch feeds for channel
+    async fn fetch_channel_feeds(&self, channel_id: &str) -> Result<Vec<ChannelFeed>>;
+
+    /// Fetch all feeds
+    async fn fetch_all_channel_feeds(&self) -> Result<Vec<ChannelFeed>>;
+
+    /// Mark feed as polled at the given time, if it hasn't been polled since `last_polled_at`
+    ///
+    /// Returns whether this caller claimed the feed.
+    async fn claim_channel_feed(
+        &self,
+        feed_id: &str,
+        last_polled_at: Option<&Timestamp>,
+        now: Timestamp,
+    ) -> Result<bool>;
+
+    /// Update feed with new information
+    async fn update_channel_feed(
+        &self,
+        feed_id: &str,
+        partial: &PartialChannelFeed,
+        remove: &[FieldsChannelFeed],
+    ) -> Result<()>;
+
+    /// Delete feed by id
+    async fn delete_channel_feed(&self, feed_id: &str) -> Result<()>;
+}
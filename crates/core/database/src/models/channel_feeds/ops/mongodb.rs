@@ -0,0 +1,124 @@
+use bson::{to_bson, Document};
+use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{ChannelFeed, FieldsChannelFeed, PartialChannelFeed};
+use crate::{IntoDocumentPath, MongoDb};
+
+use super::AbstractChannelFeeds;
+
+static COL: &str = "channel_feeds";
+
+impl MongoDb {
+    /// Find feeds matching the given filter
+    async fn find_channel_feeds(&self, filter: Document) -> Result<Vec<ChannelFeed>> {
+        Ok(self
+            .col::<ChannelFeed>(COL)
+            .find(filter, None)
+            .await
+            .map_err(|_| create_database_error!("find", COL))?
+            .filter_map(|s| async {
+                if cfg!(debug_assertions) {
+                    Some(s.unwrap())
+                } else {
+                    s.ok()
+                }
+            })
+            .collect()
+            .await)
+    }
+}
+
+#[async_trait]
+impl AbstractChannelFeeds for MongoDb {
+    /// Insert new feed into the database
+    async fn insert_channel_feed(&self, feed: &ChannelFeed) -> Result<()> {
+        query!(self, insert_one, COL, &feed).map(|_| ())
+    }
+
+    /// Fetch feed by id
+    async fn fetch_channel_feed(&self, feed_id: &str) -> Result<ChannelFeed> {
+        query!(self, find_one_by_id, COL, feed_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch feeds for channel
+    async fn fetch_channel_feeds(&self, channel_id: &str) -> Result<Vec<ChannelFeed>> {
+        self.find_channel_feeds(doc! {
+            "channel_id": channel_id,
+        })
+        .await
+    }
+
+    /// Fetch all feeds
+    async fn fetch_all_channel_feeds(&self) -> Result<Vec<ChannelFeed>> {
+        self.find_channel_feeds(doc! {}).await
+    }
+
+    /// Mark feed as polled at the given time, if it hasn't been polled since `last_polled_at`
+    async fn claim_channel_feed(
+        &self,
+        feed_id: &str,
+        last_polled_at: Option<&Timestamp>,
+        now: Timestamp,
+    ) -> Result<bool> {
+        let filter = match last_polled_at {
+            Some(last_polled_at) => doc! {
+                "_id": feed_id,
+                "last_polled_at": to_bson(last_polled_at).unwrap()
+            },
+            None => doc! {
+                "_id": feed_id,
+                "last_polled_at": {
+                    "$exists": false
+                }
+            },
+        };
+
+        self.col::<ChannelFeed>(COL)
+            .find_one_and_update(
+                filter,
+                doc! {
+                    "$set": {
+                        "last_polled_at": to_bson(&now).unwrap()
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|feed| feed.is_some())
+            .map_err(|_| create_database_error!("find_one_and_update", COL))
+    }
+
+    /// Update feed with new information
+    async fn update_channel_feed(
+        &self,
+        feed_id: &str,
+        partial: &PartialChannelFeed,
+        remove: &[FieldsChannelFeed],
+    ) -> Result<()> {
+        query!(
+            self,
+            update_one_by_id,
+            COL,
+            feed_id,
+            partial,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None
+        )
+        .map(|_| ())
+    }
+
+    /// Delete feed by id
+    async fn delete_channel_feed(&self, feed_id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, feed_id).map(|_| ())
+    }
+}
+
+impl IntoDocumentPath for FieldsChannelFeed {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsChannelFeed::Filter => "filter",
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{ChannelFeed, FieldsChannelFeed, PartialChannelFeed};
+
+use super::AbstractChannelFeeds;
+
+#[async_trait]
+impl AbstractChannelFeeds for ReferenceDb {
+    /// Insert new feed into the database
+    async fn insert_channel_feed(&self, feed: &ChannelFeed) -> Result<()> {
+        let mut feeds = self.channel_feeds.lock().await;
+        if feeds.contains_key(&feed.id) {
+            Err(create_database_error!("insert", "channel_feeds"))
+        } else {
+            feeds.insert(feed.id.to_string(), feed.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch feed by id
+    async fn fetch_channel_feed(&self, feed_id: &str) -> Result<ChannelFeed> {
+        let feeds = self.channel_feeds.lock().await;
+        feeds
+            .get(feed_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch feeds for channel
+    async fn fetch_channel_feeds(&self, channel_id: &str) -> Result<Vec<ChannelFeed>> {
+        let feeds = self.channel_feeds.lock().await;
+        Ok(feeds
+            .values()
+            .filter(|feed| feed.channel_id == channel_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all feeds
+    async fn fetch_all_channel_feeds(&self) -> Result<Vec<ChannelFeed>> {
+        let feeds = self.channel_feeds.lock().await;
+        Ok(feeds.values().cloned().collect())
+    }
+
+    /// Mark feed as polled at the given time, if it hasn't been polled since `last_polled_at`
+    async fn claim_channel_feed(
+        &self,
+        feed_id: &str,
+        last_polled_at: Option<&Timestamp>,
+        now: Timestamp,
+    ) -> Result<bool> {
+        let mut feeds = self.channel_feeds.lock().await;
+        let feed = feeds
+            .get_mut(feed_id)
+            .ok_or_else(|| create_error!(NotFound))?;
+
+        if feed.last_polled_at.as_ref() != last_polled_at {
+            return Ok(false);
+        }
+
+        feed.last_polled_at = Some(now);
+        Ok(true)
+    }
+
+    /// Update feed with new information
+    async fn update_channel_feed(
+        &self,
+        feed_id: &str,
+        partial: &PartialChannelFeed,
+        remove: &[FieldsChannelFeed],
+    ) -> Result<()> {
+        let mut feeds = self.channel_feeds.lock().await;
+        if let Some(feed) = feeds.get_mut(feed_id) {
+            for field in remove {
+                #[allow(clippy::disallowed_methods)]
+                feed.remove_field(field);
+            }
+
+            feed.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete feed by id
+    async fn delete_channel_feed(&self, feed_id: &str) -> Result<()> {
+        let mut feeds = self.channel_feeds.lock().await;
+        if feeds.remove(feed_id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Inbound email address assigned to a channel
+    pub struct ChannelInbox {
+        /// Inbox Id, used as the local part of the address
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Full email address which delivers to this inbox
+        pub address: String,
+
+        /// The channel this inbox delivers to
+        pub channel_id: String,
+        /// The webhook used to post received mail
+        pub webhook_id: String,
+        /// The user who assigned this address
+        pub creator_id: String,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ChannelInbox {
+    /// Create a new inbox for a channel on the given domain
+    pub async fn create(
+        db: &Database,
+        domain: &str,
+        channel_id: String,
+        webhook_id: String,
+        creator_id: String,
+    ) -> Result<ChannelInbox> {
+        let id = ulid::Ulid::new().to_string().to_lowercase();
+        let inbox = ChannelInbox {
+            address: format!("{id}@{domain}"),
+            id,
+            channel_id,
+            webhook_id,
+            creator_id,
+        };
+
+        db.insert_channel_inbox(&inbox).await?;
+        Ok(inbox)
+    }
+
+    /// Delete this inbox and the webhook it posts through
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_channel_inbox(&self.id).await?;
+
+        if let Ok(webhook) = db.fetch_webhook(&self.webhook_id).await {
+            webhook.delete(db).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChannelInbox;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let channel_id = "channel";
+
+            let inbox = ChannelInbox::create(
+                &db,
+                "example.com",
+                channel_id.to_string(),
+                "webhook".to_string(),
+                "user".to_string(),
+            )
+            .await
+            .unwrap();
+
+            assert!(inbox.address.ends_with("@example.com"));
+            assert!(inbox.address.starts_with(&inbox.id));
+
+            let fetched_inbox = db.fetch_channel_inbox(&inbox.id).await.unwrap();
+            let fetched_by_channel = db.fetch_channel_inbox_by_channel(channel_id).await.unwrap();
+
+            assert_eq!(inbox, fetched_inbox);
+            assert_eq!(inbox, fetched_by_channel);
+
+            inbox.delete(&db).await.unwrap();
+            assert!(db.fetch_channel_inbox(&inbox.id).await.is_err());
+            assert!(db.fetch_channel_inbox_by_channel(channel_id).await.is_err());
+        });
+    }
+}
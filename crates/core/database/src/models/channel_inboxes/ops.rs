@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::ChannelInbox;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelInboxes: Sync + Send {
+    /// Insert new inbox into the database
+    async fn insert_channel_inbox(&self, inbox: &ChannelInbox) -> Result<()>;
+
+    /// Fetch inbox by id
+    async fn fetch_channel_inbox(&self, inbox_id: &str) -> Result<ChannelInbox>;
+
+    /// Fetch inbox assigned to a channel
+    async fn fetch_channel_inbox_by_channel(&self, channel_id: &str) -> Result<ChannelInbox>;
+
+    /// Delete inbox by id
+    async fn delete_channel_inbox(&self, inbox_id: &str) -> Result<()>;
+}
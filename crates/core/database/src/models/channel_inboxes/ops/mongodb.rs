@@ -0,0 +1,39 @@
+use revolt_result::Result;
+
+use crate::ChannelInbox;
+use crate::MongoDb;
+
+use super::AbstractChannelInboxes;
+
+static COL: &str = "channel_inboxes";
+
+#[async_trait]
+impl AbstractChannelInboxes for MongoDb {
+    /// Insert new inbox into the database
+    async fn insert_channel_inbox(&self, inbox: &ChannelInbox) -> Result<()> {
+        query!(self, insert_one, COL, &inbox).map(|_| ())
+    }
+
+    /// Fetch inbox by id
+    async fn fetch_channel_inbox(&self, inbox_id: &str) -> Result<ChannelInbox> {
+        query!(self, find_one_by_id, COL, inbox_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch inbox assigned to a channel
+    async fn fetch_channel_inbox_by_channel(&self, channel_id: &str) -> Result<ChannelInbox> {
+        query!(
+            self,
+            find_one,
+            COL,
+            doc! {
+                "channel_id": channel_id
+            }
+        )?
+        .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Delete inbox by id
+    async fn delete_channel_inbox(&self, inbox_id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, inbox_id).map(|_| ())
+    }
+}
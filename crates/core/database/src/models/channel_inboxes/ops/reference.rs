@@ -0,0 +1,49 @@
+use revolt_result::Result;
+
+use crate::ChannelInbox;
+use crate::ReferenceDb;
+
+use super::AbstractChannelInboxes;
+
+#[async_trait]
+impl AbstractChannelInboxes for ReferenceDb {
+    /// Insert new inbox into the database
+    async fn insert_channel_inbox(&self, inbox: &ChannelInbox) -> Result<()> {
+        let mut inboxes = self.channel_inboxes.lock().await;
+        if inboxes.contains_key(&inbox.id) {
+            Err(create_database_error!("insert", "channel_inboxes"))
+        } else {
+            inboxes.insert(inbox.id.to_string(), inbox.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch inbox by id
+    async fn fetch_channel_inbox(&self, inbox_id: &str) -> Result<ChannelInbox> {
+        let inboxes = self.channel_inboxes.lock().await;
+        inboxes
+            .get(inbox_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch inbox assigned to a channel
+    async fn fetch_channel_inbox_by_channel(&self, channel_id: &str) -> Result<ChannelInbox> {
+        let inboxes = self.channel_inboxes.lock().await;
+        inboxes
+            .values()
+            .find(|inbox| inbox.channel_id == channel_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Delete inbox by id
+    async fn delete_channel_inbox(&self, inbox_id: &str) -> Result<()> {
+        let mut inboxes = self.channel_inboxes.lock().await;
+        if inboxes.remove(inbox_id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
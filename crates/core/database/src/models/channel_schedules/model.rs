@@ -0,0 +1,141 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Scheduled change to a channel's settings
+    pub struct ChannelSchedule {
+        /// Schedule Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// The channel to change
+        pub channel_id: String,
+        /// The user who scheduled this change
+        pub creator_id: String,
+
+        /// Time at which the change should be applied
+        pub run_at: Timestamp,
+        /// Settings to apply
+        pub changes: ChannelScheduleChanges,
+    }
+
+    /// Channel settings applied by a schedule
+    #[derive(Default)]
+    pub struct ChannelScheduleChanges {
+        /// Channel name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        /// Channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Whether this channel is age-restricted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+        /// Whether this text channel is locked
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub locked: Option<bool>,
+        /// Whether every message in this text channel must contain an attachment or link
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub media_only: Option<bool>,
+        /// Slowmode for this text channel in seconds, zero turns it off
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub slowmode: Option<u32>,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ChannelSchedule {
+    /// Create a new scheduled change
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_channel_schedule(self).await
+    }
+
+    /// Check whether this change should be applied
+    pub fn is_due(&self) -> bool {
+        *self.run_at <= *Timestamp::now_utc()
+    }
+
+    /// Delete this scheduled change
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_channel_schedule(&self.id).await
+    }
+
+    /// Take this change off the schedule so that only one node applies it
+    ///
+    /// Returns false if another node already claimed it.
+    pub async fn claim(&self, db: &Database) -> Result<bool> {
+        db.claim_channel_schedule(&self.id).await
+    }
+}
+
+impl ChannelScheduleChanges {
+    /// Check whether no settings would be changed
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.nsfw.is_none()
+            && self.locked.is_none()
+            && self.media_only.is_none()
+            && self.slowmode.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{ChannelSchedule, ChannelScheduleChanges};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let channel_id = "channel";
+
+            let due = ChannelSchedule {
+                id: "due".to_string(),
+                channel_id: channel_id.to_string(),
+                creator_id: "user".to_string(),
+                run_at: Timestamp::now_utc(),
+                changes: ChannelScheduleChanges {
+                    locked: Some(true),
+                    ..Default::default()
+                },
+            };
+
+            let later = ChannelSchedule {
+                id: "later".to_string(),
+                run_at: Timestamp::now_utc()
+                    .checked_add(Duration::hours(1))
+                    .unwrap(),
+                ..due.clone()
+            };
+
+            due.create(&db).await.unwrap();
+            later.create(&db).await.unwrap();
+            assert!(due.is_due());
+            assert!(!later.is_due());
+
+            let fetched = db.fetch_channel_schedule("due").await.unwrap();
+            let fetched_all = db.fetch_channel_schedules(channel_id).await.unwrap();
+            let fetched_due = db
+                .fetch_due_channel_schedules(Timestamp::now_utc())
+                .await
+                .unwrap();
+
+            assert_eq!(due, fetched);
+            assert_eq!(2, fetched_all.len());
+            assert_eq!(vec![due.clone()], fetched_due);
+
+            due.delete(&db).await.unwrap();
+            assert!(db.fetch_channel_schedule("due").await.is_err());
+            assert_eq!(
+                1,
+                db.fetch_channel_schedules(channel_id).await.unwrap().len()
+            );
+
+            assert!(later.claim(&db).await.unwrap());
+            assert!(!later.claim(&db).await.unwrap());
+        });
+    }
+}
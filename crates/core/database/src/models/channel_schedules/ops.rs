@@ -0,0 +1,28 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ChannelSchedule;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelSchedules: Sync + Send {
+    /// Insert new scheduled change into the database
+    async fn insert_channel_schedule(&self, schedule: &ChannelSchedule) -> Result<()>;
+
+    /// Fetch scheduled change by id
+    async fn fetch_channel_schedule(&self, id: &str) -> Result<ChannelSchedule>;
+
+    /// Fetch scheduled changes for channel
+    async fn fetch_channel_schedules(&self, channel_id: &str) -> Result<Vec<ChannelSchedule>>;
+
+    /// Fetch scheduled changes due at or before the given time
+    async fn fetch_due_channel_schedules(&self, before: Timestamp) -> Result<Vec<ChannelSchedule>>;
+
+    /// Delete scheduled change by id
+    async fn delete_channel_schedule(&self, id: &str) -> Result<()>;
+
+    /// Remove scheduled change by id, returning whether this call removed it
+    async fn claim_channel_schedule(&self, id: &str) -> Result<bool>;
+}
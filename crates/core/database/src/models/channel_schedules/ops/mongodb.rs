@@ -0,0 +1,59 @@
+use bson::to_bson;
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ChannelSchedule;
+use crate::MongoDb;
+
+use super::AbstractChannelSchedules;
+
+static COL: &str = "channel_schedules";
+
+#[async_trait]
+impl AbstractChannelSchedules for MongoDb {
+    /// Insert new scheduled change into the database
+    async fn insert_channel_schedule(&self, schedule: &ChannelSchedule) -> Result<()> {
+        query!(self, insert_one, COL, &schedule).map(|_| ())
+    }
+
+    /// Fetch scheduled change by id
+    async fn fetch_channel_schedule(&self, id: &str) -> Result<ChannelSchedule> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled changes for channel
+    async fn fetch_channel_schedules(&self, channel_id: &str) -> Result<Vec<ChannelSchedule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "channel_id": channel_id
+            }
+        )
+    }
+
+    /// Fetch scheduled changes due at or before the given time
+    async fn fetch_due_channel_schedules(&self, before: Timestamp) -> Result<Vec<ChannelSchedule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "run_at": {
+                    "$lte": to_bson(&before).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Delete scheduled change by id
+    async fn delete_channel_schedule(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Remove scheduled change by id, returning whether this call removed it
+    async fn claim_channel_schedule(&self, id: &str) -> Result<bool> {
+        query!(self, delete_one_by_id, COL, id).map(|result| result.deleted_count == 1)
+    }
+}
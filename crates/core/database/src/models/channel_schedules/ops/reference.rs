@@ -0,0 +1,66 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ChannelSchedule;
+use crate::ReferenceDb;
+
+use super::AbstractChannelSchedules;
+
+#[async_trait]
+impl AbstractChannelSchedules for ReferenceDb {
+    /// Insert new scheduled change into the database
+    async fn insert_channel_schedule(&self, schedule: &ChannelSchedule) -> Result<()> {
+        let mut schedules = self.channel_schedules.lock().await;
+        if schedules.contains_key(&schedule.id) {
+            Err(create_database_error!("insert", "channel_schedules"))
+        } else {
+            schedules.insert(schedule.id.to_string(), schedule.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch scheduled change by id
+    async fn fetch_channel_schedule(&self, id: &str) -> Result<ChannelSchedule> {
+        let schedules = self.channel_schedules.lock().await;
+        schedules
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled changes for channel
+    async fn fetch_channel_schedules(&self, channel_id: &str) -> Result<Vec<ChannelSchedule>> {
+        let schedules = self.channel_schedules.lock().await;
+        Ok(schedules
+            .values()
+            .filter(|schedule| schedule.channel_id == channel_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch scheduled changes due at or before the given time
+    async fn fetch_due_channel_schedules(&self, before: Timestamp) -> Result<Vec<ChannelSchedule>> {
+        let schedules = self.channel_schedules.lock().await;
+        Ok(schedules
+            .values()
+            .filter(|schedule| *schedule.run_at <= *before)
+            .cloned()
+            .collect())
+    }
+
+    /// Delete scheduled change by id
+    async fn delete_channel_schedule(&self, id: &str) -> Result<()> {
+        let mut schedules = self.channel_schedules.lock().await;
+        if schedules.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove scheduled change by id, returning whether this call removed it
+    async fn claim_channel_schedule(&self, id: &str) -> Result<bool> {
+        let mut schedules = self.channel_schedules.lock().await;
+        Ok(schedules.remove(id).is_some())
+    }
+}
@@ -0,0 +1,131 @@
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Change made to a channel's name or description
+    pub struct ChannelTopicChange {
+        /// Change Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Channel that was changed
+        pub channel_id: String,
+        /// User who made the change
+        pub author_id: String,
+
+        /// Change to the channel name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<TopicFieldChange>,
+        /// Change to the channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<TopicFieldChange>,
+    }
+
+    /// Value of a channel field before and after a change
+    pub struct TopicFieldChange {
+        /// Previous value
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<String>,
+        /// New value
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<String>,
+    }
+);
+
+impl TopicFieldChange {
+    /// Describe the change between two values, if they differ
+    pub fn between(before: Option<String>, after: Option<String>) -> Option<TopicFieldChange> {
+        if before == after {
+            None
+        } else {
+            Some(TopicFieldChange { before, after })
+        }
+    }
+}
+
+impl ChannelTopicChange {
+    /// Record a change to a channel's name or description
+    ///
+    /// Nothing is recorded if neither field changed.
+    pub async fn create(
+        db: &Database,
+        channel_id: &str,
+        author_id: &str,
+        name: Option<TopicFieldChange>,
+        description: Option<TopicFieldChange>,
+    ) -> Result<Option<ChannelTopicChange>> {
+        if name.is_none() && description.is_none() {
+            return Ok(None);
+        }
+
+        let change = ChannelTopicChange {
+            id: Ulid::new().to_string(),
+            channel_id: channel_id.to_string(),
+            author_id: author_id.to_string(),
+            name,
+            description,
+        };
+
+        db.insert_channel_topic_change(&change).await?;
+        Ok(Some(change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChannelTopicChange, TopicFieldChange};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let renamed = ChannelTopicChange::create(
+                &db,
+                "channel",
+                "user",
+                TopicFieldChange::between(Some("general".to_string()), Some("chat".to_string())),
+                None,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+            let described = ChannelTopicChange::create(
+                &db,
+                "channel",
+                "user",
+                None,
+                TopicFieldChange::between(None, Some("Weekly topic".to_string())),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+            let unchanged = ChannelTopicChange::create(
+                &db,
+                "channel",
+                "user",
+                TopicFieldChange::between(Some("chat".to_string()), Some("chat".to_string())),
+                None,
+            )
+            .await
+            .unwrap();
+
+            assert!(unchanged.is_none());
+
+            let history = db
+                .fetch_channel_topic_history("channel", None, 10)
+                .await
+                .unwrap();
+
+            assert_eq!(vec![described.clone(), renamed.clone()], history);
+
+            let older = db
+                .fetch_channel_topic_history("channel", Some(&described.id), 10)
+                .await
+                .unwrap();
+
+            assert_eq!(vec![renamed], older);
+        });
+    }
+}
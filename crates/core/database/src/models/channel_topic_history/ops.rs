@@ -0,0 +1,20 @@
+use revolt_result::Result;
+
+use crate::ChannelTopicChange;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelTopicHistory: Sync + Send {
+    /// Insert new channel topic change into the database
+    async fn insert_channel_topic_change(&self, change: &ChannelTopicChange) -> Result<()>;
+
+    /// Fetch topic changes for a channel, newest first, optionally before a given change
+    async fn fetch_channel_topic_history(
+        &self,
+        channel_id: &str,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ChannelTopicChange>>;
+}
@@ -0,0 +1,44 @@
+use ::mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::ChannelTopicChange;
+use crate::MongoDb;
+
+use super::AbstractChannelTopicHistory;
+
+static COL: &str = "channel_topic_history";
+
+#[async_trait]
+impl AbstractChannelTopicHistory for MongoDb {
+    /// Insert new channel topic change into the database
+    async fn insert_channel_topic_change(&self, change: &ChannelTopicChange) -> Result<()> {
+        query!(self, insert_one, COL, &change).map(|_| ())
+    }
+
+    /// Fetch topic changes for a channel, newest first, optionally before a given change
+    async fn fetch_channel_topic_history(
+        &self,
+        channel_id: &str,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ChannelTopicChange>> {
+        let mut filter = doc! {
+            "channel_id": channel_id
+        };
+
+        if let Some(id) = before {
+            filter.insert("_id", doc! { "$lt": id });
+        }
+
+        query!(
+            self,
+            find_with_options,
+            COL,
+            filter,
+            FindOptions::builder()
+                .sort(doc! { "_id": -1_i32 })
+                .limit(limit)
+                .build()
+        )
+    }
+}
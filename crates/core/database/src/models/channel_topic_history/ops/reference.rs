@@ -0,0 +1,40 @@
+use revolt_result::Result;
+
+use crate::ChannelTopicChange;
+use crate::ReferenceDb;
+
+use super::AbstractChannelTopicHistory;
+
+#[async_trait]
+impl AbstractChannelTopicHistory for ReferenceDb {
+    /// Insert new channel topic change into the database
+    async fn insert_channel_topic_change(&self, change: &ChannelTopicChange) -> Result<()> {
+        let mut history = self.channel_topic_history.lock().await;
+        if history.contains_key(&change.id) {
+            Err(create_database_error!("insert", "channel_topic_history"))
+        } else {
+            history.insert(change.id.to_string(), change.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch topic changes for a channel, newest first, optionally before a given change
+    async fn fetch_channel_topic_history(
+        &self,
+        channel_id: &str,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ChannelTopicChange>> {
+        let history = self.channel_topic_history.lock().await;
+        let mut changes: Vec<ChannelTopicChange> = history
+            .values()
+            .filter(|change| change.channel_id == channel_id)
+            .filter(|change| before.map_or(true, |id| change.id.as_str() < id))
+            .cloned()
+            .collect();
+
+        changes.sort_by(|a, b| b.id.cmp(&a.id));
+        changes.truncate(limit.max(0) as usize);
+        Ok(changes)
+    }
+}
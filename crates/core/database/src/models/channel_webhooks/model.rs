@@ -22,14 +22,30 @@ auto_derived_partial!(
 
         /// The private token for the webhook
         pub token: Option<String>,
+
+        /// Limits on how often this webhook may send messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limits: Option<WebhookLimits>,
     },
     "PartialWebhook"
 );
 
 auto_derived!(
+    /// Limits on how often a webhook may send messages
+    #[derive(Default)]
+    pub struct WebhookLimits {
+        /// Maximum number of messages per minute
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub per_minute: Option<u32>,
+        /// Maximum number of messages per day
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub per_day: Option<u32>,
+    }
+
     /// Optional fields on webhook object
     pub enum FieldsWebhook {
         Avatar,
+        Limits,
     }
 );
 
@@ -87,6 +103,7 @@ impl Webhook {
     pub fn remove_field(&mut self, field: &FieldsWebhook) {
         match field {
             FieldsWebhook::Avatar => self.avatar = None,
+            FieldsWebhook::Limits => self.limits = None,
         }
     }
 
@@ -105,7 +122,7 @@ impl Webhook {
 
 #[cfg(test)]
 mod tests {
-    use crate::{FieldsWebhook, PartialWebhook, Webhook};
+    use crate::{FieldsWebhook, PartialWebhook, Webhook, WebhookLimits};
 
     #[async_std::test]
     async fn crud() {
@@ -129,6 +146,10 @@ mod tests {
                     &db,
                     PartialWebhook {
                         name: Some("New Name".to_string()),
+                        limits: Some(WebhookLimits {
+                            per_minute: Some(10),
+                            per_day: None,
+                        }),
                         ..Default::default()
                     },
                     vec![FieldsWebhook::Avatar],
@@ -96,6 +96,30 @@ auto_derived!(
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
+
+            /// Whether only members with the ManageThreads permission may post
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            locked: bool,
+
+            /// Whether every message must contain an attachment or link
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            media_only: bool,
+
+            /// Role required to post in this channel, everyone else may only react
+            #[serde(skip_serializing_if = "Option::is_none")]
+            posting_role: Option<String>,
+
+            /// Seconds each member must wait between sending messages
+            #[serde(skip_serializing_if = "Option::is_none")]
+            slowmode: Option<u32>,
+
+            /// Depth of reply chain after which further replies are moved into a thread
+            #[serde(skip_serializing_if = "Option::is_none")]
+            auto_thread_depth: Option<u32>,
+
+            /// Restrictions on the content types of attachments sent in this channel
+            #[serde(skip_serializing_if = "Option::is_none")]
+            file_types: Option<FileTypeRestrictions>,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -113,6 +137,9 @@ auto_derived!(
             /// Custom icon attachment
             #[serde(skip_serializing_if = "Option::is_none")]
             icon: Option<File>,
+            /// Id of the last message sent in this channel
+            #[serde(skip_serializing_if = "Option::is_none")]
+            last_message_id: Option<String>,
 
             /// Default permissions assigned to users in this channel
             #[serde(skip_serializing_if = "Option::is_none")]
@@ -127,6 +154,14 @@ auto_derived!(
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
+
+            /// Maximum number of users who can be connected at once
+            #[serde(skip_serializing_if = "Option::is_none")]
+            user_limit: Option<u32>,
+
+            /// Restrictions on the content types of attachments sent in this channel
+            #[serde(skip_serializing_if = "Option::is_none")]
+            file_types: Option<FileTypeRestrictions>,
         },
     }
 );
@@ -154,6 +189,20 @@ auto_derived!(
         pub default_permissions: Option<OverrideField>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_message_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_limit: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub locked: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub media_only: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub posting_role: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub slowmode: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub auto_thread_depth: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub file_types: Option<FileTypeRestrictions>,
     }
 
     /// Optional fields on channel object
@@ -161,6 +210,21 @@ auto_derived!(
         Description,
         Icon,
         DefaultPermissions,
+        UserLimit,
+        PostingRole,
+        FileTypes,
+        Slowmode,
+        AutoThreadDepth,
+    }
+
+    /// Attachment content types permitted in a channel
+    pub struct FileTypeRestrictions {
+        /// Content types which may be sent, an empty list permits everything
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub allow: Vec<String>,
+        /// Content types which may never be sent, takes priority over `allow`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub block: Vec<String>,
     }
 );
 
@@ -343,6 +407,36 @@ impl Channel {
                 }
                 _ => {}
             },
+            FieldsChannel::UserLimit => {
+                if let Self::VoiceChannel { user_limit, .. } = self {
+                    user_limit.take();
+                }
+            }
+            FieldsChannel::PostingRole => {
+                if let Self::TextChannel { posting_role, .. } = self {
+                    posting_role.take();
+                }
+            }
+            FieldsChannel::Slowmode => {
+                if let Self::TextChannel { slowmode, .. } = self {
+                    slowmode.take();
+                }
+            }
+            FieldsChannel::AutoThreadDepth => {
+                if let Self::TextChannel {
+                    auto_thread_depth, ..
+                } = self
+                {
+                    auto_thread_depth.take();
+                }
+            }
+            FieldsChannel::FileTypes => {
+                if let Self::TextChannel { file_types, .. }
+                | Self::VoiceChannel { file_types, .. } = self
+                {
+                    file_types.take();
+                }
+            }
         }
     }
 
@@ -355,6 +449,48 @@ impl Channel {
 
     /// Apply partial channel to channel
     pub fn apply_options(&mut self, partial: PartialChannel) {
+        if let Self::VoiceChannel { user_limit, .. } = self {
+            if let Some(v) = partial.user_limit {
+                user_limit.replace(v);
+            }
+        }
+
+        if let Self::TextChannel { file_types, .. } | Self::VoiceChannel { file_types, .. } = self {
+            if let Some(v) = partial.file_types {
+                file_types.replace(v);
+            }
+        }
+
+        if let Self::TextChannel {
+            locked,
+            media_only,
+            posting_role,
+            slowmode,
+            auto_thread_depth,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.locked {
+                *locked = v;
+            }
+
+            if let Some(v) = partial.media_only {
+                *media_only = v;
+            }
+
+            if let Some(v) = partial.posting_role {
+                posting_role.replace(v);
+            }
+
+            if let Some(v) = partial.slowmode {
+                slowmode.replace(v);
+            }
+
+            if let Some(v) = partial.auto_thread_depth {
+                auto_thread_depth.replace(v);
+            }
+        }
+
         match self {
             Self::SavedMessages { .. } => {}
             Self::DirectMessage { active, .. } => {
@@ -524,6 +660,11 @@ impl IntoDocumentPath for FieldsChannel {
             FieldsChannel::Description => "description",
             FieldsChannel::Icon => "icon",
             FieldsChannel::DefaultPermissions => "default_permissions",
+            FieldsChannel::UserLimit => "user_limit",
+            FieldsChannel::PostingRole => "posting_role",
+            FieldsChannel::FileTypes => "file_types",
+            FieldsChannel::Slowmode => "slowmode",
+            FieldsChannel::AutoThreadDepth => "auto_thread_depth",
         })
     }
 }
@@ -0,0 +1,114 @@
+use revolt_result::{Error, ErrorType, Result};
+
+use crate::Database;
+
+auto_derived!(
+    /// Experimental feature which can be toggled at runtime
+    pub struct FeatureFlag {
+        /// Flag name
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Whether the feature is enabled across the whole instance
+        pub enabled: bool,
+        /// Servers the feature is enabled in regardless of the instance setting
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub servers: Vec<String>,
+    }
+);
+
+impl FeatureFlag {
+    /// Check whether a flag name is well-formed
+    pub fn is_valid_id(id: &str) -> bool {
+        !id.is_empty()
+            && id.len() <= 32
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    /// Check whether this feature is enabled, optionally within a given server
+    pub fn is_enabled(&self, server: Option<&str>) -> bool {
+        self.enabled || server.map_or(false, |server| self.servers.iter().any(|id| id == server))
+    }
+
+    /// Fetch a flag, falling back to the configured defaults if it has no override
+    pub async fn fetch(db: &Database, id: &str, defaults: &[String]) -> Result<FeatureFlag> {
+        match db.fetch_feature_flag(id).await {
+            Err(Error {
+                error_type: ErrorType::NotFound,
+                ..
+            }) => Ok(FeatureFlag {
+                id: id.to_string(),
+                enabled: defaults.iter().any(|flag| flag == id),
+                servers: vec![],
+            }),
+            result => result,
+        }
+    }
+
+    /// Fetch every configured or overridden flag
+    pub async fn fetch_all(db: &Database, defaults: &[String]) -> Result<Vec<FeatureFlag>> {
+        let mut flags = db.fetch_feature_flags().await?;
+        for id in defaults {
+            if !flags.iter().any(|flag| &flag.id == id) {
+                flags.push(FeatureFlag {
+                    id: id.to_string(),
+                    enabled: true,
+                    servers: vec![],
+                });
+            }
+        }
+
+        flags.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(flags)
+    }
+
+    /// Save this flag as an override
+    pub async fn save(&self, db: &Database) -> Result<()> {
+        db.upsert_feature_flag(self).await
+    }
+
+    /// Remove the override for this flag, returning to the configured default
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_feature_flag(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FeatureFlag;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let defaults = vec!["configured".to_string()];
+
+            let flag = FeatureFlag::fetch(&db, "federation", &defaults)
+                .await
+                .unwrap();
+            assert!(!flag.is_enabled(None));
+
+            let flag = FeatureFlag {
+                servers: vec!["server".to_string()],
+                ..flag
+            };
+
+            flag.save(&db).await.unwrap();
+
+            let fetched = FeatureFlag::fetch(&db, "federation", &defaults)
+                .await
+                .unwrap();
+            assert_eq!(flag, fetched);
+            assert!(fetched.is_enabled(Some("server")));
+            assert!(!fetched.is_enabled(Some("other")));
+
+            let flags = FeatureFlag::fetch_all(&db, &defaults).await.unwrap();
+            assert_eq!(2, flags.len());
+            assert!(flags[0].is_enabled(None));
+
+            flag.delete(&db).await.unwrap();
+            assert!(db.fetch_feature_flag("federation").await.is_err());
+            assert!(!FeatureFlag::is_valid_id("Not Valid"));
+        });
+    }
+}
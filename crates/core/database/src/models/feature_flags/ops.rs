@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::FeatureFlag;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractFeatureFlags: Sync + Send {
+    /// Fetch a feature flag override by its id
+    async fn fetch_feature_flag(&self, id: &str) -> Result<FeatureFlag>;
+
+    /// Fetch all feature flag overrides
+    async fn fetch_feature_flags(&self) -> Result<Vec<FeatureFlag>>;
+
+    /// Insert or replace a feature flag override
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> Result<()>;
+
+    /// Delete a feature flag override by its id
+    async fn delete_feature_flag(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,42 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::FeatureFlag;
+use crate::MongoDb;
+
+use super::AbstractFeatureFlags;
+
+static COL: &str = "feature_flags";
+
+#[async_trait]
+impl AbstractFeatureFlags for MongoDb {
+    /// Fetch a feature flag override by its id
+    async fn fetch_feature_flag(&self, id: &str) -> Result<FeatureFlag> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all feature flag overrides
+    async fn fetch_feature_flags(&self) -> Result<Vec<FeatureFlag>> {
+        query!(self, find, COL, doc! {})
+    }
+
+    /// Insert or replace a feature flag override
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> Result<()> {
+        self.col::<FeatureFlag>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &flag.id
+                },
+                flag,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+
+    /// Delete a feature flag override by its id
+    async fn delete_feature_flag(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,41 @@
+use revolt_result::Result;
+
+use crate::FeatureFlag;
+use crate::ReferenceDb;
+
+use super::AbstractFeatureFlags;
+
+#[async_trait]
+impl AbstractFeatureFlags for ReferenceDb {
+    /// Fetch a feature flag override by its id
+    async fn fetch_feature_flag(&self, id: &str) -> Result<FeatureFlag> {
+        let feature_flags = self.feature_flags.lock().await;
+        feature_flags
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all feature flag overrides
+    async fn fetch_feature_flags(&self) -> Result<Vec<FeatureFlag>> {
+        let feature_flags = self.feature_flags.lock().await;
+        Ok(feature_flags.values().cloned().collect())
+    }
+
+    /// Insert or replace a feature flag override
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> Result<()> {
+        let mut feature_flags = self.feature_flags.lock().await;
+        feature_flags.insert(flag.id.to_string(), flag.clone());
+        Ok(())
+    }
+
+    /// Delete a feature flag override by its id
+    async fn delete_feature_flag(&self, id: &str) -> Result<()> {
+        let mut feature_flags = self.feature_flags.lock().await;
+        if feature_flags.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use revolt_result::Result;
+
+use crate::events::client::EventV1;
+use crate::Database;
+
+auto_derived!(
+    /// Direct message exchanged with a user on another instance
+    pub struct FederatedMessage {
+        /// Message Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the local user participating in the conversation
+        pub user_id: String,
+        /// Identifier of the remote user, in the form `user_id@domain`
+        pub remote_user: String,
+        /// Whether this message was sent by the local user
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub outgoing: bool,
+
+        /// Message content
+        pub content: String,
+    }
+);
+
+impl FederatedMessage {
+    /// Save this message and notify the local user
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_federated_message(self).await?;
+
+        EventV1::FederatedMessage(self.clone().into())
+            .private(self.user_id.clone())
+            .await;
+
+        Ok(())
+    }
+}
+
+/// Split a remote user identifier into its user id and domain
+pub fn parse_remote_user(identifier: &str) -> Option<(&str, &str)> {
+    identifier
+        .split_once('@')
+        .filter(|(user, domain)| !user.is_empty() && !domain.is_empty() && !domain.contains('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_remote_user, FederatedMessage};
+
+    #[test]
+    fn remote_user() {
+        assert_eq!(
+            Some(("01EX2NCWQ0CHS3QJF0FEQS1GR4", "example.com")),
+            parse_remote_user("01EX2NCWQ0CHS3QJF0FEQS1GR4@example.com")
+        );
+        assert_eq!(None, parse_remote_user("01EX2NCWQ0CHS3QJF0FEQS1GR4"));
+        assert_eq!(None, parse_remote_user("user@example.com/path"));
+    }
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let user_id = "user";
+            let remote_user = "remote@example.com";
+
+            for (index, outgoing) in [false, true, false].into_iter().enumerate() {
+                FederatedMessage {
+                    id: format!("message{index}"),
+                    user_id: user_id.to_string(),
+                    remote_user: remote_user.to_string(),
+                    outgoing,
+                    content: "Hello!".to_string(),
+                }
+                .create(&db)
+                .await
+                .unwrap();
+            }
+
+            let messages = db
+                .fetch_federated_messages(user_id, remote_user, 2)
+                .await
+                .unwrap();
+
+            assert_eq!(2, messages.len());
+            assert_eq!("message2", messages[0].id);
+            assert_eq!("message1", messages[1].id);
+            assert!(messages[1].outgoing);
+
+            assert!(db
+                .fetch_federated_messages("other", remote_user, 50)
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+}
@@ -0,0 +1,20 @@
+use revolt_result::Result;
+
+use crate::FederatedMessage;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractFederatedMessages: Sync + Send {
+    /// Insert a new federated message into the database
+    async fn insert_federated_message(&self, message: &FederatedMessage) -> Result<()>;
+
+    /// Fetch the latest messages in a conversation with a remote user, newest first
+    async fn fetch_federated_messages(
+        &self,
+        user_id: &str,
+        remote_user: &str,
+        limit: i64,
+    ) -> Result<Vec<FederatedMessage>>;
+}
@@ -0,0 +1,39 @@
+use ::mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::FederatedMessage;
+use crate::MongoDb;
+
+use super::AbstractFederatedMessages;
+
+static COL: &str = "federated_messages";
+
+#[async_trait]
+impl AbstractFederatedMessages for MongoDb {
+    /// Insert a new federated message into the database
+    async fn insert_federated_message(&self, message: &FederatedMessage) -> Result<()> {
+        query!(self, insert_one, COL, &message).map(|_| ())
+    }
+
+    /// Fetch the latest messages in a conversation with a remote user, newest first
+    async fn fetch_federated_messages(
+        &self,
+        user_id: &str,
+        remote_user: &str,
+        limit: i64,
+    ) -> Result<Vec<FederatedMessage>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            doc! {
+                "user_id": user_id,
+                "remote_user": remote_user
+            },
+            FindOptions::builder()
+                .sort(doc! { "_id": -1_i32 })
+                .limit(limit)
+                .build()
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use revolt_result::Result;
+
+use crate::FederatedMessage;
+use crate::ReferenceDb;
+
+use super::AbstractFederatedMessages;
+
+#[async_trait]
+impl AbstractFederatedMessages for ReferenceDb {
+    /// Insert a new federated message into the database
+    async fn insert_federated_message(&self, message: &FederatedMessage) -> Result<()> {
+        let mut messages = self.federated_messages.lock().await;
+        if messages.contains_key(&message.id) {
+            Err(create_database_error!("insert", "federated_messages"))
+        } else {
+            messages.insert(message.id.to_string(), message.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch the latest messages in a conversation with a remote user, newest first
+    async fn fetch_federated_messages(
+        &self,
+        user_id: &str,
+        remote_user: &str,
+        limit: i64,
+    ) -> Result<Vec<FederatedMessage>> {
+        let messages = self.federated_messages.lock().await;
+        let mut conversation: Vec<FederatedMessage> = messages
+            .values()
+            .filter(|message| message.user_id == user_id && message.remote_user == remote_user)
+            .cloned()
+            .collect();
+
+        conversation.sort_by(|a, b| b.id.cmp(&a.id));
+        conversation.truncate(limit as usize);
+        Ok(conversation)
+    }
+}
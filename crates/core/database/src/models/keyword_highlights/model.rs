@@ -0,0 +1,123 @@
+use revolt_result::{Error, ErrorType, Result};
+
+use crate::{Database, MemberCompositeKey};
+
+auto_derived!(
+    /// Keywords a member wants to be notified about in a server
+    pub struct KeywordHighlights {
+        /// Member these keywords belong to
+        #[serde(rename = "_id")]
+        pub id: MemberCompositeKey,
+        /// Keywords which trigger a notification, matched as whole words ignoring case
+        pub keywords: Vec<String>,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl KeywordHighlights {
+    /// Fetch the keywords of a member, which are empty if none have been set
+    pub async fn fetch(db: &Database, server_id: &str, user_id: &str) -> Result<KeywordHighlights> {
+        match db.fetch_keyword_highlights(server_id, user_id).await {
+            Err(Error {
+                error_type: ErrorType::NotFound,
+                ..
+            }) => Ok(KeywordHighlights {
+                id: MemberCompositeKey {
+                    server: server_id.to_string(),
+                    user: user_id.to_string(),
+                },
+                keywords: vec![],
+            }),
+            result => result,
+        }
+    }
+
+    /// Replace the keywords of a member, an empty list removes them
+    pub async fn set(
+        db: &Database,
+        server_id: &str,
+        user_id: &str,
+        keywords: Vec<String>,
+    ) -> Result<KeywordHighlights> {
+        let highlights = KeywordHighlights {
+            id: MemberCompositeKey {
+                server: server_id.to_string(),
+                user: user_id.to_string(),
+            },
+            keywords,
+        };
+
+        if highlights.keywords.is_empty() {
+            db.delete_keyword_highlights(server_id, user_id).await?;
+        } else {
+            db.upsert_keyword_highlights(&highlights).await?;
+        }
+
+        Ok(highlights)
+    }
+
+    /// Check whether any keyword appears as a whole word in some content
+    ///
+    /// Content is expected to already be lowercase.
+    pub fn matches(&self, content: &str) -> bool {
+        self.keywords.iter().any(|keyword| {
+            let keyword = keyword.to_lowercase();
+            content.match_indices(&keyword).any(|(start, _)| {
+                let before = content[..start].chars().next_back();
+                let after = content[start + keyword.len()..].chars().next();
+                !before.is_some_and(char::is_alphanumeric)
+                    && !after.is_some_and(char::is_alphanumeric)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::KeywordHighlights;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let highlights =
+                KeywordHighlights::set(&db, "server", "user", vec!["Rust".to_string()])
+                    .await
+                    .unwrap();
+
+            KeywordHighlights::set(&db, "server", "other", vec!["release".to_string()])
+                .await
+                .unwrap();
+
+            assert_eq!(
+                highlights,
+                db.fetch_keyword_highlights("server", "user").await.unwrap()
+            );
+            assert_eq!(
+                2,
+                db.fetch_server_keyword_highlights("server")
+                    .await
+                    .unwrap()
+                    .len()
+            );
+
+            KeywordHighlights::set(&db, "server", "user", vec![])
+                .await
+                .unwrap();
+
+            assert!(db.fetch_keyword_highlights("server", "user").await.is_err());
+        });
+    }
+
+    #[test]
+    fn matches() {
+        let highlights = KeywordHighlights {
+            id: Default::default(),
+            keywords: vec!["Rust".to_string(), "new release".to_string()],
+        };
+
+        assert!(highlights.matches("i love rust!"));
+        assert!(highlights.matches("the new release is out"));
+        assert!(!highlights.matches("this is rusty"));
+        assert!(!highlights.matches("trust me"));
+    }
+}
@@ -0,0 +1,28 @@
+use revolt_result::Result;
+
+use crate::KeywordHighlights;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractKeywordHighlights: Sync + Send {
+    /// Fetch the keywords of a member
+    async fn fetch_keyword_highlights(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<KeywordHighlights>;
+
+    /// Fetch the keywords of every member in a server
+    async fn fetch_server_keyword_highlights(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<KeywordHighlights>>;
+
+    /// Insert or replace the keywords of a member
+    async fn upsert_keyword_highlights(&self, highlights: &KeywordHighlights) -> Result<()>;
+
+    /// Delete the keywords of a member
+    async fn delete_keyword_highlights(&self, server_id: &str, user_id: &str) -> Result<()>;
+}
@@ -0,0 +1,75 @@
+use ::mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::KeywordHighlights;
+use crate::MongoDb;
+
+use super::AbstractKeywordHighlights;
+
+static COL: &str = "keyword_highlights";
+
+#[async_trait]
+impl AbstractKeywordHighlights for MongoDb {
+    /// Fetch the keywords of a member
+    async fn fetch_keyword_highlights(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<KeywordHighlights> {
+        query!(
+            self,
+            find_one,
+            COL,
+            doc! {
+                "_id.server": server_id,
+                "_id.user": user_id
+            }
+        )?
+        .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch the keywords of every member in a server
+    async fn fetch_server_keyword_highlights(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<KeywordHighlights>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "_id.server": server_id
+            }
+        )
+    }
+
+    /// Insert or replace the keywords of a member
+    async fn upsert_keyword_highlights(&self, highlights: &KeywordHighlights) -> Result<()> {
+        self.col::<KeywordHighlights>(COL)
+            .replace_one(
+                doc! {
+                    "_id.server": &highlights.id.server,
+                    "_id.user": &highlights.id.user
+                },
+                highlights,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+
+    /// Delete the keywords of a member
+    async fn delete_keyword_highlights(&self, server_id: &str, user_id: &str) -> Result<()> {
+        query!(
+            self,
+            delete_one,
+            COL,
+            doc! {
+                "_id.server": server_id,
+                "_id.user": user_id
+            }
+        )
+        .map(|_| ())
+    }
+}
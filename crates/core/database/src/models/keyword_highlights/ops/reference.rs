@@ -0,0 +1,53 @@
+use revolt_result::Result;
+
+use crate::KeywordHighlights;
+use crate::ReferenceDb;
+
+use super::AbstractKeywordHighlights;
+
+#[async_trait]
+impl AbstractKeywordHighlights for ReferenceDb {
+    /// Fetch the keywords of a member
+    async fn fetch_keyword_highlights(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<KeywordHighlights> {
+        let keyword_highlights = self.keyword_highlights.lock().await;
+        keyword_highlights
+            .get(&format!("{server_id}:{user_id}"))
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch the keywords of every member in a server
+    async fn fetch_server_keyword_highlights(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<KeywordHighlights>> {
+        let keyword_highlights = self.keyword_highlights.lock().await;
+        Ok(keyword_highlights
+            .values()
+            .filter(|highlights| highlights.id.server == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Insert or replace the keywords of a member
+    async fn upsert_keyword_highlights(&self, highlights: &KeywordHighlights) -> Result<()> {
+        let mut keyword_highlights = self.keyword_highlights.lock().await;
+        keyword_highlights.insert(
+            format!("{}:{}", highlights.id.server, highlights.id.user),
+            highlights.clone(),
+        );
+
+        Ok(())
+    }
+
+    /// Delete the keywords of a member
+    async fn delete_keyword_highlights(&self, server_id: &str, user_id: &str) -> Result<()> {
+        let mut keyword_highlights = self.keyword_highlights.lock().await;
+        keyword_highlights.remove(&format!("{server_id}:{user_id}"));
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Instance-wide maintenance state
+    pub struct MaintenanceStatus {
+        /// Whether the API is rejecting writes
+        pub enabled: bool,
+        /// Message shown to users while maintenance is in progress
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>,
+        /// Time at which maintenance is expected to end
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub ends_at: Option<Timestamp>,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl MaintenanceStatus {
+    /// Fetch the current maintenance state, defaulting to disabled
+    pub async fn fetch(db: &Database) -> Result<MaintenanceStatus> {
+        Ok(db
+            .fetch_maintenance_status()
+            .await?
+            .unwrap_or(MaintenanceStatus {
+                enabled: false,
+                message: None,
+                ends_at: None,
+            }))
+    }
+
+    /// Save this as the current maintenance state
+    pub async fn save(&self, db: &Database) -> Result<()> {
+        db.set_maintenance_status(self).await
+    }
+
+    /// Milliseconds until maintenance is expected to end, if known
+    pub fn retry_after(&self) -> Option<u64> {
+        self.ends_at.map(|ends_at| {
+            (*ends_at - *Timestamp::now_utc())
+                .whole_milliseconds()
+                .max(0) as u64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaintenanceStatus;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let status = MaintenanceStatus::fetch(&db).await.unwrap();
+            assert!(!status.enabled);
+            assert_eq!(None, status.retry_after());
+
+            let status = MaintenanceStatus {
+                enabled: true,
+                message: Some("Upgrading the database".to_string()),
+                ..status
+            };
+
+            status.save(&db).await.unwrap();
+
+            let fetched = MaintenanceStatus::fetch(&db).await.unwrap();
+            assert_eq!(status, fetched);
+
+            let status = MaintenanceStatus {
+                enabled: false,
+                ..fetched
+            };
+
+            status.save(&db).await.unwrap();
+            assert!(!MaintenanceStatus::fetch(&db).await.unwrap().enabled);
+        });
+    }
+}
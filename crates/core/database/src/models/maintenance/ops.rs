@@ -0,0 +1,15 @@
+use revolt_result::Result;
+
+use crate::MaintenanceStatus;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractMaintenance: Sync + Send {
+    /// Fetch the current maintenance state, if one has been set
+    async fn fetch_maintenance_status(&self) -> Result<Option<MaintenanceStatus>>;
+
+    /// Replace the current maintenance state
+    async fn set_maintenance_status(&self, status: &MaintenanceStatus) -> Result<()>;
+}
@@ -0,0 +1,35 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::MaintenanceStatus;
+use crate::MongoDb;
+
+use super::AbstractMaintenance;
+
+static COL: &str = "maintenance";
+
+/// Id of the document holding the current state
+static ID: &str = "current";
+
+#[async_trait]
+impl AbstractMaintenance for MongoDb {
+    /// Fetch the current maintenance state, if one has been set
+    async fn fetch_maintenance_status(&self) -> Result<Option<MaintenanceStatus>> {
+        query!(self, find_one_by_id, COL, ID)
+    }
+
+    /// Replace the current maintenance state
+    async fn set_maintenance_status(&self, status: &MaintenanceStatus) -> Result<()> {
+        self.col::<MaintenanceStatus>(COL)
+            .replace_one(
+                doc! {
+                    "_id": ID
+                },
+                status,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+}
@@ -0,0 +1,22 @@
+use revolt_result::Result;
+
+use crate::MaintenanceStatus;
+use crate::ReferenceDb;
+
+use super::AbstractMaintenance;
+
+#[async_trait]
+impl AbstractMaintenance for ReferenceDb {
+    /// Fetch the current maintenance state, if one has been set
+    async fn fetch_maintenance_status(&self) -> Result<Option<MaintenanceStatus>> {
+        let maintenance = self.maintenance.lock().await;
+        Ok(maintenance.clone())
+    }
+
+    /// Replace the current maintenance state
+    async fn set_maintenance_status(&self, status: &MaintenanceStatus) -> Result<()> {
+        let mut maintenance = self.maintenance.lock().await;
+        maintenance.replace(status.clone());
+        Ok(())
+    }
+}
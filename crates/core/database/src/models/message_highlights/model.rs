@@ -0,0 +1,59 @@
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Record of a message which has been highlighted
+    pub struct MessageHighlight {
+        /// Id of the highlighted message
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Server the message was sent in
+        pub server_id: String,
+        /// Channel the message was sent in
+        pub channel_id: String,
+        /// Id of the message posted to the highlights channel
+        pub highlight_id: String,
+    }
+);
+
+impl MessageHighlight {
+    /// Record a highlighted message
+    ///
+    /// Fails if the message has already been highlighted.
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_message_highlight(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MessageHighlight;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let highlight = MessageHighlight {
+                id: "message".to_string(),
+                server_id: "server".to_string(),
+                channel_id: "channel".to_string(),
+                highlight_id: "highlight".to_string(),
+            };
+
+            highlight.create(&db).await.unwrap();
+
+            let fetched = db.fetch_message_highlight("message").await.unwrap();
+            assert_eq!(highlight, fetched);
+
+            assert!(MessageHighlight {
+                highlight_id: "other".to_string(),
+                ..highlight.clone()
+            }
+            .create(&db)
+            .await
+            .is_err());
+
+            assert!(db.fetch_message_highlight("other").await.is_err());
+        });
+    }
+}
@@ -0,0 +1,15 @@
+use revolt_result::Result;
+
+use crate::MessageHighlight;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractMessageHighlights: Sync + Send {
+    /// Insert new message highlight into the database
+    async fn insert_message_highlight(&self, highlight: &MessageHighlight) -> Result<()>;
+
+    /// Fetch message highlight by the id of the highlighted message
+    async fn fetch_message_highlight(&self, id: &str) -> Result<MessageHighlight>;
+}
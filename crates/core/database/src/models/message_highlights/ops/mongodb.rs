@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::MessageHighlight;
+use crate::MongoDb;
+
+use super::AbstractMessageHighlights;
+
+static COL: &str = "message_highlights";
+
+#[async_trait]
+impl AbstractMessageHighlights for MongoDb {
+    /// Insert new message highlight into the database
+    async fn insert_message_highlight(&self, highlight: &MessageHighlight) -> Result<()> {
+        query!(self, insert_one, COL, &highlight).map(|_| ())
+    }
+
+    /// Fetch message highlight by the id of the highlighted message
+    async fn fetch_message_highlight(&self, id: &str) -> Result<MessageHighlight> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+}
@@ -0,0 +1,29 @@
+use revolt_result::Result;
+
+use crate::MessageHighlight;
+use crate::ReferenceDb;
+
+use super::AbstractMessageHighlights;
+
+#[async_trait]
+impl AbstractMessageHighlights for ReferenceDb {
+    /// Insert new message highlight into the database
+    async fn insert_message_highlight(&self, highlight: &MessageHighlight) -> Result<()> {
+        let mut message_highlights = self.message_highlights.lock().await;
+        if message_highlights.contains_key(&highlight.id) {
+            Err(create_database_error!("insert", "message_highlights"))
+        } else {
+            message_highlights.insert(highlight.id.to_string(), highlight.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch message highlight by the id of the highlighted message
+    async fn fetch_message_highlight(&self, id: &str) -> Result<MessageHighlight> {
+        let message_highlights = self.message_highlights.lock().await;
+        message_highlights
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+}
@@ -1,40 +1,112 @@
+mod admin_audit_log;
 mod admin_migrations;
+mod ban_sync_groups;
+mod ban_sync_proposals;
 mod bots;
+mod broadcasts;
+mod channel_feeds;
+mod channel_inboxes;
+mod channel_schedules;
+mod channel_topic_history;
 mod channel_webhooks;
 mod channels;
+mod feature_flags;
+mod federated_messages;
 mod files;
+mod keyword_highlights;
+mod maintenance;
+mod message_highlights;
+mod moderation_cases;
+mod policy_acceptances;
+mod policy_documents;
+mod reaction_roles;
+mod role_templates;
 mod safety_strikes;
+mod scheduled_messages;
+mod server_announcements;
+mod server_audit_log;
 mod server_members;
 mod servers;
+mod session_scopes;
+mod threads;
 mod user_settings;
 mod users;
+mod voice_sessions;
 
+pub use admin_audit_log::*;
 pub use admin_migrations::*;
+pub use ban_sync_groups::*;
+pub use ban_sync_proposals::*;
 pub use bots::*;
+pub use broadcasts::*;
+pub use channel_feeds::*;
+pub use channel_inboxes::*;
+pub use channel_schedules::*;
+pub use channel_topic_history::*;
 pub use channel_webhooks::*;
 pub use channels::*;
+pub use feature_flags::*;
+pub use federated_messages::*;
 pub use files::*;
+pub use keyword_highlights::*;
+pub use maintenance::*;
+pub use message_highlights::*;
+pub use moderation_cases::*;
+pub use policy_acceptances::*;
+pub use policy_documents::*;
+pub use reaction_roles::*;
+pub use role_templates::*;
 pub use safety_strikes::*;
+pub use scheduled_messages::*;
+pub use server_announcements::*;
+pub use server_audit_log::*;
 pub use server_members::*;
 pub use servers::*;
+pub use session_scopes::*;
+pub use threads::*;
 pub use user_settings::*;
 pub use users::*;
+pub use voice_sessions::*;
 
 use crate::{Database, MongoDb, ReferenceDb};
 
 pub trait AbstractDatabase:
     Sync
     + Send
+    + admin_audit_log::AbstractAdminAuditLog
     + admin_migrations::AbstractMigrations
+    + ban_sync_groups::AbstractBanSyncGroups
+    + ban_sync_proposals::AbstractBanSyncProposals
     + bots::AbstractBots
+    + broadcasts::AbstractBroadcasts
+    + channel_feeds::AbstractChannelFeeds
+    + channel_inboxes::AbstractChannelInboxes
+    + channel_schedules::AbstractChannelSchedules
+    + channel_topic_history::AbstractChannelTopicHistory
     + channels::AbstractChannels
     + channel_webhooks::AbstractWebhooks
+    + feature_flags::AbstractFeatureFlags
+    + federated_messages::AbstractFederatedMessages
     + files::AbstractAttachments
+    + keyword_highlights::AbstractKeywordHighlights
+    + maintenance::AbstractMaintenance
+    + message_highlights::AbstractMessageHighlights
+    + moderation_cases::AbstractModerationCases
+    + policy_acceptances::AbstractPolicyAcceptances
+    + policy_documents::AbstractPolicyDocuments
+    + reaction_roles::AbstractReactionRoles
+    + role_templates::AbstractRoleTemplates
     + safety_strikes::AbstractAccountStrikes
+    + scheduled_messages::AbstractScheduledMessages
+    + server_announcements::AbstractServerAnnouncements
+    + server_audit_log::AbstractServerAuditLog
     + server_members::AbstractServerMembers
     + servers::AbstractServers
+    + session_scopes::AbstractSessionScopes
+    + threads::AbstractThreads
     + user_settings::AbstractUserSettings
     + users::AbstractUsers
+    + voice_sessions::AbstractVoiceSessions
 {
 }
 
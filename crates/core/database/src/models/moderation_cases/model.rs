@@ -0,0 +1,172 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Moderation action taken against a member of a server
+    pub struct ModerationCase {
+        /// Case Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Server the action was taken in
+        pub server_id: String,
+        /// Number of this case within the server, starting from 1
+        pub case_number: u32,
+        /// Id of the user the action was taken against
+        pub user_id: String,
+        /// Id of the moderator who took the action
+        pub moderator_id: String,
+        /// Action which was taken
+        pub action: ModerationAction,
+        /// Reason given for the action
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        /// Notes left on this case by moderators
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub notes: Vec<ModerationCaseNote>,
+    }
+
+    /// Action taken against a member
+    #[serde(tag = "type")]
+    pub enum ModerationAction {
+        /// Member was warned
+        Warning,
+        /// Member was timed out
+        Timeout {
+            /// Time at which the timeout ends
+            until: Timestamp,
+        },
+        /// Member was kicked
+        Kick,
+        /// User was banned
+        Ban,
+    }
+
+    /// Note left on a moderation case
+    pub struct ModerationCaseNote {
+        /// Note Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the moderator who left the note
+        pub author_id: String,
+        /// Note content
+        pub content: String,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ModerationCase {
+    /// Open a new case with the next case number in the server
+    pub async fn create(
+        db: &Database,
+        server_id: &str,
+        user_id: &str,
+        moderator_id: &str,
+        action: ModerationAction,
+        reason: Option<String>,
+    ) -> Result<ModerationCase> {
+        let case_number = db.next_moderation_case_number(server_id).await?;
+
+        let case = ModerationCase {
+            id: Ulid::new().to_string(),
+            server_id: server_id.to_string(),
+            case_number,
+            user_id: user_id.to_string(),
+            moderator_id: moderator_id.to_string(),
+            action,
+            reason,
+            notes: vec![],
+        };
+
+        db.insert_moderation_case(&case).await?;
+        Ok(case)
+    }
+
+    /// Leave a note on this case
+    pub async fn add_note(
+        &mut self,
+        db: &Database,
+        author_id: &str,
+        content: String,
+    ) -> Result<()> {
+        let note = ModerationCaseNote {
+            id: Ulid::new().to_string(),
+            author_id: author_id.to_string(),
+            content,
+        };
+
+        db.add_moderation_case_note(&self.id, &note).await?;
+        self.notes.push(note);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ModerationAction, ModerationCase};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let mut warning = ModerationCase::create(
+                &db,
+                "server",
+                "user",
+                "moderator",
+                ModerationAction::Warning,
+                Some("Spam".to_string()),
+            )
+            .await
+            .unwrap();
+
+            let ban = ModerationCase::create(
+                &db,
+                "server",
+                "user",
+                "moderator",
+                ModerationAction::Ban,
+                None,
+            )
+            .await
+            .unwrap();
+
+            ModerationCase::create(
+                &db,
+                "server",
+                "other",
+                "moderator",
+                ModerationAction::Kick,
+                None,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(1, warning.case_number);
+            assert_eq!(2, ban.case_number);
+
+            warning
+                .add_note(&db, "moderator", "Second offence".to_string())
+                .await
+                .unwrap();
+
+            let fetched = db.fetch_moderation_case("server", 1).await.unwrap();
+            let history = db
+                .fetch_member_moderation_cases("server", "user")
+                .await
+                .unwrap();
+
+            assert_eq!(warning, fetched);
+            assert_eq!(vec![warning, ban], history);
+            assert_eq!(
+                3,
+                db.fetch_latest_moderation_case("server")
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .case_number
+            );
+        });
+    }
+}
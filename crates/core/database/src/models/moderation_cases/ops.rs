@@ -0,0 +1,36 @@
+use revolt_result::Result;
+
+use crate::{ModerationCase, ModerationCaseNote};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractModerationCases: Sync + Send {
+    /// Insert new moderation case into the database
+    async fn insert_moderation_case(&self, case: &ModerationCase) -> Result<()>;
+
+    /// Fetch moderation case by its number within a server
+    async fn fetch_moderation_case(
+        &self,
+        server_id: &str,
+        case_number: u32,
+    ) -> Result<ModerationCase>;
+
+    /// Allocate the next case number in a server
+    async fn next_moderation_case_number(&self, server_id: &str) -> Result<u32>;
+
+    /// Fetch the most recently opened moderation case in a server
+    async fn fetch_latest_moderation_case(&self, server_id: &str)
+        -> Result<Option<ModerationCase>>;
+
+    /// Fetch every moderation case against a user in a server, oldest first
+    async fn fetch_member_moderation_cases(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<ModerationCase>>;
+
+    /// Add a note to a moderation case
+    async fn add_moderation_case_note(&self, id: &str, note: &ModerationCaseNote) -> Result<()>;
+}
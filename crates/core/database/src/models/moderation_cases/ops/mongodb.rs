@@ -0,0 +1,125 @@
+use ::mongodb::options::{FindOneAndUpdateOptions, FindOneOptions, FindOptions, ReturnDocument};
+use bson::{to_document, Document};
+use revolt_result::Result;
+use serde::Deserialize;
+
+use crate::MongoDb;
+use crate::{ModerationCase, ModerationCaseNote};
+
+use super::AbstractModerationCases;
+
+static COL: &str = "moderation_cases";
+static COUNTERS: &str = "moderation_case_counters";
+
+/// Last case number handed out in a server
+#[derive(Deserialize)]
+struct CaseCounter {
+    case_number: u32,
+}
+
+#[async_trait]
+impl AbstractModerationCases for MongoDb {
+    /// Insert new moderation case into the database
+    async fn insert_moderation_case(&self, case: &ModerationCase) -> Result<()> {
+        query!(self, insert_one, COL, &case).map(|_| ())
+    }
+
+    /// Fetch moderation case by its number within a server
+    async fn fetch_moderation_case(
+        &self,
+        server_id: &str,
+        case_number: u32,
+    ) -> Result<ModerationCase> {
+        query!(
+            self,
+            find_one,
+            COL,
+            doc! {
+                "server_id": server_id,
+                "case_number": case_number
+            }
+        )?
+        .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Allocate the next case number in a server
+    async fn next_moderation_case_number(&self, server_id: &str) -> Result<u32> {
+        self.col::<CaseCounter>(COUNTERS)
+            .find_one_and_update(
+                doc! {
+                    "_id": server_id
+                },
+                doc! {
+                    "$inc": {
+                        "case_number": 1_i32
+                    }
+                },
+                FindOneAndUpdateOptions::builder()
+                    .upsert(true)
+                    .return_document(ReturnDocument::After)
+                    .build(),
+            )
+            .await
+            .map_err(|_| create_database_error!("find_one_and_update", COUNTERS))?
+            .map(|counter| counter.case_number)
+            .ok_or_else(|| create_database_error!("find_one_and_update", COUNTERS))
+    }
+
+    /// Fetch the most recently opened moderation case in a server
+    async fn fetch_latest_moderation_case(
+        &self,
+        server_id: &str,
+    ) -> Result<Option<ModerationCase>> {
+        query!(
+            self,
+            find_one_with_options,
+            COL,
+            doc! {
+                "server_id": server_id
+            },
+            FindOneOptions::builder()
+                .sort(doc! { "case_number": -1_i32 })
+                .build()
+        )
+    }
+
+    /// Fetch every moderation case against a user in a server, oldest first
+    async fn fetch_member_moderation_cases(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<ModerationCase>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            doc! {
+                "server_id": server_id,
+                "user_id": user_id
+            },
+            FindOptions::builder()
+                .sort(doc! { "case_number": 1_i32 })
+                .build()
+        )
+    }
+
+    /// Add a note to a moderation case
+    async fn add_moderation_case_note(&self, id: &str, note: &ModerationCaseNote) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$push": {
+                        "notes": to_document(note)
+                            .map_err(|_| create_database_error!("to_document", "note"))?
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+}
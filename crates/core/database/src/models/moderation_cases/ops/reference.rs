@@ -0,0 +1,83 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{ModerationCase, ModerationCaseNote};
+
+use super::AbstractModerationCases;
+
+#[async_trait]
+impl AbstractModerationCases for ReferenceDb {
+    /// Insert new moderation case into the database
+    async fn insert_moderation_case(&self, case: &ModerationCase) -> Result<()> {
+        let mut moderation_cases = self.moderation_cases.lock().await;
+        if moderation_cases.contains_key(&case.id) {
+            Err(create_database_error!("insert", "moderation_cases"))
+        } else {
+            moderation_cases.insert(case.id.to_string(), case.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch moderation case by its number within a server
+    async fn fetch_moderation_case(
+        &self,
+        server_id: &str,
+        case_number: u32,
+    ) -> Result<ModerationCase> {
+        let moderation_cases = self.moderation_cases.lock().await;
+        moderation_cases
+            .values()
+            .find(|case| case.server_id == server_id && case.case_number == case_number)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Allocate the next case number in a server
+    async fn next_moderation_case_number(&self, server_id: &str) -> Result<u32> {
+        let mut counters = self.moderation_case_counters.lock().await;
+        let case_number = counters.entry(server_id.to_string()).or_default();
+        *case_number += 1;
+        Ok(*case_number)
+    }
+
+    /// Fetch the most recently opened moderation case in a server
+    async fn fetch_latest_moderation_case(
+        &self,
+        server_id: &str,
+    ) -> Result<Option<ModerationCase>> {
+        let moderation_cases = self.moderation_cases.lock().await;
+        Ok(moderation_cases
+            .values()
+            .filter(|case| case.server_id == server_id)
+            .max_by_key(|case| case.case_number)
+            .cloned())
+    }
+
+    /// Fetch every moderation case against a user in a server, oldest first
+    async fn fetch_member_moderation_cases(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<ModerationCase>> {
+        let moderation_cases = self.moderation_cases.lock().await;
+        let mut cases: Vec<ModerationCase> = moderation_cases
+            .values()
+            .filter(|case| case.server_id == server_id && case.user_id == user_id)
+            .cloned()
+            .collect();
+
+        cases.sort_by_key(|case| case.case_number);
+        Ok(cases)
+    }
+
+    /// Add a note to a moderation case
+    async fn add_moderation_case_note(&self, id: &str, note: &ModerationCaseNote) -> Result<()> {
+        let mut moderation_cases = self.moderation_cases.lock().await;
+        if let Some(case) = moderation_cases.get_mut(id) {
+            case.notes.push(note.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+use revolt_result::{Error, ErrorType, Result};
+
+use crate::{Database, PolicyDocument};
+
+auto_derived!(
+    /// Record of which policy documents a user has accepted
+    pub struct PolicyAcceptance {
+        /// User Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Latest accepted version of each document, by document id
+        #[serde(default)]
+        pub documents: HashMap<String, AcceptedPolicy>,
+    }
+
+    /// Acceptance of a specific version of a policy document
+    pub struct AcceptedPolicy {
+        /// Version which was accepted
+        pub version: u32,
+        /// Time at which it was accepted
+        pub accepted_at: Timestamp,
+    }
+);
+
+impl PolicyAcceptance {
+    /// Fetch a user's acceptance record, which is empty if they have not accepted anything yet
+    pub async fn fetch(db: &Database, user_id: &str) -> Result<PolicyAcceptance> {
+        match db.fetch_policy_acceptance(user_id).await {
+            Err(Error {
+                error_type: ErrorType::NotFound,
+                ..
+            }) => Ok(PolicyAcceptance {
+                id: user_id.to_string(),
+                documents: HashMap::new(),
+            }),
+            result => result,
+        }
+    }
+
+    /// Check whether the current version of a document has been accepted
+    pub fn has_accepted(&self, document: &PolicyDocument) -> bool {
+        self.documents
+            .get(&document.id)
+            .map_or(false, |accepted| accepted.version >= document.version)
+    }
+
+    /// Find the first document whose current version has not been accepted
+    pub fn find_outstanding<'a>(
+        &self,
+        documents: &'a [PolicyDocument],
+    ) -> Option<&'a PolicyDocument> {
+        documents
+            .iter()
+            .find(|document| !self.has_accepted(document))
+    }
+
+    /// Accept the current version of a document
+    pub async fn accept(&mut self, db: &Database, document: &PolicyDocument) -> Result<()> {
+        let accepted = AcceptedPolicy {
+            version: document.version,
+            accepted_at: Timestamp::now_utc(),
+        };
+
+        db.accept_policy_document(&self.id, &document.id, &accepted)
+            .await?;
+
+        self.documents.insert(document.id.to_string(), accepted);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PolicyAcceptance, PolicyDocument};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let terms = PolicyDocument::publish(
+                &db,
+                "terms",
+                "Terms of Service".to_string(),
+                "Be nice.".to_string(),
+            )
+            .await
+            .unwrap();
+
+            let mut acceptance = PolicyAcceptance::fetch(&db, "user").await.unwrap();
+            assert!(!acceptance.has_accepted(&terms));
+
+            acceptance.accept(&db, &terms).await.unwrap();
+
+            let fetched = PolicyAcceptance::fetch(&db, "user").await.unwrap();
+            assert_eq!(acceptance, fetched);
+            assert!(fetched.has_accepted(&terms));
+
+            let terms = PolicyDocument::publish(
+                &db,
+                "terms",
+                "Terms of Service".to_string(),
+                "Be very nice.".to_string(),
+            )
+            .await
+            .unwrap();
+
+            let documents = vec![terms];
+            assert_eq!(Some(&documents[0]), fetched.find_outstanding(&documents));
+        });
+    }
+}
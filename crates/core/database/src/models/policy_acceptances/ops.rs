@@ -0,0 +1,20 @@
+use revolt_result::Result;
+
+use crate::{AcceptedPolicy, PolicyAcceptance};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractPolicyAcceptances: Sync + Send {
+    /// Fetch the policy acceptance record of a user
+    async fn fetch_policy_acceptance(&self, user_id: &str) -> Result<PolicyAcceptance>;
+
+    /// Record that a user accepted a version of a policy document
+    async fn accept_policy_document(
+        &self,
+        user_id: &str,
+        document_id: &str,
+        accepted: &AcceptedPolicy,
+    ) -> Result<()>;
+}
@@ -0,0 +1,43 @@
+use bson::{to_bson, Document};
+use mongodb::options::UpdateOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AcceptedPolicy, PolicyAcceptance};
+
+use super::AbstractPolicyAcceptances;
+
+static COL: &str = "policy_acceptances";
+
+#[async_trait]
+impl AbstractPolicyAcceptances for MongoDb {
+    /// Fetch the policy acceptance record of a user
+    async fn fetch_policy_acceptance(&self, user_id: &str) -> Result<PolicyAcceptance> {
+        query!(self, find_one_by_id, COL, user_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Record that a user accepted a version of a policy document
+    async fn accept_policy_document(
+        &self,
+        user_id: &str,
+        document_id: &str,
+        accepted: &AcceptedPolicy,
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": user_id
+                },
+                doc! {
+                    "$set": {
+                        format!("documents.{document_id}"): to_bson(accepted)
+                            .map_err(|_| create_database_error!("to_bson", "accepted"))?
+                    }
+                },
+                UpdateOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+}
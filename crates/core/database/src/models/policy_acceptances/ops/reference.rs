@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AcceptedPolicy, PolicyAcceptance};
+
+use super::AbstractPolicyAcceptances;
+
+#[async_trait]
+impl AbstractPolicyAcceptances for ReferenceDb {
+    /// Fetch the policy acceptance record of a user
+    async fn fetch_policy_acceptance(&self, user_id: &str) -> Result<PolicyAcceptance> {
+        let policy_acceptances = self.policy_acceptances.lock().await;
+        policy_acceptances
+            .get(user_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Record that a user accepted a version of a policy document
+    async fn accept_policy_document(
+        &self,
+        user_id: &str,
+        document_id: &str,
+        accepted: &AcceptedPolicy,
+    ) -> Result<()> {
+        let mut policy_acceptances = self.policy_acceptances.lock().await;
+        policy_acceptances
+            .entry(user_id.to_string())
+            .or_insert_with(|| PolicyAcceptance {
+                id: user_id.to_string(),
+                documents: HashMap::new(),
+            })
+            .documents
+            .insert(document_id.to_string(), accepted.clone());
+
+        Ok(())
+    }
+}
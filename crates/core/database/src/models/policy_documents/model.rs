@@ -0,0 +1,107 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::{Error, ErrorType, Result};
+
+use crate::Database;
+
+auto_derived!(
+    /// Terms of service or other policy users must accept to use this instance
+    pub struct PolicyDocument {
+        /// Document name
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Document title
+        pub title: String,
+        /// Current version, incremented every time the document is published
+        pub version: u32,
+        /// Document content
+        pub content: String,
+        /// Time at which the current version was published
+        pub published_at: Timestamp,
+    }
+);
+
+impl PolicyDocument {
+    /// Check whether a document name is well-formed
+    pub fn is_valid_id(id: &str) -> bool {
+        !id.is_empty()
+            && id.len() <= 32
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    /// Publish a new version of a document, creating it if it does not exist yet
+    pub async fn publish(
+        db: &Database,
+        id: &str,
+        title: String,
+        content: String,
+    ) -> Result<PolicyDocument> {
+        let version = match db.fetch_policy_document(id).await {
+            Ok(document) => document.version + 1,
+            Err(Error {
+                error_type: ErrorType::NotFound,
+                ..
+            }) => 1,
+            Err(error) => return Err(error),
+        };
+
+        let document = PolicyDocument {
+            id: id.to_string(),
+            title,
+            version,
+            content,
+            published_at: Timestamp::now_utc(),
+        };
+
+        db.upsert_policy_document(&document).await?;
+        Ok(document)
+    }
+
+    /// Remove this document, users no longer have to accept it
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_policy_document(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PolicyDocument;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let first = PolicyDocument::publish(
+                &db,
+                "terms",
+                "Terms of Service".to_string(),
+                "Be nice.".to_string(),
+            )
+            .await
+            .unwrap();
+
+            let second = PolicyDocument::publish(
+                &db,
+                "terms",
+                "Terms of Service".to_string(),
+                "Be very nice.".to_string(),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(1, first.version);
+            assert_eq!(2, second.version);
+
+            let fetched = db.fetch_policy_document("terms").await.unwrap();
+            assert_eq!(second, fetched);
+            assert_eq!(
+                vec![second.clone()],
+                db.fetch_policy_documents().await.unwrap()
+            );
+
+            second.delete(&db).await.unwrap();
+            assert!(db.fetch_policy_document("terms").await.is_err());
+            assert!(!PolicyDocument::is_valid_id("Not Valid"));
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::PolicyDocument;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractPolicyDocuments: Sync + Send {
+    /// Fetch a policy document by its id
+    async fn fetch_policy_document(&self, id: &str) -> Result<PolicyDocument>;
+
+    /// Fetch all policy documents
+    async fn fetch_policy_documents(&self) -> Result<Vec<PolicyDocument>>;
+
+    /// Insert or replace a policy document
+    async fn upsert_policy_document(&self, document: &PolicyDocument) -> Result<()>;
+
+    /// Delete a policy document by its id
+    async fn delete_policy_document(&self, id: &str) -> Result<()>;
+}
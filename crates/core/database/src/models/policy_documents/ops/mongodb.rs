@@ -0,0 +1,42 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::PolicyDocument;
+
+use super::AbstractPolicyDocuments;
+
+static COL: &str = "policy_documents";
+
+#[async_trait]
+impl AbstractPolicyDocuments for MongoDb {
+    /// Fetch a policy document by its id
+    async fn fetch_policy_document(&self, id: &str) -> Result<PolicyDocument> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all policy documents
+    async fn fetch_policy_documents(&self) -> Result<Vec<PolicyDocument>> {
+        query!(self, find, COL, doc! {})
+    }
+
+    /// Insert or replace a policy document
+    async fn upsert_policy_document(&self, document: &PolicyDocument) -> Result<()> {
+        self.col::<PolicyDocument>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &document.id
+                },
+                document,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+
+    /// Delete a policy document by its id
+    async fn delete_policy_document(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
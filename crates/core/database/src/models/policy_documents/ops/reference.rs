@@ -0,0 +1,41 @@
+use revolt_result::Result;
+
+use crate::PolicyDocument;
+use crate::ReferenceDb;
+
+use super::AbstractPolicyDocuments;
+
+#[async_trait]
+impl AbstractPolicyDocuments for ReferenceDb {
+    /// Fetch a policy document by its id
+    async fn fetch_policy_document(&self, id: &str) -> Result<PolicyDocument> {
+        let policy_documents = self.policy_documents.lock().await;
+        policy_documents
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all policy documents
+    async fn fetch_policy_documents(&self) -> Result<Vec<PolicyDocument>> {
+        let policy_documents = self.policy_documents.lock().await;
+        Ok(policy_documents.values().cloned().collect())
+    }
+
+    /// Insert or replace a policy document
+    async fn upsert_policy_document(&self, document: &PolicyDocument) -> Result<()> {
+        let mut policy_documents = self.policy_documents.lock().await;
+        policy_documents.insert(document.id.to_string(), document.clone());
+        Ok(())
+    }
+
+    /// Delete a policy document by its id
+    async fn delete_policy_document(&self, id: &str) -> Result<()> {
+        let mut policy_documents = self.policy_documents.lock().await;
+        if policy_documents.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Role granted to members who react to a message with a given emoji
+    pub struct ReactionRole {
+        /// Reaction role Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Server the role belongs to
+        pub server_id: String,
+        /// Channel the message was sent in
+        pub channel_id: String,
+        /// Message to watch for reactions
+        pub message_id: String,
+        /// Emoji which grants the role
+        pub emoji: String,
+        /// Role to grant
+        pub role_id: String,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ReactionRole {
+    /// Create a new reaction role
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_reaction_role(self).await
+    }
+
+    /// Fetch the reaction roles bound to an emoji on a message
+    pub async fn fetch_for_reaction(
+        db: &Database,
+        message_id: &str,
+        emoji: &str,
+    ) -> Result<Vec<ReactionRole>> {
+        Ok(db
+            .fetch_message_reaction_roles(message_id)
+            .await?
+            .into_iter()
+            .filter(|binding| binding.emoji == emoji)
+            .collect())
+    }
+
+    /// Delete this reaction role
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_reaction_role(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ReactionRole;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let binding = ReactionRole {
+                id: "binding".to_string(),
+                server_id: "server".to_string(),
+                channel_id: "channel".to_string(),
+                message_id: "message".to_string(),
+                emoji: "👍".to_string(),
+                role_id: "role".to_string(),
+            };
+
+            let other = ReactionRole {
+                id: "other".to_string(),
+                emoji: "👎".to_string(),
+                ..binding.clone()
+            };
+
+            binding.create(&db).await.unwrap();
+            other.create(&db).await.unwrap();
+
+            let fetched = db.fetch_reaction_role("binding").await.unwrap();
+            let fetched_all = db.fetch_reaction_roles("server").await.unwrap();
+            let fetched_message = db.fetch_message_reaction_roles("message").await.unwrap();
+            let fetched_reaction = ReactionRole::fetch_for_reaction(&db, "message", "👍")
+                .await
+                .unwrap();
+
+            assert_eq!(binding, fetched);
+            assert_eq!(2, fetched_all.len());
+            assert_eq!(2, fetched_message.len());
+            assert_eq!(vec![binding.clone()], fetched_reaction);
+
+            binding.delete(&db).await.unwrap();
+            assert!(db.fetch_reaction_role("binding").await.is_err());
+            assert_eq!(1, db.fetch_reaction_roles("server").await.unwrap().len());
+        });
+    }
+}
@@ -0,0 +1,24 @@
+use revolt_result::Result;
+
+use crate::ReactionRole;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractReactionRoles: Sync + Send {
+    /// Insert new reaction role into the database
+    async fn insert_reaction_role(&self, binding: &ReactionRole) -> Result<()>;
+
+    /// Fetch reaction role by id
+    async fn fetch_reaction_role(&self, id: &str) -> Result<ReactionRole>;
+
+    /// Fetch reaction roles for server
+    async fn fetch_reaction_roles(&self, server_id: &str) -> Result<Vec<ReactionRole>>;
+
+    /// Fetch reaction roles bound to a message
+    async fn fetch_message_reaction_roles(&self, message_id: &str) -> Result<Vec<ReactionRole>>;
+
+    /// Delete reaction role by id
+    async fn delete_reaction_role(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,50 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::ReactionRole;
+
+use super::AbstractReactionRoles;
+
+static COL: &str = "reaction_roles";
+
+#[async_trait]
+impl AbstractReactionRoles for MongoDb {
+    /// Insert new reaction role into the database
+    async fn insert_reaction_role(&self, binding: &ReactionRole) -> Result<()> {
+        query!(self, insert_one, COL, &binding).map(|_| ())
+    }
+
+    /// Fetch reaction role by id
+    async fn fetch_reaction_role(&self, id: &str) -> Result<ReactionRole> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch reaction roles for server
+    async fn fetch_reaction_roles(&self, server_id: &str) -> Result<Vec<ReactionRole>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server_id": server_id
+            }
+        )
+    }
+
+    /// Fetch reaction roles bound to a message
+    async fn fetch_message_reaction_roles(&self, message_id: &str) -> Result<Vec<ReactionRole>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "message_id": message_id
+            }
+        )
+    }
+
+    /// Delete reaction role by id
+    async fn delete_reaction_role(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,59 @@
+use revolt_result::Result;
+
+use crate::ReactionRole;
+use crate::ReferenceDb;
+
+use super::AbstractReactionRoles;
+
+#[async_trait]
+impl AbstractReactionRoles for ReferenceDb {
+    /// Insert new reaction role into the database
+    async fn insert_reaction_role(&self, binding: &ReactionRole) -> Result<()> {
+        let mut reaction_roles = self.reaction_roles.lock().await;
+        if reaction_roles.contains_key(&binding.id) {
+            Err(create_database_error!("insert", "reaction_roles"))
+        } else {
+            reaction_roles.insert(binding.id.to_string(), binding.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch reaction role by id
+    async fn fetch_reaction_role(&self, id: &str) -> Result<ReactionRole> {
+        let reaction_roles = self.reaction_roles.lock().await;
+        reaction_roles
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch reaction roles for server
+    async fn fetch_reaction_roles(&self, server_id: &str) -> Result<Vec<ReactionRole>> {
+        let reaction_roles = self.reaction_roles.lock().await;
+        Ok(reaction_roles
+            .values()
+            .filter(|binding| binding.server_id == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch reaction roles bound to a message
+    async fn fetch_message_reaction_roles(&self, message_id: &str) -> Result<Vec<ReactionRole>> {
+        let reaction_roles = self.reaction_roles.lock().await;
+        Ok(reaction_roles
+            .values()
+            .filter(|binding| binding.message_id == message_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Delete reaction role by id
+    async fn delete_reaction_role(&self, id: &str) -> Result<()> {
+        let mut reaction_roles = self.reaction_roles.lock().await;
+        if reaction_roles.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use revolt_permissions::{ChannelPermission, Override, OverrideField};
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Preset of permissions which can be applied to server roles
+    pub struct RoleTemplate {
+        /// Template Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Template name
+        pub name: String,
+        /// Description of what this template is for
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Permissions granted or denied by this template
+        pub permissions: OverrideField,
+    }
+);
+
+impl RoleTemplate {
+    /// Templates which are available on every instance
+    pub fn builtin() -> Vec<RoleTemplate> {
+        use ChannelPermission::*;
+
+        vec![
+            RoleTemplate {
+                id: "moderator".to_string(),
+                name: "Moderator".to_string(),
+                description: Some("Manage messages and members below this role.".to_string()),
+                permissions: Override {
+                    allow: ManageMessages
+                        + PinMessages
+                        + ManageThreads
+                        + KickMembers
+                        + BanMembers
+                        + TimeoutMembers
+                        + ManageNicknames
+                        + RemoveAvatars
+                        + MuteMembers
+                        + DeafenMembers
+                        + MoveMembers
+                        + PrioritySpeaker,
+                    deny: 0,
+                }
+                .into(),
+            },
+            RoleTemplate {
+                id: "muted".to_string(),
+                name: "Muted".to_string(),
+                description: Some("Prevent members from sending messages or speaking.".to_string()),
+                permissions: Override {
+                    allow: 0,
+                    deny: SendMessage
+                        + React
+                        + UploadFiles
+                        + SendEmbeds
+                        + Speak
+                        + Video
+                        + ScreenShare,
+                }
+                .into(),
+            },
+            RoleTemplate {
+                id: "read_only".to_string(),
+                name: "Read-only".to_string(),
+                description: Some("Allow members to read but not send messages.".to_string()),
+                permissions: Override {
+                    allow: ViewChannel + ReadMessageHistory,
+                    deny: SendMessage + React + UploadFiles + SendEmbeds + Masquerade,
+                }
+                .into(),
+            },
+        ]
+    }
+
+    /// Fetch a built-in or custom template by its id
+    pub async fn fetch(db: &Database, id: &str) -> Result<RoleTemplate> {
+        if let Some(template) = RoleTemplate::builtin()
+            .into_iter()
+            .find(|template| template.id == id)
+        {
+            Ok(template)
+        } else {
+            db.fetch_role_template(id).await
+        }
+    }
+
+    /// Fetch all built-in and custom templates
+    pub async fn fetch_all(db: &Database) -> Result<Vec<RoleTemplate>> {
+        let mut templates = RoleTemplate::builtin();
+        templates.append(&mut db.fetch_role_templates().await?);
+        Ok(templates)
+    }
+
+    /// Create a custom template
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        if RoleTemplate::builtin()
+            .iter()
+            .any(|template| template.id == self.id)
+        {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        db.insert_role_template(self).await
+    }
+
+    /// Delete a custom template
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_role_template(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RoleTemplate;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let template = RoleTemplate {
+                id: "template".to_string(),
+                name: "Template".to_string(),
+                description: None,
+                permissions: Default::default(),
+            };
+
+            template.create(&db).await.unwrap();
+
+            let fetched = RoleTemplate::fetch(&db, "template").await.unwrap();
+            assert_eq!(template, fetched);
+
+            let templates = RoleTemplate::fetch_all(&db).await.unwrap();
+            assert_eq!(RoleTemplate::builtin().len() + 1, templates.len());
+
+            assert!(RoleTemplate::fetch(&db, "moderator").await.is_ok());
+            assert!(RoleTemplate {
+                id: "muted".to_string(),
+                ..template.clone()
+            }
+            .create(&db)
+            .await
+            .is_err());
+
+            template.delete(&db).await.unwrap();
+            assert!(RoleTemplate::fetch(&db, "template").await.is_err());
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::RoleTemplate;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractRoleTemplates: Sync + Send {
+    /// Insert a new role template into the database
+    async fn insert_role_template(&self, template: &RoleTemplate) -> Result<()>;
+
+    /// Fetch a role template by its id
+    async fn fetch_role_template(&self, id: &str) -> Result<RoleTemplate>;
+
+    /// Fetch all custom role templates
+    async fn fetch_role_templates(&self) -> Result<Vec<RoleTemplate>>;
+
+    /// Delete a role template by its id
+    async fn delete_role_template(&self, id: &str) -> Result<()>;
+}
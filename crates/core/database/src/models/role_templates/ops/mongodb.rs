@@ -0,0 +1,31 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::RoleTemplate;
+
+use super::AbstractRoleTemplates;
+
+static COL: &str = "role_templates";
+
+#[async_trait]
+impl AbstractRoleTemplates for MongoDb {
+    /// Insert a new role template into the database
+    async fn insert_role_template(&self, template: &RoleTemplate) -> Result<()> {
+        query!(self, insert_one, COL, &template).map(|_| ())
+    }
+
+    /// Fetch a role template by its id
+    async fn fetch_role_template(&self, id: &str) -> Result<RoleTemplate> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all custom role templates
+    async fn fetch_role_templates(&self) -> Result<Vec<RoleTemplate>> {
+        query!(self, find, COL, doc! {})
+    }
+
+    /// Delete a role template by its id
+    async fn delete_role_template(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
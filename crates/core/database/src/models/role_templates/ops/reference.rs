@@ -0,0 +1,45 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::RoleTemplate;
+
+use super::AbstractRoleTemplates;
+
+#[async_trait]
+impl AbstractRoleTemplates for ReferenceDb {
+    /// Insert a new role template into the database
+    async fn insert_role_template(&self, template: &RoleTemplate) -> Result<()> {
+        let mut role_templates = self.role_templates.lock().await;
+        if role_templates.contains_key(&template.id) {
+            Err(create_database_error!("insert", "role_templates"))
+        } else {
+            role_templates.insert(template.id.to_string(), template.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a role template by its id
+    async fn fetch_role_template(&self, id: &str) -> Result<RoleTemplate> {
+        let role_templates = self.role_templates.lock().await;
+        role_templates
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all custom role templates
+    async fn fetch_role_templates(&self) -> Result<Vec<RoleTemplate>> {
+        let role_templates = self.role_templates.lock().await;
+        Ok(role_templates.values().cloned().collect())
+    }
+
+    /// Delete a role template by its id
+    async fn delete_role_template(&self, id: &str) -> Result<()> {
+        let mut role_templates = self.role_templates.lock().await;
+        if role_templates.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::DataMessageSend;
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Message waiting to be sent at a later time
+    pub struct ScheduledMessage {
+        /// Scheduled Message Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// The channel to send in
+        pub channel_id: String,
+        /// The user who scheduled this message
+        pub author_id: String,
+
+        /// Time at which the message should be sent
+        pub send_at: Timestamp,
+        /// Message to send
+        pub message: DataMessageSend,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ScheduledMessage {
+    /// Create a new scheduled message
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_scheduled_message(self).await
+    }
+
+    /// Check whether this message should be sent
+    pub fn is_due(&self) -> bool {
+        *self.send_at <= *Timestamp::now_utc()
+    }
+
+    /// Delete this scheduled message
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_scheduled_message(&self.id).await
+    }
+
+    /// Take this message off the schedule so that only one node sends it
+    ///
+    /// Returns false if another node already claimed it.
+    pub async fn claim(&self, db: &Database) -> Result<bool> {
+        db.claim_scheduled_message(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+    use revolt_models::v0::DataMessageSend;
+
+    use crate::ScheduledMessage;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let channel_id = "channel";
+
+            let due = ScheduledMessage {
+                id: "due".to_string(),
+                channel_id: channel_id.to_string(),
+                author_id: "user".to_string(),
+                send_at: Timestamp::now_utc(),
+                message: DataMessageSend {
+                    nonce: None,
+                    content: Some("Hello!".to_string()),
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade: None,
+                    interactions: None,
+                    effect: None,
+                    thread: None,
+                    send_at: None,
+                },
+            };
+
+            let later = ScheduledMessage {
+                id: "later".to_string(),
+                send_at: Timestamp::now_utc()
+                    .checked_add(Duration::hours(1))
+                    .unwrap(),
+                ..due.clone()
+            };
+
+            due.create(&db).await.unwrap();
+            later.create(&db).await.unwrap();
+            assert!(due.is_due());
+            assert!(!later.is_due());
+
+            let fetched = db.fetch_scheduled_message("due").await.unwrap();
+            let fetched_all = db.fetch_scheduled_messages(channel_id).await.unwrap();
+            let fetched_due = db
+                .fetch_due_scheduled_messages(Timestamp::now_utc())
+                .await
+                .unwrap();
+
+            assert_eq!(due, fetched);
+            assert_eq!(2, fetched_all.len());
+            assert_eq!(vec![due.clone()], fetched_due);
+
+            due.delete(&db).await.unwrap();
+            assert!(db.fetch_scheduled_message("due").await.is_err());
+            assert_eq!(
+                1,
+                db.fetch_scheduled_messages(channel_id).await.unwrap().len()
+            );
+
+            assert!(later.claim(&db).await.unwrap());
+            assert!(!later.claim(&db).await.unwrap());
+        });
+    }
+}
@@ -0,0 +1,31 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ScheduledMessage;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractScheduledMessages: Sync + Send {
+    /// Insert new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()>;
+
+    /// Fetch scheduled message by id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage>;
+
+    /// Fetch scheduled messages for channel
+    async fn fetch_scheduled_messages(&self, channel_id: &str) -> Result<Vec<ScheduledMessage>>;
+
+    /// Fetch scheduled messages due at or before the given time
+    async fn fetch_due_scheduled_messages(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ScheduledMessage>>;
+
+    /// Delete scheduled message by id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()>;
+
+    /// Remove scheduled message by id, returning whether this call removed it
+    async fn claim_scheduled_message(&self, id: &str) -> Result<bool>;
+}
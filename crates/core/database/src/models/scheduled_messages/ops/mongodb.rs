@@ -0,0 +1,62 @@
+use bson::to_bson;
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::ScheduledMessage;
+
+use super::AbstractScheduledMessages;
+
+static COL: &str = "scheduled_messages";
+
+#[async_trait]
+impl AbstractScheduledMessages for MongoDb {
+    /// Insert new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()> {
+        query!(self, insert_one, COL, &message).map(|_| ())
+    }
+
+    /// Fetch scheduled message by id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled messages for channel
+    async fn fetch_scheduled_messages(&self, channel_id: &str) -> Result<Vec<ScheduledMessage>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "channel_id": channel_id
+            }
+        )
+    }
+
+    /// Fetch scheduled messages due at or before the given time
+    async fn fetch_due_scheduled_messages(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ScheduledMessage>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "send_at": {
+                    "$lte": to_bson(&before).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Delete scheduled message by id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Remove scheduled message by id, returning whether this call removed it
+    async fn claim_scheduled_message(&self, id: &str) -> Result<bool> {
+        query!(self, delete_one_by_id, COL, id).map(|result| result.deleted_count == 1)
+    }
+}
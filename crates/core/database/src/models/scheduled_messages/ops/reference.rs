@@ -0,0 +1,69 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::ScheduledMessage;
+
+use super::AbstractScheduledMessages;
+
+#[async_trait]
+impl AbstractScheduledMessages for ReferenceDb {
+    /// Insert new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()> {
+        let mut messages = self.scheduled_messages.lock().await;
+        if messages.contains_key(&message.id) {
+            Err(create_database_error!("insert", "scheduled_messages"))
+        } else {
+            messages.insert(message.id.to_string(), message.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch scheduled message by id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage> {
+        let messages = self.scheduled_messages.lock().await;
+        messages
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled messages for channel
+    async fn fetch_scheduled_messages(&self, channel_id: &str) -> Result<Vec<ScheduledMessage>> {
+        let messages = self.scheduled_messages.lock().await;
+        Ok(messages
+            .values()
+            .filter(|message| message.channel_id == channel_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch scheduled messages due at or before the given time
+    async fn fetch_due_scheduled_messages(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ScheduledMessage>> {
+        let messages = self.scheduled_messages.lock().await;
+        Ok(messages
+            .values()
+            .filter(|message| *message.send_at <= *before)
+            .cloned()
+            .collect())
+    }
+
+    /// Delete scheduled message by id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()> {
+        let mut messages = self.scheduled_messages.lock().await;
+        if messages.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove scheduled message by id, returning whether this call removed it
+    async fn claim_scheduled_message(&self, id: &str) -> Result<bool> {
+        let mut messages = self.scheduled_messages.lock().await;
+        Ok(messages.remove(id).is_some())
+    }
+}
@@ -0,0 +1,193 @@
+use iso8601_timestamp::{Duration, Timestamp};
+use revolt_result::Result;
+
+use crate::Database;
+
+auto_derived!(
+    /// Announcement posted to a server channel at a scheduled time
+    pub struct ServerAnnouncement {
+        /// Announcement Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// The server this announcement belongs to
+        pub server_id: String,
+        /// The channel to post in
+        pub channel_id: String,
+        /// The user who scheduled this announcement
+        pub creator_id: String,
+
+        /// Content of the announcement
+        pub content: String,
+        /// Time at which the announcement should next be posted
+        pub next_run: Timestamp,
+        /// How often the announcement repeats
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub recurrence: Option<AnnouncementRecurrence>,
+    }
+
+    /// Repetition rule for an announcement
+    pub struct AnnouncementRecurrence {
+        /// Number of units between each post
+        pub every: u32,
+        /// Unit of time to repeat by
+        pub unit: RecurrenceUnit,
+    }
+
+    /// Unit of time used by a recurrence
+    pub enum RecurrenceUnit {
+        Hour,
+        Day,
+        Week,
+    }
+);
+
+impl AnnouncementRecurrence {
+    /// Time between each post
+    pub fn interval(&self) -> Duration {
+        let every = self.every as i64;
+        match self.unit {
+            RecurrenceUnit::Hour => Duration::hours(every),
+            RecurrenceUnit::Day => Duration::days(every),
+            RecurrenceUnit::Week => Duration::weeks(every),
+        }
+    }
+
+    /// Find the first run strictly after the given time
+    ///
+    /// Runs missed while the scheduler was not running are skipped.
+    pub fn next_after(&self, previous: Timestamp, now: Timestamp) -> Option<Timestamp> {
+        let interval = self.interval();
+        if interval <= Duration::ZERO {
+            return None;
+        }
+
+        let mut next = previous;
+        while *next <= *now {
+            next = next.checked_add(interval)?;
+        }
+
+        Some(next)
+    }
+}
+
+#[allow(clippy::disallowed_methods)]
+impl ServerAnnouncement {
+    /// Create a new scheduled announcement
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_server_announcement(self).await
+    }
+
+    /// Check whether this announcement should be posted
+    pub fn is_due(&self) -> bool {
+        *self.next_run <= *Timestamp::now_utc()
+    }
+
+    /// Move this announcement to its next run, deleting it if it does not repeat
+    ///
+    /// Returns false if another node already advanced it from this run.
+    pub async fn advance(&mut self, db: &Database) -> Result<bool> {
+        let next = self
+            .recurrence
+            .as_ref()
+            .and_then(|recurrence| recurrence.next_after(self.next_run, Timestamp::now_utc()));
+
+        let advanced = db
+            .advance_server_announcement(&self.id, self.next_run, next)
+            .await?;
+
+        if let Some(next) = next {
+            self.next_run = next;
+        }
+
+        Ok(advanced)
+    }
+
+    /// Delete this scheduled announcement
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_server_announcement(&self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+
+    use crate::{AnnouncementRecurrence, RecurrenceUnit, ServerAnnouncement};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let server_id = "server";
+
+            let once = ServerAnnouncement {
+                id: "once".to_string(),
+                server_id: server_id.to_string(),
+                channel_id: "channel".to_string(),
+                creator_id: "user".to_string(),
+                content: "Hello".to_string(),
+                next_run: Timestamp::now_utc(),
+                recurrence: None,
+            };
+
+            let mut daily = ServerAnnouncement {
+                id: "daily".to_string(),
+                recurrence: Some(AnnouncementRecurrence {
+                    every: 1,
+                    unit: RecurrenceUnit::Day,
+                }),
+                ..once.clone()
+            };
+
+            once.create(&db).await.unwrap();
+            daily.create(&db).await.unwrap();
+            assert!(once.is_due());
+
+            let fetched = db.fetch_server_announcement("once").await.unwrap();
+            let fetched_all = db.fetch_server_announcements(server_id).await.unwrap();
+            let fetched_due = db
+                .fetch_due_server_announcements(Timestamp::now_utc())
+                .await
+                .unwrap();
+
+            assert_eq!(once, fetched);
+            assert_eq!(2, fetched_all.len());
+            assert_eq!(2, fetched_due.len());
+
+            let mut stale = daily.clone();
+            assert!(daily.advance(&db).await.unwrap());
+            assert!(!daily.is_due());
+            assert_eq!(daily, db.fetch_server_announcement("daily").await.unwrap());
+            assert!(!stale.advance(&db).await.unwrap());
+
+            let mut once = fetched;
+            assert!(once.clone().advance(&db).await.unwrap());
+            assert!(!once.advance(&db).await.unwrap());
+            assert!(db.fetch_server_announcement("once").await.is_err());
+            assert!(db
+                .fetch_due_server_announcements(Timestamp::now_utc())
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn next_after_skips_missed_runs() {
+        let now = Timestamp::now_utc();
+        let previous = now.checked_sub(Duration::hours(5)).unwrap();
+        let recurrence = AnnouncementRecurrence {
+            every: 2,
+            unit: RecurrenceUnit::Hour,
+        };
+
+        let next = recurrence.next_after(previous, now).unwrap();
+        assert_eq!(*next, *previous.checked_add(Duration::hours(6)).unwrap());
+
+        let never = AnnouncementRecurrence {
+            every: 0,
+            unit: RecurrenceUnit::Week,
+        };
+
+        assert!(never.next_after(previous, now).is_none());
+    }
+}
@@ -0,0 +1,38 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ServerAnnouncement;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerAnnouncements: Sync + Send {
+    /// Insert new scheduled announcement into the database
+    async fn insert_server_announcement(&self, announcement: &ServerAnnouncement) -> Result<()>;
+
+    /// Fetch scheduled announcement by id
+    async fn fetch_server_announcement(&self, id: &str) -> Result<ServerAnnouncement>;
+
+    /// Fetch scheduled announcements for server
+    async fn fetch_server_announcements(&self, server_id: &str) -> Result<Vec<ServerAnnouncement>>;
+
+    /// Fetch scheduled announcements due at or before the given time
+    async fn fetch_due_server_announcements(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ServerAnnouncement>>;
+
+    /// Move a scheduled announcement from its previous run to its next run,
+    /// deleting it if there is none, returning whether it was still at its
+    /// previous run before this call
+    async fn advance_server_announcement(
+        &self,
+        id: &str,
+        previous: Timestamp,
+        next_run: Option<Timestamp>,
+    ) -> Result<bool>;
+
+    /// Delete scheduled announcement by id
+    async fn delete_server_announcement(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,90 @@
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::ServerAnnouncement;
+
+use super::AbstractServerAnnouncements;
+
+static COL: &str = "server_announcements";
+
+#[async_trait]
+impl AbstractServerAnnouncements for MongoDb {
+    /// Insert new scheduled announcement into the database
+    async fn insert_server_announcement(&self, announcement: &ServerAnnouncement) -> Result<()> {
+        query!(self, insert_one, COL, &announcement).map(|_| ())
+    }
+
+    /// Fetch scheduled announcement by id
+    async fn fetch_server_announcement(&self, id: &str) -> Result<ServerAnnouncement> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled announcements for server
+    async fn fetch_server_announcements(&self, server_id: &str) -> Result<Vec<ServerAnnouncement>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server_id": server_id
+            }
+        )
+    }
+
+    /// Fetch scheduled announcements due at or before the given time
+    async fn fetch_due_server_announcements(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ServerAnnouncement>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "next_run": {
+                    "$lte": to_bson(&before).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Move a scheduled announcement from its previous run to its next run,
+    /// deleting it if there is none, returning whether it was still at its
+    /// previous run before this call
+    async fn advance_server_announcement(
+        &self,
+        id: &str,
+        previous: Timestamp,
+        next_run: Option<Timestamp>,
+    ) -> Result<bool> {
+        let filter = doc! {
+            "_id": id,
+            "next_run": to_bson(&previous).unwrap()
+        };
+
+        if let Some(next_run) = next_run {
+            self.col::<Document>(COL)
+                .update_one(
+                    filter,
+                    doc! {
+                        "$set": {
+                            "next_run": to_bson(&next_run).unwrap()
+                        }
+                    },
+                    None,
+                )
+                .await
+                .map(|result| result.modified_count == 1)
+                .map_err(|_| create_database_error!("update_one", COL))
+        } else {
+            query!(self, delete_one, COL, filter).map(|result| result.deleted_count == 1)
+        }
+    }
+
+    /// Delete scheduled announcement by id
+    async fn delete_server_announcement(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
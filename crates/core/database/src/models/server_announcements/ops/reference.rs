@@ -0,0 +1,89 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::ServerAnnouncement;
+
+use super::AbstractServerAnnouncements;
+
+#[async_trait]
+impl AbstractServerAnnouncements for ReferenceDb {
+    /// Insert new scheduled announcement into the database
+    async fn insert_server_announcement(&self, announcement: &ServerAnnouncement) -> Result<()> {
+        let mut announcements = self.server_announcements.lock().await;
+        if announcements.contains_key(&announcement.id) {
+            Err(create_database_error!("insert", "server_announcements"))
+        } else {
+            announcements.insert(announcement.id.to_string(), announcement.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch scheduled announcement by id
+    async fn fetch_server_announcement(&self, id: &str) -> Result<ServerAnnouncement> {
+        let announcements = self.server_announcements.lock().await;
+        announcements
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled announcements for server
+    async fn fetch_server_announcements(&self, server_id: &str) -> Result<Vec<ServerAnnouncement>> {
+        let announcements = self.server_announcements.lock().await;
+        Ok(announcements
+            .values()
+            .filter(|announcement| announcement.server_id == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch scheduled announcements due at or before the given time
+    async fn fetch_due_server_announcements(
+        &self,
+        before: Timestamp,
+    ) -> Result<Vec<ServerAnnouncement>> {
+        let announcements = self.server_announcements.lock().await;
+        Ok(announcements
+            .values()
+            .filter(|announcement| *announcement.next_run <= *before)
+            .cloned()
+            .collect())
+    }
+
+    /// Move a scheduled announcement from its previous run to its next run,
+    /// deleting it if there is none, returning whether it was still at its
+    /// previous run before this call
+    async fn advance_server_announcement(
+        &self,
+        id: &str,
+        previous: Timestamp,
+        next_run: Option<Timestamp>,
+    ) -> Result<bool> {
+        let mut announcements = self.server_announcements.lock().await;
+        if !announcements
+            .get(id)
+            .is_some_and(|announcement| *announcement.next_run == *previous)
+        {
+            return Ok(false);
+        }
+
+        if let Some(next_run) = next_run {
+            announcements.get_mut(id).unwrap().next_run = next_run;
+        } else {
+            announcements.remove(id);
+        }
+
+        Ok(true)
+    }
+
+    /// Delete scheduled announcement by id
+    async fn delete_server_announcement(&self, id: &str) -> Result<()> {
+        let mut announcements = self.server_announcements.lock().await;
+        if announcements.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
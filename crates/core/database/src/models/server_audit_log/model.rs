@@ -0,0 +1,205 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+use serde::Serialize;
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Record of a moderation-relevant change made to a server
+    pub struct AuditLogEntry {
+        /// Entry Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Server this change was made in
+        pub server_id: String,
+        /// Id of the user who made the change
+        pub actor_id: String,
+        /// Action which was performed
+        pub action: AuditLogAction,
+        /// Id of the object that was changed
+        pub target_id: String,
+        /// JSON representation of the object before the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<String>,
+        /// JSON representation of the object after the change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<String>,
+        /// Time at which the change was made
+        pub timestamp: Timestamp,
+    }
+
+    /// Action recorded in a server audit log
+    pub enum AuditLogAction {
+        /// Role was created
+        RoleCreate,
+        /// Role was edited
+        RoleUpdate,
+        /// Role was deleted
+        RoleDelete,
+        /// Member was kicked
+        MemberKick,
+        /// Member was banned
+        MemberBan,
+        /// Member was unbanned
+        MemberUnban,
+        /// Channel was deleted
+        ChannelDelete,
+        /// Webhook was created
+        WebhookCreate,
+        /// Webhook was edited
+        WebhookUpdate,
+        /// Webhook was deleted
+        WebhookDelete,
+    }
+);
+
+/// Filter applied when fetching audit log entries, newest first
+#[derive(Debug, Default)]
+pub struct AuditLogQuery {
+    /// Only include changes made by this user
+    pub actor_id: Option<String>,
+    /// Only include this kind of change
+    pub action: Option<AuditLogAction>,
+    /// Only include entries with an id lower than this
+    pub before: Option<String>,
+    /// Only include entries with an id higher than this
+    pub after: Option<String>,
+    /// Maximum number of entries to fetch
+    pub limit: i64,
+}
+
+impl AuditLogEntry {
+    /// Append a new entry to a server's audit log
+    pub async fn record<T: Serialize>(
+        db: &Database,
+        server_id: &str,
+        actor_id: &str,
+        action: AuditLogAction,
+        target_id: &str,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<AuditLogEntry> {
+        let entry = AuditLogEntry {
+            id: Ulid::new().to_string(),
+            server_id: server_id.to_string(),
+            actor_id: actor_id.to_string(),
+            action,
+            target_id: target_id.to_string(),
+            before: before
+                .map(serde_json::to_string)
+                .transpose()
+                .map_err(|_| create_error!(InternalError))?,
+            after: after
+                .map(serde_json::to_string)
+                .transpose()
+                .map_err(|_| create_error!(InternalError))?,
+            timestamp: Timestamp::now_utc(),
+        };
+
+        db.insert_audit_log_entry(&entry).await?;
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AuditLogAction, AuditLogEntry, AuditLogQuery};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let first = AuditLogEntry::record(
+                &db,
+                "server",
+                "actor",
+                AuditLogAction::RoleCreate,
+                "role",
+                None,
+                Some(&1),
+            )
+            .await
+            .unwrap();
+
+            let second = AuditLogEntry::record(
+                &db,
+                "server",
+                "other",
+                AuditLogAction::RoleUpdate,
+                "role",
+                Some(&1),
+                Some(&2),
+            )
+            .await
+            .unwrap();
+
+            AuditLogEntry::record::<()>(
+                &db,
+                "elsewhere",
+                "actor",
+                AuditLogAction::MemberKick,
+                "user",
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+            let entries = db
+                .fetch_audit_log_entries(
+                    "server",
+                    &AuditLogQuery {
+                        limit: 10,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(entries, vec![second.clone(), first.clone()]);
+
+            let entries = db
+                .fetch_audit_log_entries(
+                    "server",
+                    &AuditLogQuery {
+                        actor_id: Some("actor".to_string()),
+                        limit: 10,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(entries, vec![first.clone()]);
+
+            let entries = db
+                .fetch_audit_log_entries(
+                    "server",
+                    &AuditLogQuery {
+                        action: Some(AuditLogAction::RoleUpdate),
+                        before: Some(second.id.clone()),
+                        limit: 10,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert!(entries.is_empty());
+
+            let entries = db
+                .fetch_audit_log_entries(
+                    "server",
+                    &AuditLogQuery {
+                        after: Some(first.id.clone()),
+                        limit: 10,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(entries, vec![second]);
+        });
+    }
+}
@@ -0,0 +1,19 @@
+use revolt_result::Result;
+
+use crate::{AuditLogEntry, AuditLogQuery};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerAuditLog: Sync + Send {
+    /// Insert a new entry into a server's audit log
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()>;
+
+    /// Fetch entries from a server's audit log matching a query, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: &AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>>;
+}
@@ -0,0 +1,61 @@
+use ::mongodb::options::FindOptions;
+use bson::{to_bson, Document};
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractServerAuditLog;
+
+static COL: &str = "server_audit_log";
+
+#[async_trait]
+impl AbstractServerAuditLog for MongoDb {
+    /// Insert a new entry into a server's audit log
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        query!(self, insert_one, COL, &entry).map(|_| ())
+    }
+
+    /// Fetch entries from a server's audit log matching a query, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: &AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let mut filter = doc! {
+            "server_id": server_id
+        };
+
+        if let Some(actor_id) = &query.actor_id {
+            filter.insert("actor_id", actor_id);
+        }
+
+        if let Some(action) = &query.action {
+            filter.insert("action", to_bson(action).unwrap());
+        }
+
+        let mut id = Document::new();
+        if let Some(before) = &query.before {
+            id.insert("$lt", before);
+        }
+
+        if let Some(after) = &query.after {
+            id.insert("$gt", after);
+        }
+
+        if !id.is_empty() {
+            filter.insert("_id", id);
+        }
+
+        query!(
+            self,
+            find_with_options,
+            COL,
+            filter,
+            FindOptions::builder()
+                .sort(doc! { "_id": -1_i32 })
+                .limit(query.limit)
+                .build()
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractServerAuditLog;
+
+#[async_trait]
+impl AbstractServerAuditLog for ReferenceDb {
+    /// Insert a new entry into a server's audit log
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        let mut server_audit_log = self.server_audit_log.lock().await;
+        if server_audit_log.contains_key(&entry.id) {
+            Err(create_database_error!("insert", "server_audit_log"))
+        } else {
+            server_audit_log.insert(entry.id.to_string(), entry.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch entries from a server's audit log matching a query, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: &AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let server_audit_log = self.server_audit_log.lock().await;
+        let mut entries: Vec<AuditLogEntry> = server_audit_log
+            .values()
+            .filter(|entry| {
+                entry.server_id == server_id
+                    && query
+                        .actor_id
+                        .as_ref()
+                        .map_or(true, |actor_id| &entry.actor_id == actor_id)
+                    && query
+                        .action
+                        .as_ref()
+                        .map_or(true, |action| &entry.action == action)
+                    && query
+                        .before
+                        .as_ref()
+                        .map_or(true, |before| &entry.id < before)
+                    && query.after.as_ref().map_or(true, |after| &entry.id > after)
+            })
+            .cloned()
+            .collect();
+
+        entries.sort_by(|a, b| b.id.cmp(&a.id));
+        entries.truncate(query.limit as usize);
+        Ok(entries)
+    }
+}
@@ -1,7 +1,7 @@
 use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
-use crate::{Database, File, Server};
+use crate::{Database, File, NotificationMode, Server};
 
 auto_derived_partial!(
     /// Server Member
@@ -27,6 +27,25 @@ auto_derived_partial!(
         /// Timestamp this member is timed out until
         #[serde(skip_serializing_if = "Option::is_none")]
         pub timeout: Option<Timestamp>,
+
+        /// Whether this member has been muted in voice channels by a moderator
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub voice_muted: bool,
+        /// Whether this member has been deafened in voice channels by a moderator
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub voice_deafened: bool,
+
+        /// Which messages this member is notified about
+        #[serde(default)]
+        pub notifications: NotificationMode,
+        /// Channels this member is notified about every message in
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub followed_channels: Vec<String>,
+        /// Onboarding options picked by this member
+        ///
+        /// Not present if this member has not completed onboarding.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub onboarding: Option<Vec<String>>,
     },
     "PartialMember"
 );
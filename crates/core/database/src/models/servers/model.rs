@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use revolt_models::v0::ServerFlagsValue;
 use revolt_permissions::OverrideField;
 use revolt_result::Result;
 use ulid::Ulid;
@@ -30,6 +31,15 @@ auto_derived_partial!(
         /// Configuration for sending system event messages
         #[serde(skip_serializing_if = "Option::is_none")]
         pub system_messages: Option<SystemMessageChannels>,
+        /// Onboarding sequence for new members
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub onboarding: Option<Onboarding>,
+        /// Configuration for highlighting popular messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub highlights: Option<Highlights>,
+        /// Timeouts applied automatically when members are warned
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub warn_thresholds: Option<Vec<WarnThreshold>>,
 
         /// Roles for this server
         #[serde(
@@ -49,7 +59,10 @@ auto_derived_partial!(
 
         /// Bitfield of server flags
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub flags: Option<i32>,
+        pub flags: Option<ServerFlagsValue>,
+        /// Storage region files sent in this server must be uploaded to
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub storage_region: Option<String>,
 
         /// Whether this server is flagged as not safe for work
         #[serde(skip_serializing_if = "crate::if_false", default)]
@@ -60,6 +73,10 @@ auto_derived_partial!(
         /// Whether this server should be publicly discoverable
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub discoverable: bool,
+
+        /// Notification mode given to new members
+        #[serde(default)]
+        pub default_notifications: NotificationMode,
     },
     "PartialServer"
 );
@@ -82,6 +99,17 @@ auto_derived_partial!(
         /// Ranking of this role
         #[serde(default)]
         pub rank: i64,
+        /// Whether anyone may mention this role
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub mentionable: bool,
+        /// Whether members may give themselves this role
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub self_assignable: bool,
+        /// Position of this role in the self-assignable role menu
+        ///
+        /// Smaller values are shown first.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub menu_position: Option<i64>,
     },
     "PartialRole"
 );
@@ -95,6 +123,70 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+        /// Whether this category is shown collapsed to members by default
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub collapsed: bool,
+        /// Whether channels in this category are hidden until a role grants access to them
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub hidden: bool,
+    }
+
+    /// Option which may be picked when answering an onboarding question
+    pub struct OnboardingOption {
+        /// Unique ID for this option
+        pub id: String,
+        /// Title for this option
+        pub title: String,
+        /// Roles granted to members who pick this option
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub roles: Vec<String>,
+        /// Channels revealed to members who pick this option
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub channels: Vec<String>,
+    }
+
+    /// Question asked to members during onboarding
+    pub struct OnboardingQuestion {
+        /// Unique ID for this question
+        pub id: String,
+        /// Title for this question
+        pub title: String,
+        /// Whether members must pick at least one option
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub required: bool,
+        /// Whether members may pick more than one option
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub multiple: bool,
+        /// Options available for this question
+        pub options: Vec<OnboardingOption>,
+    }
+
+    /// Onboarding sequence shown to new members
+    pub struct Onboarding {
+        /// Whether members must complete onboarding before participating
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub enabled: bool,
+        /// Questions asked during onboarding
+        pub questions: Vec<OnboardingQuestion>,
+    }
+
+    /// Configuration for highlighting popular messages in a channel
+    pub struct Highlights {
+        /// ID of channel to post highlighted messages in
+        pub channel: String,
+        /// Number of reactions a message needs to be highlighted
+        pub threshold: usize,
+        /// Only count reactions using this emoji
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub emoji: Option<String>,
+    }
+
+    /// Timeout applied automatically once a member accumulates warnings
+    pub struct WarnThreshold {
+        /// Number of warnings at which the timeout is applied
+        pub warnings: u32,
+        /// Length of the timeout in seconds
+        pub timeout: u64,
     }
 
     /// System message channel assignments
@@ -113,6 +205,16 @@ auto_derived!(
         pub user_banned: Option<String>,
     }
 
+    /// Which messages members are notified about by default
+    #[derive(Copy, Default)]
+    pub enum NotificationMode {
+        /// Notify about every message
+        All,
+        /// Only notify about mentions
+        #[default]
+        Mentions,
+    }
+
     /// Optional fields on server object
     pub enum FieldsServer {
         Description,
@@ -120,11 +222,16 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        Onboarding,
+        Highlights,
+        WarnThresholds,
+        StorageRegion,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        MenuPosition,
     }
 );
 
@@ -179,6 +286,10 @@ impl Server {
             FieldsServer::SystemMessages => self.system_messages = None,
             FieldsServer::Icon => self.icon = None,
             FieldsServer::Banner => self.banner = None,
+            FieldsServer::Onboarding => self.onboarding = None,
+            FieldsServer::Highlights => self.highlights = None,
+            FieldsServer::WarnThresholds => self.warn_thresholds = None,
+            FieldsServer::StorageRegion => self.storage_region = None,
         }
     }
 
@@ -229,6 +340,11 @@ impl Server {
             avatar: None,
             roles: vec![],
             timeout: None,
+            voice_muted: false,
+            voice_deafened: false,
+            notifications: self.default_notifications,
+            followed_channels: vec![],
+            onboarding: None,
         };
 
         db.insert_member(&member).await?;
@@ -356,6 +472,9 @@ impl Role {
             colour: self.colour,
             hoist: Some(self.hoist),
             rank: Some(self.rank),
+            mentionable: Some(self.mentionable),
+            self_assignable: Some(self.self_assignable),
+            menu_position: self.menu_position,
         }
     }
 
@@ -410,6 +529,7 @@ impl Role {
     pub fn remove_field(&mut self, field: &FieldsRole) {
         match field {
             FieldsRole::Colour => self.colour = None,
+            FieldsRole::MenuPosition => self.menu_position = None,
         }
     }
 
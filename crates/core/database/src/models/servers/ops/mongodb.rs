@@ -144,6 +144,22 @@ impl AbstractServers for MongoDb {
             .await
             .map_err(|_| create_database_error!("update_one", "channels"))?;
 
+        self.col::<Document>("channels")
+            .update_many(
+                doc! {
+                    "server": server_id,
+                    "posting_role": role_id
+                },
+                doc! {
+                    "$unset": {
+                        "posting_role": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("update_many", "channels"))?;
+
         self.col::<Document>("servers")
             .update_one(
                 doc! {
@@ -169,6 +185,10 @@ impl IntoDocumentPath for FieldsServer {
             FieldsServer::Categories => "categories",
             FieldsServer::Description => "description",
             FieldsServer::Icon => "icon",
+            FieldsServer::Onboarding => "onboarding",
+            FieldsServer::Highlights => "highlights",
+            FieldsServer::WarnThresholds => "warn_thresholds",
+            FieldsServer::StorageRegion => "storage_region",
             FieldsServer::SystemMessages => "system_messages",
         })
     }
@@ -178,6 +198,7 @@ impl IntoDocumentPath for FieldsRole {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsRole::Colour => "colour",
+            FieldsRole::MenuPosition => "menu_position",
         })
     }
 }
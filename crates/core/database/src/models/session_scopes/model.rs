@@ -0,0 +1,57 @@
+auto_derived!(
+    /// Restrictions placed on a single session
+    pub struct SessionScopes {
+        /// Session Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the user this session belongs to
+        pub user_id: String,
+        /// Restrictions placed on the session
+        pub scopes: Vec<SessionScope>,
+    }
+
+    /// Restriction placed on what a session may do
+    pub enum SessionScope {
+        /// Session may only fetch data and cannot make changes
+        ReadOnly,
+        /// Session cannot access direct messages or groups
+        NoDirectMessages,
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{SessionScope, SessionScopes};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let first = SessionScopes {
+                id: "first".to_string(),
+                user_id: "user".to_string(),
+                scopes: vec![SessionScope::ReadOnly],
+            };
+
+            let second = SessionScopes {
+                id: "second".to_string(),
+                user_id: "user".to_string(),
+                scopes: vec![SessionScope::NoDirectMessages],
+            };
+
+            db.insert_session_scopes(&first).await.unwrap();
+            db.insert_session_scopes(&second).await.unwrap();
+            assert_eq!(db.fetch_session_scopes("first").await.unwrap(), first);
+
+            db.delete_session_scopes("first").await.unwrap();
+            assert!(db.fetch_session_scopes("first").await.is_err());
+
+            db.delete_user_session_scopes("user", Some("second"))
+                .await
+                .unwrap();
+            assert_eq!(db.fetch_session_scopes("second").await.unwrap(), second);
+
+            db.delete_user_session_scopes("user", None).await.unwrap();
+            assert!(db.fetch_session_scopes("second").await.is_err());
+        });
+    }
+}
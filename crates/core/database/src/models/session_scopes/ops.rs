@@ -0,0 +1,25 @@
+use revolt_result::Result;
+
+use crate::SessionScopes;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractSessionScopes: Sync + Send {
+    /// Insert restrictions for a session
+    async fn insert_session_scopes(&self, scopes: &SessionScopes) -> Result<()>;
+
+    /// Fetch the restrictions placed on a session
+    async fn fetch_session_scopes(&self, session_id: &str) -> Result<SessionScopes>;
+
+    /// Delete the restrictions placed on a session
+    async fn delete_session_scopes(&self, session_id: &str) -> Result<()>;
+
+    /// Delete the restrictions placed on every session of a user, optionally sparing one
+    async fn delete_user_session_scopes(
+        &self,
+        user_id: &str,
+        exclude_session_id: Option<&str>,
+    ) -> Result<()>;
+}
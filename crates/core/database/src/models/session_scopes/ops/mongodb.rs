@@ -0,0 +1,48 @@
+use bson::Document;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::SessionScopes;
+
+use super::AbstractSessionScopes;
+
+static COL: &str = "session_scopes";
+
+#[async_trait]
+impl AbstractSessionScopes for MongoDb {
+    /// Insert restrictions for a session
+    async fn insert_session_scopes(&self, scopes: &SessionScopes) -> Result<()> {
+        query!(self, insert_one, COL, &scopes).map(|_| ())
+    }
+
+    /// Fetch the restrictions placed on a session
+    async fn fetch_session_scopes(&self, session_id: &str) -> Result<SessionScopes> {
+        query!(self, find_one_by_id, COL, session_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Delete the restrictions placed on a session
+    async fn delete_session_scopes(&self, session_id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, session_id).map(|_| ())
+    }
+
+    /// Delete the restrictions placed on every session of a user, optionally sparing one
+    async fn delete_user_session_scopes(
+        &self,
+        user_id: &str,
+        exclude_session_id: Option<&str>,
+    ) -> Result<()> {
+        let mut filter = doc! {
+            "user_id": user_id
+        };
+
+        if let Some(session_id) = exclude_session_id {
+            filter.insert("_id", doc! { "$ne": session_id });
+        }
+
+        self.col::<Document>(COL)
+            .delete_many(filter, None)
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
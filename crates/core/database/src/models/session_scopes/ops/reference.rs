@@ -0,0 +1,50 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::SessionScopes;
+
+use super::AbstractSessionScopes;
+
+#[async_trait]
+impl AbstractSessionScopes for ReferenceDb {
+    /// Insert restrictions for a session
+    async fn insert_session_scopes(&self, scopes: &SessionScopes) -> Result<()> {
+        let mut session_scopes = self.session_scopes.lock().await;
+        if session_scopes.contains_key(&scopes.id) {
+            Err(create_database_error!("insert", "session_scopes"))
+        } else {
+            session_scopes.insert(scopes.id.to_string(), scopes.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch the restrictions placed on a session
+    async fn fetch_session_scopes(&self, session_id: &str) -> Result<SessionScopes> {
+        let session_scopes = self.session_scopes.lock().await;
+        session_scopes
+            .get(session_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Delete the restrictions placed on a session
+    async fn delete_session_scopes(&self, session_id: &str) -> Result<()> {
+        let mut session_scopes = self.session_scopes.lock().await;
+        session_scopes.remove(session_id);
+        Ok(())
+    }
+
+    /// Delete the restrictions placed on every session of a user, optionally sparing one
+    async fn delete_user_session_scopes(
+        &self,
+        user_id: &str,
+        exclude_session_id: Option<&str>,
+    ) -> Result<()> {
+        let mut session_scopes = self.session_scopes.lock().await;
+        session_scopes.retain(|id, scopes| {
+            scopes.user_id != user_id || Some(id.as_str()) == exclude_session_id
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+use iso8601_timestamp::{Duration, Timestamp};
+use revolt_result::Result;
+
+use crate::events::client::EventV1;
+use crate::Database;
+
+/// Default minutes of inactivity before a thread is archived
+pub const DEFAULT_AUTO_ARCHIVE_DURATION: u32 = 1440;
+
+/// Fewest minutes of inactivity before a thread can be archived
+pub const MIN_AUTO_ARCHIVE_DURATION: u32 = 60;
+
+auto_derived_partial!(
+    /// Thread of messages started from a message in a channel
+    pub struct Thread {
+        /// Thread Id, the same as the message it was started from
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Channel this thread belongs to
+        pub channel_id: String,
+        /// User who started this thread
+        pub creator_id: String,
+
+        /// Name of the thread
+        pub name: String,
+        /// Minutes without new messages after which the thread is archived
+        pub auto_archive_duration: u32,
+        /// Whether this thread is archived
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub archived: bool,
+        /// Whether this thread was archived automatically after going inactive
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub auto_archived: bool,
+        /// Id of the last message posted in this thread
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_message_id: Option<String>,
+        /// Time of the last message or unarchive in this thread
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_active_at: Option<Timestamp>,
+        /// Users who started or have posted in this thread
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub participants: Vec<String>,
+    },
+    "PartialThread"
+);
+
+impl Thread {
+    /// Start a new thread from a message
+    pub async fn create(
+        db: &Database,
+        message_id: &str,
+        message_author_id: &str,
+        channel_id: &str,
+        creator_id: &str,
+        name: String,
+        auto_archive_duration: Option<u32>,
+    ) -> Result<Thread> {
+        let mut participants = vec![creator_id.to_string()];
+        if message_author_id != creator_id {
+            participants.push(message_author_id.to_string());
+        }
+
+        let thread = Thread {
+            id: message_id.to_string(),
+            channel_id: channel_id.to_string(),
+            creator_id: creator_id.to_string(),
+            name,
+            auto_archive_duration: auto_archive_duration.unwrap_or(DEFAULT_AUTO_ARCHIVE_DURATION),
+            archived: false,
+            auto_archived: false,
+            last_message_id: None,
+            last_active_at: Some(Timestamp::now_utc()),
+            participants,
+        };
+
+        db.insert_thread(&thread).await?;
+
+        EventV1::ThreadCreate(thread.clone().into())
+            .p(thread.channel_id.clone())
+            .await;
+
+        Ok(thread)
+    }
+
+    /// Fetch a thread, making sure it belongs to the given channel
+    pub async fn fetch_in_channel(db: &Database, id: &str, channel_id: &str) -> Result<Thread> {
+        let mut thread = db.fetch_thread(id).await?;
+        if thread.channel_id != channel_id {
+            return Err(create_error!(NotFound));
+        }
+
+        thread.refresh_archived();
+        Ok(thread)
+    }
+
+    /// Fetch threads which have gone inactive but are not yet archived
+    pub async fn fetch_inactive(db: &Database) -> Result<Vec<Thread>> {
+        let Some(before) =
+            Timestamp::now_utc().checked_sub(Duration::minutes(MIN_AUTO_ARCHIVE_DURATION as i64))
+        else {
+            return Ok(vec![]);
+        };
+
+        Ok(db
+            .fetch_inactive_threads(before)
+            .await?
+            .into_iter()
+            .filter(Thread::is_inactive)
+            .collect())
+    }
+
+    /// Whether this thread is archived, either explicitly or after going inactive
+    pub fn is_archived(&self) -> bool {
+        self.archived || self.is_inactive()
+    }
+
+    /// Whether this thread was archived by a member rather than for inactivity
+    pub fn is_explicitly_archived(&self) -> bool {
+        self.archived && !self.auto_archived
+    }
+
+    /// Mark this thread as archived if it has gone inactive since it was last saved
+    pub fn refresh_archived(&mut self) {
+        if !self.archived && self.is_inactive() {
+            self.archived = true;
+            self.auto_archived = true;
+        }
+    }
+
+    /// Whether this thread has had no activity for its auto archive duration
+    pub fn is_inactive(&self) -> bool {
+        let Some(last_active_at) = self.last_active_at else {
+            return false;
+        };
+
+        Timestamp::now_utc()
+            .checked_sub(Duration::minutes(self.auto_archive_duration as i64))
+            .is_some_and(|cutoff| *last_active_at <= *cutoff)
+    }
+
+    /// Update this thread
+    ///
+    /// Unarchiving a thread restarts its inactivity timer.
+    pub async fn update(&mut self, db: &Database, mut partial: PartialThread) -> Result<()> {
+        if let Some(archived) = partial.archived {
+            partial.auto_archived = Some(false);
+            if !archived && partial.last_active_at.is_none() {
+                partial.last_active_at = Some(Timestamp::now_utc());
+            }
+        }
+
+        db.update_thread(&self.id, &partial).await?;
+        self.apply_options(partial.clone());
+
+        EventV1::ThreadUpdate {
+            id: self.id.clone(),
+            data: partial.into(),
+        }
+        .p(self.channel_id.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Archive this thread if it has gone inactive
+    ///
+    /// Returns false if it was active again or already archived by the time
+    /// it was written, such as by another node.
+    pub async fn archive_if_inactive(&mut self, db: &Database) -> Result<bool> {
+        let Some(last_active_at) = self.last_active_at else {
+            return Ok(false);
+        };
+
+        if self.archived
+            || !self.is_inactive()
+            || !db.archive_inactive_thread(&self.id, last_active_at).await?
+        {
+            return Ok(false);
+        }
+
+        let partial = PartialThread {
+            archived: Some(true),
+            auto_archived: Some(true),
+            ..Default::default()
+        };
+
+        self.apply_options(partial.clone());
+
+        EventV1::ThreadUpdate {
+            id: self.id.clone(),
+            data: partial.into(),
+        }
+        .p(self.channel_id.clone())
+        .await;
+
+        Ok(true)
+    }
+
+    /// Record a new message posted in this thread
+    ///
+    /// Posting in an archived thread brings it back, callers must check
+    /// the author is allowed to do so.
+    pub async fn record_message(
+        &mut self,
+        db: &Database,
+        message_id: &str,
+        author_id: &str,
+    ) -> Result<()> {
+        let mut partial = PartialThread {
+            last_message_id: Some(message_id.to_string()),
+            last_active_at: Some(Timestamp::now_utc()),
+            ..Default::default()
+        };
+
+        if self.archived {
+            partial.archived = Some(false);
+            partial.auto_archived = Some(false);
+        }
+
+        db.update_thread(&self.id, &partial).await?;
+        self.apply_options(partial.clone());
+
+        if !self.participants.iter().any(|id| id == author_id) {
+            db.add_thread_participant(&self.id, author_id).await?;
+            self.participants.push(author_id.to_string());
+            partial.participants = Some(self.participants.clone());
+        }
+
+        EventV1::ThreadUpdate {
+            id: self.id.clone(),
+            data: partial.into(),
+        }
+        .p(self.channel_id.clone())
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+    use ulid::Ulid;
+
+    use crate::{PartialThread, Thread};
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let message_id = Ulid::new().to_string();
+            let mut thread = Thread::create(
+                &db,
+                &message_id,
+                "author",
+                "channel",
+                "creator",
+                "Discussion".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+
+            assert!(!thread.is_archived());
+            assert_eq!(vec!["creator", "author"], thread.participants);
+            assert!(Thread::fetch_in_channel(&db, &message_id, "other")
+                .await
+                .is_err());
+
+            thread
+                .update(
+                    &db,
+                    PartialThread {
+                        archived: Some(true),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert!(thread.is_archived());
+
+            let reply_id = Ulid::new().to_string();
+            thread
+                .record_message(&db, &reply_id, "replier")
+                .await
+                .unwrap();
+
+            let fetched = Thread::fetch_in_channel(&db, &message_id, "channel")
+                .await
+                .unwrap();
+
+            assert_eq!(thread, fetched);
+            assert!(!fetched.archived);
+            assert_eq!(Some(reply_id), fetched.last_message_id);
+            assert_eq!(vec!["creator", "author", "replier"], fetched.participants);
+            assert_eq!(1, db.fetch_threads("channel").await.unwrap().len());
+        });
+    }
+
+    #[async_std::test]
+    async fn archive_inactive() {
+        database_test!(|db| async move {
+            let mut thread = Thread::create(
+                &db,
+                &Ulid::new().to_string(),
+                "author",
+                "channel",
+                "creator",
+                "Discussion".to_string(),
+                Some(60),
+            )
+            .await
+            .unwrap();
+
+            assert!(Thread::fetch_inactive(&db).await.unwrap().is_empty());
+            assert!(!thread.archive_if_inactive(&db).await.unwrap());
+
+            let last_active_at = Timestamp::now_utc().checked_sub(Duration::hours(2));
+            thread
+                .update(
+                    &db,
+                    PartialThread {
+                        last_active_at,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            let mut inactive = Thread::fetch_inactive(&db).await.unwrap();
+            assert_eq!(1, inactive.len());
+
+            let mut stale = inactive[0].clone();
+            assert!(inactive[0].archive_if_inactive(&db).await.unwrap());
+            assert!(!stale.archive_if_inactive(&db).await.unwrap());
+
+            let fetched = Thread::fetch_in_channel(&db, &thread.id, "channel")
+                .await
+                .unwrap();
+
+            assert!(fetched.archived);
+            assert!(fetched.auto_archived);
+            assert!(!fetched.is_explicitly_archived());
+            assert!(Thread::fetch_inactive(&db).await.unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn archives_after_inactivity() {
+        let mut thread = Thread {
+            auto_archive_duration: 60,
+            last_active_at: Some(Timestamp::now_utc()),
+            ..Default::default()
+        };
+
+        assert!(!thread.is_archived());
+
+        thread.last_active_at = Timestamp::now_utc().checked_sub(Duration::hours(2));
+
+        assert!(thread.is_archived());
+        assert!(!thread.is_explicitly_archived());
+
+        thread.refresh_archived();
+        assert!(thread.archived && thread.auto_archived);
+    }
+}
@@ -0,0 +1,32 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{PartialThread, Thread};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractThreads: Sync + Send {
+    /// Insert new thread into the database
+    async fn insert_thread(&self, thread: &Thread) -> Result<()>;
+
+    /// Fetch thread by id
+    async fn fetch_thread(&self, id: &str) -> Result<Thread>;
+
+    /// Fetch threads in a channel, newest first
+    async fn fetch_threads(&self, channel_id: &str) -> Result<Vec<Thread>>;
+
+    /// Fetch threads which are not archived and were last active at or before the given time
+    async fn fetch_inactive_threads(&self, before: Timestamp) -> Result<Vec<Thread>>;
+
+    /// Update thread with new information
+    async fn update_thread(&self, id: &str, partial: &PartialThread) -> Result<()>;
+
+    /// Archive a thread for inactivity if it is not archived and has not been
+    /// active since the given time, returning whether it was archived
+    async fn archive_inactive_thread(&self, id: &str, last_active_at: Timestamp) -> Result<bool>;
+
+    /// Add a user to the participants of a thread
+    async fn add_thread_participant(&self, id: &str, user_id: &str) -> Result<()>;
+}
@@ -0,0 +1,103 @@
+use ::mongodb::options::FindOptions;
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{PartialThread, Thread};
+
+use super::AbstractThreads;
+
+static COL: &str = "threads";
+
+#[async_trait]
+impl AbstractThreads for MongoDb {
+    /// Insert new thread into the database
+    async fn insert_thread(&self, thread: &Thread) -> Result<()> {
+        query!(self, insert_one, COL, &thread).map(|_| ())
+    }
+
+    /// Fetch thread by id
+    async fn fetch_thread(&self, id: &str) -> Result<Thread> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch threads in a channel, newest first
+    async fn fetch_threads(&self, channel_id: &str) -> Result<Vec<Thread>> {
+        query!(
+            self,
+            find_with_options,
+            COL,
+            doc! {
+                "channel_id": channel_id
+            },
+            FindOptions::builder().sort(doc! { "_id": -1_i32 }).build()
+        )
+    }
+
+    /// Fetch threads which are not archived and were last active at or before the given time
+    async fn fetch_inactive_threads(&self, before: Timestamp) -> Result<Vec<Thread>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "archived": {
+                    "$ne": true
+                },
+                "last_active_at": {
+                    "$lte": to_bson(&before).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Update thread with new information
+    async fn update_thread(&self, id: &str, partial: &PartialThread) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Archive a thread for inactivity if it is not archived and has not been
+    /// active since the given time, returning whether it was archived
+    async fn archive_inactive_thread(&self, id: &str, last_active_at: Timestamp) -> Result<bool> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id,
+                    "archived": {
+                        "$ne": true
+                    },
+                    "last_active_at": to_bson(&last_active_at).unwrap()
+                },
+                doc! {
+                    "$set": {
+                        "archived": true,
+                        "auto_archived": true
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|result| result.modified_count == 1)
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Add a user to the participants of a thread
+    async fn add_thread_participant(&self, id: &str, user_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$addToSet": {
+                        "participants": user_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+}
@@ -0,0 +1,106 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{PartialThread, Thread};
+
+use super::AbstractThreads;
+
+#[async_trait]
+impl AbstractThreads for ReferenceDb {
+    /// Insert new thread into the database
+    async fn insert_thread(&self, thread: &Thread) -> Result<()> {
+        let mut threads = self.threads.lock().await;
+        if threads.contains_key(&thread.id) {
+            Err(create_database_error!("insert", "threads"))
+        } else {
+            threads.insert(thread.id.to_string(), thread.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch thread by id
+    async fn fetch_thread(&self, id: &str) -> Result<Thread> {
+        let threads = self.threads.lock().await;
+        threads
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch threads in a channel, newest first
+    async fn fetch_threads(&self, channel_id: &str) -> Result<Vec<Thread>> {
+        let threads = self.threads.lock().await;
+        let mut threads: Vec<Thread> = threads
+            .values()
+            .filter(|thread| thread.channel_id == channel_id)
+            .cloned()
+            .collect();
+
+        threads.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(threads)
+    }
+
+    /// Fetch threads which are not archived and were last active at or before the given time
+    async fn fetch_inactive_threads(&self, before: Timestamp) -> Result<Vec<Thread>> {
+        let threads = self.threads.lock().await;
+        Ok(threads
+            .values()
+            .filter(|thread| {
+                !thread.archived
+                    && thread
+                        .last_active_at
+                        .is_some_and(|last_active_at| *last_active_at <= *before)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update thread with new information
+    async fn update_thread(&self, id: &str, partial: &PartialThread) -> Result<()> {
+        let mut threads = self.threads.lock().await;
+        if let Some(thread) = threads.get_mut(id) {
+            thread.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Archive a thread for inactivity if it is not archived and has not been
+    /// active since the given time, returning whether it was archived
+    async fn archive_inactive_thread(&self, id: &str, last_active_at: Timestamp) -> Result<bool> {
+        let mut threads = self.threads.lock().await;
+        match threads.get_mut(id) {
+            Some(thread)
+                if !thread.archived
+                    && thread
+                        .last_active_at
+                        .is_some_and(|current| *current == *last_active_at) =>
+            {
+                thread.archived = true;
+                thread.auto_archived = true;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Add a user to the participants of a thread
+    async fn add_thread_participant(&self, id: &str, user_id: &str) -> Result<()> {
+        let mut threads = self.threads.lock().await;
+        if let Some(thread) = threads.get_mut(id) {
+            if !thread
+                .participants
+                .iter()
+                .any(|participant| participant == user_id)
+            {
+                thread.participants.push(user_id.to_string());
+            }
+
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{Database, File};
 
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{UserBadgesValue, UserFlags, UserFlagsValue};
 use revolt_result::{Error, ErrorType, Result};
 
 auto_derived_partial!(
@@ -19,7 +21,7 @@ auto_derived_partial!(
 
         /// Bitfield of user badges
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub badges: Option<i32>,
+        pub badges: Option<UserBadgesValue>,
         /// User's current status
         #[serde(skip_serializing_if = "Option::is_none")]
         pub status: Option<UserStatus>,
@@ -29,13 +31,19 @@ auto_derived_partial!(
 
         /// Enum of user flags
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub flags: Option<i32>,
+        pub flags: Option<UserFlagsValue>,
         /// Whether this user is privileged
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub privileged: bool,
         /// Bot information
         #[serde(skip_serializing_if = "Option::is_none")]
         pub bot: Option<BotInformation>,
+        /// Guest information
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub guest: Option<GuestInformation>,
+        /// Preferred locale for server-generated text
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub locale: Option<String>,
     },
     "PartialUser"
 );
@@ -81,6 +89,9 @@ auto_derived!(
         /// Current presence option
         #[serde(skip_serializing_if = "Option::is_none")]
         pub presence: Option<Presence>,
+        /// Activity shown for bots
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub activity: String,
     }
 
     /// User's profile
@@ -91,6 +102,21 @@ auto_derived!(
         /// Background visible on user's profile
         #[serde(skip_serializing_if = "Option::is_none")]
         pub background: Option<File>,
+        /// Personal website
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub website: Option<ProfileLink>,
+        /// Donation or tipping page
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub donation: Option<ProfileLink>,
+    }
+
+    /// Link displayed on a user's profile
+    pub struct ProfileLink {
+        /// URL of the linked page
+        pub url: String,
+        /// Whether the linked page links back to this profile using `rel="me"`
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub verified: bool,
     }
 
     /// Bot information for if the user is a bot
@@ -99,13 +125,30 @@ auto_derived!(
         pub owner: String,
     }
 
+    /// Guest information for if the user is a temporary guest
+    pub struct GuestInformation {
+        /// Id of the server this guest was invited to
+        pub server: String,
+        /// Id of the only channel this guest can access
+        pub channel: String,
+        /// Whether this guest may send messages
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub write: bool,
+        /// Time at which this guest account expires
+        pub expires: Timestamp,
+    }
+
     /// Optional fields on user object
     pub enum FieldsUser {
         Avatar,
         StatusText,
         StatusPresence,
+        StatusActivity,
         ProfileContent,
         ProfileBackground,
+        ProfileWebsite,
+        ProfileDonation,
+        Locale,
     }
 );
 
@@ -162,6 +205,11 @@ impl User {
                     x.presence = None;
                 }
             }
+            FieldsUser::StatusActivity => {
+                if let Some(x) = self.status.as_mut() {
+                    x.activity = String::new();
+                }
+            }
             FieldsUser::ProfileContent => {
                 if let Some(x) = self.profile.as_mut() {
                     x.content = String::new();
@@ -172,6 +220,17 @@ impl User {
                     x.background = None;
                 }
             }
+            FieldsUser::ProfileWebsite => {
+                if let Some(x) = self.profile.as_mut() {
+                    x.website = None;
+                }
+            }
+            FieldsUser::ProfileDonation => {
+                if let Some(x) = self.profile.as_mut() {
+                    x.donation = None;
+                }
+            }
+            FieldsUser::Locale => self.locale = None,
         }
     }
 
@@ -181,15 +240,18 @@ impl User {
             db,
             PartialUser {
                 username: Some(format!("Deleted User {}", self.id)),
-                flags: Some(2),
+                flags: Some(UserFlags::Deleted.into()),
                 ..Default::default()
             },
             vec![
                 FieldsUser::Avatar,
                 FieldsUser::StatusText,
                 FieldsUser::StatusPresence,
+                FieldsUser::StatusActivity,
                 FieldsUser::ProfileContent,
                 FieldsUser::ProfileBackground,
+                FieldsUser::ProfileWebsite,
+                FieldsUser::ProfileDonation,
             ],
         )
         .await
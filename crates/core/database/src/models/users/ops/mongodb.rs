@@ -293,8 +293,12 @@ impl IntoDocumentPath for FieldsUser {
             FieldsUser::Avatar => "avatar",
             FieldsUser::ProfileBackground => "profile.background",
             FieldsUser::ProfileContent => "profile.content",
+            FieldsUser::ProfileWebsite => "profile.website",
+            FieldsUser::ProfileDonation => "profile.donation",
             FieldsUser::StatusPresence => "status.presence",
             FieldsUser::StatusText => "status.text",
+            FieldsUser::StatusActivity => "status.activity",
+            FieldsUser::Locale => "locale",
         })
     }
 }
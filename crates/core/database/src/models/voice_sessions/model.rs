@@ -0,0 +1,99 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::Database;
+
+auto_derived!(
+    /// Time a user spent connected to a voice channel
+    pub struct VoiceSession {
+        /// Session Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Server the voice channel belongs to
+        pub server_id: String,
+        /// Voice channel the user was connected to
+        pub channel_id: String,
+        /// Id of the user
+        pub user_id: String,
+        /// Time at which the user joined
+        pub joined_at: Timestamp,
+        /// Time at which the user left, absent while still connected
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub left_at: Option<Timestamp>,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl VoiceSession {
+    /// Open a new session, ending any session the user still has open
+    pub async fn start(
+        db: &Database,
+        server_id: &str,
+        channel_id: &str,
+        user_id: &str,
+    ) -> Result<VoiceSession> {
+        VoiceSession::end(db, user_id).await?;
+
+        let session = VoiceSession {
+            id: Ulid::new().to_string(),
+            server_id: server_id.to_string(),
+            channel_id: channel_id.to_string(),
+            user_id: user_id.to_string(),
+            joined_at: Timestamp::now_utc(),
+            left_at: None,
+        };
+
+        db.insert_voice_session(&session).await?;
+        Ok(session)
+    }
+
+    /// End the session the user currently has open, if any
+    pub async fn end(db: &Database, user_id: &str) -> Result<()> {
+        db.end_voice_session(user_id, Timestamp::now_utc()).await
+    }
+
+    /// Length of this session in seconds, up to now if still connected
+    pub fn duration(&self) -> u64 {
+        let left_at = self.left_at.unwrap_or_else(Timestamp::now_utc);
+        (*left_at - *self.joined_at).whole_seconds().max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::VoiceSession;
+
+    #[async_std::test]
+    async fn crud() {
+        database_test!(|db| async move {
+            let first = VoiceSession::start(&db, "server", "channel", "user")
+                .await
+                .unwrap();
+
+            let second = VoiceSession::start(&db, "server", "other", "user")
+                .await
+                .unwrap();
+
+            VoiceSession::start(&db, "server", "channel", "someone")
+                .await
+                .unwrap();
+
+            VoiceSession::end(&db, "user").await.unwrap();
+
+            let sessions = db
+                .fetch_voice_sessions("server", Some("user"))
+                .await
+                .unwrap();
+
+            assert_eq!(2, sessions.len());
+            assert!(sessions.iter().any(|session| session.id == first.id));
+            assert!(sessions.iter().any(|session| session.id == second.id));
+            assert!(sessions.iter().all(|session| session.left_at.is_some()));
+            assert_eq!(
+                3,
+                db.fetch_voice_sessions("server", None).await.unwrap().len()
+            );
+        });
+    }
+}
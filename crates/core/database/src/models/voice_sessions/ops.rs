@@ -0,0 +1,23 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::VoiceSession;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractVoiceSessions: Sync + Send {
+    /// Insert new voice session into the database
+    async fn insert_voice_session(&self, session: &VoiceSession) -> Result<()>;
+
+    /// End any voice session the user currently has open
+    async fn end_voice_session(&self, user_id: &str, left_at: Timestamp) -> Result<()>;
+
+    /// Fetch voice sessions in a server, optionally for a single user, oldest first
+    async fn fetch_voice_sessions(
+        &self,
+        server_id: &str,
+        user_id: Option<&str>,
+    ) -> Result<Vec<VoiceSession>>;
+}
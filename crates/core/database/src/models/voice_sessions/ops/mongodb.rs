@@ -0,0 +1,65 @@
+use ::mongodb::options::FindOptions;
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::VoiceSession;
+
+use super::AbstractVoiceSessions;
+
+static COL: &str = "voice_sessions";
+
+#[async_trait]
+impl AbstractVoiceSessions for MongoDb {
+    /// Insert new voice session into the database
+    async fn insert_voice_session(&self, session: &VoiceSession) -> Result<()> {
+        query!(self, insert_one, COL, &session).map(|_| ())
+    }
+
+    /// End any voice session the user currently has open
+    async fn end_voice_session(&self, user_id: &str, left_at: Timestamp) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "user_id": user_id,
+                    "left_at": {
+                        "$exists": false
+                    }
+                },
+                doc! {
+                    "$set": {
+                        "left_at": to_bson(&left_at)
+                            .map_err(|_| create_database_error!("to_bson", "left_at"))?
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
+
+    /// Fetch voice sessions in a server, optionally for a single user, oldest first
+    async fn fetch_voice_sessions(
+        &self,
+        server_id: &str,
+        user_id: Option<&str>,
+    ) -> Result<Vec<VoiceSession>> {
+        let mut filter = doc! {
+            "server_id": server_id
+        };
+
+        if let Some(user_id) = user_id {
+            filter.insert("user_id", user_id);
+        }
+
+        query!(
+            self,
+            find_with_options,
+            COL,
+            filter,
+            FindOptions::builder().sort(doc! { "_id": 1_i32 }).build()
+        )
+    }
+}
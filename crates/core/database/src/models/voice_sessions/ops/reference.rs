@@ -0,0 +1,54 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::VoiceSession;
+
+use super::AbstractVoiceSessions;
+
+#[async_trait]
+impl AbstractVoiceSessions for ReferenceDb {
+    /// Insert new voice session into the database
+    async fn insert_voice_session(&self, session: &VoiceSession) -> Result<()> {
+        let mut voice_sessions = self.voice_sessions.lock().await;
+        if voice_sessions.contains_key(&session.id) {
+            Err(create_database_error!("insert", "voice_sessions"))
+        } else {
+            voice_sessions.insert(session.id.to_string(), session.clone());
+            Ok(())
+        }
+    }
+
+    /// End any voice session the user currently has open
+    async fn end_voice_session(&self, user_id: &str, left_at: Timestamp) -> Result<()> {
+        let mut voice_sessions = self.voice_sessions.lock().await;
+        for session in voice_sessions
+            .values_mut()
+            .filter(|session| session.user_id == user_id && session.left_at.is_none())
+        {
+            session.left_at = Some(left_at);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch voice sessions in a server, optionally for a single user, oldest first
+    async fn fetch_voice_sessions(
+        &self,
+        server_id: &str,
+        user_id: Option<&str>,
+    ) -> Result<Vec<VoiceSession>> {
+        let voice_sessions = self.voice_sessions.lock().await;
+        let mut sessions: Vec<VoiceSession> = voice_sessions
+            .values()
+            .filter(|session| {
+                session.server_id == server_id
+                    && user_id.map_or(true, |user_id| session.user_id == user_id)
+            })
+            .cloned()
+            .collect();
+
+        sessions.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(sessions)
+    }
+}
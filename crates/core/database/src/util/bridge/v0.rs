@@ -39,7 +39,465 @@ impl From<crate::Bot> for Bot {
             interactions_url: value.interactions_url,
             terms_of_service_url: value.terms_of_service_url,
             privacy_policy_url: value.privacy_policy_url,
-            flags: value.flags.unwrap_or_default() as u32,
+            flags: value.flags.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<crate::ChannelFeed> for ChannelFeed {
+    fn from(value: crate::ChannelFeed) -> Self {
+        ChannelFeed {
+            id: value.id,
+            channel_id: value.channel_id,
+            webhook_id: value.webhook_id,
+            creator_id: value.creator_id,
+            url: value.url,
+            interval: value.interval,
+            filter: value.filter,
+        }
+    }
+}
+
+impl From<FieldsChannelFeed> for crate::FieldsChannelFeed {
+    fn from(value: FieldsChannelFeed) -> Self {
+        match value {
+            FieldsChannelFeed::Filter => crate::FieldsChannelFeed::Filter,
+        }
+    }
+}
+
+impl From<crate::ChannelSchedule> for ChannelSchedule {
+    fn from(value: crate::ChannelSchedule) -> Self {
+        ChannelSchedule {
+            id: value.id,
+            channel_id: value.channel_id,
+            creator_id: value.creator_id,
+            run_at: value.run_at,
+            changes: value.changes.into(),
+        }
+    }
+}
+
+impl From<crate::ChannelScheduleChanges> for ChannelScheduleChanges {
+    fn from(value: crate::ChannelScheduleChanges) -> Self {
+        ChannelScheduleChanges {
+            name: value.name,
+            description: value.description,
+            nsfw: value.nsfw,
+            locked: value.locked,
+            media_only: value.media_only,
+            slowmode: value.slowmode,
+        }
+    }
+}
+
+impl From<ChannelScheduleChanges> for crate::ChannelScheduleChanges {
+    fn from(value: ChannelScheduleChanges) -> Self {
+        crate::ChannelScheduleChanges {
+            name: value.name,
+            description: value.description,
+            nsfw: value.nsfw,
+            locked: value.locked,
+            media_only: value.media_only,
+            slowmode: value.slowmode,
+        }
+    }
+}
+
+impl From<crate::ChannelInbox> for ChannelInbox {
+    fn from(value: crate::ChannelInbox) -> Self {
+        ChannelInbox {
+            id: value.id,
+            address: value.address,
+            channel_id: value.channel_id,
+            webhook_id: value.webhook_id,
+            creator_id: value.creator_id,
+        }
+    }
+}
+
+impl From<crate::FederatedMessage> for FederatedMessage {
+    fn from(value: crate::FederatedMessage) -> Self {
+        FederatedMessage {
+            id: value.id,
+            user_id: value.user_id,
+            remote_user: value.remote_user,
+            outgoing: value.outgoing,
+            content: value.content,
+        }
+    }
+}
+
+impl From<crate::AdminAuditEntry> for AdminAuditEntry {
+    fn from(value: crate::AdminAuditEntry) -> Self {
+        AdminAuditEntry {
+            id: value.id,
+            sequence: value.sequence,
+            actor_id: value.actor_id,
+            operation: value.operation,
+            target_id: value.target_id,
+            before: value.before,
+            after: value.after,
+            previous_hash: value.previous_hash,
+            hash: value.hash,
+        }
+    }
+}
+
+impl From<crate::FeatureFlag> for FeatureFlag {
+    fn from(value: crate::FeatureFlag) -> Self {
+        FeatureFlag {
+            id: value.id,
+            enabled: value.enabled,
+            servers: value.servers,
+        }
+    }
+}
+
+impl From<crate::BanSyncGroup> for BanSyncGroup {
+    fn from(value: crate::BanSyncGroup) -> Self {
+        BanSyncGroup {
+            id: value.id,
+            name: value.name,
+            join_code: value.join_code,
+            members: value
+                .members
+                .into_iter()
+                .map(|member| member.into())
+                .collect(),
+        }
+    }
+}
+
+impl From<crate::BanSyncMember> for BanSyncMember {
+    fn from(value: crate::BanSyncMember) -> Self {
+        BanSyncMember {
+            server_id: value.server_id,
+            policy: value.policy.into(),
+        }
+    }
+}
+
+impl From<crate::BanSyncPolicy> for BanSyncPolicy {
+    fn from(value: crate::BanSyncPolicy) -> Self {
+        match value {
+            crate::BanSyncPolicy::Propose => BanSyncPolicy::Propose,
+            crate::BanSyncPolicy::AutoApply => BanSyncPolicy::AutoApply,
+        }
+    }
+}
+
+impl From<BanSyncPolicy> for crate::BanSyncPolicy {
+    fn from(value: BanSyncPolicy) -> Self {
+        match value {
+            BanSyncPolicy::Propose => crate::BanSyncPolicy::Propose,
+            BanSyncPolicy::AutoApply => crate::BanSyncPolicy::AutoApply,
+        }
+    }
+}
+
+impl From<crate::BanSyncProposal> for BanSyncProposal {
+    fn from(value: crate::BanSyncProposal) -> Self {
+        BanSyncProposal {
+            id: value.id,
+            group_id: value.group_id,
+            source_server_id: value.source_server_id,
+            server_id: value.server_id,
+            user_id: value.user_id,
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<crate::ModerationCase> for ModerationCase {
+    fn from(value: crate::ModerationCase) -> Self {
+        ModerationCase {
+            id: value.id,
+            server_id: value.server_id,
+            case_number: value.case_number,
+            user_id: value.user_id,
+            moderator_id: value.moderator_id,
+            action: value.action.into(),
+            reason: value.reason,
+            notes: value.notes.into_iter().map(|note| note.into()).collect(),
+        }
+    }
+}
+
+impl From<crate::ModerationAction> for ModerationAction {
+    fn from(value: crate::ModerationAction) -> Self {
+        match value {
+            crate::ModerationAction::Warning => ModerationAction::Warning,
+            crate::ModerationAction::Timeout { until } => ModerationAction::Timeout { until },
+            crate::ModerationAction::Kick => ModerationAction::Kick,
+            crate::ModerationAction::Ban => ModerationAction::Ban,
+        }
+    }
+}
+
+impl From<crate::ModerationCaseNote> for ModerationCaseNote {
+    fn from(value: crate::ModerationCaseNote) -> Self {
+        ModerationCaseNote {
+            id: value.id,
+            author_id: value.author_id,
+            content: value.content,
+        }
+    }
+}
+
+impl From<crate::PolicyDocument> for PolicyDocument {
+    fn from(value: crate::PolicyDocument) -> Self {
+        PolicyDocument {
+            id: value.id,
+            title: value.title,
+            version: value.version,
+            content: value.content,
+            published_at: value.published_at,
+        }
+    }
+}
+
+impl From<crate::VoiceSession> for VoiceSession {
+    fn from(value: crate::VoiceSession) -> Self {
+        VoiceSession {
+            duration: value.duration(),
+            id: value.id,
+            server_id: value.server_id,
+            channel_id: value.channel_id,
+            user_id: value.user_id,
+            joined_at: value.joined_at,
+            left_at: value.left_at,
+        }
+    }
+}
+
+impl From<crate::KeywordHighlights> for KeywordHighlights {
+    fn from(value: crate::KeywordHighlights) -> Self {
+        KeywordHighlights {
+            keywords: value.keywords,
+        }
+    }
+}
+
+impl From<crate::ServerAnnouncement> for ServerAnnouncement {
+    fn from(value: crate::ServerAnnouncement) -> Self {
+        ServerAnnouncement {
+            id: value.id,
+            server_id: value.server_id,
+            channel_id: value.channel_id,
+            creator_id: value.creator_id,
+            content: value.content,
+            next_run: value.next_run,
+            recurrence: value.recurrence.map(|recurrence| recurrence.into()),
+        }
+    }
+}
+
+impl From<crate::AnnouncementRecurrence> for AnnouncementRecurrence {
+    fn from(value: crate::AnnouncementRecurrence) -> Self {
+        AnnouncementRecurrence {
+            every: value.every,
+            unit: match value.unit {
+                crate::RecurrenceUnit::Hour => RecurrenceUnit::Hour,
+                crate::RecurrenceUnit::Day => RecurrenceUnit::Day,
+                crate::RecurrenceUnit::Week => RecurrenceUnit::Week,
+            },
+        }
+    }
+}
+
+impl From<crate::Broadcast> for Broadcast {
+    fn from(value: crate::Broadcast) -> Self {
+        Broadcast {
+            id: value.id,
+            author_id: value.author_id,
+            content: value.content,
+            target: value.target.into(),
+            send_dm: value.send_dm,
+            send_at: value.send_at,
+            sent: value.sent,
+        }
+    }
+}
+
+impl From<crate::BroadcastTarget> for BroadcastTarget {
+    fn from(value: crate::BroadcastTarget) -> Self {
+        match value {
+            crate::BroadcastTarget::All => BroadcastTarget::All,
+            crate::BroadcastTarget::Servers { ids } => BroadcastTarget::Servers { ids },
+        }
+    }
+}
+
+impl From<BroadcastTarget> for crate::BroadcastTarget {
+    fn from(value: BroadcastTarget) -> Self {
+        match value {
+            BroadcastTarget::All => crate::BroadcastTarget::All,
+            BroadcastTarget::Servers { ids } => crate::BroadcastTarget::Servers { ids },
+        }
+    }
+}
+
+impl From<crate::MaintenanceStatus> for MaintenanceStatus {
+    fn from(value: crate::MaintenanceStatus) -> Self {
+        MaintenanceStatus {
+            enabled: value.enabled,
+            message: value.message,
+            ends_at: value.ends_at,
+        }
+    }
+}
+
+impl From<crate::AuditLogEntry> for AuditLogEntry {
+    fn from(value: crate::AuditLogEntry) -> Self {
+        AuditLogEntry {
+            id: value.id,
+            server_id: value.server_id,
+            actor_id: value.actor_id,
+            action: value.action.into(),
+            target_id: value.target_id,
+            before: value.before,
+            after: value.after,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl From<crate::AuditLogAction> for AuditLogAction {
+    fn from(value: crate::AuditLogAction) -> Self {
+        match value {
+            crate::AuditLogAction::RoleCreate => AuditLogAction::RoleCreate,
+            crate::AuditLogAction::RoleUpdate => AuditLogAction::RoleUpdate,
+            crate::AuditLogAction::RoleDelete => AuditLogAction::RoleDelete,
+            crate::AuditLogAction::MemberKick => AuditLogAction::MemberKick,
+            crate::AuditLogAction::MemberBan => AuditLogAction::MemberBan,
+            crate::AuditLogAction::MemberUnban => AuditLogAction::MemberUnban,
+            crate::AuditLogAction::ChannelDelete => AuditLogAction::ChannelDelete,
+            crate::AuditLogAction::WebhookCreate => AuditLogAction::WebhookCreate,
+            crate::AuditLogAction::WebhookUpdate => AuditLogAction::WebhookUpdate,
+            crate::AuditLogAction::WebhookDelete => AuditLogAction::WebhookDelete,
+        }
+    }
+}
+
+impl From<crate::SessionScope> for SessionScope {
+    fn from(value: crate::SessionScope) -> Self {
+        match value {
+            crate::SessionScope::ReadOnly => SessionScope::ReadOnly,
+            crate::SessionScope::NoDirectMessages => SessionScope::NoDirectMessages,
+        }
+    }
+}
+
+impl From<SessionScope> for crate::SessionScope {
+    fn from(value: SessionScope) -> Self {
+        match value {
+            SessionScope::ReadOnly => crate::SessionScope::ReadOnly,
+            SessionScope::NoDirectMessages => crate::SessionScope::NoDirectMessages,
+        }
+    }
+}
+
+impl From<crate::ScheduledMessage> for ScheduledMessage {
+    fn from(value: crate::ScheduledMessage) -> Self {
+        ScheduledMessage {
+            id: value.id,
+            channel_id: value.channel_id,
+            author_id: value.author_id,
+            send_at: value.send_at,
+            message: value.message,
+        }
+    }
+}
+
+impl From<crate::ChannelTopicChange> for ChannelTopicChange {
+    fn from(value: crate::ChannelTopicChange) -> Self {
+        ChannelTopicChange {
+            id: value.id,
+            channel_id: value.channel_id,
+            author_id: value.author_id,
+            name: value.name.map(|change| change.into()),
+            description: value.description.map(|change| change.into()),
+        }
+    }
+}
+
+impl From<crate::TopicFieldChange> for TopicFieldChange {
+    fn from(value: crate::TopicFieldChange) -> Self {
+        TopicFieldChange {
+            before: value.before,
+            after: value.after,
+        }
+    }
+}
+
+impl From<AnnouncementRecurrence> for crate::AnnouncementRecurrence {
+    fn from(value: AnnouncementRecurrence) -> Self {
+        crate::AnnouncementRecurrence {
+            every: value.every,
+            unit: match value.unit {
+                RecurrenceUnit::Hour => crate::RecurrenceUnit::Hour,
+                RecurrenceUnit::Day => crate::RecurrenceUnit::Day,
+                RecurrenceUnit::Week => crate::RecurrenceUnit::Week,
+            },
+        }
+    }
+}
+
+impl From<crate::ReactionRole> for ReactionRole {
+    fn from(value: crate::ReactionRole) -> Self {
+        ReactionRole {
+            id: value.id,
+            server_id: value.server_id,
+            channel_id: value.channel_id,
+            message_id: value.message_id,
+            emoji: value.emoji,
+            role_id: value.role_id,
+        }
+    }
+}
+
+impl From<crate::RoleTemplate> for RoleTemplate {
+    fn from(value: crate::RoleTemplate) -> Self {
+        RoleTemplate {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            permissions: value.permissions.into(),
+        }
+    }
+}
+
+impl From<crate::Thread> for Thread {
+    fn from(value: crate::Thread) -> Self {
+        Thread {
+            id: value.id,
+            channel_id: value.channel_id,
+            creator_id: value.creator_id,
+            name: value.name,
+            auto_archive_duration: value.auto_archive_duration,
+            archived: value.archived,
+            auto_archived: value.auto_archived,
+            last_message_id: value.last_message_id,
+            last_active_at: value.last_active_at,
+            participants: value.participants,
+        }
+    }
+}
+
+impl From<crate::PartialThread> for PartialThread {
+    fn from(value: crate::PartialThread) -> Self {
+        PartialThread {
+            id: value.id,
+            channel_id: value.channel_id,
+            creator_id: value.creator_id,
+            name: value.name,
+            auto_archive_duration: value.auto_archive_duration,
+            archived: value.archived,
+            auto_archived: value.auto_archived,
+            last_message_id: value.last_message_id,
+            last_active_at: value.last_active_at,
+            participants: value.participants,
         }
     }
 }
@@ -52,6 +510,7 @@ impl From<crate::Webhook> for Webhook {
             avatar: value.avatar.map(|file| file.into()),
             channel_id: value.channel_id,
             token: value.token,
+            limits: value.limits.map(|limits| limits.into()),
         }
     }
 }
@@ -64,19 +523,44 @@ impl From<crate::PartialWebhook> for PartialWebhook {
             avatar: value.avatar.map(|file| file.into()),
             channel_id: value.channel_id,
             token: value.token,
+            limits: value.limits.map(|limits| limits.into()),
+        }
+    }
+}
+
+impl From<crate::WebhookLimits> for WebhookLimits {
+    fn from(value: crate::WebhookLimits) -> Self {
+        WebhookLimits {
+            per_minute: value.per_minute,
+            per_day: value.per_day,
+        }
+    }
+}
+
+impl From<WebhookLimits> for crate::WebhookLimits {
+    fn from(value: WebhookLimits) -> Self {
+        crate::WebhookLimits {
+            per_minute: value.per_minute,
+            per_day: value.per_day,
         }
     }
 }
 
 impl From<FieldsWebhook> for crate::FieldsWebhook {
-    fn from(_value: FieldsWebhook) -> Self {
-        Self::Avatar
+    fn from(value: FieldsWebhook) -> Self {
+        match value {
+            FieldsWebhook::Avatar => Self::Avatar,
+            FieldsWebhook::Limits => Self::Limits,
+        }
     }
 }
 
 impl From<crate::FieldsWebhook> for FieldsWebhook {
-    fn from(_value: crate::FieldsWebhook) -> Self {
-        Self::Avatar
+    fn from(value: crate::FieldsWebhook) -> Self {
+        match value {
+            crate::FieldsWebhook::Avatar => Self::Avatar,
+            crate::FieldsWebhook::Limits => Self::Limits,
+        }
     }
 }
 
@@ -126,6 +610,12 @@ impl From<crate::Channel> for Channel {
                 default_permissions,
                 role_permissions,
                 nsfw,
+                locked,
+                media_only,
+                posting_role,
+                slowmode,
+                auto_thread_depth,
+                file_types,
             } => Channel::TextChannel {
                 id,
                 server,
@@ -136,6 +626,12 @@ impl From<crate::Channel> for Channel {
                 default_permissions,
                 role_permissions,
                 nsfw,
+                locked,
+                media_only,
+                posting_role,
+                slowmode,
+                auto_thread_depth,
+                file_types: file_types.map(|v| v.into()),
             },
             crate::Channel::VoiceChannel {
                 id,
@@ -143,18 +639,24 @@ impl From<crate::Channel> for Channel {
                 name,
                 description,
                 icon,
+                last_message_id,
                 default_permissions,
                 role_permissions,
                 nsfw,
+                user_limit,
+                file_types,
             } => Channel::VoiceChannel {
                 id,
                 server,
                 name,
                 description,
                 icon: icon.map(|file| file.into()),
+                last_message_id,
                 default_permissions,
                 role_permissions,
                 nsfw,
+                user_limit,
+                file_types: file_types.map(|v| v.into()),
             },
         }
     }
@@ -173,6 +675,22 @@ impl From<crate::PartialChannel> for PartialChannel {
             role_permissions: value.role_permissions,
             default_permissions: value.default_permissions,
             last_message_id: value.last_message_id,
+            user_limit: value.user_limit,
+            locked: value.locked,
+            media_only: value.media_only,
+            posting_role: value.posting_role,
+            slowmode: value.slowmode,
+            auto_thread_depth: value.auto_thread_depth,
+            file_types: value.file_types.map(|v| v.into()),
+        }
+    }
+}
+
+impl From<crate::FileTypeRestrictions> for FileTypeRestrictions {
+    fn from(value: crate::FileTypeRestrictions) -> Self {
+        FileTypeRestrictions {
+            allow: value.allow,
+            block: value.block,
         }
     }
 }
@@ -183,6 +701,11 @@ impl From<FieldsChannel> for crate::FieldsChannel {
             FieldsChannel::Description => crate::FieldsChannel::Description,
             FieldsChannel::Icon => crate::FieldsChannel::Icon,
             FieldsChannel::DefaultPermissions => crate::FieldsChannel::DefaultPermissions,
+            FieldsChannel::UserLimit => crate::FieldsChannel::UserLimit,
+            FieldsChannel::PostingRole => crate::FieldsChannel::PostingRole,
+            FieldsChannel::FileTypes => crate::FieldsChannel::FileTypes,
+            FieldsChannel::Slowmode => crate::FieldsChannel::Slowmode,
+            FieldsChannel::AutoThreadDepth => crate::FieldsChannel::AutoThreadDepth,
         }
     }
 }
@@ -193,6 +716,11 @@ impl From<crate::FieldsChannel> for FieldsChannel {
             crate::FieldsChannel::Description => FieldsChannel::Description,
             crate::FieldsChannel::Icon => FieldsChannel::Icon,
             crate::FieldsChannel::DefaultPermissions => FieldsChannel::DefaultPermissions,
+            crate::FieldsChannel::UserLimit => FieldsChannel::UserLimit,
+            crate::FieldsChannel::PostingRole => FieldsChannel::PostingRole,
+            crate::FieldsChannel::FileTypes => FieldsChannel::FileTypes,
+            crate::FieldsChannel::Slowmode => FieldsChannel::Slowmode,
+            crate::FieldsChannel::AutoThreadDepth => FieldsChannel::AutoThreadDepth,
         }
     }
 }
@@ -206,6 +734,7 @@ impl From<crate::File> for File {
             metadata: value.metadata.into(),
             content_type: value.content_type,
             size: value.size,
+            region: value.region,
             deleted: value.deleted,
             reported: value.reported,
             message_id: value.message_id,
@@ -259,12 +788,13 @@ impl crate::User {
             username: self.username,
             avatar: self.avatar.map(|file| file.into()),
             relations: vec![],
-            badges: self.badges.unwrap_or_default() as u32,
+            badges: self.badges.unwrap_or_default(),
             status: None,
             profile: None,
-            flags: self.flags.unwrap_or_default() as u32,
+            flags: self.flags.unwrap_or_default(),
             privileged: self.privileged,
             bot: self.bot.map(|bot| bot.into()),
+            guest: self.guest.map(|guest| guest.into()),
             relationship,
             online: can_see_profile && revolt_presence::is_online(&self.id).await,
             id: self.id,
@@ -312,6 +842,7 @@ impl From<crate::UserStatus> for UserStatus {
         UserStatus {
             text: value.text,
             presence: value.presence.map(|presence| presence.into()),
+            activity: value.activity,
         }
     }
 }
@@ -321,6 +852,17 @@ impl From<crate::UserProfile> for UserProfile {
         UserProfile {
             content: value.content,
             background: value.background.map(|file| file.into()),
+            website: value.website.map(|link| link.into()),
+            donation: value.donation.map(|link| link.into()),
+        }
+    }
+}
+
+impl From<crate::ProfileLink> for ProfileLink {
+    fn from(value: crate::ProfileLink) -> Self {
+        ProfileLink {
+            url: value.url,
+            verified: value.verified,
         }
     }
 }
@@ -332,3 +874,14 @@ impl From<crate::BotInformation> for BotInformation {
         }
     }
 }
+
+impl From<crate::GuestInformation> for GuestInformation {
+    fn from(value: crate::GuestInformation) -> Self {
+        GuestInformation {
+            server_id: value.server,
+            channel_id: value.channel,
+            write: value.write,
+            expires: value.expires,
+        }
+    }
+}
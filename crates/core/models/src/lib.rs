@@ -18,19 +18,29 @@ macro_rules! auto_derived {
         $(
             #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
             #[cfg_attr(feature = "schemas", derive(JsonSchema))]
+            #[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
             #[derive(Debug, Clone, Eq, PartialEq)]
             $item
         )+
     };
 }
 
-#[cfg(feature = "partials")]
-macro_rules! auto_derived_partial {
+#[cfg(all(feature = "partials", not(feature = "ts")))]
+macro_rules! partial_struct {
     ( $item:item, $name:expr ) => {
         #[derive(
             OptionalStruct, Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema,
         )]
-        #[optional_derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+        #[optional_derive(
+            Debug,
+            Clone,
+            Default,
+            Eq,
+            PartialEq,
+            Serialize,
+            Deserialize,
+            JsonSchema
+        )]
         #[optional_name = $name]
         #[opt_skip_serializing_none]
         #[opt_some_priority]
@@ -38,16 +48,223 @@ macro_rules! auto_derived_partial {
     };
 }
 
+#[cfg(all(feature = "partials", feature = "ts"))]
+macro_rules! partial_struct {
+    ( $item:item, $name:expr ) => {
+        #[derive(
+            OptionalStruct,
+            Debug,
+            Clone,
+            Eq,
+            PartialEq,
+            Serialize,
+            Deserialize,
+            JsonSchema,
+            ts_rs::TS,
+        )]
+        #[optional_derive(
+            Debug,
+            Clone,
+            Default,
+            Eq,
+            PartialEq,
+            Serialize,
+            Deserialize,
+            JsonSchema,
+            ts_rs::TS
+        )]
+        #[optional_name = $name]
+        #[opt_skip_serializing_none]
+        #[opt_some_priority]
+        #[ts(export)]
+        $item
+    };
+}
+
+#[cfg(feature = "partials")]
+/// Generate builder, apply and diff helpers between a model and its partial
+macro_rules! partial_helpers {
+    ( $name:ident, $( $field:ident: $field_ty:ty ),* ) => {
+        paste::paste! {
+            impl $name {
+                /// Apply a partial onto this object
+                pub fn apply(&mut self, partial: [<Partial $name>]) {
+                    self.apply_options(partial);
+                }
+
+                /// Create a partial which turns this object into other
+                ///
+                /// Fields which were cleared in other cannot be represented and are left out.
+                #[allow(clippy::useless_conversion)]
+                pub fn diff(&self, other: &$name) -> [<Partial $name>] {
+                    let mut partial = [<Partial $name>]::default();
+                    $(
+                        if self.$field != other.$field {
+                            partial.$field = other.$field.clone().into();
+                        }
+                    )*
+                    partial
+                }
+            }
+
+            impl [<Partial $name>] {
+                /// Apply this partial onto an object
+                pub fn apply_to(self, target: &mut $name) {
+                    target.apply_options(self);
+                }
+
+                $(
+                    #[doc = concat!("Set `", stringify!($field), "` on this partial")]
+                    #[allow(clippy::useless_conversion)]
+                    pub fn [<with_ $field>](mut self, value: $field_ty) -> Self {
+                        self.$field = value.into();
+                        self
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[cfg(feature = "partials")]
+macro_rules! auto_derived_partial {
+    (
+        $( #[$meta:meta] )*
+        $vis:vis struct $name:ident {
+            $(
+                $( #[$field_meta:meta] )*
+                $field_vis:vis $field:ident: $field_ty:ty
+            ),* $(,)?
+        },
+        $partial:expr
+    ) => {
+        partial_struct!(
+            $( #[$meta] )*
+            $vis struct $name {
+                $(
+                    $( #[$field_meta] )*
+                    $field_vis $field: $field_ty
+                ),*
+            },
+            $partial
+        );
+
+        partial_helpers!($name, $( $field: $field_ty ),*);
+    };
+}
+
 #[cfg(not(feature = "partials"))]
 macro_rules! auto_derived_partial {
     ( $item:item, $name:expr ) => {
         #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+        #[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
         $item
     };
 }
 
+/// Generate a bitfield newtype over the given flag enum
+///
+/// Bitfields are (de)serialised as plain integers.
+macro_rules! bitfield {
+    (
+        $( #[$meta:meta] )*
+        $name:ident: $flag:ident {
+            $( $variant:ident ),* $(,)?
+        }
+    ) => {
+        $( #[$meta] )*
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+        #[cfg_attr(feature = "schemas", derive(JsonSchema))]
+        #[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+        #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+        pub struct $name(pub i32);
+
+        impl $name {
+            /// Check whether a given flag is set
+            pub fn has(&self, flag: $flag) -> bool {
+                let bit = flag as i32;
+                self.0 & bit == bit
+            }
+
+            /// Set a given flag
+            pub fn insert(&mut self, flag: $flag) {
+                self.0 |= flag as i32;
+            }
+
+            /// Clear a given flag
+            pub fn remove(&mut self, flag: $flag) {
+                self.0 &= !(flag as i32);
+            }
+
+            /// Set or clear a given flag
+            pub fn set(&mut self, flag: $flag, value: bool) {
+                if value {
+                    self.insert(flag);
+                } else {
+                    self.remove(flag);
+                }
+            }
+
+            /// Check whether no flags are set
+            pub fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Get the raw bits of this bitfield
+            pub fn bits(&self) -> i32 {
+                self.0
+            }
+
+            paste::paste! {
+                $(
+                    #[doc = concat!("Check whether the `", stringify!($variant), "` flag is set")]
+                    pub fn [<is_ $variant:snake>](&self) -> bool {
+                        self.has($flag::$variant)
+                    }
+                )*
+            }
+        }
+
+        impl From<i32> for $name {
+            fn from(bits: i32) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$name> for i32 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$flag> for $name {
+            fn from(flag: $flag) -> Self {
+                Self(flag as i32)
+            }
+        }
+
+        impl std::ops::BitOr<$flag> for $name {
+            type Output = $name;
+
+            fn bitor(mut self, flag: $flag) -> $name {
+                self.insert(flag);
+                self
+            }
+        }
+
+        impl std::ops::BitOrAssign<$flag> for $name {
+            fn bitor_assign(&mut self, flag: $flag) {
+                self.insert(flag);
+            }
+        }
+    };
+}
+
 pub mod v0;
 
+#[cfg(feature = "schemas")]
+pub mod schema;
+
 /// Utility function to check if a boolean value is false
 pub fn if_false(t: &bool) -> bool {
     !t
@@ -0,0 +1,238 @@
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::RootSchema,
+};
+
+use crate::v0::*;
+
+/// Add definitions for each given model to the generator
+macro_rules! define {
+    ( $gen:ident, $( $model:ty ),+ $(,)? ) => {
+        $(
+            $gen.subschema_for::<$model>();
+        )+
+    };
+}
+
+/// Generate a JSON Schema document with definitions for every v0 model
+pub fn v0() -> RootSchema {
+    let mut gen = SchemaGenerator::new(SchemaSettings::draft07());
+
+    define!(
+        gen,
+        // Account Strikes
+        AccountStrike,
+        DataCreateStrike,
+        DataEditAccountStrike,
+        // Admin Audit Log
+        AdminAuditEntry,
+        AdminAuditExport,
+        // Bots
+        Bot,
+        BotFlags,
+        BotFlagsValue,
+        PublicBot,
+        FetchBotResponse,
+        // Broadcasts
+        Broadcast,
+        BroadcastTarget,
+        DataCreateBroadcast,
+        // Challenges
+        Challenge,
+        ChallengeReason,
+        ChallengeKind,
+        // Channel Feeds
+        ChannelFeed,
+        DataCreateChannelFeed,
+        DataEditChannelFeed,
+        FieldsChannelFeed,
+        // Channel Inboxes
+        ChannelInbox,
+        DataInboundEmail,
+        // Channel Schedules
+        ChannelSchedule,
+        ChannelScheduleChanges,
+        DataCreateChannelSchedule,
+        // Channel Topic History
+        ChannelTopicChange,
+        TopicFieldChange,
+        // Channel Webhooks
+        Webhook,
+        MessageWebhook,
+        DataEditWebhook,
+        ResponseWebhook,
+        FieldsWebhook,
+        // Channels
+        Channel,
+        PartialChannel,
+        FieldsChannel,
+        FileTypeRestrictions,
+        DataEditChannel,
+        // Emojis
+        Emoji,
+        EmojiParent,
+        DataCreateEmoji,
+        // Errors
+        ApiError,
+        ApiErrorDetails,
+        ApiFieldError,
+        // Feature Flags
+        FeatureFlag,
+        DataEditFeatureFlag,
+        // Federation
+        FederatedMessage,
+        DataSendFederatedMessage,
+        FederationKey,
+        FederationEnvelope,
+        FederationRequest,
+        FederationPayload,
+        // Files
+        File,
+        Metadata,
+        // Keyword Highlights
+        KeywordHighlights,
+        DataSetKeywordHighlights,
+        // Maintenance
+        MaintenanceStatus,
+        DataEditMaintenance,
+        // Messages
+        Reply,
+        SendableEmbed,
+        SystemMessage,
+        Masquerade,
+        Interactions,
+        MessageSort,
+        MessageTimePeriod,
+        MessageFilter,
+        AttachmentFilter,
+        MessageQuery,
+        BulkMessageResponse,
+        AppendMessage,
+        DataMessageSend,
+        ScheduledMessage,
+        Message,
+        // Ban Sync
+        BanSyncGroup,
+        BanSyncMember,
+        BanSyncPolicy,
+        BanSyncProposal,
+        DataCreateBanSyncGroup,
+        DataJoinBanSyncGroup,
+        // Moderation Cases
+        ModerationCase,
+        ModerationAction,
+        ModerationCaseNote,
+        DataWarnMember,
+        DataCreateCaseNote,
+        // Policies
+        PolicyDocument,
+        DataPublishPolicy,
+        DataAcceptPolicy,
+        // Reaction Roles
+        ReactionRole,
+        DataCreateReactionRole,
+        // Reports
+        ContentReportReason,
+        UserReportReason,
+        ReportedContent,
+        ReportStatus,
+        ReportStatusString,
+        Report,
+        // Role Templates
+        RoleTemplate,
+        DataCreateRoleTemplate,
+        // Server Announcements
+        ServerAnnouncement,
+        AnnouncementRecurrence,
+        RecurrenceUnit,
+        DataCreateServerAnnouncement,
+        // Server Audit Log
+        AuditLogEntry,
+        AuditLogAction,
+        // Servers
+        Category,
+        OnboardingOption,
+        OnboardingQuestion,
+        Onboarding,
+        Highlights,
+        WarnThreshold,
+        ServerFlags,
+        ServerFlagsValue,
+        SystemMessageChannels,
+        NotificationMode,
+        FieldsServer,
+        FieldsRole,
+        Role,
+        Server,
+        // Session Scopes
+        SessionScope,
+        DataCreateScopedSession,
+        ScopedSession,
+        // Snapshots
+        SnapshotContent,
+        Snapshot,
+        SnapshotWithContext,
+        // Stats
+        IndexAccess,
+        Index,
+        LatencyHistogramEntry,
+        LatencyStats,
+        StorageStats,
+        CollectionScans,
+        QueryExecStats,
+        CollectionStats,
+        Stats,
+        // Threads
+        Thread,
+        PartialThread,
+        DataCreateThread,
+        DataEditThread,
+        // Users
+        User,
+        RelationshipStatus,
+        Relationship,
+        Presence,
+        UserStatus,
+        UserProfile,
+        ProfileLink,
+        UserBadges,
+        UserFlags,
+        UserBadgesValue,
+        UserFlagsValue,
+        BotInformation,
+        GuestInformation,
+        // Voice Sessions
+        VoiceSession,
+    );
+
+    #[cfg(feature = "partials")]
+    define!(
+        gen,
+        PartialWebhook,
+        PartialMessage,
+        PartialReport,
+        PartialRole,
+        PartialServer,
+    );
+
+    RootSchema {
+        meta_schema: gen.settings().meta_schema.clone(),
+        definitions: gen.take_definitions(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Write the v0 JSON Schema next to the TypeScript bindings
+    #[test]
+    fn export_json_schema() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/bindings/schema.json");
+        std::fs::create_dir_all(std::path::Path::new(path).parent().unwrap()).unwrap();
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(&super::v0()).expect("serialisable schema"),
+        )
+        .unwrap();
+    }
+}
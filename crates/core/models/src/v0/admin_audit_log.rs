@@ -0,0 +1,35 @@
+auto_derived!(
+    /// Record of a mutation made through the admin API
+    pub struct AdminAuditEntry {
+        /// Entry Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Position of this entry in the log, starting from 1
+        pub sequence: i64,
+        /// Id of the privileged user who made the change
+        pub actor_id: String,
+        /// Operation that was performed, such as `strike.create`
+        pub operation: String,
+        /// Id of the object that was changed
+        pub target_id: String,
+        /// JSON representation of the object before the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub before: Option<String>,
+        /// JSON representation of the object after the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub after: Option<String>,
+        /// Hash of the previous entry in the log
+        pub previous_hash: String,
+        /// Hash of this entry, chained onto the previous hash
+        pub hash: String,
+    }
+
+    /// Full export of the admin audit log
+    pub struct AdminAuditExport {
+        /// Every entry in the log, oldest first
+        pub entries: Vec<AdminAuditEntry>,
+        /// Id of the first entry which failed verification, if any
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub tampered_at: Option<String>,
+    }
+);
@@ -0,0 +1,68 @@
+auto_derived!(
+    /// Group of servers which share their bans with one another
+    pub struct BanSyncGroup {
+        /// Ban sync group Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Display name of the group
+        pub name: String,
+        /// Code other servers use to join this group
+        pub join_code: String,
+        /// Servers taking part in this group
+        pub members: Vec<BanSyncMember>,
+    }
+
+    /// Server taking part in a ban sync group
+    pub struct BanSyncMember {
+        /// Id of the server
+        pub server_id: String,
+        /// How bans from other servers in the group are handled
+        pub policy: BanSyncPolicy,
+    }
+
+    /// How a server handles bans shared by others in its group
+    pub enum BanSyncPolicy {
+        /// Bans are proposed to the server's moderators
+        Propose,
+        /// Bans are applied immediately
+        AutoApply,
+    }
+
+    /// Ban shared by another server in a ban sync group, awaiting review
+    pub struct BanSyncProposal {
+        /// Proposal Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Ban sync group the ban was shared through
+        pub group_id: String,
+        /// Server the ban was originally applied in
+        pub source_server_id: String,
+        /// Server the ban is proposed to
+        pub server_id: String,
+        /// Id of the user to ban
+        pub user_id: String,
+        /// Reason given for the original ban
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+    }
+
+    /// New ban sync group information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateBanSyncGroup {
+        /// Display name of the group
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// How bans from other servers in the group are handled
+        pub policy: BanSyncPolicy,
+    }
+
+    /// Ban sync group join information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataJoinBanSyncGroup {
+        /// Join code shared by a server in the group
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 64)))]
+        pub code: String,
+        /// How bans from other servers in the group are handled
+        pub policy: BanSyncPolicy,
+    }
+);
@@ -50,9 +50,9 @@ auto_derived!(
         /// Enum of bot flags
         #[cfg_attr(
             feature = "serde",
-            serde(skip_serializing_if = "crate::if_zero_u32", default)
+            serde(skip_serializing_if = "BotFlagsValue::is_empty", default)
         )]
-        pub flags: u32,
+        pub flags: BotFlagsValue,
     }
 
     /// Flags that may be attributed to a bot
@@ -86,3 +86,11 @@ auto_derived!(
         pub user: User,
     }
 );
+
+bitfield!(
+    /// Bitfield of bot flags
+    BotFlagsValue: BotFlags {
+        Verified,
+        Official,
+    }
+);
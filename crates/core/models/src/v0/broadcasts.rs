@@ -0,0 +1,54 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Announcement sent to users by the instance administrators
+    pub struct Broadcast {
+        /// Broadcast Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Administrator who created this broadcast
+        pub author_id: String,
+
+        /// Content of the broadcast
+        pub content: String,
+        /// Users who receive this broadcast
+        pub target: BroadcastTarget,
+        /// Whether to also send the broadcast as a direct message from the system user
+        pub send_dm: bool,
+        /// Time at which the broadcast should be sent
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub send_at: Timestamp,
+        /// Whether the broadcast has been sent
+        pub sent: bool,
+    }
+
+    /// Users who receive a broadcast
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum BroadcastTarget {
+        /// Every user on the instance
+        All,
+        /// Members of the given servers
+        Servers {
+            /// Server Ids
+            ids: Vec<String>,
+        },
+    }
+
+    /// New broadcast information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateBroadcast {
+        /// Content of the broadcast
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+        /// Users who receive this broadcast
+        pub target: BroadcastTarget,
+        /// Whether to also send the broadcast as a direct message from the system user
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub send_dm: bool,
+        /// Time at which the broadcast should be sent
+        ///
+        /// Sent immediately if not specified
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub send_at: Option<Timestamp>,
+    }
+);
@@ -0,0 +1,30 @@
+auto_derived!(
+    /// Challenge which must be solved before an action may be retried
+    pub struct Challenge {
+        /// Challenge Id, sent back along with the solution
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Why the challenge was issued
+        pub reason: ChallengeReason,
+        /// How the challenge is solved
+        pub kind: ChallengeKind,
+    }
+
+    /// Suspicious activity which caused a challenge to be issued
+    pub enum ChallengeReason {
+        /// Opening direct messages with many users in a short time
+        MassDirectMessage,
+        /// Joining many servers in a short time
+        RapidJoins,
+    }
+
+    /// Method by which a challenge is solved
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum ChallengeKind {
+        /// Solve an hCaptcha using the given site key
+        HCaptcha {
+            /// Client key used for solving the captcha
+            site_key: String,
+        },
+    }
+);
@@ -0,0 +1,56 @@
+auto_derived!(
+    /// RSS / Atom feed subscription for a channel
+    pub struct ChannelFeed {
+        /// Feed Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// The channel new entries are posted to
+        pub channel_id: String,
+        /// The webhook used to post new entries
+        pub webhook_id: String,
+        /// The user who created this subscription
+        pub creator_id: String,
+
+        /// URL of the RSS or Atom feed
+        pub url: String,
+        /// How often the feed should be polled, in minutes
+        pub interval: u32,
+        /// Only post entries whose title or summary contains this text
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub filter: Option<String>,
+    }
+
+    /// New feed subscription information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateChannelFeed {
+        /// URL of the RSS or Atom feed
+        #[cfg_attr(feature = "validator", validate(url, length(min = 1, max = 512)))]
+        pub url: String,
+        /// How often the feed should be polled, in minutes
+        #[cfg_attr(feature = "validator", validate(range(min = 5, max = 1440)))]
+        pub interval: Option<u32>,
+        /// Only post entries whose title or summary contains this text
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub filter: Option<String>,
+    }
+
+    /// Changes to a feed subscription
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditChannelFeed {
+        /// How often the feed should be polled, in minutes
+        #[cfg_attr(feature = "validator", validate(range(min = 5, max = 1440)))]
+        pub interval: Option<u32>,
+        /// Only post entries whose title or summary contains this text
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub filter: Option<String>,
+
+        /// Fields to remove from feed
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub remove: Vec<FieldsChannelFeed>,
+    }
+
+    /// Optional fields on channel feed object
+    pub enum FieldsChannelFeed {
+        Filter,
+    }
+);
@@ -0,0 +1,40 @@
+auto_derived!(
+    /// Inbound email address assigned to a channel
+    pub struct ChannelInbox {
+        /// Inbox Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Full email address which delivers to this inbox
+        pub address: String,
+
+        /// The channel this inbox delivers to
+        pub channel_id: String,
+        /// The webhook used to post received mail
+        pub webhook_id: String,
+        /// The user who assigned this address
+        pub creator_id: String,
+    }
+
+    /// Email received by the mail relay
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataInboundEmail {
+        /// Recipient addresses
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 50)))]
+        pub to: Vec<String>,
+        /// Sender address
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 256)))]
+        pub from: String,
+        /// Sender display name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 256)))]
+        pub from_name: Option<String>,
+
+        /// Email subject
+        pub subject: Option<String>,
+        /// Plain text body of the email
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub text: String,
+        /// Ids of attachments already uploaded to the file server
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub attachments: Vec<String>,
+    }
+);
@@ -0,0 +1,58 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Scheduled change to a channel's settings
+    pub struct ChannelSchedule {
+        /// Schedule Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// The channel to change
+        pub channel_id: String,
+        /// The user who scheduled this change
+        pub creator_id: String,
+
+        /// Time at which the change should be applied
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub run_at: Timestamp,
+        /// Settings to apply
+        pub changes: ChannelScheduleChanges,
+    }
+
+    /// Channel settings applied by a schedule
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct ChannelScheduleChanges {
+        /// Channel name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+        /// Channel description
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Whether this channel is age-restricted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub nsfw: Option<bool>,
+        /// Whether this text channel is locked
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub locked: Option<bool>,
+        /// Whether every message in this text channel must contain an attachment or link
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub media_only: Option<bool>,
+        /// Slowmode for this text channel in seconds, zero turns it off
+        #[cfg_attr(feature = "validator", validate(range(max = 21600)))]
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub slowmode: Option<u32>,
+    }
+
+    /// New scheduled channel change information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateChannelSchedule {
+        /// Time at which the change should be applied
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub run_at: Timestamp,
+        /// Settings to apply
+        #[cfg_attr(feature = "validator", validate)]
+        pub changes: ChannelScheduleChanges,
+    }
+);
@@ -0,0 +1,29 @@
+auto_derived!(
+    /// Change made to a channel's name or description
+    pub struct ChannelTopicChange {
+        /// Change Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Channel that was changed
+        pub channel_id: String,
+        /// User who made the change
+        pub author_id: String,
+
+        /// Change to the channel name
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<TopicFieldChange>,
+        /// Change to the channel description
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<TopicFieldChange>,
+    }
+
+    /// Value of a channel field before and after a change
+    pub struct TopicFieldChange {
+        /// Previous value
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub before: Option<String>,
+        /// New value
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub after: Option<String>,
+    }
+);
@@ -18,11 +18,29 @@ auto_derived_partial!(
 
         /// The private token for the webhook
         pub token: Option<String>,
+
+        /// Limits on how often this webhook may send messages
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub limits: Option<WebhookLimits>,
     },
     "PartialWebhook"
 );
 
 auto_derived!(
+    /// Limits on how often a webhook may send messages
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct WebhookLimits {
+        /// Maximum number of messages per minute
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000)))]
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub per_minute: Option<u32>,
+        /// Maximum number of messages per day
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000000)))]
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub per_day: Option<u32>,
+    }
+
     /// Information about the webhook bundled with Message
     pub struct MessageWebhook {
         // The name of the webhook - 1 to 32 chars
@@ -43,6 +61,12 @@ auto_derived!(
         #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
         pub avatar: Option<String>,
 
+        /// Message limits
+        ///
+        /// Can only be changed by members with `ManageWebhooks`.
+        #[cfg_attr(feature = "validator", validate)]
+        pub limits: Option<WebhookLimits>,
+
         /// Fields to remove from webhook
         #[cfg_attr(feature = "serde", serde(default))]
         pub remove: Vec<FieldsWebhook>,
@@ -66,6 +90,7 @@ auto_derived!(
     /// Optional fields on webhook object
     pub enum FieldsWebhook {
         Avatar,
+        Limits,
     }
 );
 
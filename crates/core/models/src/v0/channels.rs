@@ -103,6 +103,36 @@ auto_derived!(
                 serde(skip_serializing_if = "crate::if_false", default)
             )]
             nsfw: bool,
+
+            /// Whether only members with the ManageThreads permission may post
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            locked: bool,
+
+            /// Whether every message must contain an attachment or link
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            media_only: bool,
+
+            /// Role required to post in this channel, everyone else may only react
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            posting_role: Option<String>,
+
+            /// Seconds each member must wait between sending messages
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            slowmode: Option<u32>,
+
+            /// Depth of reply chain after which further replies are moved into a thread
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            auto_thread_depth: Option<u32>,
+
+            /// Restrictions on the content types of attachments sent in this channel
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            file_types: Option<FileTypeRestrictions>,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -120,6 +150,9 @@ auto_derived!(
             /// Custom icon attachment
             #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
             icon: Option<File>,
+            /// Id of the last message sent in this channel
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            last_message_id: Option<String>,
 
             /// Default permissions assigned to users in this channel
             #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -140,6 +173,14 @@ auto_derived!(
                 serde(skip_serializing_if = "crate::if_false", default)
             )]
             nsfw: bool,
+
+            /// Maximum number of users who can be connected at once
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            user_limit: Option<u32>,
+
+            /// Restrictions on the content types of attachments sent in this channel
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            file_types: Option<FileTypeRestrictions>,
         },
     }
 
@@ -166,6 +207,20 @@ auto_derived!(
         pub default_permissions: Option<OverrideField>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub last_message_id: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub user_limit: Option<u32>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub locked: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub media_only: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub posting_role: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub slowmode: Option<u32>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub auto_thread_depth: Option<u32>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub file_types: Option<FileTypeRestrictions>,
     }
 
     /// Optional fields on channel object
@@ -173,6 +228,30 @@ auto_derived!(
         Description,
         Icon,
         DefaultPermissions,
+        UserLimit,
+        PostingRole,
+        FileTypes,
+        Slowmode,
+        AutoThreadDepth,
+    }
+
+    /// Attachment content types permitted in a channel
+    ///
+    /// Entries are either exact content types (`application/pdf`) or
+    /// wildcards over a top-level type (`image/*`).
+    pub struct FileTypeRestrictions {
+        /// Content types which may be sent, an empty list permits everything
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        pub allow: Vec<String>,
+        /// Content types which may never be sent, takes priority over `allow`
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        pub block: Vec<String>,
     }
 
     /// New webhook information
@@ -0,0 +1,64 @@
+auto_derived!(
+    /// Custom emoji uploaded to a server
+    pub struct Emoji {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// What owns this emoji
+        pub parent: EmojiParent,
+        /// Uploader user id
+        pub creator_id: String,
+        /// Emoji name
+        pub name: String,
+        /// Alternative names this emoji can be found by
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        pub aliases: Vec<String>,
+        /// Whether the emoji is animated
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub animated: bool,
+        /// Whether the emoji is marked as nsfw
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+        /// Id of the emoji which replaced this one
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub replaced_by: Option<String>,
+    }
+
+    /// Information about what owns this emoji
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum EmojiParent {
+        /// Emoji belongs to a server
+        Server {
+            /// Server Id
+            id: String,
+        },
+        /// Emoji was deleted or replaced and no longer belongs to anything
+        Detached,
+    }
+
+    /// New emoji information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateEmoji {
+        /// Emoji name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Alternative names for the emoji
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 10)))]
+        pub aliases: Vec<String>,
+        /// Parent information
+        pub parent: EmojiParent,
+        /// Whether the emoji is mature
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub nsfw: bool,
+    }
+);
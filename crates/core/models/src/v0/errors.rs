@@ -0,0 +1,81 @@
+use super::Challenge;
+
+auto_derived!(
+    /// Machine-readable error returned by every API route
+    pub struct ApiError {
+        /// Stable error code which clients should match on, such as `UnknownChannel`
+        #[cfg_attr(feature = "serde", serde(rename = "type"))]
+        pub code: String,
+        /// Human readable description of what went wrong
+        pub message: String,
+        /// Additional information carried by some errors
+        #[cfg_attr(feature = "serde", serde(flatten))]
+        pub details: ApiErrorDetails,
+        /// Validation failures for individual fields of the request
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub fields: Vec<ApiFieldError>,
+        /// Milliseconds to wait before retrying, only present if retrying may succeed
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub retry_after: Option<u64>,
+        /// Where this error occurred
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub location: Option<String>,
+    }
+
+    /// Additional information carried by some errors
+    #[derive(Default)]
+    pub struct ApiErrorDetails {
+        /// Limit which was reached
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub max: Option<usize>,
+        /// Number of users already connected to a voice channel
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub connected: Option<usize>,
+        /// Permission which is missing
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub permission: Option<String>,
+        /// Reason given for rejecting the request
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+        /// Database operation which failed
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub operation: Option<String>,
+        /// Database collection the operation failed on
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub collection: Option<String>,
+        /// Database collection the operation failed on
+        ///
+        /// **Legacy name for `collection`**
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub with: Option<String>,
+        /// Description of a validation failure
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub error: Option<String>,
+        /// Policy document which must be accepted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub policy: Option<String>,
+        /// Version of the policy document which must be accepted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub version: Option<u32>,
+        /// Content type of the file which was rejected
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub content_type: Option<String>,
+        /// Challenge which must be solved before retrying
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub challenge: Option<Challenge>,
+    }
+
+    /// Validation failure for a single field
+    pub struct ApiFieldError {
+        /// Path to the field, such as `embeds[0].title`
+        pub field: String,
+        /// Validation rule which failed, such as `length`
+        pub code: String,
+        /// Human readable description of the failure
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub message: Option<String>,
+    }
+);
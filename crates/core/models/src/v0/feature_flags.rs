@@ -0,0 +1,26 @@
+auto_derived!(
+    /// Experimental feature which can be toggled at runtime
+    pub struct FeatureFlag {
+        /// Flag name
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Whether the feature is enabled across the whole instance
+        pub enabled: bool,
+        /// Servers the feature is enabled in regardless of the instance setting
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub servers: Vec<String>,
+    }
+
+    /// Changes to a feature flag
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditFeatureFlag {
+        /// Whether the feature is enabled across the whole instance
+        pub enabled: Option<bool>,
+        /// Servers the feature is enabled in regardless of the instance setting
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub servers: Option<Vec<String>>,
+    }
+);
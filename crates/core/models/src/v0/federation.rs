@@ -0,0 +1,71 @@
+auto_derived!(
+    /// Direct message exchanged with a user on another instance
+    pub struct FederatedMessage {
+        /// Message Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the local user participating in the conversation
+        pub user_id: String,
+        /// Identifier of the remote user, in the form `user_id@domain`
+        pub remote_user: String,
+        /// Whether this message was sent by the local user
+        pub outgoing: bool,
+
+        /// Message content
+        pub content: String,
+    }
+
+    /// New federated direct message
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataSendFederatedMessage {
+        /// Message content to send
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+    }
+
+    /// Public signing key of this instance
+    pub struct FederationKey {
+        /// Domain of this instance
+        pub domain: String,
+        /// Base64 encoded Ed25519 public key
+        pub public_key: String,
+    }
+
+    /// Signed request sent between instances
+    pub struct FederationEnvelope {
+        /// Domain of the sending instance
+        pub origin: String,
+        /// JSON encoded [`FederationRequest`]
+        pub payload: String,
+        /// Base64 encoded Ed25519 signature of the payload
+        pub signature: String,
+    }
+
+    /// Signed request sent between instances
+    pub struct FederationRequest {
+        /// Random value unique to this request, used to reject replays
+        pub nonce: String,
+        /// Unix time in seconds at which this request was signed
+        pub timestamp: u64,
+        /// Contents of the request
+        pub payload: FederationPayload,
+    }
+
+    /// Contents of a signed request sent between instances
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum FederationPayload {
+        /// Direct message from a user on the sending instance
+        DirectMessage {
+            /// Unique message id
+            id: String,
+            /// Id of the sending user on the origin instance
+            from: String,
+            /// Id of the receiving user on the destination instance
+            to: String,
+            /// Domain of the destination instance
+            destination: String,
+            /// Message content
+            content: String,
+        },
+    }
+);
@@ -14,6 +14,11 @@ auto_derived!(
         pub content_type: String,
         /// Size of this file (in bytes)
         pub size: isize,
+        /// Storage region this file was uploaded to
+        ///
+        /// Set by the file server, files without one are in the default region.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub region: Option<String>,
 
         /// Whether this file was deleted
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,15 @@
+auto_derived!(
+    /// Keywords a member wants to be notified about in a server
+    pub struct KeywordHighlights {
+        /// Keywords which trigger a notification, matched as whole words ignoring case
+        pub keywords: Vec<String>,
+    }
+
+    /// New keyword highlights
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataSetKeywordHighlights {
+        /// Keywords which should trigger a notification, an empty list removes all keywords
+        #[cfg_attr(feature = "validator", validate(length(max = 25)))]
+        pub keywords: Vec<String>,
+    }
+);
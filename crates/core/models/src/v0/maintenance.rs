@@ -0,0 +1,29 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Instance-wide maintenance state
+    pub struct MaintenanceStatus {
+        /// Whether the API is rejecting writes
+        pub enabled: bool,
+        /// Message shown to users while maintenance is in progress
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub message: Option<String>,
+        /// Time at which maintenance is expected to end
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+        pub ends_at: Option<Timestamp>,
+    }
+
+    /// New maintenance state
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditMaintenance {
+        /// Whether the API should reject writes
+        pub enabled: bool,
+        /// Message shown to users while maintenance is in progress
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1024)))]
+        pub message: Option<String>,
+        /// Time at which maintenance is expected to end
+        #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+        pub ends_at: Option<Timestamp>,
+    }
+);
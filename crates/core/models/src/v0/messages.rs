@@ -63,6 +63,26 @@ auto_derived!(
         ChannelIconChanged { by: String },
         #[serde(rename = "channel_ownership_changed")]
         ChannelOwnershipChanged { from: String, to: String },
+        #[serde(rename = "channel_locked")]
+        ChannelLocked { by: String },
+        #[serde(rename = "channel_unlocked")]
+        ChannelUnlocked { by: String },
+        #[serde(rename = "message_highlighted")]
+        MessageHighlighted {
+            id: String,
+            channel: String,
+            author: String,
+        },
+        #[serde(rename = "member_warned")]
+        MemberWarned {
+            server: String,
+            by: String,
+            reason: String,
+        },
+        #[serde(rename = "message_pinned")]
+        MessagePinned { id: String, by: String },
+        #[serde(rename = "thread_started")]
+        ThreadStarted { id: String, name: String },
     }
 
     /// Name and / or avatar override information
@@ -136,10 +156,34 @@ auto_derived!(
     pub struct MessageFilter {
         /// Parent channel ID
         pub channel: Option<String>,
+        /// Parent channel IDs, any of which may match
+        pub channels: Option<Vec<String>>,
         /// Message author ID
         pub author: Option<String>,
         /// Search query
         pub query: Option<String>,
+        /// Attachment filter
+        pub attachment: Option<AttachmentFilter>,
+    }
+
+    /// # Attachment Filter
+    ///
+    /// Matches messages with at least one attachment meeting all given criteria
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct AttachmentFilter {
+        /// Filename prefix to match, case-insensitive
+        ///
+        /// Meilisearch only matches whole filenames.
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub filename: Option<String>,
+        /// Content type (e.g. `application/pdf`) or type family (e.g. `image`) to match
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub content_type: Option<String>,
+        /// Minimum file size in bytes
+        pub min_size: Option<isize>,
+        /// Maximum file size in bytes
+        pub max_size: Option<isize>,
     }
 
     /// # Message Query
@@ -207,6 +251,36 @@ auto_derived!(
         pub masquerade: Option<Masquerade>,
         /// Information about how this message should be interacted with
         pub interactions: Option<Interactions>,
+        /// Animation clients should play when displaying this message
+        ///
+        /// Must be one of the effects enabled on this instance
+        #[validate(length(min = 1, max = 32))]
+        pub effect: Option<String>,
+        /// Thread to post this message in
+        #[validate(length(min = 26, max = 26))]
+        pub thread: Option<String>,
+        /// Time at which this message should be sent
+        ///
+        /// If set, the message is held back and sent at this time instead
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub send_at: Option<Timestamp>,
+    }
+
+    /// # Scheduled Message
+    ///
+    /// Message waiting to be sent at a later time
+    pub struct ScheduledMessage {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel this message will be sent in
+        pub channel_id: String,
+        /// Id of the user who scheduled this message
+        pub author_id: String,
+        /// Time at which this message will be sent
+        pub send_at: Timestamp,
+        /// Message to send
+        pub message: DataMessageSend,
     }
 );
 auto_derived_partial!(
@@ -221,6 +295,9 @@ auto_derived_partial!(
         pub nonce: Option<String>,
         /// Id of the channel this message was sent in
         pub channel: String,
+        /// Id of the thread this message was posted in
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread: Option<String>,
         /// Id of the user or webhook that sent this message
         pub author: String,
         /// The webhook that sent this message
@@ -237,6 +314,7 @@ auto_derived_partial!(
         pub attachments: Option<Vec<File>>,
         /// Time at which this message was last edited
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "ts", ts(type = "string | null"))]
         pub edited: Option<Timestamp>,
         /// Attached embeds to this message
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,6 +322,9 @@ auto_derived_partial!(
         /// Array of user ids mentioned in this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub mentions: Option<Vec<String>>,
+        /// Array of role ids mentioned in this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub role_mentions: Option<Vec<String>>,
         /// Array of message ids this message is replying to
         #[serde(skip_serializing_if = "Option::is_none")]
         pub replies: Option<Vec<String>>,
@@ -256,6 +337,12 @@ auto_derived_partial!(
         /// Name and / or avatar overrides for this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub masquerade: Option<Masquerade>,
+        /// Animation clients should play when displaying this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub effect: Option<String>,
+        /// Whether this message is pinned in its channel
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub pinned: bool,
     },
     "PartialMessage"
 );
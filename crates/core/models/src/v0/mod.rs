@@ -1,23 +1,69 @@
 mod account_strikes;
+mod admin_audit_log;
+mod ban_sync;
 mod bots;
+mod broadcasts;
+mod challenges;
+mod channel_feeds;
+mod channel_inboxes;
+mod channel_schedules;
+mod channel_topic_history;
 mod channel_webhooks;
 mod channels;
+mod emojis;
+mod errors;
+mod feature_flags;
+mod federation;
 mod files;
+mod keyword_highlights;
+mod maintenance;
 mod messages;
+mod moderation_cases;
+mod policies;
+mod reaction_roles;
 mod reports;
+mod role_templates;
+mod server_announcements;
+mod server_audit_log;
 mod servers;
+mod session_scopes;
 mod snapshots;
 mod stats;
+mod threads;
 mod users;
+mod voice_sessions;
 
 pub use account_strikes::*;
+pub use admin_audit_log::*;
+pub use ban_sync::*;
 pub use bots::*;
+pub use broadcasts::*;
+pub use challenges::*;
+pub use channel_feeds::*;
+pub use channel_inboxes::*;
+pub use channel_schedules::*;
+pub use channel_topic_history::*;
 pub use channel_webhooks::*;
 pub use channels::*;
+pub use emojis::*;
+pub use errors::*;
+pub use feature_flags::*;
+pub use federation::*;
 pub use files::*;
+pub use keyword_highlights::*;
+pub use maintenance::*;
 pub use messages::*;
+pub use moderation_cases::*;
+pub use policies::*;
+pub use reaction_roles::*;
 pub use reports::*;
+pub use role_templates::*;
+pub use server_announcements::*;
+pub use server_audit_log::*;
 pub use servers::*;
+pub use session_scopes::*;
 pub use snapshots::*;
 pub use stats::*;
+pub use threads::*;
 pub use users::*;
+pub use voice_sessions::*;
@@ -0,0 +1,76 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Moderation action taken against a member of a server
+    pub struct ModerationCase {
+        /// Case Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Server the action was taken in
+        pub server_id: String,
+        /// Number of this case within the server, starting from 1
+        pub case_number: u32,
+        /// Id of the user the action was taken against
+        pub user_id: String,
+        /// Id of the moderator who took the action
+        pub moderator_id: String,
+        /// Action which was taken
+        pub action: ModerationAction,
+        /// Reason given for the action
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+        /// Notes left on this case by moderators
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        pub notes: Vec<ModerationCaseNote>,
+    }
+
+    /// Action taken against a member
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum ModerationAction {
+        /// Member was warned
+        Warning,
+        /// Member was timed out
+        Timeout {
+            /// Time at which the timeout ends
+            #[cfg_attr(feature = "ts", ts(type = "string"))]
+            until: Timestamp,
+        },
+        /// Member was kicked
+        Kick,
+        /// User was banned
+        Ban,
+    }
+
+    /// Note left on a moderation case
+    pub struct ModerationCaseNote {
+        /// Note Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the moderator who left the note
+        pub author_id: String,
+        /// Note content
+        pub content: String,
+    }
+
+    /// New warning information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataWarnMember {
+        /// Reason for the warning
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1024)))]
+        pub reason: String,
+        /// Whether to send the user a notice about the warning
+        #[serde(default)]
+        pub notify: bool,
+    }
+
+    /// New case note information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateCaseNote {
+        /// Note content
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+    }
+);
@@ -0,0 +1,35 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Terms of service or other policy users must accept to use this instance
+    pub struct PolicyDocument {
+        /// Document name
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Document title
+        pub title: String,
+        /// Current version, incremented every time the document is published
+        pub version: u32,
+        /// Document content
+        pub content: String,
+        /// Time at which the current version was published
+        pub published_at: Timestamp,
+    }
+
+    /// New version of a policy document
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataPublishPolicy {
+        /// Document title
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: String,
+        /// Document content
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100000)))]
+        pub content: String,
+    }
+
+    /// Acceptance of a policy document
+    pub struct DataAcceptPolicy {
+        /// Version of the document being accepted
+        pub version: u32,
+    }
+);
@@ -0,0 +1,32 @@
+auto_derived!(
+    /// Role granted to members who react to a message with a given emoji
+    pub struct ReactionRole {
+        /// Reaction role Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Server the role belongs to
+        pub server_id: String,
+        /// Channel the message was sent in
+        pub channel_id: String,
+        /// Message to watch for reactions
+        pub message_id: String,
+        /// Emoji which grants the role
+        pub emoji: String,
+        /// Role to grant
+        pub role_id: String,
+    }
+
+    /// New reaction role information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateReactionRole {
+        /// Channel the message was sent in
+        pub channel_id: String,
+        /// Message to watch for reactions
+        pub message_id: String,
+        /// Emoji which grants the role
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 64)))]
+        pub emoji: String,
+        /// Role to grant
+        pub role_id: String,
+    }
+);
@@ -108,11 +108,15 @@ auto_derived!(
         /// Report was rejected
         Rejected {
             rejection_reason: String,
+            #[cfg_attr(feature = "ts", ts(type = "string | null"))]
             closed_at: Option<Timestamp>,
         },
 
         /// Report was actioned and resolved
-        Resolved { closed_at: Option<Timestamp> },
+        Resolved {
+            #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+            closed_at: Option<Timestamp>,
+        },
     }
 
     /// Just the status of the report
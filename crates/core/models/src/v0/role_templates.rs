@@ -0,0 +1,33 @@
+use revolt_permissions::Override;
+
+auto_derived!(
+    /// Preset of permissions which can be applied to server roles
+    pub struct RoleTemplate {
+        /// Template Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Template name
+        pub name: String,
+        /// Description of what this template is for
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Permissions granted or denied by this template
+        pub permissions: Override,
+    }
+
+    /// New role template information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateRoleTemplate {
+        /// Template Id
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub id: String,
+        /// Template name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Description of what this template is for
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub description: Option<String>,
+        /// Permissions granted or denied by this template
+        pub permissions: Override,
+    }
+);
@@ -0,0 +1,58 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Announcement posted to a server channel at a scheduled time
+    pub struct ServerAnnouncement {
+        /// Announcement Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// The server this announcement belongs to
+        pub server_id: String,
+        /// The channel to post in
+        pub channel_id: String,
+        /// The user who scheduled this announcement
+        pub creator_id: String,
+
+        /// Content of the announcement
+        pub content: String,
+        /// Time at which the announcement should next be posted
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub next_run: Timestamp,
+        /// How often the announcement repeats
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub recurrence: Option<AnnouncementRecurrence>,
+    }
+
+    /// Repetition rule for an announcement
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct AnnouncementRecurrence {
+        /// Number of units between each post
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 365)))]
+        pub every: u32,
+        /// Unit of time to repeat by
+        pub unit: RecurrenceUnit,
+    }
+
+    /// Unit of time used by a recurrence
+    pub enum RecurrenceUnit {
+        Hour,
+        Day,
+        Week,
+    }
+
+    /// New scheduled announcement information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateServerAnnouncement {
+        /// The channel to post in
+        pub channel_id: String,
+        /// Content of the announcement
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+        /// Time at which the announcement should first be posted
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub run_at: Timestamp,
+        /// How often the announcement repeats
+        #[cfg_attr(feature = "validator", validate)]
+        pub recurrence: Option<AnnouncementRecurrence>,
+    }
+);
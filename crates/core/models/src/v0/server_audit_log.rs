@@ -0,0 +1,51 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Record of a moderation-relevant change made to a server
+    pub struct AuditLogEntry {
+        /// Entry Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Server this change was made in
+        pub server_id: String,
+        /// Id of the user who made the change
+        pub actor_id: String,
+        /// Action which was performed
+        pub action: AuditLogAction,
+        /// Id of the object that was changed
+        pub target_id: String,
+        /// JSON representation of the object before the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub before: Option<String>,
+        /// JSON representation of the object after the change
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub after: Option<String>,
+        /// Time at which the change was made
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub timestamp: Timestamp,
+    }
+
+    /// Action recorded in a server audit log
+    pub enum AuditLogAction {
+        /// Role was created
+        RoleCreate,
+        /// Role was edited
+        RoleUpdate,
+        /// Role was deleted
+        RoleDelete,
+        /// Member was kicked
+        MemberKick,
+        /// Member was banned
+        MemberBan,
+        /// Member was unbanned
+        MemberUnban,
+        /// Channel was deleted
+        ChannelDelete,
+        /// Webhook was created
+        WebhookCreate,
+        /// Webhook was edited
+        WebhookUpdate,
+        /// Webhook was deleted
+        WebhookDelete,
+    }
+);
@@ -21,6 +21,51 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+        /// Whether this category is shown collapsed to members by default
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub collapsed: bool,
+        /// Whether channels in this category are hidden until a role grants access to them
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub hidden: bool,
+    }
+
+    /// Option which may be picked when answering an onboarding question
+    pub struct OnboardingOption {
+        /// Unique ID for this option
+        pub id: String,
+        /// Title for this option
+        pub title: String,
+        /// Roles granted to members who pick this option
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub roles: Vec<String>,
+        /// Channels revealed to members who pick this option
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub channels: Vec<String>,
+    }
+
+    /// Question asked to members during onboarding
+    pub struct OnboardingQuestion {
+        /// Unique ID for this question
+        pub id: String,
+        /// Title for this question
+        pub title: String,
+        /// Whether members must pick at least one option
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub required: bool,
+        /// Whether members may pick more than one option
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub multiple: bool,
+        /// Options available for this question
+        pub options: Vec<OnboardingOption>,
+    }
+
+    /// Onboarding sequence shown to new members
+    pub struct Onboarding {
+        /// Whether members must complete onboarding before participating
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub enabled: bool,
+        /// Questions asked during onboarding
+        pub questions: Vec<OnboardingQuestion>,
     }
     /// Server flag enum
     #[repr(i32)]
@@ -29,6 +74,25 @@ auto_derived!(
         Official = 2,
     }
 
+    /// Configuration for highlighting popular messages in a channel
+    pub struct Highlights {
+        /// ID of channel to post highlighted messages in
+        pub channel: String,
+        /// Number of reactions a message needs to be highlighted
+        pub threshold: usize,
+        /// Only count reactions using this emoji
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub emoji: Option<String>,
+    }
+
+    /// Timeout applied automatically once a member accumulates warnings
+    pub struct WarnThreshold {
+        /// Number of warnings at which the timeout is applied
+        pub warnings: u32,
+        /// Length of the timeout in seconds
+        pub timeout: u64,
+    }
+
     /// System message channel assignments
     pub struct SystemMessageChannels {
         /// ID of channel to send user join messages in
@@ -44,6 +108,17 @@ auto_derived!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub user_banned: Option<String>,
     }
+
+    /// Which messages members are notified about by default
+    #[derive(Copy, Default)]
+    pub enum NotificationMode {
+        /// Notify about every message
+        All,
+        /// Only notify about mentions
+        #[default]
+        Mentions,
+    }
+
     /// Optional fields on server object
     pub enum FieldsServer {
         Description,
@@ -51,13 +126,27 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        Onboarding,
+        Highlights,
+        WarnThresholds,
+        StorageRegion,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        MenuPosition,
     }
 );
+
+bitfield!(
+    /// Bitfield of server flags
+    ServerFlagsValue: ServerFlags {
+        Verified,
+        Official,
+    }
+);
+
 auto_derived_partial!(
     /// Representation of a server role
     #[opt_some_priority]
@@ -77,6 +166,17 @@ auto_derived_partial!(
         /// Ranking of this role
         #[serde(default)]
         pub rank: i64,
+        /// Whether anyone may mention this role
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub mentionable: bool,
+        /// Whether members may give themselves this role
+        #[serde(skip_serializing_if = "if_false", default)]
+        pub self_assignable: bool,
+        /// Position of this role in the self-assignable role menu
+        ///
+        /// Smaller values are shown first.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub menu_position: Option<i64>,
     },
     "PartialRole"
 );
@@ -106,6 +206,15 @@ auto_derived_partial!(
         /// Configuration for sending system event messages
         #[serde(skip_serializing_if = "Option::is_none")]
         pub system_messages: Option<SystemMessageChannels>,
+        /// Onboarding sequence for new members
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub onboarding: Option<Onboarding>,
+        /// Configuration for highlighting popular messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub highlights: Option<Highlights>,
+        /// Timeouts applied automatically when members are warned
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub warn_thresholds: Option<Vec<WarnThreshold>>,
 
         /// Roles for this server
         #[serde(
@@ -125,7 +234,10 @@ auto_derived_partial!(
 
         /// Bitfield of server flags
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub flags: Option<i32>,
+        pub flags: Option<ServerFlagsValue>,
+        /// Storage region files sent in this server must be uploaded to
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub storage_region: Option<String>,
 
         /// Whether this server is flagged as not safe for work
         #[serde(skip_serializing_if = "if_false", default)]
@@ -136,6 +248,10 @@ auto_derived_partial!(
         /// Whether this server should be publicly discoverable
         #[serde(skip_serializing_if = "if_false", default)]
         pub discoverable: bool,
+
+        /// Notification mode given to new members
+        #[serde(default)]
+        pub default_notifications: NotificationMode,
     },
     "PartialServer"
 );
@@ -0,0 +1,31 @@
+auto_derived!(
+    /// Restriction placed on what a session may do
+    pub enum SessionScope {
+        /// Session may only fetch data and cannot make changes
+        ReadOnly,
+        /// Session cannot access direct messages or groups
+        NoDirectMessages,
+    }
+
+    /// New restricted session information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateScopedSession {
+        /// Name of the session, shown in the session list
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 72)))]
+        pub name: String,
+        /// Restrictions to place on the session
+        #[cfg_attr(feature = "validator", validate(length(min = 1)))]
+        pub scopes: Vec<SessionScope>,
+    }
+
+    /// Newly created restricted session
+    pub struct ScopedSession {
+        /// Session Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Session token to authenticate with
+        pub token: String,
+        /// Restrictions placed on the session
+        pub scopes: Vec<SessionScope>,
+    }
+);
@@ -28,7 +28,10 @@ auto_derived!(
         #[serde(rename = "_id")]
         pub id: String,
         /// Report parent Id
-        pub report_id: String,
+        ///
+        /// Snapshots taken while monitoring a server have no parent report.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub report_id: Option<String>,
         /// Snapshot of content
         pub content: SnapshotContent,
     }
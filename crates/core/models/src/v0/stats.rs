@@ -10,6 +10,7 @@ auto_derived!(
         ops: i32,
 
         /// Timestamp at which data keeping begun
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
         since: Timestamp,
     }
 
@@ -92,6 +93,7 @@ auto_derived!(
         ns: String,
 
         /// Local time
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
         local_time: Timestamp,
 
         /// Latency stats
@@ -0,0 +1,73 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived_partial!(
+    /// Thread of messages started from a message in a channel
+    pub struct Thread {
+        /// Thread Id, the same as the message it was started from
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Channel this thread belongs to
+        pub channel_id: String,
+        /// User who started this thread
+        pub creator_id: String,
+
+        /// Name of the thread
+        pub name: String,
+        /// Minutes without new messages after which the thread is archived
+        pub auto_archive_duration: u32,
+        /// Whether this thread is archived
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub archived: bool,
+        /// Whether this thread was archived automatically after going inactive
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub auto_archived: bool,
+        /// Id of the last message posted in this thread
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub last_message_id: Option<String>,
+        /// Time of the last message or unarchive in this thread
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+        pub last_active_at: Option<Timestamp>,
+        /// Users who started or have posted in this thread
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub participants: Vec<String>,
+    },
+    "PartialThread"
+);
+
+auto_derived!(
+    /// New thread information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateThread {
+        /// Name of the thread
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Minutes without new messages after which the thread is archived
+        ///
+        /// Defaults to one day.
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 10080)))]
+        pub auto_archive_duration: Option<u32>,
+    }
+
+    /// Changes to a thread
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditThread {
+        /// Name of the thread
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: Option<String>,
+        /// Minutes without new messages after which the thread is archived
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 10080)))]
+        pub auto_archive_duration: Option<u32>,
+        /// Whether this thread is archived
+        pub archived: Option<bool>,
+    }
+);
@@ -1,3 +1,5 @@
+use iso8601_timestamp::Timestamp;
+
 use super::File;
 
 auto_derived!(
@@ -16,8 +18,8 @@ auto_derived!(
         pub relations: Vec<Relationship>,
 
         /// Bitfield of user badges
-        #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
-        pub badges: u32,
+        #[serde(skip_serializing_if = "UserBadgesValue::is_empty", default)]
+        pub badges: UserBadgesValue,
         /// User's current status
         #[serde(skip_serializing_if = "Option::is_none")]
         pub status: Option<UserStatus>,
@@ -26,14 +28,17 @@ auto_derived!(
         pub profile: Option<UserProfile>,
 
         /// Enum of user flags
-        #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
-        pub flags: u32,
+        #[serde(skip_serializing_if = "UserFlagsValue::is_empty", default)]
+        pub flags: UserFlagsValue,
         /// Whether this user is privileged
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub privileged: bool,
         /// Bot information
         #[serde(skip_serializing_if = "Option::is_none")]
         pub bot: Option<BotInformation>,
+        /// Guest information
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub guest: Option<GuestInformation>,
 
         /// Current session user's relationship with this user
         pub relationship: RelationshipStatus,
@@ -83,6 +88,9 @@ auto_derived!(
         /// Current presence option
         #[serde(skip_serializing_if = "Option::is_none")]
         pub presence: Option<Presence>,
+        /// Activity shown for bots
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub activity: String,
     }
 
     /// User's profile
@@ -93,6 +101,21 @@ auto_derived!(
         /// Background visible on user's profile
         #[serde(skip_serializing_if = "Option::is_none")]
         pub background: Option<File>,
+        /// Personal website
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub website: Option<ProfileLink>,
+        /// Donation or tipping page
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub donation: Option<ProfileLink>,
+    }
+
+    /// Link displayed on a user's profile
+    pub struct ProfileLink {
+        /// URL of the linked page
+        pub url: String,
+        /// Whether the linked page links back to this profile using `rel="me"`
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub verified: bool,
     }
 
     /// User badge bitfield
@@ -141,6 +164,49 @@ auto_derived!(
         #[serde(rename = "owner")]
         pub owner_id: String,
     }
+
+    /// Guest information for if the user is a temporary guest
+    pub struct GuestInformation {
+        /// Id of the server this guest was invited to
+        #[serde(rename = "server")]
+        pub server_id: String,
+        /// Id of the only channel this guest can access
+        #[serde(rename = "channel")]
+        pub channel_id: String,
+        /// Whether this guest may send messages
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub write: bool,
+        /// Time at which this guest account expires
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub expires: Timestamp,
+    }
+);
+
+bitfield!(
+    /// Bitfield of user badges
+    UserBadgesValue: UserBadges {
+        Developer,
+        Translator,
+        Supporter,
+        ResponsibleDisclosure,
+        Founder,
+        PlatformModeration,
+        ActiveSupporter,
+        Paw,
+        EarlyAdopter,
+        ReservedRelevantJokeBadge1,
+        ReservedRelevantJokeBadge2,
+    }
+);
+
+bitfield!(
+    /// Bitfield of user flags
+    UserFlagsValue: UserFlags {
+        Suspended,
+        Deleted,
+        Banned,
+        Spam,
+    }
 );
 
 pub trait CheckRelationship {
@@ -0,0 +1,25 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Time a user spent connected to a voice channel
+    pub struct VoiceSession {
+        /// Session Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Server the voice channel belongs to
+        pub server_id: String,
+        /// Voice channel the user was connected to
+        pub channel_id: String,
+        /// Id of the user
+        pub user_id: String,
+        /// Time at which the user joined
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub joined_at: Timestamp,
+        /// Time at which the user left, absent while still connected
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+        pub left_at: Option<Timestamp>,
+        /// Length of the session in seconds
+        pub duration: u64,
+    }
+);
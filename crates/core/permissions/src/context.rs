@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::{ChannelType, Override, PermissionQuery, RelationshipStatus};
+
+/// User as seen by the permission calculator
+#[derive(Debug, Clone, Default)]
+pub struct UserContext {
+    /// User Id
+    pub id: String,
+    /// Whether this user is a bot
+    pub bot: bool,
+    /// Whether this user is privileged
+    pub privileged: bool,
+}
+
+/// Server role as seen by the permission calculator
+#[derive(Debug, Clone)]
+pub struct RoleContext {
+    /// Ranking of this role, lower ranks take priority
+    pub rank: i64,
+    /// Permissions granted or denied by this role
+    pub permissions: Override,
+}
+
+/// Server as seen by the permission calculator
+#[derive(Debug, Clone, Default)]
+pub struct ServerContext {
+    /// Owner user id
+    pub owner: String,
+    /// Default permissions for all members
+    pub default_permissions: u64,
+    /// Roles available on this server
+    pub roles: HashMap<String, RoleContext>,
+}
+
+/// Server member as seen by the permission calculator
+#[derive(Debug, Clone, Default)]
+pub struct MemberContext {
+    /// Ids of the roles this member has
+    pub roles: Vec<String>,
+    /// Whether this member is currently timed out
+    pub timed_out: bool,
+}
+
+/// Channel as seen by the permission calculator
+#[derive(Debug, Clone)]
+pub struct ChannelContext {
+    /// Type of channel
+    pub channel_type: ChannelType,
+    /// Owner of this group, or user of this saved messages channel
+    pub owner: Option<String>,
+    /// Recipients of this direct message or group
+    pub recipients: Vec<String>,
+    /// Default permissions for this group or server channel
+    ///
+    /// Group channel defaults should be given as an allow-only override.
+    pub default_permissions: Option<Override>,
+    /// Role permission overrides for this server channel
+    pub role_permissions: HashMap<String, Override>,
+}
+
+/// Plain data implementation of [`PermissionQuery`]
+///
+/// Fill in what is known about the perspective user and the target,
+/// then pass this to [`crate::calculate_user_permissions`],
+/// [`crate::calculate_server_permissions`] or [`crate::calculate_channel_permissions`]
+/// to resolve permissions exactly as the server would, without a database.
+#[derive(Debug, Clone)]
+pub struct PermissionContext {
+    /// User we are calculating permissions for
+    pub perspective: UserContext,
+    /// User we are calculating permissions against
+    pub user: Option<UserContext>,
+    /// Relationship of the perspective user with the target user
+    pub relationship: RelationshipStatus,
+    /// Whether the perspective user shares a server or group with the target user
+    pub mutual_connection: bool,
+    /// Server we are calculating permissions in
+    pub server: Option<ServerContext>,
+    /// Membership of the perspective user in the server
+    pub member: Option<MemberContext>,
+    /// Channel we are calculating permissions in
+    pub channel: Option<ChannelContext>,
+}
+
+impl PermissionContext {
+    /// Create a new context from the perspective of a given user
+    pub fn new(perspective: UserContext) -> PermissionContext {
+        PermissionContext {
+            perspective,
+            user: None,
+            relationship: RelationshipStatus::None,
+            mutual_connection: false,
+            server: None,
+            member: None,
+            channel: None,
+        }
+    }
+}
+
+/// Order role overrides from lowest to highest priority
+///
+/// Roles with a lower rank take priority so they must be applied last.
+pub fn sort_role_overrides(mut roles: Vec<(i64, Override)>) -> Vec<Override> {
+    roles.sort_by(|a, b| b.0.cmp(&a.0));
+    roles.into_iter().map(|(_, v)| v).collect()
+}
+
+#[async_trait]
+impl PermissionQuery for PermissionContext {
+    // * For calculating user permission
+
+    async fn are_we_privileged(&mut self) -> bool {
+        self.perspective.privileged
+    }
+
+    async fn are_we_a_bot(&mut self) -> bool {
+        self.perspective.bot
+    }
+
+    async fn are_the_users_same(&mut self) -> bool {
+        self.user
+            .as_ref()
+            .map_or(false, |user| user.id == self.perspective.id)
+    }
+
+    async fn user_relationship(&mut self) -> RelationshipStatus {
+        self.relationship
+    }
+
+    async fn user_is_bot(&mut self) -> bool {
+        self.user.as_ref().map_or(false, |user| user.bot)
+    }
+
+    async fn have_mutual_connection(&mut self) -> bool {
+        self.mutual_connection
+    }
+
+    // * For calculating server permission
+
+    async fn are_we_server_owner(&mut self) -> bool {
+        self.server
+            .as_ref()
+            .map_or(false, |server| server.owner == self.perspective.id)
+    }
+
+    async fn are_we_a_member(&mut self) -> bool {
+        self.member.is_some()
+    }
+
+    async fn get_default_server_permissions(&mut self) -> u64 {
+        self.server
+            .as_ref()
+            .map_or(0, |server| server.default_permissions)
+    }
+
+    async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+        match (&self.server, &self.member) {
+            (Some(server), Some(member)) => sort_role_overrides(
+                member
+                    .roles
+                    .iter()
+                    .filter_map(|id| server.roles.get(id))
+                    .map(|role| (role.rank, role.permissions.clone()))
+                    .collect(),
+            ),
+            _ => vec![],
+        }
+    }
+
+    async fn are_we_timed_out(&mut self) -> bool {
+        self.member
+            .as_ref()
+            .map_or(false, |member| member.timed_out)
+    }
+
+    // * For calculating channel permission
+
+    async fn get_channel_type(&mut self) -> ChannelType {
+        self.channel
+            .as_ref()
+            .map_or(ChannelType::Unknown, |channel| channel.channel_type)
+    }
+
+    async fn get_default_channel_permissions(&mut self) -> Override {
+        self.channel
+            .as_ref()
+            .and_then(|channel| channel.default_permissions.clone())
+            .unwrap_or(Override { allow: 0, deny: 0 })
+    }
+
+    async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+        match (&self.server, &self.member, &self.channel) {
+            (Some(server), Some(member), Some(channel)) => sort_role_overrides(
+                member
+                    .roles
+                    .iter()
+                    .filter_map(|id| {
+                        Some((
+                            server.roles.get(id)?.rank,
+                            channel.role_permissions.get(id)?.clone(),
+                        ))
+                    })
+                    .collect(),
+            ),
+            _ => vec![],
+        }
+    }
+
+    async fn do_we_own_the_channel(&mut self) -> bool {
+        self.channel.as_ref().map_or(false, |channel| {
+            channel.owner.as_deref() == Some(self.perspective.id.as_str())
+        })
+    }
+
+    async fn are_we_part_of_the_channel(&mut self) -> bool {
+        self.channel.as_ref().map_or(false, |channel| {
+            channel.recipients.contains(&self.perspective.id)
+        })
+    }
+
+    async fn set_recipient_as_user(&mut self) {
+        if let Some(channel) = &self.channel {
+            if let Some(recipient) = channel
+                .recipients
+                .iter()
+                .find(|id| **id != self.perspective.id)
+            {
+                // Keep any details we were given about this user.
+                if self.user.as_ref().map(|user| &user.id) != Some(recipient) {
+                    self.user = Some(UserContext {
+                        id: recipient.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+
+    async fn set_server_from_channel(&mut self) {
+        // Server information is provided up front.
+    }
+}
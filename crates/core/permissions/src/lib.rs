@@ -1,13 +1,22 @@
+//! Permission resolution logic shared by the server and any client
+//! (such as bots or bridges) that needs to compute permissions identically.
+//!
+//! Implement [`PermissionQuery`] against your own data source, or fill in a
+//! [`PermissionContext`], and pass it to one of [`calculate_user_permissions`],
+//! [`calculate_server_permissions`] or [`calculate_channel_permissions`].
+
 #[macro_use]
 extern crate auto_ops;
 
 #[macro_use]
 extern crate async_trait;
 
+mod context;
 mod r#impl;
 mod models;
 mod r#trait;
 
+pub use context::*;
 pub use models::*;
 pub use r#impl::*;
 pub use r#trait::*;
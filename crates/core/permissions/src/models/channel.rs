@@ -2,6 +2,7 @@ use once_cell::sync::Lazy;
 use std::ops::Add;
 
 /// Abstract channel type
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ChannelType {
     SavedMessages,
     DirectMessage,
@@ -91,7 +92,26 @@ pub enum ChannelPermission {
     MoveMembers = 1 << 35,
 
     // * Misc. permissions
-    // % Bits 36 to 52: free area
+    /// Manage threads in a channel
+    ManageThreads = 1 << 36,
+    /// Pin and unpin messages in a channel
+    PinMessages = 1 << 37,
+    /// React with custom emojis from other servers
+    UseExternalEmojis = 1 << 38,
+    /// Create invites to this channel
+    CreateInvites = 1 << 39,
+    /// Join voice channels which have reached their user limit
+    BypassUserLimit = 1 << 40,
+    /// Mention roles which are not mentionable
+    MentionRoles = 1 << 41,
+    /// Share their screen in a voice call
+    ScreenShare = 1 << 42,
+    /// Be heard over other participants while speaking in a voice call
+    PrioritySpeaker = 1 << 43,
+    /// Send messages without waiting for a channel's slowmode
+    BypassSlowmode = 1 << 44,
+
+    // % Bits 45 to 52: free area
     // % Bits 53 to 64: do not use
 
     // * Grant all permissions
@@ -115,22 +135,31 @@ pub static DEFAULT_PERMISSION: Lazy<u64> = Lazy::new(|| {
     DEFAULT_PERMISSION_VIEW_ONLY.add(
         ChannelPermission::SendMessage
             + ChannelPermission::InviteOthers
+            + ChannelPermission::CreateInvites
             + ChannelPermission::SendEmbeds
             + ChannelPermission::UploadFiles
             + ChannelPermission::Connect
-            + ChannelPermission::Speak,
+            + ChannelPermission::Speak
+            + ChannelPermission::Video
+            + ChannelPermission::ScreenShare,
     )
 });
 
 pub static DEFAULT_PERMISSION_SAVED_MESSAGES: u64 = ChannelPermission::GrantAllSafe as u64;
 
 pub static DEFAULT_PERMISSION_DIRECT_MESSAGE: Lazy<u64> = Lazy::new(|| {
-    DEFAULT_PERMISSION.add(ChannelPermission::ManageChannel + ChannelPermission::React)
+    DEFAULT_PERMISSION.add(
+        ChannelPermission::ManageChannel
+            + ChannelPermission::React
+            + ChannelPermission::UseExternalEmojis
+            + ChannelPermission::PinMessages,
+    )
 });
 
 pub static DEFAULT_PERMISSION_SERVER: Lazy<u64> = Lazy::new(|| {
     DEFAULT_PERMISSION.add(
         ChannelPermission::React
+            + ChannelPermission::UseExternalEmojis
             + ChannelPermission::ChangeNickname
             + ChannelPermission::ChangeAvatar,
     )
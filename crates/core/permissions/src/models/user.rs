@@ -1,4 +1,5 @@
 /// User's relationship with another user (or themselves)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum RelationshipStatus {
     None,
     User,
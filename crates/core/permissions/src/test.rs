@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use crate::{
-    calculate_channel_permissions, calculate_user_permissions, ChannelPermission, ChannelType,
-    Override, PermissionQuery, RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
+    calculate_channel_permissions, calculate_user_permissions, ChannelContext, ChannelPermission,
+    ChannelType, MemberContext, Override, PermissionContext, PermissionQuery, RelationshipStatus,
+    RoleContext, ServerContext, UserContext, DEFAULT_PERMISSION_DIRECT_MESSAGE,
     DEFAULT_PERMISSION_SERVER, DEFAULT_PERMISSION_VIEW_ONLY,
 };
 
@@ -375,3 +378,71 @@ async fn validate_timed_out_member() {
         }
     }
 }
+
+#[async_std::test]
+async fn validate_permission_context() {
+    /// Scenario in which we are in a server channel where:
+    /// - the server grants viewing channels and sending messages by default
+    /// - a lower priority role allows managing messages and reacting
+    /// - a higher priority role denies managing messages
+    /// - the channel denies sending messages to the lower priority role
+    let mut query = PermissionContext::new(UserContext {
+        id: "user".to_string(),
+        ..Default::default()
+    });
+
+    query.server = Some(ServerContext {
+        owner: "owner".to_string(),
+        default_permissions: ChannelPermission::ViewChannel as u64
+            | ChannelPermission::SendMessage as u64,
+        roles: HashMap::from([
+            (
+                "low".to_string(),
+                RoleContext {
+                    rank: 10,
+                    permissions: Override {
+                        allow: ChannelPermission::ManageMessages as u64
+                            | ChannelPermission::React as u64,
+                        deny: 0,
+                    },
+                },
+            ),
+            (
+                "high".to_string(),
+                RoleContext {
+                    rank: 0,
+                    permissions: Override {
+                        allow: 0,
+                        deny: ChannelPermission::ManageMessages as u64,
+                    },
+                },
+            ),
+        ]),
+    });
+
+    query.member = Some(MemberContext {
+        roles: vec!["low".to_string(), "high".to_string()],
+        timed_out: false,
+    });
+
+    query.channel = Some(ChannelContext {
+        channel_type: ChannelType::ServerChannel,
+        owner: None,
+        recipients: vec![],
+        default_permissions: None,
+        role_permissions: HashMap::from([(
+            "low".to_string(),
+            Override {
+                allow: 0,
+                deny: ChannelPermission::SendMessage as u64,
+            },
+        )]),
+    });
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(
+        value,
+        ChannelPermission::ViewChannel as u64 | ChannelPermission::React as u64
+    );
+}
@@ -55,7 +55,13 @@ pub enum ErrorType {
     UnknownAttachment,
     UnknownMessage,
     CannotEditMessage,
+    ChannelLocked,
+    MediaOnlyChannel,
     CannotJoinCall,
+    VoiceChannelFull {
+        max: usize,
+        connected: usize,
+    },
     TooManyAttachments {
         max: usize,
     },
@@ -73,6 +79,14 @@ pub enum ErrorType {
     },
     AlreadyInGroup,
     NotInGroup,
+    FileTypeNotAllowed {
+        content_type: String,
+    },
+    FileRegionNotAllowed,
+    ThreadAlreadyExists,
+    SlowmodeActive {
+        retry_after: u64,
+    },
 
     // ? Server related errors
     UnknownServer,
@@ -93,6 +107,10 @@ pub enum ErrorType {
     IsBot,
     BotIsPrivate,
 
+    // ? Guest related errors
+    IsGuest,
+    CaptchaFailed,
+
     // ? User safety related errors
     CannotReportYourself,
 
@@ -108,6 +126,27 @@ pub enum ErrorType {
     CannotGiveMissingPermissions,
     NotOwner,
 
+    // ? Federation related errors
+    FederationDisabled,
+    FederationBlocked,
+
+    // ? Feature flag related errors
+    FeatureDisabled,
+
+    // ? Policy related errors
+    PolicyNotAccepted {
+        policy: String,
+        version: u32,
+    },
+
+    // ? Verification related errors
+    VerificationRequired {
+        reason: String,
+    },
+
+    // ? Session related errors
+    SessionRestricted,
+
     // ? General errors
     DatabaseError {
         operation: String,
@@ -124,9 +163,14 @@ pub enum ErrorType {
     FailedValidation {
         error: String,
     },
+    UnderMaintenance {
+        reason: Option<String>,
+        retry_after: Option<u64>,
+    },
 
     // ? Legacy errors
     VosoUnavailable,
+    SearchUnavailable,
 }
 
 #[macro_export]
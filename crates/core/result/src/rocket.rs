@@ -8,10 +8,10 @@ use rocket::{
 
 use crate::{Error, ErrorType};
 
-/// HTTP response builder for Error enum
-impl<'r> Responder<'r, 'static> for Error {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
-        let status = match self.error_type {
+impl Error {
+    /// HTTP status code for this error
+    pub fn status(&self) -> Status {
+        match &self.error_type {
             ErrorType::LabelMe => Status::InternalServerError,
 
             ErrorType::AlreadyOnboarded => Status::Forbidden,
@@ -29,7 +29,10 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::UnknownMessage => Status::NotFound,
             ErrorType::UnknownAttachment => Status::BadRequest,
             ErrorType::CannotEditMessage => Status::Forbidden,
+            ErrorType::ChannelLocked => Status::Forbidden,
+            ErrorType::MediaOnlyChannel => Status::BadRequest,
             ErrorType::CannotJoinCall => Status::BadRequest,
+            ErrorType::VoiceChannelFull { .. } => Status::Conflict,
             ErrorType::TooManyAttachments { .. } => Status::BadRequest,
             ErrorType::TooManyReplies { .. } => Status::BadRequest,
             ErrorType::EmptyMessage => Status::UnprocessableEntity,
@@ -38,6 +41,10 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::GroupTooLarge { .. } => Status::Forbidden,
             ErrorType::AlreadyInGroup => Status::Conflict,
             ErrorType::NotInGroup => Status::NotFound,
+            ErrorType::FileTypeNotAllowed { .. } => Status::BadRequest,
+            ErrorType::FileRegionNotAllowed => Status::BadRequest,
+            ErrorType::ThreadAlreadyExists => Status::Conflict,
+            ErrorType::SlowmodeActive { .. } => Status::TooManyRequests,
 
             ErrorType::UnknownServer => Status::NotFound,
             ErrorType::InvalidRole => Status::NotFound,
@@ -52,6 +59,9 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::IsBot => Status::BadRequest,
             ErrorType::BotIsPrivate => Status::Forbidden,
 
+            ErrorType::IsGuest => Status::Forbidden,
+            ErrorType::CaptchaFailed => Status::BadRequest,
+
             ErrorType::CannotReportYourself => Status::BadRequest,
 
             ErrorType::MissingPermission { .. } => Status::Forbidden,
@@ -61,6 +71,17 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::CannotGiveMissingPermissions => Status::Forbidden,
             ErrorType::NotOwner => Status::Forbidden,
 
+            ErrorType::FederationDisabled => Status::BadRequest,
+            ErrorType::FederationBlocked => Status::Forbidden,
+
+            ErrorType::FeatureDisabled => Status::Forbidden,
+
+            ErrorType::PolicyNotAccepted { .. } => Status::Forbidden,
+
+            ErrorType::VerificationRequired { .. } => Status::Forbidden,
+
+            ErrorType::SessionRestricted => Status::Forbidden,
+
             ErrorType::DatabaseError { .. } => Status::InternalServerError,
             ErrorType::InternalError => Status::InternalServerError,
             ErrorType::InvalidOperation => Status::BadRequest,
@@ -69,10 +90,19 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::InvalidSession => Status::Unauthorized,
             ErrorType::DuplicateNonce => Status::Conflict,
             ErrorType::VosoUnavailable => Status::BadRequest,
+            ErrorType::SearchUnavailable => Status::ServiceUnavailable,
             ErrorType::NotFound => Status::NotFound,
             ErrorType::NoEffect => Status::Ok,
             ErrorType::FailedValidation { .. } => Status::BadRequest,
-        };
+            ErrorType::UnderMaintenance { .. } => Status::ServiceUnavailable,
+        }
+    }
+}
+
+/// HTTP response builder for Error enum
+impl<'r> Responder<'r, 'static> for Error {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
 
         // Serialize the error data structure into JSON.
         let string = serde_json::to_string(&self).unwrap();
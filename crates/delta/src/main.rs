@@ -6,6 +6,7 @@ extern crate revolt_rocket_okapi;
 extern crate serde_json;
 
 pub mod routes;
+pub mod tasks;
 pub mod util;
 
 use std::net::Ipv4Addr;
@@ -42,8 +43,29 @@ async fn rocket() -> _ {
     };
 
     // Launch a listener for Authifier events
+    let authifier_db = db.clone();
     async_std::task::spawn(async move {
         while let Ok(event) = receiver.recv().await {
+            // Forget restrictions placed on sessions which no longer exist
+            let cleanup = match &event {
+                AuthifierEvent::DeleteSession { session_id, .. } => {
+                    authifier_db.delete_session_scopes(session_id).await
+                }
+                AuthifierEvent::DeleteAllSessions {
+                    user_id,
+                    exclude_session_id,
+                } => {
+                    authifier_db
+                        .delete_user_session_scopes(user_id, exclude_session_id.as_deref())
+                        .await
+                }
+                _ => Ok(()),
+            };
+
+            if let Err(err) = cleanup {
+                log::warn!("Failed to clear session scopes: {err:?}");
+            }
+
             match &event {
                 AuthifierEvent::CreateSession { .. } | AuthifierEvent::CreateAccount { .. } => {
                     EventV1::Auth(event).global().await
@@ -59,6 +81,14 @@ async fn rocket() -> _ {
 
     // Launch background task workers
     async_std::task::spawn(revolt_quark::tasks::start_workers(legacy_db.clone()));
+    tasks::start_workers(db.clone(), legacy_db.clone());
+
+    // Register message filters
+    revolt_quark::util::pipeline::register_filter(revolt_quark::util::spam::DuplicateSuppression);
+    revolt_quark::util::pipeline::register_filter(revolt_quark::util::spam::SpamThreshold);
+    revolt_quark::util::pipeline::register_filter(util::keyword_highlights::KeywordNotifier {
+        db: db.clone(),
+    });
 
     // Configure CORS
     let cors = revolt_quark::web::cors::new();
@@ -68,12 +98,16 @@ async fn rocket() -> _ {
     routes::mount(rocket)
         .mount("/", revolt_quark::web::cors::catch_all_options_routes())
         .mount("/", revolt_quark::web::ratelimiter::routes())
+        .mount("/", util::maintenance::routes())
+        .mount("/", util::session_scopes::routes())
         .mount("/swagger/", revolt_quark::web::swagger::routes())
         .manage(authifier)
         .manage(db)
         .manage(legacy_db)
         .manage(cors.clone())
         .attach(revolt_quark::web::ratelimiter::RatelimitFairing)
+        .attach(util::maintenance::MaintenanceFairing)
+        .attach(util::session_scopes::SessionScopeFairing)
         .attach(cors)
         .configure(rocket::Config {
             limits: rocket::data::Limits::default().limit("string", 5.megabytes()),
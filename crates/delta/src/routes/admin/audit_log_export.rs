@@ -0,0 +1,39 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_models::v0::AdminAuditExport;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// Number of entries to fetch from the database at a time
+static PAGE_SIZE: i64 = 1000;
+
+/// # Export Audit Log
+///
+/// Export the entire admin audit log, verifying that no entries have been altered or removed.
+#[openapi(tag = "Admin")]
+#[get("/audit/export")]
+pub async fn export_audit_log(db: &State<Database>, user: User) -> Result<Json<AdminAuditExport>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let mut entries: Vec<AdminAuditEntry> = vec![];
+    loop {
+        let mut page = db
+            .fetch_admin_audit_entries(entries.last().map(|entry| entry.sequence), PAGE_SIZE)
+            .await
+            .map_err(Error::from_core)?;
+
+        let done = (page.len() as i64) < PAGE_SIZE;
+        entries.append(&mut page);
+
+        if done {
+            break;
+        }
+    }
+
+    Ok(Json(AdminAuditExport {
+        tampered_at: AdminAuditEntry::verify_chain(&entries, ""),
+        entries: entries.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,30 @@
+use revolt_database::Database;
+use revolt_models::v0::AdminAuditEntry;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Audit Log
+///
+/// Fetch entries from the admin audit log, oldest first, after the given sequence number.
+#[openapi(tag = "Admin")]
+#[get("/audit?<after>&<limit>")]
+pub async fn fetch_audit_log(
+    db: &State<Database>,
+    user: User,
+    after: Option<i64>,
+    limit: Option<i64>,
+) -> Result<Json<Vec<AdminAuditEntry>>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    Ok(Json(
+        db.fetch_admin_audit_entries(after, limit.unwrap_or(50).clamp(1, 100))
+            .await
+            .map_err(Error::from_core)?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    ))
+}
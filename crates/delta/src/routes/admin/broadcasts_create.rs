@@ -0,0 +1,74 @@
+use revolt_database::{AdminAuditEntry, Broadcast, Database};
+use revolt_models::v0::DataCreateBroadcast;
+use revolt_quark::{models::User, variables::delta::SYSTEM_USER_ID, Db, Error, Result, Timestamp};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Broadcast
+///
+/// Schedule an announcement to be sent to every user on the instance or to
+/// members of specific servers.
+///
+/// Broadcasts are delivered as a gateway event and, if requested, as a direct
+/// message from the configured system user.
+#[openapi(tag = "Admin")]
+#[post("/broadcasts", data = "<data>")]
+pub async fn create_broadcast(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    data: Json<DataCreateBroadcast>,
+) -> Result<Json<revolt_models::v0::Broadcast>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // Direct messages can only be sent if a system user exists
+    if data.send_dm {
+        let system_user_id = SYSTEM_USER_ID.as_ref().ok_or(Error::InvalidOperation)?;
+        legacy_db
+            .fetch_user(system_user_id)
+            .await
+            .map_err(|_| Error::InvalidOperation)?;
+    }
+
+    if let revolt_models::v0::BroadcastTarget::Servers { ids } = &data.target {
+        if ids.is_empty() {
+            return Err(Error::InvalidOperation);
+        }
+
+        for id in ids {
+            legacy_db.fetch_server(id).await?;
+        }
+    }
+
+    let broadcast = Broadcast {
+        id: Ulid::new().to_string(),
+        author_id: user.id.clone(),
+        content: data.content,
+        target: data.target.into(),
+        send_dm: data.send_dm,
+        send_at: data.send_at.unwrap_or_else(Timestamp::now_utc),
+        sent: false,
+    };
+
+    broadcast.create(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "broadcast.create",
+        &broadcast.id,
+        None,
+        Some(&broadcast),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(broadcast.into()))
+}
@@ -0,0 +1,41 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{models::User, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Cancel Broadcast
+///
+/// Cancel a broadcast which has not been sent yet.
+#[openapi(tag = "Admin")]
+#[delete("/broadcasts/<broadcast_id>")]
+pub async fn delete_broadcast(
+    db: &State<Database>,
+    user: User,
+    broadcast_id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let broadcast = db
+        .fetch_broadcast(&broadcast_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if broadcast.sent {
+        return Err(Error::InvalidOperation);
+    }
+
+    broadcast.delete(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "broadcast.delete",
+        &broadcast.id,
+        Some(&broadcast),
+        None,
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
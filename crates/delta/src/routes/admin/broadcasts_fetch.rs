@@ -0,0 +1,30 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Broadcasts
+///
+/// Fetch all scheduled and sent broadcasts, newest first.
+#[openapi(tag = "Admin")]
+#[get("/broadcasts")]
+pub async fn fetch_broadcasts(
+    db: &State<Database>,
+    user: User,
+) -> Result<Json<Vec<revolt_models::v0::Broadcast>>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    db.fetch_broadcasts()
+        .await
+        .map(|broadcasts| {
+            Json(
+                broadcasts
+                    .into_iter()
+                    .map(|broadcast| broadcast.into())
+                    .collect(),
+            )
+        })
+        .map_err(Error::from_core)
+}
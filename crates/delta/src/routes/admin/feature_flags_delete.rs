@@ -0,0 +1,37 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{models::User, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Reset Feature Flag
+///
+/// Remove the runtime override for a feature flag, returning it to the instance configuration.
+#[openapi(tag = "Admin")]
+#[delete("/feature_flags/<flag_id>")]
+pub async fn delete_feature_flag(
+    db: &State<Database>,
+    user: User,
+    flag_id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let flag = db
+        .fetch_feature_flag(&flag_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    flag.delete(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "feature_flag.delete",
+        &flag.id,
+        Some(&flag),
+        None,
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
@@ -0,0 +1,57 @@
+use revolt_database::{AdminAuditEntry, Database, FeatureFlag};
+use revolt_models::v0::DataEditFeatureFlag;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+use crate::util::features;
+
+/// # Edit Feature Flag
+///
+/// Enable or disable an experimental feature for this instance or for specific servers.
+#[openapi(tag = "Admin")]
+#[patch("/feature_flags/<flag_id>", data = "<data>")]
+pub async fn edit_feature_flag(
+    db: &State<Database>,
+    user: User,
+    flag_id: String,
+    data: Json<DataEditFeatureFlag>,
+) -> Result<Json<revolt_models::v0::FeatureFlag>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !FeatureFlag::is_valid_id(&flag_id) {
+        return Err(Error::InvalidProperty);
+    }
+
+    let before = features::fetch(db, &flag_id).await?;
+    let mut flag = before.clone();
+
+    if let Some(enabled) = data.enabled {
+        flag.enabled = enabled;
+    }
+
+    if let Some(servers) = data.servers {
+        flag.servers = servers;
+    }
+
+    flag.save(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "feature_flag.edit",
+        &flag.id,
+        Some(&before),
+        Some(&flag),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(flag.into()))
+}
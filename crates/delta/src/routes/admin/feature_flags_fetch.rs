@@ -0,0 +1,24 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::features;
+
+/// # Fetch Feature Flags
+///
+/// Fetch every feature flag configured or overridden on this instance.
+#[openapi(tag = "Admin")]
+#[get("/feature_flags")]
+pub async fn fetch_feature_flags(
+    db: &State<Database>,
+    user: User,
+) -> Result<Json<Vec<revolt_models::v0::FeatureFlag>>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    features::fetch_all(db)
+        .await
+        .map(|flags| Json(flags.into_iter().map(|flag| flag.into()).collect()))
+}
@@ -0,0 +1,63 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{
+    models::{
+        legal_hold::{DataCreateLegalHold, LegalHoldSubject},
+        LegalHold, User,
+    },
+    Db, Error, Result, Timestamp,
+};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Place Legal Hold
+///
+/// Place a user or channel under a legal hold.
+///
+/// Content belonging to held subjects is kept when deleted and is not removed by
+/// purge jobs or account deletion until the hold is released.
+#[openapi(tag = "Admin")]
+#[put("/legal_holds/<subject_id>", data = "<data>")]
+pub async fn create_legal_hold(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    subject_id: String,
+    data: Json<DataCreateLegalHold>,
+) -> Result<Json<LegalHold>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    match data.subject {
+        LegalHoldSubject::User => legacy_db.fetch_user(&subject_id).await.map(|_| ())?,
+        LegalHoldSubject::Channel => legacy_db.fetch_channel(&subject_id).await.map(|_| ())?,
+    }
+
+    let before = legacy_db.fetch_legal_hold(&subject_id).await.ok();
+    let hold = LegalHold {
+        id: subject_id,
+        subject: data.subject,
+        reason: data.reason,
+        placed_by: user.id.clone(),
+        placed_at: Timestamp::now_utc(),
+    };
+
+    legacy_db.insert_legal_hold(&hold).await?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "legal_hold.create",
+        &hold.id,
+        before.as_ref(),
+        Some(&hold),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(hold))
+}
@@ -0,0 +1,34 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{models::User, Db, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Release Legal Hold
+///
+/// Release the legal hold on a user or channel, allowing its content to be purged again.
+#[openapi(tag = "Admin")]
+#[delete("/legal_holds/<subject_id>")]
+pub async fn delete_legal_hold(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    subject_id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let hold = legacy_db.fetch_legal_hold(&subject_id).await?;
+    legacy_db.delete_legal_hold(&hold.id).await?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "legal_hold.delete",
+        &hold.id,
+        Some(&hold),
+        None,
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
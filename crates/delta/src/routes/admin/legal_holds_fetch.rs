@@ -0,0 +1,19 @@
+use revolt_quark::{
+    models::{LegalHold, User},
+    Db, Error, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Fetch Legal Holds
+///
+/// Fetch every user and channel currently under a legal hold.
+#[openapi(tag = "Admin")]
+#[get("/legal_holds")]
+pub async fn fetch_legal_holds(db: &Db, user: User) -> Result<Json<Vec<LegalHold>>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    db.fetch_legal_holds().await.map(Json)
+}
@@ -0,0 +1,61 @@
+use revolt_database::{AdminAuditEntry, Database, MaintenanceStatus};
+use revolt_models::v0::DataEditMaintenance;
+use revolt_quark::{events::client::EventV1, models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+use crate::util::maintenance;
+
+/// # Edit Maintenance Status
+///
+/// Enable or disable maintenance mode for this instance.
+///
+/// While enabled, all routes other than admin and authentication routes
+/// reject writes with an `UnderMaintenance` error. Connected clients are
+/// notified of every change.
+#[openapi(tag = "Admin")]
+#[put("/maintenance", data = "<data>")]
+pub async fn edit_maintenance(
+    db: &State<Database>,
+    user: User,
+    data: Json<DataEditMaintenance>,
+) -> Result<Json<revolt_models::v0::MaintenanceStatus>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let before = MaintenanceStatus::fetch(db)
+        .await
+        .map_err(Error::from_core)?;
+
+    let status = MaintenanceStatus {
+        enabled: data.enabled,
+        message: data.message,
+        ends_at: data.ends_at,
+    };
+
+    status.save(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "maintenance.edit",
+        "maintenance",
+        Some(&before),
+        Some(&status),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    // Apply immediately on this node, others pick it up on their next refresh
+    maintenance::set(status.clone());
+    EventV1::MaintenanceUpdate(status.clone().into())
+        .broadcast()
+        .await;
+
+    Ok(Json(status.into()))
+}
@@ -0,0 +1,23 @@
+use revolt_database::{Database, MaintenanceStatus};
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Maintenance Status
+///
+/// Fetch the current maintenance state of this instance.
+#[openapi(tag = "Admin")]
+#[get("/maintenance")]
+pub async fn fetch_maintenance(
+    db: &State<Database>,
+    user: User,
+) -> Result<Json<revolt_models::v0::MaintenanceStatus>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    MaintenanceStatus::fetch(db)
+        .await
+        .map(|status| Json(status.into()))
+        .map_err(Error::from_core)
+}
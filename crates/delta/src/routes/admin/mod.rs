@@ -1,9 +1,48 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod audit_log_export;
+mod audit_log_fetch;
+mod broadcasts_create;
+mod broadcasts_delete;
+mod broadcasts_fetch;
+mod feature_flags_delete;
+mod feature_flags_edit;
+mod feature_flags_fetch;
+mod legal_holds_create;
+mod legal_holds_delete;
+mod legal_holds_fetch;
+mod maintenance_edit;
+mod maintenance_fetch;
 mod message_query;
+mod policies_delete;
+mod policies_publish;
+mod role_templates_create;
+mod role_templates_delete;
+mod search_reindex;
 mod stats;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![stats::stats, message_query::message_query]
+    openapi_get_routes_spec![
+        stats::stats,
+        message_query::message_query,
+        role_templates_create::create_role_template,
+        role_templates_delete::delete_role_template,
+        audit_log_fetch::fetch_audit_log,
+        audit_log_export::export_audit_log,
+        feature_flags_fetch::fetch_feature_flags,
+        feature_flags_edit::edit_feature_flag,
+        feature_flags_delete::delete_feature_flag,
+        policies_publish::publish_policy,
+        policies_delete::delete_policy,
+        legal_holds_fetch::fetch_legal_holds,
+        legal_holds_create::create_legal_hold,
+        legal_holds_delete::delete_legal_hold,
+        broadcasts_fetch::fetch_broadcasts,
+        broadcasts_create::create_broadcast,
+        broadcasts_delete::delete_broadcast,
+        maintenance_fetch::fetch_maintenance,
+        maintenance_edit::edit_maintenance,
+        search_reindex::reindex_search
+    ]
 }
@@ -0,0 +1,37 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{models::User, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Delete Policy
+///
+/// Remove a policy document, users no longer have to accept it.
+#[openapi(tag = "Admin")]
+#[delete("/policies/<policy_id>")]
+pub async fn delete_policy(
+    db: &State<Database>,
+    user: User,
+    policy_id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let document = db
+        .fetch_policy_document(&policy_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    document.delete(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "policy.delete",
+        &document.id,
+        Some(&document),
+        None,
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
@@ -0,0 +1,50 @@
+use revolt_database::{AdminAuditEntry, Database, PolicyDocument};
+use revolt_models::v0::DataPublishPolicy;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Publish Policy
+///
+/// Publish a new version of a policy document, creating it if it does not exist.
+///
+/// Users must accept the new version before they can continue using the instance.
+#[openapi(tag = "Admin")]
+#[put("/policies/<policy_id>", data = "<data>")]
+pub async fn publish_policy(
+    db: &State<Database>,
+    user: User,
+    policy_id: String,
+    data: Json<DataPublishPolicy>,
+) -> Result<Json<revolt_models::v0::PolicyDocument>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !PolicyDocument::is_valid_id(&policy_id) {
+        return Err(Error::InvalidProperty);
+    }
+
+    let before = db.fetch_policy_document(&policy_id).await.ok();
+    let document = PolicyDocument::publish(db, &policy_id, data.title, data.content)
+        .await
+        .map_err(Error::from_core)?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "policy.publish",
+        &document.id,
+        before.as_ref(),
+        Some(&document),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(document.into()))
+}
@@ -0,0 +1,46 @@
+use revolt_database::{AdminAuditEntry, Database, RoleTemplate};
+use revolt_models::v0::DataCreateRoleTemplate;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Role Template
+///
+/// Create a new role permission template for this instance.
+#[openapi(tag = "Admin")]
+#[post("/role_templates", data = "<data>")]
+pub async fn create_role_template(
+    db: &State<Database>,
+    user: User,
+    data: Json<DataCreateRoleTemplate>,
+) -> Result<Json<revolt_models::v0::RoleTemplate>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let template = RoleTemplate {
+        id: data.id,
+        name: data.name,
+        description: data.description,
+        permissions: data.permissions.into(),
+    };
+
+    template.create(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "role_template.create",
+        &template.id,
+        None,
+        Some(&template),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(template.into()))
+}
@@ -0,0 +1,36 @@
+use revolt_database::{AdminAuditEntry, Database, RoleTemplate};
+use revolt_quark::{models::User, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Delete Role Template
+///
+/// Delete a custom role permission template by its id.
+#[openapi(tag = "Admin")]
+#[delete("/role_templates/<template_id>")]
+pub async fn delete_role_template(
+    db: &State<Database>,
+    user: User,
+    template_id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let template = RoleTemplate::fetch(db, &template_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    template.delete(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "role_template.delete",
+        &template.id,
+        Some(&template),
+        None,
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
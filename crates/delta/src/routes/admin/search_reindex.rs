@@ -0,0 +1,27 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::{models::User, tasks::search_index, Db, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Reindex Search
+///
+/// Start indexing every message into the configured search backend.
+///
+/// Runs in the background, only one reindex may run at a time.
+#[openapi(tag = "Admin")]
+#[post("/search/reindex")]
+pub async fn reindex_search(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    search_index::start_reindex(legacy_db.inner().clone())?;
+    AdminAuditEntry::record::<()>(db, &user.id, "search.reindex", "search", None, None)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
@@ -1,13 +1,27 @@
 use revolt_quark::models::stats::Stats;
-use revolt_quark::{Db, Result};
+use revolt_quark::{Db, Error, Result};
 
 use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsQueryStats {
+    /// Number of days of history to include
+    #[validate(range(min = 1, max = 365))]
+    days: Option<u32>,
+}
 
 /// # Query Stats
 ///
-/// Fetch various technical statistics.
+/// Fetch various technical statistics, along with daily snapshots of collection counters.
 #[openapi(tag = "Admin")]
-#[get("/stats")]
-pub async fn stats(db: &Db) -> Result<Json<Stats>> {
-    Ok(Json(db.generate_stats().await?))
+#[get("/stats?<options..>")]
+pub async fn stats(db: &Db, options: OptionsQueryStats) -> Result<Json<Stats>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    Ok(Json(db.generate_stats(options.days.unwrap_or(30)).await?))
 }
@@ -30,6 +30,10 @@ pub async fn create_bot(db: &Db, user: User, info: Json<DataCreateBot>) -> Resul
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
     let info = info.into_inner();
     info.validate()
         .map_err(|error| Error::FailedValidation { error })?;
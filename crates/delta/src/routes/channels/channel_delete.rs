@@ -1,10 +1,14 @@
+use revolt_database::{AuditLogAction, Database};
 use revolt_quark::{
     models::{channel::PartialChannel, Channel, User},
     perms, Db, EmptyResponse, Error, Permission, Ref, Result,
 };
+use rocket::State;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::util::audit_log;
+
 /// # Query Parameters
 #[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
 pub struct OptionsChannelDelete {
@@ -18,20 +22,23 @@ pub struct OptionsChannelDelete {
 #[openapi(tag = "Channel Information")]
 #[delete("/<target>?<options..>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     options: OptionsChannelDelete,
 ) -> Result<EmptyResponse> {
-    let mut channel = target.as_channel(db).await?;
+    let mut channel = target.as_channel(legacy_db).await?;
     let mut perms = perms(&user).channel(&channel);
-    perms.throw_permission(db, Permission::ViewChannel).await?;
+    perms
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
 
     match &channel {
         Channel::SavedMessages { .. } => Err(Error::NoEffect),
         Channel::DirectMessage { .. } => channel
             .update(
-                db,
+                legacy_db,
                 PartialChannel {
                     active: Some(false),
                     ..Default::default()
@@ -42,19 +49,30 @@ pub async fn req(
             .map(|_| EmptyResponse),
         Channel::Group { .. } => channel
             .remove_user_from_group(
-                db,
+                legacy_db,
                 &user.id,
                 None,
                 options.leave_silently.unwrap_or_default(),
             )
             .await
             .map(|_| EmptyResponse),
-        Channel::TextChannel { .. } | Channel::VoiceChannel { .. } => {
+        Channel::TextChannel { id, server, .. } | Channel::VoiceChannel { id, server, .. } => {
             perms
-                .throw_permission(db, Permission::ManageChannel)
+                .throw_permission(legacy_db, Permission::ManageChannel)
                 .await?;
 
-            channel.delete(db).await.map(|_| EmptyResponse)
+            audit_log::record(
+                db,
+                server,
+                &user.id,
+                AuditLogAction::ChannelDelete,
+                id,
+                Some(&channel),
+                None,
+            )
+            .await;
+
+            channel.delete(legacy_db).await.map(|_| EmptyResponse)
         }
     }
 }
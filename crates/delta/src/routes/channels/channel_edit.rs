@@ -1,10 +1,11 @@
+use revolt_database::{ChannelTopicChange, Database, TopicFieldChange};
 use revolt_quark::{
     models::{
-        channel::{Channel, FieldsChannel, PartialChannel},
+        channel::{Channel, FieldsChannel, FileTypeRestrictions, PartialChannel},
         message::SystemMessage,
         File, User,
     },
-    perms, Database, Error, Permission, Ref, Result,
+    perms, Db, Error, Permission, Ref, Result,
 };
 
 use rocket::{serde::json::Json, State};
@@ -31,6 +32,23 @@ pub struct DataEditChannel {
     nsfw: Option<bool>,
     /// Whether this channel is archived
     archived: Option<bool>,
+    /// Maximum number of users who can be connected to this voice channel
+    #[validate(range(min = 1, max = 99))]
+    user_limit: Option<u32>,
+    /// Whether every message in this text channel must contain an attachment or link
+    media_only: Option<bool>,
+    /// Role required to post in this text channel
+    #[validate(length(min = 1, max = 128))]
+    posting_role: Option<String>,
+    /// Seconds each member must wait between messages in this text channel
+    #[validate(range(min = 1, max = 21600))]
+    slowmode: Option<u32>,
+    /// Depth of reply chain in this text channel after which further replies are moved into a thread
+    #[validate(range(min = 2, max = 25))]
+    auto_thread_depth: Option<u32>,
+    /// Attachment content types permitted in this channel
+    #[validate]
+    file_types: Option<FileTypeRestrictions>,
     #[validate(length(min = 1))]
     remove: Option<Vec<FieldsChannel>>,
 }
@@ -42,6 +60,7 @@ pub struct DataEditChannel {
 #[patch("/<target>", data = "<data>")]
 pub async fn req(
     db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     data: Json<DataEditChannel>,
@@ -50,10 +69,10 @@ pub async fn req(
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    let mut channel = target.as_channel(db).await?;
+    let mut channel = target.as_channel(legacy_db).await?;
     perms(&user)
         .channel(&channel)
-        .throw_permission_and_view_channel(db, Permission::ManageChannel)
+        .throw_permission_and_view_channel(legacy_db, Permission::ManageChannel)
         .await?;
 
     if data.name.is_none()
@@ -61,6 +80,12 @@ pub async fn req(
         && data.icon.is_none()
         && data.nsfw.is_none()
         && data.owner.is_none()
+        && data.user_limit.is_none()
+        && data.media_only.is_none()
+        && data.posting_role.is_none()
+        && data.slowmode.is_none()
+        && data.auto_thread_depth.is_none()
+        && data.file_types.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(channel));
@@ -97,11 +122,138 @@ pub async fn req(
             return Err(Error::InvalidOperation);
         }
         .into_message(channel.id().to_string())
-        .create(db, &channel, None)
+        .create(legacy_db, &channel, None)
         .await
         .ok();
     }
 
+    // Voice channel user limit
+    if let Channel::VoiceChannel { user_limit, .. } = &mut channel {
+        if let Some(new_limit) = data.user_limit {
+            *user_limit = Some(new_limit);
+            partial.user_limit = Some(new_limit);
+        }
+
+        if let Some(fields) = &data.remove {
+            if fields.contains(&FieldsChannel::UserLimit) {
+                user_limit.take();
+            }
+        }
+    } else if data.user_limit.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Text channel media-only mode
+    if let Channel::TextChannel { media_only, .. } = &mut channel {
+        if let Some(new_media_only) = data.media_only {
+            *media_only = new_media_only;
+            partial.media_only = Some(new_media_only);
+        }
+    } else if data.media_only.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Text channel posting role
+    if let Channel::TextChannel {
+        server,
+        posting_role,
+        ..
+    } = &mut channel
+    {
+        if let Some(fields) = &data.remove {
+            if fields.contains(&FieldsChannel::PostingRole) {
+                posting_role.take();
+            }
+        }
+
+        if let Some(new_role) = data.posting_role {
+            if !legacy_db
+                .fetch_server(server)
+                .await?
+                .roles
+                .contains_key(&new_role)
+            {
+                return Err(Error::InvalidRole);
+            }
+
+            *posting_role = Some(new_role.clone());
+            partial.posting_role = Some(new_role);
+        }
+    } else if data.posting_role.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Text channel slowmode
+    if let Channel::TextChannel { slowmode, .. } = &mut channel {
+        if let Some(fields) = &data.remove {
+            if fields.contains(&FieldsChannel::Slowmode) {
+                slowmode.take();
+            }
+        }
+
+        if let Some(new_slowmode) = data.slowmode {
+            *slowmode = Some(new_slowmode);
+            partial.slowmode = Some(new_slowmode);
+        }
+    } else if data.slowmode.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Text channel auto-threading
+    if let Channel::TextChannel {
+        auto_thread_depth, ..
+    } = &mut channel
+    {
+        if let Some(fields) = &data.remove {
+            if fields.contains(&FieldsChannel::AutoThreadDepth) {
+                auto_thread_depth.take();
+            }
+        }
+
+        if let Some(new_depth) = data.auto_thread_depth {
+            *auto_thread_depth = Some(new_depth);
+            partial.auto_thread_depth = Some(new_depth);
+        }
+    } else if data.auto_thread_depth.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // File type restrictions, for any channel which accepts attachments
+    if let Channel::TextChannel { file_types, .. } | Channel::VoiceChannel { file_types, .. } =
+        &mut channel
+    {
+        if let Some(fields) = &data.remove {
+            if fields.contains(&FieldsChannel::FileTypes) {
+                file_types.take();
+            }
+        }
+
+        if let Some(new_file_types) = data.file_types {
+            // Each entry must look like `type/subtype` or `type/*`
+            let valid = new_file_types
+                .allow
+                .iter()
+                .chain(&new_file_types.block)
+                .all(|pattern| {
+                    pattern.len() <= 128
+                        && pattern.split_once('/').is_some_and(|(kind, subtype)| {
+                            !kind.is_empty()
+                                && !subtype.is_empty()
+                                && !pattern.contains(char::is_whitespace)
+                        })
+                });
+
+            if !valid {
+                return Err(Error::InvalidProperty);
+            }
+
+            *file_types = Some(new_file_types.clone());
+            partial.file_types = Some(new_file_types);
+        }
+    } else if data.file_types.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
     match &mut channel {
         Channel::Group {
             id,
@@ -127,10 +279,13 @@ pub async fn req(
             nsfw,
             ..
         } => {
+            let previous_name = name.clone();
+            let previous_description = description.clone();
+
             if let Some(fields) = &data.remove {
                 if fields.contains(&FieldsChannel::Icon) {
                     if let Some(icon) = &icon {
-                        db.mark_attachment_as_deleted(&icon.id).await?;
+                        legacy_db.mark_attachment_as_deleted(&icon.id).await?;
                     }
                 }
 
@@ -148,7 +303,7 @@ pub async fn req(
             }
 
             if let Some(icon_id) = data.icon {
-                partial.icon = Some(File::use_icon(db, &icon_id, id).await?);
+                partial.icon = Some(File::use_icon(legacy_db, &icon_id, id).await?);
                 *icon = partial.icon.clone();
             }
 
@@ -175,7 +330,7 @@ pub async fn req(
                         by: user.id.clone(),
                     }
                     .into_message(channel.id().to_string())
-                    .create(db, &channel, None)
+                    .create(legacy_db, &channel, None)
                     .await
                     .ok();
                 }
@@ -185,23 +340,45 @@ pub async fn req(
                         by: user.id.clone(),
                     }
                     .into_message(channel.id().to_string())
-                    .create(db, &channel, None)
+                    .create(legacy_db, &channel, None)
                     .await
                     .ok();
                 }
 
                 if partial.icon.is_some() {
-                    SystemMessage::ChannelIconChanged { by: user.id }
-                        .into_message(channel.id().to_string())
-                        .create(db, &channel, None)
-                        .await
-                        .ok();
+                    SystemMessage::ChannelIconChanged {
+                        by: user.id.clone(),
+                    }
+                    .into_message(channel.id().to_string())
+                    .create(legacy_db, &channel, None)
+                    .await
+                    .ok();
                 }
             }
 
+            let name_change = TopicFieldChange::between(Some(previous_name), Some(name.clone()));
+            let description_change =
+                TopicFieldChange::between(previous_description, description.clone());
+
             channel
-                .update(db, partial, data.remove.unwrap_or_default())
+                .update(legacy_db, partial, data.remove.unwrap_or_default())
                 .await?;
+
+            // Keep a record of the previous name and description
+            if let Err(err) = ChannelTopicChange::create(
+                db,
+                channel.id(),
+                &user.id,
+                name_change,
+                description_change,
+            )
+            .await
+            {
+                log::warn!(
+                    "Failed to record topic change in channel {}: {err:?}",
+                    channel.id()
+                );
+            }
         }
         _ => return Err(Error::InvalidOperation),
     };
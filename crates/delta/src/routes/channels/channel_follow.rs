@@ -0,0 +1,28 @@
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, EmptyResponse, Error, Permission, Ref, Result,
+};
+
+/// # Follow Channel
+///
+/// Receive notifications for every message in this channel, regardless of the server notification setting.
+#[openapi(tag = "Channel Information")]
+#[put("/<target>/follow")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let server = match &channel {
+        Channel::TextChannel { server, .. } => server,
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, Permission::ViewChannel)
+        .await?;
+
+    let mut member = db.fetch_member(server, &user.id).await?;
+    member
+        .set_following(db, channel.id(), true)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,19 @@
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+
+/// # Lock Channel
+///
+/// Prevent members without the ManageThreads permission from posting in this channel.
+#[openapi(tag = "Channel Information")]
+#[put("/<target>/lock")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<EmptyResponse> {
+    let mut channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(db, Permission::ManageThreads)
+        .await?;
+
+    channel
+        .set_locked(db, &user.id, true)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,23 @@
+use revolt_quark::{
+    models::{Channel, User},
+    Db, EmptyResponse, Error, Ref, Result,
+};
+
+/// # Unfollow Channel
+///
+/// Stop receiving notifications for every message in this channel.
+#[openapi(tag = "Channel Information")]
+#[delete("/<target>/follow")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let server = match &channel {
+        Channel::TextChannel { server, .. } => server,
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    let mut member = db.fetch_member(server, &user.id).await?;
+    member
+        .set_following(db, channel.id(), false)
+        .await
+        .map(|_| EmptyResponse)
+}
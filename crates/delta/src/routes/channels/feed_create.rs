@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use revolt_database::{ChannelFeed, Database, Webhook};
+use revolt_models::v0::DataCreateChannelFeed;
+use revolt_quark::{
+    models::{Channel, User},
+    perms,
+    variables::delta::MAX_FEED_COUNT,
+    Db, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+use crate::util::net;
+
+/// # Subscribe to Feed
+///
+/// Subscribe this channel to an RSS or Atom feed, new entries are posted as embeds.
+#[openapi(tag = "Webhooks")]
+#[post("/<target>/feeds", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateChannelFeed>,
+) -> Result<Json<revolt_models::v0::ChannelFeed>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // Feeds are fetched by the server so may not point into private networks
+    net::public_client(&data.url, Duration::from_secs(5)).await?;
+
+    let channel = target.as_channel(legacy_db).await?;
+    if !matches!(channel, Channel::TextChannel { .. } | Channel::Group { .. }) {
+        return Err(Error::InvalidOperation);
+    }
+
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    let feeds = db
+        .fetch_channel_feeds(channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    if feeds.len() >= *MAX_FEED_COUNT {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Entries are posted through a webhook owned by the feed
+    let webhook = Webhook {
+        id: Ulid::new().to_string(),
+        name: "Feed".to_string(),
+        avatar: None,
+        channel_id: channel.id().to_string(),
+        token: Some(nanoid::nanoid!(64)),
+        limits: None,
+    };
+
+    webhook.create(db).await.map_err(Error::from_core)?;
+
+    let feed = ChannelFeed {
+        id: Ulid::new().to_string(),
+        channel_id: channel.id().to_string(),
+        webhook_id: webhook.id,
+        creator_id: user.id,
+        url: data.url,
+        interval: data.interval.unwrap_or(30),
+        filter: data.filter,
+        last_entry_id: None,
+        last_polled_at: None,
+    };
+
+    feed.create(db).await.map_err(Error::from_core)?;
+    Ok(Json(feed.into()))
+}
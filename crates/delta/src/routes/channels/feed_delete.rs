@@ -0,0 +1,36 @@
+use revolt_database::{util::reference::Reference, Database};
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Delete Feed
+///
+/// Unsubscribe this channel from a feed.
+#[openapi(tag = "Webhooks")]
+#[delete("/<target>/feeds/<feed_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    feed_id: Reference,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    let feed = db
+        .fetch_channel_feed(&feed_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if feed.channel_id != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    feed.delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
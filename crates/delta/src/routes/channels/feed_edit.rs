@@ -0,0 +1,52 @@
+use revolt_database::{util::reference::Reference, Database, PartialChannelFeed};
+use revolt_models::v0::{ChannelFeed, DataEditChannelFeed};
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Feed
+///
+/// Edit a feed subscription in this channel.
+#[openapi(tag = "Webhooks")]
+#[patch("/<target>/feeds/<feed_id>", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    feed_id: Reference,
+    data: Json<DataEditChannelFeed>,
+) -> Result<Json<ChannelFeed>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    let mut feed = db
+        .fetch_channel_feed(&feed_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if feed.channel_id != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    feed.update(
+        db,
+        PartialChannelFeed {
+            interval: data.interval,
+            filter: data.filter,
+            ..Default::default()
+        },
+        data.remove.into_iter().map(Into::into).collect(),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(feed.into()))
+}
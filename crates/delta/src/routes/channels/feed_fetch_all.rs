@@ -0,0 +1,31 @@
+use revolt_database::Database;
+use revolt_models::v0::ChannelFeed;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Feeds
+///
+/// Fetch all feed subscriptions in this channel.
+#[openapi(tag = "Webhooks")]
+#[get("/<target>/feeds")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<ChannelFeed>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    Ok(Json(
+        db.fetch_channel_feeds(channel.id())
+            .await
+            .map_err(Error::from_core)?
+            .into_iter()
+            .map(|v| v.into())
+            .collect(),
+    ))
+}
@@ -14,6 +14,10 @@ pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyR
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
     let mut channel = target.as_channel(db).await?;
     perms(&user)
         .channel(&channel)
@@ -2,7 +2,10 @@ use std::{collections::HashSet, iter::FromIterator};
 
 use revolt_quark::{
     get_relationship,
-    models::{user::RelationshipStatus, Channel, User},
+    models::{
+        user::{RelationshipStatus, SessionScope},
+        Channel, User,
+    },
     variables::delta::MAX_GROUP_SIZE,
     Db, Error, Result,
 };
@@ -41,6 +44,14 @@ pub async fn req(db: &Db, user: User, info: Json<DataCreateGroup>) -> Result<Jso
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
+    if user.scopes.contains(&SessionScope::NoDirectMessages) {
+        return Err(Error::SessionRestricted);
+    }
+
     let info = info.into_inner();
     info.validate()
         .map_err(|error| Error::FailedValidation { error })?;
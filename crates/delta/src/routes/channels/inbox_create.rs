@@ -0,0 +1,68 @@
+use revolt_database::{ChannelInbox, Database, Webhook};
+use revolt_quark::{
+    models::{Channel, User},
+    perms,
+    variables::delta::{INBOUND_EMAIL_DOMAIN, USE_INBOUND_EMAIL},
+    Db, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+
+/// # Create Email Inbox
+///
+/// Assigns an email address to this channel, mail received at the address is posted to the channel.
+#[openapi(tag = "Webhooks")]
+#[post("/<target>/inbox")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<revolt_models::v0::ChannelInbox>> {
+    if !*USE_INBOUND_EMAIL {
+        return Err(Error::InvalidOperation);
+    }
+
+    let channel = target.as_channel(legacy_db).await?;
+    if !matches!(channel, Channel::TextChannel { .. } | Channel::Group { .. }) {
+        return Err(Error::InvalidOperation);
+    }
+
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    // Only one address may be assigned per channel
+    if db
+        .fetch_channel_inbox_by_channel(channel.id())
+        .await
+        .is_ok()
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Received mail is posted through a webhook owned by the inbox
+    let webhook = Webhook {
+        id: Ulid::new().to_string(),
+        name: "Email".to_string(),
+        avatar: None,
+        channel_id: channel.id().to_string(),
+        token: Some(nanoid::nanoid!(64)),
+        limits: None,
+    };
+
+    webhook.create(db).await.map_err(Error::from_core)?;
+
+    let inbox = ChannelInbox::create(
+        db,
+        &INBOUND_EMAIL_DOMAIN,
+        channel.id().to_string(),
+        webhook.id,
+        user.id,
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(inbox.into()))
+}
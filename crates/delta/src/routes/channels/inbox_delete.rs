@@ -0,0 +1,32 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Delete Email Inbox
+///
+/// Remove the email address assigned to this channel.
+#[openapi(tag = "Webhooks")]
+#[delete("/<target>/inbox")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    let inbox = db
+        .fetch_channel_inbox_by_channel(channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    inbox
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
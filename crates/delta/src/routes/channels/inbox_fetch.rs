@@ -0,0 +1,27 @@
+use revolt_database::Database;
+use revolt_models::v0::ChannelInbox;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Email Inbox
+///
+/// Fetch the email address assigned to this channel.
+#[openapi(tag = "Webhooks")]
+#[get("/<target>/inbox")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<ChannelInbox>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ManageWebhooks)
+        .await?;
+
+    db.fetch_channel_inbox_by_channel(channel.id())
+        .await
+        .map(|inbox| Json(inbox.into()))
+        .map_err(Error::from_core)
+}
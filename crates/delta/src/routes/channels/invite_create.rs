@@ -17,10 +17,14 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Invite>> {
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
     let channel = target.as_channel(db).await?;
     perms(&user)
         .channel(&channel)
-        .throw_permission_and_view_channel(db, Permission::InviteOthers)
+        .throw_permission_and_view_channel(db, Permission::CreateInvites)
         .await?;
 
     Invite::create(db, &user, &channel).await.map(Json)
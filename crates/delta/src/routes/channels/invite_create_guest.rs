@@ -0,0 +1,71 @@
+use revolt_quark::{
+    models::{channel_invite::GuestAccess, Invite, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Guest Invite Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCreateGuestInvite {
+    /// Whether guests may send messages in this channel
+    #[serde(default)]
+    write: bool,
+    /// How long guest accounts last in seconds, between 5 minutes and 30 days
+    #[validate(range(min = 300, max = 2592000))]
+    lifetime: i64,
+    /// Number of guest accounts this invite may create
+    #[validate(range(min = 1, max = 10000))]
+    max_uses: Option<u32>,
+}
+
+/// # Create Guest Invite
+///
+/// Creates an invite which hands out temporary guest accounts limited to this channel.
+///
+/// Channel must be a `TextChannel` or `VoiceChannel`.
+#[openapi(tag = "Channel Invites")]
+#[post("/<target>/invites/guest", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateGuestInvite>,
+) -> Result<Json<Invite>> {
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let channel = target.as_channel(db).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(db, Permission::CreateInvites)
+        .await?;
+    permissions
+        .throw_permission(db, Permission::ManageChannel)
+        .await?;
+
+    Invite::create_guest(
+        db,
+        &user,
+        &channel,
+        GuestAccess {
+            write: data.write,
+            lifetime: data.lifetime,
+            max_uses: data.max_uses,
+            uses: 0,
+        },
+    )
+    .await
+    .map(Json)
+}
@@ -1,4 +1,6 @@
-use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use revolt_quark::{
+    models::User, perms, util::consistency, Db, EmptyResponse, Error, Permission, Ref, Result,
+};
 
 /// # Delete Message
 ///
@@ -18,5 +20,7 @@ pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<EmptyResp
             .await?;
     }
 
-    message.delete(db).await.map(|_| EmptyResponse)
+    message.delete(db).await?;
+    consistency::record_write(&user.id);
+    Ok(EmptyResponse)
 }
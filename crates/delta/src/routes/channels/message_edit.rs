@@ -1,13 +1,15 @@
 use revolt_quark::{
-    models::message::{PartialMessage, SendableEmbed},
+    models::message::{DataMessageSend, MessageRevision, PartialMessage, SendableEmbed},
     models::{Message, User},
     perms,
-    types::january::Embed,
+    types::{january::Embed, push::MessageAuthor},
+    util::consistency,
     Db, Error, Permission, Ref, Result, Timestamp,
 };
 
 use rocket::serde::json::Json;
 use serde::{Deserialize, Serialize};
+use ulid::Ulid;
 use validator::Validate;
 
 /// # Message Details
@@ -55,16 +57,40 @@ pub async fn req(
 
     Message::validate_sum(&edit.content, edit.embeds.as_deref().unwrap_or_default())?;
 
-    message.edited = Some(Timestamp::now_utc());
+    // Apply the recipient's media filter in the same way as when sending
+    let mut filtered = DataMessageSend {
+        content: edit.content.clone().or_else(|| message.content.clone()),
+        embeds: edit.embeds.clone(),
+        ..Default::default()
+    };
+
+    let stripped = channel
+        .apply_media_filter(db, &MessageAuthor::User(&user), &mut filtered)
+        .await?;
+
+    // Keep the previous version so moderators can review edits
+    let now = Timestamp::now_utc();
+    db.insert_message_revision(&MessageRevision {
+        id: Ulid::new().to_string(),
+        message_id: message.id.clone(),
+        channel: message.channel.clone(),
+        content: message.content.clone(),
+        embeds: message.embeds.clone(),
+        attachments: message.attachments.clone(),
+        edited: message.edited,
+        replaced_at: now,
+    })
+    .await?;
+
+    message.edited = Some(now);
     let mut partial = PartialMessage {
         edited: message.edited,
         ..Default::default()
     };
 
     // 1. Handle content update
-    if let Some(content) = &edit.content {
-        partial.content = Some(content.clone());
-    }
+    let content = edit.content.and(filtered.content);
+    partial.content = content.clone();
 
     // 2. Clear any auto generated embeds
     let mut new_embeds: Vec<Embed> = vec![];
@@ -77,7 +103,9 @@ pub async fn req(
     }
 
     // 3. Replace if we are given new embeds
-    if let Some(embeds) = edit.embeds {
+    if stripped {
+        new_embeds.clear();
+    } else if let Some(embeds) = filtered.embeds {
         new_embeds.clear();
 
         for embed in embeds {
@@ -88,9 +116,10 @@ pub async fn req(
     partial.embeds = Some(new_embeds);
 
     message.update(db, partial).await?;
+    consistency::record_write(&user.id);
 
-    // Queue up a task for processing embeds
-    if let Some(content) = edit.content {
+    // Queue up a task for processing embeds, unless links were filtered out
+    if let (Some(content), false) = (content, stripped) {
         revolt_quark::tasks::process_embeds::queue(
             message.channel.to_string(),
             message.id.to_string(),
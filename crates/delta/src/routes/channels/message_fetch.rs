@@ -9,7 +9,7 @@ use rocket::serde::json::Json;
 ///
 /// Retrieves a message by its id.
 #[openapi(tag = "Messaging")]
-#[get("/<target>/messages/<msg>")]
+#[get("/<target>/messages/<msg>", rank = 2)]
 pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<Json<Message>> {
     let channel = target.as_channel(db).await?;
     perms(&user)
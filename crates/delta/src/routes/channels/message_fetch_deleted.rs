@@ -0,0 +1,23 @@
+use revolt_quark::{
+    models::{message::DeletedMessage, User},
+    perms, Db, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Deleted Messages
+///
+/// Fetch messages in this channel which were deleted recently and can still be restored.
+///
+/// Requires `ManageMessages` permission.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/messages/deleted", rank = 1)]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<DeletedMessage>>> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, Permission::ManageMessages)
+        .await?;
+
+    db.fetch_deleted_messages(&channel.as_id()).await.map(Json)
+}
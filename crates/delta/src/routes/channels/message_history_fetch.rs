@@ -0,0 +1,28 @@
+use revolt_quark::{
+    models::{message::MessageRevision, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Message History
+///
+/// Fetch previous versions of a message from before it was edited, newest first.
+///
+/// Requires `ManageMessages` permission.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/messages/<msg>/history")]
+pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<Json<Vec<MessageRevision>>> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(db, Permission::ManageMessages)
+        .await?;
+
+    let message = msg.as_message(db).await?;
+    if message.channel != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    db.fetch_message_revisions(&message.id).await.map(Json)
+}
@@ -0,0 +1,47 @@
+use revolt_quark::{
+    models::{
+        message::{PartialMessage, SystemMessage},
+        User,
+    },
+    perms, Db, EmptyResponse, Permission, Ref, Result,
+};
+
+/// # Pin Message
+///
+/// Pin a message in its channel.
+///
+/// A system message is posted in the channel when a message is newly pinned.
+#[openapi(tag = "Messaging")]
+#[put("/<target>/messages/<msg>/pin")]
+pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(db, Permission::PinMessages)
+        .await?;
+
+    // Fetch relevant message
+    let mut message = msg.as_message_in(db, channel.id()).await?;
+    if message.pinned {
+        return Ok(EmptyResponse);
+    }
+
+    message
+        .update(
+            db,
+            PartialMessage {
+                pinned: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    SystemMessage::MessagePinned {
+        id: message.id,
+        by: user.id,
+    }
+    .into_message(channel.id().to_string())
+    .create(db, &channel, None)
+    .await
+    .map(|_| EmptyResponse)
+}
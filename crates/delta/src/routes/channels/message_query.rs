@@ -5,7 +5,9 @@ use revolt_quark::{
         },
         User,
     },
-    perms, Db, Error, Permission, Ref, Result,
+    perms,
+    util::consistency,
+    Db, Error, Permission, Ref, Result,
 };
 use rocket::serde::json::Json;
 use serde::{Deserialize, Serialize};
@@ -36,6 +38,9 @@ pub struct OptionsQueryMessages {
     nearby: Option<String>,
     /// Whether to include user (and member, if server channel) objects
     include_users: Option<bool>,
+    /// Thread id to fetch messages from
+    #[validate(length(min = 26, max = 26))]
+    thread: Option<String>,
 }
 
 /// # Fetch Messages
@@ -70,12 +75,14 @@ pub async fn req(
         sort,
         nearby,
         include_users,
+        thread,
     } = options;
 
     let messages = db
         .fetch_messages(MessageQuery {
             filter: MessageFilter {
                 channel: Some(channel.id().to_string()),
+                thread,
                 ..Default::default()
             },
             time_period: if let Some(nearby) = nearby {
@@ -88,6 +95,7 @@ pub async fn req(
                 }
             },
             limit,
+            consistent: consistency::requires_primary(&user.id),
         })
         .await?;
 
@@ -1,4 +1,11 @@
-use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+use revolt_database::Database;
+use revolt_quark::{
+    models::{Emoji, User},
+    perms, Db, EmptyResponse, Permission, Ref, Result,
+};
+use rocket::State;
+
+use crate::util::{highlights, reaction_roles};
 
 /// # Add Reaction to Message
 ///
@@ -6,24 +13,42 @@ use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Resu
 #[openapi(tag = "Interactions")]
 #[put("/<target>/messages/<msg>/reactions/<emoji>")]
 pub async fn react_message(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     msg: Ref,
     emoji: Ref,
 ) -> Result<EmptyResponse> {
-    let channel = target.as_channel(db).await?;
-    perms(&user)
-        .channel(&channel)
-        .throw_permission_and_view_channel(db, Permission::React)
+    let channel = target.as_channel(legacy_db).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::React)
         .await?;
 
+    // React with the current version of any replaced custom emoji
+    let emoji = Emoji::resolve(legacy_db, &emoji.id).await?;
+
+    // Custom emoji from other servers need an additional permission
+    if Emoji::is_external(legacy_db, &emoji, &channel).await? {
+        permissions
+            .throw_permission(legacy_db, Permission::UseExternalEmojis)
+            .await?;
+    }
+
     // Fetch relevant message
-    let message = msg.as_message_in(db, channel.id()).await?;
+    let message = msg.as_message_in(legacy_db, channel.id()).await?;
 
     // Add the reaction
-    message
-        .add_reaction(db, &user, &emoji.id)
-        .await
-        .map(|_| EmptyResponse)
+    message.add_reaction(legacy_db, &user, &emoji).await?;
+
+    // Grant any roles bound to this reaction
+    reaction_roles::apply(db, legacy_db, &message, &emoji, &[user.id], true).await?;
+
+    // Highlight the message if it now has enough reactions
+    if let Err(err) = highlights::check(db, legacy_db, &channel, &message.id).await {
+        log::warn!("Failed to highlight message {}: {err:?}", message.id);
+    }
+
+    Ok(EmptyResponse)
 }
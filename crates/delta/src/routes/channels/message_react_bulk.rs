@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use revolt_database::Database;
+use revolt_quark::{
+    models::{Emoji, User},
+    perms, Db, EmptyResponse, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::util::{highlights, reaction_roles};
+
+/// # Bulk Reaction Data
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataBulkReact {
+    /// Emoji to react with, in the order they should be added
+    #[validate(length(min = 1, max = 20))]
+    emojis: Vec<String>,
+}
+
+/// # Add Reactions to Message
+///
+/// React to a given message with multiple distinct emoji at once.
+///
+/// This is only available to bots and is intended for menu-style prompts.
+/// Every emoji is validated against the message's reaction restrictions before any are added.
+#[openapi(tag = "Interactions")]
+#[put("/<target>/messages/<msg>/reactions", data = "<data>")]
+pub async fn react_message_bulk(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    msg: Ref,
+    data: Json<DataBulkReact>,
+) -> Result<EmptyResponse> {
+    if user.bot.is_none() {
+        return Err(Error::IsNotBot);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // React with the current version of any replaced custom emoji
+    let mut emojis = Vec::with_capacity(data.emojis.len());
+    for emoji in &data.emojis {
+        emojis.push(Emoji::resolve(legacy_db, emoji).await?);
+    }
+
+    // Reactions must be distinct
+    let mut seen = HashSet::new();
+    if !emojis.iter().all(|emoji| seen.insert(emoji.as_str())) {
+        return Err(Error::InvalidOperation);
+    }
+
+    let channel = target.as_channel(legacy_db).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::React)
+        .await?;
+
+    // Fetch relevant message
+    let message = msg.as_message_in(legacy_db, channel.id()).await?;
+
+    // Check the message can fit every new reaction
+    let new = emojis
+        .iter()
+        .filter(|emoji| !message.reactions.contains_key(emoji.as_str()))
+        .count();
+
+    if message.reactions.len() + new > 20 {
+        return Err(Error::InvalidOperation);
+    }
+
+    for emoji in &emojis {
+        // Check if the emoji is whitelisted
+        if !message.interactions.can_use(emoji) {
+            return Err(Error::InvalidOperation);
+        }
+
+        // Custom emoji from other servers need an additional permission
+        if Emoji::is_external(legacy_db, emoji, &channel).await? {
+            permissions
+                .throw_permission(legacy_db, Permission::UseExternalEmojis)
+                .await?;
+        }
+    }
+
+    // Add the reactions
+    for emoji in &emojis {
+        message.add_reaction(legacy_db, &user, emoji).await?;
+
+        // Grant any roles bound to this reaction
+        reaction_roles::apply(db, legacy_db, &message, emoji, &[user.id.clone()], true).await?;
+    }
+
+    // Highlight the message if it now has enough reactions
+    if let Err(err) = highlights::check(db, legacy_db, &channel, &message.id).await {
+        log::warn!("Failed to highlight message {}: {err:?}", message.id);
+    }
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,28 @@
+use revolt_quark::{
+    models::{Message, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Restore Message
+///
+/// Restore a message which was deleted within the recovery window.
+///
+/// Requires `ManageMessages` permission.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/<msg>/restore")]
+pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<Json<Message>> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, Permission::ManageMessages)
+        .await?;
+
+    let deleted = db.fetch_deleted_message(&msg.id).await?;
+    if deleted.message.channel != channel.as_id() {
+        return Err(Error::NotFound);
+    }
+
+    Message::restore(db, &deleted.id).await.map(Json)
+}
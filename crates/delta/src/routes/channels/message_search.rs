@@ -1,11 +1,12 @@
 use revolt_quark::{
     models::{
         message::{
-            BulkMessageResponse, MessageFilter, MessageQuery, MessageSort, MessageTimePeriod,
+            AttachmentFilter, BulkMessageResponse, MessageFilter, MessageQuery, MessageSort,
+            MessageTimePeriod,
         },
         User,
     },
-    perms, Db, Error, Permission, Ref, Result,
+    perms, search, Db, Error, Permission, Ref, Result,
 };
 
 use rocket::serde::json::Json;
@@ -17,9 +18,12 @@ use validator::Validate;
 pub struct OptionsMessageSearch {
     /// Full-text search query
     ///
-    /// See [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator) for more information.
+    /// If no external search backend is configured, see [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator) for more information.
     #[validate(length(min = 1, max = 64))]
-    query: String,
+    query: Option<String>,
+    /// Only include messages with a matching attachment
+    #[validate]
+    attachment: Option<AttachmentFilter>,
 
     /// Maximum number of messages to fetch
     #[validate(range(min = 1, max = 100))]
@@ -59,6 +63,10 @@ pub async fn req(
         .validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
+    if options.query.is_none() && options.attachment.is_none() {
+        return Err(Error::InvalidOperation);
+    }
+
     let channel = target.as_channel(db).await?;
     perms(&user)
         .channel(&channel)
@@ -67,6 +75,7 @@ pub async fn req(
 
     let OptionsMessageSearch {
         query,
+        attachment,
         limit,
         before,
         after,
@@ -74,11 +83,13 @@ pub async fn req(
         include_users,
     } = options;
 
-    let messages = db
-        .fetch_messages(MessageQuery {
+    let messages = search::fetch_messages(
+        db,
+        MessageQuery {
             filter: MessageFilter {
                 channel: Some(channel.id().to_string()),
-                query: Some(query),
+                query,
+                attachment,
                 ..Default::default()
             },
             time_period: MessageTimePeriod::Absolute {
@@ -87,8 +98,10 @@ pub async fn req(
                 sort: Some(sort),
             },
             limit,
-        })
-        .await?;
+            consistent: false,
+        },
+    )
+    .await?;
 
     BulkMessageResponse::transform(db, Some(&channel), messages, include_users)
         .await
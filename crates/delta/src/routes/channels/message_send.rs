@@ -1,47 +1,76 @@
 use revolt_quark::{
-    models::{message::DataMessageSend, Message, User},
+    authifier::Authifier,
+    models::{message::DataMessageSend, Channel, Message, User},
     perms,
     types::push::MessageAuthor,
+    util::consistency,
+    variables::delta::MAX_SCHEDULED_MESSAGE_COUNT,
     web::idempotency::IdempotencyKey,
-    Db, Error, Permission, Ref, Result,
+    Db, Error, Permission, Ref, Result, Timestamp,
 };
 
-use rocket::serde::json::Json;
+use revolt_database::{Database, ScheduledMessage, Thread};
+use rocket::{serde::json::Json, State};
+use serde::Serialize;
+use ulid::Ulid;
 use validator::Validate;
 
+use crate::util::{auto_thread, policies, verification};
+
+/// # Send Response
+#[derive(Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum MessageSendResponse {
+    /// Message which was sent
+    Message(Message),
+    /// Message which will be sent later
+    Scheduled(revolt_models::v0::ScheduledMessage),
+}
+
 /// # Send Message
 ///
 /// Sends a message to the given channel.
+///
+/// If `send_at` is set, the message is held back and sent at that time instead.
 #[openapi(tag = "Messaging")]
 #[post("/<target>/messages", data = "<data>")]
 pub async fn message_send(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
+    authifier: &State<Authifier>,
     user: User,
     target: Ref,
     data: Json<DataMessageSend>,
     idempotency: IdempotencyKey,
-) -> Result<Json<Message>> {
-    let data = data.into_inner();
+) -> Result<Json<MessageSendResponse>> {
+    let mut data = data.into_inner();
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
+    policies::assert_accepted(db, &user).await?;
+
     // Ensure we have permissions to send a message
-    let channel = target.as_channel(db).await?;
+    let channel = target.as_channel(legacy_db).await?;
+
+    // Unverified accounts may not send direct messages
+    if let Channel::DirectMessage { .. } = &channel {
+        verification::assert_verified(authifier, &user).await?;
+    }
 
     let mut permissions = perms(&user).channel(&channel);
     permissions
-        .throw_permission_and_view_channel(db, Permission::SendMessage)
+        .throw_permission_and_view_channel(legacy_db, Permission::SendMessage)
         .await?;
 
     // Verify permissions for masquerade
     if let Some(masq) = &data.masquerade {
         permissions
-            .throw_permission(db, Permission::Masquerade)
+            .throw_permission(legacy_db, Permission::Masquerade)
             .await?;
 
         if masq.colour.is_some() {
             permissions
-                .throw_permission(db, Permission::ManageRole)
+                .throw_permission(legacy_db, Permission::ManageRole)
                 .await?;
         }
     }
@@ -49,34 +78,101 @@ pub async fn message_send(
     // Check permissions for embeds
     if data.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
         permissions
-            .throw_permission(db, Permission::SendEmbeds)
+            .throw_permission(legacy_db, Permission::SendEmbeds)
             .await?;
     }
 
     // Check permissions for files
     if data.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
         permissions
-            .throw_permission(db, Permission::UploadFiles)
+            .throw_permission(legacy_db, Permission::UploadFiles)
             .await?;
     }
 
     // Ensure interactions information is correct
     if let Some(interactions) = &data.interactions {
-        interactions.validate(db, &mut permissions).await?;
+        interactions.validate(legacy_db, &mut permissions).await?;
+    }
+
+    // Ensure the thread belongs to this channel, only threads archived
+    // for inactivity are brought back by anyone posting in them
+    let thread = if let Some(thread_id) = &data.thread {
+        Some(
+            Thread::fetch_in_channel(db, thread_id, channel.id())
+                .await
+                .map_err(Error::from_core)?,
+        )
+    } else if data.send_at.is_none() {
+        // Move replies deep into a long chain into a thread
+        auto_thread::resolve(db, legacy_db, &channel, &user, &data).await?
+    } else {
+        None
+    };
+
+    if let Some(thread) = &thread {
+        data.thread = Some(thread.id.clone());
+
+        if thread.is_explicitly_archived() {
+            permissions
+                .throw_permission(legacy_db, Permission::ManageThreads)
+                .await?;
+        }
+    }
+
+    // Hold the message back if it should be sent later
+    if let Some(send_at) = data.send_at {
+        if *send_at <= *Timestamp::now_utc() {
+            return Err(Error::InvalidOperation);
+        }
+
+        Channel::validate_message_data(&data)?;
+
+        let pending = db
+            .fetch_scheduled_messages(channel.id())
+            .await
+            .map_err(Error::from_core)?
+            .into_iter()
+            .filter(|message| message.author_id == user.id)
+            .count();
+
+        if pending >= *MAX_SCHEDULED_MESSAGE_COUNT {
+            return Err(Error::InvalidOperation);
+        }
+
+        let mut message = data.into_v0()?;
+        message.send_at = None;
+
+        let scheduled = ScheduledMessage {
+            id: Ulid::new().to_string(),
+            channel_id: channel.id().to_string(),
+            author_id: user.id,
+            send_at,
+            message,
+        };
+
+        scheduled.create(db).await.map_err(Error::from_core)?;
+        return Ok(Json(MessageSendResponse::Scheduled(scheduled.into())));
     }
 
     // Create the message
     let message = channel
         .send_message(
-            db,
+            legacy_db,
             data,
             MessageAuthor::User(&user),
             idempotency,
             permissions
-                .has_permission(db, Permission::SendEmbeds)
+                .has_permission(legacy_db, Permission::SendEmbeds)
                 .await?,
         )
         .await?;
 
-    Ok(Json(message))
+    if let Some(mut thread) = thread {
+        if let Err(err) = thread.record_message(db, &message.id, &user.id).await {
+            log::warn!("Failed to record message in thread {}: {err:?}", thread.id);
+        }
+    }
+
+    consistency::record_write(&user.id);
+    Ok(Json(MessageSendResponse::Message(message)))
 }
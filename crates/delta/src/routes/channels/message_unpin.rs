@@ -0,0 +1,34 @@
+use revolt_quark::{
+    models::{message::PartialMessage, User},
+    perms, Db, EmptyResponse, Permission, Ref, Result,
+};
+
+/// # Unpin Message
+///
+/// Unpin a message in its channel.
+#[openapi(tag = "Messaging")]
+#[delete("/<target>/messages/<msg>/pin")]
+pub async fn req(db: &Db, user: User, target: Ref, msg: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(db, Permission::PinMessages)
+        .await?;
+
+    // Fetch relevant message
+    let mut message = msg.as_message_in(db, channel.id()).await?;
+    if !message.pinned {
+        return Ok(EmptyResponse);
+    }
+
+    message
+        .update(
+            db,
+            PartialMessage {
+                pinned: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -1,6 +1,10 @@
+use revolt_database::Database;
 use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+use rocket::State;
 use serde::{Deserialize, Serialize};
 
+use crate::util::reaction_roles;
+
 /// # Query Parameters
 #[derive(Serialize, Deserialize, JsonSchema, FromForm)]
 pub struct OptionsUnreact {
@@ -18,41 +22,50 @@ pub struct OptionsUnreact {
 #[openapi(tag = "Interactions")]
 #[delete("/<target>/messages/<msg>/reactions/<emoji>?<options..>")]
 pub async fn unreact_message(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     msg: Ref,
     emoji: Ref,
     options: OptionsUnreact,
 ) -> Result<EmptyResponse> {
-    let channel = target.as_channel(db).await?;
+    let channel = target.as_channel(legacy_db).await?;
     let mut permissions = perms(&user).channel(&channel);
     permissions
-        .throw_permission_and_view_channel(db, Permission::React)
+        .throw_permission_and_view_channel(legacy_db, Permission::React)
         .await?;
 
     // Check if we need to escalate permissions
     let remove_all = options.remove_all.unwrap_or_default();
     if options.user_id.is_some() || remove_all {
         permissions
-            .throw_permission(db, Permission::ManageMessages)
+            .throw_permission(legacy_db, Permission::ManageMessages)
             .await?;
     }
 
     // Fetch relevant message
-    let message = msg.as_message_in(db, channel.id()).await?;
+    let message = msg.as_message_in(legacy_db, channel.id()).await?;
 
     // Check if we should wipe all of this reaction
-    if remove_all {
-        return message
-            .clear_reaction(db, &emoji.id)
-            .await
-            .map(|_| EmptyResponse);
-    }
+    let users: Vec<String> = if remove_all {
+        message.clear_reaction(legacy_db, &emoji.id).await?;
+        message
+            .reactions
+            .get(&emoji.id)
+            .map(|users| users.iter().cloned().collect())
+            .unwrap_or_default()
+    } else {
+        // Remove the reaction
+        let target_user = options.user_id.unwrap_or(user.id);
+        message
+            .remove_reaction(legacy_db, &target_user, &emoji.id)
+            .await?;
+        vec![target_user]
+    };
+
+    // Revoke any roles bound to this reaction
+    reaction_roles::apply(db, legacy_db, &message, &emoji.id, &users, false).await?;
 
-    // Remove the reaction
-    message
-        .remove_reaction(db, options.user_id.as_ref().unwrap_or(&user.id), &emoji.id)
-        .await
-        .map(|_| EmptyResponse)
+    Ok(EmptyResponse)
 }
@@ -5,25 +5,57 @@ mod channel_ack;
 mod channel_delete;
 mod channel_edit;
 mod channel_fetch;
+mod channel_follow;
+mod channel_lock;
+mod channel_unfollow;
+mod channel_unlock;
+mod feed_create;
+mod feed_delete;
+mod feed_edit;
+mod feed_fetch_all;
 mod group_add_member;
 mod group_create;
 mod group_remove_member;
+mod inbox_create;
+mod inbox_delete;
+mod inbox_fetch;
 mod invite_create;
+mod invite_create_guest;
 mod members_fetch;
 mod message_bulk_delete;
 mod message_clear_reactions;
 mod message_delete;
 mod message_edit;
 mod message_fetch;
+mod message_fetch_deleted;
+mod message_history_fetch;
+mod message_pin;
+mod message_pins_fetch;
 mod message_query;
 mod message_query_stale;
 mod message_react;
+mod message_react_bulk;
+mod message_restore;
 mod message_search;
 mod message_send;
+mod message_unpin;
 mod message_unreact;
+mod permissions_copy;
+mod permissions_explain;
 mod permissions_set;
 mod permissions_set_default;
+mod schedule_create;
+mod schedule_delete;
+mod schedule_fetch_all;
+mod scheduled_message_delete;
+mod scheduled_message_fetch_all;
+mod thread_create;
+mod thread_edit;
+mod thread_fetch_all;
+mod thread_members_fetch;
+mod topic_history_fetch;
 mod voice_join;
+mod voice_leave;
 mod webhook_create;
 mod webhook_fetch_all;
 
@@ -34,7 +66,13 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         members_fetch::req,
         channel_delete::req,
         channel_edit::req,
+        topic_history_fetch::req,
+        channel_lock::req,
+        channel_unlock::req,
+        channel_follow::req,
+        channel_unfollow::req,
         invite_create::req,
+        invite_create_guest::req,
         message_send::message_send,
         message_query::req,
         message_search::req,
@@ -43,16 +81,42 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         message_edit::req,
         message_bulk_delete::req,
         message_delete::req,
+        message_fetch_deleted::req,
+        message_history_fetch::req,
+        message_restore::req,
+        message_pin::req,
+        message_unpin::req,
+        message_pins_fetch::req,
+        thread_create::req,
+        thread_fetch_all::req,
+        thread_edit::req,
+        thread_members_fetch::req,
         group_create::req,
         group_add_member::req,
         group_remove_member::req,
         voice_join::req,
+        voice_leave::req,
+        permissions_explain::req,
+        permissions_copy::req,
         permissions_set::req,
         permissions_set_default::req,
         message_react::react_message,
+        message_react_bulk::react_message_bulk,
         message_unreact::unreact_message,
         message_clear_reactions::clear_reactions,
         webhook_create::req,
         webhook_fetch_all::req,
+        inbox_create::req,
+        inbox_fetch::req,
+        inbox_delete::req,
+        feed_create::req,
+        feed_fetch_all::req,
+        feed_edit::req,
+        feed_delete::req,
+        schedule_create::req,
+        schedule_fetch_all::req,
+        schedule_delete::req,
+        scheduled_message_fetch_all::req,
+        scheduled_message_delete::req,
     ]
 }
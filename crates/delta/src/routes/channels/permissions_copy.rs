@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use rocket::serde::json::Json;
+
+use revolt_quark::{
+    models::{
+        channel::{FieldsChannel, PartialChannel},
+        Channel, User,
+    },
+    perms, Db, Error, Override, OverrideField, Permission, Ref, Result,
+};
+
+/// # Copy Permissions
+///
+/// Replaces the default and role permission overrides of this channel
+/// with those of another channel in the same server.
+///
+/// Both channels must be a `TextChannel` or `VoiceChannel`.
+#[openapi(tag = "Channel Permissions")]
+#[post("/<target>/permissions/copy_from/<source>")]
+pub async fn req(db: &Db, user: User, target: Ref, source: Ref) -> Result<Json<Channel>> {
+    let mut channel = target.as_channel(db).await?;
+    let source = source.as_channel(db).await?;
+
+    if channel.id() == source.id() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let (server_id, current_default, current_roles) = match &channel {
+        Channel::TextChannel {
+            server,
+            default_permissions,
+            role_permissions,
+            ..
+        }
+        | Channel::VoiceChannel {
+            server,
+            default_permissions,
+            role_permissions,
+            ..
+        } => (server, *default_permissions, role_permissions.clone()),
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    let (next_default, next_roles) = match &source {
+        Channel::TextChannel {
+            server,
+            default_permissions,
+            role_permissions,
+            ..
+        }
+        | Channel::VoiceChannel {
+            server,
+            default_permissions,
+            role_permissions,
+            ..
+        } if server == server_id => (*default_permissions, role_permissions.clone()),
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    perms(&user)
+        .channel(&source)
+        .throw_permission(db, Permission::ViewChannel)
+        .await?;
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(db, Permission::ManagePermissions)
+        .await?;
+
+    let server = permissions.server.get().ok_or(Error::InvalidOperation)?;
+    let rank = permissions.get_member_rank().unwrap_or(i64::MIN);
+
+    // Overrides for roles which no longer exist are not carried over.
+    let next_roles = next_roles
+        .into_iter()
+        .filter(|(id, _)| server.roles.contains_key(id))
+        .collect::<HashMap<String, OverrideField>>();
+
+    let changed_roles = current_roles
+        .keys()
+        .chain(next_roles.keys())
+        .filter(|id| current_roles.get(*id) != next_roles.get(*id))
+        .cloned()
+        .collect::<HashSet<String>>();
+
+    if current_default == next_default && changed_roles.is_empty() {
+        return Err(Error::NoEffect);
+    }
+
+    // Check we are allowed to make every individual change.
+    let mut checks = vec![];
+    if current_default != next_default {
+        checks.push((
+            current_default.map(Override::from),
+            next_default.unwrap_or_default().into(),
+        ));
+    }
+
+    for id in &changed_roles {
+        if let Some(role) = server.roles.get(id) {
+            if role.rank <= rank {
+                return Err(Error::NotElevated);
+            }
+
+            checks.push((
+                Some(role.permissions.into()),
+                next_roles.get(id).copied().unwrap_or_default().into(),
+            ));
+        }
+    }
+
+    for (current_value, next_value) in checks {
+        permissions
+            .throw_permission_override(db, current_value, next_value)
+            .await?;
+    }
+
+    channel
+        .update(
+            db,
+            PartialChannel {
+                role_permissions: Some(next_roles),
+                default_permissions: next_default,
+                ..Default::default()
+            },
+            if next_default.is_none() {
+                vec![FieldsChannel::DefaultPermissions]
+            } else {
+                vec![]
+            },
+        )
+        .await?;
+
+    Ok(Json(channel))
+}
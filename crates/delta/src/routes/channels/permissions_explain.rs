@@ -0,0 +1,29 @@
+use rocket::serde::json::Json;
+
+use revolt_quark::{models::User, perms, Db, Permission, PermissionExplanation, Ref, Result};
+
+/// # Explain User Permissions
+///
+/// Resolves the permissions of the specified user in this channel,
+/// along with which role or override last granted or revoked each one.
+#[openapi(tag = "Channel Permissions")]
+#[get("/<target>/permissions/<user_id>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    user_id: Ref,
+) -> Result<Json<PermissionExplanation>> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(db, Permission::ManagePermissions)
+        .await?;
+
+    let target_user = user_id.as_user(db).await?;
+    perms(&target_user)
+        .channel(&channel)
+        .explain(db)
+        .await
+        .map(Json)
+}
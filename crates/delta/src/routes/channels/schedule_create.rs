@@ -0,0 +1,82 @@
+use revolt_database::{ChannelSchedule, ChannelScheduleChanges, Database};
+use revolt_models::v0::DataCreateChannelSchedule;
+use revolt_quark::{
+    models::{Channel, User},
+    perms,
+    variables::delta::MAX_SCHEDULE_COUNT,
+    Db, Error, Permission, Ref, Result, Timestamp,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Schedule Channel Change
+///
+/// Schedule changes to this channel's settings to be applied at a later time.
+#[openapi(tag = "Channel Information")]
+#[post("/<target>/schedules", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateChannelSchedule>,
+) -> Result<Json<revolt_models::v0::ChannelSchedule>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let changes: ChannelScheduleChanges = data.changes.into();
+    if changes.is_empty() {
+        return Err(Error::InvalidOperation);
+    }
+
+    if *data.run_at <= *Timestamp::now_utc() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let channel = target.as_channel(legacy_db).await?;
+    match &channel {
+        Channel::TextChannel { .. } => {}
+        Channel::VoiceChannel { .. } => {
+            if changes.locked.is_some()
+                || changes.media_only.is_some()
+                || changes.slowmode.is_some()
+            {
+                return Err(Error::InvalidOperation);
+            }
+        }
+        _ => return Err(Error::InvalidOperation),
+    }
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::ManageChannel)
+        .await?;
+
+    if changes.locked.is_some() {
+        permissions
+            .throw_permission(legacy_db, Permission::ManageThreads)
+            .await?;
+    }
+
+    let schedules = db
+        .fetch_channel_schedules(channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    if schedules.len() >= *MAX_SCHEDULE_COUNT {
+        return Err(Error::InvalidOperation);
+    }
+
+    let schedule = ChannelSchedule {
+        id: Ulid::new().to_string(),
+        channel_id: channel.id().to_string(),
+        creator_id: user.id,
+        run_at: data.run_at,
+        changes,
+    };
+
+    schedule.create(db).await.map_err(Error::from_core)?;
+    Ok(Json(schedule.into()))
+}
@@ -0,0 +1,37 @@
+use revolt_database::{util::reference::Reference, Database};
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Cancel Scheduled Change
+///
+/// Cancel a pending scheduled change to this channel.
+#[openapi(tag = "Channel Information")]
+#[delete("/<target>/schedules/<schedule_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    schedule_id: Reference,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(legacy_db, Permission::ManageChannel)
+        .await?;
+
+    let schedule = db
+        .fetch_channel_schedule(&schedule_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if schedule.channel_id != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    schedule
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
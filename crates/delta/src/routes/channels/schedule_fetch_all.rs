@@ -0,0 +1,30 @@
+use revolt_database::Database;
+use revolt_models::v0::ChannelSchedule;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Scheduled Changes
+///
+/// Fetch all pending scheduled changes to this channel, soonest first.
+#[openapi(tag = "Channel Information")]
+#[get("/<target>/schedules")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<ChannelSchedule>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(legacy_db, Permission::ManageChannel)
+        .await?;
+
+    let mut schedules = db
+        .fetch_channel_schedules(channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    schedules.sort_by(|a, b| a.run_at.cmp(&b.run_at));
+    Ok(Json(schedules.into_iter().map(|v| v.into()).collect()))
+}
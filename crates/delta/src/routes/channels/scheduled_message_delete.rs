@@ -0,0 +1,45 @@
+use revolt_database::{util::reference::Reference, Database};
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Cancel Scheduled Message
+///
+/// Cancel a pending scheduled message in this channel.
+///
+/// Users with permission to manage messages can cancel anyone's message.
+#[openapi(tag = "Messaging")]
+#[delete("/<target>/scheduled_messages/<message_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    message_id: Reference,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(legacy_db).await?;
+    let message = db
+        .fetch_scheduled_message(&message_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if message.channel_id != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
+
+    if message.author_id != user.id {
+        permissions
+            .throw_permission(legacy_db, Permission::ManageMessages)
+            .await?;
+    }
+
+    message
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
@@ -0,0 +1,39 @@
+use revolt_database::Database;
+use revolt_models::v0::ScheduledMessage;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Scheduled Messages
+///
+/// Fetch your pending scheduled messages in this channel, soonest first.
+///
+/// Users with permission to manage messages can see every pending message.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/scheduled_messages")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<ScheduledMessage>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
+
+    let manage_messages = permissions
+        .has_permission(legacy_db, Permission::ManageMessages)
+        .await?;
+
+    let mut messages: Vec<_> = db
+        .fetch_scheduled_messages(channel.id())
+        .await
+        .map_err(Error::from_core)?
+        .into_iter()
+        .filter(|message| manage_messages || message.author_id == user.id)
+        .collect();
+
+    messages.sort_by(|a, b| a.send_at.cmp(&b.send_at));
+    Ok(Json(messages.into_iter().map(|v| v.into()).collect()))
+}
@@ -0,0 +1,64 @@
+use revolt_database::{Database, Thread};
+use revolt_models::v0::{self, DataCreateThread};
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Start Thread
+///
+/// Start a thread from a message in this channel.
+#[openapi(tag = "Threads")]
+#[post("/<target>/messages/<msg>/thread", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    msg: Ref,
+    data: Json<DataCreateThread>,
+) -> Result<Json<v0::Thread>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(legacy_db, Permission::SendMessage)
+        .await?;
+
+    // Threads may only be started in server text channels
+    if !matches!(channel, Channel::TextChannel { .. }) {
+        return Err(Error::InvalidOperation);
+    }
+
+    let message = msg.as_message(legacy_db).await?;
+    if message.channel != channel.id() {
+        return Err(Error::NotFound);
+    }
+
+    // Threads cannot be started from messages inside other threads
+    if message.thread.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    if db.fetch_thread(&message.id).await.is_ok() {
+        return Err(Error::ThreadAlreadyExists);
+    }
+
+    Thread::create(
+        db,
+        &message.id,
+        &message.author,
+        channel.id(),
+        &user.id,
+        data.name,
+        data.auto_archive_duration,
+    )
+    .await
+    .map(|thread| Json(thread.into()))
+    .map_err(Error::from_core)
+}
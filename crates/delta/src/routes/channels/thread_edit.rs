@@ -0,0 +1,60 @@
+use revolt_database::{Database, PartialThread, Thread};
+use revolt_models::v0::{self, DataEditThread};
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Thread
+///
+/// Rename, archive or unarchive a thread.
+///
+/// Only the user who started the thread or members with `ManageThreads` may edit it.
+#[openapi(tag = "Threads")]
+#[patch("/<target>/threads/<thread_id>", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    thread_id: String,
+    data: Json<DataEditThread>,
+) -> Result<Json<v0::Thread>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let channel = target.as_channel(legacy_db).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::SendMessage)
+        .await?;
+
+    let mut thread = Thread::fetch_in_channel(db, &thread_id, channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    if thread.creator_id != user.id {
+        permissions
+            .throw_permission(legacy_db, Permission::ManageThreads)
+            .await?;
+    }
+
+    if data.name.is_none() && data.auto_archive_duration.is_none() && data.archived.is_none() {
+        return Ok(Json(thread.into()));
+    }
+
+    thread
+        .update(
+            db,
+            PartialThread {
+                name: data.name,
+                auto_archive_duration: data.auto_archive_duration,
+                archived: data.archived,
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(Error::from_core)?;
+
+    Ok(Json(thread.into()))
+}
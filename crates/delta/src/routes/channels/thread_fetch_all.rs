@@ -0,0 +1,42 @@
+use revolt_database::Database;
+use revolt_models::v0::Thread;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Threads
+///
+/// Fetch threads started in this channel, newest first.
+///
+/// Archived threads are only included if requested.
+#[openapi(tag = "Threads")]
+#[get("/<target>/threads?<include_archived>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    include_archived: Option<bool>,
+) -> Result<Json<Vec<Thread>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
+
+    let include_archived = include_archived.unwrap_or_default();
+    Ok(Json(
+        db.fetch_threads(channel.id())
+            .await
+            .map_err(Error::from_core)?
+            .into_iter()
+            .filter_map(|mut thread| {
+                thread.refresh_archived();
+                if include_archived || !thread.archived {
+                    Some(thread.into())
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,35 @@
+use revolt_database::{Database, Thread};
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Thread Members
+///
+/// Retrieves all users who started or have posted in a thread.
+#[openapi(tag = "Threads")]
+#[get("/<target>/threads/<thread_id>/members")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    thread_id: String,
+) -> Result<Json<Vec<User>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
+
+    let thread = Thread::fetch_in_channel(db, &thread_id, channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    Ok(Json(
+        legacy_db
+            .fetch_users(&thread.participants)
+            .await?
+            .into_iter()
+            .map(|x| x.with_relationship(&user))
+            .collect::<Vec<User>>(),
+    ))
+}
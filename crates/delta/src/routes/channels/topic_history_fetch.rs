@@ -0,0 +1,37 @@
+use revolt_database::Database;
+use revolt_models::v0::ChannelTopicChange;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Topic History
+///
+/// Fetch recent changes to this channel's name and description, newest first.
+#[openapi(tag = "Channel Information")]
+#[get("/<target>/topic_history?<before>&<limit>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    before: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<Vec<ChannelTopicChange>>> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(legacy_db, Permission::ViewChannel)
+        .await?;
+
+    Ok(Json(
+        db.fetch_channel_topic_history(
+            channel.id(),
+            before.as_deref(),
+            limit.unwrap_or(50).clamp(1, 100),
+        )
+        .await
+        .map_err(Error::from_core)?
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    ))
+}
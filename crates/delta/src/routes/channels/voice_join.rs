@@ -1,3 +1,4 @@
+use revolt_database::Database;
 use revolt_quark::{
     models::{Channel, User},
     perms,
@@ -5,27 +6,74 @@ use revolt_quark::{
     Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::util::voice;
 
 /// # Voice Server Token Response
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct CreateVoiceUserResponse {
     /// Token for authenticating with the voice server
     token: String,
+    /// Whether the user has been muted by a moderator
+    #[serde(default)]
+    muted: bool,
+    /// Whether the user has been deafened by a moderator
+    #[serde(default)]
+    deafened: bool,
+    /// Whether the user may share video
+    #[serde(default)]
+    video: bool,
+    /// Whether the user may share their screen
+    #[serde(default)]
+    screen_share: bool,
+    /// Whether the user is a priority speaker
+    #[serde(default)]
+    priority_speaker: bool,
+}
+
+/// Stream permissions passed to the voice server for a new user
+#[derive(Serialize)]
+struct VoiceUserPermissions {
+    /// Whether the user may publish a video track
+    video: bool,
+    /// Whether the user may publish a screen share track
+    screen_share: bool,
+    /// Whether other participants should be ducked while the user speaks
+    priority_speaker: bool,
+}
+
+/// Room information returned by the voice server
+#[derive(Deserialize)]
+struct VoiceRoomInfo {
+    /// Users currently connected to the room
+    #[serde(default)]
+    users: HashMap<String, serde_json::Value>,
 }
 
 /// # Join Call
 ///
 /// Asks the voice server for a token to join the call.
+///
+/// Video and screen sharing are only granted to users with the
+/// `Video` and `ScreenShare` permissions respectively. Users with
+/// `PrioritySpeaker` are flagged so that the voice server can duck
+/// other participants while they talk.
 #[openapi(tag = "Voice")]
 #[post("/<target>/join_call")]
-pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUserResponse>> {
-    let channel = target.as_channel(db).await?;
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<CreateVoiceUserResponse>> {
+    let channel = target.as_channel(legacy_db).await?;
     let mut permissions = perms(&user).channel(&channel);
 
     permissions
-        .throw_permission_and_view_channel(db, Permission::Connect)
+        .throw_permission_and_view_channel(legacy_db, Permission::Connect)
         .await?;
 
     if !*USE_VOSO {
@@ -39,6 +87,34 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
         _ => {}
     }
 
+    // Apply server voice moderation to this call
+    let (muted, deafened) = if let Channel::VoiceChannel { server, .. } = &channel {
+        let member = legacy_db.fetch_member(server, &user.id).await?;
+        (member.voice_muted, member.voice_deafened)
+    } else {
+        (false, false)
+    };
+
+    // Streams are only permitted with their own permissions
+    let video = permissions
+        .has_permission(legacy_db, Permission::Video)
+        .await?;
+    let screen_share = permissions
+        .has_permission(legacy_db, Permission::ScreenShare)
+        .await?;
+
+    let priority_speaker = permissions
+        .has_permission(legacy_db, Permission::PrioritySpeaker)
+        .await?;
+
+    let user_limit = match &channel {
+        Channel::VoiceChannel {
+            user_limit: Some(limit),
+            ..
+        } => Some(*limit as usize),
+        _ => None,
+    };
+
     // To join a call:
     // - Check if the room exists.
     // - If not, create it.
@@ -55,7 +131,22 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
     match result {
         Err(_) => return Err(Error::VosoUnavailable),
         Ok(result) => match result.status() {
-            reqwest::StatusCode::OK => (),
+            reqwest::StatusCode::OK => {
+                // Make sure there is space for us in the room
+                if let Some(max) = user_limit {
+                    let room: VoiceRoomInfo =
+                        result.json().await.map_err(|_| Error::VosoUnavailable)?;
+
+                    let connected = room.users.keys().filter(|id| **id != user.id).count();
+                    if connected >= max
+                        && !permissions
+                            .has_permission(legacy_db, Permission::BypassUserLimit)
+                            .await?
+                    {
+                        return Err(Error::VoiceChannelFull { max, connected });
+                    }
+                }
+            }
             reqwest::StatusCode::NOT_FOUND => {
                 if (client
                     .post(&format!("{}/room/{}", *VOSO_URL, channel.id()))
@@ -75,7 +166,7 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
     }
 
     // Then create a user for the room.
-    if let Ok(response) = client
+    let response = if let Ok(response) = client
         .post(&format!(
             "{}/room/{}/user/{}",
             *VOSO_URL,
@@ -86,15 +177,30 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
             reqwest::header::AUTHORIZATION,
             VOSO_MANAGE_TOKEN.to_string(),
         )
+        .json(&VoiceUserPermissions {
+            video,
+            screen_share,
+            priority_speaker,
+        })
         .send()
         .await
     {
         response
-            .json()
+            .json::<CreateVoiceUserResponse>()
             .await
-            .map_err(|_| Error::InvalidOperation)
-            .map(Json)
+            .map_err(|_| Error::InvalidOperation)?
     } else {
-        Err(Error::VosoUnavailable)
-    }
+        return Err(Error::VosoUnavailable);
+    };
+
+    voice::joined(db, legacy_db, &channel, &user.id).await;
+
+    Ok(Json(CreateVoiceUserResponse {
+        muted,
+        deafened,
+        video,
+        screen_share,
+        priority_speaker,
+        ..response
+    }))
 }
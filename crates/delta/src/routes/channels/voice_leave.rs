@@ -0,0 +1,33 @@
+use revolt_database::Database;
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, EmptyResponse, Error, Permission, Ref, Result,
+};
+use rocket::State;
+
+use crate::util::voice;
+
+/// # Leave Call
+///
+/// Let other members know that you have left the call.
+#[openapi(tag = "Voice")]
+#[post("/<target>/leave_call")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(legacy_db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(legacy_db, Permission::Connect)
+        .await?;
+
+    if let Channel::SavedMessages { .. } | Channel::TextChannel { .. } = channel {
+        return Err(Error::CannotJoinCall);
+    }
+
+    voice::left(db, &channel, &user.id).await;
+    Ok(EmptyResponse)
+}
@@ -1,4 +1,4 @@
-use revolt_database::{Database, Webhook};
+use revolt_database::{AuditLogAction, Database, Webhook};
 use revolt_quark::{
     models::{Channel, User},
     perms, Db, Error, Permission, Ref, Result,
@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 use validator::Validate;
 
+use crate::util::audit_log;
+
 #[derive(Validate, Serialize, Deserialize, JsonSchema)]
 pub struct CreateWebhookBody {
     #[validate(length(min = 1, max = 32))]
@@ -61,9 +63,26 @@ pub async fn req(
         avatar,
         channel_id: channel.id().to_string(),
         token: Some(nanoid::nanoid!(64)),
+        limits: None,
     };
 
     webhook.create(db).await.map_err(Error::from_core)?;
 
+    if let Channel::TextChannel { server, .. } = &channel {
+        audit_log::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::WebhookCreate,
+            &webhook.id,
+            None,
+            Some(&Webhook {
+                token: None,
+                ..webhook.clone()
+            }),
+        )
+        .await;
+    }
+
     Ok(Json(webhook.into()))
 }
@@ -0,0 +1,56 @@
+use revolt_quark::models::emoji::EMOJI_ASSET_SIZES;
+use revolt_quark::{Db, Error, Ref, Result};
+use revolt_rocket_okapi::revolt_okapi::openapi3::{self, RefOr};
+use rocket::response::{self, Redirect, Responder};
+use rocket::{Request, Response};
+
+pub struct CachedRedirect(Redirect);
+
+pub static CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+impl<'r> Responder<'r, 'static> for CachedRedirect {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        Response::build_from(self.0.respond_to(req)?)
+            .raw_header("Cache-Control", CACHE_CONTROL)
+            .ok()
+    }
+}
+
+impl revolt_rocket_okapi::response::OpenApiResponderInner for CachedRedirect {
+    fn responses(
+        _gen: &mut revolt_rocket_okapi::gen::OpenApiGenerator,
+    ) -> std::result::Result<openapi3::Responses, revolt_rocket_okapi::OpenApiError> {
+        let mut responses = schemars::Map::new();
+        responses.insert(
+            "308".to_string(),
+            RefOr::Object(openapi3::Response {
+                description: "Redirect to Emoji Image".to_string(),
+                ..Default::default()
+            }),
+        );
+
+        Ok(openapi3::Responses {
+            responses,
+            ..Default::default()
+        })
+    }
+}
+
+/// # Fetch Emoji Image
+///
+/// Redirect to an emoji's image on the file server.
+///
+/// Pass a size of 32, 64, 128 or 256 to get the image scaled to fit within it.
+/// The response may be cached indefinitely as emoji images never change.
+#[openapi(tag = "Emojis")]
+#[get("/emoji/<id>/asset?<size>")]
+pub async fn fetch_emoji_asset(db: &Db, id: Ref, size: Option<u32>) -> Result<CachedRedirect> {
+    if let Some(size) = size {
+        if !EMOJI_ASSET_SIZES.contains(&size) {
+            return Err(Error::InvalidProperty);
+        }
+    }
+
+    let emoji = id.as_emoji(db).await?;
+    Ok(CachedRedirect(Redirect::permanent(emoji.asset_url(size))))
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use revolt_quark::models::emoji::EmojiParent;
 use revolt_quark::models::{Emoji, File, User};
 use revolt_quark::variables::delta::MAX_EMOJI_COUNT;
@@ -15,6 +17,10 @@ pub struct DataCreateEmoji {
     /// Server name
     #[validate(length(min = 1, max = 32), regex = "RE_EMOJI")]
     name: String,
+    /// Alternative names for the emoji
+    #[serde(default)]
+    #[validate(length(max = 10))]
+    aliases: Vec<String>,
     /// Parent information
     parent: EmojiParent,
     /// Whether the emoji is mature
@@ -22,6 +28,23 @@ pub struct DataCreateEmoji {
     nsfw: bool,
 }
 
+/// Check that aliases are valid emoji names distinct from the main name and each other
+pub fn validate_aliases(name: &str, aliases: &[String]) -> Result<()> {
+    let mut seen = HashSet::new();
+    for alias in aliases {
+        if alias.is_empty()
+            || alias.len() > 32
+            || !RE_EMOJI.is_match(alias)
+            || alias == name
+            || !seen.insert(alias)
+        {
+            return Err(Error::InvalidProperty);
+        }
+    }
+
+    Ok(())
+}
+
 /// # Create New Emoji
 ///
 /// Create an emoji by its Autumn upload id.
@@ -36,6 +59,7 @@ pub async fn create_emoji(
     let data = data.into_inner();
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
+    validate_aliases(&data.name, &data.aliases)?;
 
     // Bots cannot manage emojis
     if user.bot.is_some() {
@@ -53,10 +77,9 @@ pub async fn create_emoji(
                 .throw_permission(db, Permission::ManageCustomisation)
                 .await?;
 
-            // Check that there are no more than 100 emoji
-            // ! FIXME: hardcoded upper limit
+            // Check that the server has room for another emoji
             let emojis = db.fetch_emoji_by_parent_id(&server.id).await?;
-            if emojis.len() > *MAX_EMOJI_COUNT {
+            if emojis.len() >= *MAX_EMOJI_COUNT {
                 return Err(Error::TooManyEmoji { max: *MAX_EMOJI_COUNT });
             }
         }
@@ -72,8 +95,10 @@ pub async fn create_emoji(
         parent: data.parent,
         creator_id: user.id,
         name: data.name,
+        aliases: data.aliases,
         animated: "image/gif" == &attachment.content_type,
         nsfw: data.nsfw,
+        replaced_by: None,
     };
 
     // Save emoji
@@ -0,0 +1,63 @@
+use revolt_quark::models::{emoji::EmojiParent, Emoji, User};
+use revolt_quark::{perms, Db, Error, Permission, Ref, Result};
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::util::regex::RE_EMOJI;
+
+use rocket::serde::json::Json;
+
+use super::emoji_create::validate_aliases;
+
+/// # Emoji Data
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataEditEmoji {
+    /// Emoji name
+    #[validate(length(min = 1, max = 32), regex = "RE_EMOJI")]
+    name: Option<String>,
+    /// Alternative names for the emoji
+    #[validate(length(max = 10))]
+    aliases: Option<Vec<String>>,
+}
+
+/// # Edit Emoji
+///
+/// Change the name or aliases of an emoji.
+#[openapi(tag = "Emojis")]
+#[patch("/emoji/<id>", data = "<data>")]
+pub async fn edit_emoji(
+    db: &Db,
+    user: User,
+    id: Ref,
+    data: Json<DataEditEmoji>,
+) -> Result<Json<Emoji>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // Bots cannot manage emoji
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let mut emoji = id.as_emoji(db).await?;
+    match &emoji.parent {
+        EmojiParent::Server { id } => {
+            let server = db.fetch_server(id).await?;
+
+            // Check for permission
+            perms(&user)
+                .server(&server)
+                .throw_permission(db, Permission::ManageCustomisation)
+                .await?;
+        }
+        EmojiParent::Detached => return Err(Error::InvalidOperation),
+    };
+
+    let name = data.name.unwrap_or_else(|| emoji.name.clone());
+    let aliases = data.aliases.unwrap_or_else(|| emoji.aliases.clone());
+    validate_aliases(&name, &aliases)?;
+
+    emoji.update_names(db, name, aliases).await?;
+    Ok(Json(emoji))
+}
@@ -0,0 +1,46 @@
+use revolt_quark::models::{emoji::EmojiParent, Emoji, File, User};
+use revolt_quark::{perms, Db, Error, Permission, Ref, Result};
+
+use rocket::serde::json::Json;
+
+/// # Replace Emoji
+///
+/// Replace an emoji's image with a new Autumn upload.
+///
+/// The replacement keeps the emoji's name and aliases, and existing reactions
+/// and messages using the old emoji are moved over to it in the background.
+#[openapi(tag = "Emojis")]
+#[put("/emoji/<id>/replace/<upload_id>")]
+pub async fn replace_emoji(db: &Db, user: User, id: Ref, upload_id: String) -> Result<Json<Emoji>> {
+    // Bots cannot manage emoji
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let emoji = id.as_emoji(db).await?;
+    match &emoji.parent {
+        EmojiParent::Server { id } => {
+            let server = db.fetch_server(id).await?;
+
+            // Check for permission
+            perms(&user)
+                .server(&server)
+                .throw_permission(db, Permission::ManageCustomisation)
+                .await?;
+        }
+        EmojiParent::Detached => return Err(Error::InvalidOperation),
+    };
+
+    // Find the relevant attachment
+    let attachment = File::use_emoji(db, &upload_id, &upload_id).await?;
+
+    emoji
+        .replace(
+            db,
+            upload_id,
+            user.id,
+            "image/gif" == &attachment.content_type,
+        )
+        .await
+        .map(Json)
+}
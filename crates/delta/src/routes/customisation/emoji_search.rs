@@ -0,0 +1,67 @@
+use revolt_quark::models::{Emoji, User};
+use revolt_quark::{Db, Error, Result};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsSearchEmoji {
+    /// Name to search for
+    #[validate(length(min = 1, max = 32))]
+    query: String,
+    /// Maximum number of emoji to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<usize>,
+    /// Number of results to skip
+    offset: Option<usize>,
+}
+
+/// # Search Emoji
+///
+/// Search through custom emoji from all servers the user is in, best matches first.
+#[openapi(tag = "Emojis")]
+#[get("/emoji/search?<options..>")]
+pub async fn search_emoji(
+    db: &Db,
+    user: User,
+    options: OptionsSearchEmoji,
+) -> Result<Json<Vec<Emoji>>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server_ids: Vec<String> = db
+        .fetch_all_memberships(&user.id)
+        .await?
+        .into_iter()
+        .map(|member| member.id.server)
+        .collect();
+
+    let mut matches: Vec<(usize, Emoji)> = db
+        .fetch_emoji_by_parent_ids(&server_ids)
+        .await?
+        .into_iter()
+        .filter_map(|emoji| {
+            emoji
+                .match_score(&options.query)
+                .map(|score| (score, emoji))
+        })
+        .collect();
+
+    matches.sort_by(|(score_a, emoji_a), (score_b, emoji_b)| {
+        score_a
+            .cmp(score_b)
+            .then_with(|| emoji_a.name.cmp(&emoji_b.name))
+    });
+
+    Ok(Json(
+        matches
+            .into_iter()
+            .skip(options.offset.unwrap_or(0))
+            .take(options.limit.unwrap_or(50))
+            .map(|(_, emoji)| emoji)
+            .collect(),
+    ))
+}
@@ -1,14 +1,22 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod emoji_asset;
 mod emoji_create;
 mod emoji_delete;
+mod emoji_edit;
 mod emoji_fetch;
+mod emoji_replace;
+mod emoji_search;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
         emoji_create::create_emoji,
         emoji_delete::delete_emoji,
-        emoji_fetch::fetch_emoji
+        emoji_edit::edit_emoji,
+        emoji_replace::replace_emoji,
+        emoji_fetch::fetch_emoji,
+        emoji_asset::fetch_emoji_asset,
+        emoji_search::search_emoji
     ]
 }
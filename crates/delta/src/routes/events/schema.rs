@@ -0,0 +1,18 @@
+use revolt_quark::events::schema::{catalog, EventCatalog, PROTOCOL_VERSION};
+use revolt_quark::{Error, Result};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Event Schema
+///
+/// Fetch a machine-readable catalog of the payloads sent and accepted over the events WebSocket.
+///
+/// Defaults to the latest protocol version.
+#[openapi(tag = "Core")]
+#[get("/schema?<version>")]
+pub async fn req(version: Option<i32>) -> Result<Json<EventCatalog>> {
+    catalog(version.unwrap_or(PROTOCOL_VERSION))
+        .cloned()
+        .map(Json)
+        .ok_or(Error::NotFound)
+}
@@ -0,0 +1,70 @@
+use revolt_database::{Database, FederatedMessage};
+use revolt_models::v0::{FederationEnvelope, FederationPayload};
+use revolt_quark::{
+    models::user::FederatedMessagePolicy, variables::delta::FEDERATION_DOMAIN, Db, EmptyResponse,
+    Error, Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+
+use crate::util::{features, federation};
+
+/// # Receive Federated Request
+///
+/// Receive a signed request from another instance.
+#[openapi(tag = "Federation")]
+#[post("/inbox", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    data: Json<FederationEnvelope>,
+) -> Result<EmptyResponse> {
+    features::assert_enabled(db, features::FEDERATION, None).await?;
+
+    let envelope = data.into_inner();
+    match federation::verify(&envelope).await? {
+        FederationPayload::DirectMessage {
+            id: _,
+            from,
+            to,
+            destination,
+            content,
+        } => {
+            if !destination.eq_ignore_ascii_case(&FEDERATION_DOMAIN) {
+                return Err(Error::InvalidOperation);
+            }
+
+            if from.is_empty() || from.contains('@') || content.is_empty() || content.len() > 2000 {
+                return Err(Error::InvalidProperty);
+            }
+
+            // Bots can't receive federated messages
+            let user = legacy_db.fetch_user(&to).await?;
+            if user.bot.is_some() {
+                return Err(Error::IsBot);
+            }
+
+            let remote_user = format!("{from}@{}", envelope.origin.to_lowercase());
+            // Users have to opt in to messages from other instances
+            if user.has_blocked(&remote_user)
+                || user.federated_dms != Some(FederatedMessagePolicy::Everyone)
+            {
+                return Err(Error::BlockedByOther);
+            }
+
+            // Ids chosen by the remote instance could collide with our own
+            FederatedMessage {
+                id: Ulid::new().to_string(),
+                user_id: user.id,
+                remote_user,
+                outgoing: false,
+                content,
+            }
+            .create(db)
+            .await
+            .map_err(Error::from_core)?;
+        }
+    }
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,14 @@
+use revolt_models::v0::FederationKey;
+use revolt_quark::Result;
+use rocket::serde::json::Json;
+
+use crate::util::federation;
+
+/// # Fetch Federation Key
+///
+/// Fetch the public key other instances use to verify requests from this instance.
+#[openapi(tag = "Federation")]
+#[get("/key")]
+pub async fn req() -> Result<Json<FederationKey>> {
+    federation::public_key().map(Json)
+}
@@ -0,0 +1,58 @@
+use revolt_database::{parse_remote_user, Database, FederatedMessage};
+use revolt_models::v0::{DataSendFederatedMessage, FederationPayload};
+use revolt_quark::{authifier::Authifier, models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+use crate::util::{features, federation, verification};
+
+/// # Send Federated Message
+///
+/// Send a direct message to a user on another instance, identified as `user_id@domain`.
+#[openapi(tag = "Federation")]
+#[post("/dms/<target>", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    authifier: &State<Authifier>,
+    user: User,
+    target: String,
+    data: Json<DataSendFederatedMessage>,
+) -> Result<Json<revolt_models::v0::FederatedMessage>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    features::assert_enabled(db, features::FEDERATION, None).await?;
+    verification::assert_verified(authifier, &user).await?;
+
+    let (remote_id, domain) = parse_remote_user(&target).ok_or(Error::InvalidProperty)?;
+    let domain = domain.to_lowercase();
+
+    let message = FederatedMessage {
+        id: Ulid::new().to_string(),
+        user_id: user.id.clone(),
+        remote_user: format!("{remote_id}@{domain}"),
+        outgoing: true,
+        content: data.content,
+    };
+
+    federation::deliver(
+        &domain,
+        &FederationPayload::DirectMessage {
+            id: message.id.clone(),
+            from: user.id,
+            to: remote_id.to_string(),
+            destination: domain.clone(),
+            content: message.content.clone(),
+        },
+    )
+    .await?;
+
+    message.create(db).await.map_err(Error::from_core)?;
+    Ok(Json(message.into()))
+}
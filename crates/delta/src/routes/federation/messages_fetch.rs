@@ -0,0 +1,31 @@
+use revolt_database::{parse_remote_user, Database};
+use revolt_models::v0::FederatedMessage;
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Federated Messages
+///
+/// Fetch the latest messages exchanged with a user on another instance.
+#[openapi(tag = "Federation")]
+#[get("/dms/<target>?<limit>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: String,
+    limit: Option<i64>,
+) -> Result<Json<Vec<FederatedMessage>>> {
+    let (remote_id, domain) = parse_remote_user(&target).ok_or(Error::InvalidProperty)?;
+
+    Ok(Json(
+        db.fetch_federated_messages(
+            &user.id,
+            &format!("{remote_id}@{}", domain.to_lowercase()),
+            limit.unwrap_or(50).clamp(1, 100),
+        )
+        .await
+        .map_err(Error::from_core)?
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    ))
+}
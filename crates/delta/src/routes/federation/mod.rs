@@ -0,0 +1,20 @@
+use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
+use rocket::Route;
+
+mod inbox;
+mod key;
+mod message_send;
+mod messages_fetch;
+mod user_block;
+mod user_unblock;
+
+pub fn routes() -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![
+        key::req,
+        inbox::req,
+        message_send::req,
+        messages_fetch::req,
+        user_block::req,
+        user_unblock::req,
+    ]
+}
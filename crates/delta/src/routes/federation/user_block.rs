@@ -0,0 +1,23 @@
+use revolt_database::parse_remote_user;
+use revolt_quark::{
+    models::{user::RelationshipStatus, User},
+    Db, EmptyResponse, Error, Result,
+};
+
+/// # Block Federated User
+///
+/// Block a user on another instance, identified as `user_id@domain`.
+#[openapi(tag = "Federation")]
+#[put("/users/<target>/block")]
+pub async fn req(db: &Db, user: User, target: String) -> Result<EmptyResponse> {
+    let (remote_id, domain) = parse_remote_user(&target).ok_or(Error::InvalidProperty)?;
+    let remote_user = format!("{remote_id}@{}", domain.to_lowercase());
+
+    if user.has_blocked(&remote_user) {
+        return Err(Error::NoEffect);
+    }
+
+    db.set_relationship(&user.id, &remote_user, &RelationshipStatus::Blocked)
+        .await
+        .map(|_| EmptyResponse)
+}
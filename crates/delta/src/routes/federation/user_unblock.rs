@@ -0,0 +1,20 @@
+use revolt_database::parse_remote_user;
+use revolt_quark::{models::User, Db, EmptyResponse, Error, Result};
+
+/// # Unblock Federated User
+///
+/// Unblock a user on another instance, identified as `user_id@domain`.
+#[openapi(tag = "Federation")]
+#[delete("/users/<target>/block")]
+pub async fn req(db: &Db, user: User, target: String) -> Result<EmptyResponse> {
+    let (remote_id, domain) = parse_remote_user(&target).ok_or(Error::InvalidProperty)?;
+    let remote_user = format!("{remote_id}@{}", domain.to_lowercase());
+
+    if !user.has_blocked(&remote_user) {
+        return Err(Error::NoEffect);
+    }
+
+    db.pull_relationship(&user.id, &remote_user)
+        .await
+        .map(|_| EmptyResponse)
+}
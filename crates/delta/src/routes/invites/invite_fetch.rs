@@ -1,9 +1,26 @@
-use revolt_quark::models::{Channel, File, Invite};
+use revolt_quark::models::{
+    channel_invite::GuestAccess, server::ServerFlagsValue, Channel, File, Invite,
+};
 use revolt_quark::{Db, Ref, Result};
 
 use rocket::serde::json::Json;
 use serde::Serialize;
 
+/// Maximum number of channels featured in a server invite preview
+const FEATURED_CHANNEL_LIMIT: usize = 3;
+
+/// # Featured Channel
+#[derive(Serialize, Debug, Clone, JsonSchema)]
+pub struct InviteChannel {
+    /// Id of the channel
+    id: String,
+    /// Name of the channel
+    name: String,
+    /// Description of the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
 /// # Invite
 #[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Debug, Clone, JsonSchema)]
@@ -25,7 +42,7 @@ pub enum InviteResponse {
         server_banner: Option<File>,
         /// Enum of server flags
         #[serde(skip_serializing_if = "Option::is_none")]
-        server_flags: Option<i32>,
+        server_flags: Option<ServerFlagsValue>,
         /// Id of server channel
         channel_id: String,
         /// Name of server channel
@@ -40,6 +57,13 @@ pub enum InviteResponse {
         user_avatar: Option<File>,
         /// Number of members in this server
         member_count: i64,
+        /// Number of members in this server who are currently online
+        online_count: i64,
+        /// A few channels which anyone joining can see
+        featured_channels: Vec<InviteChannel>,
+        /// Guest access offered by this invite
+        #[serde(skip_serializing_if = "Option::is_none")]
+        guest: Option<GuestAccess>,
     },
     /// Group channel invite
     Group {
@@ -68,7 +92,10 @@ pub enum InviteResponse {
 pub async fn req(db: &Db, target: Ref) -> Result<Json<InviteResponse>> {
     Ok(Json(match target.as_invite(db).await? {
         Invite::Server {
-            channel, creator, ..
+            channel,
+            creator,
+            guest,
+            ..
         } => {
             let channel = db.fetch_channel(&channel).await?;
             let user = db.fetch_user(&creator).await?;
@@ -90,9 +117,43 @@ pub async fn req(db: &Db, target: Ref) -> Result<Json<InviteResponse>> {
                 } => {
                     let server = db.fetch_server(&server).await?;
 
+                    // Feature public channels in the order they appear in the server
+                    let mut channels = db.fetch_channels(&server.channels).await?;
+                    channels.sort_by_key(|channel| {
+                        server.channels.iter().position(|id| id == channel.id())
+                    });
+
+                    let featured_channels = channels
+                        .into_iter()
+                        .filter(|channel| server.is_public_channel(channel))
+                        .filter_map(|channel| match channel {
+                            Channel::TextChannel {
+                                id,
+                                name,
+                                description,
+                                ..
+                            }
+                            | Channel::VoiceChannel {
+                                id,
+                                name,
+                                description,
+                                ..
+                            } => Some(InviteChannel {
+                                id,
+                                name,
+                                description,
+                            }),
+                            _ => None,
+                        })
+                        .take(FEATURED_CHANNEL_LIMIT)
+                        .collect();
+
                     InviteResponse::Server {
                         code: target.id,
                         member_count: db.fetch_member_count(&server.id).await? as i64,
+                        online_count: server.fetch_online_count(db).await? as i64,
+                        featured_channels,
+                        guest,
                         server_id: server.id,
                         server_name: server.name,
                         server_icon: server.icon,
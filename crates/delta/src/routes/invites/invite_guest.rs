@@ -0,0 +1,63 @@
+use crate::util::{challenges::verify_captcha, regex::RE_USERNAME};
+use revolt_quark::{
+    authifier::Authifier, models::User, variables::delta::USE_HCAPTCHA, Db, Error, Ref, Result,
+};
+
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Guest Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataJoinAsGuest {
+    /// Username to use for the guest account
+    #[validate(length(min = 2, max = 32), regex = "RE_USERNAME")]
+    username: String,
+    /// hCaptcha response, required if the instance has captcha configured
+    captcha: Option<String>,
+}
+
+/// # Guest Session
+#[derive(Serialize, JsonSchema)]
+pub struct GuestSession {
+    /// Session token to authenticate as the guest with
+    token: String,
+    /// Guest user
+    user: User,
+}
+
+/// # Join as Guest
+///
+/// Create a temporary guest account using a guest invite.
+///
+/// Guests can only access the channel the invite points to and are removed once they expire.
+/// A captcha must be solved if the instance has one configured.
+#[openapi(tag = "Invites")]
+#[post("/<target>/guest", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    authifier: &State<Authifier>,
+    target: Ref,
+    data: Json<DataJoinAsGuest>,
+) -> Result<Json<GuestSession>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if *USE_HCAPTCHA {
+        let captcha = data.captcha.as_deref().ok_or(Error::CaptchaFailed)?;
+        if !verify_captcha(captcha).await? {
+            return Err(Error::CaptchaFailed);
+        }
+    }
+
+    let invite = target.as_invite(db).await?;
+    let (user, session) = invite
+        .create_guest_user(db, authifier, data.username)
+        .await?;
+
+    Ok(Json(GuestSession {
+        token: session.token,
+        user,
+    }))
+}
@@ -1,12 +1,20 @@
 use revolt_quark::{
+    authifier::Authifier,
     models::{Channel, Invite, Server, User},
     variables::delta::MAX_SERVER_COUNT,
     Db, Error, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use revolt_database::Database;
+use revolt_models::v0::ChallengeReason;
+use rocket::{serde::json::Json, State};
 use serde::Serialize;
 
+use crate::util::{
+    challenges::{self, ChallengeSolution},
+    policies, verification,
+};
+
 /// # Join Response
 #[derive(Serialize, JsonSchema)]
 #[serde(tag = "type")]
@@ -24,22 +32,38 @@ pub enum InviteJoinResponse {
 /// Join an invite by its ID.
 #[openapi(tag = "Invites")]
 #[post("/<target>")]
-pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<InviteJoinResponse>> {
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    authifier: &State<Authifier>,
+    user: User,
+    target: Ref,
+    solution: ChallengeSolution,
+) -> Result<Json<InviteJoinResponse>> {
     if user.bot.is_some() {
         return Err(Error::IsBot);
     }
 
-    if !user.can_acquire_server(db).await? {
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
+    policies::assert_accepted(db, &user).await?;
+
+    if !user.can_acquire_server(legacy_db).await? {
         return Err(Error::TooManyServers {
             max: *MAX_SERVER_COUNT,
         });
     }
 
-    let invite = target.as_invite(db).await?;
+    verification::assert_can_join_server(authifier, legacy_db, &user).await?;
+    challenges::assert_passed(&user, ChallengeReason::RapidJoins, &solution).await?;
+
+    let invite = target.as_invite(legacy_db).await?;
     match &invite {
         Invite::Server { server, .. } => {
-            let server = db.fetch_server(server).await?;
-            let channels = server.create_member(db, user, None).await?;
+            let server = legacy_db.fetch_server(server).await?;
+            let channels = server.create_member(legacy_db, user, None).await?;
             Ok(Json(InviteJoinResponse::Server { channels, server }))
         }
         _ => unreachable!(),
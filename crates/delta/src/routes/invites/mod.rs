@@ -3,8 +3,14 @@ use rocket::Route;
 
 mod invite_delete;
 mod invite_fetch;
+mod invite_guest;
 mod invite_join;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![invite_fetch::req, invite_join::req, invite_delete::req]
+    openapi_get_routes_spec![
+        invite_fetch::req,
+        invite_join::req,
+        invite_guest::req,
+        invite_delete::req
+    ]
 }
@@ -7,8 +7,11 @@ mod admin;
 mod bots;
 mod channels;
 mod customisation;
+mod events;
+mod federation;
 mod invites;
 mod onboard;
+mod policies;
 mod push;
 mod root;
 mod safety;
@@ -31,14 +34,17 @@ pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
         "/servers" => servers::routes(),
         "/invites" => invites::routes(),
         "/custom" => customisation::routes(),
+        "/events" => events::routes(),
         "/safety" => safety::routes(),
         "/auth/account" => rocket_authifier::routes::account::routes(),
         "/auth/session" => rocket_authifier::routes::session::routes(),
         "/auth/mfa" => rocket_authifier::routes::mfa::routes(),
         "/onboard" => onboard::routes(),
+        "/policies" => policies::routes(),
         "/push" => push::routes(),
         "/sync" => sync::routes(),
-        "/webhooks" => webhooks::routes()
+        "/webhooks" => webhooks::routes(),
+        "/federation" => federation::routes()
     };
 
     rocket
@@ -70,7 +76,8 @@ fn custom_openapi_spec() -> OpenApi {
             "tags": [
               "User Information",
               "Direct Messaging",
-              "Relationships"
+              "Relationships",
+              "Federation"
             ]
           },
           {
@@ -86,6 +93,7 @@ fn custom_openapi_spec() -> OpenApi {
               "Channel Invites",
               "Channel Permissions",
               "Messaging",
+              "Threads",
               "Interactions",
               "Groups",
               "Voice",
@@ -125,6 +133,7 @@ fn custom_openapi_spec() -> OpenApi {
               "Account",
               "Session",
               "Onboarding",
+              "Policies",
               "MFA"
             ]
           },
@@ -207,6 +216,11 @@ fn custom_openapi_spec() -> OpenApi {
                 ),
                 ..Default::default()
             },
+            Tag {
+                name: "Federation".to_owned(),
+                description: Some("Direct message users on other Revolt instances".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Bots".to_owned(),
                 description: Some("Create and edit bots".to_owned()),
@@ -232,6 +246,11 @@ fn custom_openapi_spec() -> OpenApi {
                 description: Some("Send and manipulate messages".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Threads".to_owned(),
+                description: Some("Start and browse message threads".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Groups".to_owned(),
                 description: Some("Create, invite users and manipulate groups".to_owned()),
@@ -284,6 +303,13 @@ fn custom_openapi_spec() -> OpenApi {
                 ),
                 ..Default::default()
             },
+            Tag {
+                name: "Policies".to_owned(),
+                description: Some(
+                    "Terms of service and other policies users must accept".to_owned(),
+                ),
+                ..Default::default()
+            },
             Tag {
                 name: "Sync".to_owned(),
                 description: Some("Upload and retrieve any JSON data between clients".to_owned()),
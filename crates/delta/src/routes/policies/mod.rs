@@ -0,0 +1,10 @@
+use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
+use rocket::Route;
+
+mod policy_accept;
+mod policy_fetch;
+mod policy_fetch_all;
+
+pub fn routes() -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![policy_fetch_all::req, policy_fetch::req, policy_accept::req]
+}
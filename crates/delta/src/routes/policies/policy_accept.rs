@@ -0,0 +1,48 @@
+use revolt_database::{Database, PolicyAcceptance};
+use revolt_models::v0::DataAcceptPolicy;
+use revolt_quark::{models::User, EmptyResponse, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Accept Policy
+///
+/// Accept the current version of a policy document.
+///
+/// The version must match the current version, so that users only accept what they were shown.
+#[openapi(tag = "Policies")]
+#[put("/<policy_id>/accept", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    policy_id: String,
+    data: Json<DataAcceptPolicy>,
+) -> Result<EmptyResponse> {
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let document = db
+        .fetch_policy_document(&policy_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if data.version != document.version {
+        return Err(Error::PolicyNotAccepted {
+            policy: document.id,
+            version: document.version,
+        });
+    }
+
+    let mut acceptance = PolicyAcceptance::fetch(db, &user.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if acceptance.has_accepted(&document) {
+        return Ok(EmptyResponse);
+    }
+
+    acceptance
+        .accept(db, &document)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
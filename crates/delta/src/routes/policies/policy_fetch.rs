@@ -0,0 +1,16 @@
+use revolt_database::Database;
+use revolt_models::v0::PolicyDocument;
+use revolt_quark::{Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Policy
+///
+/// Fetch the current version of a policy document.
+#[openapi(tag = "Policies")]
+#[get("/<policy_id>")]
+pub async fn req(db: &State<Database>, policy_id: String) -> Result<Json<PolicyDocument>> {
+    db.fetch_policy_document(&policy_id)
+        .await
+        .map(|document| Json(document.into()))
+        .map_err(Error::from_core)
+}
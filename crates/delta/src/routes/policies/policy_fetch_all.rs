@@ -0,0 +1,23 @@
+use revolt_database::Database;
+use revolt_models::v0::PolicyDocument;
+use revolt_quark::{Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Policies
+///
+/// Fetch every policy document users must accept to use this instance.
+#[openapi(tag = "Policies")]
+#[get("/")]
+pub async fn req(db: &State<Database>) -> Result<Json<Vec<PolicyDocument>>> {
+    db.fetch_policy_documents()
+        .await
+        .map(|documents| {
+            Json(
+                documents
+                    .into_iter()
+                    .map(|document| document.into())
+                    .collect(),
+            )
+        })
+        .map_err(Error::from_core)
+}
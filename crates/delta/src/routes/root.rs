@@ -1,13 +1,21 @@
+use std::collections::HashMap;
+
+use revolt_database::Database;
 use revolt_quark::variables::delta::{
-    APP_URL, AUTUMN_URL, EXTERNAL_WS_URL, HCAPTCHA_SITEKEY, INVITE_ONLY, JANUARY_URL, USE_AUTUMN,
-    USE_EMAIL, USE_HCAPTCHA, USE_JANUARY, USE_VOSO, VAPID_PUBLIC_KEY, VOSO_URL, VOSO_WS_HOST,
+    APP_URL, AUTUMN_URL, EXTERNAL_WS_URL, HCAPTCHA_SITEKEY, INVITE_ONLY, JANUARY_URL,
+    MESSAGE_EFFECTS, MIN_ACCOUNT_AGE, REQUIRE_VERIFIED_EMAIL, STORAGE_REGIONS,
+    UNVERIFIED_MAX_SERVER_COUNT, USE_AUTUMN, USE_EMAIL, USE_HCAPTCHA, USE_JANUARY, USE_VOSO,
+    VAPID_PUBLIC_KEY, VOSO_URL, VOSO_WS_HOST,
 };
 use revolt_quark::Result;
 
 use rocket::http::Status;
 use rocket::serde::json::Json;
+use rocket::State;
 use serde::Serialize;
 
+use crate::util::features;
+
 /// # hCaptcha Configuration
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct CaptchaFeature {
@@ -26,6 +34,18 @@ pub struct Feature {
     pub url: String,
 }
 
+/// # File Server Configuration
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct FileServerFeature {
+    /// Whether the service is enabled
+    pub enabled: bool,
+    /// URL pointing to the service
+    pub url: String,
+    /// URLs of the service for each storage region servers can be pinned to
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub regions: HashMap<String, String>,
+}
+
 /// # Voice Server Configuration
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct VoiceFeature {
@@ -37,6 +57,17 @@ pub struct VoiceFeature {
     pub ws: String,
 }
 
+/// # Verification Requirements
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct VerificationFeature {
+    /// Whether a verified email is required to send DMs or join more servers
+    pub email: bool,
+    /// Minimum account age in seconds to send DMs or join more servers
+    pub account_age: i64,
+    /// Number of servers an unverified account may be in
+    pub max_servers: usize,
+}
+
 /// # Feature Configuration
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct RevoltFeatures {
@@ -46,12 +77,18 @@ pub struct RevoltFeatures {
     pub email: bool,
     /// Whether this server is invite only
     pub invite_only: bool,
+    /// Requirements accounts must meet to send DMs or join more servers
+    pub verification: VerificationFeature,
     /// File server service configuration
-    pub autumn: Feature,
+    pub autumn: FileServerFeature,
     /// Proxy service configuration
     pub january: Feature,
     /// Voice server configuration
     pub voso: VoiceFeature,
+    /// Experimental features enabled across this instance
+    pub flags: Vec<String>,
+    /// Message effects clients may send
+    pub effects: Vec<String>,
 }
 
 /// # Build Information
@@ -91,7 +128,14 @@ pub struct RevoltConfig {
 /// Fetch the server configuration for this Revolt instance.
 #[openapi(tag = "Core")]
 #[get("/")]
-pub async fn root() -> Result<Json<RevoltConfig>> {
+pub async fn root(db: &State<Database>) -> Result<Json<RevoltConfig>> {
+    let flags = features::fetch_all(db)
+        .await?
+        .into_iter()
+        .filter(|flag| flag.enabled)
+        .map(|flag| flag.id)
+        .collect();
+
     Ok(Json(RevoltConfig {
         revolt: env!("CARGO_PKG_VERSION").to_string(),
         features: RevoltFeatures {
@@ -101,9 +145,15 @@ pub async fn root() -> Result<Json<RevoltConfig>> {
             },
             email: *USE_EMAIL,
             invite_only: *INVITE_ONLY,
-            autumn: Feature {
+            verification: VerificationFeature {
+                email: *REQUIRE_VERIFIED_EMAIL,
+                account_age: *MIN_ACCOUNT_AGE,
+                max_servers: *UNVERIFIED_MAX_SERVER_COUNT,
+            },
+            autumn: FileServerFeature {
                 enabled: *USE_AUTUMN,
                 url: AUTUMN_URL.to_string(),
+                regions: STORAGE_REGIONS.clone(),
             },
             january: Feature {
                 enabled: *USE_JANUARY,
@@ -114,6 +164,8 @@ pub async fn root() -> Result<Json<RevoltConfig>> {
                 url: VOSO_URL.to_string(),
                 ws: VOSO_WS_HOST.to_string(),
             },
+            flags,
+            effects: MESSAGE_EFFECTS.clone(),
         },
         ws: EXTERNAL_WS_URL.to_string(),
         app: APP_URL.to_string(),
@@ -1,4 +1,4 @@
-use revolt_database::{AccountStrike, Database};
+use revolt_database::{AccountStrike, AdminAuditEntry, Database};
 use revolt_models::v0::{AccountStrike as AccountStrikeModel, DataCreateStrike};
 use revolt_quark::models::User;
 use revolt_quark::{Error, Result};
@@ -26,9 +26,20 @@ pub async fn create_strike(
         .await
         .map_err(Error::from_core)?;
 
-    AccountStrike::create(db, target.id, data.reason, user.id)
+    let strike = AccountStrike::create(db, target.id, data.reason, user.id.clone())
         .await
-        .map(|strike| strike.into())
-        .map(Json)
-        .map_err(Error::from_core)
+        .map_err(Error::from_core)?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "strike.create",
+        &strike.id,
+        None,
+        Some(&strike),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    Ok(Json(strike.into()))
 }
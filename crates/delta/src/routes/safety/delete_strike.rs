@@ -1,4 +1,4 @@
-use revolt_database::Database;
+use revolt_database::{AdminAuditEntry, Database};
 use revolt_quark::models::User;
 use revolt_quark::{Error, Result};
 use rocket::State;
@@ -19,5 +19,16 @@ pub async fn delete_strike(db: &State<Database>, user: User, strike_id: String)
         .await
         .map_err(Error::from_core)?;
 
-    strike.delete(db).await.map_err(Error::from_core)
+    strike.delete(db).await.map_err(Error::from_core)?;
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "strike.delete",
+        &strike.id,
+        Some(&strike),
+        None,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from_core)
 }
@@ -0,0 +1,26 @@
+use revolt_quark::models::snapshot::SnapshotDiff;
+use revolt_quark::models::User;
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+
+/// # Diff Snapshots
+///
+/// Compare two snapshots of the same object.
+#[openapi(tag = "User Safety")]
+#[get("/snapshot/<before>/diff/<after>")]
+pub async fn diff_snapshots(
+    db: &Db,
+    user: User,
+    before: String,
+    after: String,
+) -> Result<Json<SnapshotDiff>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let before = db.fetch_snapshot(&before).await?;
+    let after = db.fetch_snapshot(&after).await?;
+
+    before.diff(&after).map(Json)
+}
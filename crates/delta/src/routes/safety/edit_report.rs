@@ -1,3 +1,4 @@
+use revolt_database::{AdminAuditEntry, Database};
 use revolt_quark::{
     models::{
         report::{PartialReport, ReportStatus},
@@ -5,7 +6,7 @@ use revolt_quark::{
     },
     Db, Error, Ref, Result,
 };
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::Deserialize;
 use validator::Validate;
 
@@ -24,7 +25,8 @@ pub struct DataEditReport {
 #[openapi(tag = "User Safety")]
 #[patch("/reports/<report>", data = "<edit>")]
 pub async fn edit_report(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     report: Ref,
     edit: Json<DataEditReport>,
@@ -40,10 +42,11 @@ pub async fn edit_report(
         .map_err(|error| Error::FailedValidation { error })?;
 
     // Create and apply update to report
-    let mut report = report.as_report(db).await?;
+    let mut report = report.as_report(legacy_db).await?;
+    let before = report.clone();
     report
         .update(
-            db,
+            legacy_db,
             PartialReport {
                 status: edit.status,
                 notes: edit.notes,
@@ -52,5 +55,16 @@ pub async fn edit_report(
         )
         .await?;
 
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "report.edit",
+        &report.id,
+        Some(&before),
+        Some(&report),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
     Ok(Json(report))
 }
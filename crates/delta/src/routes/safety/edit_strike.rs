@@ -1,4 +1,4 @@
-use revolt_database::{Database, PartialAccountStrike};
+use revolt_database::{AdminAuditEntry, Database, PartialAccountStrike};
 use revolt_models::v0::DataEditAccountStrike;
 use revolt_quark::models::User;
 use revolt_quark::{Error, Result};
@@ -26,6 +26,7 @@ pub async fn edit_strike(
         .await
         .map_err(Error::from_core)?;
 
+    let before = strike.clone();
     strike
         .update(
             db,
@@ -35,5 +36,17 @@ pub async fn edit_strike(
             },
         )
         .await
-        .map_err(Error::from_core)
+        .map_err(Error::from_core)?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "strike.edit",
+        &strike.id,
+        Some(&before),
+        Some(&strike),
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from_core)
 }
@@ -0,0 +1,21 @@
+use revolt_quark::models::{Snapshot, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+
+/// # Fetch Object Snapshots
+///
+/// Fetch all snapshots taken of a given server or user, oldest first.
+#[openapi(tag = "User Safety")]
+#[get("/monitor/<id>/snapshots")]
+pub async fn fetch_monitor_snapshots(
+    db: &Db,
+    user: User,
+    id: String,
+) -> Result<Json<Vec<Snapshot>>> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    db.fetch_snapshots_by_object(&id).await.map(Json)
+}
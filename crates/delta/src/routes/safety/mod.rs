@@ -6,7 +6,12 @@ mod fetch_report;
 mod fetch_reports;
 mod report_content;
 
+mod diff_snapshots;
+mod fetch_monitor_snapshots;
 mod fetch_snapshots;
+mod monitor_server;
+mod restore_snapshot;
+mod unmonitor_server;
 
 mod create_strike;
 mod delete_strike;
@@ -22,6 +27,12 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         report_content::report_content,
         // Snapshots
         fetch_snapshots::fetch_snapshots,
+        diff_snapshots::diff_snapshots,
+        restore_snapshot::restore_snapshot,
+        // Monitoring
+        monitor_server::monitor_server,
+        unmonitor_server::unmonitor_server,
+        fetch_monitor_snapshots::fetch_monitor_snapshots,
         // Strikes
         create_strike::create_strike,
         fetch_strikes::fetch_strikes,
@@ -0,0 +1,36 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::models::User;
+use revolt_quark::{Db, EmptyResponse, Error, Ref, Result};
+use rocket::State;
+
+/// # Monitor Server
+///
+/// Start taking periodic snapshots of a server.
+#[openapi(tag = "User Safety")]
+#[put("/monitor/<server>")]
+pub async fn monitor_server(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    server: Ref,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let server = server.as_server(legacy_db).await?;
+    legacy_db.set_server_monitored(&server.id, true).await?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "server.monitor",
+        &server.id,
+        Some(&false),
+        Some(&true),
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
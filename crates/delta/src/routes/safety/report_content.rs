@@ -103,7 +103,7 @@ pub async fn report_content(db: &Db, user: User, data: Json<DataReportContent>)
         // Save a snapshot of the content
         let snapshot = Snapshot {
             id: Ulid::new().to_string(),
-            report_id: id.to_string(),
+            report_id: Some(id.to_string()),
             content,
         };
 
@@ -0,0 +1,38 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::models::User;
+use revolt_quark::{Db, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Restore Snapshot
+///
+/// Restore a server or user to the state captured in a snapshot.
+///
+/// Channels deleted since the snapshot was taken cannot be recovered.
+#[openapi(tag = "User Safety")]
+#[post("/snapshot/<id>/restore")]
+pub async fn restore_snapshot(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    id: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let snapshot = legacy_db.fetch_snapshot(&id).await?;
+    snapshot.restore(legacy_db, &user).await?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "snapshot.restore",
+        &snapshot.id,
+        None,
+        Some(&snapshot.content),
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
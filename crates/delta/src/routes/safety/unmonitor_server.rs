@@ -0,0 +1,37 @@
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::models::User;
+use revolt_quark::{Db, EmptyResponse, Error, Result};
+use rocket::State;
+
+/// # Stop Monitoring Server
+///
+/// Stop taking periodic snapshots of a server.
+///
+/// Existing snapshots are kept until they expire.
+#[openapi(tag = "User Safety")]
+#[delete("/monitor/<server>")]
+pub async fn unmonitor_server(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    server: String,
+) -> Result<EmptyResponse> {
+    // Must be privileged for this route
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    legacy_db.set_server_monitored(&server, false).await?;
+
+    AdminAuditEntry::record(
+        db,
+        &user.id,
+        "server.unmonitor",
+        &server,
+        Some(&true),
+        Some(&false),
+    )
+    .await
+    .map(|_| EmptyResponse)
+    .map_err(Error::from_core)
+}
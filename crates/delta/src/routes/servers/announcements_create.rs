@@ -0,0 +1,74 @@
+use revolt_database::{Database, ServerAnnouncement};
+use revolt_models::v0::DataCreateServerAnnouncement;
+use revolt_quark::{
+    models::{Channel, User},
+    perms,
+    variables::delta::MAX_SCHEDULE_COUNT,
+    Db, Error, Permission, Ref, Result, Timestamp,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Schedule Announcement
+///
+/// Schedule an announcement to be posted in one of this server's channels, optionally repeating.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/announcements", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateServerAnnouncement>,
+) -> Result<Json<revolt_models::v0::ServerAnnouncement>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if *data.run_at <= *Timestamp::now_utc() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let channel = Ref::from_unchecked(data.channel_id)
+        .as_channel(legacy_db)
+        .await?;
+
+    match &channel {
+        Channel::TextChannel { server: id, .. } if id == &server.id => {}
+        _ => return Err(Error::InvalidOperation),
+    }
+
+    perms(&user)
+        .channel(&channel)
+        .throw_permission_and_view_channel(legacy_db, Permission::SendMessage)
+        .await?;
+
+    let announcements = db
+        .fetch_server_announcements(&server.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if announcements.len() >= *MAX_SCHEDULE_COUNT {
+        return Err(Error::InvalidOperation);
+    }
+
+    let announcement = ServerAnnouncement {
+        id: Ulid::new().to_string(),
+        server_id: server.id,
+        channel_id: channel.id().to_string(),
+        creator_id: user.id,
+        content: data.content,
+        next_run: data.run_at,
+        recurrence: data.recurrence.map(|recurrence| recurrence.into()),
+    };
+
+    announcement.create(db).await.map_err(Error::from_core)?;
+    Ok(Json(announcement.into()))
+}
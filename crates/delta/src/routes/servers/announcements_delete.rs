@@ -0,0 +1,37 @@
+use revolt_database::{util::reference::Reference, Database};
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Cancel Scheduled Announcement
+///
+/// Cancel a scheduled announcement, including any future repeats.
+#[openapi(tag = "Server Information")]
+#[delete("/<target>/announcements/<announcement_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    announcement_id: Reference,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let announcement = db
+        .fetch_server_announcement(&announcement_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if announcement.server_id != server.id {
+        return Err(Error::NotFound);
+    }
+
+    announcement
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
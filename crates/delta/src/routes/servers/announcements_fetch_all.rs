@@ -0,0 +1,30 @@
+use revolt_database::Database;
+use revolt_models::v0::ServerAnnouncement;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Scheduled Announcements
+///
+/// Fetch all scheduled announcements in this server, soonest first.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/announcements")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<ServerAnnouncement>>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let mut announcements = db
+        .fetch_server_announcements(&server.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    announcements.sort_by(|a, b| a.next_run.cmp(&b.next_run));
+    Ok(Json(announcements.into_iter().map(|v| v.into()).collect()))
+}
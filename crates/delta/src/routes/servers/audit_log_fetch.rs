@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use revolt_database::{AuditLogAction, AuditLogQuery, Database};
+use revolt_models::v0::AuditLogEntry;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchAuditLog {
+    /// Only include changes made by this user
+    #[validate(length(min = 26, max = 26))]
+    actor: Option<String>,
+    /// Only include this kind of change, such as `MemberBan`
+    action: Option<String>,
+    /// Only include changes made at or after this time (ISO 8601)
+    after: Option<String>,
+    /// Only include changes made before this time (ISO 8601)
+    before: Option<String>,
+    /// Maximum number of entries to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+}
+
+/// Find the lowest possible entry id created at a given time
+fn entry_id_at(value: &str) -> Result<String> {
+    let time = DateTime::parse_from_rfc3339(value)
+        .map_err(|_| Error::InvalidProperty)?
+        .with_timezone(&Utc);
+
+    Ok(Ulid::from_parts(time.timestamp_millis().max(0) as u64, 0).to_string())
+}
+
+/// # Fetch Audit Log
+///
+/// Fetch moderation-relevant changes made to this server, newest first.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/audit_log?<options..>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    options: OptionsFetchAuditLog,
+) -> Result<Json<Vec<AuditLogEntry>>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let action = options
+        .action
+        .map(|action| {
+            serde_json::from_value::<AuditLogAction>(serde_json::Value::String(action))
+                .map_err(|_| Error::InvalidProperty)
+        })
+        .transpose()?;
+
+    let query = AuditLogQuery {
+        actor_id: options.actor,
+        action,
+        before: options.before.as_deref().map(entry_id_at).transpose()?,
+        after: options.after.as_deref().map(entry_id_at).transpose()?,
+        limit: options.limit.unwrap_or(50),
+    };
+
+    db.fetch_audit_log_entries(&server.id, &query)
+        .await
+        .map(|entries| Json(entries.into_iter().map(|entry| entry.into()).collect()))
+        .map_err(Error::from_core)
+}
@@ -1,12 +1,15 @@
+use revolt_database::{AuditLogAction, Database, ModerationAction, ModerationCase};
 use revolt_quark::{
     models::{server_member::MemberCompositeKey, ServerBan, User},
     perms, Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::util::{audit_log, ban_sync};
+
 /// # Ban Information
 #[derive(Validate, Serialize, Deserialize, JsonSchema)]
 pub struct DataBanCreate {
@@ -18,10 +21,13 @@ pub struct DataBanCreate {
 /// # Ban User
 ///
 /// Ban a user by their id.
+///
+/// The ban is shared with any ban sync groups this server is part of.
 #[openapi(tag = "Server Members")]
 #[put("/<server>/bans/<target>", data = "<data>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     server: Ref,
     target: Ref,
@@ -31,7 +37,7 @@ pub async fn req(
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    let server = server.as_server(db).await?;
+    let server = server.as_server(legacy_db).await?;
 
     if target.id == user.id {
         return Err(Error::CannotRemoveYourself);
@@ -44,30 +50,61 @@ pub async fn req(
     let mut permissions = perms(&user).server(&server);
 
     permissions
-        .throw_permission(db, Permission::BanMembers)
+        .throw_permission(legacy_db, Permission::BanMembers)
         .await?;
 
     // If member exists, check privileges against them
-    if let Ok(member) = target.as_member(db, &server.id).await {
+    let ban = if let Ok(member) = target.as_member(legacy_db, &server.id).await {
         if member.get_ranking(permissions.server.get().unwrap())
             <= permissions.get_member_rank().unwrap_or(i64::MIN)
         {
             return Err(Error::NotElevated);
         }
 
-        server.ban_member(db, member, data.reason).await.map(Json)
+        server.ban_member(legacy_db, member, data.reason).await?
     } else {
         let server_id = server.id.to_string();
         server
             .ban_user(
-                db,
+                legacy_db,
                 MemberCompositeKey {
                     server: server_id,
                     user: target.id,
                 },
                 data.reason,
             )
-            .await
-            .map(Json)
+            .await?
+    };
+
+    // Record the ban in the user's moderation history
+    if let Err(err) = ModerationCase::create(
+        db,
+        &ban.id.server,
+        &ban.id.user,
+        &user.id,
+        ModerationAction::Ban,
+        ban.reason.clone(),
+    )
+    .await
+    {
+        log::warn!("Failed to record ban in server {}: {err:?}", ban.id.server);
     }
+
+    audit_log::record(
+        db,
+        &ban.id.server,
+        &user.id,
+        AuditLogAction::MemberBan,
+        &ban.id.user,
+        None,
+        Some(&ban),
+    )
+    .await;
+
+    // Share the ban with allied servers
+    if let Err(err) = ban_sync::propagate(db, legacy_db, &ban).await {
+        log::warn!("Failed to share ban in server {}: {err:?}", ban.id.server);
+    }
+
+    Ok(Json(ban))
 }
@@ -0,0 +1,41 @@
+use revolt_quark::{models::User, perms, Db, Permission, Ref, Result};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Exported Ban
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Clone)]
+pub struct BanEntry {
+    /// Id of the banned user
+    #[serde(rename = "_id")]
+    #[validate(length(equal = 26))]
+    pub id: String,
+    /// Reason for ban creation
+    #[validate(length(min = 1, max = 1024))]
+    pub reason: Option<String>,
+}
+
+/// # Export Bans
+///
+/// Export the ban list of a server so it can be imported elsewhere.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/bans/export")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<BanEntry>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::BanMembers)
+        .await?;
+
+    Ok(Json(
+        db.fetch_bans(&server.id)
+            .await?
+            .into_iter()
+            .map(|ban| BanEntry {
+                id: ban.id.user,
+                reason: ban.reason,
+            })
+            .collect(),
+    ))
+}
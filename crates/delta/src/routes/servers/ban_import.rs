@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use revolt_quark::{
+    models::{server_member::MemberCompositeKey, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::ban_export::BanEntry;
+
+/// # Ban Import Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataImportBans {
+    /// Bans to import, as produced by the export endpoint
+    #[validate(length(min = 1, max = 1000))]
+    #[validate]
+    bans: Vec<BanEntry>,
+    /// Only report what would be imported without banning anyone
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// # Ban Import Result
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct BanImportResult {
+    /// Ids of users who were (or would be) banned
+    banned: Vec<String>,
+    /// Ids of users who were skipped, either already banned or not bannable by you
+    skipped: Vec<String>,
+}
+
+/// # Import Bans
+///
+/// Import a ban list exported from another server.
+///
+/// Users who are already banned, repeated entries and members you cannot ban are skipped.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/bans/import", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataImportBans>,
+) -> Result<Json<BanImportResult>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+
+    permissions
+        .throw_permission(db, Permission::BanMembers)
+        .await?;
+
+    let member_rank = permissions.get_member_rank().unwrap_or(i64::MIN);
+
+    let mut seen: HashSet<String> = db
+        .fetch_bans(&server.id)
+        .await?
+        .into_iter()
+        .map(|ban| ban.id.user)
+        .collect();
+
+    let mut banned = vec![];
+    let mut skipped = vec![];
+
+    for entry in data.bans {
+        if entry.id == user.id || entry.id == server.owner || !seen.insert(entry.id.clone()) {
+            skipped.push(entry.id);
+            continue;
+        }
+
+        // If member exists, check privileges against them
+        let member = db.fetch_member(&server.id, &entry.id).await.ok();
+        if let Some(member) = &member {
+            if member.get_ranking(&server) <= member_rank {
+                skipped.push(entry.id);
+                continue;
+            }
+        }
+
+        if !data.dry_run {
+            if let Some(member) = member {
+                server.clone().ban_member(db, member, entry.reason).await?;
+            } else {
+                server
+                    .clone()
+                    .ban_user(
+                        db,
+                        MemberCompositeKey {
+                            server: server.id.clone(),
+                            user: entry.id.clone(),
+                        },
+                        entry.reason,
+                    )
+                    .await?;
+            }
+        }
+
+        banned.push(entry.id);
+    }
+
+    Ok(Json(BanImportResult { banned, skipped }))
+}
@@ -1,17 +1,40 @@
+use revolt_database::{AuditLogAction, Database};
 use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+use rocket::State;
+
+use crate::util::audit_log;
 
 /// # Unban user
 ///
 /// Remove a user's ban.
 #[openapi(tag = "Server Members")]
 #[delete("/<server>/bans/<target>")]
-pub async fn req(db: &Db, user: User, server: Ref, target: Ref) -> Result<EmptyResponse> {
-    let server = server.as_server(db).await?;
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    server: Ref,
+    target: Ref,
+) -> Result<EmptyResponse> {
+    let server = server.as_server(legacy_db).await?;
     perms(&user)
         .server(&server)
-        .throw_permission(db, Permission::BanMembers)
+        .throw_permission(legacy_db, Permission::BanMembers)
         .await?;
 
-    let ban = target.as_ban(db, &server.id).await?;
-    db.delete_ban(&ban.id).await.map(|_| EmptyResponse)
+    let ban = target.as_ban(legacy_db, &server.id).await?;
+    legacy_db.delete_ban(&ban.id).await?;
+
+    audit_log::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberUnban,
+        &ban.id.user,
+        Some(&ban),
+        None,
+    )
+    .await;
+
+    Ok(EmptyResponse)
 }
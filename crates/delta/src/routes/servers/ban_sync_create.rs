@@ -0,0 +1,58 @@
+use revolt_database::{BanSyncGroup, Database};
+use revolt_models::v0::DataCreateBanSyncGroup;
+use revolt_quark::{
+    models::User, perms, variables::delta::MAX_BAN_SYNC_GROUP_COUNT, Db, Error, Permission, Ref,
+    Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Ban Sync Group
+///
+/// Create a new ban sync group with this server as its first member.
+///
+/// Other servers can join using the group's join code.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/ban_sync", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateBanSyncGroup>,
+) -> Result<Json<revolt_models::v0::BanSyncGroup>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let groups = db
+        .fetch_server_ban_sync_groups(&server.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if groups.len() >= *MAX_BAN_SYNC_GROUP_COUNT {
+        return Err(Error::InvalidOperation);
+    }
+
+    let mut group = BanSyncGroup {
+        id: Ulid::new().to_string(),
+        name: data.name,
+        join_code: nanoid::nanoid!(24),
+        members: vec![],
+    };
+
+    group.create(db).await.map_err(Error::from_core)?;
+    group
+        .join(db, &server.id, data.policy.into())
+        .await
+        .map_err(Error::from_core)?;
+
+    Ok(Json(group.into()))
+}
@@ -0,0 +1,27 @@
+use revolt_database::Database;
+use revolt_models::v0::BanSyncGroup;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Ban Sync Groups
+///
+/// Fetch the ban sync groups this server is part of.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/ban_sync")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<BanSyncGroup>>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::BanMembers)
+        .await?;
+
+    db.fetch_server_ban_sync_groups(&server.id)
+        .await
+        .map(|groups| Json(groups.into_iter().map(|group| group.into()).collect()))
+        .map_err(Error::from_core)
+}
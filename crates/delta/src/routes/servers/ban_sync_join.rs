@@ -0,0 +1,56 @@
+use revolt_database::Database;
+use revolt_models::v0::{BanSyncGroup, DataJoinBanSyncGroup};
+use revolt_quark::{
+    models::User, perms, variables::delta::MAX_BAN_SYNC_GROUP_COUNT, Db, Error, Permission, Ref,
+    Result,
+};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Join Ban Sync Group
+///
+/// Join a ban sync group using a join code shared by one of its servers.
+///
+/// Joining a group this server is already part of updates its policy.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/ban_sync/join", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataJoinBanSyncGroup>,
+) -> Result<Json<BanSyncGroup>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let mut group = db
+        .fetch_ban_sync_group_by_code(&data.code)
+        .await
+        .map_err(Error::from_core)?;
+
+    if group.member(&server.id).is_none() {
+        let groups = db
+            .fetch_server_ban_sync_groups(&server.id)
+            .await
+            .map_err(Error::from_core)?;
+
+        if groups.len() >= *MAX_BAN_SYNC_GROUP_COUNT {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    group
+        .join(db, &server.id, data.policy.into())
+        .await
+        .map_err(Error::from_core)?;
+
+    Ok(Json(group.into()))
+}
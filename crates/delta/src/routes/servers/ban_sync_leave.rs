@@ -0,0 +1,39 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Leave Ban Sync Group
+///
+/// Stop sharing bans with a ban sync group.
+///
+/// Pending proposals from the group are discarded and the group is deleted once empty.
+#[openapi(tag = "Server Members")]
+#[delete("/<target>/ban_sync/<group_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    group_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageServer)
+        .await?;
+
+    let mut group = db
+        .fetch_ban_sync_group(&group_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if group.member(&server.id).is_none() {
+        return Err(Error::NotFound);
+    }
+
+    group
+        .leave(db, &server.id)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
@@ -0,0 +1,82 @@
+use revolt_database::{Database, ModerationAction, ModerationCase};
+use revolt_quark::{
+    models::{ServerBan, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+
+use crate::util::ban_sync;
+
+/// # Accept Ban Sync Proposal
+///
+/// Apply a ban shared by another server in a ban sync group.
+///
+/// The resulting ban records the server it was shared from.
+#[openapi(tag = "Server Members")]
+#[put("/<target>/ban_sync/proposals/<proposal_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    proposal_id: String,
+) -> Result<Json<ServerBan>> {
+    let server = target.as_server(legacy_db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(legacy_db, Permission::BanMembers)
+        .await?;
+
+    let proposal = db
+        .fetch_ban_sync_proposal(&proposal_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if proposal.server_id != server.id {
+        return Err(Error::NotFound);
+    }
+
+    if proposal.user_id == user.id {
+        return Err(Error::CannotRemoveYourself);
+    }
+
+    if proposal.user_id == server.owner {
+        return Err(Error::InvalidOperation);
+    }
+
+    // If member exists, check privileges against them
+    if let Ok(member) = legacy_db.fetch_member(&server.id, &proposal.user_id).await {
+        if member.get_ranking(permissions.server.get().unwrap())
+            <= permissions.get_member_rank().unwrap_or(i64::MIN)
+        {
+            return Err(Error::NotElevated);
+        }
+    }
+
+    proposal.delete(db).await.map_err(Error::from_core)?;
+
+    let ban = ban_sync::apply(
+        legacy_db,
+        server,
+        &proposal.user_id,
+        proposal.reason,
+        &proposal.source_server_id,
+    )
+    .await?;
+
+    // Record the ban in the user's moderation history
+    if let Err(err) = ModerationCase::create(
+        db,
+        &ban.id.server,
+        &ban.id.user,
+        &user.id,
+        ModerationAction::Ban,
+        ban.reason.clone(),
+    )
+    .await
+    {
+        log::warn!("Failed to record ban in server {}: {err:?}", ban.id.server);
+    }
+
+    Ok(Json(ban))
+}
@@ -0,0 +1,37 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Reject Ban Sync Proposal
+///
+/// Dismiss a ban shared by another server in a ban sync group.
+#[openapi(tag = "Server Members")]
+#[delete("/<target>/ban_sync/proposals/<proposal_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    proposal_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::BanMembers)
+        .await?;
+
+    let proposal = db
+        .fetch_ban_sync_proposal(&proposal_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if proposal.server_id != server.id {
+        return Err(Error::NotFound);
+    }
+
+    proposal
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
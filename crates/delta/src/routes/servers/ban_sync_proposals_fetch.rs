@@ -0,0 +1,34 @@
+use revolt_database::Database;
+use revolt_models::v0::BanSyncProposal;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Ban Sync Proposals
+///
+/// Fetch bans shared by other servers which are awaiting review.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/ban_sync/proposals")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<BanSyncProposal>>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::BanMembers)
+        .await?;
+
+    db.fetch_ban_sync_proposals(&server.id)
+        .await
+        .map(|proposals| {
+            Json(
+                proposals
+                    .into_iter()
+                    .map(|proposal| proposal.into())
+                    .collect(),
+            )
+        })
+        .map_err(Error::from_core)
+}
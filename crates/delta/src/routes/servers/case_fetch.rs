@@ -0,0 +1,28 @@
+use revolt_database::Database;
+use revolt_models::v0::ModerationCase;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Case
+///
+/// Fetch a moderation case by its number.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/cases/<case_number>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    case_number: u32,
+) -> Result<Json<ModerationCase>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::TimeoutMembers)
+        .await?;
+
+    db.fetch_moderation_case(&server.id, case_number)
+        .await
+        .map(|case| Json(case.into()))
+        .map_err(Error::from_core)
+}
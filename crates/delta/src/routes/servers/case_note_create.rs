@@ -0,0 +1,40 @@
+use revolt_database::Database;
+use revolt_models::v0::{DataCreateCaseNote, ModerationCase};
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Add Case Note
+///
+/// Leave a note on a moderation case.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/cases/<case_number>/notes", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    case_number: u32,
+    data: Json<DataCreateCaseNote>,
+) -> Result<Json<ModerationCase>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::TimeoutMembers)
+        .await?;
+
+    let mut case = db
+        .fetch_moderation_case(&server.id, case_number)
+        .await
+        .map_err(Error::from_core)?;
+
+    case.add_note(db, &user.id, data.content)
+        .await
+        .map_err(Error::from_core)?;
+
+    Ok(Json(case.into()))
+}
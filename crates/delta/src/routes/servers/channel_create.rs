@@ -35,6 +35,11 @@ pub struct DataCreateChannel {
     /// Whether this channel is age restricted
     #[serde(skip_serializing_if = "Option::is_none")]
     nsfw: Option<bool>,
+    /// Maximum number of users who can be connected to this voice channel
+    #[validate(range(min = 1, max = 99))]
+    user_limit: Option<u32>,
+    /// Whether every message in this text channel must contain an attachment or link
+    media_only: Option<bool>,
 }
 
 /// # Create Channel
@@ -71,7 +76,17 @@ pub async fn req(
         description,
         nsfw,
         channel_type,
+        user_limit,
+        media_only,
     } = info;
+    if user_limit.is_some() && !matches!(channel_type, ChannelType::Voice) {
+        return Err(Error::InvalidOperation);
+    }
+
+    if media_only.is_some() && !matches!(channel_type, ChannelType::Text) {
+        return Err(Error::InvalidOperation);
+    }
+
     let channel = match channel_type {
         ChannelType::Text => Channel::TextChannel {
             id,
@@ -87,6 +102,12 @@ pub async fn req(
             role_permissions: HashMap::new(),
 
             nsfw: nsfw.unwrap_or(false),
+            locked: false,
+            media_only: media_only.unwrap_or(false),
+            posting_role: None,
+            slowmode: None,
+            auto_thread_depth: None,
+            file_types: None,
         },
         ChannelType::Voice => Channel::VoiceChannel {
             id,
@@ -95,11 +116,14 @@ pub async fn req(
             name,
             description,
             icon: None,
+            last_message_id: None,
 
             default_permissions: None,
             role_permissions: HashMap::new(),
 
             nsfw: nsfw.unwrap_or(false),
+            user_limit,
+            file_types: None,
         },
     };
 
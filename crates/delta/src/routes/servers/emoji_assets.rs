@@ -0,0 +1,20 @@
+use revolt_quark::models::{emoji::EmojiAsset, User};
+use revolt_quark::{perms, Db, Ref, Result};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Server Emoji Assets
+///
+/// Fetch all emoji on a server along with the URLs of their images and size variants.
+///
+/// Clients can use this to prime their emoji cache in one request.
+#[openapi(tag = "Server Customisation")]
+#[get("/<target>/emojis/assets")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<EmojiAsset>>> {
+    let server = target.as_server(db).await?;
+    perms(&user).server(&server).calc(db).await?;
+
+    db.fetch_emoji_by_parent_id(&server.id)
+        .await
+        .map(|emojis| Json(emojis.into_iter().map(|emoji| emoji.into_asset()).collect()))
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use revolt_quark::{
+    models::{Emoji, User},
+    perms, Db, Permission, Ref, Result, Timestamp,
+};
+
+use rocket::serde::json::Json;
+use serde::Serialize;
+
+/// # Emoji Usage
+#[derive(Serialize, JsonSchema)]
+pub struct EmojiUsageEntry {
+    /// Emoji
+    emoji: Emoji,
+    /// Number of times this emoji has been used in messages and reactions
+    uses: i64,
+    /// Time at which this emoji was last used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_used_at: Option<Timestamp>,
+}
+
+/// # Fetch Server Emoji Usage
+///
+/// Fetch all emoji on a server ranked by how often they are used, most used first.
+#[openapi(tag = "Server Customisation")]
+#[get("/<target>/emojis/usage")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<EmojiUsageEntry>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::ManageCustomisation)
+        .await?;
+
+    let emojis = db.fetch_emoji_by_parent_id(&server.id).await?;
+    let ids: Vec<String> = emojis.iter().map(|emoji| emoji.id.clone()).collect();
+    let mut usage: HashMap<String, (i64, Option<Timestamp>)> = db
+        .fetch_emoji_usage(&ids)
+        .await?
+        .into_iter()
+        .map(|usage| (usage.id, (usage.uses, usage.last_used_at)))
+        .collect();
+
+    let mut entries: Vec<EmojiUsageEntry> = emojis
+        .into_iter()
+        .map(|emoji| {
+            let (uses, last_used_at) = usage.remove(&emoji.id).unwrap_or_default();
+            EmojiUsageEntry {
+                emoji,
+                uses,
+                last_used_at,
+            }
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        b.uses
+            .cmp(&a.uses)
+            .then_with(|| b.last_used_at.cmp(&a.last_used_at))
+    });
+
+    Ok(Json(entries))
+}
@@ -0,0 +1,29 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::features;
+
+/// # Fetch Server Features
+///
+/// Fetch the names of experimental features enabled in this server.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/features")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<String>>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user).server(&server).calc(legacy_db).await?;
+
+    Ok(Json(
+        features::fetch_all(db)
+            .await?
+            .into_iter()
+            .filter(|flag| flag.is_enabled(Some(&server.id)))
+            .map(|flag| flag.id)
+            .collect(),
+    ))
+}
@@ -0,0 +1,23 @@
+use revolt_database::{Database, KeywordHighlights};
+use revolt_quark::{models::User, Db, Error, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Keyword Highlights
+///
+/// Fetch the keywords you are notified about in a server.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/keywords")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<revolt_models::v0::KeywordHighlights>> {
+    let server = target.as_server(legacy_db).await?;
+    legacy_db.fetch_member(&server.id, &user.id).await?;
+
+    KeywordHighlights::fetch(db, &server.id, &user.id)
+        .await
+        .map(|highlights| Json(highlights.into()))
+        .map_err(Error::from_core)
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use revolt_database::{Database, KeywordHighlights};
+use revolt_models::v0::DataSetKeywordHighlights;
+use revolt_quark::{models::User, Db, Error, Ref, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Set Keyword Highlights
+///
+/// Replace the keywords you are notified about in a server.
+///
+/// Messages you can read which contain any keyword as a whole word notify you like a mention.
+#[openapi(tag = "Server Members")]
+#[put("/<target>/keywords", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataSetKeywordHighlights>,
+) -> Result<Json<revolt_models::v0::KeywordHighlights>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let server = target.as_server(legacy_db).await?;
+    legacy_db.fetch_member(&server.id, &user.id).await?;
+
+    // Normalise keywords and drop duplicates
+    let mut seen = HashSet::new();
+    let mut keywords = vec![];
+    for keyword in data.keywords {
+        let keyword = keyword.trim().to_lowercase();
+        if keyword.is_empty() || keyword.len() > 64 {
+            return Err(Error::InvalidProperty);
+        }
+
+        if seen.insert(keyword.clone()) {
+            keywords.push(keyword);
+        }
+    }
+
+    KeywordHighlights::set(db, &server.id, &user.id, keywords)
+        .await
+        .map(|highlights| Json(highlights.into()))
+        .map_err(Error::from_core)
+}
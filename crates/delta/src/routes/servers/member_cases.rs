@@ -0,0 +1,30 @@
+use revolt_database::Database;
+use revolt_models::v0::ModerationCase;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Member Cases
+///
+/// Fetch every moderation case against a user in this server, oldest first.
+///
+/// Users who have left or been banned can still be looked up.
+#[openapi(tag = "Server Members")]
+#[get("/<server>/members/<target>/cases")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    server: Ref,
+    target: Ref,
+) -> Result<Json<Vec<ModerationCase>>> {
+    let server = server.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::TimeoutMembers)
+        .await?;
+
+    db.fetch_member_moderation_cases(&server.id, &target.id)
+        .await
+        .map(|cases| Json(cases.into_iter().map(|case| case.into()).collect()))
+        .map_err(Error::from_core)
+}
@@ -1,14 +1,16 @@
 use std::collections::HashSet;
 
+use revolt_database::{Database, ModerationAction, ModerationCase};
 use revolt_quark::{
     models::{
+        server::NotificationMode,
         server_member::{FieldsMember, PartialMember},
         File, Member, User,
     },
     perms, Db, Error, Permission, Ref, Result, Timestamp,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -24,6 +26,8 @@ pub struct DataMemberEdit {
     roles: Option<Vec<String>>,
     /// Timestamp this member is timed out until
     timeout: Option<Timestamp>,
+    /// Which messages this member is notified about
+    notifications: Option<NotificationMode>,
     /// Fields to remove from channel object
     #[validate(length(min = 1))]
     remove: Option<Vec<FieldsMember>>,
@@ -35,7 +39,8 @@ pub struct DataMemberEdit {
 #[openapi(tag = "Server Members")]
 #[patch("/<server>/members/<target>", data = "<data>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     server: Ref,
     target: Ref,
@@ -46,8 +51,8 @@ pub async fn req(
         .map_err(|error| Error::FailedValidation { error })?;
 
     // Fetch server, target member and current permissions
-    let mut server = server.as_server(db).await?;
-    let mut member = target.as_member(db, &server.id).await?;
+    let mut server = server.as_server(legacy_db).await?;
+    let mut member = target.as_member(legacy_db, &server.id).await?;
     let mut permissions = perms(&user).server(&server);
 
     // Check permissions in server
@@ -101,8 +106,12 @@ pub async fn req(
         required.push(Permission::TimeoutMembers);
     }
 
+    if data.notifications.is_some() && user.id != member.id.user {
+        return Err(Error::InvalidOperation);
+    }
+
     for permission in required {
-        permissions.throw_permission(db, permission).await?;
+        permissions.throw_permission(legacy_db, permission).await?;
     }
 
     // Resolve our ranking
@@ -139,13 +148,18 @@ pub async fn req(
         avatar,
         roles,
         timeout,
+        notifications,
         remove,
     } = data;
 
+    // Timeouts are recorded in the member's moderation history
+    let new_timeout = timeout.filter(|_| member.id.user != user.id);
+
     let mut partial = PartialMember {
         nickname,
         roles,
         timeout,
+        notifications,
         ..Default::default()
     };
 
@@ -153,19 +167,37 @@ pub async fn req(
     if let Some(fields) = &remove {
         if fields.contains(&FieldsMember::Avatar) {
             if let Some(avatar) = &member.avatar {
-                db.mark_attachment_as_deleted(&avatar.id).await?;
+                legacy_db.mark_attachment_as_deleted(&avatar.id).await?;
             }
         }
     }
 
     // 2. Apply new avatar
     if let Some(avatar) = avatar {
-        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id).await?);
+        partial.avatar = Some(File::use_avatar(legacy_db, &avatar, &user.id).await?);
     }
 
     member
-        .update(db, partial, remove.unwrap_or_default())
+        .update(legacy_db, partial, remove.unwrap_or_default())
         .await?;
 
+    if let Some(until) = new_timeout {
+        if let Err(err) = ModerationCase::create(
+            db,
+            &member.id.server,
+            &member.id.user,
+            &user.id,
+            ModerationAction::Timeout { until },
+            None,
+        )
+        .await
+        {
+            log::warn!(
+                "Failed to record timeout in server {}: {err:?}",
+                member.id.server
+            );
+        }
+    }
+
     Ok(Json(member))
 }
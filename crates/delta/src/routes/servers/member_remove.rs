@@ -1,15 +1,25 @@
+use revolt_database::{AuditLogAction, Database, ModerationAction, ModerationCase};
 use revolt_quark::{
     models::{server_member::RemovalIntention, User},
     perms, Db, EmptyResponse, Error, Permission, Ref, Result,
 };
+use rocket::State;
+
+use crate::util::audit_log;
 
 /// # Kick Member
 ///
 /// Removes a member from the server.
 #[openapi(tag = "Server Members")]
 #[delete("/<target>/members/<member>")]
-pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyResponse> {
-    let server = target.as_server(db).await?;
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    member: Ref,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(legacy_db).await?;
 
     if member.id == user.id {
         return Err(Error::CannotRemoveYourself);
@@ -22,10 +32,10 @@ pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyR
     let mut permissions = perms(&user).server(&server);
 
     permissions
-        .throw_permission(db, Permission::KickMembers)
+        .throw_permission(legacy_db, Permission::KickMembers)
         .await?;
 
-    let member = member.as_member(db, &server.id).await?;
+    let member = member.as_member(legacy_db, &server.id).await?;
 
     if member.get_ranking(permissions.server.get().unwrap())
         <= permissions.get_member_rank().unwrap_or(i64::MIN)
@@ -33,8 +43,35 @@ pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyR
         return Err(Error::NotElevated);
     }
 
+    let user_id = member.id.user.clone();
+    audit_log::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::MemberKick,
+        &user_id,
+        Some(&member),
+        None,
+    )
+    .await;
+
     server
-        .remove_member(db, member, RemovalIntention::Kick, false)
-        .await
-        .map(|_| EmptyResponse)
+        .remove_member(legacy_db, member, RemovalIntention::Kick, false)
+        .await?;
+
+    // Record the kick in the member's moderation history
+    if let Err(err) = ModerationCase::create(
+        db,
+        &server.id,
+        &user_id,
+        &user.id,
+        ModerationAction::Kick,
+        None,
+    )
+    .await
+    {
+        log::warn!("Failed to record kick in server {}: {err:?}", server.id);
+    }
+
+    Ok(EmptyResponse)
 }
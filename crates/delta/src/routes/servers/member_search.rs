@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use revolt_quark::{
+    models::{server_member::MemberQuery, Member, User},
+    perms, Db, Error, Permission, Ref, Result, Timestamp,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsSearchMembers {
+    /// Only include members with all of these roles
+    #[serde(default)]
+    roles: Vec<String>,
+    /// Only include members who joined at or after this time (ISO 8601)
+    joined_after: Option<String>,
+    /// Only include members who joined at or before this time (ISO 8601)
+    joined_before: Option<String>,
+    /// Only include members who sent a message after this time (ISO 8601)
+    active_after: Option<String>,
+    /// Only include members who have not sent a message since this time (ISO 8601)
+    inactive_after: Option<String>,
+    /// User id after which members should be fetched
+    #[validate(length(min = 26, max = 26))]
+    after: Option<String>,
+    /// Maximum number of members to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+}
+
+/// # Member Search Results
+///
+/// Both lists are sorted by ID.
+#[derive(Serialize, JsonSchema)]
+pub struct MemberSearchResponse {
+    /// List of members
+    members: Vec<Member>,
+    /// List of users
+    users: Vec<User>,
+}
+
+/// Parse a given timestamp
+fn parse_timestamp(value: &str) -> Result<Timestamp> {
+    Timestamp::parse(value).ok_or(Error::InvalidProperty)
+}
+
+/// Find the lowest possible message id sent at a given time
+fn message_id_at(value: &str) -> Result<String> {
+    let time = DateTime::parse_from_rfc3339(value)
+        .map_err(|_| Error::InvalidProperty)?
+        .with_timezone(&Utc);
+
+    Ok(Ulid::from_parts(time.timestamp_millis().max(0) as u64, 0).to_string())
+}
+
+/// # Search Members
+///
+/// Search server members by role, join date and recent activity.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/members/search?<options..>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    options: OptionsSearchMembers,
+) -> Result<Json<MemberSearchResponse>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::KickMembers)
+        .await?;
+
+    let mut query = MemberQuery {
+        roles: options.roles,
+        joined_after: options
+            .joined_after
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        joined_before: options
+            .joined_before
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        after: options.after,
+        limit: options.limit.unwrap_or(50),
+        ..Default::default()
+    };
+
+    // Resolve activity filters to sets of users who have sent messages since.
+    if let Some(active_after) = options.active_after {
+        query.users = Some(
+            db.fetch_message_authors(&server.channels, &message_id_at(&active_after)?)
+                .await?,
+        );
+    }
+
+    if let Some(inactive_after) = options.inactive_after {
+        query.exclude_users = db
+            .fetch_message_authors(&server.channels, &message_id_at(&inactive_after)?)
+            .await?;
+    }
+
+    let members = db.fetch_members_by_query(&server.id, query).await?;
+
+    let user_ids: Vec<String> = members
+        .iter()
+        .map(|member| member.id.user.clone())
+        .collect();
+
+    let mut users = User::fetch_foreign_users(db, &user_ids).await?;
+    users.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Ok(Json(MemberSearchResponse { members, users }))
+}
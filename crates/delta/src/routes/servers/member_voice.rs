@@ -0,0 +1,119 @@
+use revolt_quark::{
+    events::client::EventV1,
+    models::{server_member::PartialMember, Channel, Member, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Voice Moderation Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataMemberVoice {
+    /// Whether the member should be muted in voice channels
+    muted: Option<bool>,
+    /// Whether the member should be deafened in voice channels
+    deafened: Option<bool>,
+    /// Voice channel to move the member to
+    #[validate(length(min = 26, max = 26))]
+    channel: Option<String>,
+}
+
+/// # Moderate Member Voice
+///
+/// Server mute, deafen or move a member between voice channels.
+#[openapi(tag = "Server Members")]
+#[patch("/<server>/members/<target>/voice", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    server: Ref,
+    target: Ref,
+    data: Json<DataMemberVoice>,
+) -> Result<Json<Member>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // Fetch server, target member and current permissions
+    let server = server.as_server(db).await?;
+    let mut member = target.as_member(db, &server.id).await?;
+    let mut permissions = perms(&user).server(&server);
+
+    if data.muted.is_some() {
+        permissions
+            .throw_permission(db, Permission::MuteMembers)
+            .await?;
+    }
+
+    if data.deafened.is_some() {
+        permissions
+            .throw_permission(db, Permission::DeafenMembers)
+            .await?;
+    }
+
+    // Check that we have permissions to act against this member
+    let our_ranking = permissions.get_member_rank().unwrap_or(i64::MIN);
+    if member.id.user != user.id && member.get_ranking(&server) <= our_ranking {
+        return Err(Error::NotElevated);
+    }
+
+    // Make sure we can move members into the given channel
+    if let Some(channel) = &data.channel {
+        let channel = Ref::from_unchecked(channel.to_string())
+            .as_channel(db)
+            .await?;
+
+        match &channel {
+            Channel::VoiceChannel { server: id, .. } if id == &server.id => {}
+            _ => return Err(Error::InvalidOperation),
+        }
+
+        perms(&user)
+            .channel(&channel)
+            .throw_permission_and_view_channel(db, Permission::MoveMembers)
+            .await?;
+    }
+
+    let DataMemberVoice {
+        muted,
+        deafened,
+        channel,
+    } = data;
+
+    if muted.is_some() || deafened.is_some() {
+        member
+            .update(
+                db,
+                PartialMember {
+                    voice_muted: muted,
+                    voice_deafened: deafened,
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await?;
+
+        EventV1::VoiceMemberUpdate {
+            id: server.id.clone(),
+            user: member.id.user.clone(),
+            muted,
+            deafened,
+        }
+        .p(server.id.clone())
+        .await;
+    }
+
+    // Let the member's clients and the voice server know to switch calls
+    if let Some(channel) = channel {
+        EventV1::VoiceChannelMove {
+            id: channel,
+            user: member.id.user.clone(),
+        }
+        .p(server.id.clone())
+        .await;
+    }
+
+    Ok(Json(member))
+}
@@ -0,0 +1,71 @@
+use revolt_database::{Database, ModerationAction, ModerationCase};
+use revolt_models::v0::DataWarnMember;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+use crate::util::warnings;
+
+/// # Warn Member
+///
+/// Open a warning case against a member.
+///
+/// The member is timed out automatically if they reach one of the server's warning thresholds.
+#[openapi(tag = "Server Members")]
+#[post("/<server>/members/<target>/warn", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    server: Ref,
+    target: Ref,
+    data: Json<DataWarnMember>,
+) -> Result<Json<revolt_models::v0::ModerationCase>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = server.as_server(legacy_db).await?;
+
+    if target.id == user.id {
+        return Err(Error::InvalidOperation);
+    }
+
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(legacy_db, Permission::TimeoutMembers)
+        .await?;
+
+    let mut member = target.as_member(legacy_db, &server.id).await?;
+    if member.get_ranking(permissions.server.get().unwrap())
+        <= permissions.get_member_rank().unwrap_or(i64::MIN)
+    {
+        return Err(Error::NotElevated);
+    }
+
+    let case = ModerationCase::create(
+        db,
+        &server.id,
+        &member.id.user,
+        &user.id,
+        ModerationAction::Warning,
+        Some(data.reason),
+    )
+    .await
+    .map_err(Error::from_core)?;
+
+    if data.notify {
+        if let Err(err) = warnings::notify(legacy_db, &user, &case).await {
+            log::warn!("Failed to notify {} of warning: {err:?}", case.user_id);
+        }
+    }
+
+    if let Err(err) = warnings::escalate(db, legacy_db, &server, &mut member, &user.id).await {
+        log::warn!(
+            "Failed to escalate warning in server {}: {err:?}",
+            server.id
+        );
+    }
+
+    Ok(Json(case.into()))
+}
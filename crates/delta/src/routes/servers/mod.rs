@@ -1,27 +1,63 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod announcements_create;
+mod announcements_delete;
+mod announcements_fetch_all;
+mod audit_log_fetch;
 mod ban_create;
+mod ban_export;
+mod ban_import;
 mod ban_list;
 mod ban_remove;
+mod ban_sync_create;
+mod ban_sync_fetch;
+mod ban_sync_join;
+mod ban_sync_leave;
+mod ban_sync_proposal_accept;
+mod ban_sync_proposal_reject;
+mod ban_sync_proposals_fetch;
+mod case_fetch;
+mod case_note_create;
 mod channel_create;
+mod emoji_assets;
 mod emoji_list;
+mod emoji_usage;
+mod feature_flags_fetch;
 mod invites_fetch;
+mod keywords_fetch;
+mod keywords_set;
 mod member_edit;
 mod member_experimental_query;
 mod member_fetch;
 mod member_fetch_all;
 mod member_remove;
+mod member_cases;
+mod member_search;
+mod member_voice;
+mod member_warn;
+mod onboarding_answer;
 mod permissions_set;
 mod permissions_set_default;
+mod reaction_roles_create;
+mod reaction_roles_delete;
+mod reaction_roles_fetch;
+mod role_templates_fetch;
+mod roles_apply_template;
 mod roles_create;
 mod roles_delete;
 mod roles_edit;
+mod roles_join;
+mod roles_leave;
 mod server_ack;
 mod server_create;
 mod server_delete;
 mod server_edit;
 mod server_fetch;
+mod server_import;
+mod server_search;
+mod server_stats;
+mod voice_sessions_fetch;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -30,21 +66,57 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         server_fetch::req,
         server_edit::req,
         server_ack::req,
+        server_search::req,
+        server_import::req,
+        server_stats::req,
+        voice_sessions_fetch::req,
+        feature_flags_fetch::req,
+        announcements_fetch_all::req,
+        announcements_create::req,
+        announcements_delete::req,
+        audit_log_fetch::req,
         channel_create::req,
         member_fetch_all::req,
         member_remove::req,
         member_fetch::req,
         member_edit::req,
+        member_search::req,
+        member_voice::req,
+        member_warn::req,
+        keywords_fetch::req,
+        keywords_set::req,
+        member_cases::req,
+        case_fetch::req,
+        case_note_create::req,
+        onboarding_answer::req,
         member_experimental_query::member_experimental_query,
         ban_create::req,
         ban_remove::req,
         ban_list::req,
+        ban_export::req,
+        ban_import::req,
+        ban_sync_fetch::req,
+        ban_sync_create::req,
+        ban_sync_join::req,
+        ban_sync_leave::req,
+        ban_sync_proposals_fetch::req,
+        ban_sync_proposal_accept::req,
+        ban_sync_proposal_reject::req,
         invites_fetch::req,
         roles_create::req,
         roles_edit::req,
         roles_delete::req,
+        roles_apply_template::req,
+        roles_join::req,
+        roles_leave::req,
+        role_templates_fetch::req,
+        reaction_roles_fetch::req,
+        reaction_roles_create::req,
+        reaction_roles_delete::req,
         permissions_set::req,
         permissions_set_default::req,
-        emoji_list::list_emoji
+        emoji_list::list_emoji,
+        emoji_assets::req,
+        emoji_usage::req
     ]
 }
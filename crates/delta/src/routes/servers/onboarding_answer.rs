@@ -0,0 +1,73 @@
+use std::collections::{HashMap, HashSet};
+
+use revolt_quark::{
+    models::{server_member::PartialMember, Member, User},
+    Db, Error, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Onboarding Answers
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataOnboardingAnswers {
+    /// Option ids picked for each question id
+    answers: HashMap<String, Vec<String>>,
+}
+
+/// # Answer Onboarding
+///
+/// Complete the server's onboarding sequence, granting roles and revealing channels for the picked options.
+///
+/// Answering again replaces any roles granted by previous answers.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/onboarding", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataOnboardingAnswers>,
+) -> Result<Json<Member>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    let mut member = db.fetch_member(&server.id, &user.id).await?;
+
+    let onboarding = match &server.onboarding {
+        Some(onboarding) if onboarding.enabled => onboarding,
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    let picked = onboarding.resolve_answers(&data.answers)?;
+
+    // Swap out roles granted by any previous answers
+    let previous = onboarding.roles_for(member.onboarding.as_deref().unwrap_or_default());
+    let granted = onboarding.roles_for(&picked);
+
+    let mut roles: HashSet<String> = member
+        .roles
+        .iter()
+        .filter(|id| !previous.contains(*id))
+        .cloned()
+        .collect();
+
+    roles.extend(granted);
+    roles.retain(|id| server.roles.contains_key(id));
+
+    member
+        .update(
+            db,
+            PartialMember {
+                roles: Some(roles.into_iter().collect()),
+                onboarding: Some(picked),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(member))
+}
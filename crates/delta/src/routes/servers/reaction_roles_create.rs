@@ -0,0 +1,89 @@
+use revolt_database::{Database, ReactionRole};
+use revolt_models::v0::DataCreateReactionRole;
+use revolt_quark::{
+    models::{Channel, Emoji, User},
+    perms,
+    variables::delta::MAX_REACTION_ROLE_COUNT,
+    Db, Error, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Reaction Role
+///
+/// Grant a role to members who react to a message with a given emoji.
+///
+/// The role is revoked again when the reaction is removed.
+#[openapi(tag = "Server Permissions")]
+#[post("/<target>/reaction_roles", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateReactionRole>,
+) -> Result<Json<revolt_models::v0::ReactionRole>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(legacy_db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(legacy_db, Permission::ManageRole)
+        .await?;
+
+    // Members must not be able to hand out roles above their own
+    let role = server.roles.get(&data.role_id).ok_or(Error::NotFound)?;
+    if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(Error::NotElevated);
+    }
+
+    let channel = Ref::from_unchecked(data.channel_id)
+        .as_channel(legacy_db)
+        .await?;
+
+    match &channel {
+        Channel::TextChannel { server: id, .. } if id == &server.id => {}
+        _ => return Err(Error::NotFound),
+    }
+
+    let message = Ref::from_unchecked(data.message_id)
+        .as_message_in(legacy_db, channel.id())
+        .await?;
+
+    if !message.interactions.can_use(&data.emoji) || !Emoji::can_use(legacy_db, &data.emoji).await?
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    let bindings = db
+        .fetch_reaction_roles(&server.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if bindings.len() >= *MAX_REACTION_ROLE_COUNT {
+        return Err(Error::InvalidOperation);
+    }
+
+    if bindings.iter().any(|binding| {
+        binding.message_id == message.id
+            && binding.emoji == data.emoji
+            && binding.role_id == data.role_id
+    }) {
+        return Err(Error::InvalidOperation);
+    }
+
+    let binding = ReactionRole {
+        id: Ulid::new().to_string(),
+        server_id: server.id,
+        channel_id: message.channel,
+        message_id: message.id,
+        emoji: data.emoji,
+        role_id: data.role_id,
+    };
+
+    binding.create(db).await.map_err(Error::from_core)?;
+    Ok(Json(binding.into()))
+}
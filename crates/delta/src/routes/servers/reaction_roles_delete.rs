@@ -0,0 +1,46 @@
+use revolt_database::{util::reference::Reference, Database};
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Delete Reaction Role
+///
+/// Stop granting a role for reactions to a message.
+///
+/// Members keep any roles they were already granted.
+#[openapi(tag = "Server Permissions")]
+#[delete("/<target>/reaction_roles/<binding_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    binding_id: Reference,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(legacy_db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(legacy_db, Permission::ManageRole)
+        .await?;
+
+    let binding = db
+        .fetch_reaction_role(&binding_id.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if binding.server_id != server.id {
+        return Err(Error::NotFound);
+    }
+
+    // Members must not be able to remove bindings for roles above their own
+    if let Some(role) = server.roles.get(&binding.role_id) {
+        if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+            return Err(Error::NotElevated);
+        }
+    }
+
+    binding
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
@@ -0,0 +1,26 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Reaction Roles
+///
+/// Fetch all roles granted by reacting to messages in this server.
+#[openapi(tag = "Server Permissions")]
+#[get("/<target>/reaction_roles")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<revolt_models::v0::ReactionRole>>> {
+    let server = target.as_server(legacy_db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(legacy_db, Permission::ManageRole)
+        .await?;
+
+    db.fetch_reaction_roles(&server.id)
+        .await
+        .map(|bindings| Json(bindings.into_iter().map(Into::into).collect()))
+        .map_err(Error::from_core)
+}
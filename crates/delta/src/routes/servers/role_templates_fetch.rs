@@ -0,0 +1,18 @@
+use revolt_database::{Database, RoleTemplate};
+use revolt_quark::{models::User, Error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Role Templates
+///
+/// Fetch all role permission templates available on this instance.
+#[openapi(tag = "Server Permissions")]
+#[get("/role_templates")]
+pub async fn req(
+    db: &State<Database>,
+    _user: User,
+) -> Result<Json<Vec<revolt_models::v0::RoleTemplate>>> {
+    RoleTemplate::fetch_all(db)
+        .await
+        .map(|templates| Json(templates.into_iter().map(Into::into).collect()))
+        .map_err(Error::from_core)
+}
@@ -0,0 +1,55 @@
+use revolt_database::{Database, RoleTemplate};
+use revolt_permissions::Override as TemplateOverride;
+use revolt_quark::{
+    models::{Server, User},
+    perms, Db, Error, Override, Permission, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+
+/// # Apply Role Template
+///
+/// Replaces the permissions of the specified role with those of a template.
+#[openapi(tag = "Server Permissions")]
+#[put("/<target>/roles/<role_id>/template/<template_id>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    role_id: String,
+    template_id: String,
+) -> Result<Json<Server>> {
+    let mut server = target.as_server(legacy_db).await?;
+    if let Some((current_value, rank)) = server.roles.get(&role_id).map(|x| (x.permissions, x.rank))
+    {
+        let mut permissions = perms(&user).server(&server);
+
+        permissions
+            .throw_permission(legacy_db, Permission::ManagePermissions)
+            .await?;
+
+        if rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+            return Err(Error::NotElevated);
+        }
+
+        let template = RoleTemplate::fetch(db, &template_id)
+            .await
+            .map_err(Error::from_core)?;
+
+        let value: TemplateOverride = template.permissions.into();
+        let value = Override::new(value.allow, value.deny);
+
+        let current_value: Override = current_value.into();
+        permissions
+            .throw_permission_override(legacy_db, current_value, value)
+            .await?;
+
+        server
+            .set_role_permission(legacy_db, &role_id, value.into())
+            .await?;
+
+        Ok(Json(server))
+    } else {
+        Err(Error::NotFound)
+    }
+}
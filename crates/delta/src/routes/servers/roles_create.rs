@@ -1,12 +1,16 @@
+use revolt_database::{AuditLogAction, Database, RoleTemplate};
+use revolt_permissions::Override as TemplateOverride;
 use revolt_quark::{
     models::{server::Role, User},
-    perms, Db, Error, Permission, Ref, Result, variables::delta::MAX_ROLE_COUNT,
+    perms, Db, Error, Override, Permission, Ref, Result, variables::delta::MAX_ROLE_COUNT,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::util::audit_log;
+
 /// # Role Data
 #[derive(Validate, Deserialize, JsonSchema)]
 pub struct DataCreateRole {
@@ -17,6 +21,8 @@ pub struct DataCreateRole {
     ///
     /// Smaller values take priority.
     rank: Option<i64>,
+    /// Id of a role template to take permissions from
+    template: Option<String>,
 }
 
 /// # New Role Response
@@ -34,7 +40,8 @@ pub struct NewRoleResponse {
 #[openapi(tag = "Server Permissions")]
 #[post("/<target>/roles", data = "<data>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     data: Json<DataCreateRole>,
@@ -43,11 +50,11 @@ pub async fn req(
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    let server = target.as_server(db).await?;
+    let server = target.as_server(legacy_db).await?;
     let mut permissions = perms(&user).server(&server);
 
     permissions
-        .throw_permission(db, Permission::ManageRole)
+        .throw_permission(legacy_db, Permission::ManageRole)
         .await?;
 
     if server.roles.len() > *MAX_ROLE_COUNT {
@@ -65,14 +72,42 @@ pub async fn req(
         member_rank.unwrap_or(0).saturating_add(1)
     };
 
-    let role = Role {
+    let mut role = Role {
         name: data.name,
         rank,
         ..Default::default()
     };
 
-    Ok(Json(NewRoleResponse {
-        id: role.create(db, &server.id).await?,
-        role,
-    }))
+    if let Some(template) = data.template {
+        let template = RoleTemplate::fetch(db, &template)
+            .await
+            .map_err(Error::from_core)?;
+
+        let value: TemplateOverride = template.permissions.into();
+        let value = Override::new(value.allow, value.deny);
+
+        permissions
+            .throw_permission(legacy_db, Permission::ManagePermissions)
+            .await?;
+
+        permissions
+            .throw_permission_value(legacy_db, value.allows())
+            .await?;
+
+        role.permissions = value.into();
+    }
+
+    let id = role.create(legacy_db, &server.id).await?;
+    audit_log::record(
+        db,
+        &server.id,
+        &user.id,
+        AuditLogAction::RoleCreate,
+        &id,
+        None,
+        Some(&role),
+    )
+    .await;
+
+    Ok(Json(NewRoleResponse { id, role }))
 }
@@ -1,16 +1,26 @@
+use revolt_database::{AuditLogAction, Database};
 use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+use crate::util::audit_log;
 
 /// # Delete Role
 ///
 /// Delete a server role by its id.
 #[openapi(tag = "Server Permissions")]
 #[delete("/<target>/roles/<role_id>")]
-pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<EmptyResponse> {
-    let mut server = target.as_server(db).await?;
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    role_id: String,
+) -> Result<EmptyResponse> {
+    let mut server = target.as_server(legacy_db).await?;
     let mut permissions = perms(&user).server(&server);
 
     permissions
-        .throw_permission(db, Permission::ManageRole)
+        .throw_permission(legacy_db, Permission::ManageRole)
         .await?;
 
     let member_rank = permissions.get_member_rank().unwrap_or(0);
@@ -20,7 +30,18 @@ pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<Em
             return Err(Error::NotElevated);
         }
 
-        role.delete(db, &server.id, &role_id)
+        audit_log::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::RoleDelete,
+            &role_id,
+            Some(&role),
+            None,
+        )
+        .await;
+
+        role.delete(legacy_db, &server.id, &role_id)
             .await
             .map(|_| EmptyResponse)
     } else {
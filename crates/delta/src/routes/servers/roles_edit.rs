@@ -1,3 +1,4 @@
+use revolt_database::{AuditLogAction, Database};
 use revolt_quark::{
     models::{
         server::{FieldsRole, PartialRole, Role},
@@ -8,10 +9,12 @@ use revolt_quark::{
     Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::util::audit_log;
+
 /// # Role Data
 #[derive(Validate, Serialize, Deserialize, JsonSchema)]
 pub struct DataEditRole {
@@ -27,6 +30,12 @@ pub struct DataEditRole {
     ///
     /// Smaller values take priority.
     rank: Option<i64>,
+    /// Whether anyone may mention this role
+    mentionable: Option<bool>,
+    /// Whether members may give themselves this role
+    self_assignable: Option<bool>,
+    /// Position in the self-assignable role menu
+    menu_position: Option<i64>,
     /// Fields to remove from role object
     #[validate(length(min = 1))]
     remove: Option<Vec<FieldsRole>>,
@@ -38,7 +47,8 @@ pub struct DataEditRole {
 #[openapi(tag = "Server Permissions")]
 #[patch("/<target>/roles/<role_id>", data = "<data>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     role_id: String,
@@ -48,11 +58,11 @@ pub async fn req(
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    let mut server = target.as_server(db).await?;
+    let mut server = target.as_server(legacy_db).await?;
     let mut permissions = perms(&user).server(&server);
 
     permissions
-        .throw_permission(db, Permission::ManageRole)
+        .throw_permission(legacy_db, Permission::ManageRole)
         .await?;
 
     let member_rank = permissions.get_member_rank().unwrap_or(i64::MIN);
@@ -63,6 +73,9 @@ pub async fn req(
             colour,
             hoist,
             rank,
+            mentionable,
+            self_assignable,
+            menu_position,
             remove,
         } = data;
 
@@ -72,16 +85,31 @@ pub async fn req(
             }
         }
 
+        // Letting members assign a role to themselves requires being able to assign it
+        if self_assignable == Some(true) {
+            permissions
+                .throw_permission(legacy_db, Permission::AssignRoles)
+                .await?;
+
+            if role.rank <= member_rank {
+                return Err(Error::NotElevated);
+            }
+        }
+
         let partial = PartialRole {
             name,
             colour,
             hoist,
             rank,
+            mentionable,
+            self_assignable,
+            menu_position,
             ..Default::default()
         };
 
+        let before = role.clone();
         role.update(
-            db,
+            legacy_db,
             &server.id,
             &role_id,
             partial,
@@ -89,6 +117,17 @@ pub async fn req(
         )
         .await?;
 
+        audit_log::record(
+            db,
+            &server.id,
+            &user.id,
+            AuditLogAction::RoleUpdate,
+            &role_id,
+            Some(&before),
+            Some(&role),
+        )
+        .await;
+
         Ok(Json(role))
     } else {
         Err(Error::NotFound)
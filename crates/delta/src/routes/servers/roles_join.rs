@@ -0,0 +1,38 @@
+use revolt_quark::{
+    models::{server_member::PartialMember, User},
+    Db, EmptyResponse, Error, Ref, Result,
+};
+
+/// # Join Role
+///
+/// Give yourself a self-assignable role.
+#[openapi(tag = "Server Permissions")]
+#[put("/<target>/roles/<role_id>/membership")]
+pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut member = db.fetch_member(&server.id, &user.id).await?;
+
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?;
+    if !role.self_assignable {
+        return Err(Error::InvalidOperation);
+    }
+
+    if member.roles.contains(&role_id) {
+        return Ok(EmptyResponse);
+    }
+
+    let mut roles = member.roles.clone();
+    roles.push(role_id);
+
+    member
+        .update(
+            db,
+            PartialMember {
+                roles: Some(roles),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+        .map(|_| EmptyResponse)
+}
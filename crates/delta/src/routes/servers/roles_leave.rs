@@ -0,0 +1,42 @@
+use revolt_quark::{
+    models::{server_member::PartialMember, User},
+    Db, EmptyResponse, Error, Ref, Result,
+};
+
+/// # Leave Role
+///
+/// Remove a self-assignable role from yourself.
+#[openapi(tag = "Server Permissions")]
+#[delete("/<target>/roles/<role_id>/membership")]
+pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut member = db.fetch_member(&server.id, &user.id).await?;
+
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?;
+    if !role.self_assignable {
+        return Err(Error::InvalidOperation);
+    }
+
+    if !member.roles.contains(&role_id) {
+        return Ok(EmptyResponse);
+    }
+
+    let roles = member
+        .roles
+        .iter()
+        .filter(|id| *id != &role_id)
+        .cloned()
+        .collect();
+
+    member
+        .update(
+            db,
+            PartialMember {
+                roles: Some(roles),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+        .map(|_| EmptyResponse)
+}
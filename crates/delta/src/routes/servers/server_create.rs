@@ -1,16 +1,20 @@
 use std::collections::HashMap;
 
 use revolt_quark::{
+    authifier::Authifier,
     models::{Channel, Server, User},
     variables::delta::MAX_SERVER_COUNT,
     Db, Error, Result, DEFAULT_PERMISSION_SERVER,
 };
 
-use rocket::serde::json::Json;
+use revolt_database::Database;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 use validator::Validate;
 
+use crate::util::{policies, verification};
+
 /// # Server Data
 #[derive(Validate, Deserialize, JsonSchema)]
 pub struct DataCreateServer {
@@ -40,7 +44,9 @@ pub struct CreateServerResponse {
 #[openapi(tag = "Server Information")]
 #[post("/create", data = "<info>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
+    authifier: &State<Authifier>,
     user: User,
     info: Json<DataCreateServer>,
 ) -> Result<Json<CreateServerResponse>> {
@@ -48,16 +54,24 @@ pub async fn req(
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
+    policies::assert_accepted(db, &user).await?;
+
     let info = info.into_inner();
     info.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    if !user.can_acquire_server(db).await? {
+    if !user.can_acquire_server(legacy_db).await? {
         return Err(Error::TooManyServers {
             max: *MAX_SERVER_COUNT,
         });
     }
 
+    verification::assert_can_join_server(authifier, legacy_db, &user).await?;
+
     let DataCreateServer {
         name,
         description,
@@ -81,9 +95,15 @@ pub async fn req(
         role_permissions: HashMap::new(),
 
         nsfw: nsfw.unwrap_or(false),
+        locked: false,
+        media_only: false,
+        posting_role: None,
+        slowmode: None,
+        auto_thread_depth: None,
+        file_types: None,
     };
 
-    db.insert_channel(&channel).await?;
+    legacy_db.insert_channel(&channel).await?;
 
     let server = Server {
         id: server_id.clone(),
@@ -96,7 +116,9 @@ pub async fn req(
         ..Default::default()
     };
 
-    server.create(db).await?;
-    let channels = server.create_member(db, user, Some(vec![channel])).await?;
+    server.create(legacy_db).await?;
+    let channels = server
+        .create_member(legacy_db, user, Some(vec![channel]))
+        .await?;
     Ok(Json(CreateServerResponse { server, channels }))
 }
@@ -1,15 +1,22 @@
 use std::collections::HashSet;
 
+use revolt_database::{AdminAuditEntry, Database};
 use revolt_quark::{
     models::{
-        server::{Category, FieldsServer, PartialServer, SystemMessageChannels},
+        server::{
+            Category, FieldsServer, Highlights, NotificationMode, Onboarding, PartialServer,
+            ServerFlagsValue, SystemMessageChannels, WarnThreshold,
+        },
         File, Server, User,
     },
-    perms, Db, Error, Permission, Ref, Result,
+    perms,
+    variables::delta::STORAGE_REGIONS,
+    Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use validator::Validate;
 
 /// # Server Data
@@ -35,29 +42,55 @@ pub struct DataEditServer {
 
     /// Bitfield of server flags
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<ServerFlagsValue>,
 
     // Whether this server is age-restricted
     // nsfw: Option<bool>,
     /// Whether this server is public and should show up on [Revolt Discover](https://rvlt.gg)
     discoverable: Option<bool>,
+    /// Storage region files sent in this server must be uploaded to
+    ///
+    /// Must be one of the regions configured on this instance.
+    storage_region: Option<String>,
     /// Whether analytics should be collected for this server
     ///
     /// Must be enabled in order to show up on [Revolt Discover](https://rvlt.gg).
     analytics: Option<bool>,
+    /// Notification mode given to new members
+    default_notifications: Option<NotificationMode>,
+    /// Onboarding sequence for new members
+    #[validate]
+    onboarding: Option<Onboarding>,
+    /// Configuration for highlighting popular messages
+    #[validate]
+    highlights: Option<Highlights>,
+    /// Timeouts applied automatically when members are warned
+    #[validate(length(max = 10))]
+    #[validate]
+    warn_thresholds: Option<Vec<WarnThreshold>>,
 
     /// Fields to remove from server object
     #[validate(length(min = 1))]
     remove: Option<Vec<FieldsServer>>,
 }
 
+/// Fields of a server which only privileged users may change
+fn privileged_fields(server: &Server) -> Value {
+    json!({
+        "flags": server.flags,
+        "discoverable": server.discoverable,
+        "storage_region": server.storage_region,
+    })
+}
+
 /// # Edit Server
 ///
 /// Edit a server by its id.
 #[openapi(tag = "Server Information")]
 #[patch("/<target>", data = "<data>")]
 pub async fn req(
-    db: &Db,
+    db: &State<Database>,
+    legacy_db: &Db,
     user: User,
     target: Ref,
     data: Json<DataEditServer>,
@@ -66,9 +99,9 @@ pub async fn req(
     data.validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
-    let mut server = target.as_server(db).await?;
+    let mut server = target.as_server(legacy_db).await?;
     let mut permissions = perms(&user).server(&server);
-    permissions.calc(db).await?;
+    permissions.calc(legacy_db).await?;
 
     // Check permissions
     if data.name.is_none()
@@ -81,6 +114,11 @@ pub async fn req(
         && data.flags.is_none()
         && data.analytics.is_none()
         && data.discoverable.is_none()
+        && data.storage_region.is_none()
+        && data.default_notifications.is_none()
+        && data.onboarding.is_none()
+        && data.highlights.is_none()
+        && data.warn_thresholds.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(server));
@@ -90,27 +128,64 @@ pub async fn req(
         || data.banner.is_some()
         || data.system_messages.is_some()
         || data.analytics.is_some()
+        || data.default_notifications.is_some()
+        || data.onboarding.is_some()
+        || data.highlights.is_some()
+        || data.warn_thresholds.is_some()
         || data.remove.is_some()
     {
         permissions
-            .throw_permission(db, Permission::ManageServer)
+            .throw_permission(legacy_db, Permission::ManageServer)
             .await?;
     }
 
     // Check we are privileged if changing sensitive fields
-    if (data.flags.is_some() /*|| data.nsfw.is_some()*/ || data.discoverable.is_some())
-        && !user.privileged
-    {
+    let privileged_edit = data.flags.is_some() /*|| data.nsfw.is_some()*/
+        || data.discoverable.is_some()
+        || data.storage_region.is_some()
+        || data
+            .remove
+            .as_ref()
+            .map_or(false, |fields| fields.contains(&FieldsServer::StorageRegion));
+
+    if privileged_edit && !user.privileged {
         return Err(Error::NotPrivileged);
     }
 
     // Changing categories requires manage channel
     if data.categories.is_some() {
         permissions
-            .throw_permission(db, Permission::ManageChannel)
+            .throw_permission(legacy_db, Permission::ManageChannel)
             .await?;
     }
 
+    // Onboarding may only grant roles ranked below our own
+    if let Some(onboarding) = &data.onboarding {
+        onboarding.validate_against(&server)?;
+
+        let roles: HashSet<&String> = onboarding
+            .questions
+            .iter()
+            .flat_map(|question| question.options.iter())
+            .flat_map(|option| option.roles.iter())
+            .collect();
+
+        if !roles.is_empty() {
+            permissions
+                .throw_permission(legacy_db, Permission::AssignRoles)
+                .await?;
+
+            let our_ranking = permissions.get_member_rank().unwrap_or(i64::MIN);
+            if roles
+                .into_iter()
+                .filter_map(|id| server.roles.get(id))
+                .any(|role| role.rank <= our_ranking)
+            {
+                return Err(Error::NotElevated);
+            }
+        }
+    }
+
     let DataEditServer {
         name,
         description,
@@ -121,7 +196,12 @@ pub async fn req(
         flags,
         // nsfw,
         discoverable,
+        storage_region,
         analytics,
+        default_notifications,
+        onboarding,
+        highlights,
+        warn_thresholds,
         remove,
     } = data;
 
@@ -133,7 +213,12 @@ pub async fn req(
         flags,
         // nsfw,
         discoverable,
+        storage_region,
         analytics,
+        default_notifications,
+        onboarding,
+        highlights,
+        warn_thresholds,
         ..Default::default()
     };
 
@@ -141,13 +226,13 @@ pub async fn req(
     if let Some(fields) = &remove {
         if fields.contains(&FieldsServer::Banner) {
             if let Some(banner) = &server.banner {
-                db.mark_attachment_as_deleted(&banner.id).await?;
+                legacy_db.mark_attachment_as_deleted(&banner.id).await?;
             }
         }
 
         if fields.contains(&FieldsServer::Icon) {
             if let Some(icon) = &server.icon {
-                db.mark_attachment_as_deleted(&icon.id).await?;
+                legacy_db.mark_attachment_as_deleted(&icon.id).await?;
             }
         }
     }
@@ -161,6 +246,25 @@ pub async fn req(
         }
     }
 
+    if let Some(highlights) = &partial.highlights {
+        if !server.channels.contains(&highlights.channel) {
+            return Err(Error::NotFound);
+        }
+    }
+
+    if let Some(region) = &partial.storage_region {
+        if !STORAGE_REGIONS.contains_key(region) {
+            return Err(Error::InvalidProperty);
+        }
+    }
+
+    if let Some(thresholds) = &partial.warn_thresholds {
+        let mut counts = HashSet::new();
+        if !thresholds.iter().all(|t| counts.insert(t.warnings)) {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
     if let Some(categories) = &mut partial.categories {
         let mut channel_ids = HashSet::new();
         for category in categories {
@@ -180,19 +284,33 @@ pub async fn req(
 
     // 3. Apply new icon
     if let Some(icon) = icon {
-        partial.icon = Some(File::use_server_icon(db, &icon, &server.id).await?);
+        partial.icon = Some(File::use_server_icon(legacy_db, &icon, &server.id).await?);
         server.icon = partial.icon.clone();
     }
 
     // 4. Apply new banner
     if let Some(banner) = banner {
-        partial.banner = Some(File::use_banner(db, &banner, &server.id).await?);
+        partial.banner = Some(File::use_banner(legacy_db, &banner, &server.id).await?);
         server.banner = partial.banner.clone();
     }
 
+    let before = privileged_fields(&server);
     server
-        .update(db, partial, remove.unwrap_or_default())
+        .update(legacy_db, partial, remove.unwrap_or_default())
         .await?;
 
+    if privileged_edit {
+        AdminAuditEntry::record(
+            db,
+            &user.id,
+            "server.edit",
+            &server.id,
+            Some(&before),
+            Some(&privileged_fields(&server)),
+        )
+        .await
+        .map_err(Error::from_core)?;
+    }
+
     Ok(Json(server))
 }
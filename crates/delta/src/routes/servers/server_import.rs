@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use revolt_quark::{
+    models::{
+        server::{Category, PartialServer, Role},
+        Channel, User,
+    },
+    perms,
+    variables::delta::{MAX_CHANNEL_COUNT, MAX_ROLE_COUNT},
+    Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::util::import::{self, DiscordExport, ImportedRole, MatrixExport};
+
+/// # Import Source
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "source")]
+pub enum ImportSource {
+    /// DiscordChatExporter JSON exports, one per channel
+    Discord { exports: Vec<DiscordExport> },
+    /// Element JSON exports, one per room
+    Matrix { exports: Vec<MatrixExport> },
+}
+
+/// # Import Data
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct DataImportServer {
+    /// Exports to import
+    #[serde(flatten)]
+    source: ImportSource,
+    /// Whether to import message history
+    #[serde(default)]
+    history: bool,
+}
+
+/// # Import Result
+#[derive(Serialize, JsonSchema)]
+pub struct ImportResponse {
+    /// Channels created by this import
+    channels: Vec<Channel>,
+    /// Ids of roles created by this import
+    roles: Vec<String>,
+    /// Number of messages queued for import
+    messages: usize,
+}
+
+/// # Import Server
+///
+/// Recreate channels, roles and optionally message history from Discord or Matrix exports.
+///
+/// Roles are only available from Discord exports and are created without permissions.
+/// Categories and roles which already exist by name are reused, so large exports can
+/// be imported a few channels at a time.
+///
+/// Message history is imported in the background under your account, with each
+/// message masquerading as its original author.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/import", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataImportServer>,
+) -> Result<Json<ImportResponse>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::ManageServer)
+        .await?;
+
+    permissions
+        .throw_permission(db, Permission::ManageChannel)
+        .await?;
+
+    let (channels, roles): (_, Vec<ImportedRole>) = match data.source {
+        ImportSource::Discord { exports } => import::from_discord(exports),
+        ImportSource::Matrix { exports } => (import::from_matrix(exports), vec![]),
+    };
+
+    if channels.is_empty() {
+        return Err(Error::InvalidOperation);
+    }
+
+    if server.channels.len() + channels.len() > *MAX_CHANNEL_COUNT {
+        return Err(Error::TooManyChannels {
+            max: *MAX_CHANNEL_COUNT,
+        });
+    }
+
+    // Skip roles which already exist by name
+    let existing: HashSet<&String> = server.roles.values().map(|role| &role.name).collect();
+    let roles: Vec<ImportedRole> = roles
+        .into_iter()
+        .filter(|role| !existing.contains(&role.name))
+        .collect();
+
+    let mut role_ids = vec![];
+    if !roles.is_empty() {
+        permissions
+            .throw_permission(db, Permission::ManageRole)
+            .await?;
+
+        if server.roles.len() + roles.len() > *MAX_ROLE_COUNT {
+            return Err(Error::TooManyRoles {
+                max: *MAX_ROLE_COUNT,
+            });
+        }
+
+        // Place imported roles below every existing role and the importer
+        let lowest = server.roles.values().map(|role| role.rank).max();
+        let mut rank = lowest
+            .max(permissions.get_member_rank())
+            .unwrap_or(0)
+            .saturating_add(1);
+
+        for imported in roles {
+            let role = Role {
+                name: imported.name,
+                colour: imported.colour,
+                rank,
+                ..Default::default()
+            };
+
+            role_ids.push(role.create(db, &server.id).await?);
+            rank = rank.saturating_add(1);
+        }
+    }
+
+    // Create channels and file them under their categories
+    let mut server_channels = server.channels.clone();
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let mut created = vec![];
+    let mut history = HashMap::new();
+    let mut message_count = 0;
+
+    for imported in channels {
+        let id = Ulid::new().to_string();
+        let channel = Channel::TextChannel {
+            id: id.clone(),
+            server: server.id.clone(),
+
+            name: imported.name,
+            description: imported.description,
+
+            icon: None,
+            last_message_id: None,
+
+            default_permissions: None,
+            role_permissions: HashMap::new(),
+
+            nsfw: false,
+            locked: false,
+            media_only: false,
+            posting_role: None,
+            slowmode: None,
+            auto_thread_depth: None,
+            file_types: None,
+        };
+
+        channel.create(db).await?;
+        server_channels.push(id.clone());
+
+        if let Some(title) = imported.category {
+            let title: String = title.trim().chars().take(32).collect();
+            if !title.is_empty() {
+                if let Some(category) = categories.iter_mut().find(|c| c.title == title) {
+                    category.channels.push(id.clone());
+                } else {
+                    categories.push(Category {
+                        id: Ulid::new().to_string(),
+                        title,
+                        channels: vec![id.clone()],
+                        collapsed: false,
+                        hidden: false,
+                    });
+                }
+            }
+        }
+
+        if data.history && !imported.messages.is_empty() {
+            message_count += imported.messages.len();
+            history.insert(id, imported.messages);
+        }
+
+        created.push(channel);
+    }
+
+    server
+        .update(
+            db,
+            PartialServer {
+                channels: Some(server_channels),
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    // Write message history in the background
+    for (channel_id, messages) in history {
+        let db = db.inner().clone();
+        let author_id = user.id.clone();
+        async_std::task::spawn(async move {
+            import::backfill(&db, &channel_id, &author_id, messages).await;
+        });
+    }
+
+    Ok(Json(ImportResponse {
+        channels: created,
+        roles: role_ids,
+        messages: message_count,
+    }))
+}
@@ -0,0 +1,125 @@
+use revolt_quark::{
+    models::{
+        message::{
+            AttachmentFilter, MessageFilter, MessageQuery, MessageSort, MessageTimePeriod,
+            ServerSearchResponse,
+        },
+        User,
+    },
+    perms, search, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Search Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct OptionsServerSearch {
+    /// Full-text search query
+    ///
+    /// If no external search backend is configured, see [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator) for more information.
+    #[validate(length(min = 1, max = 64))]
+    query: Option<String>,
+    /// Only include messages with a matching attachment
+    #[validate]
+    attachment: Option<AttachmentFilter>,
+
+    /// Maximum number of messages to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+    /// Message id before which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    before: Option<String>,
+    /// Message id after which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    after: Option<String>,
+    /// Message sort direction
+    ///
+    /// By default, it will be sorted by latest.
+    #[serde(default = "MessageSort::default")]
+    sort: MessageSort,
+    /// Whether to include user and member objects
+    include_users: Option<bool>,
+}
+
+/// # Search Server Messages
+///
+/// This route searches for messages across all channels in a server
+/// which the user can read, grouping results by channel.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/search", data = "<options>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    options: Json<OptionsServerSearch>,
+) -> Result<Json<ServerSearchResponse>> {
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let options = options.into_inner();
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if options.query.is_none() && options.attachment.is_none() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let server = target.as_server(db).await?;
+    let member = db.fetch_member(&server.id, &user.id).await?;
+
+    // Only search channels the user can read the history of.
+    let mut channels = vec![];
+    for channel in db.fetch_channels(&server.channels).await? {
+        if perms(&user)
+            .server(&server)
+            .member(&member)
+            .channel(&channel)
+            .has_permission_value(db, Permission::ViewChannel + Permission::ReadMessageHistory)
+            .await?
+        {
+            channels.push(channel.id().to_string());
+        }
+    }
+
+    if channels.is_empty() {
+        return Err(Error::NotFound);
+    }
+
+    let OptionsServerSearch {
+        query,
+        attachment,
+        limit,
+        before,
+        after,
+        sort,
+        include_users,
+    } = options;
+
+    let messages = search::fetch_messages(
+        db,
+        MessageQuery {
+            filter: MessageFilter {
+                channels: Some(channels),
+                query,
+                attachment,
+                ..Default::default()
+            },
+            time_period: MessageTimePeriod::Absolute {
+                before,
+                after,
+                sort: Some(sort),
+            },
+            limit,
+            consistent: false,
+        },
+    )
+    .await?;
+
+    ServerSearchResponse::transform(db, &server.id, messages, include_users)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,22 @@
+use revolt_quark::{
+    models::{stats::ServerStats, User},
+    Db, Error, Ref, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Server Statistics
+///
+/// Fetch message, member and emoji usage statistics for a server you own.
+///
+/// Statistics are re-computed periodically, check `generated_at` for when they were last updated.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/stats")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<ServerStats>> {
+    let server = target.as_server(db).await?;
+    if server.owner != user.id {
+        return Err(Error::NotOwner);
+    }
+
+    db.fetch_server_stats(&server.id).await.map(Json)
+}
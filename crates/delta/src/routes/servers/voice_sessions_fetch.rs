@@ -0,0 +1,37 @@
+use revolt_database::Database;
+use revolt_models::v0::VoiceSession;
+use revolt_quark::{models::User, Db, Error, Ref, Result};
+use rocket::{serde::json::Json, State};
+use serde::Deserialize;
+
+/// # Query Parameters
+#[derive(Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchVoiceSessions {
+    /// Only include sessions of this user
+    user: Option<String>,
+}
+
+/// # Fetch Voice Sessions
+///
+/// Fetch voice sessions recorded in a server you own, oldest first.
+///
+/// Sessions are only recorded while analytics are enabled on the server.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/voice_sessions?<options..>")]
+pub async fn req(
+    db: &State<Database>,
+    legacy_db: &Db,
+    user: User,
+    target: Ref,
+    options: OptionsFetchVoiceSessions,
+) -> Result<Json<Vec<VoiceSession>>> {
+    let server = target.as_server(legacy_db).await?;
+    if server.owner != user.id {
+        return Err(Error::NotOwner);
+    }
+
+    db.fetch_voice_sessions(&server.id, options.user.as_deref())
+        .await
+        .map(|sessions| Json(sessions.into_iter().map(Into::into).collect()))
+        .map_err(Error::from_core)
+}
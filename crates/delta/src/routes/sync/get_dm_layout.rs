@@ -0,0 +1,15 @@
+use revolt_quark::{
+    models::{user_settings::DmLayout, User},
+    Db, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Fetch DM Layout
+///
+/// Fetch the pinned conversations and folders of the user's DM list.
+#[openapi(tag = "Sync")]
+#[get("/settings/dm_layout")]
+pub async fn req(db: &Db, user: User) -> Result<Json<DmLayout>> {
+    db.fetch_dm_layout(&user.id).await.map(Json)
+}
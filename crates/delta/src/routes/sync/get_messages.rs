@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use revolt_quark::{
+    models::{message::ChannelMessageSync, Channel, Member, Server, User},
+    perms, Db, Error, Permission, Result,
+};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsSyncMessages {
+    /// Id of the last message the client knows about
+    #[validate(length(min = 26, max = 26))]
+    since: String,
+    /// Maximum number of message ids to include per channel
+    #[validate(range(min = 0, max = 100))]
+    limit: Option<i64>,
+}
+
+/// Check whether a channel may have messages sent after the given id
+fn has_new_messages(channel: &Channel, since: &str) -> bool {
+    match channel {
+        Channel::DirectMessage {
+            last_message_id, ..
+        }
+        | Channel::Group {
+            last_message_id, ..
+        }
+        | Channel::TextChannel {
+            last_message_id, ..
+        }
+        | Channel::VoiceChannel {
+            last_message_id, ..
+        } => last_message_id
+            .as_deref()
+            .map_or(false, |last_message_id| last_message_id > since),
+        Channel::SavedMessages { .. } => true,
+    }
+}
+
+/// # Sync Messages
+///
+/// Fetch how many messages were sent in each channel you can see after a given message,
+/// along with the ids of the most recent ones.
+///
+/// Channels without any new messages are left out.
+#[openapi(tag = "Sync")]
+#[get("/messages?<options..>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    options: OptionsSyncMessages,
+) -> Result<Json<Vec<ChannelMessageSync>>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if Ulid::from_string(&options.since).is_err() {
+        return Err(Error::InvalidProperty);
+    }
+
+    // Direct messages and groups are visible to all of their recipients
+    let mut channel_ids: Vec<String> = db
+        .find_direct_messages(&user.id)
+        .await?
+        .iter()
+        .filter(|channel| has_new_messages(channel, &options.since))
+        .map(|channel| channel.id().to_string())
+        .collect();
+
+    // Server channels need to be checked against our permissions
+    let members = db.fetch_all_memberships(&user.id).await?;
+    let server_ids: Vec<String> = members
+        .iter()
+        .map(|member| member.id.server.clone())
+        .collect();
+
+    let servers = db.fetch_servers(&server_ids).await?;
+    let server_channel_ids: Vec<String> = servers
+        .iter()
+        .flat_map(|server| server.channels.clone())
+        .collect();
+
+    let members: HashMap<&str, &Member> = members
+        .iter()
+        .map(|member| (member.id.server.as_str(), member))
+        .collect();
+
+    let servers: HashMap<&str, &Server> = servers
+        .iter()
+        .map(|server| (server.id.as_str(), server))
+        .collect();
+
+    for channel in db.fetch_channels(&server_channel_ids).await? {
+        if !has_new_messages(&channel, &options.since) {
+            continue;
+        }
+
+        let server_id = match &channel {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => server,
+            _ => continue,
+        };
+
+        if let (Some(server), Some(member)) = (
+            servers.get(server_id.as_str()),
+            members.get(server_id.as_str()),
+        ) {
+            if perms(&user)
+                .channel(&channel)
+                .server(server)
+                .member(member)
+                .has_permission(db, Permission::ViewChannel)
+                .await?
+            {
+                channel_ids.push(channel.as_id());
+            }
+        }
+    }
+
+    if channel_ids.is_empty() {
+        return Ok(Json(vec![]));
+    }
+
+    db.fetch_message_sync(&channel_ids, &options.since, options.limit.unwrap_or(10))
+        .await
+        .map(Json)
+}
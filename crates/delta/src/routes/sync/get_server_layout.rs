@@ -0,0 +1,15 @@
+use revolt_quark::{
+    models::{user_settings::ServerLayout, User},
+    Db, Result,
+};
+
+use rocket::serde::json::Json;
+
+/// # Fetch Server Layout
+///
+/// Fetch the ordering and folders of the user's server list.
+#[openapi(tag = "Sync")]
+#[get("/settings/server_layout")]
+pub async fn req(db: &Db, user: User) -> Result<Json<ServerLayout>> {
+    db.fetch_server_layout(&user.id).await.map(Json)
+}
@@ -1,10 +1,24 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod get_dm_layout;
+mod get_messages;
+mod get_server_layout;
 mod get_settings;
 mod get_unreads;
+mod set_dm_layout;
+mod set_server_layout;
 mod set_settings;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![get_settings::req, set_settings::req, get_unreads::req]
+    openapi_get_routes_spec![
+        get_settings::req,
+        set_settings::req,
+        get_unreads::req,
+        get_messages::req,
+        get_server_layout::req,
+        set_server_layout::req,
+        get_dm_layout::req,
+        set_dm_layout::req
+    ]
 }
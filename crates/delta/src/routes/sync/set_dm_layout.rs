@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use revolt_quark::{
+    models::{
+        user_settings::{DmFolder, DmLayout},
+        User,
+    },
+    Db, Error, Result,
+};
+
+use chrono::prelude::*;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # DM Layout Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataDmLayout {
+    /// Ids of conversations pinned to the top of the list, in order
+    #[validate(length(max = 100))]
+    pinned: Vec<String>,
+    /// Folders in the DM list, in order
+    #[validate(length(max = 200))]
+    #[validate]
+    folders: Vec<DmFolder>,
+}
+
+/// # Set Options
+#[derive(FromForm, Serialize, Deserialize, JsonSchema)]
+pub struct OptionsSetDmLayout {
+    /// Timestamp of layout change.
+    ///
+    /// Used to avoid feedback loops.
+    timestamp: Option<i64>,
+}
+
+/// # Set DM Layout
+///
+/// Replace the pinned conversations and folders of the user's DM list.
+///
+/// If the layout has been changed since the given timestamp, the newer layout is kept and returned instead.
+#[openapi(tag = "Sync")]
+#[put("/settings/dm_layout?<options..>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    data: Json<DataDmLayout>,
+    options: OptionsSetDmLayout,
+) -> Result<Json<DmLayout>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let current_time = Utc::now().timestamp_millis();
+    let timestamp = options
+        .timestamp
+        .map(|timestamp| timestamp.min(current_time))
+        .unwrap_or(current_time);
+
+    let current = db.fetch_dm_layout(&user.id).await?;
+    if current.timestamp > timestamp {
+        return Ok(Json(current));
+    }
+
+    // Each folder may only appear once
+    let mut folder_ids = HashSet::new();
+    for folder in &data.folders {
+        if !folder_ids.insert(&folder.id) {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    // Each conversation may only be pinned or placed in a single folder
+    let mut seen = HashSet::new();
+    for id in data
+        .folders
+        .iter()
+        .flat_map(|folder| folder.channels.iter())
+        .chain(data.pinned.iter())
+    {
+        if !seen.insert(id) {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    let layout = DmLayout {
+        id: user.id,
+        pinned: data.pinned,
+        folders: data.folders,
+        timestamp,
+    };
+
+    layout.clone().set(db).await?;
+    Ok(Json(layout))
+}
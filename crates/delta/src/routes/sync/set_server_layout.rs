@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use revolt_quark::{
+    models::{
+        user_settings::{ServerFolder, ServerLayout},
+        User,
+    },
+    Db, Error, Result,
+};
+
+use chrono::prelude::*;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Server Layout Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataServerLayout {
+    /// Ids of top-level servers and folders, in order
+    #[validate(length(max = 1000))]
+    order: Vec<String>,
+    /// Folders in the server list
+    #[validate(length(max = 200))]
+    #[validate]
+    folders: Vec<ServerFolder>,
+}
+
+/// # Set Options
+#[derive(FromForm, Serialize, Deserialize, JsonSchema)]
+pub struct OptionsSetServerLayout {
+    /// Timestamp of layout change.
+    ///
+    /// Used to avoid feedback loops.
+    timestamp: Option<i64>,
+}
+
+/// # Set Server Layout
+///
+/// Replace the ordering and folders of the user's server list.
+///
+/// If the layout has been changed since the given timestamp, the newer layout is kept and returned instead.
+#[openapi(tag = "Sync")]
+#[put("/settings/server_layout?<options..>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    data: Json<DataServerLayout>,
+    options: OptionsSetServerLayout,
+) -> Result<Json<ServerLayout>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let current_time = Utc::now().timestamp_millis();
+    let timestamp = options
+        .timestamp
+        .map(|timestamp| timestamp.min(current_time))
+        .unwrap_or(current_time);
+
+    let current = db.fetch_server_layout(&user.id).await?;
+    if current.timestamp > timestamp {
+        return Ok(Json(current));
+    }
+
+    // Each folder and server may only appear once
+    let mut seen = HashSet::new();
+    for id in data
+        .folders
+        .iter()
+        .flat_map(|folder| std::iter::once(&folder.id).chain(folder.servers.iter()))
+    {
+        if !seen.insert(id) {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    let folder_ids: HashSet<&String> = data.folders.iter().map(|folder| &folder.id).collect();
+    let mut top_level = HashSet::new();
+    for id in &data.order {
+        if !top_level.insert(id) || (seen.contains(id) && !folder_ids.contains(id)) {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    let layout = ServerLayout {
+        id: user.id,
+        order: data.order,
+        folders: data.folders,
+        timestamp,
+    };
+
+    layout.clone().set(db).await?;
+    Ok(Json(layout))
+}
@@ -16,6 +16,10 @@ pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<U
         return Err(Error::IsBot);
     }
 
+    if user.guest.is_some() || target.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
     user.add_friend(db, &mut target).await?;
     Ok(Json(target.with_auto_perspective(db, &user).await))
 }
@@ -0,0 +1,79 @@
+use revolt_database::{Database, SessionScopes};
+use revolt_models::v0::{DataCreateScopedSession, ScopedSession};
+use revolt_quark::{
+    authifier::{models::Session, Authifier},
+    models::User,
+    Error, Result,
+};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Restricted Session
+///
+/// Create a new login with restrictions on what it may do,
+/// such as for a public kiosk or an untrusted device.
+///
+/// Restricted sessions cannot create further sessions.
+#[openapi(tag = "Session")]
+#[post("/@me/sessions", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    authifier: &State<Authifier>,
+    user: User,
+    data: Json<DataCreateScopedSession>,
+) -> Result<Json<ScopedSession>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
+    // Restricted sessions must not be able to mint unrestricted ones
+    if !user.scopes.is_empty() {
+        return Err(Error::SessionRestricted);
+    }
+
+    let session = Session {
+        id: Ulid::new().to_string(),
+        token: nanoid::nanoid!(64),
+        user_id: user.id.clone(),
+        name: data.name,
+        subscription: None,
+    };
+
+    // Restrictions must be in place before the session can be used
+    let scopes = SessionScopes {
+        id: session.id.clone(),
+        user_id: user.id,
+        scopes: data.scopes.into_iter().map(|scope| scope.into()).collect(),
+    };
+
+    db.insert_session_scopes(&scopes)
+        .await
+        .map_err(Error::from_core)?;
+
+    session
+        .save(authifier)
+        .await
+        .map_err(|_| Error::DatabaseError {
+            operation: "save",
+            with: "session",
+        })?;
+
+    Ok(Json(ScopedSession {
+        id: session.id,
+        token: session.token,
+        scopes: scopes
+            .scopes
+            .into_iter()
+            .map(|scope| scope.into())
+            .collect(),
+    }))
+}
@@ -0,0 +1,37 @@
+use revolt_quark::models::user::Presence;
+use revolt_quark::models::User;
+use revolt_quark::{Db, Error, Result};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Presence Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataEditPresence {
+    /// New presence, one of Online, Idle, Busy or Invisible
+    presence: Option<Presence>,
+    /// Activity to show in member lists
+    ///
+    /// Pass an empty string to clear the current activity.
+    #[validate(length(min = 0, max = 128))]
+    activity: Option<String>,
+}
+
+/// # Edit Bot Presence
+///
+/// Set the presence and activity of the currently authenticated bot.
+///
+/// This can also be done over the events socket by sending an `UpdatePresence` message.
+#[openapi(tag = "User Information")]
+#[patch("/@me/presence", data = "<data>")]
+pub async fn req(db: &Db, mut user: User, data: Json<DataEditPresence>) -> Result<Json<User>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    user.set_bot_presence(db, data.presence, data.activity)
+        .await?;
+
+    Ok(Json(user.foreign()))
+}
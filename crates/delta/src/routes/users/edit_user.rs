@@ -1,11 +1,17 @@
-use revolt_quark::models::user::{FieldsUser, PartialUser, User};
+use revolt_database::{AdminAuditEntry, Database};
+use revolt_quark::models::user::{
+    DirectMessageMediaFilter, FederatedMessagePolicy, FieldsUser, PartialUser, ProfileLink, User,
+};
 use revolt_quark::models::File;
-use revolt_quark::{Database, Error, Ref, Result};
+use revolt_quark::util::i18n;
+use revolt_quark::{Db, Error, Ref, Result};
 
-use revolt_quark::models::user::UserStatus;
+use revolt_quark::models::user::{UserBadgesValue, UserFlagsValue, UserStatus};
 use rocket::serde::json::Json;
 use rocket::State;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use url::Url;
 use validator::Validate;
 
 /// # Profile Data
@@ -19,6 +25,26 @@ pub struct UserProfileData {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(length(min = 1, max = 128))]
     background: Option<String>,
+    /// URL of personal website
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url, length(min = 1, max = 256))]
+    website: Option<String>,
+    /// URL of donation or tipping page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url, length(min = 1, max = 256))]
+    donation: Option<String>,
+}
+
+/// Create a profile link, keeping verification if the URL is unchanged
+fn profile_link(existing: Option<ProfileLink>, url: String) -> Result<ProfileLink> {
+    if !Url::parse(&url).map_or(false, |url| matches!(url.scheme(), "http" | "https")) {
+        return Err(Error::InvalidProperty);
+    }
+
+    Ok(ProfileLink {
+        verified: existing.map_or(false, |link| link.url == url && link.verified),
+        url,
+    })
 }
 
 /// # User Data
@@ -36,13 +62,23 @@ pub struct DataEditUser {
     /// This is applied as a partial.
     #[validate]
     profile: Option<UserProfileData>,
+    /// Preferred locale for server-generated text
+    #[validate(length(min = 2, max = 16))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+    /// Handling of attachments and links in direct messages from users who are not friends
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dm_media_filter: Option<DirectMessageMediaFilter>,
+    /// Who may send direct messages from other instances
+    #[serde(skip_serializing_if = "Option::is_none")]
+    federated_dms: Option<FederatedMessagePolicy>,
 
     /// Bitfield of user badges
     #[serde(skip_serializing_if = "Option::is_none")]
-    badges: Option<i32>,
+    badges: Option<UserBadgesValue>,
     /// Enum of user flags
     #[serde(skip_serializing_if = "Option::is_none")]
-    flags: Option<i32>,
+    flags: Option<UserFlagsValue>,
 
     /// Fields to remove from user object
     #[validate(length(min = 1))]
@@ -56,6 +92,7 @@ pub struct DataEditUser {
 #[patch("/<target>", data = "<data>")]
 pub async fn req(
     db: &State<Database>,
+    legacy_db: &Db,
     mut user: User,
     target: Ref,
     data: Json<DataEditUser>,
@@ -67,7 +104,7 @@ pub async fn req(
     // If we want to edit a different user than self, ensure we have
     // permissions and subsequently replace the user in question
     if target.id != "@me" && target.id != user.id {
-        let target_user = target.as_user(db).await?;
+        let target_user = target.as_user(legacy_db).await?;
         let is_bot_owner = target_user
             .bot
             .map(|bot| bot.owner == user.id)
@@ -89,6 +126,9 @@ pub async fn req(
         && data.avatar.is_none()
         && data.badges.is_none()
         && data.flags.is_none()
+        && data.locale.is_none()
+        && data.dm_media_filter.is_none()
+        && data.federated_dms.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(user));
@@ -98,14 +138,14 @@ pub async fn req(
     if let Some(fields) = &data.remove {
         if fields.contains(&FieldsUser::Avatar) {
             if let Some(avatar) = &user.avatar {
-                db.mark_attachment_as_deleted(&avatar.id).await?;
+                legacy_db.mark_attachment_as_deleted(&avatar.id).await?;
             }
         }
 
         if fields.contains(&FieldsUser::ProfileBackground) {
             if let Some(profile) = &user.profile {
                 if let Some(background) = &profile.background {
-                    db.mark_attachment_as_deleted(&background.id).await?;
+                    legacy_db.mark_attachment_as_deleted(&background.id).await?;
                 }
             }
         }
@@ -115,15 +155,25 @@ pub async fn req(
         }
     }
 
+    // Only accept locales we have translations for
+    if let Some(locale) = &data.locale {
+        if !i18n::is_supported(locale) {
+            return Err(Error::InvalidProperty);
+        }
+    }
+
     let mut partial: PartialUser = PartialUser {
         badges: data.badges,
         flags: data.flags,
+        locale: data.locale,
+        dm_media_filter: data.dm_media_filter,
+        federated_dms: data.federated_dms,
         ..Default::default()
     };
 
     // 2. Apply new avatar
     if let Some(avatar) = data.avatar {
-        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id).await?);
+        partial.avatar = Some(File::use_avatar(legacy_db, &avatar, &user.id).await?);
     }
 
     // 3. Apply new status
@@ -148,14 +198,44 @@ pub async fn req(
         }
 
         if let Some(background) = profile.background {
-            new_profile.background = Some(File::use_background(db, &background, &user.id).await?);
+            new_profile.background =
+                Some(File::use_background(legacy_db, &background, &user.id).await?);
+        }
+
+        if let Some(website) = profile.website {
+            new_profile.website = Some(profile_link(new_profile.website.take(), website)?);
+        }
+
+        if let Some(donation) = profile.donation {
+            new_profile.donation = Some(profile_link(new_profile.donation.take(), donation)?);
         }
 
         partial.profile = Some(new_profile);
     }
 
-    user.update(db, partial, data.remove.unwrap_or_default())
+    let privileged_edit = partial.badges.is_some() || partial.flags.is_some();
+    let before = json!({ "badges": user.badges, "flags": user.flags });
+
+    user.update(legacy_db, partial, data.remove.unwrap_or_default())
         .await?;
 
-    Ok(Json(user.foreign()))
+    if privileged_edit {
+        AdminAuditEntry::record(
+            db,
+            &user.id,
+            "user.edit",
+            &user.id,
+            Some(&before),
+            Some(&json!({ "badges": user.badges, "flags": user.flags })),
+        )
+        .await
+        .map_err(Error::from_core)?;
+    }
+
+    Ok(Json(User {
+        locale: user.locale.clone(),
+        dm_media_filter: user.dm_media_filter,
+        federated_dms: user.federated_dms,
+        ..user.foreign()
+    }))
 }
@@ -1,5 +1,5 @@
 use revolt_quark::{
-    models::{Channel, User},
+    models::{user::SessionScope, Channel, User},
     Database, Result,
 };
 
@@ -8,8 +8,15 @@ use rocket::{serde::json::Json, State};
 /// # Fetch Direct Message Channels
 ///
 /// This fetches your direct messages, including any DM and group DM conversations.
+///
+/// Sessions without access to direct messages only receive their saved messages.
 #[openapi(tag = "Direct Messaging")]
 #[get("/dms")]
 pub async fn req(db: &State<Database>, user: User) -> Result<Json<Vec<Channel>>> {
-    db.find_direct_messages(&user.id).await.map(Json)
+    let mut channels = db.find_direct_messages(&user.id).await?;
+    if user.scopes.contains(&SessionScope::NoDirectMessages) {
+        channels.retain(|channel| matches!(channel, Channel::SavedMessages { .. }));
+    }
+
+    Ok(Json(channels))
 }
@@ -9,5 +9,10 @@ use rocket::serde::json::Json;
 #[openapi(tag = "User Information")]
 #[get("/@me")]
 pub async fn req(user: User) -> Result<Json<User>> {
-    Ok(Json(user.foreign()))
+    Ok(Json(User {
+        locale: user.locale.clone(),
+        dm_media_filter: user.dm_media_filter,
+        federated_dms: user.federated_dms,
+        ..user.foreign()
+    }))
 }
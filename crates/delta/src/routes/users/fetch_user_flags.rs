@@ -1,3 +1,4 @@
+use revolt_quark::models::user::UserFlagsValue;
 use revolt_quark::{Database, Ref, Result};
 
 use rocket::{serde::json::Json, State};
@@ -7,7 +8,7 @@ use serde::Serialize;
 #[derive(Serialize, JsonSchema)]
 pub struct FlagResponse {
     /// Flags
-    flags: i32,
+    flags: UserFlagsValue,
 }
 
 /// # Fetch User Flags
@@ -19,7 +20,7 @@ pub async fn fetch_user_flags(db: &State<Database>, target: Ref) -> Result<Json<
     let flags = if let Ok(target) = target.as_user(db).await {
         target.flags.unwrap_or_default()
     } else {
-        0
+        Default::default()
     };
 
     Ok(Json(FlagResponse { flags }))
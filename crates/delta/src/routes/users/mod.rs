@@ -4,6 +4,8 @@ use rocket::Route;
 mod add_friend;
 mod block_user;
 mod change_username;
+mod create_session;
+mod edit_presence;
 mod edit_user;
 mod fetch_dms;
 mod fetch_profile;
@@ -16,6 +18,7 @@ mod open_dm;
 mod remove_friend;
 mod send_friend_request;
 mod unblock_user;
+mod verify_profile_links;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -24,9 +27,13 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         fetch_user::req,
         fetch_user_flags::fetch_user_flags,
         edit_user::req,
+        edit_presence::req,
         change_username::req,
         get_default_avatar::req,
         fetch_profile::req,
+        verify_profile_links::req,
+        // Sessions
+        create_session::req,
         // Direct Messaging
         fetch_dms::req,
         open_dm::req,
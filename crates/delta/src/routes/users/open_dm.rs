@@ -1,11 +1,14 @@
 use revolt_quark::{
-    models::{Channel, User},
+    models::{user::SessionScope, Channel, User},
     perms, Database, Error, Ref, Result, UserPermission,
 };
 
+use revolt_models::v0::ChallengeReason;
 use rocket::{serde::json::Json, State};
 use ulid::Ulid;
 
+use crate::util::challenges::{self, ChallengeSolution};
+
 /// # Open Direct Message
 ///
 /// Open a DM with another user.
@@ -13,7 +16,16 @@ use ulid::Ulid;
 /// If the target is oneself, a saved messages channel is returned.
 #[openapi(tag = "Direct Messaging")]
 #[get("/<target>/dm")]
-pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<Channel>> {
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Ref,
+    solution: ChallengeSolution,
+) -> Result<Json<Channel>> {
+    if user.guest.is_some() {
+        return Err(Error::IsGuest);
+    }
+
     let target = target.as_user(db).await?;
 
     // If the target is oneself, open saved messages.
@@ -31,6 +43,10 @@ pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<C
         };
     }
 
+    if user.scopes.contains(&SessionScope::NoDirectMessages) {
+        return Err(Error::SessionRestricted);
+    }
+
     // Otherwise try to find or create a DM.
     if let Ok(channel) = db.find_direct_message_channel(&user.id, &target.id).await {
         Ok(Json(channel))
@@ -40,6 +56,8 @@ pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<C
         .await
         .get_send_message()
     {
+        challenges::assert_passed(&user, ChallengeReason::MassDirectMessage, &solution).await?;
+
         let new_channel = Channel::DirectMessage {
             id: Ulid::new().to_string(),
             active: false,
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use revolt_quark::{
+    models::{
+        user::{PartialUser, ProfileLink, UserProfile},
+        User,
+    },
+    variables::delta::APP_URL,
+    Database, Error, Result,
+};
+
+use rocket::{serde::json::Json, State};
+
+use crate::util::regex::{RE_HTML_ATTRIBUTE, RE_HTML_LINK_TAG};
+
+/// Check whether a HTML document links to the given URL with `rel="me"`
+fn links_back(document: &str, profile_url: &str) -> bool {
+    RE_HTML_LINK_TAG.find_iter(document).any(|tag| {
+        let mut rel_me = false;
+        let mut href_matches = false;
+
+        for attribute in RE_HTML_ATTRIBUTE.captures_iter(tag.as_str()) {
+            let value = attribute
+                .get(2)
+                .or_else(|| attribute.get(3))
+                .or_else(|| attribute.get(4))
+                .map_or("", |value| value.as_str());
+
+            match attribute[1].to_lowercase().as_str() {
+                "rel" => {
+                    rel_me = value
+                        .split_whitespace()
+                        .any(|token| token.eq_ignore_ascii_case("me"))
+                }
+                "href" => href_matches = value.trim_end_matches('/') == profile_url,
+                _ => {}
+            }
+        }
+
+        rel_me && href_matches
+    })
+}
+
+/// Fetch the linked page and check whether it links back to the profile
+async fn verify(client: &reqwest::Client, link: &mut ProfileLink, profile_url: &str) {
+    if link.verified {
+        return;
+    }
+
+    match client.get(&link.url).send().await {
+        Ok(response) => match response.text().await {
+            Ok(document) => link.verified = links_back(&document, profile_url),
+            Err(err) => log::warn!("Failed to read profile link {}: {err:?}", link.url),
+        },
+        Err(err) => log::warn!("Failed to fetch profile link {}: {err:?}", link.url),
+    }
+}
+
+/// # Verify Profile Links
+///
+/// Check whether the website and donation links on your profile link back to it.
+///
+/// A link is verified if the linked page contains an `a` or `link` tag with
+/// `rel="me"` pointing to your profile on this instance.
+#[openapi(tag = "User Information")]
+#[post("/@me/profile/verify")]
+pub async fn req(db: &State<Database>, mut user: User) -> Result<Json<UserProfile>> {
+    let mut profile = user.profile.clone().unwrap_or_default();
+    if profile.website.is_none() && profile.donation.is_none() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|_| Error::InternalError)?;
+
+    let profile_url = format!("{}/user/{}", *APP_URL, user.id);
+    let profile_url = profile_url.trim_end_matches('/');
+
+    if let Some(website) = profile.website.as_mut() {
+        verify(&client, website, profile_url).await;
+    }
+
+    if let Some(donation) = profile.donation.as_mut() {
+        verify(&client, donation, profile_url).await;
+    }
+
+    user.update(
+        db,
+        PartialUser {
+            profile: Some(profile.clone()),
+            ..Default::default()
+        },
+        vec![],
+    )
+    .await?;
+
+    Ok(Json(profile))
+}
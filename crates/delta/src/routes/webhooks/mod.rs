@@ -9,6 +9,7 @@ mod webhook_execute;
 mod webhook_fetch_token;
 mod webhook_fetch;
 mod webhook_execute_github;
+mod webhook_execute_email;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -16,6 +17,7 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         webhook_delete::webhook_delete,
         webhook_edit_token::webhook_edit_token,
         webhook_edit::webhook_edit,
+        webhook_execute_email::webhook_execute_email,
         webhook_execute_github::webhook_execute_github,
         webhook_execute::webhook_execute,
         webhook_fetch_token::webhook_fetch_token,
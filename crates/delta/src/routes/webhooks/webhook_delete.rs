@@ -1,8 +1,13 @@
-use revolt_database::{util::reference::Reference, Database};
-use revolt_quark::{models::User, perms, Db, Error, Permission, Result};
+use revolt_database::{util::reference::Reference, AuditLogAction, Database, Webhook};
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, Error, Permission, Result,
+};
 use rocket::State;
 use rocket_empty::EmptyResponse;
 
+use crate::util::audit_log;
+
 /// # Deletes a webhook
 ///
 /// Deletes a webhook
@@ -22,6 +27,22 @@ pub async fn webhook_delete(
         .throw_permission(legacy_db, Permission::ManageWebhooks)
         .await?;
 
+    if let Channel::TextChannel { server, .. } = &channel {
+        audit_log::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::WebhookDelete,
+            &webhook.id,
+            Some(&Webhook {
+                token: None,
+                ..webhook.clone()
+            }),
+            None,
+        )
+        .await;
+    }
+
     webhook
         .delete(db)
         .await
@@ -1,5 +1,5 @@
 use revolt_database::{util::reference::Reference, Database};
-use revolt_result::Result;
+use revolt_quark::Result;
 use rocket::State;
 use rocket_empty::EmptyResponse;
 
@@ -15,5 +15,6 @@ pub async fn webhook_delete_token(
 ) -> Result<EmptyResponse> {
     let webhook = webhook_id.as_webhook(db).await?;
     webhook.assert_token(&token)?;
-    webhook.delete(db).await.map(|_| EmptyResponse)
+    webhook.delete(db).await?;
+    Ok(EmptyResponse)
 }
@@ -1,9 +1,14 @@
-use revolt_database::{util::reference::Reference, Database, PartialWebhook};
+use revolt_database::{util::reference::Reference, AuditLogAction, Database, PartialWebhook};
 use revolt_models::v0::{DataEditWebhook, Webhook};
-use revolt_quark::{models::User, perms, Db, Error, Permission, Result};
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, Error, Permission, Result,
+};
 use rocket::{serde::json::Json, State};
 use validator::Validate;
 
+use crate::util::audit_log;
+
 /// # Edits a webhook
 ///
 /// Edits a webhook
@@ -28,18 +33,24 @@ pub async fn webhook_edit(
         .throw_permission(legacy_db, Permission::ManageWebhooks)
         .await?;
 
-    if data.name.is_none() && data.avatar.is_none() && data.remove.is_empty() {
+    if data.name.is_none()
+        && data.avatar.is_none()
+        && data.limits.is_none()
+        && data.remove.is_empty()
+    {
         return Ok(Json(webhook.into()));
     };
 
     let DataEditWebhook {
         name,
         avatar,
+        limits,
         remove,
     } = data;
 
     let mut partial = PartialWebhook {
         name,
+        limits: limits.map(|limits| limits.into()),
         ..Default::default()
     };
 
@@ -52,10 +63,31 @@ pub async fn webhook_edit(
         partial.avatar = Some(file)
     }
 
+    let before = revolt_database::Webhook {
+        token: None,
+        ..webhook.clone()
+    };
+
     webhook
         .update(db, partial, remove.into_iter().map(|v| v.into()).collect())
         .await
         .map_err(Error::from_core)?;
 
+    if let Channel::TextChannel { server, .. } = &channel {
+        audit_log::record(
+            db,
+            server,
+            &user.id,
+            AuditLogAction::WebhookUpdate,
+            &webhook.id,
+            Some(&before),
+            Some(&revolt_database::Webhook {
+                token: None,
+                ..webhook.clone()
+            }),
+        )
+        .await;
+    }
+
     Ok(Json(webhook.into()))
 }
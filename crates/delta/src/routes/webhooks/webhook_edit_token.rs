@@ -1,8 +1,8 @@
 use revolt_database::util::reference::Reference;
 use revolt_database::{Database, PartialWebhook};
-use revolt_models::v0::{DataEditWebhook, Webhook};
+use revolt_models::v0::{DataEditWebhook, FieldsWebhook, Webhook};
 use revolt_models::validator::Validate;
-use revolt_result::{create_error, Result};
+use revolt_quark::{Error, Result};
 use rocket::{serde::json::Json, State};
 
 /// # Edits a webhook
@@ -17,15 +17,17 @@ pub async fn webhook_edit_token(
     data: Json<DataEditWebhook>,
 ) -> Result<Json<Webhook>> {
     let data = data.into_inner();
-    data.validate().map_err(|error| {
-        create_error!(FailedValidation {
-            error: error.to_string()
-        })
-    })?;
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
 
     let mut webhook = webhook_id.as_webhook(db).await?;
     webhook.assert_token(&token)?;
 
+    // Limits are set by channel managers and cannot be lifted with the token
+    if data.limits.is_some() || data.remove.contains(&FieldsWebhook::Limits) {
+        return Err(Error::NotPrivileged);
+    }
+
     if data.name.is_none() && data.avatar.is_none() && data.remove.is_empty() {
         return Ok(Json(webhook.into()));
     };
@@ -34,6 +36,7 @@ pub async fn webhook_edit_token(
         name,
         avatar,
         remove,
+        ..
     } = data;
 
     let mut partial = PartialWebhook {
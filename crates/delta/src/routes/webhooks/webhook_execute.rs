@@ -9,9 +9,13 @@ use rocket::{serde::json::Json, State};
 
 use validator::Validate;
 
+use crate::util::webhook_limits;
+
 /// # Executes a webhook
 ///
 /// Executes a webhook and sends a message
+///
+/// Fails with `TooManyRequests` once the webhook's message limits have been reached.
 #[openapi(tag = "Webhooks")]
 #[post("/<webhook_id>/<token>", data = "<data>")]
 pub async fn webhook_execute(
@@ -29,6 +33,13 @@ pub async fn webhook_execute(
     let webhook = webhook_id.as_webhook(db).await.map_err(Error::from_core)?;
     webhook.assert_token(&token).map_err(Error::from_core)?;
 
+    // Webhooks cannot post in threads or schedule messages
+    if data.thread.is_some() || data.send_at.is_some() {
+        return Err(Error::InvalidOperation);
+    }
+
+    webhook_limits::consume(&webhook).await?;
+
     // TODO: webhooks can currently always send masquerades, files, embeds, reactions (interactions)
     // TODO: they can also mention anyone
 
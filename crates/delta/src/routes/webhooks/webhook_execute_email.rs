@@ -0,0 +1,196 @@
+use revolt_database::Database;
+use revolt_models::v0::DataInboundEmail;
+use revolt_quark::{
+    models::message::{DataMessageSend, Masquerade, Message},
+    types::push::MessageAuthor,
+    variables::delta::{INBOUND_EMAIL_SECRET, MAX_ATTACHMENT_COUNT, USE_INBOUND_EMAIL},
+    web::idempotency::IdempotencyKey,
+    Db, Error, Result,
+};
+use revolt_rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+    revolt_okapi::openapi3::{Parameter, ParameterValue},
+};
+use ring::constant_time::verify_slices_are_equal;
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    serde::json::Json,
+    Request, State,
+};
+use schemars::schema::{InstanceType, SchemaObject, SingleOrVec};
+use validator::Validate;
+
+use crate::util::webhook_limits;
+
+/// # Receive Email
+///
+/// Posts an email received by the mail relay to every channel it was addressed to.
+///
+/// Recipients which can't be delivered to are skipped.
+#[openapi(tag = "Webhooks")]
+#[post("/email", data = "<data>")]
+pub async fn webhook_execute_email(
+    db: &State<Database>,
+    legacy_db: &Db,
+    secret: InboundEmailSecret<'_>,
+    data: Json<DataInboundEmail>,
+) -> Result<Json<Vec<Message>>> {
+    if !*USE_INBOUND_EMAIL {
+        return Err(Error::InvalidOperation);
+    }
+
+    if verify_slices_are_equal(secret.0.as_bytes(), INBOUND_EMAIL_SECRET.as_bytes()).is_err() {
+        return Err(Error::InvalidCredentials);
+    }
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // Build the message content from the subject and body
+    let content: String = match &data.subject {
+        Some(subject) if !subject.is_empty() => format!("**{subject}**\n{}", data.text),
+        _ => data.text.clone(),
+    }
+    .chars()
+    .take(2000)
+    .collect();
+
+    let sender: String = data
+        .from_name
+        .as_ref()
+        .unwrap_or(&data.from)
+        .chars()
+        .take(32)
+        .collect();
+
+    // Attachments can only be claimed by one message, so they go to the first inbox
+    let mut attachments = Some(
+        data.attachments
+            .into_iter()
+            .take(*MAX_ATTACHMENT_COUNT)
+            .collect::<Vec<String>>(),
+    )
+    .filter(|v| !v.is_empty());
+
+    let mut messages = vec![];
+    for address in data.to {
+        let address = address.to_lowercase();
+        let Some((local, _)) = address.split_once('@') else {
+            continue;
+        };
+        let Ok(inbox) = db.fetch_channel_inbox(local).await else {
+            continue;
+        };
+        if inbox.address != address {
+            continue;
+        }
+
+        let webhook = match db.fetch_webhook(&inbox.webhook_id).await {
+            Ok(webhook) => webhook,
+            Err(err) => {
+                log::warn!("Failed to fetch webhook for inbox {}: {err:?}", inbox.id);
+                continue;
+            }
+        };
+
+        // Drop mail for inboxes which have used up their limits
+        if webhook_limits::consume(&webhook).await.is_err() {
+            continue;
+        }
+
+        let channel = match legacy_db.fetch_channel(&inbox.channel_id).await {
+            Ok(channel) => channel,
+            Err(err) => {
+                log::warn!("Failed to fetch channel for inbox {}: {err:?}", inbox.id);
+                continue;
+            }
+        };
+
+        let message = match channel
+            .send_message(
+                legacy_db,
+                DataMessageSend {
+                    nonce: None,
+                    content: Some(content.clone()),
+                    attachments: attachments.take(),
+                    replies: None,
+                    embeds: None,
+                    masquerade: Some(Masquerade {
+                        name: Some(sender.clone()),
+                        avatar: None,
+                        colour: None,
+                    }),
+                    interactions: None,
+                    effect: None,
+                    thread: None,
+                    send_at: None,
+                },
+                MessageAuthor::Webhook(&webhook.into()),
+                IdempotencyKey::default(),
+                false,
+            )
+            .await
+        {
+            Ok(message) => message,
+            Err(err) => {
+                log::warn!("Failed to deliver email to inbox {}: {err:?}", inbox.id);
+                continue;
+            }
+        };
+
+        messages.push(message);
+    }
+
+    if messages.is_empty() {
+        return Err(Error::NotFound);
+    }
+
+    Ok(Json(messages))
+}
+
+/// Shared secret sent by the mail relay in the `X-Inbound-Email-Secret` header
+pub struct InboundEmailSecret<'r>(&'r str);
+
+#[async_trait]
+impl<'r> FromRequest<'r> for InboundEmailSecret<'r> {
+    type Error = Error;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.headers().get_one("X-Inbound-Email-Secret") {
+            Some(secret) => Outcome::Success(Self(secret)),
+            None => Outcome::Failure((Status::Unauthorized, Error::InvalidCredentials)),
+        }
+    }
+}
+
+impl<'r> OpenApiFromRequest<'r> for InboundEmailSecret<'r> {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> revolt_rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::Parameter(Parameter {
+            name: "X-Inbound-Email-Secret".to_string(),
+            description: Some("Shared secret configured for the mail relay".to_string()),
+            allow_empty_value: false,
+            required: true,
+            deprecated: false,
+            extensions: schemars::Map::new(),
+            location: "header".to_string(),
+            value: ParameterValue::Schema {
+                allow_reserved: false,
+                example: None,
+                examples: None,
+                explode: None,
+                style: None,
+                schema: SchemaObject {
+                    instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::String))),
+                    ..Default::default()
+                },
+            },
+        }))
+    }
+}
@@ -17,6 +17,8 @@ use serde_json::Value;
 use ulid::Ulid;
 use validator::Validate;
 
+use crate::util::webhook_limits;
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub struct GithubUser {
     name: Option<String>,
@@ -1062,6 +1064,8 @@ pub async fn webhook_execute_github(
         .validate()
         .map_err(|error| Error::FailedValidation { error })?;
 
+    webhook_limits::consume(&webhook).await?;
+
     let message_id = Ulid::new().to_string();
 
     let embed = sendable_embed
@@ -0,0 +1,76 @@
+//! Post scheduled server announcements
+
+use std::time::Duration;
+
+use revolt_database::{Database, ServerAnnouncement};
+use revolt_quark::{
+    models::message::SystemMessage, Database as LegacyDatabase, Error, Result, Timestamp,
+};
+
+/// How often to check for announcements which are due
+static POLL_INTERVAL: u64 = 30;
+
+/// Post an announcement to its channel as a system message
+async fn post_announcement(
+    legacy_db: &LegacyDatabase,
+    announcement: &ServerAnnouncement,
+) -> Result<()> {
+    let channel = legacy_db.fetch_channel(&announcement.channel_id).await?;
+
+    SystemMessage::Text {
+        content: announcement.content.clone(),
+    }
+    .into_message(channel.id().to_string())
+    .create(legacy_db, &channel, None)
+    .await
+}
+
+/// Start a new worker
+pub async fn worker(db: Database, legacy_db: LegacyDatabase) {
+    loop {
+        match db
+            .fetch_due_server_announcements(Timestamp::now_utc())
+            .await
+        {
+            Ok(announcements) => {
+                for mut announcement in announcements {
+                    // Advance before posting so only the node which moved
+                    // the announcement on from this run posts it
+                    match announcement.advance(&db).await {
+                        Ok(true) => {}
+                        Ok(false) => continue,
+                        Err(err) => {
+                            log::error!(
+                                "Failed to reschedule announcement {}: {err:?}",
+                                announcement.id
+                            );
+                            continue;
+                        }
+                    }
+
+                    match post_announcement(&legacy_db, &announcement).await {
+                        // Drop announcements whose channel has since been deleted
+                        Err(Error::NotFound) => {
+                            if announcement.recurrence.is_some() {
+                                if let Err(err) = announcement.delete(&db).await {
+                                    log::error!(
+                                        "Failed to delete announcement {}: {err:?}",
+                                        announcement.id
+                                    );
+                                }
+                            }
+                        }
+                        Err(err) => log::warn!(
+                            "Failed to post scheduled announcement {}: {err:?}",
+                            announcement.id
+                        ),
+                        Ok(_) => {}
+                    }
+                }
+            }
+            Err(err) => log::error!("Failed to fetch scheduled announcements: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
@@ -0,0 +1,211 @@
+//! Deliver scheduled instance-wide broadcasts
+
+use std::{collections::HashSet, time::Duration};
+
+use revolt_database::{Broadcast, BroadcastTarget, Database};
+use revolt_quark::{
+    events::client::EventV1,
+    models::{message::DataMessageSend, Channel, User},
+    types::push::MessageAuthor,
+    variables::delta::SYSTEM_USER_ID,
+    web::idempotency::IdempotencyKey,
+    Database as LegacyDatabase, Result, Timestamp,
+};
+use ulid::Ulid;
+
+/// How often to check for broadcasts which are due
+static POLL_INTERVAL: u64 = 30;
+
+/// Number of users fetched at a time when messaging every user
+static USER_PAGE_SIZE: i64 = 100;
+
+/// Send the broadcast by direct message to every user it targets
+///
+/// When messaging every user, users are fetched a page at a time and each
+/// page is sent before the next one is loaded.
+async fn send_to_recipients(
+    legacy_db: &LegacyDatabase,
+    system_user: &User,
+    broadcast: &Broadcast,
+) -> Result<()> {
+    match &broadcast.target {
+        BroadcastTarget::All => {
+            let mut after: Option<String> = None;
+
+            loop {
+                let page = legacy_db
+                    .fetch_user_ids_after(after.as_deref(), USER_PAGE_SIZE)
+                    .await?;
+
+                let done = (page.len() as i64) < USER_PAGE_SIZE;
+                after = page.last().cloned();
+
+                for recipient in page {
+                    send_or_log(legacy_db, system_user, broadcast, &recipient).await;
+                }
+
+                if done {
+                    break;
+                }
+            }
+        }
+        BroadcastTarget::Servers { ids } => {
+            let mut seen = HashSet::new();
+            for id in ids {
+                for member in legacy_db.fetch_all_members(id).await? {
+                    if seen.insert(member.id.user.clone()) {
+                        send_or_log(legacy_db, system_user, broadcast, &member.id.user).await;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send the broadcast to a single user, logging any failure
+async fn send_or_log(
+    legacy_db: &LegacyDatabase,
+    system_user: &User,
+    broadcast: &Broadcast,
+    recipient: &str,
+) {
+    if recipient == system_user.id {
+        return;
+    }
+
+    if let Err(err) =
+        send_direct_message(legacy_db, system_user, recipient, &broadcast.content).await
+    {
+        log::warn!(
+            "Failed to send broadcast {} to {recipient}: {err:?}",
+            broadcast.id
+        );
+    }
+}
+
+/// Send the broadcast to a user as a direct message from the system user
+async fn send_direct_message(
+    legacy_db: &LegacyDatabase,
+    system_user: &User,
+    recipient: &str,
+    content: &str,
+) -> Result<()> {
+    let channel = if let Ok(channel) = legacy_db
+        .find_direct_message_channel(&system_user.id, recipient)
+        .await
+    {
+        channel
+    } else {
+        let channel = Channel::DirectMessage {
+            id: Ulid::new().to_string(),
+            active: false,
+            recipients: vec![system_user.id.clone(), recipient.to_string()],
+            last_message_id: None,
+        };
+
+        channel.create(legacy_db).await?;
+        channel
+    };
+
+    channel
+        .send_message(
+            legacy_db,
+            DataMessageSend {
+                nonce: None,
+                content: Some(content.to_string()),
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                effect: None,
+                thread: None,
+                send_at: None,
+            },
+            MessageAuthor::User(system_user),
+            IdempotencyKey::default(),
+            false,
+        )
+        .await
+        .map(|_| ())
+}
+
+/// Deliver a broadcast over the gateway and optionally by direct message
+async fn deliver(legacy_db: &LegacyDatabase, broadcast: &Broadcast) {
+    let event = EventV1::SystemBroadcast {
+        id: broadcast.id.clone(),
+        content: broadcast.content.clone(),
+    };
+
+    match &broadcast.target {
+        BroadcastTarget::All => event.broadcast().await,
+        BroadcastTarget::Servers { ids } => {
+            for id in ids {
+                event.clone().p(id.clone()).await;
+            }
+        }
+    }
+
+    if !broadcast.send_dm {
+        return;
+    }
+
+    let system_user = match &*SYSTEM_USER_ID {
+        Some(id) => match legacy_db.fetch_user(id).await {
+            Ok(user) => user,
+            Err(err) => {
+                log::warn!(
+                    "Failed to fetch system user for broadcast {}: {err:?}",
+                    broadcast.id
+                );
+                return;
+            }
+        },
+        None => {
+            log::warn!(
+                "Broadcast {} requested direct messages but no system user is configured",
+                broadcast.id
+            );
+            return;
+        }
+    };
+
+    if let Err(err) = send_to_recipients(legacy_db, &system_user, broadcast).await {
+        log::warn!(
+            "Failed to fetch recipients for broadcast {}: {err:?}",
+            broadcast.id
+        );
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database, legacy_db: LegacyDatabase) {
+    loop {
+        match db.fetch_due_broadcasts(Timestamp::now_utc()).await {
+            Ok(broadcasts) => {
+                for mut broadcast in broadcasts {
+                    // Mark as sent first so a failed delivery is never repeated,
+                    // and only the node which marked it delivers it
+                    match broadcast.mark_sent(&db).await {
+                        Ok(true) => {}
+                        Ok(false) => continue,
+                        Err(err) => {
+                            log::error!(
+                                "Failed to mark broadcast {} as sent: {err:?}",
+                                broadcast.id
+                            );
+                            continue;
+                        }
+                    }
+
+                    deliver(&legacy_db, &broadcast).await;
+                }
+            }
+            Err(err) => log::error!("Failed to fetch scheduled broadcasts: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
@@ -0,0 +1,253 @@
+//! Poll RSS and Atom feeds and post new entries to channels
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use revolt_database::{ChannelFeed, Database};
+use revolt_quark::{
+    models::message::{DataMessageSend, SendableEmbed},
+    types::push::MessageAuthor,
+    web::idempotency::IdempotencyKey,
+    Database as LegacyDatabase,
+};
+
+use crate::util::net;
+
+/// How often to check for feeds which are due
+static POLL_INTERVAL: u64 = 60;
+
+/// Maximum number of entries posted per poll
+static MAX_ENTRIES_PER_POLL: usize = 5;
+
+/// Maximum number of feeds fetched at the same time
+static MAX_CONCURRENT_POLLS: usize = 8;
+
+/// How long to wait for a feed to respond
+static FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum size of a feed document in bytes
+static MAX_FEED_SIZE: usize = 2 * 1024 * 1024;
+
+static RE_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+/// Single entry parsed from a feed
+#[derive(Debug, PartialEq, Eq)]
+struct FeedEntry {
+    id: String,
+    title: Option<String>,
+    link: Option<String>,
+    summary: Option<String>,
+}
+
+/// Convert HTML from a feed into plain text
+fn clean_text(input: &str) -> String {
+    let text = RE_TAG.replace_all(input, "");
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/// Truncate text to a maximum number of characters
+fn truncate(input: String, max: usize) -> String {
+    if input.chars().count() > max {
+        let mut output: String = input.chars().take(max - 1).collect();
+        output.push('…');
+        output
+    } else {
+        input
+    }
+}
+
+/// Parse entries out of an RSS or Atom document, in document order (newest first)
+fn parse_entries(body: &[u8]) -> Vec<FeedEntry> {
+    let feed = match feed_rs::parser::parse(body) {
+        Ok(feed) => feed,
+        Err(_) => return vec![],
+    };
+
+    feed.entries
+        .into_iter()
+        .map(|entry| {
+            let title = entry
+                .title
+                .map(|text| clean_text(&text.content))
+                .filter(|v| !v.is_empty());
+            let link = entry
+                .links
+                .into_iter()
+                .find(|link| matches!(link.rel.as_deref(), None | Some("alternate")))
+                .map(|link| link.href)
+                .filter(|v| !v.is_empty());
+            let summary = entry
+                .summary
+                .map(|text| text.content)
+                .or_else(|| entry.content.and_then(|content| content.body))
+                .map(|text| clean_text(&text))
+                .filter(|v| !v.is_empty());
+
+            FeedEntry {
+                id: entry.id,
+                title,
+                link,
+                summary,
+            }
+        })
+        .collect()
+}
+
+/// Fetch a feed's document, refusing to connect to private addresses
+async fn fetch_feed(url: &str) -> Option<Vec<u8>> {
+    let (client, url) = match net::public_client(url, FETCH_TIMEOUT).await {
+        Ok(client) => client,
+        Err(_) => {
+            log::warn!("Refusing to fetch feed {url}");
+            return None;
+        }
+    };
+
+    let response = match client.get(url).send().await {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            log::warn!("Failed to fetch feed: {}", response.status());
+            return None;
+        }
+        Err(err) => {
+            log::warn!("Failed to fetch feed: {err:?}");
+            return None;
+        }
+    };
+
+    if response.content_length().unwrap_or(0) as usize > MAX_FEED_SIZE {
+        return None;
+    }
+
+    response
+        .bytes()
+        .await
+        .ok()
+        .filter(|body| body.len() <= MAX_FEED_SIZE)
+        .map(|body| body.to_vec())
+}
+
+/// Check whether an entry passes the feed's filter
+fn matches_filter(feed: &ChannelFeed, entry: &FeedEntry) -> bool {
+    match &feed.filter {
+        Some(filter) => {
+            let filter = filter.to_lowercase();
+            entry
+                .title
+                .iter()
+                .chain(entry.summary.iter())
+                .any(|text| text.to_lowercase().contains(&filter))
+        }
+        None => true,
+    }
+}
+
+/// Poll a single feed, posting any new entries
+async fn poll_feed(db: &Database, legacy_db: &LegacyDatabase, mut feed: ChannelFeed) {
+    // Another node may have picked this feed up already
+    match feed.claim(db).await {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(err) => {
+            log::warn!("Failed to claim feed {}: {err:?}", feed.id);
+            return;
+        }
+    }
+
+    let entries = fetch_feed(&feed.url)
+        .await
+        .map(|body| parse_entries(&body))
+        .unwrap_or_default();
+
+    // Take every entry up until the last one we have seen.
+    // If we have never seen this feed before, only record where we are.
+    let new_entries: Vec<&FeedEntry> = if feed.last_entry_id.is_some() {
+        entries
+            .iter()
+            .take_while(|entry| Some(&entry.id) != feed.last_entry_id.as_ref())
+            .filter(|entry| matches_filter(&feed, entry))
+            .take(MAX_ENTRIES_PER_POLL)
+            .collect()
+    } else {
+        vec![]
+    };
+
+    if !new_entries.is_empty() {
+        if let (Ok(webhook), Ok(channel)) = (
+            db.fetch_webhook(&feed.webhook_id).await,
+            legacy_db.fetch_channel(&feed.channel_id).await,
+        ) {
+            let webhook: revolt_models::v0::Webhook = webhook.into();
+
+            // Post oldest entries first
+            for entry in new_entries.into_iter().rev() {
+                let embed = SendableEmbed {
+                    title: entry.title.clone().map(|v| truncate(v, 100)),
+                    description: entry.summary.clone().map(|v| truncate(v, 2000)),
+                    url: entry.link.clone().filter(|v| v.len() <= 256),
+                    ..Default::default()
+                };
+
+                if let Err(err) = channel
+                    .send_message(
+                        legacy_db,
+                        DataMessageSend {
+                            nonce: None,
+                            content: None,
+                            attachments: None,
+                            replies: None,
+                            embeds: Some(vec![embed]),
+                            masquerade: None,
+                            interactions: None,
+                            effect: None,
+                            thread: None,
+                            send_at: None,
+                        },
+                        MessageAuthor::Webhook(&webhook),
+                        IdempotencyKey::default(),
+                        false,
+                    )
+                    .await
+                {
+                    log::warn!("Failed to post feed entry to {}: {err:?}", feed.channel_id);
+                }
+            }
+        }
+    }
+
+    let last_entry_id = entries
+        .first()
+        .map(|entry| entry.id.clone())
+        .or_else(|| feed.last_entry_id.clone());
+
+    if let Err(err) = feed.mark_polled(db, last_entry_id).await {
+        log::warn!("Failed to update feed {}: {err:?}", feed.id);
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database, legacy_db: LegacyDatabase) {
+    loop {
+        match db.fetch_all_channel_feeds().await {
+            Ok(feeds) => {
+                futures::stream::iter(feeds.into_iter().filter(ChannelFeed::is_due))
+                    .for_each_concurrent(MAX_CONCURRENT_POLLS, |feed| {
+                        poll_feed(&db, &legacy_db, feed)
+                    })
+                    .await;
+            }
+            Err(err) => log::error!("Failed to fetch channel feeds: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
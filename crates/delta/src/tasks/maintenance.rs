@@ -0,0 +1,21 @@
+//! Keep the cached maintenance state in sync across nodes
+
+use std::time::Duration;
+
+use revolt_database::Database;
+
+use crate::util::maintenance;
+
+/// How often to reload the maintenance state
+static POLL_INTERVAL: u64 = 5;
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        if let Err(err) = maintenance::refresh(&db).await {
+            log::error!("Failed to refresh maintenance state: {err:?}");
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
@@ -0,0 +1,23 @@
+//! Background tasks which require access to both databases
+
+use revolt_database::Database;
+use revolt_quark::Database as LegacyDatabase;
+
+pub mod announcements;
+pub mod broadcasts;
+pub mod feeds;
+pub mod maintenance;
+pub mod scheduled_messages;
+pub mod schedules;
+pub mod threads;
+
+/// Spawn background workers
+pub fn start_workers(db: Database, legacy_db: LegacyDatabase) {
+    async_std::task::spawn(announcements::worker(db.clone(), legacy_db.clone()));
+    async_std::task::spawn(broadcasts::worker(db.clone(), legacy_db.clone()));
+    async_std::task::spawn(feeds::worker(db.clone(), legacy_db.clone()));
+    async_std::task::spawn(maintenance::worker(db.clone()));
+    async_std::task::spawn(scheduled_messages::worker(db.clone(), legacy_db.clone()));
+    async_std::task::spawn(schedules::worker(db.clone(), legacy_db));
+    async_std::task::spawn(threads::worker(db));
+}
@@ -0,0 +1,123 @@
+//! Send messages which were scheduled for later
+
+use std::time::Duration;
+
+use revolt_database::{Database, ScheduledMessage, Thread};
+use revolt_quark::{
+    models::message::DataMessageSend, perms, types::push::MessageAuthor,
+    web::idempotency::IdempotencyKey, Database as LegacyDatabase, Error, Permission, Result,
+    Timestamp,
+};
+
+/// How often to check for messages which are due
+static POLL_INTERVAL: u64 = 30;
+
+/// Send a scheduled message on behalf of whoever scheduled it
+///
+/// Permissions are checked again as they may have changed since scheduling.
+/// The message has already been claimed so no other node will send it.
+async fn send_scheduled_message(
+    db: &Database,
+    legacy_db: &LegacyDatabase,
+    scheduled: &ScheduledMessage,
+) -> Result<()> {
+    let user = legacy_db.fetch_user(&scheduled.author_id).await?;
+    let channel = legacy_db.fetch_channel(&scheduled.channel_id).await?;
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::SendMessage)
+        .await?;
+
+    let data = DataMessageSend::from_v0(scheduled.message.clone())?;
+
+    if let Some(masq) = &data.masquerade {
+        permissions
+            .throw_permission(legacy_db, Permission::Masquerade)
+            .await?;
+
+        if masq.colour.is_some() {
+            permissions
+                .throw_permission(legacy_db, Permission::ManageRole)
+                .await?;
+        }
+    }
+
+    if data.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
+        permissions
+            .throw_permission(legacy_db, Permission::SendEmbeds)
+            .await?;
+    }
+
+    if data.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+        permissions
+            .throw_permission(legacy_db, Permission::UploadFiles)
+            .await?;
+    }
+
+    let thread = if let Some(thread_id) = &data.thread {
+        let thread = Thread::fetch_in_channel(db, thread_id, channel.id())
+            .await
+            .map_err(Error::from_core)?;
+
+        if thread.is_explicitly_archived() {
+            permissions
+                .throw_permission(legacy_db, Permission::ManageThreads)
+                .await?;
+        }
+
+        Some(thread)
+    } else {
+        None
+    };
+
+    let message = channel
+        .send_message(
+            legacy_db,
+            data,
+            MessageAuthor::User(&user),
+            IdempotencyKey::default(),
+            permissions
+                .has_permission(legacy_db, Permission::SendEmbeds)
+                .await?,
+        )
+        .await?;
+
+    if let Some(mut thread) = thread {
+        if let Err(err) = thread.record_message(db, &message.id, &user.id).await {
+            log::warn!("Failed to record message in thread {}: {err:?}", thread.id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Start a new worker
+pub async fn worker(db: Database, legacy_db: LegacyDatabase) {
+    loop {
+        match db.fetch_due_scheduled_messages(Timestamp::now_utc()).await {
+            Ok(messages) => {
+                for scheduled in messages {
+                    match scheduled.claim(&db).await {
+                        Ok(true) => {}
+                        Ok(false) => continue,
+                        Err(err) => {
+                            log::error!(
+                                "Failed to claim scheduled message {}: {err:?}",
+                                scheduled.id
+                            );
+                            continue;
+                        }
+                    }
+
+                    if let Err(err) = send_scheduled_message(&db, &legacy_db, &scheduled).await {
+                        log::warn!("Failed to send scheduled message {}: {err:?}", scheduled.id);
+                    }
+                }
+            }
+            Err(err) => log::error!("Failed to fetch scheduled messages: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
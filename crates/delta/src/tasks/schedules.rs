@@ -0,0 +1,103 @@
+//! Apply scheduled changes to channel settings
+
+use std::time::Duration;
+
+use revolt_database::{ChannelSchedule, Database};
+use revolt_quark::{
+    models::channel::{FieldsChannel, PartialChannel},
+    perms, Database as LegacyDatabase, Error, Permission, Result, Timestamp,
+};
+
+/// How often to check for changes which are due
+static POLL_INTERVAL: u64 = 30;
+
+/// Apply a scheduled change to its channel
+///
+/// Permissions are checked again as they may have changed since scheduling.
+/// The change has already been claimed so no other node will apply it.
+async fn apply_schedule(legacy_db: &LegacyDatabase, schedule: &ChannelSchedule) -> Result<()> {
+    let user = legacy_db.fetch_user(&schedule.creator_id).await?;
+    let mut channel = legacy_db.fetch_channel(&schedule.channel_id).await?;
+    let changes = &schedule.changes;
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(legacy_db, Permission::ManageChannel)
+        .await?;
+
+    if changes.locked.is_some() {
+        permissions
+            .throw_permission(legacy_db, Permission::ManageThreads)
+            .await?;
+    }
+
+    if changes.name.is_some()
+        || changes.description.is_some()
+        || changes.nsfw.is_some()
+        || changes.media_only.is_some()
+        || changes.slowmode.is_some()
+    {
+        let (slowmode, remove) = match changes.slowmode {
+            Some(0) => (None, vec![FieldsChannel::Slowmode]),
+            slowmode => (slowmode, vec![]),
+        };
+
+        channel
+            .update(
+                legacy_db,
+                PartialChannel {
+                    name: changes.name.clone(),
+                    description: changes.description.clone(),
+                    nsfw: changes.nsfw,
+                    media_only: changes.media_only,
+                    slowmode,
+                    ..Default::default()
+                },
+                remove,
+            )
+            .await?;
+    }
+
+    // Locking posts a system message on behalf of whoever scheduled it
+    if let Some(locked) = changes.locked {
+        match channel
+            .set_locked(legacy_db, &schedule.creator_id, locked)
+            .await
+        {
+            Ok(_) | Err(Error::NoEffect) => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(())
+}
+
+/// Start a new worker
+pub async fn worker(db: Database, legacy_db: LegacyDatabase) {
+    loop {
+        match db.fetch_due_channel_schedules(Timestamp::now_utc()).await {
+            Ok(schedules) => {
+                for schedule in schedules {
+                    match schedule.claim(&db).await {
+                        Ok(true) => {}
+                        Ok(false) => continue,
+                        Err(err) => {
+                            log::error!(
+                                "Failed to claim scheduled change {}: {err:?}",
+                                schedule.id
+                            );
+                            continue;
+                        }
+                    }
+
+                    if let Err(err) = apply_schedule(&legacy_db, &schedule).await {
+                        log::warn!("Failed to apply scheduled change {}: {err:?}", schedule.id);
+                    }
+                }
+            }
+            Err(err) => log::error!("Failed to fetch scheduled channel changes: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
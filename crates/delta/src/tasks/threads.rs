@@ -0,0 +1,26 @@
+//! Archive threads which have gone inactive
+
+use std::time::Duration;
+
+use revolt_database::{Database, Thread};
+
+/// How often to check for inactive threads
+static POLL_INTERVAL: u64 = 60;
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match Thread::fetch_inactive(&db).await {
+            Ok(threads) => {
+                for mut thread in threads {
+                    if let Err(err) = thread.archive_if_inactive(&db).await {
+                        log::error!("Failed to archive thread {}: {err:?}", thread.id);
+                    }
+                }
+            }
+            Err(err) => log::error!("Failed to fetch inactive threads: {err:?}"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
@@ -0,0 +1,24 @@
+//! Recording moderation-relevant changes to a server's audit log
+
+use revolt_database::{AuditLogAction, AuditLogEntry, Database};
+use serde::Serialize;
+
+/// Append an entry to a server's audit log
+///
+/// Failing to record an entry should never fail the change itself,
+/// so errors are only logged.
+pub async fn record<T: Serialize>(
+    db: &Database,
+    server_id: &str,
+    actor_id: &str,
+    action: AuditLogAction,
+    target_id: &str,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    if let Err(err) =
+        AuditLogEntry::record(db, server_id, actor_id, action, target_id, before, after).await
+    {
+        log::warn!("Failed to record server audit log entry: {err:?}");
+    }
+}
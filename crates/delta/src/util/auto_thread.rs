@@ -0,0 +1,136 @@
+//! Move long reply chains into threads to keep busy channels readable
+
+use revolt_database::{Database, Thread};
+use revolt_quark::{
+    models::{
+        message::{DataMessageSend, SystemMessage},
+        Channel, Message, User,
+    },
+    Database as LegacyDatabase, Error, Result,
+};
+
+/// Longest name given to a thread started automatically
+static MAX_NAME_LENGTH: usize = 32;
+
+/// Name used when the first message of a chain has no content
+static FALLBACK_NAME: &str = "Replies";
+
+/// Find the thread a reply should be sent in
+///
+/// Replies are moved into a thread once their chain is deeper than the
+/// channel's auto-thread depth, starting a thread from the message at that
+/// depth and announcing it in the channel if one does not exist yet.
+pub async fn resolve(
+    db: &Database,
+    legacy_db: &LegacyDatabase,
+    channel: &Channel,
+    user: &User,
+    data: &DataMessageSend,
+) -> Result<Option<Thread>> {
+    let Channel::TextChannel {
+        auto_thread_depth: Some(max_depth),
+        ..
+    } = channel
+    else {
+        return Ok(None);
+    };
+
+    if data.thread.is_some() {
+        return Ok(None);
+    }
+
+    let Some(reply) = data.replies.as_ref().and_then(|replies| replies.first()) else {
+        return Ok(None);
+    };
+
+    // Walk up the chain, joining any thread it has already been moved into
+    let Some(mut current) = fetch_in_channel(legacy_db, channel, &reply.id).await else {
+        return Ok(None);
+    };
+
+    let mut depth = 1;
+    loop {
+        if let Some(thread_id) = &current.thread {
+            return Thread::fetch_in_channel(db, thread_id, channel.id())
+                .await
+                .map(Some)
+                .map_err(Error::from_core);
+        }
+
+        if depth >= *max_depth {
+            break;
+        }
+
+        let Some(parent) = current.replies.as_ref().and_then(|replies| replies.first()) else {
+            return Ok(None);
+        };
+
+        let Some(parent) = fetch_in_channel(legacy_db, channel, parent).await else {
+            return Ok(None);
+        };
+
+        current = parent;
+        depth += 1;
+    }
+
+    if let Ok(thread) = Thread::fetch_in_channel(db, &current.id, channel.id()).await {
+        return Ok(Some(thread));
+    }
+
+    let name = current
+        .content
+        .as_deref()
+        .map(|content| {
+            content
+                .trim()
+                .chars()
+                .take(MAX_NAME_LENGTH)
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| FALLBACK_NAME.to_string());
+
+    let thread = match Thread::create(
+        db,
+        &current.id,
+        &current.author,
+        channel.id(),
+        &user.id,
+        name.clone(),
+        None,
+    )
+    .await
+    {
+        Ok(thread) => thread,
+        // Another reply may have started it first
+        Err(_) => {
+            return Thread::fetch_in_channel(db, &current.id, channel.id())
+                .await
+                .map(Some)
+                .map_err(Error::from_core)
+        }
+    };
+
+    SystemMessage::ThreadStarted {
+        id: thread.id.clone(),
+        name,
+    }
+    .into_message(channel.id().to_string())
+    .create(legacy_db, channel, None)
+    .await?;
+
+    Ok(Some(thread))
+}
+
+/// Fetch a message, ignoring it if it is missing or in another channel
+async fn fetch_in_channel(
+    legacy_db: &LegacyDatabase,
+    channel: &Channel,
+    id: &str,
+) -> Option<Message> {
+    legacy_db
+        .fetch_message(id)
+        .await
+        .ok()
+        .filter(|message| message.channel == channel.id())
+}
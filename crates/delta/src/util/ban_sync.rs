@@ -0,0 +1,112 @@
+//! Bans shared between servers in the same ban sync group
+
+use std::collections::HashSet;
+
+use revolt_database::{BanSyncPolicy, BanSyncProposal, Database};
+use revolt_quark::{
+    models::{
+        server_member::{MemberCompositeKey, RemovalIntention},
+        Server, ServerBan,
+    },
+    Db, Error, Result,
+};
+use ulid::Ulid;
+
+/// Share a ban with every other server in the ban sync groups of the server it was applied in
+///
+/// Servers set to auto-apply ban the user straight away, the rest receive a proposal.
+/// Server owners and users who are already banned or already proposed are skipped.
+pub async fn propagate(db: &Database, legacy_db: &Db, ban: &ServerBan) -> Result<()> {
+    let groups = db
+        .fetch_server_ban_sync_groups(&ban.id.server)
+        .await
+        .map_err(Error::from_core)?;
+
+    // A server may share more than one group with the source server
+    let mut seen = HashSet::new();
+    for group in groups {
+        for member in group.members {
+            if member.server_id == ban.id.server || !seen.insert(member.server_id.clone()) {
+                continue;
+            }
+
+            let server = match legacy_db.fetch_server(&member.server_id).await {
+                Ok(server) => server,
+                Err(_) => continue,
+            };
+
+            if server.owner == ban.id.user
+                || legacy_db.fetch_ban(&server.id, &ban.id.user).await.is_ok()
+            {
+                continue;
+            }
+
+            match member.policy {
+                BanSyncPolicy::AutoApply => {
+                    apply(
+                        legacy_db,
+                        server,
+                        &ban.id.user,
+                        ban.reason.clone(),
+                        &ban.id.server,
+                    )
+                    .await?;
+                }
+                BanSyncPolicy::Propose => {
+                    let proposals = db
+                        .fetch_ban_sync_proposals(&server.id)
+                        .await
+                        .map_err(Error::from_core)?;
+
+                    if proposals
+                        .iter()
+                        .any(|proposal| proposal.user_id == ban.id.user)
+                    {
+                        continue;
+                    }
+
+                    BanSyncProposal {
+                        id: Ulid::new().to_string(),
+                        group_id: group.id.clone(),
+                        source_server_id: ban.id.server.clone(),
+                        server_id: server.id,
+                        user_id: ban.id.user.clone(),
+                        reason: ban.reason.clone(),
+                    }
+                    .create(db)
+                    .await
+                    .map_err(Error::from_core)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Ban a user on behalf of another server, removing them if they are a member
+pub async fn apply(
+    legacy_db: &Db,
+    server: Server,
+    user_id: &str,
+    reason: Option<String>,
+    source_server_id: &str,
+) -> Result<ServerBan> {
+    if let Ok(member) = legacy_db.fetch_member(&server.id, user_id).await {
+        server
+            .remove_member(legacy_db, member, RemovalIntention::Ban, false)
+            .await?;
+    }
+
+    let ban = ServerBan {
+        id: MemberCompositeKey {
+            server: server.id,
+            user: user_id.to_string(),
+        },
+        reason,
+        synced_from: Some(source_server_id.to_string()),
+    };
+
+    legacy_db.insert_ban(&ban).await?;
+    Ok(ban)
+}
@@ -0,0 +1,224 @@
+//! Challenges required on suspicious actions, such as mass DMs or rapid server joins
+//!
+//! Activity and issued challenges are tracked in Redis, like webhook limits, so they
+//! are shared between nodes and expire on their own. Once a user crosses a threshold the action fails with a challenge which must be
+//! solved and sent back in the `X-Challenge-Solution` header, formatted as
+//! `<challenge id>:<captcha response>`, when retrying.
+
+use std::time::Duration;
+
+use revolt_models::v0::{Challenge, ChallengeKind, ChallengeReason};
+use revolt_quark::{
+    models::User,
+    redis_kiss::{get_connection, AsyncCommands, Conn},
+    variables::delta::{
+        CHALLENGE_DM_THRESHOLD, CHALLENGE_JOIN_THRESHOLD, HCAPTCHA_KEY, HCAPTCHA_SITEKEY,
+        USE_HCAPTCHA,
+    },
+    Error, Result,
+};
+use revolt_rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+    revolt_okapi::openapi3::{Parameter, ParameterValue},
+};
+use rocket::{
+    request::{FromRequest, Outcome},
+    Request,
+};
+use schemars::schema::{InstanceType, SchemaObject, SingleOrVec};
+use serde::Deserialize;
+use ulid::Ulid;
+
+/// Time a user has to solve a challenge
+const CHALLENGE_LIFETIME: Duration = Duration::from_secs(60 * 10);
+
+/// Redis key counting a user's actions within the current window
+fn window_key(user: &User, reason: &ChallengeReason) -> String {
+    format!("challenges:window:{}:{reason:?}", user.id)
+}
+
+/// Redis key of a challenge which has been issued but not solved yet
+///
+/// The key holds the user and reason it was issued for.
+fn pending_key(id: &str) -> String {
+    format!("challenges:pending:{id}")
+}
+
+/// Value stored for a pending challenge
+fn pending_value(user: &User, reason: &ChallengeReason) -> String {
+    format!("{}:{reason:?}", user.id)
+}
+
+/// Number of actions allowed within a window before a challenge is required
+fn limits(reason: &ChallengeReason) -> (u32, Duration) {
+    match reason {
+        ChallengeReason::MassDirectMessage => {
+            (*CHALLENGE_DM_THRESHOLD, Duration::from_secs(60 * 10))
+        }
+        ChallengeReason::RapidJoins => (*CHALLENGE_JOIN_THRESHOLD, Duration::from_secs(60 * 60)),
+    }
+}
+
+/// Solution to a challenge sent along with a request
+#[derive(Default)]
+pub struct ChallengeSolution {
+    solution: Option<(String, String)>,
+}
+
+/// Response from hCaptcha's verification endpoint
+#[derive(Deserialize)]
+struct CaptchaVerification {
+    success: bool,
+}
+
+/// Check a captcha response with hCaptcha
+pub async fn verify_captcha(response: &str) -> Result<bool> {
+    let verification: CaptchaVerification = reqwest::Client::new()
+        .post("https://hcaptcha.com/siteverify")
+        .form(&[("secret", HCAPTCHA_KEY.as_str()), ("response", response)])
+        .send()
+        .await
+        .map_err(|_| Error::InternalError)?
+        .json()
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    Ok(verification.success)
+}
+
+/// Check whether a solution answers a challenge issued to this user for this reason
+///
+/// Each challenge can only be solved once.
+async fn is_solved(
+    conn: &mut Conn,
+    user: &User,
+    reason: &ChallengeReason,
+    solution: &ChallengeSolution,
+) -> bool {
+    let (id, response) = match &solution.solution {
+        Some(solution) => solution,
+        None => return false,
+    };
+
+    let pending: Option<String> = conn.get(pending_key(id)).await.unwrap_or_default();
+    if pending != Some(pending_value(user, reason)) {
+        return false;
+    }
+
+    match verify_captcha(response).await {
+        // Claim the challenge so that a concurrent request cannot reuse it
+        Ok(true) => conn
+            .del::<_, u32>(pending_key(id))
+            .await
+            .map_or(false, |deleted| deleted == 1),
+        Ok(false) => false,
+        Err(err) => {
+            log::warn!("Failed to verify captcha for challenge {id}: {err:?}");
+            false
+        }
+    }
+}
+
+/// Count an action against a user, requiring a challenge if they have done it too often
+///
+/// Solving the challenge resets the count. Bots are never challenged, and
+/// challenges are only issued if the instance has captcha configured.
+pub async fn assert_passed(
+    user: &User,
+    reason: ChallengeReason,
+    solution: &ChallengeSolution,
+) -> Result<()> {
+    if user.bot.is_some() || !*USE_HCAPTCHA {
+        return Ok(());
+    }
+
+    // Let actions through if Redis cannot be reached
+    let mut conn = match get_connection().await {
+        Ok(conn) => conn,
+        Err(_) => return Ok(()),
+    };
+
+    let (threshold, length) = limits(&reason);
+    let key = window_key(user, &reason);
+
+    if is_solved(&mut conn, user, &reason, solution).await {
+        let _: Option<()> = conn.del(&key).await.ok();
+    }
+
+    let used: u32 = conn.get(&key).await.unwrap_or_default();
+    if used >= threshold {
+        let challenge = Challenge {
+            id: Ulid::new().to_string(),
+            reason: reason.clone(),
+            kind: ChallengeKind::HCaptcha {
+                site_key: HCAPTCHA_SITEKEY.to_string(),
+            },
+        };
+
+        let _: Option<()> = conn
+            .set_ex(
+                pending_key(&challenge.id),
+                pending_value(user, &reason),
+                CHALLENGE_LIFETIME.as_secs() as usize,
+            )
+            .await
+            .ok();
+
+        return Err(Error::ChallengeRequired { challenge });
+    }
+
+    // Start the window with the first action in it
+    if let Ok(1) = conn.incr::<_, _, u32>(&key, 1).await {
+        let _: Option<()> = conn.expire(&key, length.as_secs() as usize).await.ok();
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<'r> FromRequest<'r> for ChallengeSolution {
+    type Error = Error;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let solution = request
+            .headers()
+            .get_one("X-Challenge-Solution")
+            .and_then(|header| header.split_once(':'))
+            .map(|(id, response)| (id.to_string(), response.to_string()));
+
+        Outcome::Success(ChallengeSolution { solution })
+    }
+}
+
+impl<'r> OpenApiFromRequest<'r> for ChallengeSolution {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> revolt_rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::Parameter(Parameter {
+            name: "X-Challenge-Solution".to_string(),
+            description: Some(
+                "Solution to a previously issued challenge, as `<challenge id>:<response>`"
+                    .to_string(),
+            ),
+            allow_empty_value: false,
+            required: false,
+            deprecated: false,
+            extensions: schemars::Map::new(),
+            location: "header".to_string(),
+            value: ParameterValue::Schema {
+                allow_reserved: false,
+                example: None,
+                examples: None,
+                explode: None,
+                style: None,
+                schema: SchemaObject {
+                    instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::String))),
+                    ..Default::default()
+                },
+            },
+        }))
+    }
+}
@@ -0,0 +1,33 @@
+//! Experimental features which can be toggled per instance or per server
+//!
+//! Flags listed in `REVOLT_FEATURE_FLAGS` are enabled by default and can be
+//! overridden at runtime through the admin API.
+
+use revolt_database::{Database, FeatureFlag};
+use revolt_quark::{variables::delta::FEATURE_FLAGS, Error, Result};
+
+/// Direct messages with users on other instances
+pub const FEDERATION: &str = "federation";
+
+/// Fetch a flag, falling back to the instance configuration
+pub async fn fetch(db: &Database, id: &str) -> Result<FeatureFlag> {
+    FeatureFlag::fetch(db, id, &FEATURE_FLAGS)
+        .await
+        .map_err(Error::from_core)
+}
+
+/// Fetch every configured or overridden flag
+pub async fn fetch_all(db: &Database) -> Result<Vec<FeatureFlag>> {
+    FeatureFlag::fetch_all(db, &FEATURE_FLAGS)
+        .await
+        .map_err(Error::from_core)
+}
+
+/// Ensure a feature is enabled, optionally within a given server
+pub async fn assert_enabled(db: &Database, id: &str, server: Option<&str>) -> Result<()> {
+    if fetch(db, id).await?.is_enabled(server) {
+        Ok(())
+    } else {
+        Err(Error::FeatureDisabled)
+    }
+}
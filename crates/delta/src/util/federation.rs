@@ -0,0 +1,229 @@
+//! Signing and delivery of requests between federated instances
+//!
+//! Each instance is identified by the domain its API is served from and signs
+//! outgoing payloads with an Ed25519 key, the public half of which is served
+//! at `/federation/key` so that receiving instances can verify requests.
+//!
+//! Signed requests carry a timestamp and nonce. Requests are only accepted
+//! within a short window and each nonce only once, tracked in Redis so that a
+//! request cannot be replayed against another node.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use revolt_models::v0::{FederationEnvelope, FederationKey, FederationPayload, FederationRequest};
+use revolt_quark::{
+    redis_kiss::{get_connection, AsyncCommands},
+    variables::delta::{
+        FEDERATION_ALLOWLIST, FEDERATION_DENYLIST, FEDERATION_DOMAIN, FEDERATION_KEY,
+        USE_FEDERATION,
+    },
+    Error, Result,
+};
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use ulid::Ulid;
+
+use super::net;
+
+/// How long a signed request is accepted for, either side of its timestamp
+const REQUEST_LIFETIME: Duration = Duration::from_secs(60 * 5);
+
+/// How long public keys of other instances are cached for
+const KEY_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// How long to wait for another instance to respond
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Public keys of other instances and when they were fetched
+static KEYS: Lazy<DashMap<String, (Vec<u8>, Instant)>> = Lazy::new(DashMap::new);
+
+/// Signing key of this instance, derived from a base64 encoded 32 byte seed
+static KEY_PAIR: Lazy<Option<Ed25519KeyPair>> = Lazy::new(|| {
+    base64::decode(&*FEDERATION_KEY)
+        .ok()
+        .and_then(|seed| Ed25519KeyPair::from_seed_unchecked(&seed).ok())
+});
+
+/// Get the signing key, failing if federation is not configured
+fn key_pair() -> Result<&'static Ed25519KeyPair> {
+    if !*USE_FEDERATION {
+        return Err(Error::FederationDisabled);
+    }
+
+    KEY_PAIR.as_ref().ok_or(Error::FederationDisabled)
+}
+
+/// Get the current time from Unix Epoch in seconds
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards...")
+        .as_secs()
+}
+
+/// Check whether a domain is a bare hostname, without a port, path or credentials
+fn is_hostname(domain: &str) -> bool {
+    domain.len() <= 253
+        && domain.contains('.')
+        && domain.parse::<std::net::IpAddr>().is_err()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Ensure we are allowed to talk to a given instance
+pub fn assert_domain_allowed(domain: &str) -> Result<()> {
+    let domain = domain.to_lowercase();
+    if !is_hostname(&domain) {
+        return Err(Error::InvalidProperty);
+    }
+
+    if domain == FEDERATION_DOMAIN.to_lowercase()
+        || FEDERATION_DENYLIST.contains(&domain)
+        || (!FEDERATION_ALLOWLIST.is_empty() && !FEDERATION_ALLOWLIST.contains(&domain))
+    {
+        Err(Error::FederationBlocked)
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the public key of this instance
+pub fn public_key() -> Result<FederationKey> {
+    Ok(FederationKey {
+        domain: FEDERATION_DOMAIN.to_string(),
+        public_key: base64::encode(key_pair()?.public_key().as_ref()),
+    })
+}
+
+/// Sign a payload for delivery to another instance
+pub fn sign(payload: &FederationPayload) -> Result<FederationEnvelope> {
+    let request = FederationRequest {
+        nonce: Ulid::new().to_string(),
+        timestamp: now(),
+        payload: payload.clone(),
+    };
+
+    let payload = serde_json::to_string(&request).map_err(|_| Error::InternalError)?;
+    let signature = key_pair()?.sign(payload.as_bytes());
+
+    Ok(FederationEnvelope {
+        origin: FEDERATION_DOMAIN.to_string(),
+        payload,
+        signature: base64::encode(signature.as_ref()),
+    })
+}
+
+/// Fetch the public key of another instance, using the cached copy if it is recent
+async fn fetch_key(domain: &str) -> Result<Vec<u8>> {
+    if let Some(key) = KEYS.get(domain) {
+        if key.1.elapsed() < KEY_LIFETIME {
+            return Ok(key.0.clone());
+        }
+    }
+
+    let (client, url) =
+        net::public_client(&format!("https://{domain}/federation/key"), REQUEST_TIMEOUT).await?;
+
+    let key: FederationKey = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|_| Error::InvalidCredentials)?
+        .json()
+        .await
+        .map_err(|_| Error::InvalidCredentials)?;
+
+    if !key.domain.eq_ignore_ascii_case(domain) {
+        return Err(Error::InvalidCredentials);
+    }
+
+    let public_key = base64::decode(key.public_key).map_err(|_| Error::InvalidCredentials)?;
+    KEYS.insert(domain.to_string(), (public_key.clone(), Instant::now()));
+    Ok(public_key)
+}
+
+/// Record a nonce from another instance, failing if it has been seen before
+///
+/// Nonces are remembered until every request they could have been sent with has expired.
+async fn claim_nonce(origin: &str, nonce: &str) -> Result<()> {
+    let key = format!("federation:nonce:{origin}:{nonce}");
+    let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+    let claimed: bool = conn
+        .set_nx(&key, 1_u8)
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    if !claimed {
+        return Err(Error::InvalidCredentials);
+    }
+
+    conn.expire::<_, ()>(&key, REQUEST_LIFETIME.as_secs() as usize * 2)
+        .await
+        .map_err(|_| Error::InternalError)
+}
+
+/// Verify an envelope received from another instance
+pub async fn verify(envelope: &FederationEnvelope) -> Result<FederationPayload> {
+    key_pair()?;
+
+    let origin = envelope.origin.to_lowercase();
+    assert_domain_allowed(&origin)?;
+
+    let public_key = fetch_key(&origin).await?;
+    let signature = base64::decode(&envelope.signature).map_err(|_| Error::InvalidCredentials)?;
+
+    if UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(envelope.payload.as_bytes(), &signature)
+        .is_err()
+    {
+        // The instance may have rotated its key, fetch it again next time
+        KEYS.remove(&origin);
+        return Err(Error::InvalidCredentials);
+    }
+
+    let request: FederationRequest =
+        serde_json::from_str(&envelope.payload).map_err(|_| Error::InvalidProperty)?;
+
+    let now = now();
+    let lifetime = REQUEST_LIFETIME.as_secs();
+    if request.timestamp.saturating_add(lifetime) < now
+        || request.timestamp > now.saturating_add(lifetime)
+    {
+        return Err(Error::InvalidCredentials);
+    }
+
+    claim_nonce(&origin, &request.nonce).await?;
+    Ok(request.payload)
+}
+
+/// Deliver a signed payload to another instance
+pub async fn deliver(domain: &str, payload: &FederationPayload) -> Result<()> {
+    assert_domain_allowed(domain)?;
+    let envelope = sign(payload)?;
+
+    let (client, url) = net::public_client(
+        &format!("https://{domain}/federation/inbox"),
+        REQUEST_TIMEOUT,
+    )
+    .await?;
+
+    let response = client
+        .post(url)
+        .json(&envelope)
+        .send()
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(Error::InternalError)
+    }
+}
@@ -0,0 +1,75 @@
+//! Highlight messages which receive enough reactions
+
+use std::collections::HashSet;
+
+use revolt_database::{Database, MessageHighlight};
+use revolt_quark::{
+    models::{message::SystemMessage, Channel},
+    Db, Error, Result,
+};
+
+/// Highlight a message if it has passed its server's reaction threshold
+///
+/// Each message is only ever highlighted once.
+pub async fn check(
+    db: &Database,
+    legacy_db: &Db,
+    channel: &Channel,
+    message_id: &str,
+) -> Result<()> {
+    let server_id = match channel {
+        Channel::TextChannel {
+            server,
+            nsfw: false,
+            ..
+        } => server,
+        _ => return Ok(()),
+    };
+
+    let server = legacy_db.fetch_server(server_id).await?;
+    let highlights = match &server.highlights {
+        Some(highlights) if highlights.channel != channel.id() => highlights,
+        _ => return Ok(()),
+    };
+
+    // Fetch the message again so the reaction we just added is counted
+    let message = legacy_db.fetch_message(message_id).await?;
+    if message.system.is_some() || message.webhook.is_some() {
+        return Ok(());
+    }
+
+    let count = match &highlights.emoji {
+        Some(emoji) => message.reactions.get(emoji).map_or(0, |users| users.len()),
+        None => message
+            .reactions
+            .values()
+            .flatten()
+            .collect::<HashSet<&String>>()
+            .len(),
+    };
+
+    if count < highlights.threshold || db.fetch_message_highlight(&message.id).await.is_ok() {
+        return Ok(());
+    }
+
+    let target = legacy_db.fetch_channel(&highlights.channel).await?;
+    let mut highlight = SystemMessage::MessageHighlighted {
+        id: message.id.clone(),
+        channel: message.channel.clone(),
+        author: message.author.clone(),
+    }
+    .into_message(target.id().to_string());
+
+    // Claim the message first so concurrent reactions cannot highlight it twice
+    MessageHighlight {
+        id: message.id,
+        server_id: server.id.clone(),
+        channel_id: message.channel,
+        highlight_id: highlight.id.clone(),
+    }
+    .create(db)
+    .await
+    .map_err(Error::from_core)?;
+
+    highlight.create(legacy_db, &target, None).await
+}
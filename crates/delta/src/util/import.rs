@@ -0,0 +1,343 @@
+//! Recreate channels, roles and message history from other platforms' exports
+//!
+//! Discord exports are read in the JSON format produced by DiscordChatExporter,
+//! one export per channel. Matrix exports are read in the JSON format produced
+//! by Element's "Export chat" option, one export per room.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeZone, Utc};
+use revolt_quark::{
+    models::{channel::PartialChannel, message::Masquerade, Message},
+    Database,
+};
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+/// Maximum length of a channel or role name
+const MAX_NAME_LENGTH: usize = 32;
+
+/// Maximum length of message content
+const MAX_CONTENT_LENGTH: usize = 2000;
+
+/// Maximum length of a masquerade avatar URL
+const MAX_AVATAR_LENGTH: usize = 256;
+
+/// # Discord Channel Export
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct DiscordExport {
+    /// Exported channel
+    pub channel: DiscordChannel,
+    /// Messages in this channel
+    #[serde(default)]
+    pub messages: Vec<DiscordMessage>,
+}
+
+/// # Discord Channel
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct DiscordChannel {
+    /// Channel name
+    pub name: String,
+    /// Channel topic
+    pub topic: Option<String>,
+    /// Name of the category this channel is in
+    pub category: Option<String>,
+}
+
+/// # Discord Message
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct DiscordMessage {
+    /// Message type
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// ISO 8601 time at which this message was sent
+    pub timestamp: String,
+    /// Message content
+    #[serde(default)]
+    pub content: String,
+    /// Message author
+    pub author: DiscordAuthor,
+    /// Attached files
+    #[serde(default)]
+    pub attachments: Vec<DiscordAttachment>,
+}
+
+/// # Discord Author
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordAuthor {
+    /// Username
+    pub name: String,
+    /// Server nickname
+    pub nickname: Option<String>,
+    /// URL of avatar
+    pub avatar_url: Option<String>,
+    /// Roles the author had in the server
+    #[serde(default)]
+    pub roles: Vec<DiscordRole>,
+}
+
+/// # Discord Role
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct DiscordRole {
+    /// Role name
+    pub name: String,
+    /// Role colour as a hex code
+    pub color: Option<String>,
+    /// Position in the role list, higher is more important
+    #[serde(default)]
+    pub position: i64,
+}
+
+/// # Discord Attachment
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct DiscordAttachment {
+    /// URL of the file
+    pub url: String,
+}
+
+/// # Matrix Room Export
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct MatrixExport {
+    /// Room name
+    pub room_name: String,
+    /// Room topic
+    pub topic: Option<String>,
+    /// Events in this room
+    #[serde(default)]
+    pub messages: Vec<MatrixEvent>,
+}
+
+/// # Matrix Event
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct MatrixEvent {
+    /// Event type
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Matrix ID of the sender
+    pub sender: String,
+    /// Time in milliseconds since the epoch at which this event was sent
+    pub origin_server_ts: i64,
+    /// Event content
+    #[serde(default)]
+    pub content: MatrixContent,
+}
+
+/// # Matrix Event Content
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default)]
+pub struct MatrixContent {
+    /// Plain text body of a message
+    pub body: Option<String>,
+    /// Display name set by a membership event
+    pub displayname: Option<String>,
+}
+
+/// Channel recovered from an export
+pub struct ImportedChannel {
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub messages: Vec<ImportedMessage>,
+}
+
+/// Message recovered from an export
+pub struct ImportedMessage {
+    pub author: String,
+    pub avatar: Option<String>,
+    pub content: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Role recovered from an export
+pub struct ImportedRole {
+    pub name: String,
+    pub colour: Option<String>,
+    pub position: i64,
+}
+
+/// Cut a string down to at most the given number of characters
+fn truncate(value: &str, max: usize) -> String {
+    value.trim().chars().take(max).collect()
+}
+
+/// Convert an optional string into a non-empty name
+fn name_or(value: &str, fallback: &str) -> String {
+    let name = truncate(value, MAX_NAME_LENGTH);
+    if name.is_empty() {
+        fallback.to_string()
+    } else {
+        name
+    }
+}
+
+/// Read channels and roles from Discord channel exports
+pub fn from_discord(exports: Vec<DiscordExport>) -> (Vec<ImportedChannel>, Vec<ImportedRole>) {
+    let mut roles: HashMap<String, ImportedRole> = HashMap::new();
+    let mut channels = vec![];
+
+    for export in exports {
+        let mut messages = vec![];
+        for message in export.messages {
+            for role in &message.author.roles {
+                let name = name_or(&role.name, "Imported Role");
+                roles.entry(name.clone()).or_insert_with(|| ImportedRole {
+                    name,
+                    colour: role
+                        .color
+                        .as_ref()
+                        .filter(|colour| colour.starts_with('#'))
+                        .cloned(),
+                    position: role.position,
+                });
+            }
+
+            // Only carry over regular messages and replies
+            if message.kind != "Default" && message.kind != "Reply" {
+                continue;
+            }
+
+            let Ok(timestamp) = DateTime::parse_from_rfc3339(&message.timestamp) else {
+                continue;
+            };
+
+            let mut content = message.content;
+            for attachment in message.attachments {
+                if !content.is_empty() {
+                    content.push('\n');
+                }
+
+                content.push_str(&attachment.url);
+            }
+
+            if content.trim().is_empty() {
+                continue;
+            }
+
+            messages.push(ImportedMessage {
+                author: message.author.nickname.unwrap_or(message.author.name),
+                avatar: message.author.avatar_url,
+                content,
+                timestamp: timestamp.with_timezone(&Utc),
+            });
+        }
+
+        channels.push(ImportedChannel {
+            name: name_or(&export.channel.name, "imported"),
+            description: export.channel.topic.filter(|topic| !topic.is_empty()),
+            category: export.channel.category,
+            messages,
+        });
+    }
+
+    let mut roles: Vec<ImportedRole> = roles.into_values().collect();
+    roles.sort_by(|a, b| b.position.cmp(&a.position));
+    (channels, roles)
+}
+
+/// Read channels from Matrix room exports
+pub fn from_matrix(exports: Vec<MatrixExport>) -> Vec<ImportedChannel> {
+    exports
+        .into_iter()
+        .map(|export| {
+            // Resolve display names from membership events as we go
+            let mut names: HashMap<String, String> = HashMap::new();
+            let mut messages = vec![];
+
+            for event in export.messages {
+                match event.kind.as_str() {
+                    "m.room.member" => {
+                        if let Some(name) = event.content.displayname {
+                            names.insert(event.sender, name);
+                        }
+                    }
+                    "m.room.message" => {
+                        let Some(content) =
+                            event.content.body.filter(|body| !body.trim().is_empty())
+                        else {
+                            continue;
+                        };
+
+                        let Some(timestamp) =
+                            Utc.timestamp_millis_opt(event.origin_server_ts).single()
+                        else {
+                            continue;
+                        };
+
+                        messages.push(ImportedMessage {
+                            author: names.get(&event.sender).cloned().unwrap_or(event.sender),
+                            avatar: None,
+                            content,
+                            timestamp,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            ImportedChannel {
+                name: name_or(&export.room_name, "imported"),
+                description: export.topic.filter(|topic| !topic.is_empty()),
+                category: None,
+                messages,
+            }
+        })
+        .collect()
+}
+
+/// Insert imported messages into a channel, keeping their original timestamps
+///
+/// Messages are written directly to the database without fanning out events
+/// or notifications, each masquerading as its original author.
+pub async fn backfill(
+    db: &Database,
+    channel_id: &str,
+    author_id: &str,
+    messages: Vec<ImportedMessage>,
+) {
+    let mut last_message_id = None;
+
+    for message in messages {
+        let id = Ulid::from_datetime(message.timestamp).to_string();
+        let avatar = message
+            .avatar
+            .filter(|url| url.len() <= MAX_AVATAR_LENGTH && url.starts_with("https://"));
+
+        let message = Message {
+            id: id.clone(),
+            channel: channel_id.to_string(),
+            author: author_id.to_string(),
+            content: Some(truncate(&message.content, MAX_CONTENT_LENGTH)),
+            masquerade: Some(Masquerade {
+                name: Some(name_or(&message.author, "Unknown")),
+                avatar,
+                colour: None,
+            }),
+            ..Default::default()
+        };
+
+        if let Err(err) = db.insert_message(&message).await {
+            log::warn!("Failed to import message into {channel_id}: {err:?}");
+            continue;
+        }
+
+        if last_message_id.as_ref().map_or(true, |last| &id > last) {
+            last_message_id = Some(id);
+        }
+    }
+
+    if let Some(last_message_id) = last_message_id {
+        if let Err(err) = db
+            .update_channel(
+                channel_id,
+                &PartialChannel {
+                    last_message_id: Some(last_message_id),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+        {
+            log::warn!("Failed to update last message of {channel_id}: {err:?}");
+        }
+    }
+}
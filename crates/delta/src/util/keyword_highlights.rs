@@ -0,0 +1,132 @@
+//! Notify members when messages contain their keywords
+
+use std::collections::HashMap;
+
+use revolt_database::Database;
+use revolt_quark::{
+    models::{Channel, Message},
+    perms,
+    tasks::ack::{self, AckEvent},
+    types::push::{MessageAuthor, PushNotification},
+    util::{i18n, pipeline::MessageFilter, spam::SpamScore},
+    Error, Permission, Result,
+};
+
+/// Notifies members whose keywords appear in a sent message, as if they were mentioned
+pub struct KeywordNotifier {
+    pub db: Database,
+}
+
+#[async_trait]
+impl MessageFilter for KeywordNotifier {
+    async fn after_send(
+        &self,
+        legacy_db: &revolt_quark::Database,
+        channel: &Channel,
+        message: &Message,
+        _spam: &SpamScore,
+    ) {
+        if let Err(err) = notify(&self.db, legacy_db, channel, message).await {
+            log::warn!("Failed to notify keywords for {}: {err:?}", message.id);
+        }
+    }
+}
+
+/// Find members with matching keywords who can read the message and notify them
+async fn notify(
+    db: &Database,
+    legacy_db: &revolt_quark::Database,
+    channel: &Channel,
+    message: &Message,
+) -> Result<()> {
+    let server_id = match channel {
+        Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => server,
+        _ => return Ok(()),
+    };
+
+    let content = match &message.content {
+        Some(content) if message.system.is_none() => content.to_lowercase(),
+        _ => return Ok(()),
+    };
+
+    // Members who were mentioned have already been notified
+    let mentions = message.mentions.as_deref().unwrap_or_default();
+    let user_ids: Vec<String> = db
+        .fetch_server_keyword_highlights(server_id)
+        .await
+        .map_err(Error::from_core)?
+        .into_iter()
+        .filter(|highlights| {
+            highlights.id.user != message.author
+                && !mentions.contains(&highlights.id.user)
+                && highlights.matches(&content)
+        })
+        .map(|highlights| highlights.id.user)
+        .collect();
+
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let server = legacy_db.fetch_server(server_id).await?;
+    let members = legacy_db.fetch_members(server_id, &user_ids).await?;
+
+    let mut targets_by_locale: HashMap<&'static str, Vec<String>> = HashMap::new();
+    for user in legacy_db.fetch_users(&user_ids).await? {
+        let member = match members.iter().find(|member| member.id.user == user.id) {
+            Some(member) => member,
+            None => continue,
+        };
+
+        if !perms(&user)
+            .channel(channel)
+            .server(&server)
+            .member(member)
+            .has_permission(legacy_db, Permission::ViewChannel)
+            .await?
+        {
+            continue;
+        }
+
+        ack::queue(
+            channel.id().to_string(),
+            user.id.clone(),
+            AckEvent::AddMention {
+                ids: vec![message.id.to_string()],
+            },
+        )
+        .await;
+
+        targets_by_locale
+            .entry(i18n::resolve(user.locale.as_deref()))
+            .or_default()
+            .push(user.id);
+    }
+
+    if targets_by_locale.is_empty() {
+        return Ok(());
+    }
+
+    let author = if message.webhook.is_none() {
+        legacy_db.fetch_user(&message.author).await.ok()
+    } else {
+        None
+    };
+
+    let author = author.as_ref().map(MessageAuthor::User);
+    for (locale, target_ids) in targets_by_locale {
+        revolt_quark::tasks::web_push::queue(
+            target_ids,
+            json!(PushNotification::new(
+                message.clone(),
+                author.as_ref(),
+                channel.id(),
+                Some(locale)
+            ))
+            .to_string(),
+        )
+        .await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Maintenance mode, rejecting writes while the instance is being worked on
+//!
+//! The state is cached in memory and refreshed by a background task,
+//! so every request can be checked without reaching the database.
+
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use revolt_database::{Database, MaintenanceStatus};
+use revolt_quark::{Error, Result};
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::{uri::Origin, Method},
+    serde::json::Json,
+    Data, Request,
+};
+
+/// Routes which remain writable so administrators can end maintenance
+static EXEMPT_PREFIXES: [&str; 2] = ["/admin", "/auth"];
+
+static STATUS: Lazy<RwLock<MaintenanceStatus>> = Lazy::new(|| {
+    RwLock::new(MaintenanceStatus {
+        enabled: false,
+        message: None,
+        ends_at: None,
+    })
+});
+
+/// Get the cached maintenance state
+pub fn current() -> MaintenanceStatus {
+    STATUS.read().unwrap().clone()
+}
+
+/// Replace the cached maintenance state
+pub fn set(status: MaintenanceStatus) {
+    *STATUS.write().unwrap() = status;
+}
+
+/// Reload the maintenance state from the database
+pub async fn refresh(db: &Database) -> Result<()> {
+    let status = MaintenanceStatus::fetch(db)
+        .await
+        .map_err(Error::from_core)?;
+
+    set(status);
+    Ok(())
+}
+
+/// Check whether a request would be rejected during maintenance
+fn is_blocked(request: &Request<'_>) -> bool {
+    if matches!(
+        request.method(),
+        Method::Get | Method::Head | Method::Options
+    ) {
+        return false;
+    }
+
+    let path = request.uri().path();
+    !EXEMPT_PREFIXES
+        .iter()
+        .any(|prefix| path.as_str().starts_with(prefix))
+}
+
+pub struct MaintenanceFairing;
+
+#[rocket::async_trait]
+impl Fairing for MaintenanceFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Maintenance",
+            kind: Kind::Request,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        if STATUS.read().unwrap().enabled && is_blocked(request) {
+            request.set_method(Method::Get);
+            request.set_uri(Origin::parse("/maintenance").unwrap())
+        }
+    }
+}
+
+#[rocket::get("/maintenance")]
+fn maintenance_info() -> Result<Json<revolt_models::v0::MaintenanceStatus>> {
+    let status = current();
+    if status.enabled {
+        Err(Error::UnderMaintenance {
+            retry_after: status.retry_after(),
+            reason: status.message,
+        })
+    } else {
+        Ok(Json(status.into()))
+    }
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    rocket::routes![maintenance_info]
+}
@@ -1 +1,19 @@
+pub mod audit_log;
+pub mod auto_thread;
+pub mod ban_sync;
+pub mod challenges;
+pub mod features;
+pub mod federation;
+pub mod highlights;
+pub mod import;
+pub mod keyword_highlights;
+pub mod maintenance;
+pub mod net;
+pub mod policies;
+pub mod reaction_roles;
 pub mod regex;
+pub mod session_scopes;
+pub mod verification;
+pub mod voice;
+pub mod warnings;
+pub mod webhook_limits;
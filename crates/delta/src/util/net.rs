@@ -0,0 +1,86 @@
+//! Outbound requests to addresses chosen by users or remote servers
+//!
+//! Hosts are resolved up front and rejected if any address points back into a
+//! private network, the resolved address is then pinned so the request can't be
+//! rebound to a different one. Redirects are not followed.
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+
+use async_std::net::ToSocketAddrs;
+use reqwest::{redirect::Policy, Client, Url};
+use revolt_quark::{Error, Result};
+
+/// Check whether an IPv4 address is reachable on the public internet
+fn is_public_v4(ip: &Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // Shared address space (100.64.0.0/10)
+        || (a == 100 && (64..128).contains(&b))
+        // Reserved (240.0.0.0/4)
+        || a >= 240)
+}
+
+/// Check whether an IPv6 address is reachable on the public internet
+fn is_public_v6(ip: &Ipv6Addr) -> bool {
+    if let Some(ip) = ip.to_ipv4_mapped() {
+        return is_public_v4(&ip);
+    }
+
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local (fc00::/7)
+        || (first & 0xfe00) == 0xfc00
+        // Link local (fe80::/10)
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// Check whether an address is reachable on the public internet
+pub fn is_public_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => is_public_v6(ip),
+    }
+}
+
+/// Resolve a URL and build a client which may only connect to its public address
+pub async fn public_client(url: &str, timeout: Duration) -> Result<(Client, Url)> {
+    let url = Url::parse(url).map_err(|_| Error::InvalidOperation)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Error::InvalidOperation);
+    }
+
+    let host = url.host_str().ok_or(Error::InvalidOperation)?.to_string();
+    let port = url.port_or_known_default().ok_or(Error::InvalidOperation)?;
+
+    let addresses: Vec<_> = (host.trim_matches(|c| c == '[' || c == ']'), port)
+        .to_socket_addrs()
+        .await
+        .map_err(|_| Error::InvalidOperation)?
+        .collect();
+
+    if addresses.is_empty() || !addresses.iter().all(|addr| is_public_address(&addr.ip())) {
+        return Err(Error::InvalidOperation);
+    }
+
+    let client = Client::builder()
+        .timeout(timeout)
+        .redirect(Policy::none())
+        .resolve(&host, addresses[0])
+        .build()
+        .map_err(|_| Error::InternalError)?;
+
+    Ok((client, url))
+}
@@ -0,0 +1,34 @@
+//! Terms of service and other policies users must accept to use this instance
+
+use revolt_database::{Database, PolicyAcceptance};
+use revolt_quark::{models::User, Error, Result};
+
+/// Ensure a user has accepted the current version of every policy document
+///
+/// Bots are not bound by instance policies.
+pub async fn assert_accepted(db: &Database, user: &User) -> Result<()> {
+    if user.bot.is_some() {
+        return Ok(());
+    }
+
+    let documents = db
+        .fetch_policy_documents()
+        .await
+        .map_err(Error::from_core)?;
+
+    if documents.is_empty() {
+        return Ok(());
+    }
+
+    let acceptance = PolicyAcceptance::fetch(db, &user.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    match acceptance.find_outstanding(&documents) {
+        Some(document) => Err(Error::PolicyNotAccepted {
+            policy: document.id.clone(),
+            version: document.version,
+        }),
+        None => Ok(()),
+    }
+}
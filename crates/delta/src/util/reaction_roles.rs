@@ -0,0 +1,67 @@
+//! Roles granted and revoked by reacting to messages
+
+use std::collections::HashSet;
+
+use revolt_database::{Database, ReactionRole};
+use revolt_quark::{
+    models::{server_member::PartialMember, Message},
+    Db, Error, Result,
+};
+
+/// Grant or revoke the roles bound to a reaction for each given user
+///
+/// Users who are not members of the server and roles which no longer exist are skipped.
+pub async fn apply(
+    db: &Database,
+    legacy_db: &Db,
+    message: &Message,
+    emoji: &str,
+    users: &[String],
+    react: bool,
+) -> Result<()> {
+    let bindings = ReactionRole::fetch_for_reaction(db, &message.id, emoji)
+        .await
+        .map_err(Error::from_core)?;
+
+    let server = match bindings.first() {
+        Some(binding) => legacy_db.fetch_server(&binding.server_id).await?,
+        None => return Ok(()),
+    };
+
+    let roles: Vec<&String> = bindings
+        .iter()
+        .map(|binding| &binding.role_id)
+        .filter(|id| server.roles.contains_key(*id))
+        .collect();
+
+    for user in users {
+        let mut member = match legacy_db.fetch_member(&server.id, user).await {
+            Ok(member) => member,
+            Err(_) => continue,
+        };
+
+        let mut next: HashSet<String> = member.roles.iter().cloned().collect();
+        for id in &roles {
+            if react {
+                next.insert(id.to_string());
+            } else {
+                next.remove(*id);
+            }
+        }
+
+        if next.len() != member.roles.len() {
+            member
+                .update(
+                    legacy_db,
+                    PartialMember {
+                        roles: Some(next.into_iter().collect()),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -12,3 +12,13 @@ pub static RE_USERNAME: Lazy<Regex> =
 ///
 /// Alphanumeric and underscores
 pub static RE_EMOJI: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9_]+$").unwrap());
+
+/// Regex for anchor and link tags in HTML documents
+pub static RE_HTML_LINK_TAG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)<(?:a|link)\s[^>]*>"#).unwrap());
+
+/// Regex for attributes on a HTML tag
+///
+/// Captures the name and one of the double quoted, single quoted or bare value
+pub static RE_HTML_ATTRIBUTE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)([a-z-]+)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).unwrap());
@@ -0,0 +1,74 @@
+//! Session scope enforcement for routes which don't go through the User guard
+//!
+//! Authentication routes only take a Session, so restricted sessions
+//! are checked here before any write reaches them.
+
+use revolt_quark::{
+    authifier::models::Session, models::user::SessionScope, Database, Error, Result,
+};
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::{uri::Origin, Method},
+    outcome::Outcome,
+    Data, Request,
+};
+
+/// Authentication routes which restricted sessions may still write to
+static EXEMPT_PATHS: [&str; 1] = ["/auth/session/logout"];
+
+/// Check whether the scopes on a session forbid this request
+fn is_restricted(request: &Request<'_>, scopes: &[SessionScope]) -> bool {
+    let path = request.uri().path();
+    if EXEMPT_PATHS.iter().any(|exempt| path.as_str() == *exempt) {
+        return false;
+    }
+
+    scopes.contains(&SessionScope::ReadOnly)
+        || (!scopes.is_empty() && path.as_str().starts_with("/auth"))
+}
+
+pub struct SessionScopeFairing;
+
+#[rocket::async_trait]
+impl Fairing for SessionScopeFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Session Scopes",
+            kind: Kind::Request,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        if matches!(
+            request.method(),
+            Method::Get | Method::Head | Method::Options
+        ) {
+            return;
+        }
+
+        let session = match request.guard::<Session>().await {
+            Outcome::Success(session) => session,
+            _ => return,
+        };
+
+        let db = request.rocket().state::<Database>().unwrap();
+        let blocked = match db.fetch_session_scopes(&session.id).await {
+            Ok(scopes) => is_restricted(request, &scopes),
+            Err(_) => true,
+        };
+
+        if blocked {
+            request.set_method(Method::Get);
+            request.set_uri(Origin::parse("/session_restricted").unwrap())
+        }
+    }
+}
+
+#[rocket::get("/session_restricted")]
+fn session_restricted() -> Result<()> {
+    Err(Error::SessionRestricted)
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    rocket::routes![session_restricted]
+}
@@ -0,0 +1,61 @@
+//! Instance requirements accounts must meet before sending DMs or joining many servers
+
+use chrono::Utc;
+use revolt_quark::{
+    authifier::{models::EmailVerification, Authifier},
+    models::User,
+    variables::delta::{MIN_ACCOUNT_AGE, REQUIRE_VERIFIED_EMAIL, UNVERIFIED_MAX_SERVER_COUNT},
+    Database, Error, Result,
+};
+
+/// Ensure a user's account meets the instance's verification requirements
+///
+/// Bots are not subject to verification requirements.
+pub async fn assert_verified(authifier: &Authifier, user: &User) -> Result<()> {
+    if user.bot.is_some() {
+        return Ok(());
+    }
+
+    if *MIN_ACCOUNT_AGE > 0 {
+        let created_at = ulid::Ulid::from_string(&user.id)
+            .map_err(|_| Error::InvalidOperation)?
+            .datetime();
+
+        if Utc::now().signed_duration_since(created_at).num_seconds() < *MIN_ACCOUNT_AGE {
+            return Err(Error::VerificationRequired {
+                reason: "account_age".to_string(),
+            });
+        }
+    }
+
+    if *REQUIRE_VERIFIED_EMAIL {
+        let account = authifier
+            .database
+            .find_account(&user.id)
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        if !matches!(account.verification, EmailVerification::Verified) {
+            return Err(Error::VerificationRequired {
+                reason: "email".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure a user may join another server
+///
+/// Unverified accounts may only be in a limited number of servers.
+pub async fn assert_can_join_server(
+    authifier: &Authifier,
+    db: &Database,
+    user: &User,
+) -> Result<()> {
+    if db.fetch_server_count(&user.id).await? < *UNVERIFIED_MAX_SERVER_COUNT {
+        return Ok(());
+    }
+
+    assert_verified(authifier, user).await
+}
@@ -0,0 +1,52 @@
+//! Voice activity events and session history
+
+use revolt_database::{Database, VoiceSession};
+use revolt_quark::{events::client::EventV1, models::Channel, Db};
+
+/// Where voice activity in a channel should be published
+fn topic(channel: &Channel) -> String {
+    match channel {
+        Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+            server.clone()
+        }
+        _ => channel.id().to_string(),
+    }
+}
+
+/// Announce that a user joined a call
+///
+/// A session is recorded if the channel's server has analytics enabled.
+pub async fn joined(db: &Database, legacy_db: &Db, channel: &Channel, user_id: &str) {
+    EventV1::VoiceChannelJoin {
+        id: channel.id().to_string(),
+        user: user_id.to_string(),
+    }
+    .p(topic(channel))
+    .await;
+
+    if let Channel::VoiceChannel { server, .. } = channel {
+        match legacy_db.fetch_server(server).await {
+            Ok(server) if server.analytics => {
+                if let Err(err) = VoiceSession::start(db, &server.id, channel.id(), user_id).await {
+                    log::warn!("Failed to record voice session in {}: {err:?}", server.id);
+                }
+            }
+            Ok(_) => {}
+            Err(err) => log::warn!("Failed to fetch server {server}: {err:?}"),
+        }
+    }
+}
+
+/// Announce that a user left a call and end any open session
+pub async fn left(db: &Database, channel: &Channel, user_id: &str) {
+    EventV1::VoiceChannelLeave {
+        id: channel.id().to_string(),
+        user: user_id.to_string(),
+    }
+    .p(topic(channel))
+    .await;
+
+    if let Err(err) = VoiceSession::end(db, user_id).await {
+        log::warn!("Failed to end voice session for {user_id}: {err:?}");
+    }
+}
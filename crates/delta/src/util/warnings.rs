@@ -0,0 +1,107 @@
+//! Notices and automatic escalation for member warnings
+
+use std::time::{Duration, SystemTime};
+
+use revolt_database::{Database, ModerationAction, ModerationCase};
+use revolt_quark::{
+    models::{message::SystemMessage, server_member::PartialMember, Channel, Member, Server, User},
+    perms, Db, Error, Result, Timestamp,
+};
+use ulid::Ulid;
+
+/// Let a user know they were warned through their direct messages with the moderator
+///
+/// Nothing is sent if the moderator is not allowed to open a DM with the user.
+pub async fn notify(legacy_db: &Db, moderator: &User, case: &ModerationCase) -> Result<()> {
+    let channel = if let Ok(channel) = legacy_db
+        .find_direct_message_channel(&moderator.id, &case.user_id)
+        .await
+    {
+        channel
+    } else {
+        let target = legacy_db.fetch_user(&case.user_id).await?;
+        if !perms(moderator)
+            .user(&target)
+            .calc_user(legacy_db)
+            .await
+            .get_send_message()
+        {
+            return Ok(());
+        }
+
+        let channel = Channel::DirectMessage {
+            id: Ulid::new().to_string(),
+            active: false,
+            recipients: vec![moderator.id.clone(), target.id],
+            last_message_id: None,
+        };
+
+        channel.create(legacy_db).await?;
+        channel
+    };
+
+    SystemMessage::MemberWarned {
+        server: case.server_id.clone(),
+        by: moderator.id.clone(),
+        reason: case.reason.clone().unwrap_or_default(),
+    }
+    .into_message(channel.id().to_string())
+    .create(legacy_db, &channel, None)
+    .await
+}
+
+/// Time out a member if their warning count has reached one of the server's thresholds
+///
+/// Each threshold applies once, when the member receives exactly that many warnings.
+pub async fn escalate(
+    db: &Database,
+    legacy_db: &Db,
+    server: &Server,
+    member: &mut Member,
+    moderator_id: &str,
+) -> Result<()> {
+    let thresholds = match &server.warn_thresholds {
+        Some(thresholds) if !thresholds.is_empty() => thresholds,
+        _ => return Ok(()),
+    };
+
+    let warnings = db
+        .fetch_member_moderation_cases(&server.id, &member.id.user)
+        .await
+        .map_err(Error::from_core)?
+        .into_iter()
+        .filter(|case| matches!(case.action, ModerationAction::Warning))
+        .count();
+
+    let threshold = match thresholds
+        .iter()
+        .find(|threshold| threshold.warnings as usize == warnings)
+    {
+        Some(threshold) => threshold,
+        None => return Ok(()),
+    };
+
+    let until = Timestamp::from(SystemTime::now() + Duration::from_secs(threshold.timeout));
+    member
+        .update(
+            legacy_db,
+            PartialMember {
+                timeout: Some(until),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    ModerationCase::create(
+        db,
+        &server.id,
+        &member.id.user,
+        moderator_id,
+        ModerationAction::Timeout { until },
+        Some(format!("Reached {warnings} warnings")),
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from_core)
+}
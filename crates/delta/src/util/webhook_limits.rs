@@ -0,0 +1,87 @@
+//! Per-webhook message rate limits and daily quotas
+//!
+//! Usage is counted in Redis within fixed windows, so limits are shared
+//! between nodes and survive restarts. Days start at midnight UTC.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use revolt_database::Webhook;
+use revolt_quark::{
+    redis_kiss::{get_connection, AsyncCommands},
+    Error, Result,
+};
+
+const MINUTE: Duration = Duration::from_secs(60);
+const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Get the current time from Unix Epoch in milliseconds
+fn now() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards...")
+        .as_millis()
+}
+
+/// Redis key counting a webhook's usage within the window containing the given time
+fn window_key(id: &str, length: Duration, now: u128) -> String {
+    format!(
+        "webhook_limits:{id}:{}:{}",
+        length.as_secs(),
+        now / length.as_millis()
+    )
+}
+
+/// Count a message against a webhook's limits
+///
+/// Fails with the time until the exhausted window resets if any limit has been reached.
+/// Messages are let through if Redis cannot be reached.
+pub async fn consume(webhook: &Webhook) -> Result<()> {
+    let limits = match &webhook.limits {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
+
+    let windows: Vec<(u32, Duration)> = vec![(limits.per_minute, MINUTE), (limits.per_day, DAY)]
+        .into_iter()
+        .filter_map(|(limit, length)| limit.map(|limit| (limit, length)))
+        .collect();
+
+    if windows.is_empty() {
+        return Ok(());
+    }
+
+    let mut conn = match get_connection().await {
+        Ok(conn) => conn,
+        Err(_) => return Ok(()),
+    };
+
+    let now = now();
+    let mut counted = vec![];
+    let mut retry_after = None;
+    for (limit, length) in windows {
+        let key = window_key(&webhook.id, length, now);
+        let used: u32 = match conn.incr(&key, 1).await {
+            Ok(used) => used,
+            Err(_) => continue,
+        };
+
+        let _: Option<()> = conn.expire(&key, length.as_secs() as usize).await.ok();
+        counted.push(key);
+
+        if used > limit {
+            let reset = length.as_millis() - now % length.as_millis();
+            retry_after = retry_after.max(Some(reset));
+        }
+    }
+
+    if let Some(retry_after) = retry_after {
+        // Rejected messages should not use up the rest of the quota
+        for key in counted {
+            let _: Option<()> = conn.decr(&key, 1).await.ok();
+        }
+
+        return Err(Error::TooManyRequests { retry_after });
+    }
+
+    Ok(())
+}
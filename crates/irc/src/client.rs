@@ -0,0 +1,567 @@
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+};
+
+use async_std::{
+    io::{prelude::BufReadExt, BufReader, WriteExt},
+    net::TcpStream,
+    sync::Mutex,
+    task,
+};
+use futures::{
+    channel::mpsc::{unbounded, UnboundedSender},
+    pin_mut, select, FutureExt, StreamExt,
+};
+use revolt_quark::{
+    events::client::EventV1,
+    models::{
+        message::{DataMessageSend, Masquerade},
+        user::UserHint,
+        Channel, Message, User,
+    },
+    perms, redis_kiss,
+    types::push::MessageAuthor,
+    web::idempotency::IdempotencyKey,
+    Database, Permission, Result,
+};
+
+use crate::protocol::{channel_id, channel_name, sanitise_nick, Command, Line, SERVER_NAME};
+
+/// Change to the set of topics we are subscribed to
+enum Subscription {
+    Add(String),
+    Remove(String),
+}
+
+/// Shared connection state for an authenticated client
+struct Session {
+    /// Authenticated user
+    user: User,
+    /// Nickname the client is currently using
+    nick: Mutex<String>,
+    /// Ids of channels the client has joined
+    joined: Mutex<HashSet<String>>,
+    /// Cache of user ids to nicknames for incoming messages
+    nicks: Mutex<HashMap<String, String>>,
+    /// Write half of the TCP stream
+    write: Mutex<TcpStream>,
+}
+
+impl Session {
+    /// Send a line to the client
+    async fn send(&self, line: Line) -> bool {
+        self.write
+            .lock()
+            .await
+            .write_all(line.to_string().as_bytes())
+            .await
+            .is_ok()
+    }
+
+    /// Send a numeric reply to the client
+    async fn reply(&self, numeric: &str, mut params: Vec<String>) -> bool {
+        params.insert(0, self.nick.lock().await.clone());
+        self.send(Line::server(numeric, params)).await
+    }
+
+    /// Send a line to the client as if we had sent it ourselves
+    async fn echo(&self, command: &str, params: Vec<String>) -> bool {
+        let nick = self.nick.lock().await.clone();
+        self.send(Line::user(&nick, &self.user.id, command, params))
+            .await
+    }
+
+    /// Resolve the nickname to display for a given message
+    async fn resolve_nick(&self, db: &Database, message: &Message) -> String {
+        if let Some(name) = message.masquerade.as_ref().and_then(|m| m.name.as_ref()) {
+            return sanitise_nick(name);
+        }
+
+        if let Some(webhook) = &message.webhook {
+            return sanitise_nick(&webhook.name);
+        }
+
+        let mut nicks = self.nicks.lock().await;
+        if let Some(nick) = nicks.get(&message.author) {
+            return nick.clone();
+        }
+
+        let nick = db
+            .fetch_user(&message.author)
+            .await
+            .map(|user| sanitise_nick(&user.username))
+            .unwrap_or_else(|_| message.author.clone());
+
+        nicks.insert(message.author.clone(), nick.clone());
+        nick
+    }
+
+    /// Translate an incoming Revolt event to IRC lines
+    async fn handle_event(&self, db: &Database, event: EventV1) -> bool {
+        match event {
+            EventV1::Message(message) => {
+                if message.author == self.user.id && message.webhook.is_none() {
+                    return true;
+                }
+
+                if !self.joined.lock().await.contains(&message.channel) {
+                    return true;
+                }
+
+                let nick = self.resolve_nick(db, &message).await;
+                let target = channel_name(&message.channel);
+
+                let mut lines: Vec<String> = message
+                    .content
+                    .as_deref()
+                    .unwrap_or_default()
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(Into::into)
+                    .collect();
+
+                for file in message.attachments.unwrap_or_default() {
+                    lines.push(format!("[attachment: {}]", file.filename));
+                }
+
+                for line in lines {
+                    if !self
+                        .send(Line::user(
+                            &nick,
+                            &message.author,
+                            "PRIVMSG",
+                            vec![target.clone(), line],
+                        ))
+                        .await
+                    {
+                        return false;
+                    }
+                }
+
+                true
+            }
+            EventV1::ChannelDelete { id } => {
+                if self.joined.lock().await.remove(&id) {
+                    self.echo("PART", vec![channel_name(&id), "Channel deleted".into()])
+                        .await
+                } else {
+                    true
+                }
+            }
+            _ => true,
+        }
+    }
+
+    /// Fetch a channel and ensure the user may view it
+    async fn fetch_viewable_channel(&self, db: &Database, id: &str) -> Result<Channel> {
+        let channel = db.fetch_channel(id).await?;
+        perms(&self.user)
+            .channel(&channel)
+            .throw_permission_and_view_channel(db, Permission::ViewChannel)
+            .await?;
+
+        Ok(channel)
+    }
+
+    /// Join a channel by its IRC name
+    async fn join(&self, db: &Database, name: &str, tx: &UnboundedSender<Subscription>) -> bool {
+        let channel = match channel_id(name) {
+            Some(id) => self.fetch_viewable_channel(db, id).await,
+            None => {
+                return self
+                    .reply("403", vec![name.into(), "No such channel".into()])
+                    .await
+            }
+        };
+
+        let channel = match channel {
+            Ok(channel) => channel,
+            Err(_) => {
+                return self
+                    .reply("403", vec![name.into(), "No such channel".into()])
+                    .await
+            }
+        };
+
+        let id = channel.id().to_string();
+        if self.joined.lock().await.insert(id.clone()) {
+            tx.unbounded_send(Subscription::Add(id.clone())).ok();
+        }
+
+        let nick = self.nick.lock().await.clone();
+        let topic = match &channel {
+            Channel::TextChannel {
+                name, description, ..
+            }
+            | Channel::VoiceChannel {
+                name, description, ..
+            }
+            | Channel::Group {
+                name, description, ..
+            } => description.clone().unwrap_or_else(|| name.clone()),
+            _ => "Direct Message".into(),
+        };
+
+        self.echo("JOIN", vec![channel_name(&id)]).await
+            && self.reply("332", vec![channel_name(&id), topic]).await
+            && self
+                .reply("353", vec!["=".into(), channel_name(&id), nick])
+                .await
+            && self
+                .reply("366", vec![channel_name(&id), "End of /NAMES list".into()])
+                .await
+    }
+
+    /// Leave a channel by its IRC name
+    async fn part(&self, name: &str, tx: &UnboundedSender<Subscription>) -> bool {
+        let removed = match channel_id(name) {
+            Some(id) => self.joined.lock().await.remove(id),
+            None => false,
+        };
+
+        if removed {
+            tx.unbounded_send(Subscription::Remove(channel_id(name).unwrap().into()))
+                .ok();
+            self.echo("PART", vec![name.into()]).await
+        } else {
+            self.reply(
+                "442",
+                vec![name.into(), "You're not on that channel".into()],
+            )
+            .await
+        }
+    }
+
+    /// Send a message from the client to a Revolt channel
+    async fn privmsg(&self, db: &Database, target: &str, text: String) -> Result<()> {
+        let id = channel_id(target).unwrap_or_default();
+        if id.is_empty() || !self.joined.lock().await.contains(id) {
+            self.reply(
+                "442",
+                vec![target.into(), "You're not on that channel".into()],
+            )
+            .await;
+            return Ok(());
+        }
+
+        let channel = db.fetch_channel(id).await?;
+        let mut permissions = perms(&self.user).channel(&channel);
+        permissions
+            .throw_permission_and_view_channel(db, Permission::SendMessage)
+            .await?;
+
+        // Translate CTCP actions into emphasised text.
+        let content = match text
+            .strip_prefix("\u{1}ACTION ")
+            .map(|v| v.trim_end_matches('\u{1}'))
+        {
+            Some(action) => format!("*{action}*"),
+            None => text,
+        };
+
+        // Masquerade as the IRC nickname if it differs from the username.
+        let nick = self.nick.lock().await.clone();
+        let masquerade = if nick != self.user.username
+            && permissions
+                .has_permission(db, Permission::Masquerade)
+                .await?
+        {
+            Some(Masquerade {
+                name: Some(nick.chars().take(32).collect()),
+                avatar: None,
+                colour: None,
+            })
+        } else {
+            None
+        };
+
+        channel
+            .send_message(
+                db,
+                DataMessageSend {
+                    nonce: None,
+                    content: Some(content),
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade,
+                    interactions: None,
+                    effect: None,
+                    thread: None,
+                    send_at: None,
+                },
+                MessageAuthor::User(&self.user),
+                IdempotencyKey::default(),
+                permissions
+                    .has_permission(db, Permission::SendEmbeds)
+                    .await?,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// List all channels the user can see
+    async fn list(&self, db: &Database) -> bool {
+        if !self
+            .reply("321", vec!["Channel".into(), "Users  Name".into()])
+            .await
+        {
+            return false;
+        }
+
+        let mut entries = vec![];
+        if let Ok(memberships) = db.fetch_all_memberships(&self.user.id).await {
+            let ids: Vec<String> = memberships.into_iter().map(|m| m.id.server).collect();
+            for server in db.fetch_servers(&ids).await.unwrap_or_default() {
+                for channel in db
+                    .fetch_channels(&server.channels)
+                    .await
+                    .unwrap_or_default()
+                {
+                    if let Channel::TextChannel { id, name, .. }
+                    | Channel::VoiceChannel { id, name, .. } = &channel
+                    {
+                        if perms(&self.user)
+                            .channel(&channel)
+                            .has_permission(db, Permission::ViewChannel)
+                            .await
+                            .unwrap_or_default()
+                        {
+                            entries.push((id.clone(), format!("{} / {name}", server.name)));
+                        }
+                    }
+                }
+            }
+        }
+
+        for channel in db
+            .find_direct_messages(&self.user.id)
+            .await
+            .unwrap_or_default()
+        {
+            if let Channel::Group { id, name, .. } = channel {
+                entries.push((id, name));
+            }
+        }
+
+        for (id, name) in entries {
+            if !self
+                .reply("322", vec![channel_name(&id), "0".into(), name])
+                .await
+            {
+                return false;
+            }
+        }
+
+        self.reply("323", vec!["End of /LIST".into()]).await
+    }
+}
+
+/// Spawn a new IRC client worker given access to the database,
+/// the relevant TCP stream and the remote address of the client.
+pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr) {
+    // Spawn a new Async task to work on.
+    task::spawn(async move {
+        info!("User connected from {addr:?}");
+
+        let mut lines = BufReader::new(stream.clone()).lines();
+        let mut write = stream;
+
+        // Wait for the client to register, we require a token to be provided with PASS.
+        let (mut token, mut nick, mut registered) = (None, None, false);
+        while let Some(Ok(line)) = lines.next().await {
+            match Command::parse(&line) {
+                Some(Command::Pass(value)) => token = Some(value),
+                Some(Command::Nick(value)) => nick = Some(value),
+                Some(Command::User(_)) => registered = true,
+                Some(Command::Ping(data)) => {
+                    write
+                        .write_all(
+                            Line::server("PONG", vec![SERVER_NAME.into(), data])
+                                .to_string()
+                                .as_bytes(),
+                        )
+                        .await
+                        .ok();
+                }
+                Some(Command::Quit) => break,
+                _ => {}
+            }
+
+            if registered && nick.is_some() {
+                break;
+            }
+        }
+
+        let (Some(token), Some(nick), true) = (token, nick, registered) else {
+            info!("User disconnected from {addr:?}");
+            return;
+        };
+
+        // Try to authenticate the user.
+        let user = match User::from_token(db, &token, UserHint::Any).await {
+            Ok(user) => user,
+            Err(_) => {
+                write
+                    .write_all(b"ERROR :Closing link (Authentication failed)\r\n")
+                    .await
+                    .ok();
+
+                info!("User disconnected from {addr:?}");
+                return;
+            }
+        };
+
+        info!("User {addr:?} authenticated as @{}", user.username);
+
+        let session = Session {
+            nick: Mutex::new(sanitise_nick(&nick)),
+            joined: Mutex::new(HashSet::new()),
+            nicks: Mutex::new(HashMap::new()),
+            write: Mutex::new(write),
+            user,
+        };
+
+        // Welcome the client.
+        if !(session
+            .reply(
+                "001",
+                vec![format!("Welcome to Revolt, @{}", session.user.username)],
+            )
+            .await
+            && session
+                .reply("002", vec![format!("Your host is {SERVER_NAME}")])
+                .await
+            && session
+                .reply("422", vec!["Use /LIST to find channels to join".into()])
+                .await)
+        {
+            return;
+        }
+
+        let (tx, mut rx) = unbounded();
+
+        // Create a PubSub connection to poll on.
+        let listener = async {
+            if let Ok(mut conn) = redis_kiss::open_pubsub_connection().await {
+                loop {
+                    let incoming = {
+                        let mut messages = conn.on_message();
+                        select! {
+                            item = messages.next().fuse() => Ok(item.map(|item| {
+                                (
+                                    item.get_channel_name().to_string(),
+                                    redis_kiss::decode_payload::<EventV1>(&item),
+                                )
+                            })),
+                            change = rx.next() => Err(change),
+                        }
+                    };
+
+                    match incoming {
+                        // Handle incoming events.
+                        Ok(Some((channel, item))) => {
+                            if let Ok(event) = item {
+                                if !session.handle_event(db, event).await {
+                                    break;
+                                }
+                            } else {
+                                warn!("Failed to deserialise an event for {channel}!");
+                            }
+                        }
+                        // Apply changes to our subscriptions.
+                        Err(Some(Subscription::Add(id))) => {
+                            if let Err(err) = conn.subscribe(&id).await {
+                                warn!("Failed to subscribe to {id}, closing session: {err:?}");
+                                break;
+                            }
+                        }
+                        Err(Some(Subscription::Remove(id))) => {
+                            if let Err(err) = conn.unsubscribe(&id).await {
+                                warn!("Failed to unsubscribe from {id}, closing session: {err:?}");
+                                break;
+                            }
+                        }
+                        // No more data, assume we disconnected or otherwise
+                        // something bad occurred, so disconnect user.
+                        Ok(None) | Err(None) => break,
+                    }
+                }
+            }
+        }
+        .fuse();
+
+        // Read from TCP stream.
+        let worker = async {
+            while let Some(Ok(line)) = lines.next().await {
+                let ok = match Command::parse(&line) {
+                    Some(Command::Join(targets)) => {
+                        let mut ok = true;
+                        for target in targets {
+                            ok = ok && session.join(db, &target, &tx).await;
+                        }
+
+                        ok
+                    }
+                    Some(Command::Part(targets)) => {
+                        let mut ok = true;
+                        for target in targets {
+                            ok = ok && session.part(&target, &tx).await;
+                        }
+
+                        ok
+                    }
+                    Some(Command::Privmsg { target, text }) => {
+                        if let Err(err) = session.privmsg(db, &target, text).await {
+                            session
+                                .send(Line::server(
+                                    "NOTICE",
+                                    vec![target, format!("Failed to send message: {err:?}")],
+                                ))
+                                .await
+                        } else {
+                            true
+                        }
+                    }
+                    Some(Command::Nick(value)) => {
+                        let value = sanitise_nick(&value);
+                        let ok = session.echo("NICK", vec![value.clone()]).await;
+                        *session.nick.lock().await = value;
+                        ok
+                    }
+                    Some(Command::List) => session.list(db).await,
+                    Some(Command::Ping(data)) => {
+                        session
+                            .send(Line::server("PONG", vec![SERVER_NAME.into(), data]))
+                            .await
+                    }
+                    Some(Command::Unknown(command)) => {
+                        session
+                            .reply("421", vec![command, "Unknown command".into()])
+                            .await
+                    }
+                    Some(Command::Quit) => break,
+                    _ => true,
+                };
+
+                if !ok {
+                    break;
+                }
+            }
+        }
+        .fuse();
+
+        // Pin both tasks.
+        pin_mut!(listener, worker);
+
+        // Wait for either disconnect or for listener to die.
+        select!(
+            () = listener => {},
+            () = worker => {}
+        );
+
+        info!("User disconnected from {addr:?}");
+    });
+}
@@ -0,0 +1,30 @@
+use std::env;
+
+use async_std::net::TcpListener;
+
+#[macro_use]
+extern crate log;
+
+pub mod protocol;
+
+mod client;
+mod database;
+
+#[async_std::main]
+async fn main() {
+    // Configure requirements for the IRC gateway.
+    revolt_quark::configure!();
+    database::connect().await;
+
+    // Setup a TCP listener to accept IRC connections on.
+    // By default, we bind to port 6667 on all interfaces.
+    let bind = env::var("HOST").unwrap_or_else(|_| "0.0.0.0:6667".into());
+    info!("Listening on host {bind}");
+    let try_socket = TcpListener::bind(bind).await;
+    let listener = try_socket.expect("Failed to bind");
+
+    // Start accepting new connections and spawn a client for each connection.
+    while let Ok((stream, addr)) = listener.accept().await {
+        client::spawn_client(database::get_db(), stream, addr);
+    }
+}
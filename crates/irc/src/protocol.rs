@@ -0,0 +1,173 @@
+use std::fmt;
+
+/// Name this gateway identifies itself as
+pub static SERVER_NAME: &str = "revolt";
+
+/// Incoming command sent by an IRC client
+#[derive(Debug)]
+pub enum Command {
+    /// Provide session or bot token
+    Pass(String),
+    /// Set (or change) nickname
+    Nick(String),
+    /// Provide username and real name
+    User(String),
+    /// Join one or more channels
+    Join(Vec<String>),
+    /// Leave one or more channels
+    Part(Vec<String>),
+    /// Send a message to a target
+    Privmsg { target: String, text: String },
+    /// List available channels
+    List,
+    /// Keep-alive ping
+    Ping(String),
+    /// Keep-alive response
+    Pong,
+    /// Disconnect from the gateway
+    Quit,
+    /// Any command we do not handle
+    Unknown(String),
+}
+
+impl Command {
+    /// Parse a single line received from an IRC client
+    pub fn parse(line: &str) -> Option<Command> {
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        // Discard any message tags and source prefix, we don't trust either.
+        let mut rest = line;
+        if rest.starts_with('@') || rest.starts_with(':') {
+            rest = rest.split_once(' ').map(|(_, v)| v)?;
+        }
+
+        if rest.starts_with(':') {
+            rest = rest.split_once(' ').map(|(_, v)| v)?;
+        }
+
+        // Split parameters, honouring the trailing parameter.
+        let (head, trailing) = match rest.split_once(" :") {
+            Some((head, trailing)) => (head, Some(trailing)),
+            None => (rest, None),
+        };
+
+        let mut params: Vec<String> = head
+            .split(' ')
+            .filter(|v| !v.is_empty())
+            .map(Into::into)
+            .collect();
+        if params.is_empty() {
+            return None;
+        }
+
+        let command = params.remove(0).to_uppercase();
+        if let Some(trailing) = trailing {
+            params.push(trailing.to_string());
+        }
+
+        let mut params = params.into_iter();
+        Some(match command.as_str() {
+            "PASS" => Command::Pass(params.next()?),
+            "NICK" => Command::Nick(params.next()?),
+            "USER" => Command::User(params.next()?),
+            "JOIN" => Command::Join(split_targets(&params.next()?)),
+            "PART" => Command::Part(split_targets(&params.next()?)),
+            "PRIVMSG" | "NOTICE" => Command::Privmsg {
+                target: params.next()?,
+                text: params.next()?,
+            },
+            "LIST" => Command::List,
+            "PING" => Command::Ping(params.next().unwrap_or_default()),
+            "PONG" => Command::Pong,
+            "QUIT" => Command::Quit,
+            _ => Command::Unknown(command),
+        })
+    }
+}
+
+/// Split a comma separated list of targets
+fn split_targets(list: &str) -> Vec<String> {
+    list.split(',')
+        .filter(|v| !v.is_empty())
+        .map(Into::into)
+        .collect()
+}
+
+/// Outgoing line sent to an IRC client
+pub struct Line {
+    /// Source of this line, defaults to the server
+    pub source: Option<String>,
+    /// Command or numeric reply
+    pub command: String,
+    /// Parameters, the last of which is sent as trailing
+    pub params: Vec<String>,
+}
+
+impl Line {
+    /// Create a new line originating from the server
+    pub fn server(command: &str, params: Vec<String>) -> Line {
+        Line {
+            source: None,
+            command: command.to_string(),
+            params,
+        }
+    }
+
+    /// Create a new line originating from a user
+    pub fn user(nick: &str, user_id: &str, command: &str, params: Vec<String>) -> Line {
+        Line {
+            source: Some(format!("{nick}!{user_id}@{SERVER_NAME}")),
+            command: command.to_string(),
+            params,
+        }
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ":{} {}",
+            self.source.as_deref().unwrap_or(SERVER_NAME),
+            self.command
+        )?;
+
+        if let Some((last, rest)) = self.params.split_last() {
+            for param in rest {
+                write!(f, " {param}")?;
+            }
+
+            write!(f, " :{last}")?;
+        }
+
+        write!(f, "\r\n")
+    }
+}
+
+/// Convert a Revolt display name into a valid IRC nickname
+pub fn sanitise_nick(name: &str) -> String {
+    let nick: String = name
+        .chars()
+        .map(|c| match c {
+            ' ' | ',' | '*' | '?' | '!' | '@' | ':' | '#' | '&' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    if nick.is_empty() {
+        "_".to_string()
+    } else {
+        nick
+    }
+}
+
+/// Convert a Revolt channel id into an IRC channel name
+pub fn channel_name(id: &str) -> String {
+    format!("#{id}")
+}
+
+/// Convert an IRC channel name into a Revolt channel id
+pub fn channel_id(name: &str) -> Option<&str> {
+    name.strip_prefix('#').filter(|id| !id.is_empty())
+}
@@ -1,17 +1,22 @@
 use authifier::AuthifierEvent;
-use revolt_models::v0::{FieldsWebhook, PartialWebhook, Webhook};
+use indexmap::{IndexMap, IndexSet};
+use revolt_models::v0::{
+    FederatedMessage, FieldsWebhook, MaintenanceStatus, PartialThread, PartialWebhook, Thread,
+    Webhook,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::models::channel::{FieldsChannel, PartialChannel};
 use crate::models::message::{AppendMessage, PartialMessage};
 use crate::models::server::{FieldsRole, FieldsServer, PartialRole, PartialServer};
 use crate::models::server_member::{FieldsMember, MemberCompositeKey, PartialMember};
-use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus};
+use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, UserFlagsValue};
+use crate::models::user_settings::{DmLayout, ServerLayout};
 use crate::models::{Channel, Emoji, Member, Message, Report, Server, User, UserSettings};
 use crate::Error;
 
 /// WebSocket Client Errors
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(tag = "error")]
 pub enum WebSocketError {
     LabelMe,
@@ -23,7 +28,7 @@ pub enum WebSocketError {
 }
 
 /// Ping Packet
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 pub enum Ping {
     Binary(Vec<u8>),
@@ -31,7 +36,7 @@ pub enum Ping {
 }
 
 /// Untagged Error
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ErrorEvent {
     Error(WebSocketError),
@@ -39,7 +44,7 @@ pub enum ErrorEvent {
 }
 
 /// Protocol Events
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum EventV1 {
     /// Multiple events
@@ -60,6 +65,15 @@ pub enum EventV1 {
     /// Ping response
     Pong { data: Ping },
 
+    /// Batch of client events was accepted by the broker
+    Acknowledged { id: String },
+
+    /// Batch of client events could not be queued and should be re-sent
+    DeliveryFailed { id: String },
+
+    /// Gateway node is shutting down, clients should reconnect
+    Reconnect,
+
     /// New message
     Message(Message),
 
@@ -103,6 +117,18 @@ pub enum EventV1 {
         emoji_id: String,
     },
 
+    /// Reaction changes to a message made in quick succession
+    MessageReactBatch {
+        id: String,
+        channel_id: String,
+        /// Emojis cleared from the message, applied first
+        cleared: Vec<String>,
+        /// Users who removed each emoji
+        removed: IndexMap<String, IndexSet<String>>,
+        /// Users who added each emoji
+        added: IndexMap<String, IndexSet<String>>,
+    },
+
     /// Bulk delete messages
     BulkMessageDelete { channel: String, ids: Vec<String> },
 
@@ -138,6 +164,23 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// User was moved to another voice channel
+    VoiceChannelMove { id: String, user: String },
+
+    /// User joined a voice channel
+    VoiceChannelJoin { id: String, user: String },
+
+    /// User left a voice channel
+    VoiceChannelLeave { id: String, user: String },
+
+    /// Member was server muted or deafened
+    VoiceMemberUpdate {
+        id: String,
+        user: String,
+        muted: Option<bool>,
+        deafened: Option<bool>,
+    },
+
     /// New server
     ServerCreate {
         id: String,
@@ -198,6 +241,18 @@ pub enum EventV1 {
     /// Settings updated remotely
     UserSettingsUpdate { id: String, update: UserSettings },
 
+    /// Announcement from the instance administrators
+    SystemBroadcast { id: String, content: String },
+
+    /// Instance maintenance state changed
+    MaintenanceUpdate(MaintenanceStatus),
+
+    /// Server list layout updated remotely
+    UserServerLayoutUpdate { id: String, layout: ServerLayout },
+
+    /// DM list layout updated remotely
+    UserDmLayoutUpdate { id: String, layout: DmLayout },
+
     /// User has been platform banned or deleted their account
     ///
     /// Clients should remove the following associated data:
@@ -207,11 +262,17 @@ pub enum EventV1 {
     /// - Server Memberships
     ///
     /// User flags are specified to explain why a wipe is occurring though not all reasons will necessarily ever appear.
-    UserPlatformWipe { user_id: String, flags: i32 },
+    UserPlatformWipe {
+        user_id: String,
+        flags: UserFlagsValue,
+    },
 
     /// New emoji
     EmojiCreate(Emoji),
 
+    /// Update existing emoji
+    EmojiUpdate(Emoji),
+
     /// Delete emoji
     EmojiDelete { id: String },
 
@@ -228,9 +289,18 @@ pub enum EventV1 {
     /// Delete webhook
     WebhookDelete { id: String },
 
+    /// New thread
+    ThreadCreate(Thread),
+
+    /// Update existing thread
+    ThreadUpdate { id: String, data: PartialThread },
+
     /// New report
     ReportCreate(Report),
 
+    /// New direct message from or to a user on another instance
+    FederatedMessage(FederatedMessage),
+
     /// Auth events
-    Auth(AuthifierEvent),
+    Auth(#[schemars(with = "serde_json::Value")] AuthifierEvent),
 }
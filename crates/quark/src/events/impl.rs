@@ -4,7 +4,7 @@ use crate::{
     get_relationship,
     models::{
         server_member::FieldsMember,
-        user::{PartialUser, Presence, RelationshipStatus},
+        user::{PartialUser, Presence, RelationshipStatus, SessionScope},
         Channel, Member, User,
     },
     perms, Database, Permission, Result,
@@ -14,37 +14,92 @@ use revolt_presence::filter_online;
 
 use super::{
     client::EventV1,
-    state::{Cache, State},
+    server::EventFilter,
+    state::{Cache, State, MAX_MEMBER_LIST_SUBSCRIPTION},
 };
 
 /// Cache Manager
 impl Cache {
-    /// Check whether the current user can view a channel
-    pub async fn can_view_channel(&self, db: &Database, channel: &Channel) -> bool {
-        match &channel {
-            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
-                let member = self.members.values().find(|x| &x.id.server == server);
+    /// Calculate the current user's permissions in a server channel
+    ///
+    /// Results are kept until invalidated by an event which may change them.
+    async fn channel_permissions(&self, db: &Database, server_id: &str, channel: &Channel) -> u64 {
+        let cached = self
+            .permissions
+            .lock()
+            .unwrap()
+            .get(server_id)
+            .and_then(|channels| channels.get(channel.id()))
+            .copied();
+
+        if let Some(value) = cached {
+            return value;
+        }
 
-                let server = self.servers.get(server);
-                let mut perms = perms(self.users.get(&self.user_id).unwrap()).channel(channel);
+        let member = self.members.values().find(|x| x.id.server == server_id);
 
-                if let Some(member) = member {
-                    perms.member.set_ref(member);
-                }
+        let server = self.servers.get(server_id);
+        let mut perms = perms(self.users.get(&self.user_id).unwrap()).channel(channel);
 
-                if let Some(server) = server {
-                    perms.server.set_ref(server);
-                }
+        if let Some(member) = member {
+            perms.member.set_ref(member);
+        }
+
+        if let Some(server) = server {
+            perms.server.set_ref(server);
+        }
+
+        match perms.calc(db).await {
+            Ok(value) => {
+                let value = value.0[0];
+                self.permissions
+                    .lock()
+                    .unwrap()
+                    .entry(server_id.to_string())
+                    .or_default()
+                    .insert(channel.id().to_string(), value);
+
+                value
+            }
+            Err(_) => 0,
+        }
+    }
+
+    /// Forget calculated permissions for all channels in a server
+    pub fn invalidate_server_permissions(&self, server_id: &str) {
+        self.permissions.lock().unwrap().remove(server_id);
+    }
 
-                perms
-                    .has_permission(db, Permission::ViewChannel)
-                    .await
-                    .unwrap_or_default()
+    /// Forget calculated permissions for a channel
+    pub fn invalidate_channel_permissions(&self, channel: &Channel) {
+        if let Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } = channel
+        {
+            if let Some(channels) = self.permissions.lock().unwrap().get_mut(server) {
+                channels.remove(channel.id());
             }
+        }
+    }
+
+    /// Check whether the current user can view a channel
+    pub async fn can_view_channel(&self, db: &Database, channel: &Channel) -> bool {
+        match &channel {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                let value = self.channel_permissions(db, server, channel).await;
+                value & Permission::ViewChannel as u64 == Permission::ViewChannel as u64
+            }
+            Channel::DirectMessage { .. } | Channel::Group { .. } => self.allows_direct_messages(),
             _ => true,
         }
     }
 
+    /// Check whether the current session may see direct messages and groups
+    pub fn allows_direct_messages(&self) -> bool {
+        self.users
+            .get(&self.user_id)
+            .map(|user| !user.scopes.contains(&SessionScope::NoDirectMessages))
+            .unwrap_or(true)
+    }
+
     /// Filter a given vector of channels to only include the ones we can access
     pub async fn filter_accessible_channels(
         &self,
@@ -187,11 +242,17 @@ impl State {
 
         // Make sure we see our own user correctly.
         user.relationship = Some(RelationshipStatus::User);
-        users.push(user.foreign());
+        users.push(User {
+            locale: user.locale.clone(),
+            dm_media_filter: user.dm_media_filter,
+            federated_dms: user.federated_dms,
+            ..user.foreign()
+        });
 
         // Set subscription state internally.
         self.reset_state();
         self.insert_subscription(self.private_topic.clone());
+        self.insert_subscription("broadcast".to_string());
 
         for user in &users {
             self.insert_subscription(user.id.clone());
@@ -216,6 +277,8 @@ impl State {
 
     /// Re-determine the currently accessible server channels
     pub async fn recalculate_server(&mut self, db: &Database, id: &str, event: &mut EventV1) {
+        self.cache.invalidate_server_permissions(id);
+
         if let Some(server) = self.cache.servers.get(id) {
             let mut channel_ids = HashSet::new();
             let mut added_channels = vec![];
@@ -288,7 +351,11 @@ impl State {
         }
     }
 
-    /// Push presence change to the user and all associated server topics
+    /// Push presence change to everyone interested in this user
+    ///
+    /// Presence is only published on the user's own topic, which is
+    /// subscribed to by friends, DM recipients and anyone with this user
+    /// visible in their member list, rather than fanned out to every server.
     pub async fn broadcast_presence_change(&self, target: bool) {
         if if let Some(status) = &self.cache.users.get(&self.cache.user_id).unwrap().status {
             status.presence != Some(Presence::Invisible)
@@ -305,14 +372,38 @@ impl State {
                 event_id: Some(ulid::Ulid::new().to_string()),
             };
 
-            for server in self.cache.servers.keys() {
-                event.clone().p(server.clone()).await;
-            }
-
             event.p(self.cache.user_id.clone()).await;
         }
     }
 
+    /// Replace the members of a server's member list we want presence updates for
+    ///
+    /// Clients should call this with the members currently rendered in the member list.
+    pub async fn subscribe_member_list(
+        &mut self,
+        db: &Database,
+        server: &str,
+        mut users: Vec<String>,
+    ) {
+        if !self.cache.servers.contains_key(server) {
+            return;
+        }
+
+        users.truncate(MAX_MEMBER_LIST_SUBSCRIPTION);
+
+        // Only watch users who are actually in the server.
+        let users = if users.is_empty() {
+            HashSet::new()
+        } else {
+            match db.fetch_members(server, &users).await {
+                Ok(members) => members.into_iter().map(|member| member.id.user).collect(),
+                Err(_) => return,
+            }
+        };
+
+        self.replace_member_list(server, users);
+    }
+
     /// Handle an incoming event for protocol version 1
     pub async fn handle_incoming_event_v1(&mut self, db: &Database, event: &mut EventV1) -> bool {
         /* Superseded by private topics.
@@ -343,6 +434,10 @@ impl State {
 
         match event {
             EventV1::ChannelCreate(channel) => {
+                if !self.cache.can_view_channel(db, channel).await {
+                    return false;
+                }
+
                 let id = channel.id().to_string();
                 self.insert_subscription(id.clone());
                 self.cache.channels.insert(id, channel.clone());
@@ -365,6 +460,8 @@ impl State {
                 }
 
                 if let Some(channel) = self.cache.channels.get(id) {
+                    self.cache.invalidate_channel_permissions(channel);
+
                     let can_view = self.cache.can_view_channel(db, channel).await;
                     if could_view != can_view {
                         if can_view {
@@ -379,7 +476,9 @@ impl State {
             }
             EventV1::ChannelDelete { id } => {
                 self.remove_subscription(id);
-                self.cache.channels.remove(id);
+                if let Some(channel) = self.cache.channels.remove(id) {
+                    self.cache.invalidate_channel_permissions(&channel);
+                }
             }
             EventV1::ChannelGroupJoin { user, .. } => {
                 self.insert_subscription(user.clone());
@@ -387,7 +486,7 @@ impl State {
             EventV1::ChannelGroupLeave { id, user, .. } => {
                 if user == &self.cache.user_id {
                     self.remove_subscription(id);
-                } else if !self.cache.can_subscribe_to_user(user) {
+                } else if !self.is_user_of_interest(user) {
                     self.remove_subscription(user);
                 }
             }
@@ -419,7 +518,7 @@ impl State {
                     server.apply_options(data.clone());
                 }
 
-                if data.default_permissions.is_some() {
+                if data.default_permissions.is_some() || data.owner.is_some() {
                     queue_server = Some(id.clone());
                 }
             }
@@ -429,6 +528,8 @@ impl State {
             EventV1::ServerMemberLeave { id, user } => {
                 if user == &self.cache.user_id {
                     self.remove_subscription(id);
+                    self.remove_member_list(id);
+                    self.cache.invalidate_server_permissions(id);
 
                     if let Some(server) = self.cache.servers.remove(id) {
                         for channel in &server.channels {
@@ -440,6 +541,8 @@ impl State {
             }
             EventV1::ServerDelete { id } => {
                 self.remove_subscription(id);
+                self.remove_member_list(id);
+                self.cache.invalidate_server_permissions(id);
 
                 if let Some(server) = self.cache.servers.remove(id) {
                     for channel in &server.channels {
@@ -458,6 +561,9 @@ impl State {
                         member.apply_options(data.clone());
                     }
 
+                    // Timeouts also affect permissions
+                    self.cache.invalidate_server_permissions(&id.server);
+
                     if data.roles.is_some() || clear.contains(&FieldsMember::Roles) {
                         queue_server = Some(id.server.clone());
                     }
@@ -514,7 +620,7 @@ impl State {
             EventV1::UserRelationship { id, user, .. } => {
                 self.cache.users.insert(id.clone(), user.clone());
 
-                if self.cache.can_subscribe_to_user(id) {
+                if self.is_user_of_interest(id) {
                     self.insert_subscription(id.clone());
                 } else {
                     self.remove_subscription(id);
@@ -555,6 +661,17 @@ impl EventV1 {
         redis_kiss::publish(channel, self).await.unwrap();
     }
 
+    /// Publish helper wrapper, reporting whether the broker accepted the event
+    pub async fn try_p(self, channel: String) -> Result<()> {
+        #[cfg(debug_assertions)]
+        info!("Publishing event to {channel}: {self:?}");
+
+        redis_kiss::publish(channel, self)
+            .await
+            .map(|_| ())
+            .map_err(|_| crate::Error::InternalError)
+    }
+
     /// Publish user event
     pub async fn p_user(self, id: String, db: &Database) {
         self.clone().p(id.clone()).await;
@@ -577,4 +694,61 @@ impl EventV1 {
     pub async fn global(self) {
         self.p("global".to_string()).await;
     }
+
+    /// Publish event to every connected user
+    pub async fn broadcast(self) {
+        self.p("broadcast".to_string()).await;
+    }
+
+    /// Check whether this event falls under any of the given filters
+    pub fn is_excluded(&self, exclude: &[EventFilter]) -> bool {
+        exclude.iter().any(|filter| match filter {
+            EventFilter::Typing => matches!(
+                self,
+                EventV1::ChannelStartTyping { .. } | EventV1::ChannelStopTyping { .. }
+            ),
+            EventFilter::Presence => {
+                matches!(self, EventV1::UserUpdate { data, .. } if data.online.is_some())
+            }
+            EventFilter::Reactions => matches!(
+                self,
+                EventV1::MessageReact { .. }
+                    | EventV1::MessageUnreact { .. }
+                    | EventV1::MessageRemoveReaction { .. }
+                    | EventV1::MessageReactBatch { .. }
+            ),
+            EventFilter::Voice => matches!(
+                self,
+                EventV1::VoiceChannelJoin { .. }
+                    | EventV1::VoiceChannelLeave { .. }
+                    | EventV1::VoiceChannelMove { .. }
+                    | EventV1::VoiceMemberUpdate { .. }
+            ),
+            EventFilter::Acks => matches!(self, EventV1::ChannelAck { .. }),
+        })
+    }
+
+    /// Drop this event if it is excluded, filtering bulk events individually
+    pub fn filter(self, exclude: &[EventFilter]) -> Option<EventV1> {
+        if exclude.is_empty() {
+            return Some(self);
+        }
+
+        match self {
+            EventV1::Bulk { v } => {
+                let v: Vec<EventV1> = v
+                    .into_iter()
+                    .filter_map(|event| event.filter(exclude))
+                    .collect();
+
+                if v.is_empty() {
+                    None
+                } else {
+                    Some(EventV1::Bulk { v })
+                }
+            }
+            event if event.is_excluded(exclude) => None,
+            event => Some(event),
+        }
+    }
 }
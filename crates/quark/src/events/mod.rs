@@ -1,4 +1,5 @@
 pub mod client;
 pub mod r#impl;
+pub mod schema;
 pub mod server;
 pub mod state;
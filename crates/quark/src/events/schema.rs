@@ -0,0 +1,45 @@
+use once_cell::sync::Lazy;
+use schemars::{gen::SchemaSettings, schema::RootSchema};
+use serde::Serialize;
+
+use super::client::{ErrorEvent, EventV1};
+use super::server::ClientMessage;
+
+/// Current version of the events protocol
+pub const PROTOCOL_VERSION: i32 = 1;
+
+/// Machine-readable catalog of events protocol payloads
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct EventCatalog {
+    /// Version of the events protocol described
+    pub version: i32,
+    /// Schema of payloads sent by the server
+    #[schemars(with = "serde_json::Value")]
+    pub server: RootSchema,
+    /// Schema of payloads accepted from clients
+    #[schemars(with = "serde_json::Value")]
+    pub client: RootSchema,
+}
+
+/// Catalog generated from the event definitions
+static CATALOG: Lazy<EventCatalog> = Lazy::new(|| {
+    let mut gen = SchemaSettings::draft07().into_generator();
+    gen.subschema_for::<ErrorEvent>();
+
+    EventCatalog {
+        version: PROTOCOL_VERSION,
+        server: gen.into_root_schema_for::<EventV1>(),
+        client: SchemaSettings::draft07()
+            .into_generator()
+            .into_root_schema_for::<ClientMessage>(),
+    }
+});
+
+/// Get the event catalog for a given protocol version
+pub fn catalog(version: i32) -> Option<&'static EventCatalog> {
+    if version == PROTOCOL_VERSION {
+        Some(&CATALOG)
+    } else {
+        None
+    }
+}
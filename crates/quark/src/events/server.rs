@@ -1,12 +1,66 @@
 use serde::Deserialize;
 
 use super::client::Ping;
+use crate::models::user::Presence;
 
-#[derive(Deserialize, Debug)]
+/// Category of events a client can choose not to receive
+#[derive(Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventFilter {
+    /// Typing indicators
+    Typing,
+    /// Users coming online or going offline
+    Presence,
+    /// Reactions being added or removed
+    Reactions,
+    /// Voice channel joins, leaves and state changes
+    Voice,
+    /// Channels being marked as read
+    Acks,
+}
+
+impl EventFilter {
+    /// Parse a filter from its name, ignoring case
+    pub fn from_name(name: &str) -> Option<EventFilter> {
+        match name.to_ascii_lowercase().as_str() {
+            "typing" => Some(EventFilter::Typing),
+            "presence" => Some(EventFilter::Presence),
+            "reactions" => Some(EventFilter::Reactions),
+            "voice" => Some(EventFilter::Voice),
+            "acks" => Some(EventFilter::Acks),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, JsonSchema, Debug)]
 #[serde(tag = "type")]
 pub enum ClientMessage {
-    Authenticate { token: String },
-    BeginTyping { channel: String },
-    EndTyping { channel: String },
-    Ping { data: Ping, responded: Option<()> },
+    Authenticate {
+        token: String,
+        /// Categories of events this session should not receive
+        #[serde(default)]
+        exclude: Vec<EventFilter>,
+    },
+    BeginTyping {
+        channel: String,
+    },
+    EndTyping {
+        channel: String,
+    },
+    SubscribeMembers {
+        server: String,
+        users: Vec<String>,
+    },
+    UpdatePresence {
+        presence: Option<Presence>,
+        activity: Option<String>,
+    },
+    Ping {
+        data: Ping,
+        responded: Option<()>,
+    },
+    Batch {
+        id: String,
+        events: Vec<ClientMessage>,
+    },
 }
@@ -1,9 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 
 use lru::LruCache;
 
 use crate::models::{Channel, Member, Server, User};
 
+/// Maximum number of members a client may watch per server member list
+pub const MAX_MEMBER_LIST_SUBSCRIPTION: usize = 200;
+
 /// Enumeration representing some change in subscriptions
 pub enum SubscriptionStateChange {
     /// No change
@@ -37,6 +41,9 @@ pub struct Cache {
     pub members: HashMap<String, Member>,
     pub servers: HashMap<String, Server>,
 
+    /// Calculated channel permissions, keyed by server and then channel
+    pub permissions: Mutex<HashMap<String, HashMap<String, u64>>>,
+
     pub seen_events: LruCache<String, ()>,
 }
 
@@ -50,6 +57,8 @@ impl Default for Cache {
             members: Default::default(),
             servers: Default::default(),
 
+            permissions: Default::default(),
+
             seen_events: LruCache::new(20),
         }
     }
@@ -61,6 +70,7 @@ pub struct State {
 
     pub private_topic: String,
     subscribed: HashSet<String>,
+    member_lists: HashMap<String, HashSet<String>>,
     state: SubscriptionStateChange,
 }
 
@@ -83,6 +93,7 @@ impl State {
             cache,
             subscribed,
             private_topic,
+            member_lists: HashMap::new(),
             state: SubscriptionStateChange::Reset,
         }
     }
@@ -117,6 +128,49 @@ impl State {
     pub fn reset_state(&mut self) {
         self.state = SubscriptionStateChange::Reset;
         self.subscribed.clear();
+        self.member_lists.clear();
+    }
+
+    /// Check whether we should receive updates about a given user
+    ///
+    /// This covers ourselves, friends, DM recipients and
+    /// members currently visible in one of our member lists.
+    pub fn is_user_of_interest(&self, user_id: &str) -> bool {
+        user_id == self.cache.user_id
+            || self.cache.can_subscribe_to_user(user_id)
+            || self
+                .member_lists
+                .values()
+                .any(|users| users.contains(user_id))
+    }
+
+    /// Replace the members we are watching in a server's member list
+    pub fn replace_member_list(&mut self, server: &str, users: HashSet<String>) {
+        let previous = self
+            .member_lists
+            .insert(server.to_string(), users.clone())
+            .unwrap_or_default();
+
+        for id in previous.difference(&users) {
+            if !self.is_user_of_interest(id) {
+                self.remove_subscription(id);
+            }
+        }
+
+        for id in users {
+            self.insert_subscription(id);
+        }
+    }
+
+    /// Stop watching a server's member list
+    pub fn remove_member_list(&mut self, server: &str) {
+        if let Some(previous) = self.member_lists.remove(server) {
+            for id in previous {
+                if !self.is_user_of_interest(&id) {
+                    self.remove_subscription(&id);
+                }
+            }
+        }
     }
 
     /// Add a new subscription
@@ -1,10 +1,29 @@
-use crate::{models::stats::Stats, AbstractStats, Result};
+use crate::{
+    models::stats::{ServerStats, Stats, StatsSnapshot},
+    AbstractStats, Error, Result,
+};
 
 use super::super::DummyDb;
 
 #[async_trait]
 impl AbstractStats for DummyDb {
-    async fn generate_stats(&self) -> Result<Stats> {
+    async fn generate_stats(&self, _days: u32) -> Result<Stats> {
         todo!()
     }
+
+    async fn snapshot_stats(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn fetch_stats_history(&self, _days: u32) -> Result<Vec<StatsSnapshot>> {
+        Ok(vec![])
+    }
+
+    async fn update_server_stats(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn fetch_server_stats(&self, _server: &str) -> Result<ServerStats> {
+        Err(Error::NotFound)
+    }
 }
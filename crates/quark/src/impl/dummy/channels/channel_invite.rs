@@ -11,6 +11,7 @@ impl AbstractChannelInvite for DummyDb {
             server: "server".into(),
             creator: "creator".into(),
             channel: "channel".into(),
+            guest: None,
         })
     }
 
@@ -24,6 +25,11 @@ impl AbstractChannelInvite for DummyDb {
         Ok(())
     }
 
+    async fn use_guest_invite(&self, code: &str) -> Result<bool> {
+        info!("Use guest invite {code}");
+        Ok(true)
+    }
+
     async fn fetch_invites_for_server(&self, server: &str) -> Result<Vec<Invite>> {
         Ok(vec![self.fetch_invite(server).await.unwrap()])
     }
@@ -1,4 +1,10 @@
-use crate::models::message::{AppendMessage, Message, MessageQuery, PartialMessage};
+use indexmap::{IndexMap, IndexSet};
+use iso8601_timestamp::Timestamp;
+
+use crate::models::message::{
+    AppendMessage, ChannelMessageSync, DeletedMessage, Message, MessageQuery, MessageRevision,
+    PartialMessage,
+};
 use crate::{AbstractMessage, Result};
 
 use super::super::DummyDb;
@@ -41,10 +47,79 @@ impl AbstractMessage for DummyDb {
         Ok(())
     }
 
+    async fn trash_message(&self, message: &DeletedMessage) -> Result<()> {
+        info!("Trash {}", message.id);
+        Ok(())
+    }
+
+    async fn fetch_deleted_message(&self, id: &str) -> Result<DeletedMessage> {
+        Ok(DeletedMessage {
+            id: id.into(),
+            message: self.fetch_message(id).await?,
+            deleted_at: Timestamp::now_utc(),
+        })
+    }
+
+    async fn fetch_deleted_messages(&self, _channel: &str) -> Result<Vec<DeletedMessage>> {
+        Ok(vec![])
+    }
+
+    async fn restore_message(&self, id: &str) -> Result<Message> {
+        info!("Restore {id}");
+        self.fetch_message(id).await
+    }
+
+    async fn purge_deleted_messages(
+        &self,
+        before: Timestamp,
+        _exclude: &[String],
+    ) -> Result<Vec<DeletedMessage>> {
+        info!("Purge messages deleted before {before:?}");
+        Ok(vec![])
+    }
+
+    async fn insert_message_revision(&self, revision: &MessageRevision) -> Result<()> {
+        info!("Insert revision {} of {}", revision.id, revision.message_id);
+        Ok(())
+    }
+
+    async fn fetch_message_revisions(&self, _message: &str) -> Result<Vec<MessageRevision>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_messages_by_id(&self, ids: &[String]) -> Result<Vec<Message>> {
+        let mut messages = vec![];
+        for id in ids {
+            messages.push(self.fetch_message(id).await?);
+        }
+
+        Ok(messages)
+    }
+
     async fn fetch_messages(&self, _query: MessageQuery) -> Result<Vec<Message>> {
         Ok(vec![])
     }
 
+    async fn fetch_pinned_messages(&self, _channel: &str) -> Result<Vec<Message>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_message_authors(&self, channels: &[String], after: &str) -> Result<Vec<String>> {
+        info!("Fetch authors in {channels:?} after {after}");
+        Ok(vec![])
+    }
+
+    /// Count messages in each of the given channels after a given message id
+    async fn fetch_message_sync(
+        &self,
+        channels: &[String],
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<ChannelMessageSync>> {
+        info!("Sync up to {limit} messages in {channels:?} after {after}");
+        Ok(vec![])
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         info!("Add to {id} with {emoji} and {user}");
@@ -62,4 +137,21 @@ impl AbstractMessage for DummyDb {
         info!("Clear {emoji} on {id}");
         Ok(())
     }
+
+    async fn migrate_emoji_references(&self, from: &str, to: &str) -> Result<()> {
+        info!("Migrate references to {from} over to {to}");
+        Ok(())
+    }
+
+    /// Apply a batch of reaction changes to a message
+    async fn apply_reactions(
+        &self,
+        id: &str,
+        cleared: &[String],
+        added: &IndexMap<String, IndexSet<String>>,
+        removed: &IndexMap<String, IndexSet<String>>,
+    ) -> Result<Vec<String>> {
+        info!("Apply reactions on {id}: clear {cleared:?}, add {added:?}, remove {removed:?}");
+        Ok(vec![])
+    }
 }
@@ -5,6 +5,23 @@ use super::super::DummyDb;
 
 #[async_trait]
 impl AbstractAttachment for DummyDb {
+    async fn fetch_unused_attachment(
+        &self,
+        attachment_id: &str,
+        tag: &str,
+        _parent_type: &str,
+    ) -> Result<File> {
+        Ok(File {
+            id: attachment_id.into(),
+            tag: tag.into(),
+            filename: "file.txt".into(),
+            content_type: "plain/text".into(),
+            size: 100,
+
+            ..Default::default()
+        })
+    }
+
     async fn find_and_use_attachment(
         &self,
         attachment_id: &str,
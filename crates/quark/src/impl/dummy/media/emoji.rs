@@ -1,4 +1,4 @@
-use crate::models::emoji::EmojiParent;
+use crate::models::emoji::{EmojiParent, EmojiUsage};
 use crate::models::Emoji;
 use crate::{AbstractEmoji, Result};
 
@@ -11,10 +11,12 @@ impl AbstractEmoji for DummyDb {
         Ok(Emoji {
             id: id.into(),
             name: id.into(),
+            aliases: vec![],
             parent: EmojiParent::Server { id: id.into() },
             creator_id: id.into(),
             animated: false,
             nsfw: false,
+            replaced_by: None,
         })
     }
 
@@ -34,9 +36,32 @@ impl AbstractEmoji for DummyDb {
         Ok(())
     }
 
+    /// Fetch usage counters for the given emoji
+    async fn fetch_emoji_usage(&self, _ids: &[String]) -> Result<Vec<EmojiUsage>> {
+        Ok(vec![])
+    }
+
+    /// Record that an emoji has been used a number of times
+    async fn increment_emoji_usage(&self, id: &str, count: i64) -> Result<()> {
+        info!("Increment usage of {id} by {count}");
+        Ok(())
+    }
+
     /// Detach an emoji by its id
+    /// Update the name and aliases of an emoji
+    async fn update_emoji_names(&self, id: &str, name: &str, aliases: &[String]) -> Result<()> {
+        info!("Rename {id} to {name} with aliases {aliases:?}");
+        Ok(())
+    }
+
     async fn detach_emoji(&self, emoji: &Emoji) -> Result<()> {
         info!("Detach {emoji:?}");
         Ok(())
     }
+
+    /// Detach an emoji and point it at the emoji which replaced it
+    async fn replace_emoji(&self, emoji: &Emoji, replacement_id: &str) -> Result<()> {
+        info!("Replace {emoji:?} with {replacement_id}");
+        Ok(())
+    }
 }
@@ -29,6 +29,7 @@ pub mod users {
 }
 
 pub mod safety {
+    pub mod legal_hold;
     pub mod report;
     pub mod snapshot;
 }
@@ -0,0 +1,29 @@
+use crate::models::LegalHold;
+use crate::{AbstractLegalHold, Error, Result};
+
+use super::super::DummyDb;
+
+#[async_trait]
+impl AbstractLegalHold for DummyDb {
+    async fn insert_legal_hold(&self, hold: &LegalHold) -> Result<()> {
+        info!("Insert {hold:?}");
+        Ok(())
+    }
+
+    async fn fetch_legal_hold(&self, _id: &str) -> Result<LegalHold> {
+        Err(Error::NotFound)
+    }
+
+    async fn fetch_legal_holds(&self) -> Result<Vec<LegalHold>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_held_ids(&self) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn delete_legal_hold(&self, id: &str) -> Result<()> {
+        info!("Delete legal hold on {id}");
+        Ok(())
+    }
+}
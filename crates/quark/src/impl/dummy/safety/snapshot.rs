@@ -10,7 +10,27 @@ impl AbstractSnapshot for DummyDb {
         Ok(())
     }
 
+    async fn fetch_snapshot(&self, _id: &str) -> Result<Snapshot> {
+        todo!()
+    }
+
     async fn fetch_snapshots(&self, _report_id: &str) -> Result<Vec<Snapshot>> {
         todo!()
     }
+
+    async fn fetch_snapshots_by_object(&self, _object_id: &str) -> Result<Vec<Snapshot>> {
+        todo!()
+    }
+
+    async fn delete_unreported_snapshots_before(&self, _id: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn set_server_monitored(&self, _server_id: &str, _monitored: bool) -> Result<()> {
+        Ok(())
+    }
+
+    async fn fetch_monitored_servers(&self) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
 }
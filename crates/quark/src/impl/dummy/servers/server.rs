@@ -16,6 +16,9 @@ impl AbstractServer for DummyDb {
             channels: vec!["channel".into()],
             categories: None,
             system_messages: None,
+            onboarding: None,
+            highlights: None,
+            warn_thresholds: None,
 
             roles: std::collections::HashMap::new(),
             default_permissions: *DEFAULT_PERMISSION_SERVER as i64,
@@ -24,10 +27,12 @@ impl AbstractServer for DummyDb {
             banner: None,
 
             flags: None,
+            storage_region: None,
 
             nsfw: false,
             analytics: true,
             discoverable: true,
+            default_notifications: Default::default(),
         })
     }
 
@@ -1,4 +1,6 @@
-use crate::models::server_member::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::models::server_member::{
+    FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember,
+};
 use crate::{AbstractServerMember, Result};
 
 use super::super::DummyDb;
@@ -18,6 +20,11 @@ impl AbstractServerMember for DummyDb {
             avatar: None,
             roles: vec![],
             timeout: None,
+            voice_muted: false,
+            voice_deafened: false,
+            notifications: Default::default(),
+            followed_channels: vec![],
+            onboarding: None,
         })
     }
 
@@ -45,6 +52,18 @@ impl AbstractServerMember for DummyDb {
         Ok(vec![self.fetch_member(server, "member").await.unwrap()])
     }
 
+    async fn fetch_notified_members(&self, _server: &str, _channel: &str) -> Result<Vec<Member>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_members_with_roles(
+        &self,
+        _server: &str,
+        _roles: &[String],
+    ) -> Result<Vec<Member>> {
+        Ok(vec![])
+    }
+
     async fn fetch_all_memberships<'a>(&self, user: &str) -> Result<Vec<Member>> {
         Ok(vec![self.fetch_member("server", user).await.unwrap()])
     }
@@ -53,6 +72,15 @@ impl AbstractServerMember for DummyDb {
         Ok(vec![self.fetch_member(server, "member").await.unwrap()])
     }
 
+    async fn fetch_members_by_query(
+        &self,
+        server: &str,
+        query: MemberQuery,
+    ) -> Result<Vec<Member>> {
+        info!("Query members in {server} with {query:?}");
+        Ok(vec![self.fetch_member(server, "member").await.unwrap()])
+    }
+
     async fn fetch_member_count(&self, _server: &str) -> Result<usize> {
         Ok(100)
     }
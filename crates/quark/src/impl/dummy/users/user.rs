@@ -1,4 +1,4 @@
-use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, User};
+use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, SessionScope, User};
 use crate::{AbstractUser, Result};
 
 use super::super::DummyDb;
@@ -21,6 +21,10 @@ impl AbstractUser for DummyDb {
         self.fetch_user(token).await
     }
 
+    async fn fetch_session_scopes(&self, _session_id: &str) -> Result<Vec<SessionScope>> {
+        Ok(vec![])
+    }
+
     async fn insert_user(&self, user: &User) -> Result<()> {
         info!("Insert {:?}", user);
         Ok(())
@@ -45,6 +49,14 @@ impl AbstractUser for DummyDb {
         Ok(vec![self.fetch_user("id").await.unwrap()])
     }
 
+    async fn fetch_expired_guests(&self) -> Result<Vec<User>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_user_ids_after(&self, _after: Option<&str>, _limit: i64) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
     async fn is_username_taken(&self, _username: &str) -> Result<bool> {
         Ok(false)
     }
@@ -1,4 +1,7 @@
-use crate::models::UserSettings;
+use crate::models::{
+    user_settings::{DmLayout, ServerLayout},
+    UserSettings,
+};
 use crate::{AbstractUserSettings, Result};
 
 use super::super::DummyDb;
@@ -18,6 +21,30 @@ impl AbstractUserSettings for DummyDb {
         Ok(())
     }
 
+    async fn fetch_server_layout(&self, id: &str) -> Result<ServerLayout> {
+        Ok(ServerLayout {
+            id: id.into(),
+            ..Default::default()
+        })
+    }
+
+    async fn set_server_layout(&self, layout: &ServerLayout) -> Result<()> {
+        info!("Set {layout:?}");
+        Ok(())
+    }
+
+    async fn fetch_dm_layout(&self, id: &str) -> Result<DmLayout> {
+        Ok(DmLayout {
+            id: id.into(),
+            ..Default::default()
+        })
+    }
+
+    async fn set_dm_layout(&self, layout: &DmLayout) -> Result<()> {
+        info!("Set {layout:?}");
+        Ok(())
+    }
+
     async fn delete_user_settings(&self, id: &str) -> Result<()> {
         info!("Delete {id}");
         Ok(())
@@ -1,19 +1,35 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use ulid::Ulid;
 
 use crate::{
     events::client::EventV1,
     models::{
-        channel::{FieldsChannel, PartialChannel},
-        message::{DataMessageSend, Message, Reply, SystemMessage, RE_MENTION},
-        Channel,
+        channel::{FieldsChannel, FileTypeRestrictions, PartialChannel},
+        message::{
+            DataMessageSend, Message, MessageFilter, MessageQuery, MessageSort, MessageTimePeriod,
+            Reply, SystemMessage, RE_CUSTOM_EMOJI, RE_MENTION, RE_ROLE_MENTION,
+        },
+        user::{DirectMessageMediaFilter, RelationshipStatus},
+        Channel, File,
+    },
+    permissions::r#impl::user::get_relationship,
+    perms,
+    tasks::{ack::AckEvent, emoji_usage, process_embeds},
+    types::{
+        january::{Embed, Text},
+        push::MessageAuthor,
+    },
+    util::{pipeline, spam},
+    variables::delta::{
+        DEFAULT_STORAGE_REGION, MAX_ATTACHMENT_COUNT, MAX_EMBED_COUNT, MAX_REPLY_COUNT,
+        MESSAGE_EFFECTS,
     },
-    tasks::{ack::AckEvent, process_embeds},
-    types::push::MessageAuthor,
-    variables::delta::{MAX_ATTACHMENT_COUNT, MAX_REPLY_COUNT, MAX_EMBED_COUNT},
     web::idempotency::IdempotencyKey,
-    Database, Error, OverrideField, Ref, Result,
+    Database, Error, OverrideField, Permission, Ref, Result,
 };
 
 impl Channel {
@@ -94,6 +110,38 @@ impl Channel {
         Ok(())
     }
 
+    /// Lock or unlock a text channel and announce the change
+    pub async fn set_locked(&mut self, db: &Database, by: &str, state: bool) -> Result<()> {
+        match self {
+            Self::TextChannel { locked, .. } => {
+                if *locked == state {
+                    return Err(Error::NoEffect);
+                }
+            }
+            _ => return Err(Error::InvalidOperation),
+        }
+
+        self.update(
+            db,
+            PartialChannel {
+                locked: Some(state),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+        let by = by.to_string();
+        if state {
+            SystemMessage::ChannelLocked { by }
+        } else {
+            SystemMessage::ChannelUnlocked { by }
+        }
+        .into_message(self.id().to_string())
+        .create(db, self, None)
+        .await
+    }
+
     /// Delete a channel
     pub async fn delete(self, db: &Database) -> Result<()> {
         let id = self.id().to_string();
@@ -133,12 +181,84 @@ impl Channel {
                 }
                 _ => {}
             },
+            FieldsChannel::UserLimit => {
+                if let Self::VoiceChannel { user_limit, .. } = self {
+                    user_limit.take();
+                }
+            }
+            FieldsChannel::PostingRole => {
+                if let Self::TextChannel { posting_role, .. } = self {
+                    posting_role.take();
+                }
+            }
+            FieldsChannel::Slowmode => {
+                if let Self::TextChannel { slowmode, .. } = self {
+                    slowmode.take();
+                }
+            }
+            FieldsChannel::AutoThreadDepth => {
+                if let Self::TextChannel {
+                    auto_thread_depth, ..
+                } = self
+                {
+                    auto_thread_depth.take();
+                }
+            }
+            FieldsChannel::FileTypes => {
+                if let Self::TextChannel { file_types, .. }
+                | Self::VoiceChannel { file_types, .. } = self
+                {
+                    file_types.take();
+                }
+            }
         }
     }
 
     /// Apply partial channel to channel
     pub fn apply_options(&mut self, partial: PartialChannel) {
         // ! FIXME: maybe flatten channel object?
+        if let Self::VoiceChannel { user_limit, .. } = self {
+            if let Some(v) = partial.user_limit {
+                user_limit.replace(v);
+            }
+        }
+
+        if let Self::TextChannel { file_types, .. } | Self::VoiceChannel { file_types, .. } = self {
+            if let Some(v) = partial.file_types {
+                file_types.replace(v);
+            }
+        }
+
+        if let Self::TextChannel {
+            locked,
+            media_only,
+            posting_role,
+            slowmode,
+            auto_thread_depth,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.locked {
+                *locked = v;
+            }
+
+            if let Some(v) = partial.media_only {
+                *media_only = v;
+            }
+
+            if let Some(v) = partial.posting_role {
+                posting_role.replace(v);
+            }
+
+            if let Some(v) = partial.slowmode {
+                slowmode.replace(v);
+            }
+
+            if let Some(v) = partial.auto_thread_depth {
+                auto_thread_depth.replace(v);
+            }
+        }
+
         match self {
             Self::DirectMessage { active, .. } => {
                 if let Some(v) = partial.active {
@@ -403,41 +523,28 @@ impl Channel {
     }
 
     /// Creates a message in a channel
+    ///
+    /// Messages pass through validation, channel policy, spam scoring,
+    /// attachment resolution, filters, mention parsing, persistence and
+    /// fan-out in that order, see [`crate::util::pipeline`] for registering
+    /// custom filters.
     pub async fn send_message(
         &self,
         db: &Database,
-        data: DataMessageSend,
+        mut data: DataMessageSend,
         author: MessageAuthor<'_>,
         mut idempotency: IdempotencyKey,
         generate_embeds: bool,
     ) -> Result<Message> {
-        Message::validate_sum(&data.content, data.embeds.as_deref().unwrap_or_default())?;
+        // 1. Validation
+        Channel::validate_message_data(&data)?;
 
+        // 2. Channel policy
+        self.check_posting_policy(db, &author, &data).await?;
+        let stripped = self.apply_media_filter(db, &author, &mut data).await?;
+        let generate_embeds = generate_embeds && !stripped;
         idempotency.consume_nonce(data.nonce).await?;
 
-        // Check the message is not empty
-        if (data.content.as_ref().map_or(true, |v| v.is_empty()))
-            && (data.attachments.as_ref().map_or(true, |v| v.is_empty()))
-            && (data.embeds.as_ref().map_or(true, |v| v.is_empty()))
-        {
-            return Err(Error::EmptyMessage);
-        }
-
-        // Ensure restrict_reactions is not specified without reactions list
-        if let Some(interactions) = &data.interactions {
-            if interactions.restrict_reactions {
-                let disallowed = if let Some(list) = &interactions.reactions {
-                    list.is_empty()
-                } else {
-                    true
-                };
-
-                if disallowed {
-                    return Err(Error::InvalidProperty);
-                }
-            }
-        }
-
         let (author_id, webhook) = match &author {
             MessageAuthor::User(user) => (user.id.clone(), None),
             MessageAuthor::Webhook(webhook) => (webhook.id.clone(), Some((*webhook).clone())),
@@ -448,55 +555,341 @@ impl Channel {
         let mut message = Message {
             id: message_id.clone(),
             channel: self.id().to_string(),
+            thread: data.thread,
+            content: data.content,
             masquerade: data.masquerade,
             interactions: data.interactions.unwrap_or_default(),
+            effect: data.effect,
             author: author_id,
             webhook: webhook.map(|w| w.into()),
             ..Default::default()
         };
 
-        // Parse mentions in message.
-        let mut mentions = HashSet::new();
-        if let Some(content) = &data.content {
-            for capture in RE_MENTION.captures_iter(content) {
-                if let Some(mention) = capture.get(1) {
-                    mentions.insert(mention.as_str().to_string());
+        // 3. Spam scoring
+        let spam = spam::score_message(db, self, &author, &message).await;
+
+        // 4. Attachments and embeds
+        // Files are looked up here but only claimed once the message is accepted
+        let mut attachments = vec![];
+        for attachment_id in data.attachments.as_deref().unwrap_or_default() {
+            attachments.push(
+                db.fetch_unused_attachment(attachment_id, "attachments", "message")
+                    .await?,
+            );
+        }
+
+        let mut embeds = vec![];
+        for sendable_embed in data.embeds.unwrap_or_default() {
+            embeds.push(sendable_embed.resolve(db).await?)
+        }
+
+        if !attachments.is_empty() {
+            message.attachments.replace(attachments);
+        }
+
+        if !embeds.is_empty() {
+            message.embeds.replace(embeds);
+        }
+
+        self.check_files(db, &message).await?;
+
+        // 5. Filters
+        pipeline::run_filters(db, self, &author, &mut message, &spam).await?;
+
+        // 6. Mention parsing
+        self.parse_mentions(db, &author, &mut message, data.replies)
+            .await?;
+
+        // 7. Persistence
+        for file in message_files(&message) {
+            db.find_and_use_attachment(&file.id, "attachments", "message", &message_id)
+                .await?;
+        }
+
+        // Pass-through nonce value for clients
+        message.nonce = Some(idempotency.into_key());
+
+        // 8. Fan-out
+        message.create(db, self, Some(author)).await?;
+
+        // Count uses of custom emoji
+        if let Some(content) = &message.content {
+            let emojis: HashSet<&str> = RE_CUSTOM_EMOJI
+                .captures_iter(content)
+                .filter_map(|capture| capture.get(1))
+                .map(|emoji| emoji.as_str())
+                .collect();
+
+            for emoji in emojis {
+                emoji_usage::queue(emoji.to_string()).await;
+            }
+        }
+
+        // Queue up a task for processing embeds
+        if generate_embeds {
+            if let Some(content) = &message.content {
+                process_embeds::queue(
+                    self.id().to_string(),
+                    message.id.to_string(),
+                    content.clone(),
+                )
+                .await;
+            }
+        }
+
+        spam::record_sent(&message).await;
+        pipeline::run_after_send(db, self, &message, &spam).await;
+
+        Ok(message)
+    }
+
+    /// Check the author may post this message under the channel's posting rules
+    ///
+    /// Locked channels and channels with a posting role only accept messages
+    /// from moderators, or members holding the role. Webhooks are managed by
+    /// moderators so they may always post. Media-only channels reject messages
+    /// without an attachment or link from anyone. Members without ManageMessages
+    /// or BypassSlowmode must wait out the channel's slowmode between messages,
+    /// and so must each webhook.
+    async fn check_posting_policy(
+        &self,
+        db: &Database,
+        author: &MessageAuthor<'_>,
+        data: &DataMessageSend,
+    ) -> Result<()> {
+        let Channel::TextChannel {
+            locked,
+            posting_role,
+            media_only,
+            slowmode,
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+
+        if let (true, MessageAuthor::User(user)) = (*locked || posting_role.is_some(), author) {
+            let mut permissions = perms(user).channel(self);
+            let moderator = permissions
+                .has_permission(db, Permission::ManageThreads)
+                .await?;
+
+            if !moderator {
+                let has_role = posting_role.as_ref().is_some_and(|role| {
+                    permissions
+                        .member
+                        .get()
+                        .is_some_and(|member| member.roles.contains(role))
+                });
+
+                if *locked || !has_role {
+                    return Err(Error::ChannelLocked);
                 }
             }
         }
 
-        // Verify replies are valid.
-        let mut replies = HashSet::new();
-        if let Some(entries) = data.replies {
-            if entries.len() > *MAX_REPLY_COUNT {
-                return Err(Error::TooManyReplies {
-                    max: *MAX_REPLY_COUNT,
+        if *media_only && !data.has_media() {
+            return Err(Error::MediaOnlyChannel);
+        }
+
+        if let Some(slowmode) = slowmode {
+            let exempt = match author {
+                MessageAuthor::User(user) => {
+                    let mut permissions = perms(user).channel(self);
+                    permissions
+                        .has_permission(db, Permission::ManageMessages)
+                        .await?
+                        || permissions
+                            .has_permission(db, Permission::BypassSlowmode)
+                            .await?
+                }
+                // Otherwise bots could post through a webhook to skip the cooldown
+                MessageAuthor::Webhook(_) => false,
+            };
+
+            if !exempt {
+                if let Some(retry_after) =
+                    self.slowmode_remaining(db, author.id(), *slowmode).await?
+                {
+                    return Err(Error::SlowmodeActive { retry_after });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Milliseconds until an author may send another message under this channel's slowmode
+    ///
+    /// Reads from the primary so every node sees the same latest message.
+    async fn slowmode_remaining(
+        &self,
+        db: &Database,
+        author: &str,
+        seconds: u32,
+    ) -> Result<Option<u64>> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let cooldown = seconds as u64 * 1000;
+
+        let messages = db
+            .fetch_messages(MessageQuery {
+                limit: Some(1),
+                filter: MessageFilter {
+                    channel: Some(self.id().to_string()),
+                    author: Some(author.to_string()),
+                    ..Default::default()
+                },
+                time_period: MessageTimePeriod::Absolute {
+                    before: None,
+                    after: Some(Ulid::from_parts(now.saturating_sub(cooldown), 0).to_string()),
+                    sort: Some(MessageSort::Latest),
+                },
+                consistent: true,
+            })
+            .await?;
+
+        Ok(messages
+            .first()
+            .and_then(|message| Ulid::from_string(&message.id).ok())
+            .map(|id| (id.timestamp_ms() + cooldown).saturating_sub(now)))
+    }
+
+    /// Check the files attached to a message are allowed in this channel
+    async fn check_files(&self, db: &Database, message: &Message) -> Result<()> {
+        if message_files(message).next().is_none() {
+            return Ok(());
+        }
+
+        if let Channel::TextChannel {
+            file_types: Some(file_types),
+            ..
+        }
+        | Channel::VoiceChannel {
+            file_types: Some(file_types),
+            ..
+        } = self
+        {
+            if let Some(file) =
+                message_files(message).find(|file| !file_types.permits(&file.content_type))
+            {
+                return Err(Error::FileTypeNotAllowed {
+                    content_type: file.content_type.clone(),
                 });
             }
+        }
+
+        // Servers pinned to a storage region only accept files stored there
+        //
+        // Regions are recorded by the file server, files it hasn't tagged are
+        // treated as living in the default region, or let through if there is none.
+        if let Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } = self {
+            if let Some(region) = db.fetch_server(server).await?.storage_region {
+                if message_files(message).any(|file| {
+                    file.region
+                        .as_ref()
+                        .or(DEFAULT_STORAGE_REGION.as_ref())
+                        .is_some_and(|file_region| file_region != &region)
+                }) {
+                    return Err(Error::FileRegionNotAllowed);
+                }
+            }
+        }
 
-            for Reply { id, mention } in entries {
-                let message = Ref::from_unchecked(id).as_message(db).await?;
+        Ok(())
+    }
 
-                if mention {
-                    mentions.insert(message.author.to_owned());
+    /// Apply the recipient's media filter to a direct message from someone who is not their friend
+    ///
+    /// Returns whether attachments, embeds and links were stripped from the message.
+    pub async fn apply_media_filter(
+        &self,
+        db: &Database,
+        author: &MessageAuthor<'_>,
+        data: &mut DataMessageSend,
+    ) -> Result<bool> {
+        let (Channel::DirectMessage { recipients, .. }, MessageAuthor::User(user)) = (self, author)
+        else {
+            return Ok(false);
+        };
+
+        if !data.has_media() && data.embeds.as_ref().map_or(true, |v| v.is_empty()) {
+            return Ok(false);
+        }
+
+        let Some(recipient) = recipients.iter().find(|id| *id != &user.id) else {
+            return Ok(false);
+        };
+
+        let recipient = db.fetch_user(recipient).await?;
+        if get_relationship(&recipient, &user.id) == RelationshipStatus::Friend {
+            return Ok(false);
+        }
+
+        let rejected = || Error::MessageRejected {
+            reason: "This user only accepts attachments and links from friends.".to_string(),
+        };
+
+        match recipient.dm_media_filter {
+            None | Some(DirectMessageMediaFilter::Allow) => Ok(false),
+            Some(DirectMessageMediaFilter::Strip) => {
+                data.strip_media();
+                if data.content.as_ref().map_or(true, |v| v.trim().is_empty()) {
+                    return Err(rejected());
                 }
 
-                replies.insert(message.id);
+                Ok(true)
             }
+            Some(DirectMessageMediaFilter::Block) => Err(rejected()),
         }
+    }
 
-        if !mentions.is_empty() {
-            message.mentions.replace(mentions.into_iter().collect());
+    /// Check a message is well-formed before doing any work on it
+    pub fn validate_message_data(data: &DataMessageSend) -> Result<()> {
+        Message::validate_sum(&data.content, data.embeds.as_deref().unwrap_or_default())?;
+
+        // Check the message is not empty
+        if (data.content.as_ref().map_or(true, |v| v.is_empty()))
+            && (data.attachments.as_ref().map_or(true, |v| v.is_empty()))
+            && (data.embeds.as_ref().map_or(true, |v| v.is_empty()))
+        {
+            return Err(Error::EmptyMessage);
         }
 
-        if !replies.is_empty() {
-            message
-                .replies
-                .replace(replies.into_iter().collect::<Vec<String>>());
+        // Ensure restrict_reactions is not specified without reactions list
+        if let Some(interactions) = &data.interactions {
+            if interactions.restrict_reactions {
+                let disallowed = if let Some(list) = &interactions.reactions {
+                    list.is_empty()
+                } else {
+                    true
+                };
+
+                if disallowed {
+                    return Err(Error::InvalidProperty);
+                }
+            }
+        }
+
+        // Only allow effects enabled on this instance
+        if let Some(effect) = &data.effect {
+            if !MESSAGE_EFFECTS.contains(effect) {
+                return Err(Error::InvalidProperty);
+            }
+        }
+
+        if data
+            .replies
+            .as_ref()
+            .is_some_and(|v| v.len() > *MAX_REPLY_COUNT)
+        {
+            return Err(Error::TooManyReplies {
+                max: *MAX_REPLY_COUNT,
+            });
         }
 
-        // Add attachments to message.
-        let mut attachments = vec![];
         if data.attachments.as_ref().is_some_and(|v| v.len() > *MAX_ATTACHMENT_COUNT) {
             return Err(Error::TooManyAttachments {
                 max: *MAX_ATTACHMENT_COUNT,
@@ -509,47 +902,120 @@ impl Channel {
             })
         }
 
-        for attachment_id in data.attachments.as_deref().unwrap_or_default() {
-            attachments.push(
-                db.find_and_use_attachment(attachment_id, "attachments", "message", &message_id)
-                    .await?,
-            );
-        }
+        Ok(())
+    }
 
-        if !attachments.is_empty() {
-            message.attachments.replace(attachments);
+    /// Resolve user mentions, role mentions and replies for a message
+    async fn parse_mentions(
+        &self,
+        db: &Database,
+        author: &MessageAuthor<'_>,
+        message: &mut Message,
+        replies: Option<Vec<Reply>>,
+    ) -> Result<()> {
+        // Parse mentions in message.
+        let mut mentions = HashSet::new();
+        if let Some(content) = &message.content {
+            for capture in RE_MENTION.captures_iter(content) {
+                if let Some(mention) = capture.get(1) {
+                    mentions.insert(mention.as_str().to_string());
+                }
+            }
         }
 
-        // Process included embeds.
-        let mut embeds = vec![];
-        for sendable_embed in data.embeds.unwrap_or_default() {
-            embeds.push(sendable_embed.into_embed(db, &message_id).await?)
+        // Parse role mentions, dropping any the author may not mention.
+        let mut role_mentions = HashSet::new();
+        if let (Some(content), Channel::TextChannel { server, .. }) = (&message.content, self) {
+            let ids: HashSet<&str> = RE_ROLE_MENTION
+                .captures_iter(content)
+                .filter_map(|capture| capture.get(1))
+                .map(|mention| mention.as_str())
+                .collect();
+
+            if !ids.is_empty() {
+                let server = db.fetch_server(server).await?;
+                let can_mention_any = match author {
+                    MessageAuthor::User(user) => {
+                        perms(user)
+                            .channel(self)
+                            .has_permission(db, Permission::MentionRoles)
+                            .await?
+                    }
+                    MessageAuthor::Webhook(_) => false,
+                };
+
+                for id in ids {
+                    if let Some(role) = server.roles.get(id) {
+                        if role.mentionable || can_mention_any {
+                            role_mentions.insert(id.to_string());
+                        }
+                    }
+                }
+            }
         }
 
-        if !embeds.is_empty() {
-            message.embeds.replace(embeds);
+        if !role_mentions.is_empty() {
+            message
+                .role_mentions
+                .replace(role_mentions.into_iter().collect());
         }
 
-        // Set content
-        message.content = data.content;
+        // Verify replies are valid.
+        let mut reply_ids = HashSet::new();
+        for Reply { id, mention } in replies.unwrap_or_default() {
+            let message = Ref::from_unchecked(id).as_message(db).await?;
 
-        // Pass-through nonce value for clients
-        message.nonce = Some(idempotency.into_key());
+            if mention {
+                mentions.insert(message.author.to_owned());
+            }
 
-        message.create(db, self, Some(author)).await?;
+            reply_ids.insert(message.id);
+        }
 
-        // Queue up a task for processing embeds
-        if generate_embeds {
-            if let Some(content) = &message.content {
-                process_embeds::queue(
-                    self.id().to_string(),
-                    message.id.to_string(),
-                    content.clone(),
-                )
-                .await;
-            }
+        if !mentions.is_empty() {
+            message.mentions.replace(mentions.into_iter().collect());
         }
 
-        Ok(message)
+        if !reply_ids.is_empty() {
+            message
+                .replies
+                .replace(reply_ids.into_iter().collect::<Vec<String>>());
+        }
+
+        Ok(())
+    }
+}
+
+impl FileTypeRestrictions {
+    /// Check whether a file with the given content type may be sent
+    pub fn permits(&self, content_type: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix("/*") {
+            Some(kind) => content_type
+                .split_once('/')
+                .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(kind)),
+            None => pattern.eq_ignore_ascii_case(content_type),
+        };
+
+        !self.block.iter().any(matches) && (self.allow.is_empty() || self.allow.iter().any(matches))
     }
 }
+
+/// Iterate over every file attached to a message, including embed media
+fn message_files(message: &Message) -> impl Iterator<Item = &File> {
+    message
+        .attachments
+        .iter()
+        .flatten()
+        .chain(
+            message
+                .embeds
+                .iter()
+                .flatten()
+                .filter_map(|embed| match embed {
+                    Embed::Text(Text {
+                        media: Some(media), ..
+                    }) => Some(media),
+                    _ => None,
+                }),
+        )
+}
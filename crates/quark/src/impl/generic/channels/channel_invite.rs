@@ -1,7 +1,16 @@
+use std::time::{Duration, SystemTime};
+
+use authifier::{models::Session, Authifier};
+use iso8601_timestamp::Timestamp;
 use nanoid::nanoid;
+use ulid::Ulid;
 
 use crate::{
-    models::{Channel, Invite, User},
+    models::{
+        channel_invite::GuestAccess,
+        user::{GuestInformation, User},
+        Channel, Invite,
+    },
     Database, Error, Result,
 };
 
@@ -41,6 +50,7 @@ impl Invite {
                     creator: creator.id.clone(),
                     server: server.clone(),
                     channel: id.clone(),
+                    guest: None,
                 })
             }
             _ => Err(Error::InvalidOperation),
@@ -50,6 +60,94 @@ impl Invite {
         Ok(invite)
     }
 
+    /// Create a new guest invite to a server channel
+    pub async fn create_guest(
+        db: &Database,
+        creator: &User,
+        target: &Channel,
+        access: GuestAccess,
+    ) -> Result<Invite> {
+        let invite = match &target {
+            Channel::TextChannel { id, server, .. } | Channel::VoiceChannel { id, server, .. } => {
+                Invite::Server {
+                    code: nanoid!(8, &ALPHABET),
+                    creator: creator.id.clone(),
+                    server: server.clone(),
+                    channel: id.clone(),
+                    guest: Some(access),
+                }
+            }
+            _ => return Err(Error::InvalidOperation),
+        };
+
+        db.insert_invite(&invite).await?;
+        Ok(invite)
+    }
+
+    /// Create a temporary guest account using this invite
+    ///
+    /// Returns the guest user along with a session to authenticate as them.
+    pub async fn create_guest_user(
+        &self,
+        db: &Database,
+        authifier: &Authifier,
+        username: String,
+    ) -> Result<(User, Session)> {
+        let (server, channel, access) = match self {
+            Invite::Server {
+                server,
+                channel,
+                guest: Some(access),
+                ..
+            } => (server, channel, access),
+            _ => return Err(Error::InvalidOperation),
+        };
+
+        // Invites which have been used up are treated as gone
+        if !db.use_guest_invite(self.code()).await? {
+            return Err(Error::NotFound);
+        }
+
+        let server = db.fetch_server(server).await?;
+        let user = User {
+            id: Ulid::new().to_string(),
+            username: User::validate_username(db, username).await?,
+            guest: Some(GuestInformation {
+                server: server.id.clone(),
+                channel: channel.clone(),
+                write: access.write,
+                expires: Timestamp::from(
+                    SystemTime::now() + Duration::from_secs(access.lifetime as u64),
+                ),
+            }),
+            ..Default::default()
+        };
+
+        db.insert_user(&user).await?;
+        if let Err(error) = server.create_member(db, user.clone(), None).await {
+            db.delete_user(&user.id).await.ok();
+            return Err(error);
+        }
+
+        let session = Session {
+            id: Ulid::new().to_string(),
+            token: nanoid!(64),
+            user_id: user.id.clone(),
+            name: "Guest".to_string(),
+            subscription: None,
+        };
+
+        session
+            .save(authifier)
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "save",
+                with: "session",
+            })?;
+
+        Ok((user, session))
+    }
+
     /// Resolve an invite by its ID or by a public server ID
     pub async fn find(db: &Database, code: &str) -> Result<Invite> {
         if let Ok(invite) = db.fetch_invite(code).await {
@@ -62,6 +160,7 @@ impl Invite {
                         server: server.id,
                         creator: server.owner,
                         channel,
+                        guest: None,
                     });
                 }
             }
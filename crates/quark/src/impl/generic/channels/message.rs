@@ -1,5 +1,10 @@
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
+use iso8601_timestamp::Timestamp;
+use linkify::{LinkFinder, LinkKind};
 use revolt_presence::filter_online;
 use serde_json::json;
 use ulid::Ulid;
@@ -9,17 +14,22 @@ use crate::{
     events::client::EventV1,
     models::{
         message::{
-            AppendMessage, BulkMessageResponse, Interactions, PartialMessage, SendableEmbed,
+            AppendMessage, BulkMessageResponse, ChannelSearchResults, DataMessageSend,
+            DeletedMessage, Interactions, PartialMessage, SendableEmbed, ServerSearchResponse,
             SystemMessage,
         },
-        Channel, Emoji, Message, User,
+        Channel, Emoji, LegalHold, Member, Message, User,
     },
     permissions::PermissionCalculator,
-    tasks::ack::AckEvent,
+    tasks::{
+        ack::AckEvent,
+        reactions::{self, ReactionChange},
+    },
     types::{
         january::{Embed, Text},
         push::{MessageAuthor, PushNotification},
     },
+    util::{i18n, variables::delta::MESSAGE_RECOVERY_WINDOW},
     Database, Error, Permission, Result,
 };
 
@@ -44,6 +54,9 @@ impl Message {
         )
         .await;
 
+        // Add to search index
+        crate::tasks::search_index::queue_index(self).await;
+
         // Add mentions for affected users
         if let Some(mentions) = &self.mentions {
             for user in mentions {
@@ -72,38 +85,180 @@ impl Message {
             .await?;
 
         // Push out Web Push notifications
-        crate::tasks::web_push::queue(
-            {
-                let mut target_ids = vec![];
-                match &channel {
-                    Channel::DirectMessage { recipients, .. }
-                    | Channel::Group { recipients, .. } => {
-                        target_ids = (&recipients.iter().cloned().collect::<HashSet<String>>()
-                            - &filter_online(recipients).await)
-                            .into_iter()
-                            .collect::<Vec<String>>();
+        let target_ids = {
+            let mut target_ids = vec![];
+            match &channel {
+                Channel::DirectMessage { recipients, .. } | Channel::Group { recipients, .. } => {
+                    target_ids = (&recipients.iter().cloned().collect::<HashSet<String>>()
+                        - &filter_online(recipients).await)
+                        .into_iter()
+                        .collect::<Vec<String>>();
+                }
+                Channel::TextChannel { server, .. } => {
+                    if let Some(mentions) = &self.mentions {
+                        target_ids.append(&mut mentions.clone());
                     }
-                    Channel::TextChannel { .. } => {
-                        if let Some(mentions) = &self.mentions {
-                            target_ids.append(&mut mentions.clone());
+
+                    for id in self
+                        .fetch_role_mentioned_members(db, channel, server)
+                        .await
+                        .unwrap_or_default()
+                    {
+                        crate::tasks::ack::queue(
+                            channel.id().to_string(),
+                            id.to_string(),
+                            AckEvent::AddMention {
+                                ids: vec![self.id.to_string()],
+                            },
+                        )
+                        .await;
+
+                        target_ids.push(id);
+                    }
+
+                    for id in self
+                        .fetch_notified_members(db, channel, server)
+                        .await
+                        .unwrap_or_default()
+                    {
+                        if !target_ids.contains(&id) {
+                            target_ids.push(id);
                         }
                     }
-                    _ => {}
-                };
-                target_ids
-            },
-            json!(PushNotification::new(self.clone(), sender, channel.id())).to_string(),
-        )
-        .await;
+                }
+                _ => {}
+            };
+            target_ids
+        };
+
+        if target_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Render a notification for each locale in use by the targets
+        let mut targets_by_locale: HashMap<&'static str, Vec<String>> = HashMap::new();
+        for user in db.fetch_users(&target_ids).await.unwrap_or_default() {
+            targets_by_locale
+                .entry(i18n::resolve(user.locale.as_deref()))
+                .or_default()
+                .push(user.id);
+        }
+
+        for (locale, target_ids) in targets_by_locale {
+            crate::tasks::web_push::queue(
+                target_ids,
+                json!(PushNotification::new(
+                    self.clone(),
+                    sender.as_ref(),
+                    channel.id(),
+                    Some(locale)
+                ))
+                .to_string(),
+            )
+            .await;
+        }
 
         Ok(())
     }
 
+    /// Find offline members who should be notified about every message in a server channel
+    ///
+    /// This includes members who notify on all messages and members following this channel.
+    async fn fetch_notified_members(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        server: &str,
+    ) -> Result<Vec<String>> {
+        let members = db.fetch_notified_members(server, channel.id()).await?;
+        let ids: Vec<String> = members
+            .iter()
+            .map(|member| member.id.user.clone())
+            .filter(|id| id != &self.author)
+            .collect();
+
+        let offline: Vec<String> = (&ids.iter().cloned().collect::<HashSet<String>>()
+            - &filter_online(&ids).await)
+            .into_iter()
+            .collect();
+
+        self.filter_channel_viewers(db, channel, server, &members, &offline)
+            .await
+    }
+
+    /// Find members who hold a role mentioned by this message
+    ///
+    /// Users who were also mentioned directly are left out, as they are already notified.
+    async fn fetch_role_mentioned_members(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        server: &str,
+    ) -> Result<Vec<String>> {
+        let roles = match &self.role_mentions {
+            Some(roles) if !roles.is_empty() => roles,
+            _ => return Ok(vec![]),
+        };
+
+        let members = db.fetch_members_with_roles(server, roles).await?;
+        let ids: Vec<String> = members
+            .iter()
+            .map(|member| member.id.user.clone())
+            .filter(|id| id != &self.author)
+            .filter(|id| {
+                !self
+                    .mentions
+                    .as_ref()
+                    .map(|mentions| mentions.contains(id))
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        self.filter_channel_viewers(db, channel, server, &members, &ids)
+            .await
+    }
+
+    /// Keep only the given users who are able to view a server channel
+    async fn filter_channel_viewers(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        server: &str,
+        members: &[Member],
+        ids: &[String],
+    ) -> Result<Vec<String>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let server = db.fetch_server(server).await?;
+        let mut target_ids = vec![];
+        for user in db.fetch_users(ids).await? {
+            if let Some(member) = members.iter().find(|member| member.id.user == user.id) {
+                if PermissionCalculator::new(&user)
+                    .channel(channel)
+                    .server(&server)
+                    .member(member)
+                    .has_permission(db, Permission::ViewChannel)
+                    .await?
+                {
+                    target_ids.push(user.id);
+                }
+            }
+        }
+
+        Ok(target_ids)
+    }
+
     /// Update message data
     pub async fn update(&mut self, db: &Database, partial: PartialMessage) -> Result<()> {
         self.apply_options(partial.clone());
         db.update_message(&self.id, &partial).await?;
 
+        if partial.content.is_some() {
+            crate::tasks::search_index::queue_index(self).await;
+        }
+
         EventV1::MessageUpdate {
             id: self.id.clone(),
             channel: self.channel.clone(),
@@ -136,30 +291,67 @@ impl Message {
     }
 
     /// Delete a message
+    ///
+    /// Messages are held for recovery until the recovery window passes,
+    /// after which they are purged along with their attachments.
+    /// Messages under a legal hold are kept until the hold is released.
     pub async fn delete(self, db: &Database) -> Result<()> {
-        let file_ids: Vec<String> = self
-            .attachments
-            .map(|files| files.iter().map(|file| file.id.to_string()).collect())
-            .unwrap_or_default();
+        let id = self.id.clone();
+        let channel = self.channel.clone();
+
+        // Content under a legal hold is always kept for recovery
+        if *MESSAGE_RECOVERY_WINDOW > 0
+            || LegalHold::any_held(db, &[&self.channel, &self.author]).await?
+        {
+            db.trash_message(&DeletedMessage {
+                id: id.clone(),
+                message: self,
+                deleted_at: Timestamp::now_utc(),
+            })
+            .await?;
+        } else {
+            let file_ids: Vec<String> = self
+                .attachments
+                .map(|files| files.iter().map(|file| file.id.to_string()).collect())
+                .unwrap_or_default();
+
+            if !file_ids.is_empty() {
+                db.mark_attachments_as_deleted(&file_ids).await?;
+            }
 
-        if !file_ids.is_empty() {
-            db.mark_attachments_as_deleted(&file_ids).await?;
+            db.delete_message(&id).await?;
         }
 
-        db.delete_message(&self.id).await?;
+        crate::tasks::search_index::queue_remove(vec![id.clone()]).await;
 
         EventV1::MessageDelete {
-            id: self.id,
-            channel: self.channel.clone(),
+            id,
+            channel: channel.clone(),
         }
-        .p(self.channel)
+        .p(channel)
         .await;
         Ok(())
     }
 
+    /// Restore a message which is still within its recovery window
+    pub async fn restore(db: &Database, id: &str) -> Result<Message> {
+        let message = db.restore_message(id).await?;
+        crate::tasks::search_index::queue_index(&message).await;
+
+        EventV1::Message(message.clone())
+            .p(message.channel.clone())
+            .await;
+
+        Ok(message)
+    }
+
     /// Bulk delete messages
+    ///
+    /// Messages are always held for recovery and purged once the recovery
+    /// window passes, unless they are under a legal hold.
     pub async fn bulk_delete(db: &Database, channel: &str, ids: Vec<String>) -> Result<()> {
         db.delete_messages(channel, ids.clone()).await?;
+        crate::tasks::search_index::queue_remove(ids.clone()).await;
         EventV1::BulkMessageDelete {
             channel: channel.to_string(),
             ids,
@@ -191,8 +383,9 @@ impl Message {
 
     /// Add a reaction to a message
     pub async fn add_reaction(&self, db: &Database, user: &User, emoji: &str) -> Result<()> {
-        // Check how many reactions are already on the message
-        if self.reactions.len() >= 20 {
+        // Check how many reactions are already on the message,
+        // this is enforced again when the reaction is written
+        if !self.reactions.contains_key(emoji) && self.reactions.len() >= reactions::MAX_REACTIONS {
             return Err(Error::InvalidOperation);
         }
 
@@ -206,65 +399,61 @@ impl Message {
             return Err(Error::InvalidOperation);
         }
 
-        // Send reaction event
-        EventV1::MessageReact {
-            id: self.id.to_string(),
-            channel_id: self.channel.to_string(),
-            user_id: user.id.to_string(),
-            emoji_id: emoji.to_string(),
-        }
-        .p(self.channel.to_string())
+        // Queue reaction to be written and published
+        reactions::queue(
+            db,
+            self.id.to_string(),
+            self.channel.to_string(),
+            ReactionChange::React {
+                user_id: user.id.to_string(),
+                emoji_id: emoji.to_string(),
+            },
+        )
         .await;
 
-        // Add emoji
-        db.add_reaction(&self.id, emoji, &user.id).await
+        // Count uses of custom emoji
+        if Ulid::from_str(emoji).is_ok() {
+            crate::tasks::emoji_usage::queue(emoji.to_string()).await;
+        }
+
+        Ok(())
     }
 
     /// Remove a reaction from a message
+    ///
+    /// The reaction may still be waiting to be written, so this is not checked
+    /// against the message and the batch resolves the order of changes instead.
     pub async fn remove_reaction(&self, db: &Database, user: &str, emoji: &str) -> Result<()> {
-        // Check if it actually exists
-        let empty = if let Some(users) = self.reactions.get(emoji) {
-            if !users.contains(user) {
-                return Err(Error::NotFound);
-            }
-
-            users.len() == 1
-        } else {
-            return Err(Error::NotFound);
-        };
-
-        // Send reaction event
-        EventV1::MessageUnreact {
-            id: self.id.to_string(),
-            channel_id: self.channel.to_string(),
-            user_id: user.to_string(),
-            emoji_id: emoji.to_string(),
-        }
-        .p(self.channel.to_string())
+        // Queue removal to be written and published,
+        // emojis left without reactions are removed entirely
+        reactions::queue(
+            db,
+            self.id.to_string(),
+            self.channel.to_string(),
+            ReactionChange::Unreact {
+                user_id: user.to_string(),
+                emoji_id: emoji.to_string(),
+            },
+        )
         .await;
 
-        if empty {
-            // If empty, remove the reaction entirely
-            db.clear_reaction(&self.id, emoji).await
-        } else {
-            // Otherwise only remove that one reaction
-            db.remove_reaction(&self.id, emoji, user).await
-        }
+        Ok(())
     }
 
     /// Remove a reaction from a message
     pub async fn clear_reaction(&self, db: &Database, emoji: &str) -> Result<()> {
-        // Send reaction event
-        EventV1::MessageRemoveReaction {
-            id: self.id.to_string(),
-            channel_id: self.channel.to_string(),
-            emoji_id: emoji.to_string(),
-        }
-        .p(self.channel.to_string())
+        // Queue removal to be written and published
+        reactions::queue(
+            db,
+            self.id.to_string(),
+            self.channel.to_string(),
+            ReactionChange::Clear {
+                emoji_id: emoji.to_string(),
+            },
+        )
         .await;
 
-        // Write to database
-        db.clear_reaction(&self.id, emoji).await
+        Ok(())
     }
 
     pub fn is_webhook(&self) -> bool {
@@ -297,7 +486,12 @@ impl IntoUsers for Message {
                 | SystemMessage::UserBanned { id, .. } => ids.push(id.clone()),
                 SystemMessage::ChannelRenamed { by, .. }
                 | SystemMessage::ChannelDescriptionChanged { by, .. }
-                | SystemMessage::ChannelIconChanged { by, .. } => ids.push(by.clone()),
+                | SystemMessage::ChannelIconChanged { by, .. }
+                | SystemMessage::ChannelLocked { by, .. }
+                | SystemMessage::ChannelUnlocked { by, .. } => ids.push(by.clone()),
+                SystemMessage::MessageHighlighted { author, .. } => ids.push(author.clone()),
+                SystemMessage::MemberWarned { by, .. } => ids.push(by.clone()),
+                SystemMessage::MessagePinned { by, .. } => ids.push(by.clone()),
                 _ => {}
             }
         }
@@ -317,6 +511,57 @@ impl IntoUsers for Vec<Message> {
     }
 }
 
+impl DataMessageSend {
+    /// Check whether this message has an attachment or contains a link
+    pub fn has_media(&self) -> bool {
+        if self.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+            return true;
+        }
+
+        let mut finder = LinkFinder::new();
+        finder.kinds(&[LinkKind::Url]);
+
+        self.content
+            .as_deref()
+            .is_some_and(|content| finder.links(content).next().is_some())
+    }
+
+    /// Remove attachments, embeds and links from this message
+    pub fn strip_media(&mut self) {
+        self.attachments = None;
+        self.embeds = None;
+
+        if let Some(content) = &mut self.content {
+            let mut finder = LinkFinder::new();
+            finder.kinds(&[LinkKind::Url]);
+
+            let mut stripped = String::new();
+            let mut last = 0;
+            for link in finder.links(content) {
+                stripped.push_str(&content[last..link.start()]);
+                last = link.end();
+            }
+
+            stripped.push_str(&content[last..]);
+            *content = stripped;
+        }
+    }
+
+    /// Convert into the API model, used to store this message for later
+    pub fn into_v0(self) -> Result<revolt_models::v0::DataMessageSend> {
+        serde_json::to_value(self)
+            .and_then(serde_json::from_value)
+            .map_err(|_| Error::InternalError)
+    }
+
+    /// Convert from the API model, used to send a stored message
+    pub fn from_v0(data: revolt_models::v0::DataMessageSend) -> Result<Self> {
+        serde_json::to_value(data)
+            .and_then(serde_json::from_value)
+            .map_err(|_| Error::InternalError)
+    }
+}
+
 impl SystemMessage {
     pub fn into_message(self, channel: String) -> Message {
         Message {
@@ -328,31 +573,70 @@ impl SystemMessage {
             ..Default::default()
         }
     }
+
+    /// Render this system message as text in the given locale
+    pub fn render(&self, locale: Option<&str>) -> String {
+        let key = match self {
+            SystemMessage::Text { content } => return content.clone(),
+            SystemMessage::ChannelRenamed { name, .. } => {
+                return i18n::translate(locale, "system.channel_renamed", &[("name", name)])
+            }
+            SystemMessage::ThreadStarted { name, .. } => {
+                return i18n::translate(locale, "system.thread_started", &[("name", name)])
+            }
+            SystemMessage::UserAdded { .. } => "system.user_added",
+            SystemMessage::UserRemove { .. } => "system.user_remove",
+            SystemMessage::UserJoined { .. } => "system.user_joined",
+            SystemMessage::UserLeft { .. } => "system.user_left",
+            SystemMessage::UserKicked { .. } => "system.user_kicked",
+            SystemMessage::UserBanned { .. } => "system.user_banned",
+            SystemMessage::ChannelDescriptionChanged { .. } => "system.channel_description_changed",
+            SystemMessage::ChannelIconChanged { .. } => "system.channel_icon_changed",
+            SystemMessage::ChannelOwnershipChanged { .. } => "system.channel_ownership_changed",
+            SystemMessage::ChannelLocked { .. } => "system.channel_locked",
+            SystemMessage::ChannelUnlocked { .. } => "system.channel_unlocked",
+            SystemMessage::MessageHighlighted { .. } => "system.message_highlighted",
+            SystemMessage::MemberWarned { .. } => "system.member_warned",
+            SystemMessage::MessagePinned { .. } => "system.message_pinned",
+        };
+
+        i18n::translate(locale, key, &[])
+    }
 }
 
 impl From<SystemMessage> for String {
     fn from(s: SystemMessage) -> String {
-        match s {
-            SystemMessage::Text { content } => content,
-            SystemMessage::UserAdded { .. } => "User added to the channel.".to_string(),
-            SystemMessage::UserRemove { .. } => "User removed from the channel.".to_string(),
-            SystemMessage::UserJoined { .. } => "User joined the channel.".to_string(),
-            SystemMessage::UserLeft { .. } => "User left the channel.".to_string(),
-            SystemMessage::UserKicked { .. } => "User kicked from the channel.".to_string(),
-            SystemMessage::UserBanned { .. } => "User banned from the channel.".to_string(),
-            SystemMessage::ChannelRenamed { .. } => "Channel renamed.".to_string(),
-            SystemMessage::ChannelDescriptionChanged { .. } => {
-                "Channel description changed.".to_string()
-            }
-            SystemMessage::ChannelIconChanged { .. } => "Channel icon changed.".to_string(),
-            SystemMessage::ChannelOwnershipChanged { .. } => {
-                "Channel ownership changed.".to_string()
-            }
-        }
+        s.render(None)
     }
 }
 
 impl SendableEmbed {
+    /// Convert into an embed, without claiming its media
+    ///
+    /// The media must be claimed separately once the message is saved.
+    pub async fn resolve(self, db: &Database) -> Result<Embed> {
+        self.validate()
+            .map_err(|error| Error::FailedValidation { error })?;
+
+        let media = if let Some(id) = self.media {
+            Some(
+                db.fetch_unused_attachment(&id, "attachments", "message")
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Embed::Text(Text {
+            icon_url: self.icon_url,
+            url: self.url,
+            title: self.title,
+            description: self.description,
+            media,
+            colour: self.colour,
+        }))
+    }
+
     pub async fn into_embed(self, db: &Database, message_id: &str) -> Result<Embed> {
         self.validate()
             .map_err(|error| Error::FailedValidation { error })?;
@@ -409,6 +693,46 @@ impl BulkMessageResponse {
     }
 }
 
+impl ServerSearchResponse {
+    pub async fn transform(
+        db: &Database,
+        server: &str,
+        messages: Vec<Message>,
+        include_users: Option<bool>,
+    ) -> Result<ServerSearchResponse> {
+        let (users, members) = if let Some(true) = include_users {
+            let user_ids = messages.get_user_ids();
+            (
+                Some(User::fetch_foreign_users(db, &user_ids).await?),
+                Some(db.fetch_members(server, &user_ids).await?),
+            )
+        } else {
+            (None, None)
+        };
+
+        // Group messages by channel, keeping channels in order of their best result.
+        let mut channels: Vec<ChannelSearchResults> = vec![];
+        for message in messages {
+            match channels
+                .iter_mut()
+                .find(|results| results.channel == message.channel)
+            {
+                Some(results) => results.messages.push(message),
+                None => channels.push(ChannelSearchResults {
+                    channel: message.channel.clone(),
+                    messages: vec![message],
+                }),
+            }
+        }
+
+        Ok(ServerSearchResponse {
+            channels,
+            users,
+            members,
+        })
+    }
+}
+
 impl Interactions {
     /// Validate interactions info is correct
     pub async fn validate(
@@ -427,6 +751,17 @@ impl Interactions {
                 if !Emoji::can_use(db, reaction).await? {
                     return Err(Error::InvalidOperation);
                 }
+
+                let external = match permissions.channel.get() {
+                    Some(channel) => Emoji::is_external(db, reaction, channel).await?,
+                    None => false,
+                };
+
+                if external {
+                    permissions
+                        .throw_permission(db, Permission::UseExternalEmojis)
+                        .await?;
+                }
             }
         }
 
@@ -451,3 +786,67 @@ impl Interactions {
         !self.restrict_reactions && self.reactions.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iso8601_timestamp::{Duration, Timestamp};
+    use ulid::Ulid;
+
+    use crate::models::legal_hold::LegalHoldSubject;
+    use crate::models::{LegalHold, Message};
+    use crate::{Database, DatabaseInfo};
+
+    #[async_std::test]
+    async fn bulk_delete_keeps_held_messages() {
+        // Only meaningful against a real database
+        let db = DatabaseInfo::Auto.connect().await.unwrap();
+        if let Database::Dummy(_) = db {
+            return;
+        }
+
+        let channel = Ulid::new().to_string();
+        let held_author = Ulid::new().to_string();
+        let other_author = Ulid::new().to_string();
+
+        db.insert_legal_hold(&LegalHold {
+            id: held_author.clone(),
+            subject: LegalHoldSubject::User,
+            reason: "Test".to_string(),
+            placed_by: "admin".to_string(),
+            placed_at: Timestamp::now_utc(),
+        })
+        .await
+        .unwrap();
+
+        let mut ids = vec![];
+        for author in [&held_author, &other_author] {
+            let message = Message {
+                id: Ulid::new().to_string(),
+                channel: channel.clone(),
+                author: author.to_string(),
+                content: Some("Hello".to_string()),
+                ..Default::default()
+            };
+
+            db.insert_message(&message).await.unwrap();
+            ids.push(message.id);
+        }
+
+        db.delete_messages(&channel, ids.clone()).await.unwrap();
+        assert!(db.fetch_message(&ids[0]).await.is_err());
+        assert!(db.fetch_message(&ids[1]).await.is_err());
+
+        // Purge everything regardless of the recovery window
+        let future = Timestamp::now_utc().checked_add(Duration::days(1)).unwrap();
+        let held = db.fetch_held_ids().await.unwrap();
+        let purged = db.purge_deleted_messages(future, &held).await.unwrap();
+
+        assert!(purged.iter().any(|message| message.id == ids[1]));
+        assert!(db.fetch_deleted_message(&ids[0]).await.is_ok());
+        assert!(db.fetch_deleted_message(&ids[1]).await.is_err());
+
+        db.delete_legal_hold(&held_author).await.unwrap();
+        db.purge_deleted_messages(future, &[]).await.unwrap();
+        assert!(db.fetch_deleted_message(&ids[0]).await.is_err());
+    }
+}
@@ -5,7 +5,11 @@ use ulid::Ulid;
 
 use crate::{
     events::client::EventV1,
-    models::{emoji::EmojiParent, Emoji},
+    models::{
+        emoji::{EmojiAsset, EmojiParent, EMOJI_ASSET_SIZES},
+        Channel, Emoji,
+    },
+    variables::delta::AUTUMN_URL,
     Database, Result,
 };
 
@@ -44,13 +48,178 @@ impl Emoji {
         db.detach_emoji(&self).await
     }
 
+    /// Change the name and aliases of an emoji
+    pub async fn update_names(
+        &mut self,
+        db: &Database,
+        name: String,
+        aliases: Vec<String>,
+    ) -> Result<()> {
+        db.update_emoji_names(&self.id, &name, &aliases).await?;
+        self.name = name;
+        self.aliases = aliases;
+
+        EventV1::EmojiUpdate(self.clone())
+            .p(self.parent().to_string())
+            .await;
+
+        Ok(())
+    }
+
+    /// Replace this emoji with a newly uploaded image
+    ///
+    /// Existing reactions and messages using this emoji are moved over
+    /// to the replacement in the background.
+    pub async fn replace(
+        self,
+        db: &Database,
+        id: String,
+        creator_id: String,
+        animated: bool,
+    ) -> Result<Emoji> {
+        let replacement = Emoji {
+            id,
+            parent: self.parent.clone(),
+            creator_id,
+            name: self.name.clone(),
+            aliases: self.aliases.clone(),
+            animated,
+            nsfw: self.nsfw,
+            replaced_by: None,
+        };
+
+        replacement.create(db).await?;
+        db.replace_emoji(&self, &replacement.id).await?;
+
+        EventV1::EmojiDelete {
+            id: self.id.to_string(),
+        }
+        .p(self.parent().to_string())
+        .await;
+
+        let db = db.clone();
+        let from = self.id;
+        let to = replacement.id.clone();
+        async_std::task::spawn(async move {
+            if let Ok(usage) = db.fetch_emoji_usage(&[from.clone()]).await {
+                for usage in usage {
+                    if let Err(err) = db.increment_emoji_usage(&to, usage.uses).await {
+                        warn!("Failed to carry over usage of {from} to {to}: {err:?}");
+                    }
+                }
+            }
+
+            if let Err(err) = db.migrate_emoji_references(&from, &to).await {
+                error!("Failed to migrate references to {from} over to {to}: {err:?}");
+            }
+        });
+
+        Ok(replacement)
+    }
+
+    /// URL of this emoji's image on the file server, optionally scaled to fit a size
+    ///
+    /// Emoji images never change once uploaded, so these can be cached indefinitely.
+    pub fn asset_url(&self, size: Option<u32>) -> String {
+        match size {
+            Some(size) => format!("{}/emojis/{}?max_side={}", *AUTUMN_URL, self.id, size),
+            None => format!("{}/emojis/{}", *AUTUMN_URL, self.id),
+        }
+    }
+
+    /// Attach the URLs of this emoji's image and each of its size variants
+    pub fn into_asset(self) -> EmojiAsset {
+        EmojiAsset {
+            url: self.asset_url(None),
+            variants: EMOJI_ASSET_SIZES
+                .iter()
+                .map(|size| (*size, self.asset_url(Some(*size))))
+                .collect(),
+            emoji: self,
+        }
+    }
+
+    /// Score how well this emoji's name or aliases match a search query, lower is better
+    ///
+    /// Returns None if the query isn't contained in any name, even loosely.
+    pub fn match_score(&self, query: &str) -> Option<usize> {
+        let query = query.to_lowercase();
+        std::iter::once(&self.name)
+            .chain(self.aliases.iter())
+            .filter_map(|name| Emoji::name_score(&name.to_lowercase(), &query))
+            .min()
+    }
+
+    /// Score how well a single lowercase name matches a lowercase query
+    fn name_score(name: &str, query: &str) -> Option<usize> {
+        if name == query {
+            Some(0)
+        } else if name.starts_with(query) {
+            Some(1)
+        } else if name.contains(query) {
+            Some(2)
+        } else {
+            // Fall back to matching characters in order, penalising gaps.
+            let mut gaps = 0;
+            let mut chars = name.chars();
+            for c in query.chars() {
+                loop {
+                    match chars.next() {
+                        Some(n) if n == c => break,
+                        Some(_) => gaps += 1,
+                        None => return None,
+                    }
+                }
+            }
+
+            Some(3 + gaps)
+        }
+    }
+
+    /// Follow a custom emoji's replacements through to the emoji currently in use
+    ///
+    /// Unicode emoji and custom emoji which were never replaced are returned as-is.
+    pub async fn resolve(db: &Database, emoji: &str) -> Result<String> {
+        let mut id = emoji.to_string();
+        if Ulid::from_str(&id).is_err() {
+            return Ok(id);
+        }
+
+        // Bound the number of hops in case replacements ever form a cycle
+        for _ in 0..8 {
+            match db.fetch_emoji(&id).await?.replaced_by {
+                Some(replacement) => id = replacement,
+                None => break,
+            }
+        }
+
+        Ok(id)
+    }
+
     /// Check whether we can use a given emoji
+    ///
+    /// Custom emoji must still belong to a server.
     pub async fn can_use(db: &Database, emoji: &str) -> Result<bool> {
         if Ulid::from_str(emoji).is_ok() {
-            db.fetch_emoji(emoji).await?;
-            Ok(true)
+            let emoji = db.fetch_emoji(emoji).await?;
+            Ok(!matches!(emoji.parent, EmojiParent::Detached))
         } else {
             Ok(PERMISSIBLE_EMOJIS.contains(emoji))
         }
     }
+
+    /// Check whether a given emoji is a custom emoji from outside of the channel's server
+    pub async fn is_external(db: &Database, emoji: &str, channel: &Channel) -> Result<bool> {
+        if Ulid::from_str(emoji).is_err() {
+            return Ok(false);
+        }
+
+        let emoji = db.fetch_emoji(emoji).await?;
+        Ok(match channel {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                emoji.parent() != server.as_str()
+            }
+            _ => true,
+        })
+    }
 }
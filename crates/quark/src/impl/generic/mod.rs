@@ -25,6 +25,7 @@ pub mod users {
 }
 
 pub mod safety {
+    pub mod legal_hold;
     pub mod report;
     pub mod snapshot;
 }
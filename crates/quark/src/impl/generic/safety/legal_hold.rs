@@ -0,0 +1,16 @@
+use crate::{models::LegalHold, Database, Error, Result};
+
+impl LegalHold {
+    /// Check whether any of the given users or channels are under a legal hold
+    pub async fn any_held(db: &Database, ids: &[&str]) -> Result<bool> {
+        for id in ids {
+            match db.fetch_legal_hold(id).await {
+                Ok(_) => return Ok(true),
+                Err(Error::NotFound) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(false)
+    }
+}
@@ -1,10 +1,19 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+use ulid::Ulid;
+
 use crate::{
+    events::client::EventV1,
     models::{
         message::{MessageFilter, MessageQuery, MessageSort, MessageTimePeriod},
-        snapshot::SnapshotContent,
-        Message, Server, User,
+        report::PartialReport,
+        server::{FieldsRole, FieldsServer, PartialServer, SystemMessageChannels},
+        snapshot::{SnapshotChange, SnapshotContent, SnapshotDiff},
+        user::{FieldsUser, PartialUser, UserProfile},
+        Message, Server, Snapshot, User,
     },
-    Database, Result,
+    Database, Error, Result,
 };
 
 impl SnapshotContent {
@@ -32,6 +41,7 @@ impl SnapshotContent {
                     after: None,
                     sort: Some(MessageSort::Latest),
                 },
+                consistent: true,
             })
             .await?;
 
@@ -48,6 +58,7 @@ impl SnapshotContent {
                     after: Some(message.id.to_string()),
                     sort: Some(MessageSort::Oldest),
                 },
+                consistent: true,
             })
             .await?;
 
@@ -85,4 +96,277 @@ impl SnapshotContent {
 
         Ok((SnapshotContent::User(user), files))
     }
+
+    /// Id of the object captured in this snapshot
+    pub fn object_id(&self) -> &str {
+        match self {
+            SnapshotContent::Message { message, .. } => &message.id,
+            SnapshotContent::Server(server) => &server.id,
+            SnapshotContent::User(user) => &user.id,
+        }
+    }
+}
+
+/// Walk two values and record every leaf which differs between them
+fn diff_values(
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<SnapshotChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+            for key in keys {
+                let path = if path.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                diff_values(path, a.get(key), b.get(key), changes);
+            }
+        }
+        (a, b) if a != b => changes.push(SnapshotChange {
+            path,
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+impl Snapshot {
+    /// Take a snapshot of a monitored server
+    pub async fn monitor_server(db: &Database, server: Server) -> Result<()> {
+        let (content, _) = SnapshotContent::generate_from_server(server)?;
+        db.insert_snapshot(&Snapshot {
+            id: Ulid::new().to_string(),
+            report_id: None,
+            content,
+        })
+        .await
+    }
+
+    /// Compare this snapshot against another snapshot of the same object
+    pub fn diff(&self, other: &Snapshot) -> Result<SnapshotDiff> {
+        if self.content.object_id() != other.content.object_id() {
+            return Err(Error::InvalidOperation);
+        }
+
+        // Snapshot ids are ordered by creation time
+        let (before, after) = if self.id <= other.id {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let serialise = |snapshot: &Snapshot| {
+            serde_json::to_value(&snapshot.content).map_err(|_| Error::InternalError)
+        };
+
+        let mut changes = vec![];
+        diff_values(
+            String::new(),
+            Some(&serialise(before)?),
+            Some(&serialise(after)?),
+            &mut changes,
+        );
+
+        Ok(SnapshotDiff {
+            before: before.id.clone(),
+            after: after.id.clone(),
+            changes,
+        })
+    }
+
+    /// Restore the object captured in this snapshot to its prior state
+    ///
+    /// If this snapshot belongs to a report, a note is appended to it recording who performed the restoration.
+    pub async fn restore(&self, db: &Database, user: &User) -> Result<()> {
+        match &self.content {
+            SnapshotContent::Server(server) => restore_server(db, server).await?,
+            SnapshotContent::User(target) => restore_user(db, target).await?,
+            // Messages are not restored as their deletion is irreversible
+            SnapshotContent::Message { .. } => return Err(Error::InvalidOperation),
+        }
+
+        info!(
+            "{} restored {} from snapshot {}.",
+            user.id,
+            self.content.object_id(),
+            self.id
+        );
+
+        let report_id = match &self.report_id {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+
+        let mut report = db.fetch_report(report_id).await?;
+        let mut notes = report.notes.clone();
+        if !notes.is_empty() {
+            notes.push('\n');
+        }
+
+        notes.push_str(&format!(
+            "Restored {} from snapshot {} (by {}).",
+            self.content.object_id(),
+            self.id,
+            user.id
+        ));
+
+        report
+            .update(
+                db,
+                PartialReport {
+                    notes: Some(notes),
+                    ..Default::default()
+                },
+            )
+            .await
+    }
+}
+
+/// Restore a server's information and roles
+///
+/// Deleted channels cannot be recovered, so any references to them are dropped.
+async fn restore_server(db: &Database, snapshot: &Server) -> Result<()> {
+    let mut server = db.fetch_server(&snapshot.id).await?;
+
+    // Remove any roles created since the snapshot
+    let created: Vec<String> = server
+        .roles
+        .keys()
+        .filter(|id| !snapshot.roles.contains_key(*id))
+        .cloned()
+        .collect();
+
+    for id in created {
+        if let Some(role) = server.roles.remove(&id) {
+            role.delete(db, &server.id, &id).await?;
+        }
+    }
+
+    // Bring back or reset every role in the snapshot under its original id
+    for (id, role) in &snapshot.roles {
+        if let Some(current) = server.roles.get_mut(id) {
+            let mut remove = vec![];
+            if role.colour.is_none() {
+                remove.push(FieldsRole::Colour);
+            }
+
+            if role.menu_position.is_none() {
+                remove.push(FieldsRole::MenuPosition);
+            }
+
+            current
+                .update(db, &server.id, id, role.clone().into_optional(), remove)
+                .await?;
+        } else {
+            db.insert_role(&server.id, id, role).await?;
+
+            EventV1::ServerRoleUpdate {
+                id: server.id.clone(),
+                role_id: id.clone(),
+                data: role.clone().into_optional(),
+                clear: vec![],
+            }
+            .p(server.id.clone())
+            .await;
+        }
+    }
+
+    // Only keep references to channels which still exist
+    let exists = |id: &Option<String>| id.clone().filter(|id| server.channels.contains(id));
+
+    let categories = snapshot.categories.clone().map(|mut categories| {
+        for category in &mut categories {
+            category
+                .channels
+                .retain(|channel| server.channels.contains(channel));
+        }
+
+        categories
+    });
+
+    let system_messages = snapshot
+        .system_messages
+        .as_ref()
+        .map(|channels| SystemMessageChannels {
+            user_joined: exists(&channels.user_joined),
+            user_left: exists(&channels.user_left),
+            user_kicked: exists(&channels.user_kicked),
+            user_banned: exists(&channels.user_banned),
+        });
+
+    let mut remove = vec![];
+    if snapshot.description.is_none() {
+        remove.push(FieldsServer::Description);
+    }
+
+    if categories.is_none() {
+        remove.push(FieldsServer::Categories);
+    }
+
+    if system_messages.is_none() {
+        remove.push(FieldsServer::SystemMessages);
+    }
+
+    server
+        .update(
+            db,
+            PartialServer {
+                name: Some(snapshot.name.clone()),
+                description: snapshot.description.clone(),
+                categories,
+                system_messages,
+                default_permissions: Some(snapshot.default_permissions),
+                ..Default::default()
+            },
+            remove,
+        )
+        .await
+}
+
+/// Restore a user's username, status and profile text
+async fn restore_user(db: &Database, snapshot: &User) -> Result<()> {
+    let mut user = db.fetch_user(&snapshot.id).await?;
+
+    if user.username != snapshot.username {
+        user.update_username(db, snapshot.username.clone()).await?;
+    }
+
+    let content = snapshot
+        .profile
+        .as_ref()
+        .and_then(|profile| profile.content.clone());
+
+    let mut remove = vec![];
+    if snapshot.status.is_none() {
+        remove.push(FieldsUser::StatusText);
+        remove.push(FieldsUser::StatusPresence);
+        remove.push(FieldsUser::StatusActivity);
+    }
+
+    if content.is_none() {
+        remove.push(FieldsUser::ProfileContent);
+    }
+
+    // Keep the current profile background and links
+    let profile = content.map(|content| UserProfile {
+        content: Some(content),
+        ..user.profile.clone().unwrap_or_default()
+    });
+
+    user.update(
+        db,
+        PartialUser {
+            status: snapshot.status.clone(),
+            profile,
+            ..Default::default()
+        },
+        remove,
+    )
+    .await
 }
@@ -1,6 +1,7 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use iso8601_timestamp::Timestamp;
+use revolt_presence::filter_online;
 use ulid::Ulid;
 
 use crate::{
@@ -8,12 +9,13 @@ use crate::{
     models::{
         message::SystemMessage,
         server::{
-            FieldsRole, FieldsServer, PartialRole, PartialServer, Role, SystemMessageChannels,
+            Category, FieldsRole, FieldsServer, Onboarding, OnboardingOption, PartialRole,
+            PartialServer, Role, SystemMessageChannels,
         },
         server_member::{MemberCompositeKey, RemovalIntention},
         Channel, Member, Server, ServerBan, User,
     },
-    perms, Database, Error, OverrideField, Permission, Result,
+    perms, Database, Error, Override, OverrideField, Permission, Result,
 };
 
 impl Role {
@@ -25,6 +27,9 @@ impl Role {
             colour: self.colour,
             hoist: Some(self.hoist),
             rank: Some(self.rank),
+            mentionable: Some(self.mentionable),
+            self_assignable: Some(self.self_assignable),
+            menu_position: self.menu_position,
         }
     }
 
@@ -91,6 +96,7 @@ impl Role {
     pub fn remove(&mut self, field: &FieldsRole) {
         match field {
             FieldsRole::Colour => self.colour = None,
+            FieldsRole::MenuPosition => self.menu_position = None,
         }
     }
 }
@@ -127,6 +133,104 @@ impl Server {
         Ok(())
     }
 
+    /// Count members of this server who are currently online
+    pub async fn fetch_online_count(&self, db: &Database) -> Result<usize> {
+        let user_ids: Vec<String> = db
+            .fetch_all_members(&self.id)
+            .await?
+            .into_iter()
+            .map(|member| member.id.user)
+            .collect();
+
+        Ok(filter_online(&user_ids).await.len())
+    }
+
+    /// Find the category a channel belongs to
+    pub fn category_of(&self, channel: &str) -> Option<&Category> {
+        self.categories
+            .as_ref()?
+            .iter()
+            .find(|category| category.channels.iter().any(|id| id == channel))
+    }
+
+    /// Check whether a channel is hidden from a member by its category
+    ///
+    /// Channels in a hidden category stay hidden until one of the member's roles
+    /// grants ViewChannel, either on the server or through a channel override.
+    pub fn category_hides_channel(&self, member: &Member, channel: &Channel) -> bool {
+        if !self
+            .category_of(channel.id())
+            .map_or(false, |category| category.hidden)
+        {
+            return false;
+        }
+
+        let role_permissions = match channel {
+            Channel::TextChannel {
+                role_permissions, ..
+            }
+            | Channel::VoiceChannel {
+                role_permissions, ..
+            } => role_permissions,
+            _ => return false,
+        };
+
+        let view = Permission::ViewChannel as u64;
+        !member.roles.iter().any(|id| {
+            self.roles.get(id).map_or(false, |role| {
+                let server: Override = role.permissions.into();
+                let channel: Option<Override> = role_permissions.get(id).map(|v| (*v).into());
+
+                server.allows() & view == view
+                    || channel.map_or(false, |v| v.allows() & view == view)
+            })
+        })
+    }
+
+    /// Check whether a channel in this server can be seen by a member with no roles
+    ///
+    /// Channels marked as not safe for work, in a hidden category or revealed
+    /// through onboarding are never public.
+    pub fn is_public_channel(&self, channel: &Channel) -> bool {
+        let default_permissions = match channel {
+            Channel::TextChannel {
+                nsfw: false,
+                default_permissions,
+                ..
+            }
+            | Channel::VoiceChannel {
+                nsfw: false,
+                default_permissions,
+                ..
+            } => default_permissions,
+            _ => return false,
+        };
+
+        if self
+            .onboarding
+            .as_ref()
+            .map_or(false, |onboarding| onboarding.gates_channel(channel.id()))
+        {
+            return false;
+        }
+
+        if self
+            .category_of(channel.id())
+            .map_or(false, |category| category.hidden)
+        {
+            return false;
+        }
+
+        let mut value = self.default_permissions as u64;
+        if let Some(default) = default_permissions {
+            let v: Override = (*default).into();
+            value = (value | v.allows()) & !v.denies();
+        }
+
+        let view = Permission::ViewChannel as u64;
+        value & view == view
+    }
+
     /// Delete a server
     pub async fn delete(self, db: &Database) -> Result<()> {
         EventV1::ServerDelete {
@@ -146,6 +250,10 @@ impl Server {
             FieldsServer::SystemMessages => self.system_messages = None,
             FieldsServer::Icon => self.icon = None,
             FieldsServer::Banner => self.banner = None,
+            FieldsServer::Onboarding => self.onboarding = None,
+            FieldsServer::Highlights => self.highlights = None,
+            FieldsServer::WarnThresholds => self.warn_thresholds = None,
+            FieldsServer::StorageRegion => self.storage_region = None,
         }
     }
 
@@ -196,6 +304,11 @@ impl Server {
             avatar: None,
             roles: vec![],
             timeout: None,
+            voice_muted: false,
+            voice_deafened: false,
+            notifications: self.default_notifications,
+            followed_channels: vec![],
+            onboarding: None,
         };
 
         db.insert_member(&member).await?;
@@ -295,7 +408,11 @@ impl Server {
         id: MemberCompositeKey,
         reason: Option<String>,
     ) -> Result<ServerBan> {
-        let ban = ServerBan { id, reason };
+        let ban = ServerBan {
+            id,
+            reason,
+            synced_from: None,
+        };
         db.insert_ban(&ban).await?;
         Ok(ban)
     }
@@ -337,3 +454,115 @@ impl SystemMessageChannels {
         ids
     }
 }
+
+impl Onboarding {
+    /// Iterate over every option in this onboarding sequence
+    fn options(&self) -> impl Iterator<Item = &OnboardingOption> {
+        self.questions
+            .iter()
+            .flat_map(|question| question.options.iter())
+    }
+
+    /// Find all options which reveal a channel
+    fn options_revealing<'a>(
+        &'a self,
+        channel: &'a str,
+    ) -> impl Iterator<Item = &'a OnboardingOption> {
+        self.options()
+            .filter(move |option| option.channels.iter().any(|id| id == channel))
+    }
+
+    /// Check whether a channel is only revealed through onboarding
+    pub fn gates_channel(&self, channel: &str) -> bool {
+        self.enabled && self.options_revealing(channel).next().is_some()
+    }
+
+    /// Check whether a channel is hidden from a member
+    ///
+    /// Channels revealed by any option stay hidden until the member picks one of those options.
+    pub fn hides_channel(&self, member: &Member, channel: &str) -> bool {
+        if !self.gates_channel(channel) {
+            return false;
+        }
+
+        let picked = member.onboarding.as_deref().unwrap_or_default();
+        !self
+            .options_revealing(channel)
+            .any(|option| picked.contains(&option.id))
+    }
+
+    /// Find all roles granted by the given options
+    pub fn roles_for(&self, picked: &[String]) -> HashSet<String> {
+        self.options()
+            .filter(|option| picked.contains(&option.id))
+            .flat_map(|option| option.roles.iter().cloned())
+            .collect()
+    }
+
+    /// Check that this onboarding sequence only references roles and channels in the server
+    pub fn validate_against(&self, server: &Server) -> Result<()> {
+        let mut question_ids = HashSet::new();
+        let mut option_ids = HashSet::new();
+
+        for question in &self.questions {
+            if !question_ids.insert(&question.id) {
+                return Err(Error::InvalidOperation);
+            }
+
+            for option in &question.options {
+                if !option_ids.insert(&option.id) {
+                    return Err(Error::InvalidOperation);
+                }
+
+                if option.roles.iter().any(|id| !server.roles.contains_key(id)) {
+                    return Err(Error::InvalidRole);
+                }
+
+                if option
+                    .channels
+                    .iter()
+                    .any(|id| !server.channels.contains(id))
+                {
+                    return Err(Error::NotFound);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check answers given by a member and resolve the options they picked
+    pub fn resolve_answers(&self, answers: &HashMap<String, Vec<String>>) -> Result<Vec<String>> {
+        if answers
+            .keys()
+            .any(|id| !self.questions.iter().any(|question| &question.id == id))
+        {
+            return Err(Error::InvalidOperation);
+        }
+
+        let mut picked = vec![];
+        for question in &self.questions {
+            let answer = answers
+                .get(&question.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            if (question.required && answer.is_empty()) || (!question.multiple && answer.len() > 1)
+            {
+                return Err(Error::InvalidOperation);
+            }
+
+            for id in answer {
+                if !question.options.iter().any(|option| &option.id == id) {
+                    return Err(Error::InvalidOperation);
+                }
+
+                if !picked.contains(id) {
+                    picked.push(id.clone());
+                }
+            }
+        }
+
+        Ok(picked)
+    }
+}
@@ -59,6 +59,35 @@ impl Member {
         }
     }
 
+    /// Follow or unfollow a channel to be notified about every message in it
+    pub async fn set_following(
+        &mut self,
+        db: &Database,
+        channel: &str,
+        follow: bool,
+    ) -> Result<()> {
+        let mut followed_channels = self.followed_channels.clone();
+        followed_channels.retain(|id| id != channel);
+
+        if follow {
+            followed_channels.push(channel.to_string());
+        }
+
+        if followed_channels == self.followed_channels {
+            return Ok(());
+        }
+
+        self.update(
+            db,
+            PartialMember {
+                followed_channels: Some(followed_channels),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
     pub fn remove(&mut self, field: &FieldsMember) {
         match field {
             FieldsMember::Avatar => self.avatar = None,
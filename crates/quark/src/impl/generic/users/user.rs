@@ -1,24 +1,21 @@
 use crate::events::client::EventV1;
+use crate::models::LegalHold;
 use crate::models::user::{
-    Badges, FieldsUser, PartialUser, Presence, RelationshipStatus, User, UserHint,
+    Badges, FieldsUser, Flags, PartialUser, Presence, RelationshipStatus, User, UserHint,
 };
 use crate::permissions::defn::UserPerms;
 use crate::permissions::r#impl::user::get_relationship;
 use crate::{perms, Database, Error, Result};
 
 use futures::try_join;
-use impl_ops::impl_op_ex_commutative;
 use revolt_presence::filter_online;
-use std::ops;
-
-impl_op_ex_commutative!(+ |a: &i32, b: &Badges| -> i32 { *a | *b as i32 });
 
 impl User {
     /// Update user data
     pub async fn update<'a>(
         &mut self,
         db: &Database,
-        partial: PartialUser,
+        mut partial: PartialUser,
         remove: Vec<FieldsUser>,
     ) -> Result<()> {
         for field in &remove {
@@ -29,6 +26,40 @@ impl User {
 
         db.update_user(&self.id, &partial, remove.clone()).await?;
 
+        // Locale and privacy settings are only visible to the user themselves
+        let locale = partial.locale.take();
+        let dm_media_filter = partial.dm_media_filter.take();
+        let federated_dms = partial.federated_dms.take();
+        let (private, remove): (Vec<FieldsUser>, Vec<FieldsUser>) =
+            remove.into_iter().partition(|field| {
+                matches!(
+                    field,
+                    FieldsUser::Locale
+                        | FieldsUser::DirectMessageMediaFilter
+                        | FieldsUser::FederatedMessagePolicy
+                )
+            });
+
+        if locale.is_some()
+            || dm_media_filter.is_some()
+            || federated_dms.is_some()
+            || !private.is_empty()
+        {
+            EventV1::UserUpdate {
+                id: self.id.clone(),
+                data: PartialUser {
+                    locale,
+                    dm_media_filter,
+                    federated_dms,
+                    ..Default::default()
+                },
+                clear: private,
+                event_id: Some(ulid::Ulid::new().to_string()),
+            }
+            .private(self.id.clone())
+            .await;
+        }
+
         EventV1::UserUpdate {
             id: self.id.clone(),
             data: partial,
@@ -55,6 +86,11 @@ impl User {
                     x.presence = None;
                 }
             }
+            FieldsUser::StatusActivity => {
+                if let Some(x) = self.status.as_mut() {
+                    x.activity = None;
+                }
+            }
             FieldsUser::ProfileContent => {
                 if let Some(x) = self.profile.as_mut() {
                     x.content = None;
@@ -65,6 +101,19 @@ impl User {
                     x.background = None;
                 }
             }
+            FieldsUser::ProfileWebsite => {
+                if let Some(x) = self.profile.as_mut() {
+                    x.website = None;
+                }
+            }
+            FieldsUser::ProfileDonation => {
+                if let Some(x) = self.profile.as_mut() {
+                    x.donation = None;
+                }
+            }
+            FieldsUser::Locale => self.locale = None,
+            FieldsUser::DirectMessageMediaFilter => self.dm_media_filter = None,
+            FieldsUser::FederatedMessagePolicy => self.federated_dms = None,
         }
     }
 
@@ -73,13 +122,16 @@ impl User {
     pub fn foreign(mut self) -> User {
         self.profile = None;
         self.relations = None;
+        self.locale = None;
+        self.dm_media_filter = None;
+        self.federated_dms = None;
 
-        let mut badges = self.badges.unwrap_or(0);
+        let mut badges = self.badges.unwrap_or_default();
         if let Ok(id) = ulid::Ulid::from_string(&self.id) {
             // Yes, this is hard-coded
             // No, I don't care + ratio
             if id.datetime().timestamp_millis() < 1629638578431 {
-                badges = badges + Badges::EarlyAdopter;
+                badges.insert(Badges::EarlyAdopter);
             }
         }
 
@@ -220,6 +272,52 @@ impl User {
         .await
     }
 
+    /// Set a bot's presence and activity
+    ///
+    /// An empty activity clears the current one.
+    pub async fn set_bot_presence(
+        &mut self,
+        db: &Database,
+        presence: Option<Presence>,
+        activity: Option<String>,
+    ) -> Result<()> {
+        if self.bot.is_none() {
+            return Err(Error::IsNotBot);
+        }
+
+        // Bots don't receive notifications, so focus has no meaning for them
+        if presence == Some(Presence::Focus)
+            || activity
+                .as_ref()
+                .map_or(false, |activity| activity.chars().count() > 128)
+        {
+            return Err(Error::InvalidProperty);
+        }
+
+        let mut status = self.status.clone().unwrap_or_default();
+        if let Some(presence) = presence {
+            status.presence = Some(presence);
+        }
+
+        if let Some(activity) = activity {
+            status.activity = if activity.is_empty() {
+                None
+            } else {
+                Some(activity)
+            };
+        }
+
+        self.update(
+            db,
+            PartialUser {
+                status: Some(status),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
     /// Apply a certain relationship between two users
     pub async fn apply_relationship(
         &self,
@@ -372,20 +470,38 @@ impl User {
     }
 
     /// Mark as deleted
+    ///
+    /// Users under a legal hold keep their profile data.
     pub async fn mark_deleted(&mut self, db: &Database) -> Result<()> {
+        if LegalHold::any_held(db, &[&self.id]).await? {
+            return self
+                .update(
+                    db,
+                    PartialUser {
+                        flags: Some(Flags::Deleted.into()),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await;
+        }
+
         self.update(
             db,
             PartialUser {
                 username: Some(format!("Deleted User {}", self.id)),
-                flags: Some(2),
+                flags: Some(Flags::Deleted.into()),
                 ..Default::default()
             },
             vec![
                 FieldsUser::Avatar,
                 FieldsUser::StatusText,
                 FieldsUser::StatusPresence,
+                FieldsUser::StatusActivity,
                 FieldsUser::ProfileContent,
                 FieldsUser::ProfileBackground,
+                FieldsUser::ProfileWebsite,
+                FieldsUser::ProfileDonation,
             ],
         )
         .await
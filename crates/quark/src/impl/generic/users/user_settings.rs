@@ -1,4 +1,11 @@
-use crate::{events::client::EventV1, models::UserSettings, Database, Result};
+use crate::{
+    events::client::EventV1,
+    models::{
+        user_settings::{DmLayout, ServerLayout},
+        UserSettings,
+    },
+    Database, Result,
+};
 
 #[async_trait]
 pub trait UserSettingsImpl {
@@ -20,3 +27,37 @@ impl UserSettingsImpl for UserSettings {
         Ok(())
     }
 }
+
+impl ServerLayout {
+    /// Save this layout and notify the user's other sessions
+    pub async fn set(self, db: &Database) -> Result<()> {
+        db.set_server_layout(&self).await?;
+
+        let id = self.id.clone();
+        EventV1::UserServerLayoutUpdate {
+            id: id.clone(),
+            layout: self,
+        }
+        .private(id)
+        .await;
+
+        Ok(())
+    }
+}
+
+impl DmLayout {
+    /// Save this layout and notify the user's other sessions
+    pub async fn set(self, db: &Database) -> Result<()> {
+        db.set_dm_layout(&self).await?;
+
+        let id = self.id.clone();
+        EventV1::UserDmLayoutUpdate {
+            id: id.clone(),
+            layout: self,
+        }
+        .private(id)
+        .await;
+
+        Ok(())
+    }
+}
@@ -1,18 +1,24 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use bson::{from_document, Document};
+use bson::{from_document, to_bson, Document};
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
+use mongodb::options::{FindOptions, ReplaceOptions};
+use ulid::Ulid;
 
 use crate::{
-    models::stats::{Index, Stats},
-    AbstractStats, Error, Result,
+    models::stats::{CollectionSnapshot, Index, ServerStats, ServerStatsDay, Stats, StatsSnapshot},
+    AbstractEmoji, AbstractStats, Error, Result,
 };
 
 use super::super::MongoDb;
 
 #[async_trait]
 impl AbstractStats for MongoDb {
-    async fn generate_stats(&self) -> Result<Stats> {
+    async fn generate_stats(&self, days: u32) -> Result<Stats> {
         let mut indices = HashMap::new();
         let mut coll_stats = HashMap::new();
 
@@ -85,6 +91,300 @@ impl AbstractStats for MongoDb {
         Ok(Stats {
             indices,
             coll_stats,
+            history: self.fetch_stats_history(days).await?,
+            write_buffers: crate::tasks::write_buffer_stats(),
+        })
+    }
+
+    async fn snapshot_stats(&self) -> Result<()> {
+        let collection_names =
+            self.db()
+                .list_collection_names(None)
+                .await
+                .map_err(|_| Error::DatabaseError {
+                    operation: "list_collection_names",
+                    with: "database",
+                })?;
+
+        let mut collections = HashMap::new();
+        for collection in collection_names {
+            let storage = self
+                .col::<Document>(&collection)
+                .aggregate(
+                    vec![doc! {
+                        "$collStats": {
+                            "storageStats": {}
+                        }
+                    }],
+                    None,
+                )
+                .await
+                .map_err(|_| Error::DatabaseError {
+                    operation: "aggregate",
+                    with: "col",
+                })?
+                .filter_map(|s| async { s.ok() })
+                .collect::<Vec<Document>>()
+                .await
+                .into_iter()
+                .find_map(|doc| doc.get_document("storageStats").ok().cloned());
+
+            if let Some(storage) = storage {
+                let get = |key: &str| {
+                    storage.get(key).and_then(|value| {
+                        value.as_i64().or_else(|| value.as_i32().map(|v| v as i64))
+                    })
+                };
+
+                collections.insert(
+                    collection,
+                    CollectionSnapshot {
+                        count: get("count").unwrap_or_default(),
+                        size: get("size").unwrap_or_default(),
+                        storage_size: get("storageSize").unwrap_or_default(),
+                        total_index_size: get("totalIndexSize").unwrap_or_default(),
+                    },
+                );
+            }
+        }
+
+        let snapshot = StatsSnapshot {
+            date: Timestamp::from(UNIX_EPOCH + Duration::from_millis(start_of_today())),
+            collections,
+        };
+
+        let date = to_bson(&snapshot.date).map_err(|_| Error::DatabaseError {
+            operation: "to_bson",
+            with: "stats_snapshot",
+        })?;
+
+        // Later snapshots on the same day replace earlier ones
+        self.col::<StatsSnapshot>("stats_snapshots")
+            .replace_one(
+                doc! { "_id": date },
+                &snapshot,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "replace_one",
+                with: "stats_snapshots",
+            })
+    }
+
+    async fn fetch_stats_history(&self, days: u32) -> Result<Vec<StatsSnapshot>> {
+        let since = start_of_today().saturating_sub(days as u64 * DAY_MS);
+        let since =
+            to_bson(&Timestamp::from(UNIX_EPOCH + Duration::from_millis(since))).map_err(|_| {
+                Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "stats_snapshot",
+                }
+            })?;
+
+        self.find_with_options(
+            "stats_snapshots",
+            doc! {
+                "_id": {
+                    "$gt": since
+                }
+            },
+            FindOptions::builder().sort(doc! { "_id": 1_i32 }).build(),
+        )
+        .await
+    }
+
+    async fn update_server_stats(&self) -> Result<()> {
+        let servers = self
+            .find_with_options::<_, Document>(
+                "servers",
+                doc! {},
+                FindOptions::builder()
+                    .projection(doc! { "_id": 1_i32, "channels": 1_i32 })
+                    .build(),
+            )
+            .await?;
+
+        for server in servers {
+            let (id, channels) = match (server.get_str("_id"), server.get_array("channels")) {
+                (Ok(id), Ok(channels)) => (id.to_string(), channels.clone()),
+                _ => continue,
+            };
+
+            let stats = self.compute_server_stats(id, channels).await?;
+            self.col::<ServerStats>("server_stats")
+                .replace_one(
+                    doc! { "_id": &stats.id },
+                    &stats,
+                    ReplaceOptions::builder().upsert(true).build(),
+                )
+                .await
+                .map_err(|_| Error::DatabaseError {
+                    operation: "replace_one",
+                    with: "server_stats",
+                })?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_server_stats(&self, server: &str) -> Result<ServerStats> {
+        self.find_one_by_id("server_stats", server).await
+    }
+}
+
+/// Number of days of history kept for each server
+const HISTORY_DAYS: u64 = 30;
+
+/// Milliseconds in a day
+const DAY_MS: u64 = 86_400_000;
+
+/// Unix time in milliseconds at the start of the current day
+fn start_of_today() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    now - now % DAY_MS
+}
+
+impl MongoDb {
+    /// Count documents in a collection matching a filter
+    async fn count(&self, collection: &'static str, filter: Document) -> Result<i64> {
+        self.col::<Document>(collection)
+            .count_documents(filter, None)
+            .await
+            .map(|c| c as i64)
+            .map_err(|_| Error::DatabaseError {
+                operation: "count_documents",
+                with: collection,
+            })
+    }
+
+    /// Compute statistics for a single server
+    async fn compute_server_stats(&self, id: String, channels: bson::Array) -> Result<ServerStats> {
+        // Walk through each of the last few days, oldest first
+        let today = start_of_today();
+        let mut history = vec![];
+        for day in (0..HISTORY_DAYS).rev() {
+            let start = today - day * DAY_MS;
+            let end = start + DAY_MS;
+
+            let messages = self
+                .count(
+                    "messages",
+                    doc! {
+                        "channel": { "$in": &channels },
+                        "_id": {
+                            "$gte": Ulid::from_parts(start, 0).to_string(),
+                            "$lt": Ulid::from_parts(end, 0).to_string()
+                        }
+                    },
+                )
+                .await?;
+
+            let end = to_bson(&Timestamp::from(UNIX_EPOCH + Duration::from_millis(end))).map_err(
+                |_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "joined_at",
+                },
+            )?;
+
+            let members = self
+                .count(
+                    "server_members",
+                    doc! {
+                        "_id.server": &id,
+                        "joined_at": { "$lt": end }
+                    },
+                )
+                .await?;
+
+            history.push(ServerStatsDay {
+                date: Timestamp::from(UNIX_EPOCH + Duration::from_millis(start)),
+                messages,
+                members,
+            });
+        }
+
+        let messages = self
+            .count("messages", doc! { "channel": { "$in": &channels } })
+            .await?;
+
+        let members = self
+            .count("server_members", doc! { "_id.server": &id })
+            .await?;
+
+        // Tally reactions using this server's emoji
+        let emojis: Vec<String> = self
+            .fetch_emoji_by_parent_id(&id)
+            .await?
+            .into_iter()
+            .map(|emoji| emoji.id)
+            .collect();
+
+        let mut emoji_usage = HashMap::new();
+        if !emojis.is_empty() {
+            let usage = self
+                .col::<Document>("messages")
+                .aggregate(
+                    vec![
+                        doc! {
+                            "$match": {
+                                "channel": { "$in": &channels },
+                                "reactions": { "$exists": true }
+                            }
+                        },
+                        doc! {
+                            "$project": {
+                                "reactions": { "$objectToArray": "$reactions" }
+                            }
+                        },
+                        doc! { "$unwind": "$reactions" },
+                        doc! {
+                            "$match": {
+                                "reactions.k": { "$in": &emojis }
+                            }
+                        },
+                        doc! {
+                            "$group": {
+                                "_id": "$reactions.k",
+                                "count": { "$sum": { "$size": "$reactions.v" } }
+                            }
+                        },
+                    ],
+                    None,
+                )
+                .await
+                .map_err(|_| Error::DatabaseError {
+                    operation: "aggregate",
+                    with: "messages",
+                })?
+                .filter_map(|s| async { s.ok() })
+                .collect::<Vec<Document>>()
+                .await;
+
+            for entry in usage {
+                if let (Ok(emoji), Some(count)) = (
+                    entry.get_str("_id"),
+                    entry.get("count").and_then(|count| {
+                        count.as_i64().or_else(|| count.as_i32().map(|c| c as i64))
+                    }),
+                ) {
+                    emoji_usage.insert(emoji.to_string(), count);
+                }
+            }
+        }
+
+        Ok(ServerStats {
+            id,
+            generated_at: Timestamp::now_utc(),
+            messages,
+            members,
+            history,
+            emoji_usage,
         })
     }
 }
@@ -110,8 +110,8 @@ impl AbstractChannel for MongoDb {
         self.delete_associated_channel_objects(Bson::String(id.to_string()))
             .await?;
 
-        // Delete messages.
-        self.delete_bulk_messages(doc! {
+        // Hold messages for recovery.
+        self.trash_bulk_messages(doc! {
             "channel": &id
         })
         .await?;
@@ -312,6 +312,11 @@ impl IntoDocumentPath for FieldsChannel {
             FieldsChannel::DefaultPermissions => "default_permissions",
             FieldsChannel::Description => "description",
             FieldsChannel::Icon => "icon",
+            FieldsChannel::UserLimit => "user_limit",
+            FieldsChannel::PostingRole => "posting_role",
+            FieldsChannel::FileTypes => "file_types",
+            FieldsChannel::Slowmode => "slowmode",
+            FieldsChannel::AutoThreadDepth => "auto_thread_depth",
         })
     }
 }
@@ -1,5 +1,7 @@
+use mongodb::bson::Document;
+
 use crate::models::Invite;
-use crate::{AbstractChannelInvite, Result};
+use crate::{AbstractChannelInvite, Error, Result};
 
 use super::super::MongoDb;
 
@@ -19,6 +21,45 @@ impl AbstractChannelInvite for MongoDb {
         self.delete_one_by_id(COL, code).await.map(|_| ())
     }
 
+    async fn use_guest_invite(&self, code: &str) -> Result<bool> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": code,
+                    "guest": {
+                        "$exists": true
+                    },
+                    "$or": [
+                        {
+                            "guest.max_uses": {
+                                "$exists": false
+                            }
+                        },
+                        {
+                            "$expr": {
+                                "$lt": [
+                                    { "$ifNull": ["$guest.uses", 0] },
+                                    "$guest.max_uses"
+                                ]
+                            }
+                        }
+                    ]
+                },
+                doc! {
+                    "$inc": {
+                        "guest.uses": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|result| result.modified_count == 1)
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_one",
+                with: "channel_invites",
+            })
+    }
+
     async fn fetch_invites_for_server(&self, server: &str) -> Result<Vec<Invite>> {
         self.find(
             COL,
@@ -1,65 +1,65 @@
 use bson::{to_bson, Document};
-use futures::try_join;
+use futures::{try_join, StreamExt};
+use indexmap::{IndexMap, IndexSet};
+use iso8601_timestamp::Timestamp;
 use mongodb::options::FindOptions;
 
 use crate::models::message::{
-    AppendMessage, Message, MessageQuery, MessageSort, MessageTimePeriod, PartialMessage,
+    AppendMessage, ChannelMessageSync, DeletedMessage, Message, MessageQuery, MessageRevision,
+    MessageSort, MessageTimePeriod, PartialMessage,
 };
-use crate::r#impl::mongo::DocumentId;
+use crate::tasks::reactions::MAX_REACTIONS;
 use crate::{AbstractMessage, Error, Result};
 
 use super::super::MongoDb;
 
 static COL: &str = "messages";
+static DELETED_COL: &str = "deleted_messages";
+static REVISIONS_COL: &str = "message_revisions";
 
 impl MongoDb {
-    pub async fn delete_bulk_messages(&self, projection: Document) -> Result<()> {
-        let mut for_attachments = projection.clone();
-        for_attachments.insert(
-            "attachments",
-            doc! {
-                "$exists": 1_i32
-            },
-        );
-
-        // Check if there are any attachments we need to delete.
-        let message_ids_with_attachments = self
-            .find_with_options::<_, DocumentId>(
-                COL,
-                for_attachments,
-                FindOptions::builder()
-                    .projection(doc! { "_id": 1_i32 })
-                    .build(),
-            )
-            .await?
-            .into_iter()
-            .map(|x| x.id)
-            .collect::<Vec<String>>();
+    /// Move all messages matching the projection out of their channels and hold them for recovery
+    ///
+    /// Messages, their attachments and their previous versions are purged once
+    /// the recovery window passes, unless they are under a legal hold.
+    pub async fn trash_bulk_messages(&self, projection: Document) -> Result<()> {
+        let deleted_at = to_bson(&Timestamp::now_utc()).map_err(|_| Error::DatabaseError {
+            operation: "to_bson",
+            with: "timestamp",
+        })?;
 
-        // If we found any, mark them as deleted.
-        if !message_ids_with_attachments.is_empty() {
-            self.col::<Document>("attachments")
-                .update_many(
+        // Copy the messages into the recovery collection.
+        self.col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": projection.clone()
+                    },
                     doc! {
-                        "message_id": {
-                            "$in": message_ids_with_attachments
+                        "$project": {
+                            "_id": 1,
+                            "message": "$$ROOT",
+                            "deleted_at": {
+                                "$literal": deleted_at
+                            }
                         }
                     },
                     doc! {
-                        "$set": {
-                            "deleted": true
+                        "$merge": {
+                            "into": DELETED_COL,
+                            "whenMatched": "keepExisting"
                         }
                     },
-                    None,
-                )
-                .await
-                .map_err(|_| Error::DatabaseError {
-                    operation: "update_many",
-                    with: "attachments",
-                })?;
-        }
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "aggregate",
+                with: "messages",
+            })?;
 
-        // And then delete said messages.
+        // And then remove them from their channels.
         self.col::<Document>(COL)
             .delete_many(projection, None)
             .await
@@ -128,11 +128,24 @@ impl AbstractMessage for MongoDb {
     }
 
     async fn delete_message(&self, id: &str) -> Result<()> {
-        self.delete_one_by_id(COL, id).await.map(|_| ())
+        self.delete_one_by_id(COL, id).await?;
+        self.col::<Document>(REVISIONS_COL)
+            .delete_many(
+                doc! {
+                    "message_id": id
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: REVISIONS_COL,
+            })
     }
 
     async fn delete_messages(&self, channel: &str, ids: Vec<String>) -> Result<()> {
-        self.delete_bulk_messages(doc! {
+        self.trash_bulk_messages(doc! {
             "channel": channel,
             "_id": {
                 "$in": ids
@@ -141,6 +154,147 @@ impl AbstractMessage for MongoDb {
         .await
     }
 
+    async fn trash_message(&self, message: &DeletedMessage) -> Result<()> {
+        self.insert_one(DELETED_COL, message).await?;
+        self.delete_one_by_id(COL, &message.id).await.map(|_| ())
+    }
+
+    async fn fetch_deleted_message(&self, id: &str) -> Result<DeletedMessage> {
+        self.find_one_by_id(DELETED_COL, id).await
+    }
+
+    async fn fetch_deleted_messages(&self, channel: &str) -> Result<Vec<DeletedMessage>> {
+        self.find_with_options(
+            DELETED_COL,
+            doc! {
+                "message.channel": channel
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "deleted_at": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn restore_message(&self, id: &str) -> Result<Message> {
+        let deleted: DeletedMessage = self.find_one_by_id(DELETED_COL, id).await?;
+        self.insert_one(COL, &deleted.message).await?;
+        self.delete_one_by_id(DELETED_COL, id).await?;
+        Ok(deleted.message)
+    }
+
+    async fn purge_deleted_messages(
+        &self,
+        before: Timestamp,
+        exclude: &[String],
+    ) -> Result<Vec<DeletedMessage>> {
+        let projection = doc! {
+            "deleted_at": {
+                "$lt": to_bson(&before).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "timestamp",
+                })?
+            },
+            "message.channel": {
+                "$nin": exclude
+            },
+            "message.author": {
+                "$nin": exclude
+            }
+        };
+
+        let messages: Vec<DeletedMessage> = self.find(DELETED_COL, projection).await?;
+        if messages.is_empty() {
+            return Ok(messages);
+        }
+
+        let ids: Vec<&String> = messages.iter().map(|message| &message.id).collect();
+        self.col::<Document>("attachments")
+            .update_many(
+                doc! {
+                    "message_id": {
+                        "$in": &ids
+                    }
+                },
+                doc! {
+                    "$set": {
+                        "deleted": true
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_many",
+                with: "attachments",
+            })?;
+
+        self.col::<Document>(DELETED_COL)
+            .delete_many(
+                doc! {
+                    "_id": {
+                        "$in": &ids
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: DELETED_COL,
+            })?;
+
+        self.col::<Document>(REVISIONS_COL)
+            .delete_many(
+                doc! {
+                    "message_id": {
+                        "$in": &ids
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: REVISIONS_COL,
+            })?;
+
+        Ok(messages)
+    }
+
+    async fn insert_message_revision(&self, revision: &MessageRevision) -> Result<()> {
+        self.insert_one(REVISIONS_COL, revision).await.map(|_| ())
+    }
+
+    async fn fetch_message_revisions(&self, message: &str) -> Result<Vec<MessageRevision>> {
+        self.find_with_options(
+            REVISIONS_COL,
+            doc! {
+                "message_id": message
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn fetch_messages_by_id(&self, ids: &[String]) -> Result<Vec<Message>> {
+        self.find(
+            COL,
+            doc! {
+                "_id": {
+                    "$in": ids
+                }
+            },
+        )
+        .await
+    }
+
     async fn fetch_messages(&self, query: MessageQuery) -> Result<Vec<Message>> {
         let mut filter = doc! {};
 
@@ -149,10 +303,70 @@ impl AbstractMessage for MongoDb {
             filter.insert("channel", channel);
         }
 
+        if let Some(channels) = query.filter.channels {
+            filter.insert(
+                "channel",
+                doc! {
+                    "$in": channels
+                },
+            );
+        }
+
+        if let Some(thread) = query.filter.thread {
+            filter.insert("thread", thread);
+        }
+
         if let Some(author) = query.filter.author {
             filter.insert("author", author);
         }
 
+        if let Some(attachment) = query.filter.attachment {
+            let mut element = doc! {};
+
+            if let Some(filename) = attachment.filename {
+                element.insert(
+                    "filename",
+                    doc! {
+                        "$regex": format!("^{}", regex::escape(&filename)),
+                        "$options": "i"
+                    },
+                );
+            }
+
+            if let Some(content_type) = attachment.content_type {
+                if content_type.contains('/') {
+                    element.insert("content_type", content_type);
+                } else {
+                    element.insert(
+                        "content_type",
+                        doc! {
+                            "$regex": format!("^{}/", regex::escape(&content_type))
+                        },
+                    );
+                }
+            }
+
+            let mut size = doc! {};
+            if let Some(min_size) = attachment.min_size {
+                size.insert("$gte", min_size as i64);
+            }
+
+            if let Some(max_size) = attachment.max_size {
+                size.insert("$lte", max_size as i64);
+            }
+
+            if !size.is_empty() {
+                element.insert("size", size);
+            }
+
+            filter.insert(
+                "attachments",
+                doc! {
+                    "$elemMatch": element
+                },
+            );
+        }
+
         let is_search_query = if let Some(query) = query.filter.query {
             filter.insert(
                 "$text",
@@ -168,6 +382,7 @@ impl AbstractMessage for MongoDb {
 
         // 2. Find query limit
         let limit = query.limit.unwrap_or(50);
+        let selection_criteria = MongoDb::replica_selection(query.consistent);
 
         // 3. Apply message time period
         match query.time_period {
@@ -200,6 +415,7 @@ impl AbstractMessage for MongoDb {
                             .sort(doc! {
                                 "_id": 1_i32
                             })
+                            .selection_criteria(selection_criteria.clone())
                             .build(),
                     ),
                     self.find_with_options::<_, Message>(
@@ -210,6 +426,7 @@ impl AbstractMessage for MongoDb {
                             .sort(doc! {
                                 "_id": -1_i32
                             })
+                            .selection_criteria(selection_criteria)
                             .build(),
                     )
                 )?;
@@ -268,6 +485,7 @@ impl AbstractMessage for MongoDb {
                                 "_id": 1_i32
                             },
                         })
+                        .selection_criteria(selection_criteria)
                         .build(),
                 )
                 .await
@@ -275,6 +493,121 @@ impl AbstractMessage for MongoDb {
         }
     }
 
+    async fn fetch_pinned_messages(&self, channel: &str) -> Result<Vec<Message>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "channel": channel,
+                "pinned": true
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn fetch_message_authors(&self, channels: &[String], after: &str) -> Result<Vec<String>> {
+        self.col::<Document>(COL)
+            .distinct(
+                "author",
+                doc! {
+                    "channel": {
+                        "$in": channels
+                    },
+                    "_id": {
+                        "$gt": after
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|authors| {
+                authors
+                    .into_iter()
+                    .filter_map(|author| author.as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .map_err(|_| Error::DatabaseError {
+                operation: "distinct",
+                with: "messages",
+            })
+    }
+
+    /// Count messages in each of the given channels after a given message id
+    async fn fetch_message_sync(
+        &self,
+        channels: &[String],
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<ChannelMessageSync>> {
+        Ok(self
+            .col::<Document>(COL)
+            .aggregate(
+                vec![
+                    doc! {
+                        "$match": {
+                            "channel": {
+                                "$in": channels
+                            },
+                            "_id": {
+                                "$gt": after
+                            }
+                        }
+                    },
+                    doc! {
+                        "$sort": {
+                            "_id": -1
+                        }
+                    },
+                    doc! {
+                        "$group": {
+                            "_id": "$channel",
+                            "count": {
+                                "$sum": 1
+                            },
+                            "ids": {
+                                "$push": "$_id"
+                            }
+                        }
+                    },
+                    doc! {
+                        "$project": {
+                            "count": 1,
+                            "latest": {
+                                "$slice": ["$ids", limit]
+                            }
+                        }
+                    },
+                ],
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "aggregate",
+                with: "messages",
+            })?
+            .filter_map(|s| async { s.ok() })
+            .collect::<Vec<Document>>()
+            .await
+            .into_iter()
+            .filter_map(|doc| {
+                Some(ChannelMessageSync {
+                    channel: doc.get_str("_id").ok()?.to_string(),
+                    count: doc.get_i32("count").ok()?.into(),
+                    latest: doc
+                        .get_array("latest")
+                        .ok()?
+                        .iter()
+                        .filter_map(|id| id.as_str().map(ToString::to_string))
+                        .collect(),
+                })
+            })
+            .collect())
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         self.col::<Document>(COL)
@@ -340,4 +673,188 @@ impl AbstractMessage for MongoDb {
                 with: "message",
             })
     }
+
+    /// Apply a batch of reaction changes to a message
+    async fn migrate_emoji_references(&self, from: &str, to: &str) -> Result<()> {
+        let col = self.col::<Document>(COL);
+        let map_err = |_| Error::DatabaseError {
+            operation: "update_many",
+            with: "messages",
+        };
+
+        // Move reactions over to the new emoji
+        let from_key = format!("reactions.{from}");
+        col.update_many(
+            doc! {
+                &from_key: {
+                    "$exists": true
+                }
+            },
+            doc! {
+                "$rename": {
+                    &from_key: format!("reactions.{to}")
+                }
+            },
+            None,
+        )
+        .await
+        .map_err(map_err)?;
+
+        // Swap the emoji in reaction whitelists
+        col.update_many(
+            doc! {
+                "interactions.reactions": from
+            },
+            doc! {
+                "$set": {
+                    "interactions.reactions.$": to
+                }
+            },
+            None,
+        )
+        .await
+        .map_err(map_err)?;
+
+        // Rewrite the emoji in message content
+        let from_tag = format!(":{from}:");
+        col.update_many(
+            doc! {
+                "content": {
+                    "$regex": &from_tag
+                }
+            },
+            vec![doc! {
+                "$set": {
+                    "content": {
+                        "$replaceAll": {
+                            "input": "$content",
+                            "find": &from_tag,
+                            "replacement": format!(":{to}:")
+                        }
+                    }
+                }
+            }],
+            None,
+        )
+        .await
+        .map_err(map_err)?;
+
+        Ok(())
+    }
+
+    async fn apply_reactions(
+        &self,
+        id: &str,
+        cleared: &[String],
+        added: &IndexMap<String, IndexSet<String>>,
+        removed: &IndexMap<String, IndexSet<String>>,
+    ) -> Result<Vec<String>> {
+        let col = self.col::<Document>(COL);
+        let map_err = |_| Error::DatabaseError {
+            operation: "update_one",
+            with: "message",
+        };
+
+        // 1. Clear emojis
+        if !cleared.is_empty() {
+            let mut unset = doc! {};
+            for emoji in cleared {
+                unset.insert(format!("reactions.{emoji}"), 1);
+            }
+
+            col.update_one(doc! { "_id": id }, doc! { "$unset": unset }, None)
+                .await
+                .map_err(map_err)?;
+        }
+
+        // 2. Add new reactions, only adding emojis while under the limit
+        let mut rejected = vec![];
+        for (emoji, users) in added {
+            let path = format!("reactions.{emoji}");
+            let result = col
+                .update_one(
+                    doc! {
+                        "_id": id,
+                        "$or": [
+                            {
+                                &path: {
+                                    "$exists": true
+                                }
+                            },
+                            {
+                                "$expr": {
+                                    "$lt": [
+                                        {
+                                            "$size": {
+                                                "$objectToArray": {
+                                                    "$ifNull": ["$reactions", {}]
+                                                }
+                                            }
+                                        },
+                                        MAX_REACTIONS as i32
+                                    ]
+                                }
+                            }
+                        ]
+                    },
+                    doc! {
+                        "$addToSet": {
+                            &path: {
+                                "$each": users.iter().cloned().collect::<Vec<String>>()
+                            }
+                        }
+                    },
+                    None,
+                )
+                .await
+                .map_err(map_err)?;
+
+            if result.matched_count == 0 {
+                rejected.push(emoji.clone());
+            }
+        }
+
+        // 3. Remove reactions and drop any emojis left empty
+        if !removed.is_empty() {
+            let mut pull = doc! {};
+            for (emoji, users) in removed {
+                pull.insert(
+                    format!("reactions.{emoji}"),
+                    doc! {
+                        "$in": users.iter().cloned().collect::<Vec<String>>()
+                    },
+                );
+            }
+
+            col.update_one(doc! { "_id": id }, doc! { "$pull": pull }, None)
+                .await
+                .map_err(map_err)?;
+
+            col.update_one(
+                doc! {
+                    "_id": id,
+                    "reactions": {
+                        "$type": "object"
+                    }
+                },
+                vec![doc! {
+                    "$set": {
+                        "reactions": {
+                            "$arrayToObject": {
+                                "$filter": {
+                                    "input": { "$objectToArray": "$reactions" },
+                                    "cond": { "$gt": [{ "$size": "$$this.v" }, 0] }
+                                }
+                            }
+                        }
+                    }
+                }],
+                None,
+            )
+            .await
+            .map_err(map_err)?;
+        }
+
+        Ok(rejected)
+    }
 }
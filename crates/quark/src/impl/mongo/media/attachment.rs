@@ -30,6 +30,31 @@ impl MongoDb {
 
 #[async_trait]
 impl AbstractAttachment for MongoDb {
+    async fn fetch_unused_attachment(
+        &self,
+        attachment_id: &str,
+        tag: &str,
+        parent_type: &str,
+    ) -> Result<File> {
+        let key = format!("{parent_type}_id");
+        match self
+            .find_one::<File>(
+                COL,
+                doc! {
+                    "_id": attachment_id,
+                    "tag": tag,
+                    &key: {
+                        "$exists": false
+                    }
+                },
+            )
+            .await
+        {
+            Err(Error::NotFound) => Err(Error::UnknownAttachment),
+            result => result,
+        }
+    }
+
     async fn find_and_use_attachment(
         &self,
         attachment_id: &str,
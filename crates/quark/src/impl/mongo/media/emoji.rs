@@ -1,11 +1,14 @@
-use bson::Document;
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use mongodb::options::UpdateOptions;
 
-use crate::models::Emoji;
+use crate::models::{emoji::EmojiUsage, Emoji};
 use crate::{AbstractEmoji, Error, Result};
 
 use super::super::MongoDb;
 
 static COL: &str = "emojis";
+static USAGE: &str = "emoji_usage";
 
 #[async_trait]
 impl AbstractEmoji for MongoDb {
@@ -43,6 +46,67 @@ impl AbstractEmoji for MongoDb {
         self.insert_one(COL, emoji).await.map(|_| ())
     }
 
+    /// Fetch usage counters for the given emoji
+    async fn fetch_emoji_usage(&self, ids: &[String]) -> Result<Vec<EmojiUsage>> {
+        self.find(
+            USAGE,
+            doc! {
+                "_id": {
+                    "$in": ids
+                }
+            },
+        )
+        .await
+    }
+
+    /// Record that an emoji has been used a number of times
+    async fn increment_emoji_usage(&self, id: &str, count: i64) -> Result<()> {
+        self.col::<Document>(USAGE)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$inc": {
+                        "uses": count
+                    },
+                    "$set": {
+                        "last_used_at": to_bson(&Timestamp::now_utc()).unwrap()
+                    }
+                },
+                UpdateOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_one",
+                with: "emoji_usage",
+            })
+    }
+
+    /// Update the name and aliases of an emoji
+    async fn update_emoji_names(&self, id: &str, name: &str, aliases: &[String]) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$set": {
+                        "name": name,
+                        "aliases": aliases
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_one",
+                with: "emojis",
+            })
+    }
+
     /// Delete an emoji by its id
     async fn detach_emoji(&self, emoji: &Emoji) -> Result<()> {
         self.col::<Document>(COL)
@@ -66,4 +130,29 @@ impl AbstractEmoji for MongoDb {
                 with: "emojis",
             })
     }
+
+    /// Detach an emoji and point it at the emoji which replaced it
+    async fn replace_emoji(&self, emoji: &Emoji, replacement_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": &emoji.id
+                },
+                doc! {
+                    "$set": {
+                        "parent": {
+                            "type": "Detached"
+                        },
+                        "replaced_by": replacement_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_one",
+                with: "emojis",
+            })
+    }
 }
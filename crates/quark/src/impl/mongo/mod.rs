@@ -1,15 +1,22 @@
 use std::ops::Deref;
+use std::time::Duration;
 
 use bson::{to_document, Document};
 use futures::StreamExt;
 use mongodb::{
-    options::{FindOneOptions, FindOptions},
+    options::{
+        FindOneOptions, FindOptions, ReadPreference, ReadPreferenceOptions, SelectionCriteria,
+    },
     results::{DeleteResult, InsertOneResult, UpdateResult},
 };
 use rocket::serde::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use crate::{util::manipulation::prefix_keys, AbstractDatabase, Error, Result};
+use crate::{
+    util::manipulation::prefix_keys,
+    variables::delta::{READ_REPLICAS, READ_REPLICA_MAX_STALENESS},
+    AbstractDatabase, Error, Result,
+};
 
 pub mod admin {
     pub mod stats;
@@ -40,6 +47,7 @@ pub mod users {
 }
 
 pub mod safety {
+    pub mod legal_hold;
     pub mod report;
     pub mod snapshot;
 }
@@ -66,6 +74,24 @@ impl MongoDb {
         self.db().collection(collection)
     }
 
+    /// Selection criteria for reads which may be served by a replica
+    ///
+    /// Returns nothing, reading from the primary, if replicas are disabled
+    /// or the read must be consistent with recent writes.
+    pub fn replica_selection(consistent: bool) -> Option<SelectionCriteria> {
+        if consistent || !*READ_REPLICAS {
+            return None;
+        }
+
+        Some(SelectionCriteria::ReadPreference(
+            ReadPreference::SecondaryPreferred {
+                options: ReadPreferenceOptions::builder()
+                    .max_staleness(Duration::from_secs(*READ_REPLICA_MAX_STALENESS))
+                    .build(),
+            },
+        ))
+    }
+
     async fn insert_one<T: Serialize>(
         &self,
         collection: &'static str,
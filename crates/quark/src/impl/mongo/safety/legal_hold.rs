@@ -0,0 +1,69 @@
+use mongodb::options::{FindOptions, ReplaceOptions};
+
+use crate::models::LegalHold;
+use crate::{AbstractLegalHold, Error, Result};
+
+use super::super::{DocumentId, MongoDb};
+
+static COL: &str = "legal_holds";
+
+#[async_trait]
+impl AbstractLegalHold for MongoDb {
+    async fn insert_legal_hold(&self, hold: &LegalHold) -> Result<()> {
+        self.col::<LegalHold>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &hold.id
+                },
+                hold,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "replace_one",
+                with: "legal_holds",
+            })
+    }
+
+    async fn fetch_legal_hold(&self, id: &str) -> Result<LegalHold> {
+        self.find_one_by_id(COL, id).await
+    }
+
+    async fn fetch_legal_holds(&self) -> Result<Vec<LegalHold>> {
+        self.find_with_options(
+            COL,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {
+                    "placed_at": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn fetch_held_ids(&self) -> Result<Vec<String>> {
+        Ok(self
+            .find_with_options::<_, DocumentId>(
+                COL,
+                doc! {},
+                FindOptions::builder()
+                    .projection(doc! { "_id": 1_i32 })
+                    .build(),
+            )
+            .await?
+            .into_iter()
+            .map(|doc| doc.id)
+            .collect())
+    }
+
+    async fn delete_legal_hold(&self, id: &str) -> Result<()> {
+        let result = self.delete_one_by_id(COL, id).await?;
+        if result.deleted_count == 0 {
+            Err(Error::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+}
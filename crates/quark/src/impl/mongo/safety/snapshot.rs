@@ -1,9 +1,13 @@
+use bson::Document;
+use mongodb::options::{FindOptions, ReplaceOptions};
+
 use crate::models::Snapshot;
-use crate::{AbstractSnapshot, Result};
+use crate::{AbstractSnapshot, Error, Result};
 
-use super::super::MongoDb;
+use super::super::{DocumentId, MongoDb};
 
 static COL: &str = "safety_snapshots";
+static MONITORS: &str = "safety_monitors";
 
 #[async_trait]
 impl AbstractSnapshot for MongoDb {
@@ -11,6 +15,10 @@ impl AbstractSnapshot for MongoDb {
         self.insert_one(COL, snapshot).await.map(|_| ())
     }
 
+    async fn fetch_snapshot(&self, id: &str) -> Result<Snapshot> {
+        self.find_one_by_id(COL, id).await
+    }
+
     async fn fetch_snapshots(&self, report_id: &str) -> Result<Vec<Snapshot>> {
         self.find(
             COL,
@@ -20,4 +28,72 @@ impl AbstractSnapshot for MongoDb {
         )
         .await
     }
+
+    async fn fetch_snapshots_by_object(&self, object_id: &str) -> Result<Vec<Snapshot>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "content._id": object_id
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": 1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn delete_unreported_snapshots_before(&self, id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .delete_many(
+                doc! {
+                    "_id": {
+                        "$lt": id
+                    },
+                    "report_id": {
+                        "$exists": false
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: "snapshots",
+            })
+    }
+
+    async fn set_server_monitored(&self, server_id: &str, monitored: bool) -> Result<()> {
+        if monitored {
+            self.col::<Document>(MONITORS)
+                .replace_one(
+                    doc! {
+                        "_id": server_id
+                    },
+                    doc! {
+                        "_id": server_id
+                    },
+                    ReplaceOptions::builder().upsert(true).build(),
+                )
+                .await
+                .map(|_| ())
+                .map_err(|_| Error::DatabaseError {
+                    operation: "replace_one",
+                    with: "safety_monitors",
+                })
+        } else {
+            self.delete_one_by_id(MONITORS, server_id).await.map(|_| ())
+        }
+    }
+
+    async fn fetch_monitored_servers(&self) -> Result<Vec<String>> {
+        Ok(self
+            .find::<DocumentId>(MONITORS, doc! {})
+            .await?
+            .into_iter()
+            .map(|doc| doc.id)
+            .collect())
+    }
 }
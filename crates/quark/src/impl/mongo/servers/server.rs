@@ -10,8 +10,8 @@ static COL: &str = "servers";
 
 impl MongoDb {
     pub async fn delete_associated_server_objects(&self, server: &Server) -> Result<()> {
-        // Check if there are any attachments we need to delete.
-        self.delete_bulk_messages(doc! {
+        // Hold messages for recovery.
+        self.trash_bulk_messages(doc! {
             "channel": {
                 "$in": &server.channels
             }
@@ -200,6 +200,25 @@ impl AbstractServer for MongoDb {
                 with: "channels",
             })?;
 
+        self.col::<Document>("channels")
+            .update_many(
+                doc! {
+                    "server": server_id,
+                    "posting_role": role_id
+                },
+                doc! {
+                    "$unset": {
+                        "posting_role": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_many",
+                with: "channels",
+            })?;
+
         self.col::<Document>("servers")
             .update_one(
                 doc! {
@@ -228,6 +247,10 @@ impl IntoDocumentPath for FieldsServer {
             FieldsServer::Categories => "categories",
             FieldsServer::Description => "description",
             FieldsServer::Icon => "icon",
+            FieldsServer::Onboarding => "onboarding",
+            FieldsServer::Highlights => "highlights",
+            FieldsServer::WarnThresholds => "warn_thresholds",
+            FieldsServer::StorageRegion => "storage_region",
             FieldsServer::SystemMessages => "system_messages",
         })
     }
@@ -237,6 +260,7 @@ impl IntoDocumentPath for FieldsRole {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsRole::Colour => "colour",
+            FieldsRole::MenuPosition => "menu_position",
         })
     }
 }
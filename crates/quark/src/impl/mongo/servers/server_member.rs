@@ -1,6 +1,9 @@
-use bson::Document;
+use bson::{to_bson, Document};
+use mongodb::options::FindOptions;
 
-use crate::models::server_member::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::models::server_member::{
+    FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember,
+};
 use crate::r#impl::mongo::IntoDocumentPath;
 use crate::{AbstractServerMember, Error, Result};
 
@@ -67,6 +70,37 @@ impl AbstractServerMember for MongoDb {
         .await
     }
 
+    async fn fetch_notified_members(&self, server: &str, channel: &str) -> Result<Vec<Member>> {
+        self.find(
+            COL,
+            doc! {
+                "_id.server": server,
+                "$or": [
+                    { "notifications": "All" },
+                    { "followed_channels": channel }
+                ]
+            },
+        )
+        .await
+    }
+
+    async fn fetch_members_with_roles(
+        &self,
+        server: &str,
+        roles: &[String],
+    ) -> Result<Vec<Member>> {
+        self.find(
+            COL,
+            doc! {
+                "_id.server": server,
+                "roles": {
+                    "$in": roles
+                }
+            },
+        )
+        .await
+    }
+
     async fn fetch_all_memberships<'a>(&self, user: &str) -> Result<Vec<Member>> {
         self.find(
             COL,
@@ -90,6 +124,82 @@ impl AbstractServerMember for MongoDb {
         .await
     }
 
+    async fn fetch_members_by_query(
+        &self,
+        server: &str,
+        query: MemberQuery,
+    ) -> Result<Vec<Member>> {
+        let mut filter = doc! {
+            "_id.server": server
+        };
+
+        // 1. Filter by user
+        let mut user = doc! {};
+        if let Some(users) = query.users {
+            user.insert("$in", users);
+        }
+
+        if !query.exclude_users.is_empty() {
+            user.insert("$nin", query.exclude_users);
+        }
+
+        if let Some(after) = query.after {
+            user.insert("$gt", after);
+        }
+
+        if !user.is_empty() {
+            filter.insert("_id.user", user);
+        }
+
+        // 2. Filter by roles
+        if !query.roles.is_empty() {
+            filter.insert(
+                "roles",
+                doc! {
+                    "$all": query.roles
+                },
+            );
+        }
+
+        // 3. Filter by join date
+        let mut joined_at = doc! {};
+        if let Some(joined_after) = query.joined_after {
+            joined_at.insert(
+                "$gte",
+                to_bson(&joined_after).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "joined_at",
+                })?,
+            );
+        }
+
+        if let Some(joined_before) = query.joined_before {
+            joined_at.insert(
+                "$lte",
+                to_bson(&joined_before).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "joined_at",
+                })?,
+            );
+        }
+
+        if !joined_at.is_empty() {
+            filter.insert("joined_at", joined_at);
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    "_id.user": 1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
     async fn fetch_member_count(&self, server: &str) -> Result<usize> {
         self.col::<Document>(COL)
             .count_documents(
@@ -1,9 +1,10 @@
-use bson::Document;
+use bson::{to_bson, Document};
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
 use mongodb::options::{Collation, CollationStrength, FindOneOptions, FindOptions};
 use once_cell::sync::Lazy;
 
-use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, User};
+use crate::models::user::{FieldsUser, Flags, PartialUser, RelationshipStatus, SessionScope, User};
 use crate::r#impl::mongo::IntoDocumentPath;
 use crate::{AbstractUser, Error, Result};
 
@@ -53,7 +54,36 @@ impl AbstractUser for MongoDb {
             })?
             .ok_or(Error::InvalidSession)?;
 
-        self.fetch_user(session.get_str("user_id").unwrap()).await
+        let mut user = self.fetch_user(session.get_str("user_id").unwrap()).await?;
+        user.scopes = self
+            .fetch_session_scopes(session.get_str("_id").unwrap())
+            .await?;
+
+        Ok(user)
+    }
+
+    async fn fetch_session_scopes(&self, session_id: &str) -> Result<Vec<SessionScope>> {
+        let scopes = self
+            .col::<Document>("session_scopes")
+            .find_one(
+                doc! {
+                    "_id": session_id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "find_one",
+                with: "session_scopes",
+            })?;
+
+        match scopes.and_then(|scopes| scopes.get("scopes").cloned()) {
+            Some(scopes) => bson::from_bson(scopes).map_err(|_| Error::DatabaseError {
+                operation: "from_bson",
+                with: "session_scopes",
+            }),
+            None => Ok(vec![]),
+        }
     }
 
     async fn insert_user(&self, user: &User) -> Result<()> {
@@ -106,6 +136,65 @@ impl AbstractUser for MongoDb {
         Ok(users)
     }
 
+    async fn fetch_expired_guests(&self) -> Result<Vec<User>> {
+        self.find(
+            COL,
+            doc! {
+                "guest.expires": {
+                    "$lt": to_bson(&Timestamp::now_utc()).map_err(|_| Error::DatabaseError {
+                        operation: "to_bson",
+                        with: "timestamp",
+                    })?
+                },
+                "flags": {
+                    "$not": {
+                        "$bitsAllSet": Flags::Deleted as i32
+                    }
+                }
+            },
+        )
+        .await
+    }
+
+    async fn fetch_user_ids_after(&self, after: Option<&str>, limit: i64) -> Result<Vec<String>> {
+        let mut filter = doc! {
+            "bot": {
+                "$exists": false
+            },
+            "flags": {
+                "$not": {
+                    "$bitsAllSet": Flags::Deleted as i32
+                }
+            }
+        };
+
+        if let Some(after) = after {
+            filter.insert("_id", doc! { "$gt": after });
+        }
+
+        Ok(self
+            .col::<Document>(COL)
+            .find(
+                filter,
+                FindOptions::builder()
+                    .projection(doc! { "_id": 1 })
+                    .sort(doc! { "_id": 1 })
+                    .limit(limit)
+                    .build(),
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "find",
+                with: "users",
+            })?
+            .filter_map(|s| async { s.ok() })
+            .collect::<Vec<Document>>()
+            .await
+            .into_iter()
+            .filter_map(|x| x.get_str("_id").ok().map(|x| x.to_string()))
+            .collect::<Vec<String>>())
+    }
+
     async fn is_username_taken(&self, username: &str) -> Result<bool> {
         // ! FIXME: move this up to generic
         match self.fetch_user_by_username(username).await {
@@ -311,8 +400,14 @@ impl IntoDocumentPath for FieldsUser {
             FieldsUser::Avatar => "avatar",
             FieldsUser::ProfileBackground => "profile.background",
             FieldsUser::ProfileContent => "profile.content",
+            FieldsUser::ProfileWebsite => "profile.website",
+            FieldsUser::ProfileDonation => "profile.donation",
             FieldsUser::StatusPresence => "status.presence",
             FieldsUser::StatusText => "status.text",
+            FieldsUser::StatusActivity => "status.activity",
+            FieldsUser::Locale => "locale",
+            FieldsUser::DirectMessageMediaFilter => "dm_media_filter",
+            FieldsUser::FederatedMessagePolicy => "federated_dms",
         })
     }
 }
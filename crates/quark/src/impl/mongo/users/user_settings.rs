@@ -1,12 +1,17 @@
 use bson::{to_bson, Document};
-use mongodb::options::{FindOneOptions, UpdateOptions};
+use mongodb::options::{FindOneOptions, ReplaceOptions, UpdateOptions};
 
-use crate::models::UserSettings;
+use crate::models::{
+    user_settings::{DmLayout, ServerLayout},
+    UserSettings,
+};
 use crate::{AbstractUserSettings, Error, Result};
 
 use super::super::MongoDb;
 
 static COL: &str = "user_settings";
+static LAYOUTS: &str = "server_layouts";
+static DM_LAYOUTS: &str = "dm_layouts";
 
 #[async_trait]
 impl AbstractUserSettings for MongoDb {
@@ -56,7 +61,63 @@ impl AbstractUserSettings for MongoDb {
             })
     }
 
+    async fn fetch_server_layout(&self, id: &str) -> Result<ServerLayout> {
+        match self.find_one_by_id(LAYOUTS, id).await {
+            Err(Error::NotFound) => Ok(ServerLayout {
+                id: id.to_string(),
+                ..Default::default()
+            }),
+            result => result,
+        }
+    }
+
+    async fn set_server_layout(&self, layout: &ServerLayout) -> Result<()> {
+        self.col::<ServerLayout>(LAYOUTS)
+            .replace_one(
+                doc! {
+                    "_id": &layout.id
+                },
+                layout,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "replace_one",
+                with: "server_layouts",
+            })
+    }
+
+    async fn fetch_dm_layout(&self, id: &str) -> Result<DmLayout> {
+        match self.find_one_by_id(DM_LAYOUTS, id).await {
+            Err(Error::NotFound) => Ok(DmLayout {
+                id: id.to_string(),
+                ..Default::default()
+            }),
+            result => result,
+        }
+    }
+
+    async fn set_dm_layout(&self, layout: &DmLayout) -> Result<()> {
+        self.col::<DmLayout>(DM_LAYOUTS)
+            .replace_one(
+                doc! {
+                    "_id": &layout.id
+                },
+                layout,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "replace_one",
+                with: "dm_layouts",
+            })
+    }
+
     async fn delete_user_settings(&self, id: &str) -> Result<()> {
+        self.delete_one_by_id(LAYOUTS, id).await?;
+        self.delete_one_by_id(DM_LAYOUTS, id).await?;
         self.delete_one_by_id(COL, id).await.map(|_| ())
     }
 }
@@ -3,10 +3,10 @@ use revolt_okapi::openapi3::{SecurityScheme, SecuritySchemeData};
 use revolt_rocket_okapi::gen::OpenApiGenerator;
 use revolt_rocket_okapi::request::{OpenApiFromRequest, RequestHeaderInput};
 
-use rocket::http::Status;
+use rocket::http::{Method, Status};
 use rocket::request::{self, FromRequest, Outcome, Request};
 
-use crate::models::user::UserHint;
+use crate::models::user::{SessionScope, UserHint};
 use crate::models::User;
 use crate::Database;
 
@@ -31,8 +31,11 @@ impl<'r> FromRequest<'r> for User {
                     }
                 } else if let Outcome::Success(session) = request.guard::<Session>().await {
                     // This uses a guard so can't really easily be refactored into from_token at this stage.
-                    if let Ok(user) = db.fetch_user(&session.user_id).await {
-                        return Some(user);
+                    if let Ok(mut user) = db.fetch_user(&session.user_id).await {
+                        if let Ok(scopes) = db.fetch_session_scopes(&session.id).await {
+                            user.scopes = scopes;
+                            return Some(user);
+                        }
                     }
                 }
 
@@ -41,6 +44,16 @@ impl<'r> FromRequest<'r> for User {
             .await;
 
         if let Some(user) = user {
+            // Read-only sessions may only fetch data
+            if user.scopes.contains(&SessionScope::ReadOnly)
+                && !matches!(
+                    request.method(),
+                    Method::Get | Method::Head | Method::Options
+                )
+            {
+                return Outcome::Failure((Status::Forbidden, authifier::Error::InvalidSession));
+            }
+
             Outcome::Success(user.clone())
         } else {
             Outcome::Failure((Status::Unauthorized, authifier::Error::InvalidSession))
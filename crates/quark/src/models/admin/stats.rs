@@ -111,6 +111,88 @@ pub struct CollectionStats {
     count: u64,
 }
 
+/// Activity figures for a single day
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ServerStatsDay {
+    /// Start of the day
+    pub date: Timestamp,
+
+    /// Number of messages sent on this day
+    pub messages: i64,
+
+    /// Number of members at the end of this day
+    pub members: i64,
+}
+
+/// Statistics for a single server
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ServerStats {
+    /// Server Id
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Time at which these statistics were generated
+    pub generated_at: Timestamp,
+
+    /// Total number of messages sent in this server
+    pub messages: i64,
+
+    /// Current number of members
+    pub members: i64,
+
+    /// Daily activity, oldest first
+    pub history: Vec<ServerStatsDay>,
+
+    /// Number of reactions using each of this server's emoji
+    pub emoji_usage: HashMap<String, i64>,
+}
+
+/// Counters for a single collection at a point in time
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct CollectionSnapshot {
+    /// Number of documents in collection
+    pub count: i64,
+
+    /// Uncompressed data size
+    pub size: i64,
+
+    /// Data size on disk
+    pub storage_size: i64,
+
+    /// Total size of all indexes
+    pub total_index_size: i64,
+}
+
+/// Daily snapshot of instance counters
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct StatsSnapshot {
+    /// Start of the day this snapshot was taken
+    #[serde(rename = "_id")]
+    pub date: Timestamp,
+
+    /// Counters for each collection
+    pub collections: HashMap<String, CollectionSnapshot>,
+}
+
+/// Counters for a write-behind buffer since this process started
+#[derive(Serialize, JsonSchema, Debug, Clone, Default)]
+pub struct WriteBufferStats {
+    /// Number of changes accepted into the buffer
+    pub buffered: u64,
+
+    /// Number of times the buffer was flushed to the database
+    pub flushes: u64,
+
+    /// Number of database writes made while flushing
+    pub writes: u64,
+
+    /// Number of database writes which failed
+    pub failures: u64,
+
+    /// Number of changes dropped because the buffer was full
+    pub dropped: u64,
+}
+
 /// Server Stats
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct Stats {
@@ -119,4 +201,10 @@ pub struct Stats {
 
     /// Collection stats
     pub coll_stats: HashMap<String, CollectionStats>,
+
+    /// Daily snapshots of collection counters, oldest first
+    pub history: Vec<StatsSnapshot>,
+
+    /// Write-behind buffer counters for the instance serving this request
+    pub write_buffers: HashMap<String, WriteBufferStats>,
 }
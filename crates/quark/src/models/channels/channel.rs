@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::{models::attachment::File, OverrideField};
 
@@ -101,6 +102,30 @@ pub enum Channel {
         /// Whether this channel is marked as not safe for work
         #[serde(skip_serializing_if = "if_false", default)]
         nsfw: bool,
+
+        /// Whether only members with the ManageThreads permission may post
+        #[serde(skip_serializing_if = "if_false", default)]
+        locked: bool,
+
+        /// Whether every message must contain an attachment or link
+        #[serde(skip_serializing_if = "if_false", default)]
+        media_only: bool,
+
+        /// Role required to post in this channel, everyone else may only react
+        #[serde(skip_serializing_if = "Option::is_none")]
+        posting_role: Option<String>,
+
+        /// Seconds each member must wait between sending messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        slowmode: Option<u32>,
+
+        /// Depth of reply chain after which further replies are moved into a thread
+        #[serde(skip_serializing_if = "Option::is_none")]
+        auto_thread_depth: Option<u32>,
+
+        /// Restrictions on the content types of attachments sent in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_types: Option<FileTypeRestrictions>,
     },
     /// Voice channel belonging to a server
     VoiceChannel {
@@ -118,6 +143,9 @@ pub enum Channel {
         /// Custom icon attachment
         #[serde(skip_serializing_if = "Option::is_none")]
         icon: Option<File>,
+        /// Id of the last message sent in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_message_id: Option<String>,
 
         /// Default permissions assigned to users in this channel
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -132,6 +160,14 @@ pub enum Channel {
         /// Whether this channel is marked as not safe for work
         #[serde(skip_serializing_if = "if_false", default)]
         nsfw: bool,
+
+        /// Maximum number of users who can be connected at once
+        #[serde(skip_serializing_if = "Option::is_none")]
+        user_limit: Option<u32>,
+
+        /// Restrictions on the content types of attachments sent in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_types: Option<FileTypeRestrictions>,
     },
 }
 
@@ -158,6 +194,20 @@ pub struct PartialChannel {
     pub default_permissions: Option<OverrideField>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posting_role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slowmode: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_thread_depth: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_types: Option<FileTypeRestrictions>,
 }
 
 /// Optional fields on channel object
@@ -166,4 +216,25 @@ pub enum FieldsChannel {
     Description,
     Icon,
     DefaultPermissions,
+    UserLimit,
+    PostingRole,
+    FileTypes,
+    Slowmode,
+    AutoThreadDepth,
+}
+
+/// Attachment content types permitted in a channel
+///
+/// Entries are either exact content types (`application/pdf`) or
+/// wildcards over a top-level type (`image/*`).
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct FileTypeRestrictions {
+    /// Content types which may be sent, an empty list permits everything
+    #[validate(length(max = 32))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Content types which may never be sent, takes priority over `allow`
+    #[validate(length(max = 32))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub block: Vec<String>,
 }
@@ -1,5 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+/// Temporary guest access granted through an invite
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct GuestAccess {
+    /// Whether guests may send messages in the channel
+    #[serde(default)]
+    pub write: bool,
+    /// How long guest accounts last in seconds
+    pub lifetime: i64,
+    /// Number of guest accounts this invite may create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+    /// Number of guest accounts created so far
+    #[serde(default)]
+    pub uses: u32,
+}
+
 /// Representation of an invite to a channel on Revolt
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(tag = "type")]
@@ -15,6 +31,9 @@ pub enum Invite {
         creator: String,
         /// Id of the server channel this invite points to
         channel: String,
+        /// Guest access granted by this invite
+        #[serde(skip_serializing_if = "Option::is_none")]
+        guest: Option<GuestAccess>,
     },
     /// Invite to a group channel
     Group {
@@ -24,6 +24,12 @@ pub fn if_false(t: &bool) -> bool {
 pub static RE_MENTION: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"<@([0-9A-HJKMNP-TV-Z]{26})>").unwrap());
 
+pub static RE_ROLE_MENTION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<%([0-9A-HJKMNP-TV-Z]{26})>").unwrap());
+
+pub static RE_CUSTOM_EMOJI: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r":([0-9A-HJKMNP-TV-Z]{26}):").unwrap());
+
 /// # Reply
 ///
 /// Representation of a message reply before it is sent.
@@ -77,6 +83,26 @@ pub enum SystemMessage {
     ChannelIconChanged { by: String },
     #[serde(rename = "channel_ownership_changed")]
     ChannelOwnershipChanged { from: String, to: String },
+    #[serde(rename = "channel_locked")]
+    ChannelLocked { by: String },
+    #[serde(rename = "channel_unlocked")]
+    ChannelUnlocked { by: String },
+    #[serde(rename = "message_highlighted")]
+    MessageHighlighted {
+        id: String,
+        channel: String,
+        author: String,
+    },
+    #[serde(rename = "member_warned")]
+    MemberWarned {
+        server: String,
+        by: String,
+        reason: String,
+    },
+    #[serde(rename = "message_pinned")]
+    MessagePinned { id: String, by: String },
+    #[serde(rename = "thread_started")]
+    ThreadStarted { id: String, name: String },
 }
 
 /// Name and / or avatar override information
@@ -126,6 +152,9 @@ pub struct Message {
     pub nonce: Option<String>,
     /// Id of the channel this message was sent in
     pub channel: String,
+    /// Id of the thread this message was posted in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<String>,
     /// Id of the user or webhook that sent this message
     pub author: String,
     /// The webhook that sent this message
@@ -149,6 +178,9 @@ pub struct Message {
     /// Array of user ids mentioned in this message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mentions: Option<Vec<String>>,
+    /// Array of role ids mentioned in this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_mentions: Option<Vec<String>>,
     /// Array of message ids this message is replying to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<String>>,
@@ -161,6 +193,12 @@ pub struct Message {
     /// Name and / or avatar overrides for this message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub masquerade: Option<Masquerade>,
+    /// Animation clients should play when displaying this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect: Option<String>,
+    /// Whether this message is pinned in its channel
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub pinned: bool,
 }
 
 /// # Message Sort
@@ -207,10 +245,35 @@ pub enum MessageTimePeriod {
 pub struct MessageFilter {
     /// Parent channel ID
     pub channel: Option<String>,
+    /// Parent channel IDs, any of which may match
+    pub channels: Option<Vec<String>>,
+    /// Thread ID
+    pub thread: Option<String>,
     /// Message author ID
     pub author: Option<String>,
     /// Search query
     pub query: Option<String>,
+    /// Attachment filter
+    pub attachment: Option<AttachmentFilter>,
+}
+
+/// # Attachment Filter
+///
+/// Matches messages with at least one attachment meeting all given criteria
+#[derive(Serialize, Deserialize, JsonSchema, Validate, Default, Clone, Debug)]
+pub struct AttachmentFilter {
+    /// Filename prefix to match, case-insensitive
+    ///
+    /// Meilisearch only matches whole filenames.
+    #[validate(length(min = 1, max = 128))]
+    pub filename: Option<String>,
+    /// Content type (e.g. `application/pdf`) or type family (e.g. `image`) to match
+    #[validate(length(min = 1, max = 128))]
+    pub content_type: Option<String>,
+    /// Minimum file size in bytes
+    pub min_size: Option<isize>,
+    /// Maximum file size in bytes
+    pub max_size: Option<isize>,
 }
 
 /// # Message Query
@@ -226,6 +289,9 @@ pub struct MessageQuery {
     /// Time period to fetch
     #[serde(flatten)]
     pub time_period: MessageTimePeriod,
+    /// Whether this query must see the latest writes, skipping read replicas
+    #[serde(skip)]
+    pub consistent: bool,
 }
 
 /// # Bulk Message Response
@@ -249,15 +315,94 @@ pub enum BulkMessageResponse {
     },
 }
 
+/// # Channel Search Results
+#[derive(Serialize, JsonSchema)]
+pub struct ChannelSearchResults {
+    /// Channel ID
+    pub channel: String,
+    /// List of matching messages in this channel
+    pub messages: Vec<Message>,
+}
+
+/// # Server Search Response
+///
+/// Response used when searching across a server
+#[derive(Serialize, JsonSchema)]
+pub struct ServerSearchResponse {
+    /// Matching messages grouped by channel
+    pub channels: Vec<ChannelSearchResults>,
+    /// List of users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<User>>,
+    /// List of members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<Member>>,
+}
+
+/// # Channel Message Sync
+///
+/// New messages in a channel since a given point
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct ChannelMessageSync {
+    /// Channel ID
+    pub channel: String,
+    /// Number of new messages
+    pub count: i64,
+    /// IDs of the most recent new messages, newest first
+    pub latest: Vec<String>,
+}
+
+/// # Deleted Message
+///
+/// Message held for recovery after being deleted
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct DeletedMessage {
+    /// Message ID
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Message as it was when deleted
+    pub message: Message,
+    /// Time at which this message was deleted
+    pub deleted_at: Timestamp,
+}
+
+/// # Message Revision
+///
+/// Previous version of a message, kept when the message is edited
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct MessageRevision {
+    /// Revision ID
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Id of the message this is a revision of
+    pub message_id: String,
+    /// Id of the channel the message was sent in
+    pub channel: String,
+    /// Message content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Attached embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    /// Array of attachments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<File>>,
+    /// Time at which this revision was created by an edit, if it was not the original
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited: Option<Timestamp>,
+    /// Time at which this revision was replaced by a newer edit
+    pub replaced_at: Timestamp,
+}
+
 /// # Appended Information
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct AppendMessage {
     /// Additional embeds to include in this message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub embeds: Option<Vec<Embed>>,
 }
 
-#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Default)]
 pub struct DataMessageSend {
     /// Unique token to prevent duplicate message sending
     ///
@@ -269,7 +414,6 @@ pub struct DataMessageSend {
     #[validate(length(min = 0, max = 2000))]
     pub content: Option<String>,
     /// Attachments to include in message
-
     pub attachments: Option<Vec<String>>,
     /// Messages to reply to
     pub replies: Option<Vec<Reply>>,
@@ -283,4 +427,17 @@ pub struct DataMessageSend {
     pub masquerade: Option<Masquerade>,
     /// Information about how this message should be interacted with
     pub interactions: Option<Interactions>,
+    /// Animation clients should play when displaying this message
+    ///
+    /// Must be one of the effects enabled on this instance
+    #[validate(length(min = 1, max = 32))]
+    pub effect: Option<String>,
+    /// Thread to post this message in
+    #[validate(length(min = 26, max = 26))]
+    pub thread: Option<String>,
+    /// Time at which this message should be sent
+    ///
+    /// If set, the message is held back and sent at this time instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_at: Option<Timestamp>,
 }
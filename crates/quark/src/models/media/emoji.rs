@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use iso8601_timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 /// Utility function to check if a boolean value is false
@@ -25,10 +28,44 @@ pub struct Emoji {
     pub creator_id: String,
     /// Emoji name
     pub name: String,
+    /// Alternative names this emoji can be found by
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub aliases: Vec<String>,
     /// Whether the emoji is animated
     #[serde(skip_serializing_if = "if_false", default)]
     pub animated: bool,
     /// Whether the emoji is marked as nsfw
     #[serde(skip_serializing_if = "if_false", default)]
     pub nsfw: bool,
+    /// Id of the emoji which replaced this one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+}
+
+/// Usage counter for a custom emoji
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct EmojiUsage {
+    /// Emoji Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Number of times this emoji has been used in messages and reactions
+    pub uses: i64,
+    /// Time at which this emoji was last used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<Timestamp>,
+}
+
+/// Sizes in pixels which emoji images are offered at
+pub const EMOJI_ASSET_SIZES: [u32; 4] = [32, 64, 128, 256];
+
+/// Emoji along with where its image can be fetched from
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct EmojiAsset {
+    /// Emoji information
+    #[serde(flatten)]
+    pub emoji: Emoji,
+    /// URL of the original image
+    pub url: String,
+    /// URLs of the image scaled to fit within each size, keyed by size
+    pub variants: BTreeMap<u32, String>,
 }
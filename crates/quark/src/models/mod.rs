@@ -28,6 +28,7 @@ mod users {
 }
 
 mod safety {
+    pub mod legal_hold;
     pub mod report;
     pub mod snapshot;
 }
@@ -45,6 +46,7 @@ pub use channel::Channel;
 pub use channel_invite::Invite;
 pub use channel_unread::ChannelUnread;
 pub use emoji::Emoji;
+pub use legal_hold::LegalHold;
 pub use message::Message;
 pub use report::Report;
 pub use server::Server;
@@ -0,0 +1,36 @@
+use iso8601_timestamp::Timestamp;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Kind of object placed under a legal hold
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum LegalHoldSubject {
+    User,
+    Channel,
+}
+
+/// Legal hold preserving content belonging to a user or channel
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct LegalHold {
+    /// Id of the held user or channel
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Kind of object being held
+    pub subject: LegalHoldSubject,
+    /// Reason this hold was placed
+    pub reason: String,
+    /// Id of the privileged user who placed this hold
+    pub placed_by: String,
+    /// Time at which this hold was placed
+    pub placed_at: Timestamp,
+}
+
+/// # Legal Hold Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCreateLegalHold {
+    /// Kind of object to hold
+    pub subject: LegalHoldSubject,
+    /// Reason for placing this hold
+    #[validate(length(min = 1, max = 1024))]
+    pub reason: String,
+}
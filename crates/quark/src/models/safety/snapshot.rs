@@ -31,11 +31,38 @@ pub struct Snapshot {
     #[serde(rename = "_id")]
     pub id: String,
     /// Report parent Id
-    pub report_id: String,
+    ///
+    /// Snapshots taken while monitoring a server have no parent report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_id: Option<String>,
     /// Snapshot of content
     pub content: SnapshotContent,
 }
 
+/// Single difference between two snapshots
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SnapshotChange {
+    /// Path to the changed value, separated by dots
+    pub path: String,
+    /// Value in the earlier snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// Value in the later snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+}
+
+/// Differences between two snapshots of the same object
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SnapshotDiff {
+    /// Id of the earlier snapshot
+    pub before: String,
+    /// Id of the later snapshot
+    pub after: String,
+    /// Changed values
+    pub changes: Vec<SnapshotChange>,
+}
+
 /// Snapshot of some content with required data to render
 #[derive(Serialize, JsonSchema, Debug)]
 pub struct SnapshotWithContext {
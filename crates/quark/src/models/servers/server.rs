@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -33,6 +32,17 @@ pub struct Role {
     /// Ranking of this role
     #[serde(default)]
     pub rank: i64,
+    /// Whether anyone may mention this role
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub mentionable: bool,
+    /// Whether members may give themselves this role
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub self_assignable: bool,
+    /// Position of this role in the self-assignable role menu
+    ///
+    /// Smaller values are shown first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_position: Option<i64>,
 }
 
 /// Channel category
@@ -46,6 +56,86 @@ pub struct Category {
     pub title: String,
     /// Channels in this category
     pub channels: Vec<String>,
+    /// Whether this category is shown collapsed to members by default
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub collapsed: bool,
+    /// Whether channels in this category are hidden until a role grants access to them
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub hidden: bool,
+}
+
+/// Option which may be picked when answering an onboarding question
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct OnboardingOption {
+    /// Unique ID for this option
+    #[validate(length(min = 1, max = 32))]
+    pub id: String,
+    /// Title for this option
+    #[validate(length(min = 1, max = 32))]
+    pub title: String,
+    /// Roles granted to members who pick this option
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub roles: Vec<String>,
+    /// Channels revealed to members who pick this option
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub channels: Vec<String>,
+}
+
+/// Question asked to members during onboarding
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct OnboardingQuestion {
+    /// Unique ID for this question
+    #[validate(length(min = 1, max = 32))]
+    pub id: String,
+    /// Title for this question
+    #[validate(length(min = 1, max = 100))]
+    pub title: String,
+    /// Whether members must pick at least one option
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub required: bool,
+    /// Whether members may pick more than one option
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub multiple: bool,
+    /// Options available for this question
+    #[validate(length(min = 1, max = 20))]
+    #[validate]
+    pub options: Vec<OnboardingOption>,
+}
+
+/// Onboarding sequence shown to new members
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct Onboarding {
+    /// Whether members must complete onboarding before participating
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub enabled: bool,
+    /// Questions asked during onboarding
+    #[validate(length(max = 20))]
+    #[validate]
+    pub questions: Vec<OnboardingQuestion>,
+}
+
+/// Configuration for highlighting popular messages in a channel
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct Highlights {
+    /// ID of channel to post highlighted messages in
+    pub channel: String,
+    /// Number of reactions a message needs to be highlighted
+    #[validate(range(min = 1, max = 1000))]
+    pub threshold: usize,
+    /// Only count reactions using this emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+/// Timeout applied automatically once a member accumulates warnings
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct WarnThreshold {
+    /// Number of warnings at which the timeout is applied
+    #[validate(range(min = 1, max = 100))]
+    pub warnings: u32,
+    /// Length of the timeout in seconds
+    #[validate(range(min = 60, max = 2419200))]
+    pub timeout: u64,
 }
 
 /// System message channel assignments
@@ -65,12 +155,16 @@ pub struct SystemMessageChannels {
     pub user_banned: Option<String>,
 }
 
-/// Server flag enum
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum ServerFlags {
-    Verified = 1,
-    Official = 2,
+pub use revolt_models::v0::{ServerFlags, ServerFlagsValue};
+
+/// Which messages members are notified about by default
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum NotificationMode {
+    /// Notify about every message
+    All,
+    /// Only notify about mentions
+    #[default]
+    Mentions,
 }
 
 /// Representation of a server on Revolt
@@ -101,6 +195,15 @@ pub struct Server {
     /// Configuration for sending system event messages
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_messages: Option<SystemMessageChannels>,
+    /// Onboarding sequence for new members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onboarding: Option<Onboarding>,
+    /// Configuration for highlighting popular messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights: Option<Highlights>,
+    /// Timeouts applied automatically when members are warned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warn_thresholds: Option<Vec<WarnThreshold>>,
 
     /// Roles for this server
     #[serde(
@@ -120,7 +223,10 @@ pub struct Server {
 
     /// Bitfield of server flags
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<ServerFlagsValue>,
+    /// Storage region files sent in this server must be uploaded to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_region: Option<String>,
 
     /// Whether this server is flagged as not safe for work
     #[serde(skip_serializing_if = "if_false", default)]
@@ -131,6 +237,10 @@ pub struct Server {
     /// Whether this server should be publicly discoverable
     #[serde(skip_serializing_if = "if_false", default)]
     pub discoverable: bool,
+
+    /// Notification mode given to new members
+    #[serde(default)]
+    pub default_notifications: NotificationMode,
 }
 
 /// Optional fields on server object
@@ -141,10 +251,15 @@ pub enum FieldsServer {
     SystemMessages,
     Icon,
     Banner,
+    Onboarding,
+    Highlights,
+    WarnThresholds,
+    StorageRegion,
 }
 
 /// Optional fields on server object
 #[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 pub enum FieldsRole {
     Colour,
+    MenuPosition,
 }
@@ -10,4 +10,7 @@ pub struct ServerBan {
     pub id: MemberCompositeKey,
     /// Reason for ban creation
     pub reason: Option<String>,
+    /// Server this ban was shared from through a ban sync group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synced_from: Option<String>,
 }
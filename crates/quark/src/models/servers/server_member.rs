@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::attachment::File;
 
+use super::server::{if_false, NotificationMode};
+
 /// Composite primary key consisting of server and user id
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 pub struct MemberCompositeKey {
@@ -39,6 +41,25 @@ pub struct Member {
     /// Timestamp this member is timed out until
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<Timestamp>,
+
+    /// Whether this member has been muted in voice channels by a moderator
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub voice_muted: bool,
+    /// Whether this member has been deafened in voice channels by a moderator
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub voice_deafened: bool,
+
+    /// Which messages this member is notified about
+    #[serde(default)]
+    pub notifications: NotificationMode,
+    /// Channels this member is notified about every message in
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub followed_channels: Vec<String>,
+    /// Onboarding options picked by this member
+    ///
+    /// Not present if this member has not completed onboarding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onboarding: Option<Vec<String>>,
 }
 
 /// Optional fields on server member object
@@ -50,6 +71,25 @@ pub enum FieldsMember {
     Timeout,
 }
 
+/// Filter used when searching for server members
+#[derive(Debug, Default)]
+pub struct MemberQuery {
+    /// Only include members with all of these roles
+    pub roles: Vec<String>,
+    /// Only include members who joined at or after this time
+    pub joined_after: Option<Timestamp>,
+    /// Only include members who joined at or before this time
+    pub joined_before: Option<Timestamp>,
+    /// Only include these users
+    pub users: Option<Vec<String>>,
+    /// Exclude these users
+    pub exclude_users: Vec<String>,
+    /// User id after which members should be fetched
+    pub after: Option<String>,
+    /// Maximum number of members to fetch
+    pub limit: i64,
+}
+
 /// Member removal intention
 pub enum RemovalIntention {
     Leave,
@@ -1,4 +1,3 @@
-use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 
 /// Utility function to check if a boolean value is false
@@ -6,13 +5,7 @@ pub fn if_false(t: &bool) -> bool {
     !t
 }
 
-/// Bot flag enum
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum BotFlags {
-    Verified = 1,
-    Official = 2,
-}
+pub use revolt_models::v0::{BotFlags, BotFlagsValue};
 
 /// Representation of a bot on Revolt
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, OptionalStruct, Default)]
@@ -52,7 +45,7 @@ pub struct Bot {
 
     /// Enum of bot flags
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<BotFlagsValue>,
 }
 
 /// Optional fields on bot object
@@ -1,4 +1,4 @@
-use num_enum::TryFromPrimitive;
+use iso8601_timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -29,6 +29,26 @@ pub struct Relationship {
     pub status: RelationshipStatus,
 }
 
+/// Handling of attachments and links in direct messages from users who are not friends
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectMessageMediaFilter {
+    /// Deliver messages unchanged
+    Allow,
+    /// Remove attachments, embeds and links before delivering
+    Strip,
+    /// Reject messages containing attachments, embeds or links
+    Block,
+}
+
+/// Who may send direct messages from other instances
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FederatedMessagePolicy {
+    /// Accept messages from anyone who hasn't been blocked
+    Everyone,
+    /// Reject all messages from other instances
+    Nobody,
+}
+
 /// Presence status
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
 pub enum Presence {
@@ -54,6 +74,10 @@ pub struct UserStatus {
     /// Current presence option
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<Presence>,
+    /// Activity shown for bots
+    #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity: Option<String>,
 }
 
 /// User's profile
@@ -65,49 +89,27 @@ pub struct UserProfile {
     /// Background visible on user's profile
     #[serde(skip_serializing_if = "Option::is_none")]
     pub background: Option<File>,
+    /// Personal website
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<ProfileLink>,
+    /// Donation or tipping page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation: Option<ProfileLink>,
 }
 
-/// User badge bitfield
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum Badges {
-    /// Revolt Developer
-    Developer = 1,
-    /// Helped translate Revolt
-    Translator = 2,
-    /// Monetarily supported Revolt
-    Supporter = 4,
-    /// Responsibly disclosed a security issue
-    ResponsibleDisclosure = 8,
-    /// Revolt Founder
-    Founder = 16,
-    /// Platform moderator
-    PlatformModeration = 32,
-    /// Active monetary supporter
-    ActiveSupporter = 64,
-    /// 🦊🦝
-    Paw = 128,
-    /// Joined as one of the first 1000 users in 2021
-    EarlyAdopter = 256,
-    /// Amogus
-    ReservedRelevantJokeBadge1 = 512,
-    /// Low resolution troll face
-    ReservedRelevantJokeBadge2 = 1024,
+/// Link displayed on a user's profile
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ProfileLink {
+    /// URL of the linked page
+    pub url: String,
+    /// Whether the linked page links back to this profile using `rel="me"`
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub verified: bool,
 }
 
-/// User flag enum
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum Flags {
-    /// User has been suspended from the platform
-    Suspended = 1,
-    /// User has deleted their account
-    Deleted = 2,
-    /// User was banned off the platform
-    Banned = 4,
-    /// User was marked as spam and removed from platform
-    Spam = 8,
-}
+pub use revolt_models::v0::{
+    SessionScope, UserBadges as Badges, UserBadgesValue, UserFlags as Flags, UserFlagsValue,
+};
 
 /// Bot information for if the user is a bot
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
@@ -116,9 +118,23 @@ pub struct BotInformation {
     pub owner: String,
 }
 
+/// Guest information for if the user is a temporary guest
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct GuestInformation {
+    /// Id of the server this guest was invited to
+    pub server: String,
+    /// Id of the only channel this guest can access
+    pub channel: String,
+    /// Whether this guest may send messages
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub write: bool,
+    /// Time at which this guest account expires
+    pub expires: Timestamp,
+}
+
 /// Representiation of a User on Revolt.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, OptionalStruct, Default)]
-#[optional_derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[optional_derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone)]
 #[optional_name = "PartialUser"]
 #[opt_skip_serializing_none]
 #[opt_some_priority]
@@ -137,7 +153,7 @@ pub struct User {
 
     /// Bitfield of user badges
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub badges: Option<i32>,
+    pub badges: Option<UserBadgesValue>,
     /// User's current status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<UserStatus>,
@@ -147,13 +163,27 @@ pub struct User {
 
     /// Enum of user flags
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<UserFlagsValue>,
     /// Whether this user is privileged
     #[serde(skip_serializing_if = "if_false", default)]
     pub privileged: bool,
     /// Bot information
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<BotInformation>,
+    /// Guest information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guest: Option<GuestInformation>,
+    /// Preferred locale for server-generated text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Handling of attachments and links in direct messages from users who are not friends
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_media_filter: Option<DirectMessageMediaFilter>,
+    /// Who may send direct messages from other instances
+    ///
+    /// Nobody may unless this is set to [`FederatedMessagePolicy::Everyone`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federated_dms: Option<FederatedMessagePolicy>,
 
     // ? Entries below should never be pushed to the database
     /// Current session user's relationship with this user
@@ -162,6 +192,9 @@ pub struct User {
     /// Whether this user is currently online
     #[serde(skip_serializing_if = "Option::is_none")]
     pub online: Option<bool>,
+    /// Restrictions placed on the session this user authenticated with
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub scopes: Vec<SessionScope>,
 }
 
 /// Optional fields on user object
@@ -170,8 +203,14 @@ pub enum FieldsUser {
     Avatar,
     StatusText,
     StatusPresence,
+    StatusActivity,
     ProfileContent,
     ProfileBackground,
+    ProfileWebsite,
+    ProfileDonation,
+    Locale,
+    DirectMessageMediaFilter,
+    FederatedMessagePolicy,
 }
 
 /// Enumeration providing a hint to the type of user we are handling
@@ -1,6 +1,83 @@
-use std::collections::HashMap;
-
-/// HashMap of user settings
-/// Each key is mapped to a tuple consisting of the
-/// revision timestamp and serialised data (in JSON format)
-pub type UserSettings = HashMap<String, (i64, String)>;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::util::regex::RE_COLOUR;
+
+/// HashMap of user settings
+/// Each key is mapped to a tuple consisting of the
+/// revision timestamp and serialised data (in JSON format)
+pub type UserSettings = HashMap<String, (i64, String)>;
+
+/// Folder grouping servers in a user's server list
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct ServerFolder {
+    /// Unique ID for this folder
+    #[validate(length(min = 1, max = 32))]
+    pub id: String,
+    /// Folder name
+    #[validate(length(min = 1, max = 32))]
+    pub name: String,
+    /// Colour used for this folder
+    ///
+    /// This can be any valid CSS colour
+    #[validate(length(min = 1, max = 128), regex = "RE_COLOUR")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colour: Option<String>,
+    /// Ids of servers in this folder, in order
+    pub servers: Vec<String>,
+}
+
+/// Ordering and folders of a user's server list
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerLayout {
+    /// User Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Ids of top-level servers and folders, in order
+    #[validate(length(max = 1000))]
+    pub order: Vec<String>,
+    /// Folders in the server list
+    #[validate(length(max = 200))]
+    #[validate]
+    pub folders: Vec<ServerFolder>,
+    /// Revision timestamp of this layout
+    pub timestamp: i64,
+}
+
+/// Folder grouping direct message conversations in a user's DM list
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct DmFolder {
+    /// Unique ID for this folder
+    #[validate(length(min = 1, max = 32))]
+    pub id: String,
+    /// Folder name
+    #[validate(length(min = 1, max = 32))]
+    pub name: String,
+    /// Colour used for this folder
+    ///
+    /// This can be any valid CSS colour
+    #[validate(length(min = 1, max = 128), regex = "RE_COLOUR")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colour: Option<String>,
+    /// Ids of conversations in this folder, in order
+    pub channels: Vec<String>,
+}
+
+/// Pinned conversations and folders of a user's DM list
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DmLayout {
+    /// User Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Ids of conversations pinned to the top of the list, in order
+    #[validate(length(max = 100))]
+    pub pinned: Vec<String>,
+    /// Folders in the DM list, in order
+    #[validate(length(max = 200))]
+    #[validate]
+    pub folders: Vec<DmFolder>,
+    /// Revision timestamp of this layout
+    pub timestamp: i64,
+}
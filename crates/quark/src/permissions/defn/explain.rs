@@ -0,0 +1,52 @@
+use serde::Serialize;
+
+use super::Permission;
+
+/// Where a permission was last granted or revoked
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum PermissionSource {
+    /// User is privileged
+    Privileged,
+    /// User owns the server or channel
+    Owner,
+    /// Determined by the type of channel
+    Channel,
+    /// Server default permissions
+    ServerDefault,
+    /// Server role permissions
+    ServerRole { id: String },
+    /// Channel default permission override
+    ChannelDefault,
+    /// Channel role permission override
+    ChannelRole { id: String },
+    /// Member is timed out
+    Timeout,
+    /// Channel has not been revealed through onboarding
+    Onboarding,
+    /// Channel is in a hidden category and no role grants access
+    Category { id: String },
+    /// User is a guest limited to a single channel
+    Guest,
+}
+
+/// Resolution of a single permission
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct PermissionBreakdown {
+    /// Permission being described
+    pub permission: Permission,
+    /// Whether the permission was granted
+    pub granted: bool,
+    /// Last source that granted or revoked this permission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PermissionSource>,
+}
+
+/// Resolved permissions along with where each one came from
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct PermissionExplanation {
+    /// Resolved permission value
+    pub permissions: u64,
+    /// Breakdown of every known permission
+    pub breakdown: Vec<PermissionBreakdown>,
+}
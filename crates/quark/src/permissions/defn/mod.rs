@@ -1,7 +1,9 @@
+mod explain;
 mod permission;
 mod user;
 
 use bson::Bson;
+pub use explain::*;
 pub use permission::*;
 pub use user::*;
 
@@ -22,7 +24,7 @@ pub struct Override {
 
 /// Representation of a single permission override
 /// as it appears on models and in the database
-#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct OverrideField {
     /// Allow bit flags
     a: i64,
@@ -31,6 +33,11 @@ pub struct OverrideField {
 }
 
 impl Override {
+    /// Create a new override from allow and deny values
+    pub fn new(allow: u64, deny: u64) -> Override {
+        Override { allow, deny }
+    }
+
     /// Into allows
     pub fn allows(&self) -> u64 {
         self.allow
@@ -84,7 +84,26 @@ pub enum Permission {
     MoveMembers = 1 << 35,
 
     // * Misc. permissions
-    // % Bits 36 to 52: free area
+    /// Manage threads in a channel
+    ManageThreads = 1 << 36,
+    /// Pin and unpin messages in a channel
+    PinMessages = 1 << 37,
+    /// React with custom emojis from other servers
+    UseExternalEmojis = 1 << 38,
+    /// Create invites to this channel
+    CreateInvites = 1 << 39,
+    /// Join voice channels which have reached their user limit
+    BypassUserLimit = 1 << 40,
+    /// Mention roles which are not mentionable
+    MentionRoles = 1 << 41,
+    /// Share their screen in a voice call
+    ScreenShare = 1 << 42,
+    /// Be heard over other participants while speaking in a voice call
+    PrioritySpeaker = 1 << 43,
+    /// Send messages without waiting for a channel's slowmode
+    BypassSlowmode = 1 << 44,
+
+    // % Bits 45 to 52: free area
     // % Bits 53 to 64: do not use
 
     // * Grant all permissions
@@ -103,13 +122,17 @@ pub static DEFAULT_PERMISSION_VIEW_ONLY: Lazy<u64> = Lazy::new(|| Permission::Vi
 pub static DEFAULT_PERMISSION: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION_VIEW_ONLY.add(
     Permission::SendMessage
     + Permission::InviteOthers
+    + Permission::CreateInvites
     + Permission::SendEmbeds
     + Permission::UploadFiles
     + Permission::Connect
-    + Permission::Speak));
+    + Permission::Speak
+    + Permission::Video
+    + Permission::ScreenShare));
 pub static DEFAULT_PERMISSION_SAVED_MESSAGES: u64 = Permission::GrantAllSafe as u64;
-pub static DEFAULT_PERMISSION_DIRECT_MESSAGE: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION.add(Permission::ManageChannel + Permission::React));
-pub static DEFAULT_PERMISSION_SERVER: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION.add(Permission::React + Permission::ChangeNickname + Permission::ChangeAvatar));
+pub static DEFAULT_PERMISSION_DIRECT_MESSAGE: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION.add(Permission::ManageChannel + Permission::React + Permission::UseExternalEmojis + Permission::PinMessages));
+pub static DEFAULT_PERMISSION_SERVER: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION.add(Permission::React + Permission::UseExternalEmojis + Permission::ChangeNickname + Permission::ChangeAvatar));
+pub static ALLOW_FOR_GUESTS: Lazy<u64> = Lazy::new(|| DEFAULT_PERMISSION_VIEW_ONLY.add(Permission::SendMessage + Permission::SendEmbeds + Permission::UploadFiles + Permission::React));
 
 bitfield! {
     #[derive(Default)]
@@ -151,6 +174,17 @@ bitfield! {
     pub can_mute_members, _: 29;
     pub can_deafen_members, _: 28;
     pub can_move_members, _: 27;
+
+    // * Misc. permissions
+    pub can_manage_threads, _: 26;
+    pub can_pin_messages, _: 25;
+    pub can_use_external_emojis, _: 24;
+    pub can_create_invites, _: 23;
+    pub can_bypass_user_limit, _: 22;
+    pub can_mention_roles, _: 21;
+    pub can_screen_share, _: 20;
+    pub can_priority_speak, _: 19;
+    pub can_bypass_slowmode, _: 18;
 }
 
 pub type Perms = Permissions<[u64; 1]>;
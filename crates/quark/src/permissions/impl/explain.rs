@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use super::permission::guest_permissions;
+use crate::{
+    models::Channel, permissions::PermissionCalculator, Override, Permission, PermissionBreakdown,
+    PermissionExplanation, PermissionSource, Result, ALLOW_IN_TIMEOUT,
+};
+
+/// Permission value which remembers what last changed each bit
+struct Trace {
+    value: u64,
+    sources: HashMap<u64, PermissionSource>,
+}
+
+impl Trace {
+    fn new() -> Trace {
+        Trace {
+            value: 0,
+            sources: HashMap::new(),
+        }
+    }
+
+    /// Mark every bit in the given mask as changed by source
+    fn record(&mut self, mask: u64, source: &PermissionSource) {
+        for bit in (0..64).map(|i| 1_u64 << i).filter(|bit| mask & bit == *bit) {
+            self.sources.insert(bit, source.clone());
+        }
+    }
+
+    /// Allow given permissions
+    fn allow(&mut self, v: u64, source: PermissionSource) {
+        self.value |= v;
+        self.record(v, &source);
+    }
+
+    /// Apply a given override
+    fn apply(&mut self, v: Override, source: PermissionSource) {
+        self.value |= v.allows();
+        self.value &= !v.denies();
+        self.record(v.allows() | v.denies(), &source);
+    }
+
+    /// Restrict to given permissions
+    fn restrict(&mut self, v: u64, source: PermissionSource) {
+        self.record(self.value & !v, &source);
+        self.value &= v;
+    }
+
+    /// Produce a breakdown of every known permission
+    fn finish(mut self) -> PermissionExplanation {
+        let breakdown = (0..64)
+            .map(|i| 1_u64 << i)
+            .filter_map(|bit| {
+                Permission::try_from(bit)
+                    .ok()
+                    .map(|permission| PermissionBreakdown {
+                        permission,
+                        granted: self.value & bit == bit,
+                        source: self.sources.remove(&bit),
+                    })
+            })
+            .collect();
+
+        PermissionExplanation {
+            permissions: self.value,
+            breakdown,
+        }
+    }
+}
+
+impl PermissionCalculator<'_> {
+    /// Calculate the permissions from our perspective in the given channel,
+    /// recording which role or override last granted or revoked each permission
+    pub async fn explain(&mut self, db: &crate::Database) -> Result<PermissionExplanation> {
+        let mut trace = Trace::new();
+
+        if self.perspective.privileged {
+            trace.allow(
+                Permission::GrantAllSafe as u64,
+                PermissionSource::Privileged,
+            );
+            return Ok(trace.finish());
+        }
+
+        let (default_permissions, role_permissions) = match self.channel.get().unwrap() {
+            Channel::TextChannel {
+                server,
+                default_permissions,
+                role_permissions,
+                ..
+            }
+            | Channel::VoiceChannel {
+                server,
+                default_permissions,
+                role_permissions,
+                ..
+            } => {
+                if !self.server.has() {
+                    self.server.set(db.fetch_server(server).await?);
+                }
+
+                (*default_permissions, role_permissions.clone())
+            }
+            _ => {
+                // Other channel types do not have any overrides to explain.
+                // Guests are already restricted by calc.
+                let value = self.calc(db).await?.0[0];
+                trace.allow(value, PermissionSource::Channel);
+                return Ok(trace.finish());
+            }
+        };
+
+        let server = self.server.get().unwrap();
+
+        // 1. Check if owner.
+        if self.perspective.id == server.owner {
+            trace.allow(Permission::GrantAllSafe as u64, PermissionSource::Owner);
+            return Ok(trace.finish());
+        }
+
+        // 2. Fetch member.
+        if !self.member.has() {
+            self.member
+                .set(db.fetch_member(&server.id, &self.perspective.id).await?);
+        }
+
+        let member = self.member.get().unwrap();
+        let timed_out = member.in_timeout();
+
+        // 3. Apply allows from server default_permissions.
+        trace.allow(
+            server.default_permissions as u64,
+            PermissionSource::ServerDefault,
+        );
+
+        // 4. Apply allows and denies from server roles in order.
+        let mut roles = member
+            .roles
+            .iter()
+            .filter_map(|id| {
+                server
+                    .roles
+                    .get(id)
+                    .map(|role| (role.rank, id, Override::from(role.permissions)))
+            })
+            .collect::<Vec<(i64, &String, Override)>>();
+
+        roles.sort_by(|a, b| b.0.cmp(&a.0));
+
+        for (_, id, v) in roles {
+            trace.apply(v, PermissionSource::ServerRole { id: id.clone() });
+        }
+
+        if timed_out {
+            trace.restrict(*ALLOW_IN_TIMEOUT, PermissionSource::Timeout);
+        }
+
+        // 5. Apply default allows and denies for channel.
+        if let Some(default) = default_permissions {
+            trace.apply(default.into(), PermissionSource::ChannelDefault);
+        }
+
+        // 6. Apply allows and denies from channel role overrides in order.
+        let mut roles = member
+            .roles
+            .iter()
+            .filter_map(|id| {
+                let role = server.roles.get(id)?;
+                let v = role_permissions.get(id)?;
+                Some((role.rank, id, Override::from(*v)))
+            })
+            .collect::<Vec<(i64, &String, Override)>>();
+
+        roles.sort_by(|a, b| b.0.cmp(&a.0));
+
+        for (_, id, v) in roles {
+            trace.apply(v, PermissionSource::ChannelRole { id: id.clone() });
+        }
+
+        // 7. Revoke permissions if member is timed out.
+        if timed_out {
+            trace.restrict(*ALLOW_IN_TIMEOUT, PermissionSource::Timeout);
+        }
+
+        // 8. Hide channel if it has not been revealed through onboarding.
+        let channel = self.channel.get().unwrap();
+        if server.onboarding.as_ref().map_or(false, |onboarding| {
+            onboarding.hides_channel(member, channel.id())
+        }) {
+            trace.restrict(0, PermissionSource::Onboarding);
+        }
+
+        // 9. Hide channel if its category is hidden and no role grants access.
+        if server.category_hides_channel(member, channel) {
+            trace.restrict(
+                0,
+                PermissionSource::Category {
+                    id: server
+                        .category_of(channel.id())
+                        .map(|category| category.id.clone())
+                        .unwrap_or_default(),
+                },
+            );
+        }
+
+        // 10. Restrict guests to the channel they were invited to.
+        if let Some(guest) = &self.perspective.guest {
+            trace.restrict(
+                guest_permissions(guest, Some(channel)),
+                PermissionSource::Guest,
+            );
+        }
+
+        Ok(trace.finish())
+    }
+}
@@ -1,2 +1,3 @@
+pub mod explain;
 pub mod permission;
 pub mod user;
@@ -1,9 +1,14 @@
 use std::collections::HashSet;
 
 use crate::{
-    models::Channel, permissions::PermissionCalculator, Override, Permission, PermissionValue,
-    Permissions, Perms, Result, ALLOW_IN_TIMEOUT, DEFAULT_PERMISSION_DIRECT_MESSAGE,
-    DEFAULT_PERMISSION_SAVED_MESSAGES, DEFAULT_PERMISSION_VIEW_ONLY,
+    models::{
+        user::{GuestInformation, SessionScope},
+        Channel,
+    },
+    permissions::PermissionCalculator,
+    Override, Permission, PermissionValue, Permissions, Perms, Result, ALLOW_FOR_GUESTS,
+    ALLOW_IN_TIMEOUT, DEFAULT_PERMISSION_DIRECT_MESSAGE, DEFAULT_PERMISSION_SAVED_MESSAGES,
+    DEFAULT_PERMISSION_VIEW_ONLY,
 };
 
 use super::super::Permission::GrantAllSafe;
@@ -17,20 +22,52 @@ impl PermissionCalculator<'_> {
             return Ok(Permissions([GrantAllSafe as u64]));
         }
 
-        let value = if self.channel.has() {
+        let mut value = if self.channel.has() {
             calculate_channel_permission(self, db).await?
         } else if self.server.has() {
             calculate_server_permission(self, db).await?
         } else {
             panic!("Expected `PermissionCalculator.(user|server) to exist.");
+        };
+
+        // Restrict guests to the channel they were invited to.
+        if let Some(guest) = &self.perspective.guest {
+            value.restrict(guest_permissions(guest, self.channel.get()));
+        }
+
+        // Keep sessions without access to direct messages out of them.
+        if self
+            .perspective
+            .scopes
+            .contains(&SessionScope::NoDirectMessages)
+            && matches!(
+                self.channel.get(),
+                Some(Channel::DirectMessage { .. } | Channel::Group { .. })
+            )
+        {
+            value.restrict(0);
         }
-        .into();
 
+        let value = value.into();
         self.cached_permission = Some(value);
         Ok(Permissions([value]))
     }
 }
 
+/// Internal helper function for finding the most a guest may do in a given channel
+pub(crate) fn guest_permissions(guest: &GuestInformation, channel: Option<&Channel>) -> u64 {
+    match channel {
+        Some(channel) if channel.id() == guest.channel => {
+            if guest.write {
+                *ALLOW_FOR_GUESTS
+            } else {
+                *DEFAULT_PERMISSION_VIEW_ONLY
+            }
+        }
+        _ => 0,
+    }
+}
+
 /// Internal helper function for calculating server permission
 async fn calculate_server_permission(
     data: &mut PermissionCalculator<'_>,
@@ -221,6 +258,18 @@ async fn calculate_channel_permission(
                     permissions.restrict(*ALLOW_IN_TIMEOUT);
                 }
 
+                // 6. Hide channel if it has not been revealed through onboarding.
+                if server.onboarding.as_ref().map_or(false, |onboarding| {
+                    onboarding.hides_channel(member, channel.id())
+                }) {
+                    permissions.restrict(0);
+                }
+
+                // 7. Hide channel if its category is hidden and no role grants access.
+                if server.category_hides_channel(member, channel) {
+                    permissions.restrict(0);
+                }
+
                 permissions
             } else {
                 (Permission::GrantAllSafe as u64).into()
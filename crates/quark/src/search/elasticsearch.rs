@@ -0,0 +1,259 @@
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::models::message::MessageSort;
+use crate::{Error, Result};
+
+use super::{SearchBackend, SearchDocument, SearchQuery};
+
+/// Elasticsearch search backend
+pub struct Elasticsearch {
+    client: Client,
+    host: String,
+    index: String,
+    key: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SearchHit {
+    #[serde(rename = "_id")]
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct SearchHits {
+    hits: Vec<SearchHit>,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    hits: SearchHits,
+}
+
+impl Elasticsearch {
+    /// Create a new Elasticsearch backend for the given index
+    pub fn new(host: &str, index: &str, key: Option<String>) -> Elasticsearch {
+        Elasticsearch {
+            client: Client::new(),
+            host: host.trim_end_matches('/').to_string(),
+            index: index.to_string(),
+            key,
+        }
+    }
+
+    /// Attach authentication to a request
+    fn auth(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.key {
+            Some(key) => request.header("Authorization", format!("ApiKey {key}")),
+            None => request,
+        }
+    }
+
+    /// Send a request, failing if the backend did not accept it
+    async fn send(&self, request: RequestBuilder) -> Result<reqwest::Response> {
+        let response = self
+            .auth(request)
+            .send()
+            .await
+            .map_err(|_| Error::SearchUnavailable)?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            error!(
+                "Elasticsearch rejected request with status {}.",
+                response.status()
+            );
+
+            Err(Error::SearchUnavailable)
+        }
+    }
+
+    /// Submit a set of bulk operations
+    async fn bulk(&self, lines: Vec<String>) -> Result<()> {
+        let mut body = lines.join("\n");
+        body.push('\n');
+
+        self.send(
+            self.client
+                .post(format!("{}/_bulk", self.host))
+                .header("Content-Type", "application/x-ndjson")
+                .body(body),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl SearchBackend for Elasticsearch {
+    async fn prepare(&self) -> Result<()> {
+        let response = self
+            .auth(
+                self.client
+                    .put(format!("{}/{}", self.host, self.index))
+                    .json(&json!({
+                        "mappings": {
+                            "properties": {
+                                "id": { "type": "keyword" },
+                                "channel": { "type": "keyword" },
+                                "author": { "type": "keyword" },
+                                "content": { "type": "text" },
+                                "attachments": {
+                                    "type": "nested",
+                                    "properties": {
+                                        "filename": { "type": "keyword" },
+                                        "content_type": { "type": "keyword" },
+                                        "content_family": { "type": "keyword" },
+                                        "size": { "type": "long" }
+                                    }
+                                },
+                                "created_at": { "type": "long" }
+                            }
+                        }
+                    })),
+            )
+            .send()
+            .await
+            .map_err(|_| Error::SearchUnavailable)?;
+
+        // Index creation fails with a bad request if it already exists.
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::BAD_REQUEST => Ok(()),
+            _ => Err(Error::SearchUnavailable),
+        }
+    }
+
+    async fn index(&self, documents: &[SearchDocument]) -> Result<()> {
+        let mut lines = vec![];
+        for document in documents {
+            lines
+                .push(json!({ "index": { "_index": self.index, "_id": document.id } }).to_string());
+            lines.push(json!(document).to_string());
+        }
+
+        self.bulk(lines).await
+    }
+
+    async fn remove(&self, ids: &[String]) -> Result<()> {
+        let lines = ids
+            .iter()
+            .map(|id| json!({ "delete": { "_index": self.index, "_id": id } }).to_string())
+            .collect();
+
+        self.bulk(lines).await
+    }
+
+    async fn search(&self, query: SearchQuery<'_>) -> Result<Vec<String>> {
+        let mut filters = vec![];
+
+        if let Some(channel) = &query.filter.channel {
+            filters.push(json!({ "term": { "channel": channel } }));
+        }
+
+        if let Some(channels) = &query.filter.channels {
+            filters.push(json!({ "terms": { "channel": channels } }));
+        }
+
+        if let Some(author) = &query.filter.author {
+            filters.push(json!({ "term": { "author": author } }));
+        }
+
+        if let Some(attachment) = &query.filter.attachment {
+            let mut element = vec![];
+
+            if let Some(filename) = &attachment.filename {
+                element.push(json!({
+                    "wildcard": {
+                        "attachments.filename": {
+                            "value": format!("{}*", escape_wildcard(filename)),
+                            "case_insensitive": true
+                        }
+                    }
+                }));
+            }
+
+            if let Some(content_type) = &attachment.content_type {
+                if content_type.contains('/') {
+                    element.push(json!({ "term": { "attachments.content_type": content_type } }));
+                } else {
+                    element.push(json!({ "term": { "attachments.content_family": content_type } }));
+                }
+            }
+
+            let mut size = json!({});
+            if let Some(min_size) = attachment.min_size {
+                size["gte"] = json!(min_size);
+            }
+
+            if let Some(max_size) = attachment.max_size {
+                size["lte"] = json!(max_size);
+            }
+
+            if attachment.min_size.is_some() || attachment.max_size.is_some() {
+                element.push(json!({ "range": { "attachments.size": size } }));
+            }
+
+            filters.push(json!({
+                "nested": {
+                    "path": "attachments",
+                    "query": { "bool": { "filter": element } }
+                }
+            }));
+        }
+
+        let mut range = json!({});
+        if let Some(before) = query.before {
+            range["lt"] = json!(before);
+        }
+
+        if let Some(after) = query.after {
+            range["gt"] = json!(after);
+        }
+
+        if query.before.is_some() || query.after.is_some() {
+            filters.push(json!({ "range": { "created_at": range } }));
+        }
+
+        let mut body = json!({
+            "query": {
+                "bool": {
+                    "must": [{ "match": { "content": query.query } }],
+                    "filter": filters
+                }
+            },
+            "size": query.limit,
+            "_source": false
+        });
+
+        match query.sort {
+            // Elasticsearch ranks by score when no sort is given
+            MessageSort::Relevance => {}
+            MessageSort::Latest => body["sort"] = json!([{ "created_at": "desc" }]),
+            MessageSort::Oldest => body["sort"] = json!([{ "created_at": "asc" }]),
+        }
+
+        let response: SearchResponse = self
+            .send(
+                self.client
+                    .post(format!("{}/{}/_search", self.host, self.index))
+                    .json(&body),
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|_| Error::SearchUnavailable)?;
+
+        Ok(response.hits.hits.into_iter().map(|hit| hit.id).collect())
+    }
+}
+
+/// Escape wildcard characters in user input
+fn escape_wildcard(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('*', "\\*")
+        .replace('?', "\\?")
+}
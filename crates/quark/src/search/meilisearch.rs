@@ -0,0 +1,190 @@
+use reqwest::{Client, RequestBuilder};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::models::message::MessageSort;
+use crate::{Error, Result};
+
+use super::{SearchBackend, SearchDocument, SearchQuery};
+
+/// Meilisearch search backend
+pub struct Meilisearch {
+    client: Client,
+    url: String,
+    key: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SearchHit {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    hits: Vec<SearchHit>,
+}
+
+impl Meilisearch {
+    /// Create a new Meilisearch backend for the given index
+    pub fn new(host: &str, index: &str, key: Option<String>) -> Meilisearch {
+        Meilisearch {
+            client: Client::new(),
+            url: format!("{}/indexes/{index}", host.trim_end_matches('/')),
+            key,
+        }
+    }
+
+    /// Attach authentication to a request
+    fn auth(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        }
+    }
+
+    /// Send a request, failing if the backend did not accept it
+    async fn send(&self, request: RequestBuilder) -> Result<reqwest::Response> {
+        let response = self
+            .auth(request)
+            .send()
+            .await
+            .map_err(|_| Error::SearchUnavailable)?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            error!(
+                "Meilisearch rejected request with status {}.",
+                response.status()
+            );
+
+            Err(Error::SearchUnavailable)
+        }
+    }
+}
+
+#[async_trait]
+impl SearchBackend for Meilisearch {
+    async fn prepare(&self) -> Result<()> {
+        // Settings updates create the index if it doesn't exist yet.
+        self.send(
+            self.client
+                .patch(format!("{}/settings", self.url))
+                .json(&json!({
+                    "searchableAttributes": ["content"],
+                    "filterableAttributes": [
+                        "channel",
+                        "author",
+                        "created_at",
+                        "attachments.filename",
+                        "attachments.content_type",
+                        "attachments.content_family",
+                        "attachments.size"
+                    ],
+                    "sortableAttributes": ["created_at"]
+                })),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn index(&self, documents: &[SearchDocument]) -> Result<()> {
+        self.send(
+            self.client
+                .post(format!("{}/documents", self.url))
+                .query(&[("primaryKey", "id")])
+                .json(documents),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn remove(&self, ids: &[String]) -> Result<()> {
+        self.send(
+            self.client
+                .post(format!("{}/documents/delete-batch", self.url))
+                .json(ids),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn search(&self, query: SearchQuery<'_>) -> Result<Vec<String>> {
+        let mut filters = vec![];
+
+        if let Some(channel) = &query.filter.channel {
+            filters.push(format!("channel = {}", json!(channel)));
+        }
+
+        if let Some(channels) = &query.filter.channels {
+            filters.push(format!("channel IN {}", json!(channels)));
+        }
+
+        if let Some(author) = &query.filter.author {
+            filters.push(format!("author = {}", json!(author)));
+        }
+
+        // Attributes of nested objects are matched independently of each other.
+        if let Some(attachment) = &query.filter.attachment {
+            if let Some(filename) = &attachment.filename {
+                filters.push(format!("attachments.filename = {}", json!(filename)));
+            }
+
+            if let Some(content_type) = &attachment.content_type {
+                if content_type.contains('/') {
+                    filters.push(format!(
+                        "attachments.content_type = {}",
+                        json!(content_type)
+                    ));
+                } else {
+                    filters.push(format!(
+                        "attachments.content_family = {}",
+                        json!(content_type)
+                    ));
+                }
+            }
+
+            if let Some(min_size) = attachment.min_size {
+                filters.push(format!("attachments.size >= {min_size}"));
+            }
+
+            if let Some(max_size) = attachment.max_size {
+                filters.push(format!("attachments.size <= {max_size}"));
+            }
+        }
+
+        if let Some(before) = query.before {
+            filters.push(format!("created_at < {before}"));
+        }
+
+        if let Some(after) = query.after {
+            filters.push(format!("created_at > {after}"));
+        }
+
+        let mut body = json!({
+            "q": query.query,
+            "limit": query.limit,
+            "attributesToRetrieve": ["id"]
+        });
+
+        if !filters.is_empty() {
+            body["filter"] = json!(filters.join(" AND "));
+        }
+
+        match query.sort {
+            // Meilisearch ranks by relevance when no sort is given
+            MessageSort::Relevance => {}
+            MessageSort::Latest => body["sort"] = json!(["created_at:desc"]),
+            MessageSort::Oldest => body["sort"] = json!(["created_at:asc"]),
+        }
+
+        let response: SearchResponse = self
+            .send(self.client.post(format!("{}/search", self.url)).json(&body))
+            .await?
+            .json()
+            .await
+            .map_err(|_| Error::SearchUnavailable)?;
+
+        Ok(response.hits.into_iter().map(|hit| hit.id).collect())
+    }
+}
@@ -0,0 +1,189 @@
+//! External full-text search backends
+//!
+//! When `REVOLT_SEARCH_BACKEND` is configured, message content is mirrored
+//! into an external index and searches are resolved against it instead of
+//! the database's own text index.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::models::message::{MessageFilter, MessageQuery, MessageSort, MessageTimePeriod};
+use crate::models::Message;
+use crate::variables::delta::{SEARCH_BACKEND, SEARCH_INDEX, SEARCH_KEY, SEARCH_URL};
+use crate::{Database, Result};
+
+mod elasticsearch;
+mod meilisearch;
+
+pub use self::elasticsearch::Elasticsearch;
+pub use self::meilisearch::Meilisearch;
+
+/// Message as it is stored in the search index
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchDocument {
+    /// Message ID
+    pub id: String,
+    /// Parent channel ID
+    pub channel: String,
+    /// Message author ID
+    pub author: String,
+    /// Message content
+    pub content: String,
+    /// Metadata of attached files
+    pub attachments: Vec<SearchAttachment>,
+    /// Creation time in milliseconds, derived from the message ID
+    pub created_at: u64,
+}
+
+/// Attachment metadata as it is stored in the search index
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchAttachment {
+    /// Original filename
+    pub filename: String,
+    /// Raw content type
+    pub content_type: String,
+    /// Type family, the part of the content type before the slash
+    pub content_family: String,
+    /// Size in bytes
+    pub size: isize,
+}
+
+impl SearchDocument {
+    /// Create a search document from a message, if it has anything to index
+    pub fn from_message(message: &Message) -> Option<SearchDocument> {
+        let content = message.content.clone().unwrap_or_default();
+        let attachments: Vec<SearchAttachment> = message
+            .attachments
+            .iter()
+            .flatten()
+            .map(|file| SearchAttachment {
+                filename: file.filename.clone(),
+                content_type: file.content_type.clone(),
+                content_family: file
+                    .content_type
+                    .split('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                size: file.size,
+            })
+            .collect();
+
+        if content.is_empty() && attachments.is_empty() {
+            return None;
+        }
+
+        Some(SearchDocument {
+            id: message.id.clone(),
+            channel: message.channel.clone(),
+            author: message.author.clone(),
+            content,
+            attachments,
+            created_at: timestamp_of(&message.id).unwrap_or_default(),
+        })
+    }
+}
+
+/// Search request passed through to a backend
+pub struct SearchQuery<'a> {
+    /// Text to search for
+    pub query: &'a str,
+    /// Filter to apply
+    pub filter: &'a MessageFilter,
+    /// Only include messages created before this time (in milliseconds)
+    pub before: Option<u64>,
+    /// Only include messages created after this time (in milliseconds)
+    pub after: Option<u64>,
+    /// Result sort order
+    pub sort: MessageSort,
+    /// Maximum number of results
+    pub limit: i64,
+}
+
+#[async_trait]
+pub trait SearchBackend: Sync + Send {
+    /// Prepare the index, creating it and configuring it if necessary
+    async fn prepare(&self) -> Result<()>;
+
+    /// Add or replace documents in the index
+    async fn index(&self, documents: &[SearchDocument]) -> Result<()>;
+
+    /// Remove documents from the index by their ids
+    async fn remove(&self, ids: &[String]) -> Result<()>;
+
+    /// Search the index, returning matching message ids in order
+    async fn search(&self, query: SearchQuery<'_>) -> Result<Vec<String>>;
+}
+
+static BACKEND: Lazy<Option<Box<dyn SearchBackend>>> = Lazy::new(create_backend);
+
+/// Create the search backend from environment configuration
+fn create_backend() -> Option<Box<dyn SearchBackend>> {
+    match SEARCH_BACKEND.as_deref() {
+        Some("meilisearch") => Some(Box::new(Meilisearch::new(
+            &SEARCH_URL,
+            &SEARCH_INDEX,
+            SEARCH_KEY.clone(),
+        ))),
+        Some("elasticsearch") => Some(Box::new(Elasticsearch::new(
+            &SEARCH_URL,
+            &SEARCH_INDEX,
+            SEARCH_KEY.clone(),
+        ))),
+        Some(other) => {
+            warn!("Unknown search backend `{other}`, falling back to database search.");
+            None
+        }
+        None => None,
+    }
+}
+
+/// Get the configured search backend, if any
+pub fn backend() -> Option<&'static dyn SearchBackend> {
+    BACKEND.as_deref()
+}
+
+/// Derive the creation time of a message from its id
+pub fn timestamp_of(id: &str) -> Option<u64> {
+    Ulid::from_string(id).ok().map(|id| id.timestamp_ms())
+}
+
+/// Fetch messages by given query, using the search backend for text queries if configured
+pub async fn fetch_messages(db: &Database, query: MessageQuery) -> Result<Vec<Message>> {
+    let backend = match backend() {
+        Some(backend) if query.filter.query.is_some() => backend,
+        _ => return db.fetch_messages(query).await,
+    };
+
+    let (before, after, sort) = match query.time_period {
+        MessageTimePeriod::Absolute {
+            before,
+            after,
+            sort,
+        } => (before, after, sort),
+        // Searching around a message is left to the database.
+        MessageTimePeriod::Relative { .. } => return db.fetch_messages(query).await,
+    };
+
+    let ids = backend
+        .search(SearchQuery {
+            query: query.filter.query.as_deref().unwrap_or_default(),
+            filter: &query.filter,
+            before: before.as_deref().and_then(timestamp_of),
+            after: after.as_deref().and_then(timestamp_of),
+            sort: sort.unwrap_or_default(),
+            limit: query.limit.unwrap_or(50),
+        })
+        .await?;
+
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Messages deleted from the database may linger in the index,
+    // these simply drop out here as they can no longer be found.
+    let mut messages = db.fetch_messages_by_id(&ids).await?;
+    messages.sort_by_key(|message| ids.iter().position(|id| id == &message.id));
+    Ok(messages)
+}
@@ -5,7 +5,7 @@ use deadqueue::limited::Queue;
 use once_cell::sync::Lazy;
 use std::{collections::HashMap, time::Duration};
 
-use super::DelayedTask;
+use super::{DelayedTask, FlushMetrics};
 
 /// Enumeration of possible events
 #[derive(Debug, Eq, PartialEq)]
@@ -40,14 +40,20 @@ struct Task {
 
 static Q: Lazy<Queue<Data>> = Lazy::new(|| Queue::new(10_000));
 
+/// Counters for acks written to the database
+pub static METRICS: FlushMetrics = FlushMetrics::new();
+
 /// Queue a new task for a worker
 pub async fn queue(channel: String, user: String, event: AckEvent) {
-    Q.try_push(Data {
+    if Q.try_push(Data {
         channel,
         user,
         event,
     })
-    .ok();
+    .is_ok()
+    {
+        METRICS.record_buffered();
+    }
 
     info!("Queue is using {} slots from {}.", Q.len(), Q.capacity());
 }
@@ -66,6 +72,7 @@ pub async fn worker(db: Database) {
         }
 
         // Commit any due tasks to the database.
+        let mut failures = 0;
         for key in &keys {
             if let Some(task) = tasks.remove(key) {
                 let Task { event } = task.data;
@@ -78,12 +85,17 @@ pub async fn worker(db: Database) {
                     }
                 } {
                     error!("{err:?} for {event:?}. ({user}, {channel})");
+                    failures += 1;
                 } else {
                     info!("User {user} ack in {channel} with {event:?}");
                 }
             }
         }
 
+        if !keys.is_empty() {
+            METRICS.record_flush(keys.len() as u64, failures);
+        }
+
         // Clear keys
         keys.clear();
 
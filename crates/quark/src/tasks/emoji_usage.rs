@@ -0,0 +1,43 @@
+// Queue Type: Batched
+use crate::Database;
+
+use deadqueue::limited::Queue;
+use once_cell::sync::Lazy;
+use std::{collections::HashMap, time::Duration};
+
+/// How often counters are committed to the database, in seconds
+static FLUSH_INTERVAL: u64 = 30;
+
+static Q: Lazy<Queue<String>> = Lazy::new(|| Queue::new(10_000));
+
+/// Queue a use of a custom emoji
+pub async fn queue(emoji: String) {
+    Q.try_push(emoji).ok();
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    let mut counts = HashMap::<String, i64>::new();
+    let mut elapsed = 0;
+
+    loop {
+        // Tally incoming uses.
+        while let Some(emoji) = Q.try_pop() {
+            *counts.entry(emoji).or_default() += 1;
+        }
+
+        // Commit counters to the database.
+        if elapsed >= FLUSH_INTERVAL {
+            for (emoji, count) in counts.drain() {
+                if let Err(err) = db.increment_emoji_usage(&emoji, count).await {
+                    error!("Failed to update usage of emoji {emoji} with {err:?}!");
+                }
+            }
+
+            elapsed = 0;
+        }
+
+        async_std::task::sleep(Duration::from_secs(1)).await;
+        elapsed += 1;
+    }
+}
@@ -0,0 +1,63 @@
+// Queue Type: Periodic
+use crate::{
+    events::client::EventV1,
+    models::{server_member::RemovalIntention, User},
+    Database, Error, Result,
+};
+
+use authifier::AuthifierEvent;
+use std::time::Duration;
+
+/// Time between each sweep for expired guests
+const INTERVAL: Duration = Duration::from_secs(60);
+
+/// Remove an expired guest from their server and sign them out
+async fn expire(db: &Database, sessions: &authifier::Database, mut user: User) -> Result<()> {
+    if let Some(guest) = &user.guest {
+        if let Ok(member) = db.fetch_member(&guest.server, &user.id).await {
+            db.fetch_server(&guest.server)
+                .await?
+                .remove_member(db, member, RemovalIntention::Leave, true)
+                .await?;
+        }
+    }
+
+    sessions
+        .delete_all_sessions(&user.id, None)
+        .await
+        .map_err(|_| Error::DatabaseError {
+            operation: "delete_many",
+            with: "sessions",
+        })?;
+
+    EventV1::Auth(AuthifierEvent::DeleteAllSessions {
+        user_id: user.id.clone(),
+        exclude_session_id: None,
+    })
+    .private(user.id.clone())
+    .await;
+
+    user.mark_deleted(db).await
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    let sessions: authifier::Database = db.clone().into();
+
+    loop {
+        match db.fetch_expired_guests().await {
+            Ok(guests) => {
+                for user in guests {
+                    let id = user.id.clone();
+                    match expire(&db, &sessions, user).await {
+                        Ok(_) => info!("Expired guest {id}."),
+                        Err(err) => error!("Failed to expire guest {id} with {err:?}!"),
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch expired guests with {err:?}!"),
+        }
+
+        async_std::task::sleep(INTERVAL).await;
+    }
+}
@@ -0,0 +1,33 @@
+// Queue Type: Periodic
+use crate::{util::variables::delta::MESSAGE_RECOVERY_WINDOW, Database, Result};
+
+use iso8601_timestamp::Timestamp;
+use std::time::{Duration, SystemTime};
+
+/// Time between each sweep for messages past their recovery window
+const INTERVAL: Duration = Duration::from_secs(60);
+
+/// Purge messages past their recovery window, keeping any under a legal hold
+async fn run(db: &Database) -> Result<usize> {
+    let cutoff = SystemTime::now()
+        .checked_sub(Duration::from_secs(*MESSAGE_RECOVERY_WINDOW))
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let held = db.fetch_held_ids().await?;
+    db.purge_deleted_messages(Timestamp::from(cutoff), &held)
+        .await
+        .map(|messages| messages.len())
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match run(&db).await {
+            Ok(0) => {}
+            Ok(count) => info!("Purged {count} deleted messages."),
+            Err(err) => error!("Failed to purge deleted messages with {err:?}!"),
+        }
+
+        async_std::task::sleep(INTERVAL).await;
+    }
+}
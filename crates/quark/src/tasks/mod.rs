@@ -1,15 +1,25 @@
 //! Semi-important background task management
 
-use crate::Database;
+use crate::{models::stats::WriteBufferStats, Database};
 
 use async_std::task;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
 const WORKER_COUNT: usize = 5;
 
 pub mod ack;
+pub mod emoji_usage;
+pub mod guest_expiry;
 pub mod last_message_id;
+pub mod message_purge;
+pub mod monitor_snapshots;
 pub mod process_embeds;
+pub mod reactions;
+pub mod search_index;
+pub mod server_stats;
+pub mod stats_snapshot;
 pub mod web_push;
 
 /// Spawn background workers
@@ -20,6 +30,15 @@ pub async fn start_workers(db: Database) {
         task::spawn(process_embeds::worker(db.clone()));
         task::spawn(web_push::worker(db.clone().into()));
     }
+
+    task::spawn(emoji_usage::worker(db.clone()));
+    task::spawn(guest_expiry::worker(db.clone()));
+    task::spawn(message_purge::worker(db.clone()));
+    task::spawn(reactions::worker(db.clone()));
+    task::spawn(search_index::worker());
+    task::spawn(server_stats::worker(db.clone()));
+    task::spawn(stats_snapshot::worker(db.clone()));
+    task::spawn(monitor_snapshots::worker(db));
 }
 
 /// Task with additional information on when it should run
@@ -56,3 +75,68 @@ impl<T> DelayedTask<T> {
             || self.last_updated.elapsed().as_secs() > SAVE_CONSTANT
     }
 }
+
+/// Counters describing how a write-behind buffer is flushed
+pub struct FlushMetrics {
+    buffered: AtomicU64,
+    flushes: AtomicU64,
+    writes: AtomicU64,
+    failures: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl FlushMetrics {
+    /// Create a new set of counters
+    pub const fn new() -> Self {
+        FlushMetrics {
+            buffered: AtomicU64::new(0),
+            flushes: AtomicU64::new(0),
+            writes: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a change being accepted into the buffer
+    pub fn record_buffered(&self) {
+        self.buffered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a change being dropped because the buffer was full
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a flush and the writes it made
+    pub fn record_flush(&self, writes: u64, failures: u64) {
+        self.flushes.fetch_add(1, Ordering::Relaxed);
+        self.writes.fetch_add(writes, Ordering::Relaxed);
+        self.failures.fetch_add(failures, Ordering::Relaxed);
+    }
+
+    /// Take a snapshot of the current counters
+    pub fn snapshot(&self) -> WriteBufferStats {
+        WriteBufferStats {
+            buffered: self.buffered.load(Ordering::Relaxed),
+            flushes: self.flushes.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for FlushMetrics {
+    fn default() -> Self {
+        FlushMetrics::new()
+    }
+}
+
+/// Collect counters for every write-behind buffer in this process
+pub fn write_buffer_stats() -> HashMap<String, WriteBufferStats> {
+    HashMap::from([
+        ("acks".to_string(), ack::METRICS.snapshot()),
+        ("reactions".to_string(), reactions::METRICS.snapshot()),
+        ("search_index".to_string(), search_index::METRICS.snapshot()),
+    ])
+}
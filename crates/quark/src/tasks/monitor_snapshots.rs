@@ -0,0 +1,44 @@
+// Queue Type: Periodic
+use crate::{
+    models::Snapshot,
+    util::variables::delta::{MONITOR_SNAPSHOT_INTERVAL, MONITOR_SNAPSHOT_RETENTION},
+    Database, Result,
+};
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ulid::Ulid;
+
+/// Snapshot every monitored server and prune old snapshots
+async fn run(db: &Database) -> Result<()> {
+    for id in db.fetch_monitored_servers().await? {
+        match db.fetch_server(&id).await {
+            Ok(server) => Snapshot::monitor_server(db, server).await?,
+            Err(err) => warn!("Failed to fetch monitored server {id} with {err:?}!"),
+        }
+    }
+
+    // Snapshots attached to reports are kept indefinitely
+    let cutoff = SystemTime::now()
+        .checked_sub(Duration::from_secs(
+            *MONITOR_SNAPSHOT_RETENTION * 60 * 60 * 24,
+        ))
+        .unwrap_or(UNIX_EPOCH)
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    db.delete_unreported_snapshots_before(&Ulid::from_parts(cutoff, 0).to_string())
+        .await
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match run(&db).await {
+            Ok(_) => info!("Took snapshots of monitored servers."),
+            Err(err) => error!("Failed to snapshot monitored servers with {err:?}!"),
+        }
+
+        async_std::task::sleep(Duration::from_secs(*MONITOR_SNAPSHOT_INTERVAL * 60 * 60)).await;
+    }
+}
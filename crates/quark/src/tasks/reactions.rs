@@ -0,0 +1,228 @@
+// Queue Type: Batched
+use crate::{events::client::EventV1, Database};
+
+use deadqueue::limited::Queue;
+use indexmap::{IndexMap, IndexSet};
+use once_cell::sync::Lazy;
+use std::time::Duration;
+
+use super::FlushMetrics;
+
+/// How long reaction changes are collected for before being written and published, in milliseconds
+static BATCH_WINDOW: u64 = 250;
+
+/// Maximum number of distinct emojis on a single message
+pub static MAX_REACTIONS: usize = 20;
+
+/// Users added or removed per emoji
+type ReactionUsers = IndexMap<String, IndexSet<String>>;
+
+/// Change to the reactions on a message
+#[derive(Debug)]
+pub enum ReactionChange {
+    /// User reacted with an emoji
+    React { user_id: String, emoji_id: String },
+    /// User removed their reaction
+    Unreact { user_id: String, emoji_id: String },
+    /// Emoji was removed from the message entirely
+    Clear { emoji_id: String },
+}
+
+/// Task information
+struct Data {
+    /// Message Id
+    id: String,
+    /// Channel the message is in
+    channel: String,
+    /// Change to apply
+    change: ReactionChange,
+}
+
+/// Reaction changes collected for a single message
+struct Batch {
+    /// Channel the message is in
+    channel: String,
+    /// Emojis cleared from the message
+    cleared: Vec<String>,
+    /// Latest state of each (emoji, user) pair, true if reacted
+    changes: IndexMap<(String, String), bool>,
+}
+
+static Q: Lazy<Queue<Data>> = Lazy::new(|| Queue::new(10_000));
+
+/// Counters for reactions written to the database
+pub static METRICS: FlushMetrics = FlushMetrics::new();
+
+impl Batch {
+    fn new(channel: String) -> Batch {
+        Batch {
+            channel,
+            cleared: vec![],
+            changes: IndexMap::new(),
+        }
+    }
+
+    /// Fold a change into this batch, later changes win
+    fn push(&mut self, change: ReactionChange) {
+        match change {
+            ReactionChange::React { user_id, emoji_id } => {
+                self.changes.insert((emoji_id, user_id), true);
+            }
+            ReactionChange::Unreact { user_id, emoji_id } => {
+                self.changes.insert((emoji_id, user_id), false);
+            }
+            ReactionChange::Clear { emoji_id } => {
+                self.changes.retain(|(emoji, _), _| emoji != &emoji_id);
+                if !self.cleared.contains(&emoji_id) {
+                    self.cleared.push(emoji_id);
+                }
+            }
+        }
+    }
+
+    /// Check whether this batch has nothing left to publish
+    fn is_empty(&self) -> bool {
+        self.cleared.is_empty() && self.changes.is_empty()
+    }
+
+    /// Group changes into users added and removed per emoji
+    fn grouped(&self) -> (ReactionUsers, ReactionUsers) {
+        let mut added = ReactionUsers::new();
+        let mut removed = ReactionUsers::new();
+        for ((emoji, user), reacted) in &self.changes {
+            let target = if *reacted { &mut added } else { &mut removed };
+            target
+                .entry(emoji.clone())
+                .or_default()
+                .insert(user.clone());
+        }
+
+        (added, removed)
+    }
+
+    /// Write this batch to the database
+    ///
+    /// Reactions which could not be added are dropped from the batch.
+    async fn write(&mut self, db: &Database, id: &str) -> bool {
+        let (added, removed) = self.grouped();
+        match db
+            .apply_reactions(id, &self.cleared, &added, &removed)
+            .await
+        {
+            Ok(rejected) => {
+                self.changes
+                    .retain(|(emoji, _), reacted| !*reacted || !rejected.contains(emoji));
+                true
+            }
+            Err(err) => {
+                error!("{err:?} writing reactions to {id}.");
+                false
+            }
+        }
+    }
+
+    /// Produce the smallest event describing this batch
+    fn into_event(mut self, id: String) -> EventV1 {
+        let channel_id = self.channel;
+
+        // Lone changes are sent as they always have been.
+        if self.cleared.is_empty() && self.changes.len() == 1 {
+            let ((emoji_id, user_id), reacted) = self.changes.pop().unwrap();
+            return if reacted {
+                EventV1::MessageReact {
+                    id,
+                    channel_id,
+                    user_id,
+                    emoji_id,
+                }
+            } else {
+                EventV1::MessageUnreact {
+                    id,
+                    channel_id,
+                    user_id,
+                    emoji_id,
+                }
+            };
+        }
+
+        if self.cleared.len() == 1 && self.changes.is_empty() {
+            return EventV1::MessageRemoveReaction {
+                id,
+                channel_id,
+                emoji_id: self.cleared.pop().unwrap(),
+            };
+        }
+
+        let (added, removed) = self.grouped();
+        EventV1::MessageReactBatch {
+            id,
+            channel_id,
+            cleared: self.cleared,
+            removed,
+            added,
+        }
+    }
+}
+
+/// Queue a reaction change to be written and published
+pub async fn queue(db: &Database, id: String, channel: String, change: ReactionChange) {
+    if let Err(Data {
+        id,
+        channel,
+        change,
+    }) = Q.try_push(Data {
+        id,
+        channel,
+        change,
+    }) {
+        // Apply immediately rather than dropping the change.
+        let mut batch = Batch::new(channel.clone());
+        batch.push(change);
+        batch.write(db, &id).await;
+        if !batch.is_empty() {
+            batch.into_event(id).p(channel).await;
+        }
+    } else {
+        METRICS.record_buffered();
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        // Collect changes made since the last window, per message.
+        let mut batches = IndexMap::<String, Batch>::new();
+        while let Some(Data {
+            id,
+            channel,
+            change,
+        }) = Q.try_pop()
+        {
+            batches
+                .entry(id)
+                .or_insert_with(|| Batch::new(channel))
+                .push(change);
+        }
+
+        // Write and publish once per message.
+        if !batches.is_empty() {
+            let writes = batches.len() as u64;
+            let mut failures = 0;
+
+            for (id, mut batch) in batches {
+                if !batch.write(&db, &id).await {
+                    failures += 1;
+                }
+
+                if !batch.is_empty() {
+                    let channel = batch.channel.clone();
+                    batch.into_event(id).p(channel).await;
+                }
+            }
+
+            METRICS.record_flush(writes, failures);
+        }
+
+        async_std::task::sleep(Duration::from_millis(BATCH_WINDOW)).await;
+    }
+}
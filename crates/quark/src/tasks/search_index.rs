@@ -0,0 +1,218 @@
+use crate::models::message::{MessageFilter, MessageQuery, MessageSort, MessageTimePeriod};
+use crate::models::Message;
+use crate::search::{backend, SearchDocument};
+use crate::tasks::FlushMetrics;
+use crate::{Database, Error, Result};
+
+use deadqueue::limited::Queue;
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Maximum number of operations to send to the search backend at once
+const BATCH_SIZE: usize = 100;
+
+/// Counters for the index queue
+pub static METRICS: FlushMetrics = FlushMetrics::new();
+
+/// Whether a reindex is currently running
+static REINDEXING: AtomicBool = AtomicBool::new(false);
+
+/// Task information
+#[derive(Debug)]
+enum IndexTask {
+    /// Add or replace a message in the index
+    Index(SearchDocument),
+    /// Remove messages from the index
+    Remove(Vec<String>),
+}
+
+static Q: Lazy<Queue<IndexTask>> = Lazy::new(|| Queue::new(10_000));
+
+/// Push a task onto the queue, counting it as dropped if the queue is full
+fn push(task: IndexTask) {
+    if Q.try_push(task).is_ok() {
+        METRICS.record_buffered();
+    } else {
+        METRICS.record_dropped();
+        warn!("Search index queue is full, dropping task.");
+    }
+
+    debug!("Queue is using {} slots from {}.", Q.len(), Q.capacity());
+}
+
+/// Queue a message to be (re-)indexed
+pub async fn queue_index(message: &Message) {
+    if backend().is_none() {
+        return;
+    }
+
+    match SearchDocument::from_message(message) {
+        Some(document) => push(IndexTask::Index(document)),
+        // Message no longer has any content to search for
+        None => push(IndexTask::Remove(vec![message.id.clone()])),
+    }
+}
+
+/// Queue messages to be removed from the index
+pub async fn queue_remove(ids: Vec<String>) {
+    if backend().is_none() {
+        return;
+    }
+
+    push(IndexTask::Remove(ids));
+}
+
+/// Start indexing every message in the database, oldest first
+///
+/// Used to backfill the index after enabling a backend or to recover from
+/// dropped tasks. Only one reindex may run at a time.
+pub fn start_reindex(db: Database) -> Result<()> {
+    let Some(backend) = backend() else {
+        return Err(Error::InvalidOperation);
+    };
+
+    if REINDEXING.swap(true, Ordering::SeqCst) {
+        return Err(Error::InvalidOperation);
+    }
+
+    async_std::task::spawn(async move {
+        let mut after = None;
+        let mut indexed = 0;
+
+        loop {
+            let messages = match db
+                .fetch_messages(MessageQuery {
+                    limit: Some(BATCH_SIZE as i64),
+                    filter: MessageFilter::default(),
+                    time_period: MessageTimePeriod::Absolute {
+                        before: None,
+                        after: after.clone(),
+                        sort: Some(MessageSort::Oldest),
+                    },
+                    consistent: false,
+                })
+                .await
+            {
+                Ok(messages) => messages,
+                Err(err) => {
+                    error!("Failed to fetch messages for reindex: {:?}", err);
+                    break;
+                }
+            };
+
+            let Some(last) = messages.last() else {
+                break;
+            };
+
+            after = Some(last.id.clone());
+
+            let documents: Vec<SearchDocument> = messages
+                .iter()
+                .filter_map(SearchDocument::from_message)
+                .collect();
+
+            if !documents.is_empty() {
+                if let Err(err) = backend.index(&documents).await {
+                    error!("Encountered an error reindexing messages: {:?}", err);
+                    break;
+                }
+            }
+
+            indexed += documents.len();
+        }
+
+        info!("Reindexed {indexed} messages.");
+        REINDEXING.store(false, Ordering::SeqCst);
+    });
+
+    Ok(())
+}
+
+/// Start a new worker
+pub async fn worker() {
+    let Some(backend) = backend() else {
+        return;
+    };
+
+    if let Err(err) = backend.prepare().await {
+        error!("Encountered an error preparing search index: {:?}", err);
+    }
+
+    loop {
+        let mut tasks = vec![Q.pop().await];
+        while tasks.len() < BATCH_SIZE {
+            match Q.try_pop() {
+                Some(task) => tasks.push(task),
+                None => break,
+            }
+        }
+
+        // Apply operations in order, batching consecutive ones of the same kind.
+        let mut documents = vec![];
+        let mut ids = vec![];
+        for task in tasks {
+            match task {
+                IndexTask::Index(document) => {
+                    if !ids.is_empty() {
+                        flush_remove(&mut ids).await;
+                    }
+
+                    documents.push(document);
+                }
+                IndexTask::Remove(mut remove) => {
+                    if !documents.is_empty() {
+                        flush_index(&mut documents).await;
+                    }
+
+                    ids.append(&mut remove);
+                }
+            }
+        }
+
+        flush_index(&mut documents).await;
+        flush_remove(&mut ids).await;
+    }
+}
+
+/// Send pending documents to the search backend
+async fn flush_index(documents: &mut Vec<SearchDocument>) {
+    if documents.is_empty() {
+        return;
+    }
+
+    if let Some(backend) = backend() {
+        let failed = if let Err(err) = backend.index(documents).await {
+            error!("Encountered an error indexing messages: {:?}", err);
+            documents.len()
+        } else {
+            0
+        };
+
+        METRICS.record_flush(documents.len() as u64, failed as u64);
+    }
+
+    documents.clear();
+}
+
+/// Send pending removals to the search backend
+async fn flush_remove(ids: &mut Vec<String>) {
+    if ids.is_empty() {
+        return;
+    }
+
+    if let Some(backend) = backend() {
+        let failed = if let Err(err) = backend.remove(ids).await {
+            error!(
+                "Encountered an error removing messages from index: {:?}",
+                err
+            );
+            ids.len()
+        } else {
+            0
+        };
+
+        METRICS.record_flush(ids.len() as u64, failed as u64);
+    }
+
+    ids.clear();
+}
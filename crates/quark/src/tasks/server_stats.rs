@@ -0,0 +1,19 @@
+// Queue Type: Periodic
+use crate::Database;
+
+use std::time::Duration;
+
+/// Time between each re-computation of server statistics
+const INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.update_server_stats().await {
+            Ok(_) => info!("Updated server statistics."),
+            Err(err) => error!("Failed to update server statistics with {err:?}!"),
+        }
+
+        async_std::task::sleep(INTERVAL).await;
+    }
+}
@@ -0,0 +1,19 @@
+// Queue Type: Periodic
+use crate::Database;
+
+use std::time::Duration;
+
+/// Time between each snapshot, the latest snapshot each day is kept
+const INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.snapshot_stats().await {
+            Ok(_) => info!("Recorded instance statistics snapshot."),
+            Err(err) => error!("Failed to record instance statistics with {err:?}!"),
+        }
+
+        async_std::task::sleep(INTERVAL).await;
+    }
+}
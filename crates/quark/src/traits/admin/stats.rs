@@ -1,6 +1,22 @@
-use crate::{models::stats::Stats, Result};
+use crate::{
+    models::stats::{ServerStats, Stats, StatsSnapshot},
+    Result,
+};
 
 #[async_trait]
 pub trait AbstractStats: Sync + Send {
-    async fn generate_stats(&self) -> Result<Stats>;
+    /// Generate current statistics along with the given number of days of history
+    async fn generate_stats(&self, days: u32) -> Result<Stats>;
+
+    /// Record today's snapshot of collection counters
+    async fn snapshot_stats(&self) -> Result<()>;
+
+    /// Fetch snapshots taken within the given number of days
+    async fn fetch_stats_history(&self, days: u32) -> Result<Vec<StatsSnapshot>>;
+
+    /// Re-compute statistics for every server
+    async fn update_server_stats(&self) -> Result<()>;
+
+    /// Fetch the last computed statistics for a server
+    async fn fetch_server_stats(&self, server: &str) -> Result<ServerStats>;
 }
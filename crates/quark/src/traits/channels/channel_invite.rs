@@ -12,6 +12,11 @@ pub trait AbstractChannelInvite: Sync + Send {
     /// Delete an invite by its id
     async fn delete_invite(&self, code: &str) -> Result<()>;
 
+    /// Count a guest account created through an invite
+    ///
+    /// Returns false if the invite grants no guest access or has no uses left.
+    async fn use_guest_invite(&self, code: &str) -> Result<bool>;
+
     /// Fetch all invites for a server
     async fn fetch_invites_for_server(&self, server: &str) -> Result<Vec<Invite>>;
 }
@@ -1,4 +1,10 @@
-use crate::models::message::{AppendMessage, Message, MessageQuery, PartialMessage};
+use indexmap::{IndexMap, IndexSet};
+use iso8601_timestamp::Timestamp;
+
+use crate::models::message::{
+    AppendMessage, ChannelMessageSync, DeletedMessage, Message, MessageQuery, MessageRevision,
+    PartialMessage,
+};
 use crate::Result;
 
 #[async_trait]
@@ -15,15 +21,59 @@ pub trait AbstractMessage: Sync + Send {
     /// Append information to a given message
     async fn append_message(&self, id: &str, append: &AppendMessage) -> Result<()>;
 
-    /// Delete a message from the database by its id
+    /// Delete a message and its previous versions from the database by its id
     async fn delete_message(&self, id: &str) -> Result<()>;
 
-    /// Delete messages from a channel by their ids and corresponding channel id
+    /// Move messages out of a channel by their ids and hold them for recovery
     async fn delete_messages(&self, channel: &str, ids: Vec<String>) -> Result<()>;
 
+    /// Move a message out of its channel and hold it for recovery
+    async fn trash_message(&self, message: &DeletedMessage) -> Result<()>;
+
+    /// Fetch a message held for recovery by its id
+    async fn fetch_deleted_message(&self, id: &str) -> Result<DeletedMessage>;
+
+    /// Fetch all messages held for recovery in a channel
+    async fn fetch_deleted_messages(&self, channel: &str) -> Result<Vec<DeletedMessage>>;
+
+    /// Move a message held for recovery back into its channel
+    async fn restore_message(&self, id: &str) -> Result<Message>;
+
+    /// Permanently delete messages held for recovery which were deleted before a given time,
+    /// skipping any sent by or in one of the given users or channels
+    async fn purge_deleted_messages(
+        &self,
+        before: Timestamp,
+        exclude: &[String],
+    ) -> Result<Vec<DeletedMessage>>;
+
+    /// Store a previous version of an edited message
+    async fn insert_message_revision(&self, revision: &MessageRevision) -> Result<()>;
+
+    /// Fetch all previous versions of a message, newest first
+    async fn fetch_message_revisions(&self, message: &str) -> Result<Vec<MessageRevision>>;
+
+    /// Fetch multiple messages by their ids
+    async fn fetch_messages_by_id(&self, ids: &[String]) -> Result<Vec<Message>>;
+
     /// Fetch multiple messages by given query
     async fn fetch_messages(&self, query: MessageQuery) -> Result<Vec<Message>>;
 
+    /// Fetch all pinned messages in a channel, newest first
+    async fn fetch_pinned_messages(&self, channel: &str) -> Result<Vec<Message>>;
+
+    /// Fetch ids of all users who sent a message in any of the given channels after a given message id
+    async fn fetch_message_authors(&self, channels: &[String], after: &str) -> Result<Vec<String>>;
+
+    /// Count messages in each of the given channels after a given message id,
+    /// including up to `limit` of the newest ids per channel
+    async fn fetch_message_sync(
+        &self,
+        channels: &[String],
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<ChannelMessageSync>>;
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()>;
 
@@ -32,4 +82,22 @@ pub trait AbstractMessage: Sync + Send {
 
     /// Remove reaction from a message
     async fn clear_reaction(&self, id: &str, emoji: &str) -> Result<()>;
+
+    /// Point reactions, reaction whitelists and content of messages using an emoji at another emoji
+    async fn migrate_emoji_references(&self, from: &str, to: &str) -> Result<()>;
+
+    /// Apply a batch of reaction changes to a message
+    ///
+    /// Emojis are cleared first, then users are added and removed.
+    /// Emojis left without any reactions are removed from the message.
+    ///
+    /// Returns emojis which were not added as the message already had the
+    /// maximum number of distinct emojis.
+    async fn apply_reactions(
+        &self,
+        id: &str,
+        cleared: &[String],
+        added: &IndexMap<String, IndexSet<String>>,
+        removed: &IndexMap<String, IndexSet<String>>,
+    ) -> Result<Vec<String>>;
 }
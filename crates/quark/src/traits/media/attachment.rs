@@ -3,6 +3,10 @@ use crate::Result;
 
 #[async_trait]
 pub trait AbstractAttachment: Sync + Send {
+    /// Find an attachment by its details which is not yet used by a given parent type.
+    async fn fetch_unused_attachment(&self, id: &str, tag: &str, parent_type: &str)
+        -> Result<File>;
+
     /// Find an attachment by its details and mark it as used by a given parent.
     async fn find_and_use_attachment(
         &self,
@@ -1,4 +1,4 @@
-use crate::models::Emoji;
+use crate::models::{emoji::EmojiUsage, Emoji};
 use crate::Result;
 
 #[async_trait]
@@ -15,6 +15,18 @@ pub trait AbstractEmoji: Sync + Send {
     /// Insert emoji into database.
     async fn insert_emoji(&self, emoji: &Emoji) -> Result<()>;
 
+    /// Fetch usage counters for the given emoji
+    async fn fetch_emoji_usage(&self, ids: &[String]) -> Result<Vec<EmojiUsage>>;
+
+    /// Record that an emoji has been used a number of times
+    async fn increment_emoji_usage(&self, id: &str, count: i64) -> Result<()>;
+
+    /// Update the name and aliases of an emoji
+    async fn update_emoji_names(&self, id: &str, name: &str, aliases: &[String]) -> Result<()>;
+
     /// Detach an emoji by its id
     async fn detach_emoji(&self, emoji: &Emoji) -> Result<()>;
+
+    /// Detach an emoji and point it at the emoji which replaced it
+    async fn replace_emoji(&self, emoji: &Emoji, replacement_id: &str) -> Result<()>;
 }
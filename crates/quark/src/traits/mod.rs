@@ -27,6 +27,7 @@ mod users {
 }
 
 mod safety {
+    pub mod legal_hold;
     pub mod report;
     pub mod snapshot;
 }
@@ -49,6 +50,7 @@ pub use users::bot::AbstractBot;
 pub use users::user::AbstractUser;
 pub use users::user_settings::AbstractUserSettings;
 
+pub use safety::legal_hold::AbstractLegalHold;
 pub use safety::report::AbstractReport;
 pub use safety::snapshot::AbstractSnapshot;
 
@@ -70,5 +72,6 @@ pub trait AbstractDatabase:
     + AbstractUserSettings
     + AbstractReport
     + AbstractSnapshot
+    + AbstractLegalHold
 {
 }
@@ -0,0 +1,20 @@
+use crate::models::LegalHold;
+use crate::Result;
+
+#[async_trait]
+pub trait AbstractLegalHold: Sync + Send {
+    /// Insert or replace a legal hold
+    async fn insert_legal_hold(&self, hold: &LegalHold) -> Result<()>;
+
+    /// Fetch a legal hold by the id of its subject
+    async fn fetch_legal_hold(&self, id: &str) -> Result<LegalHold>;
+
+    /// Fetch all legal holds
+    async fn fetch_legal_holds(&self) -> Result<Vec<LegalHold>>;
+
+    /// Fetch ids of every held user and channel
+    async fn fetch_held_ids(&self) -> Result<Vec<String>>;
+
+    /// Release a legal hold by the id of its subject
+    async fn delete_legal_hold(&self, id: &str) -> Result<()>;
+}
@@ -6,6 +6,21 @@ pub trait AbstractSnapshot: Sync + Send {
     /// Insert a new snapshot into the database
     async fn insert_snapshot(&self, snapshot: &Snapshot) -> Result<()>;
 
+    /// Fetch a snapshot by its id
+    async fn fetch_snapshot(&self, id: &str) -> Result<Snapshot>;
+
     /// Fetch a snapshots by a report's id
     async fn fetch_snapshots(&self, report_id: &str) -> Result<Vec<Snapshot>>;
+
+    /// Fetch snapshots of a given object, oldest first
+    async fn fetch_snapshots_by_object(&self, object_id: &str) -> Result<Vec<Snapshot>>;
+
+    /// Delete snapshots without a parent report taken before the given id
+    async fn delete_unreported_snapshots_before(&self, id: &str) -> Result<()>;
+
+    /// Mark a server as monitored or stop monitoring it
+    async fn set_server_monitored(&self, server_id: &str, monitored: bool) -> Result<()>;
+
+    /// Fetch ids of all monitored servers
+    async fn fetch_monitored_servers(&self) -> Result<Vec<String>>;
 }
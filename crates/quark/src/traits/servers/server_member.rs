@@ -1,4 +1,6 @@
-use crate::models::server_member::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::models::server_member::{
+    FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember,
+};
 use crate::Result;
 
 #[async_trait]
@@ -29,6 +31,17 @@ pub trait AbstractServerMember: Sync + Send {
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server: &str, ids: &'a [String]) -> Result<Vec<Member>>;
 
+    /// Fetch members of a server matching a given query, sorted by user id
+    async fn fetch_members_by_query(&self, server: &str, query: MemberQuery)
+        -> Result<Vec<Member>>;
+
+    /// Fetch members of a server who want to be notified about every message in a channel
+    async fn fetch_notified_members(&self, server: &str, channel: &str) -> Result<Vec<Member>>;
+
+    /// Fetch members of a server who hold any of the given roles
+    async fn fetch_members_with_roles(&self, server: &str, roles: &[String])
+        -> Result<Vec<Member>>;
+
     /// Fetch member count of a server
     async fn fetch_member_count(&self, server: &str) -> Result<usize>;
 
@@ -1,4 +1,4 @@
-use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, User};
+use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus, SessionScope, User};
 use crate::Result;
 
 #[async_trait]
@@ -12,6 +12,9 @@ pub trait AbstractUser: Sync + Send {
     /// Fetch a user from the database by their session token
     async fn fetch_user_by_token(&self, token: &str) -> Result<User>;
 
+    /// Fetch the restrictions placed on a session
+    async fn fetch_session_scopes(&self, session_id: &str) -> Result<Vec<SessionScope>>;
+
     /// Insert a new user into the database
     async fn insert_user(&self, user: &User) -> Result<()>;
 
@@ -29,6 +32,12 @@ pub trait AbstractUser: Sync + Send {
     /// Fetch multiple users by their ids
     async fn fetch_users<'a>(&self, ids: &'a [String]) -> Result<Vec<User>>;
 
+    /// Fetch guest users which have expired and are not yet deleted
+    async fn fetch_expired_guests(&self) -> Result<Vec<User>>;
+
+    /// Fetch ids of users who are not bots or deleted, in order, after a given id
+    async fn fetch_user_ids_after(&self, after: Option<&str>, limit: i64) -> Result<Vec<String>>;
+
     /// Check whether a username is already in use by another user
     async fn is_username_taken(&self, username: &str) -> Result<bool>;
 
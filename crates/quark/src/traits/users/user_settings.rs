@@ -1,4 +1,7 @@
-use crate::models::UserSettings;
+use crate::models::{
+    user_settings::{DmLayout, ServerLayout},
+    UserSettings,
+};
 use crate::Result;
 
 #[async_trait]
@@ -9,6 +12,18 @@ pub trait AbstractUserSettings: Sync + Send {
     /// Update a subset of user settings
     async fn set_user_settings(&self, id: &str, settings: &UserSettings) -> Result<()>;
 
+    /// Fetch a user's server layout
+    async fn fetch_server_layout(&self, id: &str) -> Result<ServerLayout>;
+
+    /// Replace a user's server layout
+    async fn set_server_layout(&self, layout: &ServerLayout) -> Result<()>;
+
+    /// Fetch a user's DM layout
+    async fn fetch_dm_layout(&self, id: &str) -> Result<DmLayout>;
+
+    /// Replace a user's DM layout
+    async fn set_dm_layout(&self, layout: &DmLayout) -> Result<()>;
+
     /// Delete all user settings
     async fn delete_user_settings(&self, id: &str) -> Result<()>;
 }
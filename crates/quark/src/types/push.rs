@@ -4,6 +4,7 @@ use revolt_models::v0::Webhook;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{Message, User};
+use crate::util::i18n;
 use crate::variables::delta::{APP_URL, AUTUMN_URL, PUBLIC_URL};
 
 /// Push Notification
@@ -56,7 +57,13 @@ impl<'a> MessageAuthor<'a> {
 
 impl PushNotification {
     /// Create a new notification from a given message, author and channel ID
-    pub fn new(msg: Message, author: Option<MessageAuthor<'_>>, channel_id: &str) -> Self {
+    /// with any server-generated text rendered in the given locale
+    pub fn new(
+        msg: Message,
+        author: Option<&MessageAuthor<'_>>,
+        channel_id: &str,
+        locale: Option<&str>,
+    ) -> Self {
         let icon = if let Some(author) = &author {
             if let Some(avatar) = author.avatar() {
                 format!("{}/avatars/{}", &*AUTUMN_URL, avatar)
@@ -74,11 +81,11 @@ impl PushNotification {
         });
 
         let body = if let Some(sys) = msg.system {
-            sys.into()
+            sys.render(locale)
         } else if let Some(text) = msg.content {
             text
         } else {
-            "Empty Message".to_string()
+            i18n::translate(locale, "push.empty_message", &[])
         };
 
         let timestamp = SystemTime::now()
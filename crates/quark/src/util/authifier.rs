@@ -1,5 +1,6 @@
 use authifier::config::{ResolveIp, Shield};
 
+use super::i18n;
 use super::variables::delta::{
     APP_URL, AUTHIFIER_SHIELD_KEY, HCAPTCHA_KEY, INVITE_ONLY, SMTP_FROM, SMTP_HOST, SMTP_PASSWORD,
     SMTP_USERNAME, USE_EMAIL, USE_HCAPTCHA,
@@ -25,19 +26,19 @@ pub fn config() -> Config {
                 expiry: Default::default(),
                 templates: Templates {
                     verify: Template {
-                        title: "Verify your Revolt account.".into(),
+                        title: i18n::translate(None, "email.verify.title", &[]),
                         text: include_str!(crate::asset!("templates/verify.txt")).into(),
                         url: format!("{}/login/verify/", *APP_URL),
                         html: Some(include_str!(crate::asset!("templates/verify.html")).into()),
                     },
                     reset: Template {
-                        title: "Reset your Revolt password.".into(),
+                        title: i18n::translate(None, "email.reset.title", &[]),
                         text: include_str!(crate::asset!("templates/reset.txt")).into(),
                         url: format!("{}/login/reset/", *APP_URL),
                         html: Some(include_str!(crate::asset!("templates/reset.html")).into()),
                     },
                     deletion: Template {
-                        title: "Confirm account deletion.".into(),
+                        title: i18n::translate(None, "email.deletion.title", &[]),
                         text: include_str!(crate::asset!("templates/deletion.txt")).into(),
                         url: format!("{}/delete/", *APP_URL),
                         html: Some(include_str!(crate::asset!("templates/deletion.html")).into()),
@@ -0,0 +1,56 @@
+//! Read-your-writes consistency when reading from replicas
+//!
+//! When `REVOLT_READ_REPLICAS` is enabled, read-heavy queries such as message
+//! history may be served by secondaries lagging behind the primary by up to
+//! `REVOLT_READ_REPLICA_MAX_STALENESS` seconds. Users who have written within
+//! that window are routed to the primary so they always see their own changes.
+//!
+//! Writes are tracked per process, so requests from the same user should be
+//! routed to the same instance for this to hold across a deployment.
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+use crate::variables::delta::{READ_REPLICAS, READ_REPLICA_MAX_STALENESS};
+
+/// Number of tracked users after which expired entries are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Time of the most recent write made by each user
+static RECENT_WRITES: Lazy<Mutex<HashMap<String, Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Time for which replicas may not have caught up with a write
+fn window() -> Duration {
+    Duration::from_secs(*READ_REPLICA_MAX_STALENESS)
+}
+
+/// Record that a user has just written data
+pub fn record_write(user_id: &str) {
+    if !*READ_REPLICAS {
+        return;
+    }
+
+    let mut writes = RECENT_WRITES.lock().unwrap();
+    if writes.len() >= PRUNE_THRESHOLD {
+        let window = window();
+        writes.retain(|_, at| at.elapsed() < window);
+    }
+
+    writes.insert(user_id.to_string(), Instant::now());
+}
+
+/// Check whether reads made for a user must go to the primary
+pub fn requires_primary(user_id: &str) -> bool {
+    if !*READ_REPLICAS {
+        return true;
+    }
+
+    RECENT_WRITES
+        .lock()
+        .unwrap()
+        .get(user_id)
+        .map_or(false, |at| at.elapsed() < window())
+}
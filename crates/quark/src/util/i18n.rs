@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use super::variables::delta::DEFAULT_LOCALE;
+
+/// Locale used when nothing else matches
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// Translation catalog, mapping keys to templated strings
+type Catalog = HashMap<String, String>;
+
+/// All bundled translation catalogs
+static CATALOGS: Lazy<HashMap<&'static str, Catalog>> = Lazy::new(|| {
+    [
+        ("en", include_str!(crate::asset!("locales/en.json"))),
+        ("de", include_str!(crate::asset!("locales/de.json"))),
+        ("es", include_str!(crate::asset!("locales/es.json"))),
+        ("fr", include_str!(crate::asset!("locales/fr.json"))),
+    ]
+    .into_iter()
+    .map(|(locale, source)| {
+        (
+            locale,
+            serde_json::from_str(source).expect("valid translation catalog"),
+        )
+    })
+    .collect()
+});
+
+/// Find the bundled locale matching a given locale tag
+///
+/// Tags such as `de-AT` fall back to their language (`de`).
+fn find(locale: &str) -> Option<&'static str> {
+    let locale = locale.trim().replace('_', "-").to_lowercase();
+    let language = locale.split('-').next().unwrap_or_default();
+
+    CATALOGS
+        .keys()
+        .find(|key| **key == locale)
+        .or_else(|| CATALOGS.keys().find(|key| **key == language))
+        .copied()
+}
+
+/// Check whether a locale tag resolves to a bundled catalog
+pub fn is_supported(locale: &str) -> bool {
+    find(locale).is_some()
+}
+
+/// Resolve the locale to render text in, falling back to the instance default
+pub fn resolve(locale: Option<&str>) -> &'static str {
+    locale
+        .and_then(find)
+        .or_else(|| find(&DEFAULT_LOCALE))
+        .unwrap_or(FALLBACK_LOCALE)
+}
+
+/// Translate a key into the given locale, substituting `{name}` style arguments
+///
+/// Missing keys fall back to English and then to the key itself.
+pub fn translate(locale: Option<&str>, key: &str, args: &[(&str, &str)]) -> String {
+    let template = CATALOGS
+        .get(resolve(locale))
+        .and_then(|catalog| catalog.get(key))
+        .or_else(|| {
+            CATALOGS
+                .get(FALLBACK_LOCALE)
+                .and_then(|catalog| catalog.get(key))
+        })
+        .map(String::as_str)
+        .unwrap_or(key);
+
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
@@ -1,9 +1,13 @@
 pub mod authifier;
+pub mod consistency;
+pub mod i18n;
 pub mod log;
 pub mod manipulation;
 pub mod pfp;
+pub mod pipeline;
 pub mod r#ref;
 pub mod regex;
 pub mod result;
+pub mod spam;
 pub mod value;
 pub mod variables;
@@ -0,0 +1,80 @@
+//! Message send pipeline
+//!
+//! Every message passes through validation, channel policy, spam scoring,
+//! attachment resolution, filters, mention parsing, persistence and fan-out
+//! in that order. Instance operators can register their own [`MessageFilter`]
+//! to inspect, rewrite or reject messages without having to fork the send path.
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    models::{Channel, Message},
+    types::push::MessageAuthor,
+    util::spam::SpamScore,
+    Database, Result,
+};
+
+/// Custom step in the message send pipeline
+#[async_trait]
+pub trait MessageFilter: Send + Sync {
+    /// Inspect or modify a message before mentions are parsed and it is stored
+    ///
+    /// Attachments and embeds have been resolved at this point, replies have not.
+    /// The spam score is computed from the message as it was first received.
+    /// Return an error, such as [`crate::Error::MessageRejected`], to reject the message.
+    async fn filter(
+        &self,
+        _db: &Database,
+        _channel: &Channel,
+        _author: &MessageAuthor<'_>,
+        _message: &mut Message,
+        _spam: &SpamScore,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called once the message has been stored and fanned out
+    async fn after_send(
+        &self,
+        _db: &Database,
+        _channel: &Channel,
+        _message: &Message,
+        _spam: &SpamScore,
+    ) {
+    }
+}
+
+static FILTERS: Lazy<RwLock<Vec<Arc<dyn MessageFilter>>>> = Lazy::new(Default::default);
+
+/// Register a new filter, filters run in the order they were registered
+pub fn register_filter<F: MessageFilter + 'static>(filter: F) {
+    FILTERS.write().unwrap().push(Arc::new(filter));
+}
+
+/// Take a snapshot of registered filters so the lock is not held across awaits
+fn filters() -> Vec<Arc<dyn MessageFilter>> {
+    FILTERS.read().unwrap().clone()
+}
+
+/// Run a message through all registered filters
+pub async fn run_filters(
+    db: &Database,
+    channel: &Channel,
+    author: &MessageAuthor<'_>,
+    message: &mut Message,
+    spam: &SpamScore,
+) -> Result<()> {
+    for filter in filters() {
+        filter.filter(db, channel, author, message, spam).await?;
+    }
+
+    Ok(())
+}
+
+/// Notify all registered filters that a message was sent
+pub async fn run_after_send(db: &Database, channel: &Channel, message: &Message, spam: &SpamScore) {
+    for filter in filters() {
+        filter.after_send(db, channel, message, spam).await;
+    }
+}
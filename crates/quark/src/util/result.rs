@@ -1,3 +1,4 @@
+use revolt_models::v0::{ApiError, ApiErrorDetails, ApiFieldError, Challenge};
 use revolt_okapi::openapi3::SchemaObject;
 use revolt_rocket_okapi::revolt_okapi::openapi3;
 use rocket::{
@@ -8,7 +9,7 @@ use rocket::{
 use schemars::schema::Schema;
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
-use validator::ValidationErrors;
+use validator::{ValidationErrors, ValidationErrorsKind};
 
 use crate::{Permission, UserPermission};
 
@@ -43,7 +44,16 @@ pub enum Error {
     UnknownAttachment,
     UnknownMessage,
     CannotEditMessage,
+    ChannelLocked,
+    MediaOnlyChannel,
+    MessageRejected {
+        reason: String,
+    },
     CannotJoinCall,
+    VoiceChannelFull {
+        max: usize,
+        connected: usize,
+    },
     TooManyAttachments {
         max: usize,
     },
@@ -64,6 +74,14 @@ pub enum Error {
     },
     AlreadyInGroup,
     NotInGroup,
+    FileTypeNotAllowed {
+        content_type: String,
+    },
+    FileRegionNotAllowed,
+    ThreadAlreadyExists,
+    SlowmodeActive {
+        retry_after: u64,
+    },
 
     // ? Server related errors
     UnknownServer,
@@ -82,8 +100,13 @@ pub enum Error {
     // ? Bot related errors
     ReachedMaximumBots,
     IsBot,
+    IsNotBot,
     BotIsPrivate,
 
+    // ? Guest related errors
+    IsGuest,
+    CaptchaFailed,
+
     // ? User safety related errors
     CannotReportYourself,
 
@@ -99,6 +122,27 @@ pub enum Error {
     CannotGiveMissingPermissions,
     NotOwner,
 
+    // ? Federation related errors
+    FederationDisabled,
+    FederationBlocked,
+
+    // ? Feature flag related errors
+    FeatureDisabled,
+
+    // ? Policy related errors
+    PolicyNotAccepted {
+        policy: String,
+        version: u32,
+    },
+
+    // ? Verification related errors
+    VerificationRequired {
+        reason: String,
+    },
+
+    // ? Session related errors
+    SessionRestricted,
+
     // ? General errors
     DatabaseError {
         operation: &'static str,
@@ -111,12 +155,23 @@ pub enum Error {
     InvalidSession,
     DuplicateNonce,
     VosoUnavailable,
+    SearchUnavailable,
     NotFound,
     NoEffect,
+    TooManyRequests {
+        retry_after: u128,
+    },
+    ChallengeRequired {
+        challenge: Challenge,
+    },
     FailedValidation {
         #[serde(skip_serializing, skip_deserializing)]
         error: ValidationErrors,
     },
+    UnderMaintenance {
+        reason: Option<String>,
+        retry_after: Option<u64>,
+    },
 }
 
 impl Error {
@@ -151,15 +206,21 @@ impl Error {
     }
 }
 
+impl From<revolt_result::Error> for Error {
+    fn from(error: revolt_result::Error) -> Error {
+        Error::from_core(error)
+    }
+}
+
 /// Result type with custom Error
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
-/// HTTP response builder for Error enum
-impl<'r> Responder<'r, 'static> for Error {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
-        let status = match self {
+impl Error {
+    /// HTTP status code for this error
+    pub fn status(&self) -> Status {
+        match self {
             Error::LabelMe => Status::InternalServerError,
-            Error::Core { .. } => Status::InternalServerError,
+            Error::Core { error } => error.status(),
 
             Error::AlreadyOnboarded => Status::Forbidden,
 
@@ -176,7 +237,11 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::UnknownMessage => Status::NotFound,
             Error::UnknownAttachment => Status::BadRequest,
             Error::CannotEditMessage => Status::Forbidden,
+            Error::ChannelLocked => Status::Forbidden,
+            Error::MediaOnlyChannel => Status::BadRequest,
+            Error::MessageRejected { .. } => Status::Forbidden,
             Error::CannotJoinCall => Status::BadRequest,
+            Error::VoiceChannelFull { .. } => Status::Conflict,
             Error::TooManyAttachments { .. } => Status::BadRequest,
             Error::TooManyReplies { .. } => Status::BadRequest,
             Error::EmptyMessage => Status::UnprocessableEntity,
@@ -185,6 +250,10 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::GroupTooLarge { .. } => Status::Forbidden,
             Error::AlreadyInGroup => Status::Conflict,
             Error::NotInGroup => Status::NotFound,
+            Error::FileTypeNotAllowed { .. } => Status::BadRequest,
+            Error::FileRegionNotAllowed => Status::BadRequest,
+            Error::ThreadAlreadyExists => Status::Conflict,
+            Error::SlowmodeActive { .. } => Status::TooManyRequests,
 
             Error::UnknownServer => Status::NotFound,
             Error::InvalidRole => Status::NotFound,
@@ -198,8 +267,12 @@ impl<'r> Responder<'r, 'static> for Error {
 
             Error::ReachedMaximumBots => Status::BadRequest,
             Error::IsBot => Status::BadRequest,
+            Error::IsNotBot => Status::BadRequest,
             Error::BotIsPrivate => Status::Forbidden,
 
+            Error::IsGuest => Status::Forbidden,
+            Error::CaptchaFailed => Status::BadRequest,
+
             Error::CannotReportYourself => Status::BadRequest,
 
             Error::MissingPermission { .. } => Status::Forbidden,
@@ -209,6 +282,17 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::CannotGiveMissingPermissions => Status::Forbidden,
             Error::NotOwner => Status::Forbidden,
 
+            Error::FederationDisabled => Status::BadRequest,
+            Error::FederationBlocked => Status::Forbidden,
+
+            Error::FeatureDisabled => Status::Forbidden,
+
+            Error::PolicyNotAccepted { .. } => Status::Forbidden,
+
+            Error::VerificationRequired { .. } => Status::Forbidden,
+
+            Error::SessionRestricted => Status::Forbidden,
+
             Error::DatabaseError { .. } => Status::InternalServerError,
             Error::InternalError => Status::InternalServerError,
             Error::InvalidOperation => Status::BadRequest,
@@ -217,13 +301,230 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::InvalidSession => Status::Unauthorized,
             Error::DuplicateNonce => Status::Conflict,
             Error::VosoUnavailable => Status::BadRequest,
+            Error::SearchUnavailable => Status::ServiceUnavailable,
             Error::NotFound => Status::NotFound,
             Error::NoEffect => Status::Ok,
+            Error::TooManyRequests { .. } => Status::TooManyRequests,
+            Error::ChallengeRequired { .. } => Status::Forbidden,
             Error::FailedValidation { .. } => Status::BadRequest,
+            Error::UnderMaintenance { .. } => Status::ServiceUnavailable,
+        }
+    }
+
+    /// Build the machine-readable representation of this error
+    pub fn to_api_error(&self) -> ApiError {
+        /// Error code and details as serialised by the tagged enum
+        #[derive(Deserialize, Default)]
+        struct Tagged {
+            #[serde(rename = "type")]
+            code: String,
+            #[serde(flatten)]
+            details: ApiErrorDetails,
+        }
+
+        let (tagged, location) = match self {
+            Error::Core { error } => (
+                serde_json::to_value(&error.error_type),
+                Some(error.location.clone()),
+            ),
+            _ => (serde_json::to_value(self), None),
+        };
+
+        let Tagged { code, details } = tagged
+            .ok()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
+
+        let fields = match self {
+            Error::FailedValidation { error } => {
+                let mut fields = vec![];
+                collect_field_errors(&mut fields, "", error);
+                fields
+            }
+            _ => vec![],
+        };
+
+        let retry_after = match self {
+            Error::TooManyRequests { retry_after } => Some(*retry_after as u64),
+            Error::SlowmodeActive { retry_after } => Some(*retry_after),
+            Error::UnderMaintenance { retry_after, .. } => *retry_after,
+            Error::SearchUnavailable | Error::VosoUnavailable => Some(RETRY_UNAVAILABLE_AFTER),
+            Error::Core { error } => match error.error_type {
+                revolt_result::ErrorType::SearchUnavailable
+                | revolt_result::ErrorType::VosoUnavailable => Some(RETRY_UNAVAILABLE_AFTER),
+                revolt_result::ErrorType::SlowmodeActive { retry_after } => Some(retry_after),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        ApiError {
+            message: describe(&code, &details),
+            code,
+            details,
+            fields,
+            retry_after,
+            location,
+        }
+    }
+}
+
+/// Milliseconds clients should wait before retrying an unavailable service
+const RETRY_UNAVAILABLE_AFTER: u64 = 5000;
+
+/// Flatten nested validation errors into a list of field errors
+fn collect_field_errors(fields: &mut Vec<ApiFieldError>, prefix: &str, errors: &ValidationErrors) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{prefix}.{field}")
         };
 
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                for error in errors {
+                    fields.push(ApiFieldError {
+                        field: path.clone(),
+                        code: error.code.to_string(),
+                        message: error.message.as_ref().map(|message| message.to_string()),
+                    });
+                }
+            }
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(fields, &path, errors),
+            ValidationErrorsKind::List(errors) => {
+                for (index, errors) in errors {
+                    collect_field_errors(fields, &format!("{path}[{index}]"), errors);
+                }
+            }
+        }
+    }
+}
+
+/// Human readable description for an error code
+fn describe(code: &str, details: &ApiErrorDetails) -> String {
+    let max = details.max.unwrap_or_default();
+    match code {
+        "AlreadyOnboarded" => "You have already completed onboarding.".to_string(),
+
+        "UsernameTaken" => "This username is already taken.".to_string(),
+        "InvalidUsername" => "This username is not valid.".to_string(),
+        "UnknownUser" => "This user does not exist.".to_string(),
+        "AlreadyFriends" => "You are already friends with this user.".to_string(),
+        "AlreadySentRequest" => "You have already sent a friend request to this user.".to_string(),
+        "Blocked" => "You have blocked this user.".to_string(),
+        "BlockedByOther" => "This user has blocked you.".to_string(),
+        "NotFriends" => "You are not friends with this user.".to_string(),
+
+        "UnknownChannel" => "This channel does not exist.".to_string(),
+        "UnknownAttachment" => "This attachment does not exist or was already used.".to_string(),
+        "UnknownMessage" => "This message does not exist.".to_string(),
+        "CannotEditMessage" => "You cannot edit this message.".to_string(),
+        "ChannelLocked" => "This channel is locked.".to_string(),
+        "MediaOnlyChannel" => "Messages in this channel must include media.".to_string(),
+        "MessageRejected" => format!(
+            "This message was rejected: {}",
+            details.reason.as_deref().unwrap_or("no reason given")
+        ),
+        "CannotJoinCall" => "You cannot join this call.".to_string(),
+        "VoiceChannelFull" => format!("This voice channel is full ({max} users)."),
+        "TooManyAttachments" => format!("You cannot send more than {max} attachments."),
+        "TooManyReplies" => format!("You cannot reply to more than {max} messages."),
+        "TooManyChannels" => format!("You cannot have more than {max} channels."),
+        "TooManyEmbeds" => format!("You cannot send more than {max} embeds."),
+        "EmptyMessage" => "Messages must have content, attachments or embeds.".to_string(),
+        "PayloadTooLarge" => "This message is too long.".to_string(),
+        "CannotRemoveYourself" => "You cannot remove yourself.".to_string(),
+        "GroupTooLarge" => format!("Groups cannot have more than {max} members."),
+        "AlreadyInGroup" => "This user is already in the group.".to_string(),
+        "NotInGroup" => "This user is not in the group.".to_string(),
+        "FileTypeNotAllowed" => format!(
+            "Files of type {} cannot be sent in this channel.",
+            details.content_type.as_deref().unwrap_or("unknown")
+        ),
+        "FileRegionNotAllowed" => {
+            "Files sent in this server must be stored in its storage region.".to_string()
+        }
+        "ThreadAlreadyExists" => "A thread has already been started from this message.".to_string(),
+        "SlowmodeActive" => "You are sending messages too quickly in this channel.".to_string(),
+
+        "UnknownServer" => "This server does not exist.".to_string(),
+        "InvalidRole" => "This role does not exist.".to_string(),
+        "Banned" => "You are banned from this server.".to_string(),
+        "TooManyServers" => format!("You cannot be in more than {max} servers."),
+        "TooManyEmoji" => format!("Servers cannot have more than {max} emoji."),
+        "TooManyRoles" => format!("Servers cannot have more than {max} roles."),
+
+        "ReachedMaximumBots" => "You have reached the maximum number of bots.".to_string(),
+        "IsBot" => "This action cannot be performed by or on a bot.".to_string(),
+        "IsNotBot" => "This action can only be performed by a bot.".to_string(),
+        "BotIsPrivate" => "This bot is private.".to_string(),
+
+        "IsGuest" => "This action cannot be performed by a guest.".to_string(),
+        "CaptchaFailed" => "The captcha was missing or could not be verified.".to_string(),
+
+        "CannotReportYourself" => "You cannot report yourself.".to_string(),
+
+        "MissingPermission" | "MissingUserPermission" => format!(
+            "You are missing the {} permission.",
+            details.permission.as_deref().unwrap_or("required")
+        ),
+        "NotElevated" => "You cannot act on someone ranked above or equal to you.".to_string(),
+        "NotPrivileged" => "This action requires a privileged account.".to_string(),
+        "CannotGiveMissingPermissions" => {
+            "You cannot grant permissions you do not have.".to_string()
+        }
+        "NotOwner" => "Only the owner can perform this action.".to_string(),
+
+        "FederationDisabled" => "Federation is not enabled on this instance.".to_string(),
+        "FederationBlocked" => "This instance does not federate with the other.".to_string(),
+
+        "FeatureDisabled" => "This feature is not enabled.".to_string(),
+
+        "PolicyNotAccepted" => format!(
+            "You must accept version {} of the {} policy.",
+            details.version.unwrap_or_default(),
+            details.policy.as_deref().unwrap_or("instance")
+        ),
+
+        "VerificationRequired" => match details.reason.as_deref() {
+            Some("email") => "You must verify your email address first.".to_string(),
+            Some("account_age") => "Your account is too new to do this yet.".to_string(),
+            _ => "Your account must be verified first.".to_string(),
+        },
+
+        "SessionRestricted" => "This session is not allowed to perform this action.".to_string(),
+
+        "DatabaseError" => "A database operation failed.".to_string(),
+        "InternalError" => "An internal error occurred.".to_string(),
+        "InvalidOperation" => "This operation is not valid.".to_string(),
+        "InvalidCredentials" => "The provided credentials are incorrect.".to_string(),
+        "InvalidProperty" => "A provided property is not valid.".to_string(),
+        "InvalidSession" => "This session is not valid.".to_string(),
+        "DuplicateNonce" => "This request has already been processed.".to_string(),
+        "VosoUnavailable" => "Voice is currently unavailable.".to_string(),
+        "SearchUnavailable" => "Search is currently unavailable.".to_string(),
+        "NotFound" => "The requested resource was not found.".to_string(),
+        "NoEffect" => "This request had no effect.".to_string(),
+        "TooManyRequests" => "You are being rate limited.".to_string(),
+        "ChallengeRequired" => "Solve the challenge before retrying this action.".to_string(),
+        "FailedValidation" => "The request body failed validation.".to_string(),
+        "UnderMaintenance" => details
+            .reason
+            .clone()
+            .unwrap_or_else(|| "This instance is undergoing maintenance.".to_string()),
+
+        _ => "An unknown error occurred.".to_string(),
+    }
+}
+
+/// HTTP response builder for Error enum
+impl<'r> Responder<'r, 'static> for Error {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
+
         // Serialize the error data structure into JSON.
-        let string = serde_json::to_string(&self).unwrap();
+        let string = serde_json::to_string(&self.to_api_error()).unwrap();
 
         // Build and send the request.
         Response::build()
@@ -247,7 +548,7 @@ impl revolt_rocket_okapi::response::OpenApiResponderInner for Error {
         });
 
         let mut schema_generator = settings.into_generator();
-        let schema = schema_generator.root_schema_for::<Error>();
+        let schema = schema_generator.root_schema_for::<ApiError>();
 
         let definitions = gen.schema_generator().definitions_mut();
         for (key, value) in schema.definitions {
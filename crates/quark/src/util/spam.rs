@@ -0,0 +1,660 @@
+//! Spam scoring
+//!
+//! Every message is rated by the registered [`SpamHeuristic`]s before filters
+//! run. The combined [`SpamScore`] is handed to each
+//! [`crate::util::pipeline::MessageFilter`] so that automated moderation can
+//! act on suspicious messages. Instance operators can register their own
+//! heuristics alongside the built-in ones.
+//!
+//! [`DuplicateSuppression`] is a filter which blocks or reports the same
+//! content being posted across many channels, depending on configuration.
+//! [`SpamThreshold`] does the same for messages whose combined score is too high.
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use linkify::{LinkFinder, LinkKind};
+use once_cell::sync::Lazy;
+use redis_kiss::{get_connection, AsyncCommands};
+use ulid::Ulid;
+
+use crate::{
+    events::client::EventV1,
+    models::{
+        report::{ContentReportReason, ReportStatus, ReportedContent},
+        Channel, Message, Report,
+    },
+    types::push::MessageAuthor,
+    util::pipeline::MessageFilter,
+    variables::delta::{
+        DUPLICATE_MESSAGE_ACTION, DUPLICATE_MESSAGE_CHANNELS, SPAM_ACTION, SPAM_THRESHOLD,
+        SYSTEM_USER_ID,
+    },
+    Database, Error, Result, Timestamp,
+};
+
+/// Heuristic which rates how likely a message is to be spam
+#[async_trait]
+pub trait SpamHeuristic: Send + Sync {
+    /// Name reported alongside this heuristic's score
+    fn name(&self) -> &'static str;
+
+    /// Rate a message from 0.0, not suspicious, to 1.0, almost certainly spam
+    async fn score(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        author: &MessageAuthor<'_>,
+        message: &Message,
+    ) -> f32;
+}
+
+/// Score given by a single heuristic
+#[derive(Debug, Clone)]
+pub struct SpamSignal {
+    /// Name of the heuristic
+    pub heuristic: &'static str,
+    /// Score it gave the message
+    pub score: f32,
+}
+
+/// Combined spam score of a message
+#[derive(Debug, Clone, Default)]
+pub struct SpamScore {
+    /// Sum of every heuristic's score, capped at 1.0
+    pub score: f32,
+    /// Heuristics which found the message suspicious
+    pub signals: Vec<SpamSignal>,
+}
+
+static HEURISTICS: Lazy<RwLock<Vec<Arc<dyn SpamHeuristic>>>> = Lazy::new(|| {
+    RwLock::new(vec![
+        Arc::new(DuplicateContent),
+        Arc::new(LinkDensity),
+        Arc::new(JoinAge),
+    ])
+});
+
+/// Register a new heuristic to run after the built-in ones
+pub fn register_heuristic<H: SpamHeuristic + 'static>(heuristic: H) {
+    HEURISTICS.write().unwrap().push(Arc::new(heuristic));
+}
+
+/// Take a snapshot of registered heuristics so the lock is not held across awaits
+fn heuristics() -> Vec<Arc<dyn SpamHeuristic>> {
+    HEURISTICS.read().unwrap().clone()
+}
+
+/// Rate a message with every registered heuristic
+pub async fn score_message(
+    db: &Database,
+    channel: &Channel,
+    author: &MessageAuthor<'_>,
+    message: &Message,
+) -> SpamScore {
+    let mut result = SpamScore::default();
+    for heuristic in heuristics() {
+        let score = heuristic
+            .score(db, channel, author, message)
+            .await
+            .clamp(0.0, 1.0);
+
+        if score > 0.0 {
+            result.score += score;
+            result.signals.push(SpamSignal {
+                heuristic: heuristic.name(),
+                score,
+            });
+        }
+    }
+
+    result.score = result.score.min(1.0);
+    result
+}
+
+/// How long sent content is remembered for duplicate detection
+const DUPLICATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Hash message content so that trivial differences in case and spacing are ignored
+///
+/// This is FNV-1a rather than the standard library hasher, which is not
+/// guaranteed to agree between builds, as hashes are shared between nodes.
+fn content_hash(message: &Message) -> Option<u64> {
+    let content = message.content.as_deref()?.trim().to_lowercase();
+    if content.is_empty() {
+        return None;
+    }
+
+    Some(content.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    }))
+}
+
+/// Redis key of the sorted set of channels an author posted some content in, scored by time sent
+fn duplicate_key(author_id: &str, hash: u64) -> String {
+    format!("spam:duplicate:{author_id}:{hash:016x}")
+}
+
+/// Current time in milliseconds since the epoch
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64
+}
+
+/// Fetch the channels an author posted this message's content in within the window
+async fn recent_channels(author_id: &str, message: &Message) -> HashSet<String> {
+    let hash = match content_hash(message) {
+        Some(hash) => hash,
+        None => return HashSet::new(),
+    };
+
+    let since = now_millis() - DUPLICATE_WINDOW.as_millis() as i64;
+    if let Ok(mut conn) = get_connection().await {
+        conn.zrangebyscore::<_, _, _, Vec<String>>(duplicate_key(author_id, hash), since, "+inf")
+            .await
+            .map(|channels| channels.into_iter().collect())
+            .unwrap_or_default()
+    } else {
+        HashSet::new()
+    }
+}
+
+/// Remember that a message's content was posted in its channel
+///
+/// Only called once the message has been stored, rejected messages are never counted.
+pub async fn record_sent(message: &Message) {
+    let hash = match content_hash(message) {
+        Some(hash) => hash,
+        None => return,
+    };
+
+    if let Ok(mut conn) = get_connection().await {
+        let key = duplicate_key(&message.author, hash);
+        let now = now_millis();
+        let since = now - DUPLICATE_WINDOW.as_millis() as i64;
+
+        let _: Option<()> = conn.zadd(&key, &message.channel, now).await.ok();
+        let _: Option<()> = conn.zrembyscore(&key, "-inf", since).await.ok();
+        let _: Option<()> = conn
+            .expire(&key, DUPLICATE_WINDOW.as_secs() as usize)
+            .await
+            .ok();
+    }
+}
+
+/// Count the distinct channels an author recently posted this message's content in
+///
+/// This includes the channel the message itself was sent in.
+pub async fn duplicate_channel_count(author_id: &str, message: &Message) -> usize {
+    if content_hash(message).is_none() {
+        return 0;
+    }
+
+    let mut channels = recent_channels(author_id, message).await;
+    channels.insert(message.channel.clone());
+    channels.len()
+}
+
+/// Same content posted across several channels within a short time
+pub struct DuplicateContent;
+
+#[async_trait]
+impl SpamHeuristic for DuplicateContent {
+    fn name(&self) -> &'static str {
+        "duplicate_content"
+    }
+
+    async fn score(
+        &self,
+        _db: &Database,
+        channel: &Channel,
+        author: &MessageAuthor<'_>,
+        message: &Message,
+    ) -> f32 {
+        let other_channels = recent_channels(author.id(), message)
+            .await
+            .iter()
+            .filter(|id| id.as_str() != channel.id())
+            .count();
+
+        // Four or more other channels is almost certainly spam
+        (other_channels as f32 * 0.25).min(1.0)
+    }
+}
+
+/// Messages made up mostly of links
+pub struct LinkDensity;
+
+#[async_trait]
+impl SpamHeuristic for LinkDensity {
+    fn name(&self) -> &'static str {
+        "link_density"
+    }
+
+    async fn score(
+        &self,
+        _db: &Database,
+        _channel: &Channel,
+        _author: &MessageAuthor<'_>,
+        message: &Message,
+    ) -> f32 {
+        let content = match &message.content {
+            Some(content) => content,
+            None => return 0.0,
+        };
+
+        let mut finder = LinkFinder::new();
+        finder.kinds(&[LinkKind::Url]);
+
+        let links = finder.links(content).count();
+        if links == 0 {
+            return 0.0;
+        }
+
+        let words = content.split_whitespace().count().max(1);
+        let density = (links as f32 / words as f32).min(1.0);
+
+        // A single link on its own is common, only many links score highly
+        density * links.min(4) as f32 / 4.0
+    }
+}
+
+/// How recently a member must have joined to be considered new
+const NEW_MEMBER_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// How recently an account must have been created to be considered new
+const NEW_ACCOUNT_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Authors who only just joined the server or created their account
+pub struct JoinAge;
+
+#[async_trait]
+impl SpamHeuristic for JoinAge {
+    fn name(&self) -> &'static str {
+        "join_age"
+    }
+
+    async fn score(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        author: &MessageAuthor<'_>,
+        _message: &Message,
+    ) -> f32 {
+        let user = match author {
+            MessageAuthor::User(user) if user.bot.is_none() => user,
+            _ => return 0.0,
+        };
+
+        let mut score = 0.0;
+
+        let now = SystemTime::now();
+        let created_at = Ulid::from_string(&user.id)
+            .map(|id| UNIX_EPOCH + Duration::from_millis(id.timestamp_ms()))
+            .unwrap_or(UNIX_EPOCH);
+
+        if now.duration_since(created_at).unwrap_or_default() < NEW_ACCOUNT_AGE {
+            score += 0.2;
+        }
+
+        if let Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } = channel
+        {
+            if let Ok(member) = db.fetch_member(server, &user.id).await {
+                if *member.joined_at > *Timestamp::from(now - NEW_MEMBER_AGE) {
+                    score += 0.3;
+                }
+            }
+        }
+
+        score
+    }
+}
+
+/// Block or report the same content being posted across many channels
+///
+/// Configured through `REVOLT_DUPLICATE_MESSAGE_ACTION`, which is either
+/// `block` to reject the message or `flag` to file a report for it, and
+/// `REVOLT_DUPLICATE_MESSAGE_CHANNELS`, the number of channels the same
+/// content may be posted in within a minute.
+pub struct DuplicateSuppression;
+
+impl DuplicateSuppression {
+    /// Check whether a message is over the configured duplicate limit
+    async fn over_limit(author_id: &str, message: &Message) -> bool {
+        duplicate_channel_count(author_id, message).await > *DUPLICATE_MESSAGE_CHANNELS
+    }
+}
+
+#[async_trait]
+impl MessageFilter for DuplicateSuppression {
+    async fn filter(
+        &self,
+        _db: &Database,
+        _channel: &Channel,
+        author: &MessageAuthor<'_>,
+        message: &mut Message,
+        _spam: &SpamScore,
+    ) -> Result<()> {
+        if DUPLICATE_MESSAGE_ACTION.as_str() == "block"
+            && Self::over_limit(author.id(), message).await
+        {
+            return Err(Error::MessageRejected {
+                reason: "The same message was posted in too many channels.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn after_send(
+        &self,
+        db: &Database,
+        _channel: &Channel,
+        message: &Message,
+        _spam: &SpamScore,
+    ) {
+        // Only report the first message over the limit in each burst
+        if DUPLICATE_MESSAGE_ACTION.as_str() != "flag"
+            || duplicate_channel_count(&message.author, message).await
+                != *DUPLICATE_MESSAGE_CHANNELS + 1
+        {
+            return;
+        }
+
+        report_message(
+            db,
+            message,
+            format!(
+                "Same message posted in {} channels within a minute.",
+                *DUPLICATE_MESSAGE_CHANNELS + 1
+            ),
+        )
+        .await;
+    }
+}
+
+/// Block or report messages whose combined spam score is too high
+///
+/// Configured through `REVOLT_SPAM_ACTION`, which is either `block` to reject
+/// the message or `flag` to file a report for it, and `REVOLT_SPAM_THRESHOLD`,
+/// the score from 0.0 to 1.0 at which to act.
+pub struct SpamThreshold;
+
+impl SpamThreshold {
+    /// Check whether a spam score is at or over the configured threshold
+    fn over_threshold(spam: &SpamScore) -> bool {
+        spam.score >= *SPAM_THRESHOLD
+    }
+}
+
+#[async_trait]
+impl MessageFilter for SpamThreshold {
+    async fn filter(
+        &self,
+        _db: &Database,
+        _channel: &Channel,
+        _author: &MessageAuthor<'_>,
+        _message: &mut Message,
+        spam: &SpamScore,
+    ) -> Result<()> {
+        if SPAM_ACTION.as_str() == "block" && Self::over_threshold(spam) {
+            return Err(Error::MessageRejected {
+                reason: "This message was detected as spam.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn after_send(
+        &self,
+        db: &Database,
+        _channel: &Channel,
+        message: &Message,
+        spam: &SpamScore,
+    ) {
+        if SPAM_ACTION.as_str() != "flag" || !Self::over_threshold(spam) {
+            return;
+        }
+
+        let signals = spam
+            .signals
+            .iter()
+            .map(|signal| format!("{} ({:.2})", signal.heuristic, signal.score))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        report_message(
+            db,
+            message,
+            format!("Spam score of {:.2} from {signals}.", spam.score),
+        )
+        .await;
+    }
+}
+
+/// File a spam report against a message on behalf of the system user
+///
+/// Nothing is reported unless `REVOLT_SYSTEM_USER_ID` is configured.
+async fn report_message(db: &Database, message: &Message, additional_context: String) {
+    let author_id = match &*SYSTEM_USER_ID {
+        Some(id) => id.to_string(),
+        None => {
+            warn!(
+                "Not reporting spam message {} as no system user is configured",
+                message.id
+            );
+            return;
+        }
+    };
+
+    let report = Report {
+        id: Ulid::new().to_string(),
+        author_id,
+        content: ReportedContent::Message {
+            id: message.id.to_string(),
+            report_reason: ContentReportReason::SpamAbuse,
+        },
+        additional_context,
+        status: ReportStatus::Created {},
+        notes: String::new(),
+    };
+
+    if let Err(err) = db.insert_report(&report).await {
+        warn!("Failed to report spam message {}: {err:?}", message.id);
+        return;
+    }
+
+    EventV1::ReportCreate(report).global().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use redis_kiss::get_connection;
+    use ulid::Ulid;
+
+    use super::{
+        record_sent, DuplicateContent, DuplicateSuppression, JoinAge, LinkDensity, SpamHeuristic,
+    };
+    use crate::{
+        models::{user::BotInformation, Channel, Message, User},
+        types::push::MessageAuthor,
+        variables::delta::DUPLICATE_MESSAGE_CHANNELS,
+        DatabaseInfo,
+    };
+
+    fn message(author: &str, channel: &str, content: &str) -> Message {
+        Message {
+            id: Ulid::new().to_string(),
+            channel: channel.to_string(),
+            author: author.to_string(),
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[async_std::test]
+    async fn duplicate_content_scores_other_channels() {
+        // Only meaningful with Redis available
+        if get_connection().await.is_err() {
+            return;
+        }
+
+        let db = DatabaseInfo::Dummy.connect().await.unwrap();
+        let user = User {
+            id: Ulid::new().to_string(),
+            ..Default::default()
+        };
+
+        let author = MessageAuthor::User(&user);
+        let channels: Vec<Channel> = (0..5)
+            .map(|_| Channel::SavedMessages {
+                id: Ulid::new().to_string(),
+                user: user.id.clone(),
+            })
+            .collect();
+
+        // Repeating content in the same channel is not suspicious
+        for _ in 0..2 {
+            let sent = message(&user.id, channels[0].id(), "Hello");
+            let score = DuplicateContent
+                .score(&db, &channels[0], &author, &sent)
+                .await;
+
+            assert_eq!(score, 0.0);
+            record_sent(&sent).await;
+        }
+
+        // Case and surrounding whitespace are ignored
+        for channel in &channels[1..4] {
+            record_sent(&message(&user.id, channel.id(), " HELLO ")).await;
+        }
+
+        let sent = message(&user.id, channels[4].id(), "hello");
+        let score = DuplicateContent
+            .score(&db, &channels[4], &author, &sent)
+            .await;
+
+        assert_eq!(score, 1.0);
+
+        let other = message(&user.id, channels[4].id(), "Goodbye");
+        let score = DuplicateContent
+            .score(&db, &channels[4], &author, &other)
+            .await;
+
+        assert_eq!(score, 0.0);
+    }
+
+    #[async_std::test]
+    async fn duplicate_suppression_only_counts_sent_messages() {
+        // Only meaningful with Redis available
+        if get_connection().await.is_err() {
+            return;
+        }
+
+        let author = Ulid::new().to_string();
+        let mut channels = vec![];
+        for _ in 0..*DUPLICATE_MESSAGE_CHANNELS {
+            let channel = Ulid::new().to_string();
+            let sent = message(&author, &channel, "Buy now");
+
+            // Checking a message does not count it until it is recorded as sent
+            assert!(!DuplicateSuppression::over_limit(&author, &sent).await);
+            assert!(!DuplicateSuppression::over_limit(&author, &sent).await);
+
+            record_sent(&sent).await;
+            channels.push(channel);
+        }
+
+        let new_channel = message(&author, &Ulid::new().to_string(), "Buy now");
+        assert!(DuplicateSuppression::over_limit(&author, &new_channel).await);
+
+        let same_channel = message(&author, &channels[0], "Buy now");
+        assert!(!DuplicateSuppression::over_limit(&author, &same_channel).await);
+
+        let other_author = message(
+            &Ulid::new().to_string(),
+            &Ulid::new().to_string(),
+            "Buy now",
+        );
+        assert!(!DuplicateSuppression::over_limit(&other_author.author, &other_author).await);
+    }
+
+    #[async_std::test]
+    async fn link_density_scores_many_links() {
+        let db = DatabaseInfo::Dummy.connect().await.unwrap();
+        let user = User {
+            id: Ulid::new().to_string(),
+            ..Default::default()
+        };
+
+        let author = MessageAuthor::User(&user);
+        let channel = Channel::SavedMessages {
+            id: Ulid::new().to_string(),
+            user: user.id.clone(),
+        };
+
+        let score = |content: &str| {
+            let message = message(&user.id, channel.id(), content);
+            let (db, channel, author) = (&db, &channel, &author);
+            async move { LinkDensity.score(db, channel, author, &message).await }
+        };
+
+        assert_eq!(score("No links here").await, 0.0);
+        assert!(score("Have a look at https://revolt.chat when you get a moment").await < 0.1);
+        assert_eq!(
+            score("https://a.example https://b.example https://c.example https://d.example").await,
+            1.0
+        );
+    }
+
+    #[async_std::test]
+    async fn join_age_scores_new_accounts_and_members() {
+        let db = DatabaseInfo::Dummy.connect().await.unwrap();
+        let new_user = User {
+            id: Ulid::new().to_string(),
+            ..Default::default()
+        };
+
+        let old_user = User {
+            id: "00000000000000000000000000".to_string(),
+            ..Default::default()
+        };
+
+        let bot = User {
+            bot: Some(BotInformation {
+                owner: old_user.id.clone(),
+            }),
+            ..new_user.clone()
+        };
+
+        let dm = Channel::SavedMessages {
+            id: Ulid::new().to_string(),
+            user: new_user.id.clone(),
+        };
+
+        // The dummy database reports every member as having just joined
+        let server_channel: Channel = serde_json::from_value(serde_json::json!({
+            "channel_type": "TextChannel",
+            "_id": Ulid::new().to_string(),
+            "server": Ulid::new().to_string(),
+            "name": "general"
+        }))
+        .unwrap();
+
+        let sent = message(&new_user.id, dm.id(), "Hello");
+        let score = |user: &User, channel: &Channel| {
+            let author = MessageAuthor::User(user);
+            let (db, channel, sent) = (&db, channel.clone(), &sent);
+            async move { JoinAge.score(db, &channel, &author, sent).await }
+        };
+
+        assert_eq!(score(&old_user, &dm).await, 0.0);
+        assert_eq!(score(&new_user, &dm).await, 0.2);
+        assert_eq!(score(&old_user, &server_channel).await, 0.3);
+        assert_eq!(score(&new_user, &server_channel).await, 0.5);
+        assert_eq!(score(&bot, &server_channel).await, 0.0);
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::env;
 use once_cell::sync::Lazy;
 
@@ -5,8 +6,11 @@ use once_cell::sync::Lazy;
 pub static PUBLIC_URL: Lazy<String> = Lazy::new(|| env::var("REVOLT_PUBLIC_URL").expect("Missing REVOLT_PUBLIC_URL environment variable."));
 pub static APP_URL: Lazy<String> = Lazy::new(|| env::var("REVOLT_APP_URL").expect("Missing REVOLT_APP_URL environment variable."));
 pub static EXTERNAL_WS_URL: Lazy<String> = Lazy::new(|| env::var("REVOLT_EXTERNAL_WS_URL").expect("Missing REVOLT_EXTERNAL_WS_URL environment variable."));
+pub static DEFAULT_LOCALE: Lazy<String> = Lazy::new(|| env::var("REVOLT_DEFAULT_LOCALE").unwrap_or_else(|_| "en".to_string()));
 
 pub static AUTUMN_URL: Lazy<String> = Lazy::new(|| env::var("AUTUMN_PUBLIC_URL").unwrap_or_else(|_| "https://example.com".to_string()));
+pub static STORAGE_REGIONS: Lazy<HashMap<String, String>> = Lazy::new(|| env::var("REVOLT_STORAGE_REGIONS").map_or(HashMap::new(), |v| v.split(',').filter_map(|r| r.split_once('=')).map(|(region, url)| (region.trim().to_lowercase(), url.trim().to_string())).filter(|(region, url)| !region.is_empty() && !url.is_empty()).collect()));
+pub static DEFAULT_STORAGE_REGION: Lazy<Option<String>> = Lazy::new(|| env::var("REVOLT_DEFAULT_STORAGE_REGION").ok().map(|v| v.trim().to_lowercase()).filter(|v| !v.is_empty()));
 pub static JANUARY_URL: Lazy<String> = Lazy::new(|| env::var("JANUARY_PUBLIC_URL").unwrap_or_else(|_| "https://example.com".to_string()));
 pub static JANUARY_CONCURRENT_CONNECTIONS: Lazy<usize> = Lazy::new(|| env::var("JANUARY_CONCURRENT_CONNECTIONS").map_or(50, |v| v.parse().unwrap()));
 pub static VOSO_URL: Lazy<String> = Lazy::new(|| env::var("VOSO_PUBLIC_URL").unwrap_or_else(|_| "https://example.com".to_string()));
@@ -17,7 +21,25 @@ pub static HCAPTCHA_KEY: Lazy<String> = Lazy::new(|| env::var("REVOLT_HCAPTCHA_K
 pub static HCAPTCHA_SITEKEY: Lazy<String> = Lazy::new(|| env::var("REVOLT_HCAPTCHA_SITEKEY").unwrap_or_else(|_| "10000000-ffff-ffff-ffff-000000000001".to_string()));
 pub static VAPID_PRIVATE_KEY: Lazy<String> = Lazy::new(|| env::var("REVOLT_VAPID_PRIVATE_KEY").expect("Missing REVOLT_VAPID_PRIVATE_KEY environment variable."));
 pub static VAPID_PUBLIC_KEY: Lazy<String> = Lazy::new(|| env::var("REVOLT_VAPID_PUBLIC_KEY").expect("Missing REVOLT_VAPID_PUBLIC_KEY environment variable."));
+pub static INBOUND_EMAIL_DOMAIN: Lazy<String> = Lazy::new(|| env::var("REVOLT_INBOUND_EMAIL_DOMAIN").unwrap_or_else(|_| "".to_string()));
+pub static INBOUND_EMAIL_SECRET: Lazy<String> = Lazy::new(|| env::var("REVOLT_INBOUND_EMAIL_SECRET").unwrap_or_else(|_| "".to_string()));
+pub static FEDERATION_DOMAIN: Lazy<String> = Lazy::new(|| env::var("REVOLT_FEDERATION_DOMAIN").unwrap_or_else(|_| "".to_string()));
+pub static FEDERATION_KEY: Lazy<String> = Lazy::new(|| env::var("REVOLT_FEDERATION_KEY").unwrap_or_else(|_| "".to_string()));
+pub static FEDERATION_ALLOWLIST: Lazy<Vec<String>> = Lazy::new(|| env::var("REVOLT_FEDERATION_ALLOWLIST").map_or(vec![], |v| v.split(',').map(|d| d.trim().to_lowercase()).filter(|d| !d.is_empty()).collect()));
+pub static FEDERATION_DENYLIST: Lazy<Vec<String>> = Lazy::new(|| env::var("REVOLT_FEDERATION_DENYLIST").map_or(vec![], |v| v.split(',').map(|d| d.trim().to_lowercase()).filter(|d| !d.is_empty()).collect()));
+pub static FEATURE_FLAGS: Lazy<Vec<String>> = Lazy::new(|| env::var("REVOLT_FEATURE_FLAGS").map_or(vec!["federation".to_string()], |v| v.split(',').map(|f| f.trim().to_lowercase()).filter(|f| !f.is_empty()).collect()));
+pub static MESSAGE_EFFECTS: Lazy<Vec<String>> = Lazy::new(|| env::var("REVOLT_MESSAGE_EFFECTS").map_or(vec!["confetti".to_string(), "spotlight".to_string()], |v| v.split(',').map(|e| e.trim().to_lowercase()).filter(|e| !e.is_empty()).collect()));
+pub static SEARCH_BACKEND: Lazy<Option<String>> = Lazy::new(|| env::var("REVOLT_SEARCH_BACKEND").ok().map(|v| v.to_lowercase()));
+pub static SEARCH_URL: Lazy<String> = Lazy::new(|| env::var("REVOLT_SEARCH_URL").unwrap_or_else(|_| "http://localhost:7700".to_string()));
+pub static READ_REPLICAS: Lazy<bool> = Lazy::new(|| env::var("REVOLT_READ_REPLICAS").map_or(false, |v| v == "1"));
+pub static READ_REPLICA_MAX_STALENESS: Lazy<u64> = Lazy::new(|| env::var("REVOLT_READ_REPLICA_MAX_STALENESS").unwrap_or_else(|_| "90".to_string()).parse().unwrap());
+pub static SEARCH_KEY: Lazy<Option<String>> = Lazy::new(|| env::var("REVOLT_SEARCH_KEY").ok());
+pub static SEARCH_INDEX: Lazy<String> = Lazy::new(|| env::var("REVOLT_SEARCH_INDEX").unwrap_or_else(|_| "messages".to_string()));
 pub static AUTHIFIER_SHIELD_KEY: Lazy<Option<String>> = Lazy::new(|| env::var("REVOLT_AUTHIFIER_SHIELD_KEY").ok());
+pub static MONITOR_SNAPSHOT_INTERVAL: Lazy<u64> = Lazy::new(|| env::var("REVOLT_MONITOR_SNAPSHOT_INTERVAL").unwrap_or_else(|_| "6".to_string()).parse().unwrap());
+pub static MONITOR_SNAPSHOT_RETENTION: Lazy<u64> = Lazy::new(|| env::var("REVOLT_MONITOR_SNAPSHOT_RETENTION").unwrap_or_else(|_| "30".to_string()).parse().unwrap());
+pub static MESSAGE_RECOVERY_WINDOW: Lazy<u64> = Lazy::new(|| env::var("REVOLT_MESSAGE_RECOVERY_WINDOW").unwrap_or_else(|_| "600".to_string()).parse().unwrap());
+pub static SYSTEM_USER_ID: Lazy<Option<String>> = Lazy::new(|| env::var("REVOLT_SYSTEM_USER_ID").ok());
 
 // Application Flags
 pub static INVITE_ONLY: Lazy<bool> = Lazy::new(|| env::var("REVOLT_INVITE_ONLY").map_or(false, |v| v == "1"));
@@ -31,7 +53,10 @@ pub static USE_EMAIL: Lazy<bool> = Lazy::new(|| env::var("REVOLT_USE_EMAIL_VERIF
 pub static USE_HCAPTCHA: Lazy<bool> = Lazy::new(|| env::var("REVOLT_HCAPTCHA_KEY").is_ok());
 pub static USE_AUTUMN: Lazy<bool> = Lazy::new(|| env::var("AUTUMN_PUBLIC_URL").is_ok());
 pub static USE_JANUARY: Lazy<bool> = Lazy::new(|| env::var("JANUARY_PUBLIC_URL").is_ok());
+pub static USE_INBOUND_EMAIL: Lazy<bool> = Lazy::new(|| env::var("REVOLT_INBOUND_EMAIL_DOMAIN").is_ok() && env::var("REVOLT_INBOUND_EMAIL_SECRET").map(|secret| !secret.is_empty()).unwrap_or(false));
+pub static USE_FEDERATION: Lazy<bool> = Lazy::new(|| env::var("REVOLT_FEDERATION_DOMAIN").is_ok() && env::var("REVOLT_FEDERATION_KEY").is_ok());
 pub static USE_VOSO: Lazy<bool> = Lazy::new(|| env::var("VOSO_PUBLIC_URL").is_ok() && env::var("VOSO_MANAGE_TOKEN").is_ok());
+pub static REQUIRE_VERIFIED_EMAIL: Lazy<bool> = Lazy::new(|| env::var("REVOLT_REQUIRE_VERIFIED_EMAIL").is_ok());
 
 // SMTP Settings
 pub static SMTP_HOST: Lazy<String> = Lazy::new(|| env::var("REVOLT_SMTP_HOST").unwrap_or_else(|_| "".to_string()));
@@ -48,7 +73,20 @@ pub static MAX_CHANNEL_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_CH
 pub static MAX_ROLE_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_ROLE_COUNT").unwrap_or_else(|_| "200".to_string()).parse().unwrap());
 pub static MAX_EMOJI_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_EMOJI_COUNT").unwrap_or_else(|_| "100".to_string()).parse().unwrap());
 pub static MAX_ATTACHMENT_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_ATTACHMENT_COUNT").unwrap_or_else(|_| "5".to_string()).parse().unwrap());
+pub static MAX_FEED_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_FEED_COUNT").unwrap_or_else(|_| "10".to_string()).parse().unwrap());
+pub static MAX_SCHEDULE_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_SCHEDULE_COUNT").unwrap_or_else(|_| "25".to_string()).parse().unwrap());
+pub static MAX_SCHEDULED_MESSAGE_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_SCHEDULED_MESSAGE_COUNT").unwrap_or_else(|_| "50".to_string()).parse().unwrap());
+pub static MAX_REACTION_ROLE_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_REACTION_ROLE_COUNT").unwrap_or_else(|_| "100".to_string()).parse().unwrap());
+pub static MAX_BAN_SYNC_GROUP_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_BAN_SYNC_GROUP_COUNT").unwrap_or_else(|_| "5".to_string()).parse().unwrap());
 pub static MAX_REPLY_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_MAX_REPLY_COUNT").unwrap_or_else(|_| "5".to_string()).parse().unwrap());
+pub static UNVERIFIED_MAX_SERVER_COUNT: Lazy<usize> = Lazy::new(|| env::var("REVOLT_UNVERIFIED_MAX_SERVER_COUNT").unwrap_or_else(|_| "5".to_string()).parse().unwrap());
+pub static MIN_ACCOUNT_AGE: Lazy<i64> = Lazy::new(|| env::var("REVOLT_MIN_ACCOUNT_AGE").unwrap_or_else(|_| "0".to_string()).parse().unwrap());
+pub static CHALLENGE_DM_THRESHOLD: Lazy<u32> = Lazy::new(|| env::var("REVOLT_CHALLENGE_DM_THRESHOLD").unwrap_or_else(|_| "10".to_string()).parse().unwrap());
+pub static CHALLENGE_JOIN_THRESHOLD: Lazy<u32> = Lazy::new(|| env::var("REVOLT_CHALLENGE_JOIN_THRESHOLD").unwrap_or_else(|_| "5".to_string()).parse().unwrap());
+pub static DUPLICATE_MESSAGE_ACTION: Lazy<String> = Lazy::new(|| env::var("REVOLT_DUPLICATE_MESSAGE_ACTION").unwrap_or_else(|_| "".to_string()));
+pub static DUPLICATE_MESSAGE_CHANNELS: Lazy<usize> = Lazy::new(|| env::var("REVOLT_DUPLICATE_MESSAGE_CHANNELS").unwrap_or_else(|_| "3".to_string()).parse().unwrap());
+pub static SPAM_ACTION: Lazy<String> = Lazy::new(|| env::var("REVOLT_SPAM_ACTION").unwrap_or_else(|_| "".to_string()));
+pub static SPAM_THRESHOLD: Lazy<f32> = Lazy::new(|| env::var("REVOLT_SPAM_THRESHOLD").unwrap_or_else(|_| "0.8".to_string()).parse().unwrap());
 
 pub static EARLY_ADOPTER_BADGE: Lazy<i64> = Lazy::new(|| env::var("REVOLT_EARLY_ADOPTER_BADGE").unwrap_or_else(|_| "0".to_string()).parse().unwrap());
 
@@ -41,6 +41,15 @@ impl IdempotencyKey {
     }
 }
 
+impl Default for IdempotencyKey {
+    /// Generate a fresh key for requests which did not originate from HTTP
+    fn default() -> Self {
+        IdempotencyKey {
+            key: ulid::Ulid::new().to_string(),
+        }
+    }
+}
+
 impl<'r> OpenApiFromRequest<'r> for IdempotencyKey {
     fn from_request_input(
         _gen: &mut OpenApiGenerator,
@@ -9,6 +9,7 @@ use std::ops::Add;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::authifier::models::Session;
+use crate::{Error, Result};
 use rocket::fairing::{Fairing, Info, Kind};
 use rocket::http::uri::Origin;
 use rocket::http::{Method, Status};
@@ -127,6 +128,15 @@ fn resolve_bucket<'r>(request: &'r rocket::Request<'_>) -> (&'r str, Option<&'r
                     ("auth", None)
                 }
             }
+            ("invites", Some(_)) => {
+                if request.method() == Method::Post {
+                    if let Some("guest") = request.routed_segment(2) {
+                        return ("invite_guest", None);
+                    }
+                }
+
+                ("any", None)
+            }
             ("swagger", _) => ("swagger", None),
             ("safety", Some("report")) => ("safety_report", Some("report")),
             ("safety", _) => ("safety", None),
@@ -151,6 +161,7 @@ fn resolve_bucket_limit(bucket: &str) -> u8 {
         "swagger" => 100,
         "safety" => 15,
         "safety_report" => 3,
+        "invite_guest" => 2,
         _ => 20,
     }
 }
@@ -318,8 +329,13 @@ impl<'r> FromRequest<'r> for RatelimitInformation {
 }
 
 #[rocket::get("/ratelimit")]
-fn ratelimit_info(info: RatelimitInformation) -> Json<RatelimitInformation> {
-    Json(info)
+fn ratelimit_info(info: RatelimitInformation) -> Result<Json<RatelimitInformation>> {
+    match info {
+        RatelimitInformation::Failure { retry_after } => {
+            Err(Error::TooManyRequests { retry_after })
+        }
+        info => Ok(Json(info)),
+    }
 }
 
 pub fn routes() -> Vec<rocket::Route> {
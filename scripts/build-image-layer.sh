@@ -22,6 +22,7 @@ deps() {
   mkdir -p \
     crates/bonfire/src \
     crates/delta/src \
+    crates/irc/src \
     crates/quark/src \
     crates/core/database/src \
     crates/core/models/src \
@@ -30,7 +31,8 @@ deps() {
     crates/core/result/src
   echo 'fn main() { panic!("stub"); }' |
     tee crates/bonfire/src/main.rs |
-    tee crates/delta/src/main.rs
+    tee crates/delta/src/main.rs |
+    tee crates/irc/src/main.rs
   echo '' |
     tee crates/quark/src/lib.rs |
     tee crates/core/database/src/lib.rs |
@@ -45,6 +47,7 @@ apps() {
   touch -am \
     crates/bonfire/src/main.rs \
     crates/delta/src/main.rs \
+    crates/irc/src/main.rs \
     crates/quark/src/lib.rs \
     crates/core/database/src/lib.rs \
     crates/core/models/src/lib.rs \